@@ -8,8 +8,7 @@
 
 use std::{env, path::PathBuf};
 
-use noodles_bam::{self as bam, bai};
-use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_bam::{self as bam, bai, index_stats::IndexStats};
 use noodles_sam as sam;
 use tokio::fs::File;
 
@@ -22,27 +21,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let index = bai::r#async::read(src.with_extension("bam.bai")).await?;
 
-    for (reference_sequence, index_reference_sequence) in header
-        .reference_sequences()
-        .values()
-        .zip(index.reference_sequences())
-    {
-        let (mapped_record_count, unmapped_record_count) = index_reference_sequence
-            .metadata()
-            .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
-            .unwrap_or_default();
-
-        println!(
-            "{}\t{}\t{}\t{}",
-            reference_sequence.name(),
-            reference_sequence.len(),
-            mapped_record_count,
-            unmapped_record_count
-        );
-    }
-
-    let unmapped_record_count = index.unplaced_unmapped_record_count().unwrap_or_default();
-    println!("*\t0\t0\t{}", unmapped_record_count);
+    let stats = IndexStats::from_index(header.reference_sequences(), &index)?;
+    print!("{}", stats);
 
     Ok(())
 }
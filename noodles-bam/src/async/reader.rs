@@ -18,7 +18,7 @@ use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 use self::{query::query, record::read_record};
 use crate::{
     lazy,
-    reader::{bytes_with_nul_to_string, resolve_region},
+    reader::{bytes_with_nul_to_string, resolve_region, Fields},
     MAGIC_NUMBER,
 };
 
@@ -50,6 +50,7 @@ use crate::{
 pub struct Reader<R> {
     inner: R,
     buf: BytesMut,
+    fields: Fields,
 }
 
 impl<R> Reader<R>
@@ -98,6 +99,42 @@ where
         self.inner
     }
 
+    /// Returns the record fields that are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::{self as bam, reader::Fields};
+    /// let data = [];
+    /// let reader = bam::AsyncReader::from(&data[..]);
+    /// assert_eq!(reader.fields(), Fields::default());
+    /// ```
+    pub fn fields(&self) -> Fields {
+        self.fields
+    }
+
+    /// Sets the record fields to decode.
+    ///
+    /// This applies to records read with [`Self::read_record`] and its streams. Fields that are
+    /// not decoded are left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::{self as bam, reader::Fields};
+    ///
+    /// let data = [];
+    /// let mut reader = bam::AsyncReader::from(&data[..]);
+    ///
+    /// let fields = Fields::default().set_sequence(false).set_quality_scores(false);
+    /// reader.set_fields(fields);
+    ///
+    /// assert_eq!(reader.fields(), fields);
+    /// ```
+    pub fn set_fields(&mut self, fields: Fields) {
+        self.fields = fields;
+    }
+
     /// Reads the raw SAM header.
     ///
     /// The BAM magic number is also checked.
@@ -194,7 +231,7 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, &mut self.buf, record).await
+        read_record(&mut self.inner, &mut self.buf, self.fields, record).await
     }
 
     /// Reads a single record without eagerly decoding its fields.
@@ -275,10 +312,12 @@ where
     /// # }
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        let fields = self.fields;
+
         Box::pin(stream::try_unfold(
             (&mut self.inner, &mut self.buf, Record::default()),
             move |(reader, buf, mut record)| async move {
-                read_record(reader, buf, &mut record)
+                read_record(reader, buf, fields, &mut record)
                     .await
                     .map(|n| match n {
                         0 => None,
@@ -463,6 +502,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: BytesMut::new(),
+            fields: Fields::default(),
         }
     }
 }
@@ -613,4 +653,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_records_with_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::TryStreamExt;
+
+        use crate::writer::record::encode_record;
+
+        let header = sam::Header::default();
+        let expected = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(sam::record::Flags::empty())
+            .set_sequence("ACG".parse()?)
+            .set_quality_scores("NDL".parse()?)
+            .build();
+
+        let mut buf = Vec::new();
+        encode_record(&mut buf, &header, &expected)?;
+
+        let mut data = (buf.len() as u32).to_le_bytes().to_vec();
+        data.extend(buf);
+
+        let mut reader = Reader::from(&data[..]);
+        reader.set_fields(Fields::default().set_sequence(false));
+
+        let records: Vec<_> = reader.records().try_collect().await?;
+        assert_eq!(records.len(), 1);
+
+        let record = &records[0];
+        assert_eq!(record.read_name(), expected.read_name());
+        assert!(record.sequence().is_empty());
+        assert_eq!(record.quality_scores(), expected.quality_scores());
+
+        Ok(())
+    }
 }
@@ -4,16 +4,17 @@ use bytes::BytesMut;
 use noodles_sam::alignment::Record;
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
+use crate::reader::{record::decode_record, Fields};
+
 pub(super) async fn read_record<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    fields: Fields,
     record: &mut Record,
 ) -> io::Result<usize>
 where
     R: AsyncRead + Unpin,
 {
-    use crate::reader::record::decode_record;
-
     let block_size = match read_block_size(reader).await? {
         0 => return Ok(0),
         n => n,
@@ -22,7 +23,7 @@ where
     buf.resize(block_size, Default::default());
     reader.read_exact(buf).await?;
 
-    decode_record(buf, record)?;
+    decode_record(buf, fields, record)?;
 
     Ok(block_size)
 }
@@ -76,7 +77,7 @@ mod tests {
         let mut reader = &data[..];
         let mut buf = BytesMut::new();
         let mut record = Record::default();
-        let block_size = read_record(&mut reader, &mut buf, &mut record).await?;
+        let block_size = read_record(&mut reader, &mut buf, Fields::default(), &mut record).await?;
 
         assert_eq!(block_size, 34);
         assert_eq!(record, Record::default());
@@ -12,7 +12,10 @@ use std::io;
 use bit_vec::BitVec;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
-use noodles_csi::{binning_index::ReferenceSequenceExt, index::reference_sequence::Metadata};
+use noodles_csi::{
+    binning_index::{estimate_record_counts, ReferenceSequenceExt},
+    index::reference_sequence::Metadata,
+};
 
 use super::{resolve_interval, MIN_SHIFT};
 
@@ -101,11 +104,7 @@ impl ReferenceSequence {
         let (start, end) = resolve_interval(interval)?;
         let region_bins = region_to_bins(start, end);
 
-        let query_bins = self
-            .bins()
-            .iter()
-            .filter(|b| region_bins[b.id() as usize])
-            .collect();
+        let query_bins = self.bins().iter().filter(|b| region_bins[b.id()]).collect();
 
         Ok(query_bins)
     }
@@ -134,6 +133,40 @@ impl ReferenceSequence {
         let i = (usize::from(start) - 1) / WINDOW_SIZE;
         self.intervals.get(i).copied().unwrap_or_default()
     }
+
+    /// Estimates the number of records in each 16 kbp window of the linear index.
+    ///
+    /// See [`noodles_csi::binning_index::estimate_record_counts`]. This does not read any records
+    /// and returns `None` if the reference sequence does not have metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::bai::index::ReferenceSequence;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::index::reference_sequence::Metadata;
+    ///
+    /// let reference_sequence = ReferenceSequence::new(Vec::new(), Vec::new(), None);
+    /// assert!(reference_sequence.estimate_record_counts().is_none());
+    ///
+    /// let intervals = vec![
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(1 << 16),
+    /// ];
+    /// let metadata = Metadata::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(4 << 16),
+    ///     8,
+    ///     0,
+    /// );
+    /// let reference_sequence = ReferenceSequence::new(Vec::new(), intervals, Some(metadata));
+    /// assert_eq!(reference_sequence.estimate_record_counts(), Some(vec![2, 6]));
+    /// ```
+    pub fn estimate_record_counts(&self) -> Option<Vec<u64>> {
+        self.metadata
+            .as_ref()
+            .map(|metadata| estimate_record_counts(&self.intervals, metadata))
+    }
 }
 
 impl ReferenceSequenceExt for ReferenceSequence {
@@ -191,7 +224,7 @@ fn region_to_bins(start: Position, end: Position) -> BitVec {
     let start = usize::from(start) - 1;
     let end = usize::from(end) - 1;
 
-    let mut bins = BitVec::from_elem(bin::MAX_ID as usize, false);
+    let mut bins = BitVec::from_elem(bin::MAX_ID, false);
     bins.set(0, true);
 
     for k in (1 + (start >> 26))..=(1 + (end >> 26)) {
@@ -240,7 +273,7 @@ mod tests {
         let start = Position::try_from(8)?;
         let end = Position::try_from(13)?;
         let actual = region_to_bins(start, end);
-        let mut expected = BitVec::from_elem(bin::MAX_ID as usize, false);
+        let mut expected = BitVec::from_elem(bin::MAX_ID, false);
         for &k in &[0, 1, 9, 73, 585, 4681] {
             expected.set(k, true);
         }
@@ -249,7 +282,7 @@ mod tests {
         let start = Position::try_from(63245985)?;
         let end = Position::try_from(63255986)?;
         let actual = region_to_bins(start, end);
-        let mut expected = BitVec::from_elem(bin::MAX_ID as usize, false);
+        let mut expected = BitVec::from_elem(bin::MAX_ID, false);
         for &k in &[0, 1, 16, 133, 1067, 8541] {
             expected.set(k, true);
         }
@@ -0,0 +1,278 @@
+//! BAM index statistics.
+
+use std::{fmt, io, num::NonZeroUsize};
+
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::header::{reference_sequence::Name, ReferenceSequences};
+
+/// Index statistics of a reference sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceSequenceStats {
+    name: Name,
+    len: NonZeroUsize,
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequenceStats {
+    /// Returns the reference sequence name.
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the reference sequence length.
+    pub fn len(&self) -> NonZeroUsize {
+        self.len
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of placed unmapped records.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+/// BAM index statistics.
+///
+/// These are the per-reference sequence record counts stored in an index (BAI or CSI), i.e., the
+/// same statistics as `samtools idxstats`. They are read entirely from the SAM header reference
+/// sequences and the index; no records are read.
+///
+/// The [`fmt::Display`] implementation writes the statistics in the same tab-delimited format as
+/// `samtools idxstats`, where each line is a reference sequence name, reference sequence length,
+/// number of mapped records, and number of placed unmapped records. The last line (`*`) is the
+/// number of unplaced unmapped records.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_bam::{self as bam, bai, index_stats::IndexStats};
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header: sam::Header = reader.read_header()?.parse()?;
+///
+/// let index = bai::read("sample.bam.bai")?;
+/// let stats = IndexStats::from_index(header.reference_sequences(), &index)?;
+///
+/// for reference_sequence_stats in stats.reference_sequences() {
+///     println!(
+///         "{}\t{}",
+///         reference_sequence_stats.name(),
+///         reference_sequence_stats.mapped_record_count()
+///     );
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndexStats {
+    reference_sequences: Vec<ReferenceSequenceStats>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl IndexStats {
+    /// Builds index statistics from reference sequences and an associated index.
+    ///
+    /// Reference sequences without index metadata have record counts of 0. This returns an error
+    /// if the index has more reference sequences than the given reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::{bai, index_stats::IndexStats};
+    /// use noodles_sam::header::ReferenceSequences;
+    ///
+    /// let reference_sequences = ReferenceSequences::default();
+    /// let index = bai::Index::default();
+    ///
+    /// let stats = IndexStats::from_index(&reference_sequences, &index)?;
+    /// assert!(stats.reference_sequences().is_empty());
+    /// assert_eq!(stats.unplaced_unmapped_record_count(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn from_index<I>(reference_sequences: &ReferenceSequences, index: &I) -> io::Result<Self>
+    where
+        I: BinningIndex,
+    {
+        let index_reference_sequences = index.reference_sequences();
+
+        if index_reference_sequences.len() > reference_sequences.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "index reference sequence count mismatch: expected <= {}, got {}",
+                    reference_sequences.len(),
+                    index_reference_sequences.len()
+                ),
+            ));
+        }
+
+        let reference_sequences = reference_sequences
+            .values()
+            .enumerate()
+            .map(|(i, reference_sequence)| {
+                let (mapped_record_count, unmapped_record_count) = index_reference_sequences
+                    .get(i)
+                    .and_then(|rs| rs.metadata())
+                    .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
+                    .unwrap_or_default();
+
+                ReferenceSequenceStats {
+                    name: reference_sequence.name().clone(),
+                    len: reference_sequence.len(),
+                    mapped_record_count,
+                    unmapped_record_count,
+                }
+            })
+            .collect();
+
+        let unplaced_unmapped_record_count =
+            index.unplaced_unmapped_record_count().unwrap_or_default();
+
+        Ok(Self {
+            reference_sequences,
+            unplaced_unmapped_record_count,
+        })
+    }
+
+    /// Returns the statistics of each reference sequence.
+    pub fn reference_sequences(&self) -> &[ReferenceSequenceStats] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unplaced unmapped records.
+    pub fn unplaced_unmapped_record_count(&self) -> u64 {
+        self.unplaced_unmapped_record_count
+    }
+
+    /// Returns the total number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.reference_sequences
+            .iter()
+            .map(|rs| rs.mapped_record_count)
+            .sum()
+    }
+
+    /// Returns the total number of unmapped records, both placed and unplaced.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.reference_sequences
+            .iter()
+            .map(|rs| rs.unmapped_record_count)
+            .sum::<u64>()
+            + self.unplaced_unmapped_record_count
+    }
+}
+
+impl fmt::Display for IndexStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rs in &self.reference_sequences {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                rs.name, rs.len, rs.mapped_record_count, rs.unmapped_record_count
+            )?;
+        }
+
+        writeln!(f, "*\t0\t0\t{}", self.unplaced_unmapped_record_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_core::Position;
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+    use noodles_sam::{alignment::Record, header::ReferenceSequence, record::Flags};
+
+    use super::*;
+    use crate::bai;
+
+    #[test]
+    fn test_from_index() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences: ReferenceSequences = [
+            ReferenceSequence::new("sq0".parse()?, 8)?,
+            ReferenceSequence::new("sq1".parse()?, 13)?,
+            ReferenceSequence::new("sq2".parse()?, 21)?,
+        ]
+        .into_iter()
+        .map(|rs| (rs.name().to_string(), rs))
+        .collect();
+
+        let records = [
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar("4M".parse()?)
+                .build(),
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("4M".parse()?)
+                .build(),
+            Record::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_cigar("4M".parse()?)
+                .build(),
+            Record::builder().set_flags(Flags::UNMAPPED).build(),
+        ];
+
+        let mut builder = bai::Index::builder();
+
+        for (i, record) in records.iter().enumerate() {
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(i as u64),
+                bgzf::VirtualPosition::from(i as u64 + 1),
+            );
+
+            builder.add_record(record, chunk)?;
+        }
+
+        let index = builder.build(reference_sequences.len());
+        let stats = IndexStats::from_index(&reference_sequences, &index)?;
+
+        let actual: Vec<_> = stats
+            .reference_sequences()
+            .iter()
+            .map(|rs| {
+                (
+                    rs.name().to_string(),
+                    rs.mapped_record_count(),
+                    rs.unmapped_record_count(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (String::from("sq0"), 2, 0),
+            (String::from("sq1"), 0, 1),
+            (String::from("sq2"), 0, 0),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(stats.unplaced_unmapped_record_count(), 1);
+        assert_eq!(stats.mapped_record_count(), 2);
+        assert_eq!(stats.unmapped_record_count(), 2);
+
+        assert_eq!(
+            stats.to_string(),
+            "sq0\t8\t2\t0\nsq1\t13\t0\t1\nsq2\t21\t0\t0\n*\t0\t0\t1\n"
+        );
+
+        assert!(matches!(
+            IndexStats::from_index(&ReferenceSequences::default(), &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
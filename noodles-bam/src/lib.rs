@@ -53,12 +53,14 @@
 mod r#async;
 
 pub mod bai;
+pub mod index_stats;
 pub mod lazy;
 pub mod reader;
 pub mod record;
+mod reheader;
 pub mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{reader::Reader, reheader::reheader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -1,5 +1,7 @@
 //! BAM reader and iterators.
 
+mod count;
+mod fields;
 mod lazy_records;
 pub(crate) mod query;
 pub mod record;
@@ -7,7 +9,8 @@ mod records;
 mod unmapped_records;
 
 pub use self::{
-    lazy_records::LazyRecords, query::Query, records::Records, unmapped_records::UnmappedRecords,
+    fields::Fields, lazy_records::LazyRecords, query::Query, records::Records,
+    unmapped_records::UnmappedRecords,
 };
 
 use std::{
@@ -26,7 +29,7 @@ use noodles_sam::{
     header::{ReferenceSequence, ReferenceSequences},
 };
 
-use super::{bai, lazy, MAGIC_NUMBER};
+use super::{lazy, MAGIC_NUMBER};
 
 /// A BAM reader.
 ///
@@ -60,6 +63,7 @@ use super::{bai, lazy, MAGIC_NUMBER};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    fields: Fields,
 }
 
 impl<R> Reader<R>
@@ -108,6 +112,42 @@ where
         self.inner
     }
 
+    /// Returns the record fields that are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::{self as bam, reader::Fields};
+    /// let data = [];
+    /// let reader = bam::Reader::from(&data[..]);
+    /// assert_eq!(reader.fields(), Fields::default());
+    /// ```
+    pub fn fields(&self) -> Fields {
+        self.fields
+    }
+
+    /// Sets the record fields to decode.
+    ///
+    /// This applies to records read with [`Self::read_record`] and its iterators. Fields that are
+    /// not decoded are left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::{self as bam, reader::Fields};
+    ///
+    /// let data = [];
+    /// let mut reader = bam::Reader::from(&data[..]);
+    ///
+    /// let fields = Fields::default().set_sequence(false).set_quality_scores(false);
+    /// reader.set_fields(fields);
+    ///
+    /// assert_eq!(reader.fields(), fields);
+    /// ```
+    pub fn set_fields(&mut self, fields: Fields) {
+        self.fields = fields;
+    }
+
     /// Reads the raw SAM header.
     ///
     /// The BAM magic number is also checked.
@@ -189,7 +229,7 @@ where
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
         use self::record::read_record;
-        read_record(&mut self.inner, &mut self.buf, record)
+        read_record(&mut self.inner, &mut self.buf, self.fields, record)
     }
 
     /// Reads a single record without eagerly decoding its fields.
@@ -402,6 +442,45 @@ where
         ))
     }
 
+    /// Counts the records that intersect the given region.
+    ///
+    /// This is faster than counting the records of a [`Self::query`]. When the region covers an
+    /// entire reference sequence, e.g., `sq0`, the count is read from the index's reference
+    /// sequence metadata, if available, and no records are read. Otherwise, only the positions
+    /// and CIGARs of records in the overlapping chunks are decoded.
+    ///
+    /// Placed unmapped records are counted as covering their alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let n = reader.count(reference_sequences, &index, &region)?;
+    /// println!("{}", n);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count<I>(
+        &mut self,
+        reference_sequences: &ReferenceSequences,
+        index: &I,
+        region: &Region,
+    ) -> io::Result<u64>
+    where
+        I: BinningIndex,
+    {
+        let reference_sequence_id = resolve_region(reference_sequences, region)?;
+        count::count(self, index, reference_sequence_id, region.interval())
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
@@ -420,7 +499,10 @@ where
     /// }
     /// # Ok::<(), io::Error>(())
     /// ```
-    pub fn query_unmapped(&mut self, index: &bai::Index) -> io::Result<UnmappedRecords<'_, R>> {
+    pub fn query_unmapped<I>(&mut self, index: &I) -> io::Result<UnmappedRecords<'_, R>>
+    where
+        I: BinningIndex,
+    {
         if let Some(pos) = index.first_record_in_last_linear_bin_start_position() {
             self.seek(pos)?;
         } else {
@@ -436,6 +518,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            fields: Fields::default(),
         }
     }
 }
@@ -0,0 +1,173 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::{region::Interval, Position};
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam as sam;
+
+use super::Reader;
+use crate::lazy;
+
+// Counts the records that intersect the given interval.
+//
+// If the interval covers the entire reference sequence and the index has reference sequence
+// metadata, the count is taken from the metadata. Otherwise, the records in the chunks that
+// overlap the interval are read as lazy records, and only their positions and CIGARs are decoded.
+pub(super) fn count<R, I>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &I,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> io::Result<u64>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    if is_unbounded(interval) {
+        if let Some(metadata) = index
+            .reference_sequences()
+            .get(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+        {
+            return Ok(metadata.mapped_record_count() + metadata.unmapped_record_count());
+        }
+    }
+
+    let chunks = index.query(reference_sequence_id, interval)?;
+
+    let mut record = lazy::Record::default();
+    let mut n = 0;
+
+    for chunk in chunks {
+        reader.seek(chunk.start())?;
+
+        loop {
+            if reader.read_lazy_record(&mut record)? == 0 {
+                break;
+            }
+
+            if intersects(&record, reference_sequence_id, interval)? {
+                n += 1;
+            }
+
+            if reader.virtual_position() >= chunk.end() {
+                break;
+            }
+        }
+    }
+
+    Ok(n)
+}
+
+fn is_unbounded(interval: Interval) -> bool {
+    matches!(interval.start(), None | Some(Position::MIN)) && interval.end().is_none()
+}
+
+// A record without an alignment span, e.g., a placed unmapped record, is treated as covering its
+// alignment start.
+fn intersects(
+    record: &lazy::Record,
+    reference_sequence_id: usize,
+    region_interval: Interval,
+) -> io::Result<bool> {
+    if record.reference_sequence_id()? != Some(reference_sequence_id) {
+        return Ok(false);
+    }
+
+    let start = match record.alignment_start()? {
+        Some(position) => position,
+        None => return Ok(false),
+    };
+
+    let cigar = sam::record::Cigar::try_from(record.cigar())?;
+    let span = cigar.alignment_span().max(1);
+
+    let end = usize::from(start)
+        .checked_add(span - 1)
+        .and_then(Position::new)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid alignment end"))?;
+
+    Ok(Interval::from(start..=end).intersects(region_interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+    use noodles_sam::{alignment::Record, header::ReferenceSequence, record::Flags};
+
+    use super::*;
+    use crate::{bai, Writer};
+
+    #[test]
+    fn test_count() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 34)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 21)?)
+            .build();
+
+        let records = [
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar("4M".parse()?)
+                .build(),
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(8)?)
+                .set_cigar("5M".parse()?)
+                .build(),
+            Record::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(13)?)
+                .build(),
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_cigar("3M".parse()?)
+                .build(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        let mut index_builder = bai::Index::builder();
+
+        for record in &records {
+            let start = writer.get_ref().virtual_position();
+            writer.write_record(&header, record)?;
+            let end = writer.get_ref().virtual_position();
+            index_builder.add_record(record, Chunk::new(start, end))?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = index_builder.build(header.reference_sequences().len());
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let reference_sequences = header.reference_sequences();
+
+        let mut t = |raw_region: &str, expected: u64| -> Result<(), Box<dyn std::error::Error>> {
+            let region = raw_region.parse()?;
+            let actual = reader.count(reference_sequences, &index, &region)?;
+            assert_eq!(actual, expected, "{}", raw_region);
+            Ok(())
+        };
+
+        t("sq0", 3)?;
+        t("sq0:1-4", 1)?;
+        t("sq0:4-8", 2)?;
+        t("sq0:10-", 2)?;
+        t("sq0:14-34", 0)?;
+        t("sq1", 1)?;
+        t("sq1:1-4", 0)?;
+
+        Ok(())
+    }
+}
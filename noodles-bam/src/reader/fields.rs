@@ -0,0 +1,70 @@
+/// Optional record fields to decode.
+///
+/// By default, all fields are decoded. Fields that are skipped are still read from the stream but
+/// left empty in the record, which avoids the cost of decoding them when they are not used, e.g.,
+/// when only positions, flags, and names are needed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::reader::Fields;
+///
+/// let fields = Fields::default()
+///     .set_sequence(false)
+///     .set_quality_scores(false);
+///
+/// assert!(!fields.sequence());
+/// assert!(!fields.quality_scores());
+/// assert!(fields.data());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fields {
+    sequence: bool,
+    quality_scores: bool,
+    data: bool,
+}
+
+impl Fields {
+    /// Returns whether the sequence is decoded.
+    pub fn sequence(&self) -> bool {
+        self.sequence
+    }
+
+    /// Sets whether the sequence is decoded.
+    pub fn set_sequence(mut self, sequence: bool) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Returns whether the quality scores are decoded.
+    pub fn quality_scores(&self) -> bool {
+        self.quality_scores
+    }
+
+    /// Sets whether the quality scores are decoded.
+    pub fn set_quality_scores(mut self, quality_scores: bool) -> Self {
+        self.quality_scores = quality_scores;
+        self
+    }
+
+    /// Returns whether the data fields are decoded.
+    pub fn data(&self) -> bool {
+        self.data
+    }
+
+    /// Sets whether the data fields are decoded.
+    pub fn set_data(mut self, data: bool) -> Self {
+        self.data = data;
+        self
+    }
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Self {
+            sequence: true,
+            quality_scores: true,
+            data: true,
+        }
+    }
+}
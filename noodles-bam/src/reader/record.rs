@@ -23,9 +23,12 @@ use bytes::Buf;
 use noodles_core::Position;
 use noodles_sam::{self as sam, alignment::Record};
 
+use super::Fields;
+
 pub(crate) fn read_record<R>(
     reader: &mut R,
     buf: &mut Vec<u8>,
+    fields: Fields,
     record: &mut Record,
 ) -> io::Result<usize>
 where
@@ -40,7 +43,7 @@ where
     reader.read_exact(buf)?;
 
     let mut src = &buf[..];
-    decode_record(&mut src, record)?;
+    decode_record(&mut src, fields, record)?;
 
     Ok(block_size)
 }
@@ -56,7 +59,7 @@ where
     }
 }
 
-pub(crate) fn decode_record<B>(src: &mut B, record: &mut Record) -> io::Result<()>
+pub(crate) fn decode_record<B>(src: &mut B, fields: Fields, record: &mut Record) -> io::Result<()>
 where
     B: Buf,
 {
@@ -84,10 +87,40 @@ where
 
     get_read_name(src, record.read_name_mut(), l_read_name)?;
     get_cigar(src, record.cigar_mut(), n_cigar_op)?;
-    get_sequence(src, record.sequence_mut(), l_seq)?;
-    get_quality_scores(src, record.quality_scores_mut(), l_seq)?;
 
-    get_data(src, record.data_mut())?;
+    if fields.sequence() {
+        get_sequence(src, record.sequence_mut(), l_seq)?;
+    } else {
+        skip(src, (l_seq + 1) / 2)?;
+        record.sequence_mut().clear();
+    }
+
+    if fields.quality_scores() {
+        get_quality_scores(src, record.quality_scores_mut(), l_seq)?;
+    } else {
+        skip(src, l_seq)?;
+        record.quality_scores_mut().clear();
+    }
+
+    if fields.data() {
+        get_data(src, record.data_mut())?;
+    } else {
+        src.advance(src.remaining());
+        record.data_mut().clear();
+    }
+
+    Ok(())
+}
+
+fn skip<B>(src: &mut B, len: usize) -> io::Result<()>
+where
+    B: Buf,
+{
+    if src.remaining() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    src.advance(len);
 
     Ok(())
 }
@@ -188,7 +221,7 @@ mod tests {
         let mut reader = &data[..];
         let mut buf = Vec::new();
         let mut record = Record::default();
-        let block_size = read_record(&mut reader, &mut buf, &mut record)?;
+        let block_size = read_record(&mut reader, &mut buf, Fields::default(), &mut record)?;
 
         assert_eq!(block_size, 34);
         assert_eq!(record, Record::default());
@@ -196,6 +229,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decode_record_with_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::writer::record::encode_record;
+
+        let header = sam::Header::default();
+        let expected = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(sam::record::Flags::empty())
+            .set_sequence("ACG".parse()?)
+            .set_quality_scores("NDL".parse()?)
+            .set_data("NH:i:1".parse()?)
+            .build();
+
+        let mut buf = Vec::new();
+        encode_record(&mut buf, &header, &expected)?;
+
+        let mut record = Record::default();
+        let fields = Fields::default().set_quality_scores(false).set_data(false);
+        decode_record(&mut &buf[..], fields, &mut record)?;
+
+        assert_eq!(record.read_name(), expected.read_name());
+        assert_eq!(record.flags(), expected.flags());
+        assert_eq!(record.sequence(), expected.sequence());
+        assert!(record.quality_scores().is_empty());
+        assert!(record.data().is_empty());
+
+        let fields = Fields::default().set_sequence(false);
+        decode_record(&mut &buf[..], fields, &mut record)?;
+
+        assert!(record.sequence().is_empty());
+        assert_eq!(record.quality_scores(), expected.quality_scores());
+        assert_eq!(record.data(), expected.data());
+
+        Ok(())
+    }
+
     #[test]
     fn test_decode_record_with_invalid_l_read_name() {
         let data = vec![
@@ -208,7 +277,7 @@ mod tests {
         let mut record = Record::default();
 
         assert!(matches!(
-            decode_record(&mut src, &mut record),
+            decode_record(&mut src, Fields::default(), &mut record),
             Err(e) if e.kind() == io::ErrorKind::InvalidData
         ));
     }
@@ -247,7 +247,7 @@ mod tests {
         }
 
         t(
-            &[b'n'],
+            b"n",
             Type::Character,
             Value::Character(Character::try_from('n')?),
         )?;
@@ -0,0 +1,146 @@
+use std::io::{self, Read, Write};
+
+use noodles_sam as sam;
+
+use super::{Reader, Writer};
+
+/// Replaces the header of a BAM stream.
+///
+/// This is equivalent to `samtools reheader`. The header (including the binary reference sequence
+/// dictionary) is read from `reader` and discarded, and the given header is written to `writer` in
+/// its place. Only the BGZF block in which the original header ends is recompressed; all
+/// subsequent blocks are copied as is, without decoding records.
+///
+/// Records reference sequences by index, so the reference sequences of the new header are
+/// expected to be compatible with the original header, i.e., in the same order.
+///
+/// Because the compressed positions of records change, any existing index of the original stream
+/// (`.bai` or `.csi`) is invalidated and must be rebuilt for the output.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let mut header: sam::Header = reader.read_header()?.parse()?;
+/// header.add_comment("noodles-bam");
+///
+/// let src = File::open("sample.bam")?;
+/// let dst = File::create("out.bam")?;
+/// bam::reheader(src, dst, &header)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reheader<R, W>(reader: R, writer: W, header: &sam::Header) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = Reader::new(reader);
+    reader.read_header()?;
+    reader.read_reference_sequences()?;
+    let mut reader = reader.into_inner();
+
+    let mut writer = Writer::new(writer);
+    writer.write_header(header)?;
+    writer.write_reference_sequences(header.reference_sequences())?;
+    let mut writer = writer.into_inner();
+
+    // The remainder of the block that contains the end of the header holds the first records.
+    if reader.virtual_position().uncompressed() > 0 {
+        let mut buf = Vec::new();
+        reader.read_block(&mut buf)?;
+        writer.write_all(&buf)?;
+    }
+
+    writer.flush()?;
+
+    // Taking the inner writer skips writing the BGZF EOF block. The original one is copied with
+    // the rest of the stream.
+    let mut writer = writer.into_inner();
+    io::copy(reader.get_mut(), &mut writer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::Record,
+        header::{header::SortOrder, ReferenceSequence},
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build())
+    }
+
+    fn write_bam(
+        header: &sam::Header,
+        flush_header: bool,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        if flush_header {
+            writer.get_mut().flush()?;
+        }
+
+        for name in ["r0", "r1", "r2"] {
+            let record = Record::builder()
+                .set_read_name(name.parse()?)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .build();
+
+            writer.write_record(header, &record)?;
+        }
+
+        writer.into_inner().finish().map_err(|e| e.into())
+    }
+
+    fn read_bam(src: &[u8]) -> io::Result<(String, Vec<Record>)> {
+        let mut reader = Reader::new(src);
+        let header_text = reader.read_header()?;
+        reader.read_reference_sequences()?;
+        let records = reader.records().collect::<io::Result<_>>()?;
+        Ok((header_text, records))
+    }
+
+    #[test]
+    fn test_reheader() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut new_header = build_header()?;
+        *new_header.header_mut() = Some(
+            sam::header::header::Header::builder()
+                .set_sort_order(SortOrder::Coordinate)
+                .build(),
+        );
+        new_header.add_comment("noodles-bam");
+
+        for flush_header in [false, true] {
+            let src = write_bam(&header, flush_header)?;
+
+            let mut dst = Vec::new();
+            reheader(&src[..], &mut dst, &new_header)?;
+
+            let (_, expected_records) = read_bam(&src)?;
+            let (actual_header_text, actual_records) = read_bam(&dst)?;
+
+            assert_eq!(actual_header_text, new_header.to_string());
+            assert_eq!(actual_records, expected_records);
+            assert!(bgzf::has_eof(&mut io::Cursor::new(&dst))?);
+        }
+
+        Ok(())
+    }
+}
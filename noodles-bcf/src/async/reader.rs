@@ -8,10 +8,14 @@ use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
+use noodles_vcf as vcf;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
 use self::{query::query, record::read_record};
-use crate::{header::string_maps::ContigStringMap, Record};
+use crate::{
+    header::{string_maps::ContigStringMap, StringMaps},
+    Record,
+};
 
 /// An async BCF reader.
 ///
@@ -41,6 +45,7 @@ use crate::{header::string_maps::ContigStringMap, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    string_maps: Result<StringMaps, vcf::header::ParseError>,
 }
 
 impl<R> Reader<R>
@@ -123,6 +128,9 @@ where
     /// This returns the raw VCF header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_vcf::Header`].
     ///
+    /// The string maps of the header are also built and can be accessed using
+    /// [`Self::string_maps`]. A header that fails to build string maps is not an error here.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -141,7 +149,42 @@ where
     /// # }
     /// ```
     pub async fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner).await
+        let raw_header = read_header(&mut self.inner).await?;
+
+        self.string_maps = raw_header.parse();
+
+        Ok(raw_header)
+    }
+
+    /// Returns the string maps of the header.
+    ///
+    /// The string maps are built when the header is read (see [`Self::read_header`]). Until the
+    /// header is read, this only includes the implicit "PASS" filter.
+    ///
+    /// This returns an `InvalidData` error if the string maps could not be built from the header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    /// reader.read_file_format().await?;
+    /// reader.read_header().await?;
+    ///
+    /// let string_maps = reader.string_maps()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn string_maps(&self) -> io::Result<&StringMaps> {
+        self.string_maps
+            .as_ref()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.clone()))
     }
 
     /// Reads a single record.
@@ -354,6 +397,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            string_maps: Ok(StringMaps::default()),
         }
     }
 }
@@ -1,12 +1,18 @@
 //! BCF reader and iterators.
 
+mod batch;
+mod count;
 pub(crate) mod query;
 pub(crate) mod record;
 mod records;
 pub(crate) mod string_map;
 pub(crate) mod value;
 
-pub use self::{query::Query, records::Records};
+pub use self::{
+    batch::{Batch, Series, Values},
+    query::Query,
+    records::Records,
+};
 
 use std::{
     ffi::CStr,
@@ -17,9 +23,10 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
+use noodles_vcf as vcf;
 
 use super::Record;
-use crate::header::string_maps::ContigStringMap;
+use crate::header::{string_maps::ContigStringMap, StringMaps};
 
 /// A BCF reader.
 ///
@@ -27,6 +34,7 @@ use crate::header::string_maps::ContigStringMap;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    string_maps: Result<StringMaps, vcf::header::ParseError>,
 }
 
 impl<R> Reader<R>
@@ -104,6 +112,9 @@ where
     /// This returns the raw VCF header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_vcf::Header`].
     ///
+    /// The string maps of the header are also built and can be accessed using
+    /// [`Self::string_maps`]. A header that fails to build string maps is not an error here.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -115,7 +126,39 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner)
+        let raw_header = read_header(&mut self.inner)?;
+
+        self.string_maps = raw_header.parse();
+
+        Ok(raw_header)
+    }
+
+    /// Returns the string maps of the header.
+    ///
+    /// The string maps are built when the header is read (see [`Self::read_header`]). They map
+    /// the string (FILTER, FORMAT, and INFO) and contig IDs used in records to their names. Until
+    /// the header is read, this only includes the implicit "PASS" filter.
+    ///
+    /// This returns an `InvalidData` error if the string maps could not be built from the header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// reader.read_file_format()?;
+    /// reader.read_header()?;
+    ///
+    /// let string_maps = reader.string_maps()?;
+    /// let dp_id = string_maps.strings().get_index_of("DP");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn string_maps(&self) -> io::Result<&StringMaps> {
+        self.string_maps
+            .as_ref()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.clone()))
     }
 
     /// Reads a single record.
@@ -169,6 +212,38 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Reads up to `len` records into a columnar batch.
+    ///
+    /// The batch is cleared before reading. Only the chromosome ID, position, alleles, and
+    /// requested genotype fields of each record are decoded; the other fields are skipped.
+    ///
+    /// If successful, the number of records read is returned. If 0 is returned, the stream
+    /// reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf::{self as bcf, header::StringMaps, reader::Batch};
+    /// use noodles_vcf::{self as vcf, header::format::Key};
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// reader.read_file_format()?;
+    ///
+    /// let header: vcf::Header = reader.read_header()?.parse()?;
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let mut batch = Batch::new(&header, &string_maps, &[Key::Genotype])?;
+    ///
+    /// while reader.read_batch(&mut batch, 1024)? > 0 {
+    ///     println!("{:?}", batch.positions());
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_batch(&mut self, batch: &mut Batch, len: usize) -> io::Result<usize> {
+        batch::read_batch(&mut self.inner, &mut self.buf, batch, len)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -278,6 +353,45 @@ where
             region.interval(),
         ))
     }
+
+    /// Counts the records that intersect the given region.
+    ///
+    /// This is faster than counting the records of a [`Self::query`]. When the region covers an
+    /// entire contig, e.g., `sq0`, the count is read from the index's reference sequence metadata,
+    /// if available, and no records are read. Otherwise, only the chromosome, position, and
+    /// reference length of records in the overlapping chunks are decoded; the info and genotype
+    /// fields are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// reader.read_file_format()?;
+    ///
+    /// let string_maps: StringMaps = reader.read_header()?.parse()?;
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let n = reader.count(string_maps.contigs(), &index, &region)?;
+    /// println!("{}", n);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count<I>(
+        &mut self,
+        contig_string_map: &ContigStringMap,
+        index: &I,
+        region: &Region,
+    ) -> io::Result<u64>
+    where
+        I: BinningIndex,
+    {
+        let chromosome_id = resolve_region(contig_string_map, region)?;
+        count::count(self, index, chromosome_id, region.interval())
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -285,6 +399,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            string_maps: Ok(StringMaps::default()),
         }
     }
 }
@@ -403,4 +518,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_string_maps() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_vcf::{
+            self as vcf,
+            header::{info::Key, Contig, Info},
+        };
+
+        use crate::Writer;
+
+        let header = vcf::Header::builder()
+            .add_info(Info::from(Key::TotalDepth))
+            .add_contig(Contig::new("sq0".parse()?))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(&writer.get_ref().get_ref()[..]);
+        assert_eq!(reader.string_maps()?, &StringMaps::default());
+
+        reader.read_file_format()?;
+        reader.read_header()?;
+
+        assert_eq!(reader.string_maps()?, &StringMaps::from(&header));
+        assert_eq!(reader.string_maps()?, writer.string_maps());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_maps_with_a_position_mismatch() -> io::Result<()> {
+        let raw_header = b"##fileformat=VCFv4.3
+##FILTER=<ID=PASS,Description=\"All filters passed\",IDX=8>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+\0";
+
+        let mut data = (raw_header.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(raw_header);
+
+        let mut reader = Reader::from(&data[..]);
+        reader.read_header()?;
+
+        assert!(matches!(
+            reader.string_maps(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,592 @@
+//! BCF record batch.
+
+use std::{
+    io::{self, Read},
+    mem,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use noodles_vcf::{
+    self as vcf,
+    header::format::{Key, Type as FormatType},
+};
+
+use super::{
+    record::{read_chrom, read_pos},
+    string_map::read_string_map_index,
+    value::{read_type, skip_value},
+};
+use crate::{
+    header::StringMaps,
+    record::value::{Int16, Int8, Type},
+};
+
+/// The missing value of a 32-bit integer in a series.
+pub const MISSING_INTEGER: i32 = i32::MIN;
+
+/// The end-of-vector value of a 32-bit integer in a series.
+pub const END_OF_VECTOR_INTEGER: i32 = i32::MIN + 1;
+
+/// The values of a genotype field series.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    /// 32-bit integers.
+    ///
+    /// 8- and 16-bit integers are widened. Missing and end-of-vector values are normalized to
+    /// [`MISSING_INTEGER`] and [`END_OF_VECTOR_INTEGER`], respectively.
+    Integer(Vec<i32>),
+    /// Single-precision floating-points.
+    ///
+    /// The bit patterns of missing and end-of-vector values are preserved.
+    Float(Vec<f32>),
+    /// Raw characters and strings.
+    String(Vec<u8>),
+}
+
+impl Values {
+    fn len(&self) -> usize {
+        match self {
+            Self::Integer(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            Self::Integer(values) => values.clear(),
+            Self::Float(values) => values.clear(),
+            Self::String(values) => values.clear(),
+        }
+    }
+}
+
+/// A columnar series of a genotype field.
+///
+/// The values of record `i` are `values[offsets[i]..offsets[i + 1]]`, in sample-major order, i.e.,
+/// each sample has `(offsets[i + 1] - offsets[i]) / sample_count` values. Records without the
+/// field have no values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    key: Key,
+    id: usize,
+    values: Values,
+    offsets: Vec<usize>,
+}
+
+impl Series {
+    /// Returns the genotype field key.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns the values.
+    pub fn values(&self) -> &Values {
+        &self.values
+    }
+
+    /// Returns the value offsets of each record.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+}
+
+/// A batch of BCF records decoded into columnar buffers.
+///
+/// A batch holds the chromosome IDs, positions, and alleles of each record and a series for each
+/// requested genotype field. The buffers are reused by each call to
+/// [`super::Reader::read_batch`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Batch {
+    chromosome_ids: Vec<usize>,
+    positions: Vec<usize>,
+    allele_offsets: Vec<usize>,
+    allele_value_offsets: Vec<usize>,
+    allele_values: Vec<u8>,
+    series: Vec<Series>,
+}
+
+impl Batch {
+    /// Creates a batch that decodes the given genotype fields.
+    ///
+    /// The series value types are taken from the header formats, except for genotypes (`GT`),
+    /// which are always integers, i.e., BCF-encoded alleles. This returns an error if a key is not
+    /// in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{header::StringMaps, reader::Batch};
+    /// use noodles_vcf::{self as vcf, header::{format::Key, Format}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .build();
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let batch = Batch::new(&header, &string_maps, &[Key::Genotype])?;
+    /// assert!(batch.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(header: &vcf::Header, string_maps: &StringMaps, keys: &[Key]) -> io::Result<Self> {
+        let series = keys
+            .iter()
+            .map(|key| {
+                let format = header.formats().get(key).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing header FORMAT record for {}", key),
+                    )
+                })?;
+
+                let id = string_maps
+                    .strings()
+                    .get_index_of(key.as_ref())
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("missing string map entry for {}", key),
+                        )
+                    })?;
+
+                // GT is encoded as integers in BCF regardless of its header type.
+                let values = match format.ty() {
+                    _ if *key == Key::Genotype => Values::Integer(Vec::new()),
+                    FormatType::Integer => Values::Integer(Vec::new()),
+                    FormatType::Float => Values::Float(Vec::new()),
+                    FormatType::Character | FormatType::String => Values::String(Vec::new()),
+                };
+
+                Ok(Series {
+                    key: key.clone(),
+                    id,
+                    values,
+                    offsets: vec![0],
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            chromosome_ids: Vec::new(),
+            positions: Vec::new(),
+            allele_offsets: vec![0],
+            allele_value_offsets: vec![0],
+            allele_values: Vec::new(),
+            series,
+        })
+    }
+
+    /// Returns the number of records in the batch.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns whether the batch has any records.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns the chromosome ID of each record.
+    pub fn chromosome_ids(&self) -> &[usize] {
+        &self.chromosome_ids
+    }
+
+    /// Returns the 1-based position of each record.
+    pub fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    /// Returns the allele offsets of each record.
+    ///
+    /// The alleles of record `i` are the alleles `allele_offsets[i]..allele_offsets[i + 1]`. The
+    /// first allele of each record is the reference bases.
+    pub fn allele_offsets(&self) -> &[usize] {
+        &self.allele_offsets
+    }
+
+    /// Returns the value offsets of each allele.
+    ///
+    /// The bases of allele `j` are `allele_values[allele_value_offsets[j]..allele_value_offsets[j
+    /// + 1]]`.
+    pub fn allele_value_offsets(&self) -> &[usize] {
+        &self.allele_value_offsets
+    }
+
+    /// Returns the concatenated bases of all alleles.
+    pub fn allele_values(&self) -> &[u8] {
+        &self.allele_values
+    }
+
+    /// Returns the alleles of the given record.
+    pub fn alleles(&self, i: usize) -> Option<Vec<&[u8]>> {
+        let start = *self.allele_offsets.get(i)?;
+        let end = *self.allele_offsets.get(i + 1)?;
+
+        let alleles = (start..end)
+            .map(|j| {
+                let value_start = self.allele_value_offsets[j];
+                let value_end = self.allele_value_offsets[j + 1];
+                &self.allele_values[value_start..value_end]
+            })
+            .collect();
+
+        Some(alleles)
+    }
+
+    /// Returns the genotype field series, in the order they were requested.
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    fn clear(&mut self) {
+        self.chromosome_ids.clear();
+        self.positions.clear();
+
+        self.allele_offsets.clear();
+        self.allele_offsets.push(0);
+        self.allele_value_offsets.clear();
+        self.allele_value_offsets.push(0);
+        self.allele_values.clear();
+
+        for series in &mut self.series {
+            series.values.clear();
+            series.offsets.clear();
+            series.offsets.push(0);
+        }
+    }
+}
+
+pub(super) fn read_batch<R>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    batch: &mut Batch,
+    len: usize,
+) -> io::Result<usize>
+where
+    R: Read,
+{
+    batch.clear();
+
+    for _ in 0..len {
+        if !read_batch_record(reader, buf, batch)? {
+            break;
+        }
+    }
+
+    Ok(batch.len())
+}
+
+fn read_batch_record<R>(reader: &mut R, buf: &mut Vec<u8>, batch: &mut Batch) -> io::Result<bool>
+where
+    R: Read,
+{
+    let l_shared = match reader.read_u32::<LittleEndian>() {
+        Ok(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    let l_indiv = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    buf.resize(l_shared + l_indiv, 0);
+    reader.read_exact(buf)?;
+
+    let (mut shared_src, mut indiv_src) = buf.split_at(l_shared);
+
+    let counts = read_site(&mut shared_src, batch)?;
+    read_genotypes(&mut indiv_src, &mut batch.series, counts)?;
+
+    Ok(true)
+}
+
+// Reads the chromosome ID, position, and alleles of a record. The other shared fields are skipped.
+//
+// This returns the format and sample counts.
+fn read_site(src: &mut &[u8], batch: &mut Batch) -> io::Result<(usize, usize)> {
+    let chromosome_id = read_chrom(src)?;
+    let position = read_pos(src).map(usize::from)?;
+
+    // rlen, qual, n_info
+    skip(
+        src,
+        mem::size_of::<i32>() + mem::size_of::<f32>() + mem::size_of::<u16>(),
+    )?;
+
+    let n_allele = src.read_u16::<LittleEndian>().map(usize::from)?;
+
+    let n_fmt_sample = src.read_u32::<LittleEndian>()?;
+    let n_fmt = usize::from((n_fmt_sample >> 24) as u8);
+    let n_sample = (n_fmt_sample & 0xffffff) as usize;
+
+    // id
+    skip_value(src)?;
+
+    for _ in 0..n_allele {
+        match read_type(src)? {
+            Some(Type::String(len)) => {
+                let value = take(src, len)?;
+                batch.allele_values.extend_from_slice(value);
+                batch.allele_value_offsets.push(batch.allele_values.len());
+            }
+            ty => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid allele type: expected String, got {:?}", ty),
+                ))
+            }
+        }
+    }
+
+    batch.chromosome_ids.push(chromosome_id);
+    batch.positions.push(position);
+
+    let allele_count = batch.allele_value_offsets.len() - 1;
+    batch.allele_offsets.push(allele_count);
+
+    Ok((n_fmt, n_sample))
+}
+
+fn read_genotypes(
+    src: &mut &[u8],
+    series: &mut [Series],
+    (format_count, sample_count): (usize, usize),
+) -> io::Result<()> {
+    for _ in 0..format_count {
+        let id = read_string_map_index(src)?;
+
+        let ty = read_type(src)?.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid genotype field type")
+        })?;
+
+        let (value_size, len) = match ty {
+            Type::Int8(len) => (mem::size_of::<i8>(), len),
+            Type::Int16(len) => (mem::size_of::<i16>(), len),
+            Type::Int32(len) => (mem::size_of::<i32>(), len),
+            Type::Float(len) => (mem::size_of::<f32>(), len),
+            Type::String(len) => (mem::size_of::<u8>(), len),
+        };
+
+        let data = take(src, value_size * len * sample_count)?;
+
+        if let Some(s) = series.iter_mut().find(|s| s.id == id) {
+            extend_values(&mut s.values, ty, data).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("invalid genotype field value for {}: {}", s.key, e),
+                )
+            })?;
+        }
+    }
+
+    for s in series {
+        s.offsets.push(s.values.len());
+    }
+
+    Ok(())
+}
+
+fn extend_values(values: &mut Values, ty: Type, data: &[u8]) -> io::Result<()> {
+    match (values, ty) {
+        (Values::Integer(values), Type::Int8(_)) => {
+            values.extend(data.iter().map(|&b| match Int8::from(b as i8) {
+                Int8::Missing => MISSING_INTEGER,
+                Int8::EndOfVector => END_OF_VECTOR_INTEGER,
+                Int8::Value(n) | Int8::Reserved(n) => i32::from(n),
+            }));
+        }
+        (Values::Integer(values), Type::Int16(_)) => {
+            values.extend(data.chunks_exact(2).map(|chunk| {
+                let n = i16::from_le_bytes([chunk[0], chunk[1]]);
+
+                match Int16::from(n) {
+                    Int16::Missing => MISSING_INTEGER,
+                    Int16::EndOfVector => END_OF_VECTOR_INTEGER,
+                    Int16::Value(n) | Int16::Reserved(n) => i32::from(n),
+                }
+            }));
+        }
+        (Values::Integer(values), Type::Int32(_)) => {
+            // The 32-bit missing and end-of-vector values are the normalized values.
+            values.extend(
+                data.chunks_exact(4)
+                    .map(|chunk| i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])),
+            );
+        }
+        (Values::Float(values), Type::Float(_)) => {
+            values.extend(
+                data.chunks_exact(4)
+                    .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])),
+            );
+        }
+        (Values::String(values), Type::String(_)) => values.extend_from_slice(data),
+        (_, ty) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("type mismatch: got {:?}", ty),
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn skip(src: &mut &[u8], len: usize) -> io::Result<()> {
+    take(src, len).map(|_| ())
+}
+
+fn take<'a>(src: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let (buf, rest) = src.split_at(len);
+    *src = rest;
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::{
+        header::{Contig, Format},
+        record::{Genotypes, Position},
+    };
+
+    use super::*;
+    use crate::{Reader, Writer};
+
+    #[test]
+    fn test_read_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig(Contig::new("sq0".parse()?))
+            .add_contig(Contig::new("sq1".parse()?))
+            .add_format(Format::from(Key::Genotype))
+            .add_format(Format::from(Key::ReadDepth))
+            .add_format(Format::from(Key::Filter))
+            .add_format(Format::from(Key::ConditionalGenotypeQuality))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let records = [
+            ("sq0", 1, "A", "C", "GT:DP:FT\t0/1:300:PASS\t1|1:5:q10"),
+            ("sq0", 8, "AC", "G,T", "GT:DP\t0/2:8\t./.:."),
+            ("sq1", 5, "G", ".", ""),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for (chromosome, position, reference_bases, alternate_bases, genotypes) in records {
+            let mut builder = vcf::Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::from(position))
+                .set_reference_bases(reference_bases.parse()?)
+                .set_alternate_bases(alternate_bases.parse()?);
+
+            if !genotypes.is_empty() {
+                builder = builder.set_genotypes(Genotypes::parse(genotypes, &header)?);
+            }
+
+            let record = builder.build()?;
+            writer.write_vcf_record(&header, &string_maps, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_format()?;
+        reader.read_header()?;
+
+        let mut batch = Batch::new(
+            &header,
+            &string_maps,
+            &[Key::ReadDepth, Key::Filter, Key::ConditionalGenotypeQuality],
+        )?;
+
+        assert_eq!(reader.read_batch(&mut batch, 2)?, 2);
+
+        assert_eq!(batch.chromosome_ids(), [0, 0]);
+        assert_eq!(batch.positions(), [1, 8]);
+        assert_eq!(batch.allele_offsets(), [0, 2, 5]);
+        assert_eq!(
+            batch.alleles(1),
+            Some(vec![&b"AC"[..], &b"G"[..], &b"T"[..]])
+        );
+        assert_eq!(batch.allele_values(), b"ACACGT");
+
+        let series = batch.series();
+        assert_eq!(series.len(), 3);
+
+        assert_eq!(series[0].key(), &Key::ReadDepth);
+        assert_eq!(series[0].offsets(), [0, 2, 4]);
+        assert_eq!(
+            series[0].values(),
+            &Values::Integer(vec![300, 5, 8, MISSING_INTEGER])
+        );
+
+        assert_eq!(series[1].offsets(), [0, 8, 8]);
+        assert_eq!(series[1].values(), &Values::String(b"PASSq10\0".to_vec()));
+
+        assert_eq!(series[2].offsets(), [0, 0, 0]);
+
+        assert_eq!(reader.read_batch(&mut batch, 2)?, 1);
+        assert_eq!(batch.chromosome_ids(), [1]);
+        assert_eq!(batch.positions(), [5]);
+        assert_eq!(batch.alleles(0), Some(vec![&b"G"[..]]));
+        assert_eq!(batch.series()[0].offsets(), [0, 0]);
+
+        assert_eq!(reader.read_batch(&mut batch, 2)?, 0);
+        assert!(batch.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_genotypes_with_genotype_key() -> io::Result<()> {
+        let header = vcf::Header::builder()
+            .add_format(Format::from(Key::Genotype))
+            .build();
+        let string_maps = StringMaps::from(&header);
+        let mut batch = Batch::new(&header, &string_maps, &[Key::Genotype])?;
+
+        let id = string_maps
+            .strings()
+            .get_index_of(Key::Genotype.as_ref())
+            .expect("missing GT string map entry");
+
+        let data = [
+            0x11, id as u8, // key = GT
+            0x21,     // type = Int8(2)
+            0x02, 0x04, // sample 0 = 0/1
+            0x05, 0x81, // sample 1 = 1 (haploid)
+        ];
+
+        read_genotypes(&mut &data[..], &mut batch.series, (1, 2))?;
+
+        let series = &batch.series()[0];
+        assert_eq!(series.offsets(), [0, 4]);
+        assert_eq!(
+            series.values(),
+            &Values::Integer(vec![2, 4, 5, END_OF_VECTOR_INTEGER])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_missing_format() {
+        let header = vcf::Header::default();
+        let string_maps = StringMaps::from(&header);
+
+        assert!(matches!(
+            Batch::new(&header, &string_maps, &[Key::Genotype]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::{self, Read, Seek};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use noodles_bgzf as bgzf;
+use noodles_core::{region::Interval, Position};
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+
+use super::{
+    record::{read_chrom, read_pos, read_rlen},
+    Reader,
+};
+
+// Counts the records that intersect the given interval.
+//
+// If the interval covers the entire contig and the index has reference sequence metadata, the
+// count is taken from the metadata. Otherwise, the records in the chunks that overlap the interval
+// are read, and only the chromosome, position, and reference length of each record are decoded.
+pub(super) fn count<R, I>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &I,
+    chromosome_id: usize,
+    interval: Interval,
+) -> io::Result<u64>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    if is_unbounded(interval) {
+        if let Some(metadata) = index
+            .reference_sequences()
+            .get(chromosome_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+        {
+            return Ok(metadata.mapped_record_count() + metadata.unmapped_record_count());
+        }
+    }
+
+    let chunks = index.query(chromosome_id, interval)?;
+
+    let mut n = 0;
+
+    for chunk in chunks {
+        reader.seek(chunk.start())?;
+
+        while let Some((id, record_interval)) =
+            read_site_interval(&mut reader.inner, &mut reader.buf)?
+        {
+            if id == chromosome_id && record_interval.intersects(interval) {
+                n += 1;
+            }
+
+            if reader.virtual_position() >= chunk.end() {
+                break;
+            }
+        }
+    }
+
+    Ok(n)
+}
+
+fn is_unbounded(interval: Interval) -> bool {
+    matches!(interval.start(), None | Some(Position::MIN)) && interval.end().is_none()
+}
+
+// Reads a record, decoding only its chromosome ID and interval.
+//
+// The remaining shared fields and all of the genotype fields are skipped.
+fn read_site_interval<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<(usize, Interval)>>
+where
+    R: Read,
+{
+    let l_shared = match reader.read_u32::<LittleEndian>() {
+        Ok(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let l_indiv = reader.read_u32::<LittleEndian>().map(u64::from)?;
+
+    buf.resize(l_shared, 0);
+    reader.read_exact(buf)?;
+
+    let mut src = &buf[..];
+    let chromosome_id = read_chrom(&mut src)?;
+    let start = read_pos(&mut src).map(usize::from)?;
+    let len = read_rlen(&mut src)?;
+
+    let end = start
+        .checked_add(len.max(1) - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid record end"))?;
+
+    let start =
+        Position::try_from(start).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let end = Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let n = io::copy(&mut reader.take(l_indiv), &mut io::sink())?;
+
+    if n < l_indiv {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(Some((chromosome_id, Interval::from(start..=end))))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi as csi;
+    use noodles_vcf::{self as vcf, header::Contig};
+
+    use super::*;
+    use crate::{header::StringMaps, Writer};
+
+    #[test]
+    fn test_count() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig(Contig::new("sq0".parse()?))
+            .add_contig(Contig::new("sq1".parse()?))
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let records = [
+            ("sq0", 1, "A"),
+            ("sq0", 8, "ACGTA"),
+            ("sq0", 21, "C"),
+            ("sq1", 5, "G"),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        let mut indexer = csi::Index::indexer();
+
+        for (chromosome, position, reference_bases) in records {
+            let record = vcf::Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(vcf::record::Position::from(position))
+                .set_reference_bases(reference_bases.parse()?)
+                .build()?;
+
+            let start = writer.get_ref().virtual_position();
+            writer.write_vcf_record(&header, &string_maps, &record)?;
+            let end = writer.get_ref().virtual_position();
+
+            let chromosome_id = string_maps
+                .contigs()
+                .get_index_of(chromosome)
+                .expect("missing contig");
+
+            indexer.add_record(
+                chromosome_id,
+                Position::try_from(position)?,
+                Position::try_from(position + reference_bases.len() - 1)?,
+                csi::index::reference_sequence::bin::Chunk::new(start, end),
+            )?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = indexer.build();
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.read_file_format()?;
+        reader.read_header()?;
+
+        let contigs = string_maps.contigs();
+
+        let mut t = |raw_region: &str, expected: u64| -> Result<(), Box<dyn std::error::Error>> {
+            let region = raw_region.parse()?;
+            let actual = reader.count(contigs, &index, &region)?;
+            assert_eq!(actual, expected, "{}", raw_region);
+            Ok(())
+        };
+
+        t("sq0", 3)?;
+        t("sq0:1-4", 1)?;
+        t("sq0:10-21", 2)?;
+        t("sq0:13-20", 0)?;
+        t("sq1", 1)?;
+        t("sq1:6-", 0)?;
+
+        Ok(())
+    }
+}
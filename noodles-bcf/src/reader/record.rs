@@ -1,7 +1,10 @@
 mod genotypes;
 pub mod info;
 
-pub use self::{genotypes::read_genotypes, info::read_info};
+pub use self::{
+    genotypes::{read_genotype_field_id, read_genotypes},
+    info::read_info,
+};
 
 use std::io::{self, Read};
 
@@ -117,12 +120,10 @@ where
             .map(Some)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e)),
         Float::Missing => Ok(None),
-        qual => {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("invalid qual: {:?}", qual),
-            ));
-        }
+        qual => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid qual: {:?}", qual),
+        )),
     }
 }
 
@@ -294,7 +295,7 @@ pub(crate) mod tests {
         read_record(&mut reader, &mut buf, &mut record)?;
 
         assert_eq!(record.chromosome_id(), 1);
-        assert_eq!(record.position(), Position::try_from(101)?);
+        assert_eq!(record.position(), Position::from(101));
         assert_eq!(record.rlen(), 1);
         assert_eq!(
             record.quality_score(),
@@ -1,6 +1,6 @@
 use std::{
     io::{self, Read},
-    str,
+    mem, str,
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
@@ -63,6 +63,27 @@ where
     Ok(Genotypes::new(keys, genotypes))
 }
 
+pub fn read_genotype_field_id(src: &mut &[u8], sample_count: usize) -> io::Result<usize> {
+    let id = read_string_map_index(src)?;
+
+    let len = match read_type(src)? {
+        Some(Type::Int8(len)) | Some(Type::String(len)) => len,
+        Some(Type::Int16(len)) => len * mem::size_of::<i16>(),
+        Some(Type::Int32(len)) | Some(Type::Float(len)) => len * mem::size_of::<i32>(),
+        None => 0,
+    };
+
+    let len = len * sample_count;
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    *src = &src[len..];
+
+    Ok(id)
+}
+
 fn read_genotype_field_key<R>(
     reader: &mut R,
     formats: &vcf::header::Formats,
@@ -4,7 +4,10 @@ use noodles_vcf::{self as vcf, header::info::Type};
 
 use crate::{
     header::string_maps::StringStringMap,
-    reader::{string_map::read_string_map_index, value::read_value},
+    reader::{
+        string_map::read_string_map_index,
+        value::{read_value, skip_value},
+    },
     record::{
         value::{Float, Int16, Int32, Int8},
         Value,
@@ -52,6 +55,12 @@ where
     Ok(vcf::record::info::Field::new(key, value))
 }
 
+pub fn read_info_field_id(src: &mut &[u8]) -> io::Result<usize> {
+    let id = read_string_map_index(src)?;
+    skip_value(src)?;
+    Ok(id)
+}
+
 fn read_info_field_key<R>(
     reader: &mut R,
     infos: &vcf::header::Infos,
@@ -2,7 +2,10 @@ mod ty;
 
 pub use self::ty::read_type;
 
-use std::io::{self, Read};
+use std::{
+    io::{self, Read},
+    mem,
+};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
@@ -67,6 +70,23 @@ where
     }
 }
 
+pub fn skip_value(src: &mut &[u8]) -> io::Result<()> {
+    let len = match read_type(src)? {
+        Some(Type::Int8(len)) | Some(Type::String(len)) => len,
+        Some(Type::Int16(len)) => len * mem::size_of::<i16>(),
+        Some(Type::Int32(len)) | Some(Type::Float(len)) => len * mem::size_of::<i32>(),
+        None => 0,
+    };
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    *src = &src[len..];
+
+    Ok(())
+}
+
 fn read_i8<R>(reader: &mut R) -> io::Result<i8>
 where
     R: Read,
@@ -257,4 +277,31 @@ mod tests {
             Ok(Some(Value::String(Some(value)))) if value == "ndls"
         ));
     }
+
+    #[test]
+    fn test_skip_value() -> io::Result<()> {
+        let data = [
+            0x01, // Int8(0)
+            0x12, 0x79, 0x01, // Int16(1)
+            0x37, 0x6e, 0x64, 0x6c, // String(3)
+            0x11, // Int8(1)
+        ];
+        let mut src = &data[..];
+
+        skip_value(&mut src)?;
+        assert_eq!(src.len(), 8);
+
+        skip_value(&mut src)?;
+        assert_eq!(src.len(), 5);
+
+        skip_value(&mut src)?;
+        assert_eq!(src, [0x11]);
+
+        assert!(matches!(
+            skip_value(&mut src),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
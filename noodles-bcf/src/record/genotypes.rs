@@ -112,6 +112,27 @@ impl Genotypes {
         self.format_count
     }
 
+    /// Returns an iterator over the keys of all genotype fields as string map indices.
+    ///
+    /// Field values are skipped and not decoded. The indices can be compared with those in the
+    /// string string map (see [`crate::header::StringMaps::strings`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::Genotypes;
+    /// let genotypes = Genotypes::default();
+    /// assert!(genotypes.keys().next().is_none());
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = io::Result<usize>> + '_ {
+        use crate::reader::record::read_genotype_field_id;
+
+        let mut src = &self.buf[..];
+        let sample_count = self.len();
+
+        (0..self.format_count()).map(move |_| read_genotype_field_id(&mut src, sample_count))
+    }
+
     pub(crate) fn set_format_count(&mut self, format_count: usize) {
         self.format_count = format_count;
     }
@@ -132,3 +153,41 @@ impl AsMut<Vec<u8>> for Genotypes {
         &mut self.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys() -> io::Result<()> {
+        let genotypes = Genotypes {
+            buf: vec![
+                0x11, 0x03, // key = 3
+                0x11, // type = Int8(1)
+                0x02, 0x04, // values = [0, 1]
+                0x11, 0x04, // key = 4
+                0x12, // type = Int16(1)
+                0x05, 0x00, 0x08, 0x00, // values = [5, 8]
+            ],
+            format_count: 2,
+            sample_count: 2,
+        };
+
+        let keys: Vec<_> = genotypes.keys().collect::<io::Result<_>>()?;
+        assert_eq!(keys, [3, 4]);
+
+        let genotypes = Genotypes {
+            buf: vec![0x11, 0x03, 0x11, 0x02],
+            format_count: 1,
+            sample_count: 2,
+        };
+
+        let mut keys = genotypes.keys();
+        assert!(matches!(
+            keys.next(),
+            Some(Err(ref e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
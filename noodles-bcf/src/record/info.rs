@@ -199,6 +199,36 @@ impl Info {
             .map(move |_| read_info_field(&mut reader, header.infos(), string_string_map))
     }
 
+    /// Returns an iterator over the keys of all info fields as string map indices.
+    ///
+    /// Unlike [`Self::values`], this does not decode field values or resolve keys using a
+    /// header. The indices can be compared with those in the string string map (see
+    /// [`crate::header::StringMaps::strings`]), e.g., to filter records by which info fields are
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::record::Info;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x11, 0x05, // AC=5
+    ///     0x11, 0x02, 0x11, 0x08, // DP=8
+    /// ];
+    ///
+    /// let info = Info::new(data, 2);
+    /// let keys: Vec<_> = info.keys().collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(keys, [1, 2]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = io::Result<usize>> + '_ {
+        use crate::reader::record::info::read_info_field_id;
+        let mut src = &self.buf[..];
+        (0..self.len()).map(move |_| read_info_field_id(&mut src))
+    }
+
     pub(crate) fn set_field_count(&mut self, field_count: usize) {
         self.field_count = field_count;
     }
@@ -20,6 +20,7 @@ const MINOR: u8 = 2;
 /// A BCF writer.
 pub struct Writer<W> {
     inner: W,
+    string_maps: StringMaps,
 }
 
 impl<W> Writer<W>
@@ -96,7 +97,37 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
-        write_header(&mut self.inner, header)
+        write_header(&mut self.inner, header)?;
+        self.string_maps = StringMaps::from(header);
+        Ok(())
+    }
+
+    /// Returns the string maps of the header.
+    ///
+    /// The string maps are built when the header is written (see [`Self::write_header`]). They
+    /// can be used to encode the string (FILTER, FORMAT, and INFO) and contig IDs of records.
+    /// Until the header is written, this only includes the implicit "PASS" filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{self as vcf, header::{info::Key, Info}};
+    ///
+    /// let mut writer = bcf::Writer::new(Vec::new());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(Key::TotalDepth))
+    ///     .build();
+    ///
+    /// writer.write_header(&header)?;
+    ///
+    /// assert_eq!(writer.string_maps().strings().get_index_of("DP"), Some(1));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn string_maps(&self) -> &StringMaps {
+        &self.string_maps
     }
 
     /// Writes a record.
@@ -191,7 +222,10 @@ where
 
 impl<W> From<W> for Writer<W> {
     fn from(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            string_maps: StringMaps::default(),
+        }
     }
 }
 
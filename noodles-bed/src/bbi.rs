@@ -0,0 +1,221 @@
+//! BBI (big binary indexed) file components.
+//!
+//! bigBed and bigWig share a common container layout: a header, zoom level headers, a chromosome
+//! B+ tree, data blocks, and an R-tree index over the data blocks.
+
+pub(crate) mod chromosome_tree;
+pub(crate) mod header;
+pub(crate) mod r_tree;
+
+pub use self::{chromosome_tree::ReferenceSequence, header::Header, header::ZoomLevel};
+
+pub(crate) use self::header::read_header;
+
+use std::{
+    cmp,
+    collections::HashSet,
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use noodles_core::{Position, Region};
+
+// Counts and sizes are read from the input, so they are not trusted to preallocate buffers.
+pub(crate) const MAX_PREALLOCATED_LEN: usize = 1 << 16;
+
+const MAX_TREE_DEPTH: usize = 64;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+// A data block as (offset, size).
+pub(crate) type Block = (u64, u64);
+
+// The offsets of the nodes visited while traversing a tree.
+//
+// Nodes are read recursively, so a node that references itself or an ancestor, or a tree that is
+// too deep, is an error rather than an unbounded recursion.
+#[derive(Debug, Default)]
+pub(crate) struct VisitedNodes(HashSet<u64>);
+
+impl VisitedNodes {
+    pub(crate) fn insert(&mut self, offset: u64, depth: usize) -> io::Result<()> {
+        if depth > MAX_TREE_DEPTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("tree depth exceeds {}", MAX_TREE_DEPTH),
+            ));
+        }
+
+        if !self.0.insert(offset) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("tree node at offset {} is visited more than once", offset),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// Reads the magic number and returns the byte order it was written in.
+pub(crate) fn read_byte_order<R>(
+    reader: &mut R,
+    magic_number: u32,
+    name: &str,
+) -> io::Result<ByteOrder>
+where
+    R: Read,
+{
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+
+    if u32::from_le_bytes(buf) == magic_number {
+        Ok(ByteOrder::LittleEndian)
+    } else if u32::from_be_bytes(buf) == magic_number {
+        Ok(ByteOrder::BigEndian)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {} header", name),
+        ))
+    }
+}
+
+// Returns the reference sequence of the region and the region interval as a 0-based, half-open
+// interval. A missing end is set to the reference sequence length.
+pub(crate) fn resolve_region(
+    reference_sequences: &[ReferenceSequence],
+    region: &Region,
+) -> io::Result<(ReferenceSequence, u64, u64)> {
+    let reference_sequence = reference_sequences
+        .iter()
+        .find(|rs| rs.name == region.name())
+        .cloned()
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid reference sequence name: {}", region.name()),
+            )
+        })?;
+
+    let interval = region.interval();
+
+    let start = interval.start().map(Position::to_zero_based).unwrap_or(0) as u64;
+
+    let end = interval
+        .end()
+        .map(|position| usize::from(position) as u64)
+        .unwrap_or_else(|| u64::from(reference_sequence.len));
+
+    Ok((reference_sequence, start, end))
+}
+
+// Reads a (possibly compressed) data block.
+pub(crate) fn read_block<R>(
+    reader: &mut R,
+    header: &Header,
+    (offset, size): Block,
+) -> io::Result<Vec<u8>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let len = usize::try_from(size).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut buf = Vec::with_capacity(cmp::min(len, MAX_PREALLOCATED_LEN));
+    reader.by_ref().take(size).read_to_end(&mut buf)?;
+
+    if buf.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "unexpected EOF in data block",
+        ));
+    }
+
+    if header.is_compressed() {
+        let mut decoder = ZlibDecoder::new(&buf[..]);
+        let capacity = usize::try_from(header.uncompress_buf_size).unwrap_or(usize::MAX);
+        let mut dst = Vec::with_capacity(cmp::min(capacity, MAX_PREALLOCATED_LEN));
+        decoder.read_to_end(&mut dst)?;
+        Ok(dst)
+    } else {
+        Ok(buf)
+    }
+}
+
+pub(crate) fn compress(buf: &[u8]) -> io::Result<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(buf)?;
+    encoder.finish()
+}
+
+pub(crate) fn read_c_string<R>(reader: &mut R) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_u8()? {
+            0 => break,
+            b => buf.push(b),
+        }
+    }
+
+    Ok(buf)
+}
+
+pub(crate) fn read_u16<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<u16>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_u16::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_u16::<BigEndian>(),
+    }
+}
+
+pub(crate) fn read_u32<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<u32>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_u32::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_u32::<BigEndian>(),
+    }
+}
+
+pub(crate) fn read_u64<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<u64>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_u64::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_u64::<BigEndian>(),
+    }
+}
+
+pub(crate) fn read_f32<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<f32>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_f32::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_f32::<BigEndian>(),
+    }
+}
+
+pub(crate) fn read_f64<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<f64>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_f64::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_f64::<BigEndian>(),
+    }
+}
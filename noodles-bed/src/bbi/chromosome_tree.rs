@@ -0,0 +1,289 @@
+use std::{
+    cmp,
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use super::{read_u16, read_u32, read_u64, ByteOrder, VisitedNodes, MAX_PREALLOCATED_LEN};
+
+const MAGIC_NUMBER: u32 = 0x78ca8c91;
+
+/// A bigBed or bigWig reference sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceSequence {
+    pub(crate) name: String,
+    pub(crate) id: u32,
+    pub(crate) len: u32,
+}
+
+impl ReferenceSequence {
+    /// Returns the reference sequence name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the reference sequence length.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns whether the reference sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+// Reads the chromosome B+ tree at the given offset. The reference sequences are sorted by ID.
+pub(crate) fn read<R>(
+    reader: &mut R,
+    byte_order: ByteOrder,
+    offset: u64,
+) -> io::Result<Vec<ReferenceSequence>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let magic_number = read_u32(reader, byte_order)?;
+
+    if magic_number != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid chromosome tree header",
+        ));
+    }
+
+    let _block_size = read_u32(reader, byte_order)?;
+
+    let key_size = read_u32(reader, byte_order).and_then(|n| {
+        usize::try_from(n)
+            .ok()
+            .filter(|&n| n <= MAX_PREALLOCATED_LEN)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid chromosome tree key size: {}", n),
+                )
+            })
+    })?;
+
+    let _val_size = read_u32(reader, byte_order)?;
+    let item_count = read_u64(reader, byte_order)?;
+    let _reserved = read_u64(reader, byte_order)?;
+
+    let capacity = usize::try_from(item_count).unwrap_or(usize::MAX);
+    let mut reference_sequences = Vec::with_capacity(cmp::min(capacity, MAX_PREALLOCATED_LEN));
+
+    let root_offset = reader.stream_position()?;
+    let mut visited_nodes = VisitedNodes::default();
+
+    read_node(
+        reader,
+        byte_order,
+        root_offset,
+        key_size,
+        &mut visited_nodes,
+        0,
+        &mut reference_sequences,
+    )?;
+
+    reference_sequences.sort_by_key(|rs| rs.id);
+
+    Ok(reference_sequences)
+}
+
+fn read_node<R>(
+    reader: &mut R,
+    byte_order: ByteOrder,
+    offset: u64,
+    key_size: usize,
+    visited_nodes: &mut VisitedNodes,
+    depth: usize,
+    reference_sequences: &mut Vec<ReferenceSequence>,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    visited_nodes.insert(offset, depth)?;
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let is_leaf = reader.read_u8()? != 0;
+    let _reserved = reader.read_u8()?;
+    let count = read_u16(reader, byte_order)?;
+
+    let mut key = vec![0; key_size];
+
+    if is_leaf {
+        for _ in 0..count {
+            reader.read_exact(&mut key)?;
+            let id = read_u32(reader, byte_order)?;
+            let len = read_u32(reader, byte_order)?;
+
+            let name = parse_key(&key)?;
+
+            reference_sequences.push(ReferenceSequence { name, id, len });
+        }
+    } else {
+        let mut child_offsets = Vec::with_capacity(usize::from(count));
+
+        for _ in 0..count {
+            reader.read_exact(&mut key)?;
+            let child_offset = read_u64(reader, byte_order)?;
+            child_offsets.push(child_offset);
+        }
+
+        for child_offset in child_offsets {
+            read_node(
+                reader,
+                byte_order,
+                child_offset,
+                key_size,
+                visited_nodes,
+                depth + 1,
+                reference_sequences,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// Keys are padded with NULs.
+fn parse_key(buf: &[u8]) -> io::Result<String> {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+
+    String::from_utf8(buf[..len].to_vec())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// Writes a chromosome B+ tree with a single leaf node in little-endian byte order. IDs are
+// assigned in the given order.
+pub(crate) fn write<W, N>(writer: &mut W, reference_sequences: &[(N, u32)]) -> io::Result<()>
+where
+    W: Write,
+    N: AsRef<str>,
+{
+    let count = u16::try_from(reference_sequences.len())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    // Keys are sorted by name.
+    let mut items: Vec<_> = reference_sequences
+        .iter()
+        .enumerate()
+        .map(|(id, (name, len))| (name.as_ref(), id as u32, *len))
+        .collect();
+
+    items.sort_by(|a, b| a.0.cmp(b.0));
+
+    let key_size = items
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    writer.write_u32::<LittleEndian>(MAGIC_NUMBER)?;
+    writer.write_u32::<LittleEndian>(u32::from(count.max(1)))?; // block size
+    writer.write_u32::<LittleEndian>(key_size as u32)?;
+    writer.write_u32::<LittleEndian>(8)?; // val size
+    writer.write_u64::<LittleEndian>(u64::from(count))?;
+    writer.write_u64::<LittleEndian>(0)?; // reserved
+
+    writer.write_u8(1)?; // is leaf
+    writer.write_u8(0)?; // reserved
+    writer.write_u16::<LittleEndian>(count)?;
+
+    for (name, id, len) in items {
+        let mut key = vec![0; key_size];
+        key[..name.len()].copy_from_slice(name.as_bytes());
+        writer.write_all(&key)?;
+        writer.write_u32::<LittleEndian>(id)?;
+        writer.write_u32::<LittleEndian>(len)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_write_and_read() -> io::Result<()> {
+        let mut buf = vec![0; 8];
+        write(&mut buf, &[("sq1", 13), ("sq0", 8)])?;
+
+        let mut reader = Cursor::new(buf);
+        let reference_sequences = read(&mut reader, ByteOrder::LittleEndian, 8)?;
+
+        assert_eq!(
+            reference_sequences,
+            [
+                ReferenceSequence {
+                    name: String::from("sq1"),
+                    id: 0,
+                    len: 13,
+                },
+                ReferenceSequence {
+                    name: String::from("sq0"),
+                    id: 1,
+                    len: 8,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn build_header(key_size: u32, item_count: u64) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend(MAGIC_NUMBER.to_le_bytes());
+        buf.extend(1u32.to_le_bytes()); // block size
+        buf.extend(key_size.to_le_bytes());
+        buf.extend(8u32.to_le_bytes()); // val size
+        buf.extend(item_count.to_le_bytes());
+        buf.extend(0u64.to_le_bytes()); // reserved
+        buf
+    }
+
+    #[test]
+    fn test_read_with_invalid_key_size() {
+        let buf = build_header(u32::MAX, 0);
+        let mut reader = Cursor::new(buf);
+
+        assert!(matches!(
+            read(&mut reader, ByteOrder::LittleEndian, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_with_large_item_count() {
+        let mut buf = build_header(4, u64::MAX);
+        buf.extend([1, 0, 1, 0]); // is leaf = true, reserved, count = 1
+        let mut reader = Cursor::new(buf);
+
+        assert!(matches!(
+            read(&mut reader, ByteOrder::LittleEndian, 0),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn test_read_with_self_referencing_node() {
+        let mut buf = build_header(4, 1);
+        let root_offset = buf.len() as u64;
+        buf.extend([0, 0, 1, 0]); // is leaf = false, reserved, count = 1
+        buf.extend(b"sq0\x00");
+        buf.extend(root_offset.to_le_bytes());
+        let mut reader = Cursor::new(buf);
+
+        assert!(matches!(
+            read(&mut reader, ByteOrder::LittleEndian, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    cmp,
+    io::{self, Read, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::{read_u16, read_u32, read_u64, ByteOrder, MAX_PREALLOCATED_LEN};
+
+pub(crate) const HEADER_SIZE: u64 = 64;
+pub(crate) const ZOOM_LEVEL_HEADER_SIZE: u64 = 24;
+
+/// A bigBed or bigWig header.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Header {
+    pub(crate) version: u16,
+    pub(crate) chromosome_tree_offset: u64,
+    pub(crate) full_data_offset: u64,
+    pub(crate) full_index_offset: u64,
+    pub(crate) field_count: u16,
+    pub(crate) defined_field_count: u16,
+    pub(crate) auto_sql_offset: u64,
+    pub(crate) total_summary_offset: u64,
+    pub(crate) uncompress_buf_size: u32,
+    pub(crate) zoom_levels: Vec<ZoomLevel>,
+}
+
+impl Header {
+    /// Returns the format version.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the number of fields in each record.
+    ///
+    /// This is always 0 for bigWig files.
+    pub fn field_count(&self) -> u16 {
+        self.field_count
+    }
+
+    /// Returns the number of standard BED fields in each record.
+    ///
+    /// The remaining fields are custom fields described by the autoSql definition.
+    pub fn defined_field_count(&self) -> u16 {
+        self.defined_field_count
+    }
+
+    /// Returns whether the data blocks are compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.uncompress_buf_size > 0
+    }
+
+    /// Returns the zoom level headers.
+    pub fn zoom_levels(&self) -> &[ZoomLevel] {
+        &self.zoom_levels
+    }
+}
+
+/// A bigBed or bigWig zoom level header.
+///
+/// Zoom levels hold precomputed summaries of the data at lower resolutions.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ZoomLevel {
+    pub(crate) reduction_level: u32,
+    pub(crate) data_offset: u64,
+    pub(crate) index_offset: u64,
+}
+
+impl ZoomLevel {
+    /// Returns the number of bases summarized by each item.
+    pub fn reduction_level(&self) -> u32 {
+        self.reduction_level
+    }
+
+    /// Returns the offset of the zoom data.
+    pub fn data_offset(&self) -> u64 {
+        self.data_offset
+    }
+
+    /// Returns the offset of the zoom index.
+    pub fn index_offset(&self) -> u64 {
+        self.index_offset
+    }
+}
+
+pub(crate) fn read_header<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<Header>
+where
+    R: Read,
+{
+    let version = read_u16(reader, byte_order)?;
+    let zoom_level_count = read_u16(reader, byte_order)?;
+    let chromosome_tree_offset = read_u64(reader, byte_order)?;
+    let full_data_offset = read_u64(reader, byte_order)?;
+    let full_index_offset = read_u64(reader, byte_order)?;
+    let field_count = read_u16(reader, byte_order)?;
+    let defined_field_count = read_u16(reader, byte_order)?;
+    let auto_sql_offset = read_u64(reader, byte_order)?;
+    let total_summary_offset = read_u64(reader, byte_order)?;
+    let uncompress_buf_size = read_u32(reader, byte_order)?;
+    let _extension_offset = read_u64(reader, byte_order)?;
+
+    let capacity = cmp::min(usize::from(zoom_level_count), MAX_PREALLOCATED_LEN);
+    let mut zoom_levels = Vec::with_capacity(capacity);
+
+    for _ in 0..zoom_level_count {
+        let reduction_level = read_u32(reader, byte_order)?;
+        let _reserved = read_u32(reader, byte_order)?;
+        let data_offset = read_u64(reader, byte_order)?;
+        let index_offset = read_u64(reader, byte_order)?;
+
+        zoom_levels.push(ZoomLevel {
+            reduction_level,
+            data_offset,
+            index_offset,
+        });
+    }
+
+    Ok(Header {
+        version,
+        chromosome_tree_offset,
+        full_data_offset,
+        full_index_offset,
+        field_count,
+        defined_field_count,
+        auto_sql_offset,
+        total_summary_offset,
+        uncompress_buf_size,
+        zoom_levels,
+    })
+}
+
+// Writes the header and zoom level headers, excluding the magic number, in little-endian byte
+// order.
+pub(crate) fn write_header<W>(writer: &mut W, header: &Header) -> io::Result<()>
+where
+    W: Write,
+{
+    let zoom_level_count = u16::try_from(header.zoom_levels.len())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    writer.write_u16::<LittleEndian>(header.version)?;
+    writer.write_u16::<LittleEndian>(zoom_level_count)?;
+    writer.write_u64::<LittleEndian>(header.chromosome_tree_offset)?;
+    writer.write_u64::<LittleEndian>(header.full_data_offset)?;
+    writer.write_u64::<LittleEndian>(header.full_index_offset)?;
+    writer.write_u16::<LittleEndian>(header.field_count)?;
+    writer.write_u16::<LittleEndian>(header.defined_field_count)?;
+    writer.write_u64::<LittleEndian>(header.auto_sql_offset)?;
+    writer.write_u64::<LittleEndian>(header.total_summary_offset)?;
+    writer.write_u32::<LittleEndian>(header.uncompress_buf_size)?;
+    writer.write_u64::<LittleEndian>(0)?; // extension offset
+
+    for zoom_level in &header.zoom_levels {
+        writer.write_u32::<LittleEndian>(zoom_level.reduction_level)?;
+        writer.write_u32::<LittleEndian>(0)?; // reserved
+        writer.write_u64::<LittleEndian>(zoom_level.data_offset)?;
+        writer.write_u64::<LittleEndian>(zoom_level.index_offset)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,342 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom, Write},
+    ops::Range,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use super::{read_u16, read_u32, read_u64, Block, ByteOrder, VisitedNodes};
+
+const MAGIC_NUMBER: u32 = 0x2468ace0;
+const HEADER_SIZE: u64 = 48;
+const BLOCK_SIZE: usize = 256;
+
+const LEAF_ITEM_SIZE: u64 = 32;
+const INTERNAL_ITEM_SIZE: u64 = 24;
+
+// A (reference sequence ID, 0-based position) pair.
+pub(crate) type Position = (u32, u32);
+
+// The bounds of a data block, as a 0-based, half-open interval.
+pub(crate) type Bounds = (Position, Position);
+
+// Collects the data blocks that intersect the query (reference sequence ID, 0-based start,
+// exclusive end) using the R-tree at the given offset.
+pub(crate) fn read_blocks<R>(
+    reader: &mut R,
+    byte_order: ByteOrder,
+    offset: u64,
+    query: (u32, u64, u64),
+) -> io::Result<Vec<Block>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let magic_number = read_u32(reader, byte_order)?;
+
+    if magic_number != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid R-tree header",
+        ));
+    }
+
+    let mut blocks = Vec::new();
+    let mut visited_nodes = VisitedNodes::default();
+
+    read_node(
+        reader,
+        byte_order,
+        offset + HEADER_SIZE,
+        query,
+        &mut visited_nodes,
+        0,
+        &mut blocks,
+    )?;
+
+    Ok(blocks)
+}
+
+fn read_node<R>(
+    reader: &mut R,
+    byte_order: ByteOrder,
+    offset: u64,
+    query: (u32, u64, u64),
+    visited_nodes: &mut VisitedNodes,
+    depth: usize,
+    blocks: &mut Vec<Block>,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    visited_nodes.insert(offset, depth)?;
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let is_leaf = reader.read_u8()? != 0;
+    let _reserved = reader.read_u8()?;
+    let count = read_u16(reader, byte_order)?;
+
+    let mut child_offsets = Vec::new();
+
+    for _ in 0..count {
+        let start_chrom_ix = read_u32(reader, byte_order)?;
+        let start_base = read_u32(reader, byte_order).map(u64::from)?;
+        let end_chrom_ix = read_u32(reader, byte_order)?;
+        let end_base = read_u32(reader, byte_order).map(u64::from)?;
+
+        let is_hit = overlaps(
+            query,
+            (start_chrom_ix, start_base),
+            (end_chrom_ix, end_base),
+        );
+
+        if is_leaf {
+            let data_offset = read_u64(reader, byte_order)?;
+            let data_size = read_u64(reader, byte_order)?;
+
+            if is_hit {
+                blocks.push((data_offset, data_size));
+            }
+        } else {
+            let child_offset = read_u64(reader, byte_order)?;
+
+            if is_hit {
+                child_offsets.push(child_offset);
+            }
+        }
+    }
+
+    for child_offset in child_offsets {
+        read_node(
+            reader,
+            byte_order,
+            child_offset,
+            query,
+            visited_nodes,
+            depth + 1,
+            blocks,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn overlaps(
+    (id, start, end): (u32, u64, u64),
+    item_start: (u32, u64),
+    item_end: (u32, u64),
+) -> bool {
+    (id, start) < item_end && item_start < (id, end)
+}
+
+// Writes an R-tree over the given data blocks in little-endian byte order.
+//
+// `offset` is the position in the file where the R-tree starts, and `end_file_offset` is the end
+// of the data section.
+pub(crate) fn write<W>(
+    writer: &mut W,
+    offset: u64,
+    items: &[(Bounds, Block)],
+    end_file_offset: u64,
+    items_per_slot: u32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let levels = build_levels(items);
+
+    let root_bounds = levels
+        .last()
+        .and_then(|nodes| nodes.first())
+        .map(|(bounds, _)| *bounds)
+        .unwrap_or_default();
+
+    writer.write_u32::<LittleEndian>(MAGIC_NUMBER)?;
+    writer.write_u32::<LittleEndian>(BLOCK_SIZE as u32)?;
+    writer.write_u64::<LittleEndian>(items.len() as u64)?;
+    writer.write_u32::<LittleEndian>(root_bounds.0 .0)?;
+    writer.write_u32::<LittleEndian>(root_bounds.0 .1)?;
+    writer.write_u32::<LittleEndian>(root_bounds.1 .0)?;
+    writer.write_u32::<LittleEndian>(root_bounds.1 .1)?;
+    writer.write_u64::<LittleEndian>(end_file_offset)?;
+    writer.write_u32::<LittleEndian>(items_per_slot)?;
+    writer.write_u32::<LittleEndian>(0)?; // reserved
+
+    if levels.is_empty() {
+        writer.write_u8(1)?; // is leaf
+        writer.write_u8(0)?; // reserved
+        writer.write_u16::<LittleEndian>(0)?;
+        return Ok(());
+    }
+
+    // Nodes are written top-down, starting with the root.
+    let mut node_offsets = vec![Vec::new(); levels.len()];
+    let mut node_offset = offset + HEADER_SIZE;
+
+    for (i, nodes) in levels.iter().enumerate().rev() {
+        let item_size = if i == 0 {
+            LEAF_ITEM_SIZE
+        } else {
+            INTERNAL_ITEM_SIZE
+        };
+
+        for (_, children) in nodes {
+            node_offsets[i].push(node_offset);
+            node_offset += 4 + children.len() as u64 * item_size;
+        }
+    }
+
+    for (i, nodes) in levels.iter().enumerate().rev() {
+        for (_, children) in nodes {
+            let count = children.len() as u16;
+
+            writer.write_u8(u8::from(i == 0))?; // is leaf
+            writer.write_u8(0)?; // reserved
+            writer.write_u16::<LittleEndian>(count)?;
+
+            for j in children.clone() {
+                if i == 0 {
+                    let (bounds, (data_offset, data_size)) = items[j];
+                    write_bounds(writer, bounds)?;
+                    writer.write_u64::<LittleEndian>(data_offset)?;
+                    writer.write_u64::<LittleEndian>(data_size)?;
+                } else {
+                    let (bounds, _) = levels[i - 1][j];
+                    write_bounds(writer, bounds)?;
+                    writer.write_u64::<LittleEndian>(node_offsets[i - 1][j])?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Groups items into levels of nodes, from the leaves to the root. Each node holds its bounds and
+// the range of its children in the level below (or the items, for leaves).
+fn build_levels(items: &[(Bounds, Block)]) -> Vec<Vec<(Bounds, Range<usize>)>> {
+    let mut levels: Vec<Vec<(Bounds, Range<usize>)>> = Vec::new();
+    let mut bounds: Vec<Bounds> = items.iter().map(|(bounds, _)| *bounds).collect();
+
+    while !bounds.is_empty() {
+        let nodes: Vec<_> = (0..bounds.len())
+            .step_by(BLOCK_SIZE)
+            .map(|start| {
+                let end = (start + BLOCK_SIZE).min(bounds.len());
+                (merge_bounds(&bounds[start..end]), start..end)
+            })
+            .collect();
+
+        let is_root = nodes.len() == 1;
+        bounds = nodes.iter().map(|(bounds, _)| *bounds).collect();
+        levels.push(nodes);
+
+        if is_root {
+            break;
+        }
+    }
+
+    levels
+}
+
+fn merge_bounds(bounds: &[Bounds]) -> Bounds {
+    let start = bounds
+        .iter()
+        .map(|(start, _)| *start)
+        .min()
+        .unwrap_or_default();
+    let end = bounds.iter().map(|(_, end)| *end).max().unwrap_or_default();
+    (start, end)
+}
+
+fn write_bounds<W>(writer: &mut W, (start, end): Bounds) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u32::<LittleEndian>(start.0)?;
+    writer.write_u32::<LittleEndian>(start.1)?;
+    writer.write_u32::<LittleEndian>(end.0)?;
+    writer.write_u32::<LittleEndian>(end.1)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_write_and_read_blocks() -> io::Result<()> {
+        // 600 blocks of 10 bases each on 2 reference sequences, which requires 2 levels.
+        let items: Vec<_> = (0..600)
+            .map(|i| {
+                let id = i / 300;
+                let start = (i % 300) * 10;
+                let bounds = ((id, start), (id, start + 10));
+                (bounds, (u64::from(i) * 100, 100))
+            })
+            .collect();
+
+        let mut buf = Vec::new();
+        write(&mut buf, 0, &items, 0, 1)?;
+
+        let mut reader = Cursor::new(buf);
+
+        let blocks = read_blocks(&mut reader, ByteOrder::LittleEndian, 0, (0, 15, 25))?;
+        assert_eq!(blocks, [(100, 100), (200, 100)]);
+
+        let blocks = read_blocks(&mut reader, ByteOrder::LittleEndian, 0, (1, 2995, 3000))?;
+        assert_eq!(blocks, [(59900, 100)]);
+
+        let blocks = read_blocks(&mut reader, ByteOrder::LittleEndian, 0, (2, 0, 10))?;
+        assert!(blocks.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_and_read_blocks_with_no_items() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write(&mut buf, 0, &[], 0, 1)?;
+
+        let mut reader = Cursor::new(buf);
+        let blocks = read_blocks(&mut reader, ByteOrder::LittleEndian, 0, (0, 0, 10))?;
+        assert!(blocks.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_blocks_with_self_referencing_node() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write(&mut buf, 0, &[], 0, 1)?;
+        buf.truncate(HEADER_SIZE as usize);
+
+        buf.extend([0, 0, 1, 0]); // is leaf = false, reserved, count = 1
+        buf.extend(0u32.to_le_bytes()); // start chrom ix
+        buf.extend(0u32.to_le_bytes()); // start base
+        buf.extend(0u32.to_le_bytes()); // end chrom ix
+        buf.extend(100u32.to_le_bytes()); // end base
+        buf.extend(HEADER_SIZE.to_le_bytes()); // child offset
+
+        let mut reader = Cursor::new(buf);
+
+        assert!(matches!(
+            read_blocks(&mut reader, ByteOrder::LittleEndian, 0, (0, 0, 10)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert!(overlaps((0, 10, 20), (0, 5), (0, 15)));
+        assert!(overlaps((1, 10, 20), (0, 5), (2, 0)));
+        assert!(!overlaps((0, 10, 20), (0, 20), (0, 30)));
+        assert!(!overlaps((0, 10, 20), (0, 0), (0, 10)));
+        assert!(!overlaps((1, 10, 20), (0, 0), (0, 100)));
+    }
+}
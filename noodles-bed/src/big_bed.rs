@@ -0,0 +1,13 @@
+//! UCSC bigBed reader.
+//!
+//! bigBed is an indexed binary format for BED records. Records are stored in (usually compressed)
+//! data blocks, which are indexed by an R-tree for region queries. Reference sequence names are
+//! stored in a B+ tree.
+
+mod reader;
+
+pub use self::reader::Reader;
+
+pub use crate::bbi::{Header, ReferenceSequence, ZoomLevel};
+
+const MAGIC_NUMBER: u32 = 0x8789f2eb;
@@ -0,0 +1,400 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Seek, SeekFrom},
+    iter,
+    str::FromStr,
+};
+
+use noodles_core::Region;
+
+use super::MAGIC_NUMBER;
+use crate::{
+    bbi::{self, chromosome_tree, r_tree, ByteOrder, Header, ReferenceSequence},
+    record::ParseError,
+    Record,
+};
+
+/// A bigBed reader.
+///
+/// The header and reference sequences are read when the reader is created. Records are then read
+/// on demand using the R-tree index.
+pub struct Reader<R> {
+    inner: R,
+    byte_order: ByteOrder,
+    header: Header,
+    reference_sequences: Vec<ReferenceSequence>,
+}
+
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a bigBed reader.
+    ///
+    /// This reads the header, zoom level headers, and reference sequences (chromosome B+ tree).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bed::big_bed;
+    /// let reader = File::open("annotations.bb").and_then(big_bed::Reader::new)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(0))?;
+
+        let byte_order = bbi::read_byte_order(&mut inner, MAGIC_NUMBER, "bigBed")?;
+        let header = bbi::read_header(&mut inner, byte_order)?;
+        let reference_sequences =
+            chromosome_tree::read(&mut inner, byte_order, header.chromosome_tree_offset)?;
+
+        Ok(Self {
+            inner,
+            byte_order,
+            header,
+            reference_sequences,
+        })
+    }
+
+    /// Returns the header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the reference sequences.
+    pub fn reference_sequences(&self) -> &[ReferenceSequence] {
+        &self.reference_sequences
+    }
+
+    /// Reads the autoSql definition, if present.
+    ///
+    /// The autoSql definition describes the fields of each record.
+    pub fn read_auto_sql(&mut self) -> io::Result<Option<String>> {
+        if self.header.auto_sql_offset == 0 {
+            return Ok(None);
+        }
+
+        self.inner
+            .seek(SeekFrom::Start(self.header.auto_sql_offset))?;
+
+        let buf = bbi::read_c_string(&mut self.inner)?;
+
+        String::from_utf8(buf)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// Records are converted to BED records of size `N`. Custom fields are kept as optional
+    /// fields.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bed::big_bed;
+    ///
+    /// let mut reader = File::open("annotations.bb").and_then(big_bed::Reader::new)?;
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query::<6>(&region)? {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<const N: u8>(
+        &mut self,
+        region: &Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record<N>>> + '_>
+    where
+        Record<N>: FromStr<Err = ParseError>,
+    {
+        let (reference_sequence, start, end) =
+            bbi::resolve_region(&self.reference_sequences, region)?;
+
+        let blocks = r_tree::read_blocks(
+            &mut self.inner,
+            self.byte_order,
+            self.header.full_index_offset,
+            (reference_sequence.id, start, end),
+        )?;
+
+        let mut blocks = blocks.into_iter();
+        let mut records = VecDeque::new();
+
+        Ok(iter::from_fn(move || loop {
+            if let Some(record) = records.pop_front() {
+                return Some(record);
+            }
+
+            let block = blocks.next()?;
+
+            match bbi::read_block(&mut self.inner, &self.header, block) {
+                Ok(buf) => {
+                    let result = decode_records(
+                        &buf,
+                        self.byte_order,
+                        &reference_sequence,
+                        start,
+                        end,
+                        &mut records,
+                    );
+
+                    if let Err(e) = result {
+                        return Some(Err(e));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }))
+    }
+}
+
+fn decode_records<const N: u8>(
+    mut buf: &[u8],
+    byte_order: ByteOrder,
+    reference_sequence: &ReferenceSequence,
+    query_start: u64,
+    query_end: u64,
+    records: &mut VecDeque<io::Result<Record<N>>>,
+) -> io::Result<()>
+where
+    Record<N>: FromStr<Err = ParseError>,
+{
+    while !buf.is_empty() {
+        let id = bbi::read_u32(&mut buf, byte_order)?;
+        let start = bbi::read_u32(&mut buf, byte_order).map(u64::from)?;
+        let end = bbi::read_u32(&mut buf, byte_order).map(u64::from)?;
+        let rest = bbi::read_c_string(&mut buf)?;
+
+        if id != reference_sequence.id || start >= query_end || end <= query_start {
+            continue;
+        }
+
+        let rest = std::str::from_utf8(&rest)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut line = format!("{}\t{}\t{}", reference_sequence.name, start, end);
+
+        if !rest.is_empty() {
+            line.push('\t');
+            line.push_str(rest);
+        }
+
+        let record = line
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+
+        records.push_back(record);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use byteorder::{LittleEndian, WriteBytesExt};
+    use flate2::{write::ZlibEncoder, Compression};
+
+    use super::*;
+
+    const HEADER_SIZE: u64 = 64;
+    const CHROMOSOME_TREE_MAGIC_NUMBER: u32 = 0x78ca8c91;
+    const R_TREE_MAGIC_NUMBER: u32 = 0x2468ace0;
+
+    // Builds a bigBed file with a single-leaf chromosome tree and a single-leaf R-tree. Each
+    // record is (chromosome ID, start, end, rest), and each inner list is a data block.
+    fn build(
+        chromosomes: &[(&str, u32)],
+        blocks: &[Vec<(u32, u32, u32, &str)>],
+        is_compressed: bool,
+    ) -> io::Result<Vec<u8>> {
+        const KEY_SIZE: usize = 8;
+
+        let mut encoded_blocks = Vec::new();
+        let mut max_block_size = 0;
+
+        for block in blocks {
+            let mut buf = Vec::new();
+
+            for &(id, start, end, rest) in block {
+                buf.write_u32::<LittleEndian>(id)?;
+                buf.write_u32::<LittleEndian>(start)?;
+                buf.write_u32::<LittleEndian>(end)?;
+                buf.write_all(rest.as_bytes())?;
+                buf.write_u8(0)?;
+            }
+
+            max_block_size = max_block_size.max(buf.len());
+
+            if is_compressed {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(&buf)?;
+                buf = encoder.finish()?;
+            }
+
+            encoded_blocks.push(buf);
+        }
+
+        let chromosome_tree_offset = HEADER_SIZE;
+        let chromosome_tree_size = 32 + 4 + chromosomes.len() as u64 * (KEY_SIZE as u64 + 8);
+        let full_data_offset = chromosome_tree_offset + chromosome_tree_size;
+        let data_size: u64 = encoded_blocks.iter().map(|b| b.len() as u64).sum();
+        let full_index_offset = full_data_offset + 8 + data_size;
+
+        let mut buf = Vec::new();
+
+        // header
+        buf.write_u32::<LittleEndian>(MAGIC_NUMBER)?;
+        buf.write_u16::<LittleEndian>(4)?; // version
+        buf.write_u16::<LittleEndian>(0)?; // zoom levels
+        buf.write_u64::<LittleEndian>(chromosome_tree_offset)?;
+        buf.write_u64::<LittleEndian>(full_data_offset)?;
+        buf.write_u64::<LittleEndian>(full_index_offset)?;
+        buf.write_u16::<LittleEndian>(4)?; // field count
+        buf.write_u16::<LittleEndian>(4)?; // defined field count
+        buf.write_u64::<LittleEndian>(0)?; // autoSql offset
+        buf.write_u64::<LittleEndian>(0)?; // total summary offset
+        let uncompress_buf_size = if is_compressed { max_block_size } else { 0 };
+        buf.write_u32::<LittleEndian>(uncompress_buf_size as u32)?;
+        buf.write_u64::<LittleEndian>(0)?; // extension offset
+
+        // chromosome tree
+        buf.write_u32::<LittleEndian>(CHROMOSOME_TREE_MAGIC_NUMBER)?;
+        buf.write_u32::<LittleEndian>(chromosomes.len() as u32)?; // block size
+        buf.write_u32::<LittleEndian>(KEY_SIZE as u32)?;
+        buf.write_u32::<LittleEndian>(8)?; // val size
+        buf.write_u64::<LittleEndian>(chromosomes.len() as u64)?;
+        buf.write_u64::<LittleEndian>(0)?; // reserved
+
+        buf.write_u8(1)?; // is leaf
+        buf.write_u8(0)?; // reserved
+        buf.write_u16::<LittleEndian>(chromosomes.len() as u16)?;
+
+        for (id, (name, len)) in chromosomes.iter().enumerate() {
+            let mut key = [0; KEY_SIZE];
+            key[..name.len()].copy_from_slice(name.as_bytes());
+            buf.write_all(&key)?;
+            buf.write_u32::<LittleEndian>(id as u32)?;
+            buf.write_u32::<LittleEndian>(*len)?;
+        }
+
+        // data
+        let record_count: usize = blocks.iter().map(|block| block.len()).sum();
+        buf.write_u64::<LittleEndian>(record_count as u64)?;
+
+        let mut block_offsets = Vec::new();
+
+        for encoded_block in &encoded_blocks {
+            block_offsets.push(buf.len() as u64);
+            buf.write_all(encoded_block)?;
+        }
+
+        // R-tree
+        buf.write_u32::<LittleEndian>(R_TREE_MAGIC_NUMBER)?;
+        buf.write_u32::<LittleEndian>(blocks.len() as u32)?; // block size
+        buf.write_u64::<LittleEndian>(record_count as u64)?;
+        buf.write_all(&[0; 16])?; // bounds
+        buf.write_u64::<LittleEndian>(full_index_offset)?; // end file offset
+        buf.write_u32::<LittleEndian>(1)?; // items per slot
+        buf.write_u32::<LittleEndian>(0)?; // reserved
+
+        buf.write_u8(1)?; // is leaf
+        buf.write_u8(0)?; // reserved
+        buf.write_u16::<LittleEndian>(blocks.len() as u16)?;
+
+        for ((block, offset), encoded_block) in
+            blocks.iter().zip(block_offsets).zip(&encoded_blocks)
+        {
+            let (start_id, start, _, _) = block[0];
+            let (end_id, _, _, _) = block[block.len() - 1];
+            let end = block.iter().map(|r| r.2).max().unwrap();
+
+            buf.write_u32::<LittleEndian>(start_id)?;
+            buf.write_u32::<LittleEndian>(start)?;
+            buf.write_u32::<LittleEndian>(end_id)?;
+            buf.write_u32::<LittleEndian>(end)?;
+            buf.write_u64::<LittleEndian>(offset)?;
+            buf.write_u64::<LittleEndian>(encoded_block.len() as u64)?;
+        }
+
+        Ok(buf)
+    }
+
+    fn data(is_compressed: bool) -> io::Result<Vec<u8>> {
+        build(
+            &[("sq0", 100), ("sq1", 50)],
+            &[
+                vec![(0, 7, 13, "r0"), (0, 20, 34, "r1")],
+                vec![(0, 55, 89, "r2"), (1, 4, 8, "r3")],
+            ],
+            is_compressed,
+        )
+    }
+
+    #[test]
+    fn test_new() -> io::Result<()> {
+        let reader = Reader::new(Cursor::new(data(true)?))?;
+
+        assert_eq!(reader.header().version(), 4);
+        assert!(reader.header().is_compressed());
+        assert!(reader.header().zoom_levels().is_empty());
+
+        let names: Vec<_> = reader
+            .reference_sequences()
+            .iter()
+            .map(|rs| (rs.name(), rs.len()))
+            .collect();
+        assert_eq!(names, [("sq0", 100), ("sq1", 50)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_invalid_magic_number() {
+        let data = [0; 64];
+        assert!(matches!(
+            Reader::new(Cursor::new(&data[..])),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        fn names(records: &[Record<4>]) -> Vec<&str> {
+            records
+                .iter()
+                .filter_map(|record| record.name().map(|name| &**name))
+                .collect()
+        }
+
+        for is_compressed in [false, true] {
+            let mut reader = Reader::new(Cursor::new(data(is_compressed)?))?;
+
+            let region = "sq0:10-25".parse()?;
+            let records: Vec<Record<4>> = reader.query(&region)?.collect::<io::Result<_>>()?;
+            assert_eq!(names(&records), ["r0", "r1"]);
+
+            let region = "sq0:35-60".parse()?;
+            let records: Vec<Record<4>> = reader.query(&region)?.collect::<io::Result<_>>()?;
+            assert_eq!(names(&records), ["r2"]);
+
+            let region = "sq1".parse()?;
+            let records: Vec<Record<4>> = reader.query(&region)?.collect::<io::Result<_>>()?;
+            assert_eq!(names(&records), ["r3"]);
+
+            let region = "sq0:90-100".parse()?;
+            assert_eq!(reader.query::<3>(&region)?.count(), 0);
+
+            let region = "sq2".parse()?;
+            assert!(reader.query::<3>(&region).is_err());
+        }
+
+        Ok(())
+    }
+}
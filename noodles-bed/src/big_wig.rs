@@ -0,0 +1,15 @@
+//! UCSC bigWig reader and writer.
+//!
+//! bigWig is an indexed binary format for continuous-valued data, e.g., coverage. It shares its
+//! container layout with bigBed. Values are stored in (usually compressed) data blocks, and
+//! precomputed summaries are stored in zoom levels at decreasing resolutions.
+
+mod reader;
+mod summary;
+mod writer;
+
+pub use self::{reader::Reader, summary::Summary, writer::Writer};
+
+pub use crate::bbi::{Header, ReferenceSequence, ZoomLevel};
+
+const MAGIC_NUMBER: u32 = 0x888ffc26;
@@ -0,0 +1,309 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    iter,
+};
+
+use byteorder::ReadBytesExt;
+use noodles_core::{
+    coordinates::{OneBasedInterval, ZeroBasedInterval},
+    region::Interval,
+    Region,
+};
+
+use super::{Summary, ZoomLevel, MAGIC_NUMBER};
+use crate::bbi::{self, chromosome_tree, r_tree, ByteOrder, Header, ReferenceSequence};
+
+const BED_GRAPH: u8 = 1;
+const VARIABLE_STEP: u8 = 2;
+const FIXED_STEP: u8 = 3;
+
+/// A bigWig reader.
+///
+/// The header and reference sequences are read when the reader is created. Values are then read
+/// on demand using the R-tree indices.
+pub struct Reader<R> {
+    inner: R,
+    byte_order: ByteOrder,
+    header: Header,
+    reference_sequences: Vec<ReferenceSequence>,
+}
+
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a bigWig reader.
+    ///
+    /// This reads the header, zoom level headers, and reference sequences (chromosome B+ tree).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bed::big_wig;
+    /// let reader = File::open("coverage.bw").and_then(big_wig::Reader::new)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(0))?;
+
+        let byte_order = bbi::read_byte_order(&mut inner, MAGIC_NUMBER, "bigWig")?;
+        let header = bbi::read_header(&mut inner, byte_order)?;
+        let reference_sequences =
+            chromosome_tree::read(&mut inner, byte_order, header.chromosome_tree_offset)?;
+
+        Ok(Self {
+            inner,
+            byte_order,
+            header,
+            reference_sequences,
+        })
+    }
+
+    /// Returns the header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the reference sequences.
+    pub fn reference_sequences(&self) -> &[ReferenceSequence] {
+        &self.reference_sequences
+    }
+
+    /// Reads the summary of all values in the file, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bed::big_wig;
+    /// use noodles_core::Position;
+    ///
+    /// let mut writer = big_wig::Writer::new(Vec::new());
+    /// let interval = (Position::try_from(1)?..=Position::try_from(4)?).into();
+    /// writer.write_records(&[("sq0", 8)], &[("sq0", interval, 2.0)])?;
+    ///
+    /// let mut reader = big_wig::Reader::new(Cursor::new(writer.into_inner()))?;
+    /// let summary = reader.read_total_summary()?.unwrap();
+    /// assert_eq!(summary.bases_covered(), 4);
+    /// assert_eq!(summary.mean(), Some(2.0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_total_summary(&mut self) -> io::Result<Option<Summary>> {
+        if self.header.total_summary_offset == 0 {
+            return Ok(None);
+        }
+
+        self.inner
+            .seek(SeekFrom::Start(self.header.total_summary_offset))?;
+
+        let byte_order = self.byte_order;
+
+        Ok(Some(Summary {
+            bases_covered: bbi::read_u64(&mut self.inner, byte_order)?,
+            min: bbi::read_f64(&mut self.inner, byte_order)?,
+            max: bbi::read_f64(&mut self.inner, byte_order)?,
+            sum: bbi::read_f64(&mut self.inner, byte_order)?,
+            sum_squares: bbi::read_f64(&mut self.inner, byte_order)?,
+        }))
+    }
+
+    /// Returns an iterator over values that intersect the given region.
+    ///
+    /// Each item is the interval the value covers and the value. Intervals are 1-based and
+    /// inclusive, and they are not clipped to the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bed::big_wig;
+    /// use noodles_core::Position;
+    ///
+    /// let mut writer = big_wig::Writer::new(Vec::new());
+    /// let interval = (Position::try_from(1)?..=Position::try_from(4)?).into();
+    /// writer.write_records(&[("sq0", 8)], &[("sq0", interval, 2.0)])?;
+    ///
+    /// let mut reader = big_wig::Reader::new(Cursor::new(writer.into_inner()))?;
+    /// let region = "sq0:2-3".parse()?;
+    /// let values: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+    /// assert_eq!(values, [(interval, 2.0)]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(
+        &mut self,
+        region: &Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<(Interval, f32)>> + '_> {
+        let index_offset = self.header.full_index_offset;
+        self.query_blocks(index_offset, region, decode_values)
+    }
+
+    /// Returns an iterator over zoom level summaries that intersect the given region.
+    ///
+    /// The zoom level is one of the zoom levels in the header. Each item is the interval the
+    /// summary covers and the summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bed::big_wig;
+    /// use noodles_core::Position;
+    ///
+    /// let mut writer = big_wig::Writer::new(Vec::new());
+    /// let interval = (Position::try_from(1)?..=Position::try_from(4)?).into();
+    /// writer.write_records(&[("sq0", 8)], &[("sq0", interval, 2.0)])?;
+    ///
+    /// let mut reader = big_wig::Reader::new(Cursor::new(writer.into_inner()))?;
+    /// let zoom_levels = reader.header().zoom_levels().to_vec();
+    /// let region = "sq0".parse()?;
+    ///
+    /// for zoom_level in zoom_levels {
+    ///     for result in reader.query_zoom(zoom_level, &region)? {
+    ///         let (interval, summary) = result?;
+    ///         println!("{}\t{}\t{:?}", zoom_level.reduction_level(), interval, summary.mean());
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_zoom(
+        &mut self,
+        zoom_level: ZoomLevel,
+        region: &Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<(Interval, Summary)>> + '_> {
+        self.query_blocks(zoom_level.index_offset, region, decode_summaries)
+    }
+
+    fn query_blocks<T, F>(
+        &mut self,
+        index_offset: u64,
+        region: &Region,
+        decode: F,
+    ) -> io::Result<impl Iterator<Item = io::Result<T>> + '_>
+    where
+        T: 'static,
+        F: Fn(&[u8], ByteOrder, (u32, u64, u64)) -> io::Result<Vec<T>> + 'static,
+    {
+        let (reference_sequence, start, end) =
+            bbi::resolve_region(&self.reference_sequences, region)?;
+
+        let query = (reference_sequence.id, start, end);
+        let blocks = r_tree::read_blocks(&mut self.inner, self.byte_order, index_offset, query)?;
+
+        let mut blocks = blocks.into_iter();
+        let mut items = Vec::new().into_iter();
+
+        Ok(iter::from_fn(move || loop {
+            if let Some(item) = items.next() {
+                return Some(Ok(item));
+            }
+
+            let block = blocks.next()?;
+
+            let result = bbi::read_block(&mut self.inner, &self.header, block)
+                .and_then(|buf| decode(&buf, self.byte_order, query));
+
+            match result {
+                Ok(decoded_items) => items = decoded_items.into_iter(),
+                Err(e) => return Some(Err(e)),
+            }
+        }))
+    }
+}
+
+fn decode_values(
+    mut buf: &[u8],
+    byte_order: ByteOrder,
+    (query_id, query_start, query_end): (u32, u64, u64),
+) -> io::Result<Vec<(Interval, f32)>> {
+    let reader = &mut buf;
+
+    let id = bbi::read_u32(reader, byte_order)?;
+    let chrom_start = bbi::read_u32(reader, byte_order).map(u64::from)?;
+    let _chrom_end = bbi::read_u32(reader, byte_order)?;
+    let item_step = bbi::read_u32(reader, byte_order).map(u64::from)?;
+    let item_span = bbi::read_u32(reader, byte_order).map(u64::from)?;
+    let ty = reader.read_u8()?;
+    let _reserved = reader.read_u8()?;
+    let item_count = bbi::read_u16(reader, byte_order)?;
+
+    let mut values = Vec::new();
+
+    if id != query_id {
+        return Ok(values);
+    }
+
+    for i in 0..u64::from(item_count) {
+        let (start, end, value) = match ty {
+            BED_GRAPH => {
+                let start = bbi::read_u32(reader, byte_order).map(u64::from)?;
+                let end = bbi::read_u32(reader, byte_order).map(u64::from)?;
+                let value = bbi::read_f32(reader, byte_order)?;
+                (start, end, value)
+            }
+            VARIABLE_STEP => {
+                let start = bbi::read_u32(reader, byte_order).map(u64::from)?;
+                let value = bbi::read_f32(reader, byte_order)?;
+                (start, start + item_span, value)
+            }
+            FIXED_STEP => {
+                let start = chrom_start + i * item_step;
+                let value = bbi::read_f32(reader, byte_order)?;
+                (start, start + item_span, value)
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid bigWig section type: {}", ty),
+                ))
+            }
+        };
+
+        if start < query_end && end > query_start {
+            values.push((build_interval(start, end)?, value));
+        }
+    }
+
+    Ok(values)
+}
+
+fn decode_summaries(
+    mut buf: &[u8],
+    byte_order: ByteOrder,
+    (query_id, query_start, query_end): (u32, u64, u64),
+) -> io::Result<Vec<(Interval, Summary)>> {
+    let reader = &mut buf;
+    let mut summaries = Vec::new();
+
+    while !reader.is_empty() {
+        let id = bbi::read_u32(reader, byte_order)?;
+        let start = bbi::read_u32(reader, byte_order).map(u64::from)?;
+        let end = bbi::read_u32(reader, byte_order).map(u64::from)?;
+        let bases_covered = bbi::read_u32(reader, byte_order).map(u64::from)?;
+        let min = bbi::read_f32(reader, byte_order).map(f64::from)?;
+        let max = bbi::read_f32(reader, byte_order).map(f64::from)?;
+        let sum = bbi::read_f32(reader, byte_order).map(f64::from)?;
+        let sum_squares = bbi::read_f32(reader, byte_order).map(f64::from)?;
+
+        if id == query_id && start < query_end && end > query_start {
+            let summary = Summary::new(bases_covered, min, max, sum, sum_squares);
+            summaries.push((build_interval(start, end)?, summary));
+        }
+    }
+
+    Ok(summaries)
+}
+
+// Converts a 0-based, half-open interval to a 1-based, inclusive interval.
+fn build_interval(start: u64, end: u64) -> io::Result<Interval> {
+    let interval = ZeroBasedInterval::new(start as usize, end as usize).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid interval: [{}, {})", start, end),
+        )
+    })?;
+
+    OneBasedInterval::try_from(interval)
+        .map(Interval::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
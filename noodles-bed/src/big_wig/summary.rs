@@ -0,0 +1,157 @@
+/// A bigWig summary.
+///
+/// A summary holds statistics of the values over a range of bases. Summaries are stored for each
+/// zoom level record and for the whole file (the total summary).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub(super) bases_covered: u64,
+    pub(super) min: f64,
+    pub(super) max: f64,
+    pub(super) sum: f64,
+    pub(super) sum_squares: f64,
+}
+
+impl Summary {
+    /// Creates a bigWig summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// ```
+    pub fn new(bases_covered: u64, min: f64, max: f64, sum: f64, sum_squares: f64) -> Self {
+        Self {
+            bases_covered,
+            min,
+            max,
+            sum,
+            sum_squares,
+        }
+    }
+
+    /// Returns the number of bases with a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.bases_covered(), 8);
+    /// ```
+    pub fn bases_covered(&self) -> u64 {
+        self.bases_covered
+    }
+
+    /// Returns the minimum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.min(), 0.0);
+    /// ```
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.max(), 2.0);
+    /// ```
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the sum of the values of each covered base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.sum(), 13.0);
+    /// ```
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the sum of the squares of the values of each covered base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.sum_squares(), 21.0);
+    /// ```
+    pub fn sum_squares(&self) -> f64 {
+        self.sum_squares
+    }
+
+    /// Returns the mean value of the covered bases.
+    ///
+    /// This returns `None` if no bases are covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig::Summary;
+    ///
+    /// let summary = Summary::new(8, 0.0, 2.0, 13.0, 21.0);
+    /// assert_eq!(summary.mean(), Some(1.625));
+    ///
+    /// assert!(Summary::default().mean().is_none());
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        if self.bases_covered == 0 {
+            None
+        } else {
+            Some(self.sum / self.bases_covered as f64)
+        }
+    }
+
+    // Adds a value that covers `len` bases.
+    pub(super) fn add(&mut self, value: f64, len: u64) {
+        if len == 0 {
+            return;
+        }
+
+        if self.bases_covered == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        let n = len as f64;
+
+        self.bases_covered += len;
+        self.sum += value * n;
+        self.sum_squares += value * value * n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let mut summary = Summary::default();
+
+        summary.add(2.0, 0);
+        assert_eq!(summary, Summary::default());
+
+        summary.add(2.0, 3);
+        summary.add(-1.0, 2);
+        assert_eq!(summary, Summary::new(5, -1.0, 2.0, 4.0, 14.0));
+    }
+}
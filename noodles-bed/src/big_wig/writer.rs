@@ -0,0 +1,585 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{self, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use noodles_core::region::Interval;
+
+use super::{Summary, MAGIC_NUMBER};
+use crate::bbi::{
+    self, chromosome_tree,
+    header::{HEADER_SIZE, ZOOM_LEVEL_HEADER_SIZE},
+    r_tree::{self, Bounds},
+    Block, Header, ZoomLevel,
+};
+
+const VERSION: u16 = 4;
+const BED_GRAPH: u8 = 1;
+const ITEMS_PER_SLOT: usize = 1024;
+const TOTAL_SUMMARY_SIZE: u64 = 40;
+
+const MAX_ZOOM_LEVEL_COUNT: usize = 10;
+const ZOOM_INCREMENT: u64 = 4;
+
+// A value as (reference sequence ID, 0-based start, exclusive end, value).
+type Item = (u32, u32, u32, f32);
+
+// A zoom level summary as (reference sequence ID, 0-based start, exclusive end, summary).
+type ZoomItem = (u32, u32, u32, Summary);
+
+/// A bigWig writer.
+///
+/// Because the index is written after the data, all records are written at once. Values are
+/// stored as bedGraph sections in compressed data blocks, and zoom levels are computed from the
+/// values.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a bigWig writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig;
+    /// let writer = big_wig::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig;
+    /// let writer = big_wig::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::big_wig;
+    /// let writer = big_wig::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes a bigWig file with the given reference sequences and records.
+    ///
+    /// Reference sequences are (name, length) pairs. Records are (reference sequence name,
+    /// interval, value) triples and must be sorted by reference sequence, in the order given, and
+    /// start position. Intervals are 1-based and inclusive and must have both a start and end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed::big_wig;
+    /// use noodles_core::Position;
+    ///
+    /// let mut writer = big_wig::Writer::new(Vec::new());
+    ///
+    /// let reference_sequences = [("sq0", 8), ("sq1", 13)];
+    /// let records = [
+    ///     ("sq0", (Position::try_from(1)?..=Position::try_from(4)?).into(), 2.0),
+    ///     ("sq1", (Position::try_from(5)?..=Position::try_from(13)?).into(), 0.5),
+    /// ];
+    ///
+    /// writer.write_records(&reference_sequences, &records)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_records<N, M>(
+        &mut self,
+        reference_sequences: &[(N, u32)],
+        records: &[(M, Interval, f32)],
+    ) -> io::Result<()>
+    where
+        N: AsRef<str>,
+        M: AsRef<str>,
+    {
+        let items = build_items(reference_sequences, records)?;
+        let zoom_level_summaries = build_zoom_level_summaries(reference_sequences, &items);
+
+        let mut total_summary = Summary::default();
+
+        for &(_, start, end, value) in &items {
+            total_summary.add(f64::from(value), u64::from(end - start));
+        }
+
+        let zoom_level_count = zoom_level_summaries.len() as u64;
+        let total_summary_offset = HEADER_SIZE + zoom_level_count * ZOOM_LEVEL_HEADER_SIZE;
+        let chromosome_tree_offset = total_summary_offset + TOTAL_SUMMARY_SIZE;
+
+        let mut buf = Vec::new();
+        chromosome_tree::write(&mut buf, reference_sequences)?;
+
+        let full_data_offset = chromosome_tree_offset + buf.len() as u64;
+        let mut uncompress_buf_size = 0;
+
+        let blocks = build_value_blocks(&items)?;
+        let full_index_offset = write_blocks(
+            &mut buf,
+            chromosome_tree_offset,
+            &blocks,
+            &mut uncompress_buf_size,
+        )?;
+
+        let mut zoom_levels = Vec::with_capacity(zoom_level_summaries.len());
+
+        for (reduction_level, summaries) in zoom_level_summaries {
+            let data_offset = chromosome_tree_offset + buf.len() as u64;
+            let blocks = build_summary_blocks(&summaries)?;
+            let index_offset = write_blocks(
+                &mut buf,
+                chromosome_tree_offset,
+                &blocks,
+                &mut uncompress_buf_size,
+            )?;
+
+            zoom_levels.push(ZoomLevel {
+                reduction_level,
+                data_offset,
+                index_offset,
+            });
+        }
+
+        let header = Header {
+            version: VERSION,
+            chromosome_tree_offset,
+            full_data_offset,
+            full_index_offset,
+            field_count: 0,
+            defined_field_count: 0,
+            auto_sql_offset: 0,
+            total_summary_offset,
+            uncompress_buf_size,
+            zoom_levels,
+        };
+
+        self.inner.write_u32::<LittleEndian>(MAGIC_NUMBER)?;
+        bbi::header::write_header(&mut self.inner, &header)?;
+
+        self.inner
+            .write_u64::<LittleEndian>(total_summary.bases_covered)?;
+        self.inner.write_f64::<LittleEndian>(total_summary.min)?;
+        self.inner.write_f64::<LittleEndian>(total_summary.max)?;
+        self.inner.write_f64::<LittleEndian>(total_summary.sum)?;
+        self.inner
+            .write_f64::<LittleEndian>(total_summary.sum_squares)?;
+
+        self.inner.write_all(&buf)?;
+
+        Ok(())
+    }
+}
+
+fn build_items<N, M>(
+    reference_sequences: &[(N, u32)],
+    records: &[(M, Interval, f32)],
+) -> io::Result<Vec<Item>>
+where
+    N: AsRef<str>,
+    M: AsRef<str>,
+{
+    let ids: HashMap<&str, (u32, u32)> = reference_sequences
+        .iter()
+        .enumerate()
+        .map(|(id, (name, len))| (name.as_ref(), (id as u32, *len)))
+        .collect();
+
+    let mut items = Vec::with_capacity(records.len());
+
+    for (name, interval, value) in records {
+        let name = name.as_ref();
+
+        let (id, len) = ids.get(name).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid reference sequence name: {}", name),
+            )
+        })?;
+
+        let (start, end) = match (interval.start(), interval.end()) {
+            (Some(start), Some(end)) => (start.to_zero_based(), usize::from(end)),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unbounded interval: {}", interval),
+                ))
+            }
+        };
+
+        let start =
+            u32::try_from(start).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let end = u32::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        if start >= end || end > len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid interval for {}: {}", name, interval),
+            ));
+        }
+
+        if let Some(&(prev_id, prev_start, _, _)) = items.last() {
+            if (id, start) < (prev_id, prev_start) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "records are not sorted",
+                ));
+            }
+        }
+
+        items.push((id, start, end, *value));
+    }
+
+    Ok(items)
+}
+
+// Computes the zoom levels as (reduction level, summaries).
+//
+// The first reduction level is 10 times the mean item length, and each subsequent level is 4
+// times the previous. Levels stop being added when they no longer reduce the number of items by
+// at least half.
+fn build_zoom_level_summaries<N>(
+    reference_sequences: &[(N, u32)],
+    items: &[Item],
+) -> Vec<(u32, Vec<ZoomItem>)> {
+    let mut zoom_levels = Vec::new();
+
+    if items.is_empty() {
+        return zoom_levels;
+    }
+
+    let total_len: u64 = items
+        .iter()
+        .map(|&(_, start, end, _)| u64::from(end - start))
+        .sum();
+    let mean_len = total_len / items.len() as u64;
+
+    let max_len = reference_sequences
+        .iter()
+        .map(|(_, len)| u64::from(*len))
+        .max()
+        .unwrap_or(0);
+
+    let mut reduction_level = (mean_len * 10).max(1);
+    let mut prev_count = items.len();
+
+    while zoom_levels.len() < MAX_ZOOM_LEVEL_COUNT && reduction_level <= u64::from(u32::MAX) {
+        let summaries = summarize(reference_sequences, items, reduction_level as u32);
+
+        if summaries.len() * 2 > prev_count {
+            break;
+        }
+
+        prev_count = summaries.len();
+        zoom_levels.push((reduction_level as u32, summaries));
+
+        if reduction_level >= max_len {
+            break;
+        }
+
+        reduction_level *= ZOOM_INCREMENT;
+    }
+
+    zoom_levels
+}
+
+fn summarize<N>(
+    reference_sequences: &[(N, u32)],
+    items: &[Item],
+    reduction_level: u32,
+) -> Vec<ZoomItem> {
+    let mut bins: BTreeMap<(u32, u32), Summary> = BTreeMap::new();
+
+    for &(id, start, end, value) in items {
+        for bin in (start / reduction_level)..=((end - 1) / reduction_level) {
+            let bin_start = bin * reduction_level;
+            let bin_end = bin_start.saturating_add(reduction_level);
+
+            let len = end.min(bin_end) - start.max(bin_start);
+
+            bins.entry((id, bin))
+                .or_default()
+                .add(f64::from(value), u64::from(len));
+        }
+    }
+
+    bins.into_iter()
+        .map(|((id, bin), summary)| {
+            let start = bin * reduction_level;
+            let len = reference_sequences[id as usize].1;
+            let end = start.saturating_add(reduction_level).min(len);
+            (id, start, end, summary)
+        })
+        .collect()
+}
+
+// Splits the items into bedGraph sections, returning the uncompressed blocks and their bounds.
+fn build_value_blocks(items: &[Item]) -> io::Result<Vec<(Bounds, Vec<u8>)>> {
+    let mut blocks = Vec::new();
+
+    for chunk in chunk_by_reference_sequence(items, |item| item.0) {
+        let id = chunk[0].0;
+        let start = chunk[0].1;
+        let end = chunk.iter().map(|item| item.2).max().unwrap_or(start);
+
+        let mut buf = Vec::new();
+
+        buf.write_u32::<LittleEndian>(id)?;
+        buf.write_u32::<LittleEndian>(start)?;
+        buf.write_u32::<LittleEndian>(end)?;
+        buf.write_u32::<LittleEndian>(0)?; // item step
+        buf.write_u32::<LittleEndian>(0)?; // item span
+        buf.write_u8(BED_GRAPH)?;
+        buf.write_u8(0)?; // reserved
+        buf.write_u16::<LittleEndian>(chunk.len() as u16)?;
+
+        for &(_, start, end, value) in chunk {
+            buf.write_u32::<LittleEndian>(start)?;
+            buf.write_u32::<LittleEndian>(end)?;
+            buf.write_f32::<LittleEndian>(value)?;
+        }
+
+        blocks.push((((id, start), (id, end)), buf));
+    }
+
+    Ok(blocks)
+}
+
+fn build_summary_blocks(summaries: &[ZoomItem]) -> io::Result<Vec<(Bounds, Vec<u8>)>> {
+    let mut blocks = Vec::new();
+
+    for chunk in chunk_by_reference_sequence(summaries, |summary| summary.0) {
+        let id = chunk[0].0;
+        let start = chunk[0].1;
+        let end = chunk.iter().map(|summary| summary.2).max().unwrap_or(start);
+
+        let mut buf = Vec::new();
+
+        for (id, start, end, summary) in chunk {
+            buf.write_u32::<LittleEndian>(*id)?;
+            buf.write_u32::<LittleEndian>(*start)?;
+            buf.write_u32::<LittleEndian>(*end)?;
+            buf.write_u32::<LittleEndian>(summary.bases_covered as u32)?;
+            buf.write_f32::<LittleEndian>(summary.min as f32)?;
+            buf.write_f32::<LittleEndian>(summary.max as f32)?;
+            buf.write_f32::<LittleEndian>(summary.sum as f32)?;
+            buf.write_f32::<LittleEndian>(summary.sum_squares as f32)?;
+        }
+
+        blocks.push((((id, start), (id, end)), buf));
+    }
+
+    Ok(blocks)
+}
+
+// Splits a list into chunks of at most `ITEMS_PER_SLOT` items that are on the same reference
+// sequence.
+fn chunk_by_reference_sequence<T, F>(items: &[T], id: F) -> Vec<&[T]>
+where
+    F: Fn(&T) -> u32,
+{
+    let mut chunks = Vec::new();
+    let mut rest = items;
+
+    while let Some(first) = rest.first() {
+        let first_id = id(first);
+
+        let len = rest
+            .iter()
+            .take(ITEMS_PER_SLOT)
+            .take_while(|item| id(item) == first_id)
+            .count();
+
+        let (chunk, tail) = rest.split_at(len);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+// Writes a data section (item count and compressed blocks) followed by its R-tree index.
+//
+// `base_offset` is the file offset of the start of `buf`. This returns the file offset of the
+// index.
+fn write_blocks(
+    buf: &mut Vec<u8>,
+    base_offset: u64,
+    blocks: &[(Bounds, Vec<u8>)],
+    uncompress_buf_size: &mut u32,
+) -> io::Result<u64> {
+    buf.write_u32::<LittleEndian>(blocks.len() as u32)?;
+
+    let mut items: Vec<(Bounds, Block)> = Vec::with_capacity(blocks.len());
+
+    for (bounds, block) in blocks {
+        *uncompress_buf_size = (*uncompress_buf_size).max(block.len() as u32);
+
+        let data = bbi::compress(block)?;
+        let offset = base_offset + buf.len() as u64;
+
+        buf.write_all(&data)?;
+        items.push((*bounds, (offset, data.len() as u64)));
+    }
+
+    let index_offset = base_offset + buf.len() as u64;
+    r_tree::write(
+        buf,
+        index_offset,
+        &items,
+        index_offset,
+        ITEMS_PER_SLOT as u32,
+    )?;
+
+    Ok(index_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::{Position, Region};
+
+    use super::*;
+    use crate::big_wig::Reader;
+
+    fn interval(
+        start: usize,
+        end: usize,
+    ) -> Result<Interval, noodles_core::position::TryFromIntError> {
+        Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+    }
+
+    #[test]
+    fn test_write_records() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = [("sq0", 10000), ("sq1", 100)];
+
+        let mut records: Vec<_> = (0..2000)
+            .map(|i| {
+                let start = i * 5 + 1;
+                interval(start, start + 4).map(|interval| ("sq0", interval, i as f32))
+            })
+            .collect::<Result<_, _>>()?;
+        records.push(("sq1", interval(11, 20)?, 0.5));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_records(&reference_sequences, &records)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.into_inner()))?;
+
+        assert_eq!(reader.header().version(), VERSION);
+        assert!(reader.header().is_compressed());
+
+        let names: Vec<_> = reader
+            .reference_sequences()
+            .iter()
+            .map(|rs| (rs.name(), rs.len()))
+            .collect();
+        assert_eq!(names, reference_sequences);
+
+        let region = "sq0:5008-5012".parse()?;
+        let values: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+        assert_eq!(
+            values,
+            [
+                (interval(5006, 5010)?, 1001.0),
+                (interval(5011, 5015)?, 1002.0)
+            ]
+        );
+
+        let region = "sq1".parse()?;
+        let values: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+        assert_eq!(values, [(interval(11, 20)?, 0.5)]);
+
+        let region = Region::new("sq1", Position::try_from(21)?..=Position::try_from(100)?);
+        assert_eq!(reader.query(&region)?.count(), 0);
+
+        let total_summary = reader.read_total_summary()?.unwrap();
+        assert_eq!(total_summary.bases_covered(), 10010);
+        assert_eq!(total_summary.min(), 0.0);
+        assert_eq!(total_summary.max(), 1999.0);
+
+        let zoom_levels = reader.header().zoom_levels().to_vec();
+        assert!(!zoom_levels.is_empty());
+        assert_eq!(zoom_levels[0].reduction_level(), 50);
+
+        let region = "sq0:1-100".parse()?;
+        let summaries: Vec<_> = reader
+            .query_zoom(zoom_levels[0], &region)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(
+            summaries,
+            [
+                (interval(1, 50)?, Summary::new(50, 0.0, 9.0, 225.0, 1425.0)),
+                (
+                    interval(51, 100)?,
+                    Summary::new(50, 10.0, 19.0, 725.0, 10925.0)
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_invalid_records() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = [("sq0", 8)];
+        let mut writer = Writer::new(Vec::new());
+
+        let records = [("sq1", interval(1, 4)?, 0.0)];
+        assert!(writer
+            .write_records(&reference_sequences, &records)
+            .is_err());
+
+        let records = [("sq0", interval(5, 9)?, 0.0)];
+        assert!(writer
+            .write_records(&reference_sequences, &records)
+            .is_err());
+
+        let records = [("sq0", interval(5, 8)?, 0.0), ("sq0", interval(1, 4)?, 0.0)];
+        assert!(writer
+            .write_records(&reference_sequences, &records)
+            .is_err());
+
+        let records = [("sq0", Interval::from(Position::try_from(1)?..), 0.0)];
+        assert!(writer
+            .write_records(&reference_sequences, &records)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_no_records() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = [("sq0", 8)];
+        let records: [(&str, Interval, f32); 0] = [];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_records(&reference_sequences, &records)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.into_inner()))?;
+        assert!(reader.header().zoom_levels().is_empty());
+
+        let region = "sq0".parse()?;
+        assert_eq!(reader.query(&region)?.count(), 0);
+
+        Ok(())
+    }
+}
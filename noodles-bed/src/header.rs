@@ -0,0 +1,145 @@
+//! BED header and components.
+//!
+//! A BED header is the list of `browser` lines and, optionally, the `track` line at the start of
+//! a file. These are used by genome browsers to configure the display of the records.
+
+pub mod browser;
+pub mod track;
+
+pub use self::{browser::Browser, track::Track};
+
+use std::fmt;
+
+/// A BED header.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Header {
+    browsers: Vec<Browser>,
+    track: Option<Track>,
+}
+
+impl Header {
+    /// Creates a BED header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{header::Track, Header};
+    /// let header = Header::new(Vec::new(), Some(Track::default()));
+    /// ```
+    pub fn new(browsers: Vec<Browser>, track: Option<Track>) -> Self {
+        Self { browsers, track }
+    }
+
+    /// Returns the browser lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::Header;
+    /// let header = Header::default();
+    /// assert!(header.browsers().is_empty());
+    /// ```
+    pub fn browsers(&self) -> &[Browser] {
+        &self.browsers
+    }
+
+    /// Returns a mutable reference to the browser lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{header::Browser, Header};
+    ///
+    /// let mut header = Header::default();
+    /// header
+    ///     .browsers_mut()
+    ///     .push(Browser::new("position", vec![String::from("sq0:8-13")]));
+    ///
+    /// assert_eq!(header.browsers().len(), 1);
+    /// ```
+    pub fn browsers_mut(&mut self) -> &mut Vec<Browser> {
+        &mut self.browsers
+    }
+
+    /// Returns the track line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::Header;
+    /// let header = Header::default();
+    /// assert!(header.track().is_none());
+    /// ```
+    pub fn track(&self) -> Option<&Track> {
+        self.track.as_ref()
+    }
+
+    /// Returns a mutable reference to the track line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{header::Track, Header};
+    ///
+    /// let mut header = Header::default();
+    /// *header.track_mut() = Some(Track::default());
+    ///
+    /// assert!(header.track().is_some());
+    /// ```
+    pub fn track_mut(&mut self) -> &mut Option<Track> {
+        &mut self.track
+    }
+
+    /// Returns whether there are no browser lines or track line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::Header;
+    /// let header = Header::default();
+    /// assert!(header.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.browsers.is_empty() && self.track.is_none()
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for browser in &self.browsers {
+            writeln!(f, "{}", browser)?;
+        }
+
+        if let Some(track) = self.track() {
+            writeln!(f, "{}", track)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert!(Header::default().to_string().is_empty());
+
+        let mut track = Track::default();
+        *track.name_mut() = Some(String::from("ndls"));
+
+        let header = Header::new(
+            vec![
+                Browser::new("position", vec![String::from("sq0:8-13")]),
+                Browser::new("hide", vec![String::from("all")]),
+            ],
+            Some(track),
+        );
+
+        assert_eq!(
+            header.to_string(),
+            "browser position sq0:8-13\nbrowser hide all\ntrack name=ndls\n"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! BED header browser line.
+
+use std::{error, fmt, str::FromStr};
+
+pub(crate) const PREFIX: &str = "browser";
+
+/// A BED header browser line.
+///
+/// A browser line sets an option of the genome browser, e.g., `browser position sq0:8-13` or
+/// `browser hide all`. It is a name followed by a list of values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Browser {
+    name: String,
+    values: Vec<String>,
+}
+
+impl Browser {
+    /// Creates a BED header browser line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Browser;
+    /// let browser = Browser::new("position", vec![String::from("sq0:8-13")]);
+    /// ```
+    pub fn new<N>(name: N, values: Vec<String>) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            values,
+        }
+    }
+
+    /// Returns the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Browser;
+    /// let browser = Browser::new("position", vec![String::from("sq0:8-13")]);
+    /// assert_eq!(browser.name(), "position");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Browser;
+    /// let browser = Browser::new("position", vec![String::from("sq0:8-13")]);
+    /// assert_eq!(browser.values(), [String::from("sq0:8-13")]);
+    /// ```
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+}
+
+impl fmt::Display for Browser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", PREFIX, self.name)?;
+
+        for value in &self.values {
+            write!(f, " {}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw BED header browser line fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The prefix (`browser`) is missing.
+    MissingPrefix,
+    /// The name is missing.
+    MissingName,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => write!(f, "missing prefix ('{}')", PREFIX),
+            Self::MissingName => f.write_str("missing name"),
+        }
+    }
+}
+
+impl FromStr for Browser {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split_ascii_whitespace();
+
+        if components.next() != Some(PREFIX) {
+            return Err(ParseError::MissingPrefix);
+        }
+
+        let name = components.next().ok_or(ParseError::MissingName)?;
+        let values = components.map(String::from).collect();
+
+        Ok(Self::new(name, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let browser = Browser::new("position", vec![String::from("sq0:8-13")]);
+        assert_eq!(browser.to_string(), "browser position sq0:8-13");
+
+        let browser = Browser::new("dense", vec![String::from("ndls0"), String::from("ndls1")]);
+        assert_eq!(browser.to_string(), "browser dense ndls0 ndls1");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "browser position sq0:8-13".parse(),
+            Ok(Browser::new("position", vec![String::from("sq0:8-13")]))
+        );
+
+        assert_eq!(
+            "browser  hide\tall".parse(),
+            Ok(Browser::new("hide", vec![String::from("all")]))
+        );
+
+        assert_eq!("".parse::<Browser>(), Err(ParseError::MissingPrefix));
+        assert_eq!(
+            "track name=ndls".parse::<Browser>(),
+            Err(ParseError::MissingPrefix)
+        );
+        assert_eq!("browser".parse::<Browser>(), Err(ParseError::MissingName));
+    }
+}
@@ -0,0 +1,493 @@
+//! BED header track line.
+
+pub mod visibility;
+
+pub use self::visibility::Visibility;
+
+use std::{error, fmt, str::FromStr};
+
+use crate::record::{color, Color};
+
+pub(crate) const PREFIX: &str = "track";
+
+const NAME: &str = "name";
+const DESCRIPTION: &str = "description";
+const VISIBILITY: &str = "visibility";
+const COLOR: &str = "color";
+const ITEM_RGB: &str = "itemRgb";
+const USE_SCORE: &str = "useScore";
+
+/// A BED header track line.
+///
+/// A track line is a list of `key=value` attributes that define the display of the records that
+/// follow it, e.g., `track name=ndls description="noodles" itemRgb=On`. Values with whitespace are
+/// quoted.
+///
+/// Common attributes are parsed into typed fields. All other attributes are kept, in order, as raw
+/// key-value pairs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Track {
+    name: Option<String>,
+    description: Option<String>,
+    visibility: Option<Visibility>,
+    color: Option<Color>,
+    item_rgb: bool,
+    use_score: bool,
+    other_fields: Vec<(String, String)>,
+}
+
+impl Track {
+    /// Returns the track name (`name`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let track: Track = "track name=ndls".parse()?;
+    /// assert_eq!(track.name(), Some("ndls"));
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns a mutable reference to the track name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let mut track = Track::default();
+    /// *track.name_mut() = Some(String::from("ndls"));
+    /// assert_eq!(track.name(), Some("ndls"));
+    /// ```
+    pub fn name_mut(&mut self) -> &mut Option<String> {
+        &mut self.name
+    }
+
+    /// Returns the track description (`description`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let track: Track = "track description=\"noodles track\"".parse()?;
+    /// assert_eq!(track.description(), Some("noodles track"));
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns a mutable reference to the track description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let mut track = Track::default();
+    /// *track.description_mut() = Some(String::from("noodles track"));
+    /// assert_eq!(track.description(), Some("noodles track"));
+    /// ```
+    pub fn description_mut(&mut self) -> &mut Option<String> {
+        &mut self.description
+    }
+
+    /// Returns the initial display mode (`visibility`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::{track::Visibility, Track};
+    /// let track: Track = "track visibility=2".parse()?;
+    /// assert_eq!(track.visibility(), Some(Visibility::Full));
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn visibility(&self) -> Option<Visibility> {
+        self.visibility
+    }
+
+    /// Returns a mutable reference to the initial display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::{track::Visibility, Track};
+    /// let mut track = Track::default();
+    /// *track.visibility_mut() = Some(Visibility::Pack);
+    /// assert_eq!(track.visibility(), Some(Visibility::Pack));
+    /// ```
+    pub fn visibility_mut(&mut self) -> &mut Option<Visibility> {
+        &mut self.visibility
+    }
+
+    /// Returns the default color of the records (`color`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{header::Track, record::Color};
+    /// let track: Track = "track color=255,0,0".parse()?;
+    /// assert_eq!(track.color(), Some(Color::RED));
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Returns a mutable reference to the default color of the records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{header::Track, record::Color};
+    /// let mut track = Track::default();
+    /// *track.color_mut() = Some(Color::BLUE);
+    /// assert_eq!(track.color(), Some(Color::BLUE));
+    /// ```
+    pub fn color_mut(&mut self) -> &mut Option<Color> {
+        &mut self.color
+    }
+
+    /// Returns whether the record item RGB field is used to color records (`itemRgb=On`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    ///
+    /// let track: Track = "track itemRgb=On".parse()?;
+    /// assert!(track.item_rgb());
+    ///
+    /// let track: Track = "track name=ndls".parse()?;
+    /// assert!(!track.item_rgb());
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn item_rgb(&self) -> bool {
+        self.item_rgb
+    }
+
+    /// Returns a mutable reference to whether the record item RGB field is used to color records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let mut track = Track::default();
+    /// *track.item_rgb_mut() = true;
+    /// assert!(track.item_rgb());
+    /// ```
+    pub fn item_rgb_mut(&mut self) -> &mut bool {
+        &mut self.item_rgb
+    }
+
+    /// Returns whether the record score is used to shade records (`useScore=1`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let track: Track = "track useScore=1".parse()?;
+    /// assert!(track.use_score());
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn use_score(&self) -> bool {
+        self.use_score
+    }
+
+    /// Returns a mutable reference to whether the record score is used to shade records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let mut track = Track::default();
+    /// *track.use_score_mut() = true;
+    /// assert!(track.use_score());
+    /// ```
+    pub fn use_score_mut(&mut self) -> &mut bool {
+        &mut self.use_score
+    }
+
+    /// Returns the attributes that are not parsed into typed fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    /// let track: Track = "track name=ndls type=bedDetail".parse()?;
+    /// assert_eq!(
+    ///     track.other_fields(),
+    ///     [(String::from("type"), String::from("bedDetail"))]
+    /// );
+    /// # Ok::<_, noodles_bed::header::track::ParseError>(())
+    /// ```
+    pub fn other_fields(&self) -> &[(String, String)] {
+        &self.other_fields
+    }
+
+    /// Returns a mutable reference to the attributes that are not parsed into typed fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::header::Track;
+    ///
+    /// let mut track = Track::default();
+    /// track
+    ///     .other_fields_mut()
+    ///     .push((String::from("type"), String::from("bedDetail")));
+    ///
+    /// assert_eq!(track.to_string(), "track type=bedDetail");
+    /// ```
+    pub fn other_fields_mut(&mut self) -> &mut Vec<(String, String)> {
+        &mut self.other_fields
+    }
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(PREFIX)?;
+
+        if let Some(name) = self.name() {
+            write_field(f, NAME, name)?;
+        }
+
+        if let Some(description) = self.description() {
+            write_field(f, DESCRIPTION, description)?;
+        }
+
+        if let Some(visibility) = self.visibility() {
+            write_field(f, VISIBILITY, visibility.as_ref())?;
+        }
+
+        if let Some(color) = self.color() {
+            write_field(f, COLOR, &color.to_string())?;
+        }
+
+        if self.item_rgb() {
+            write_field(f, ITEM_RGB, "On")?;
+        }
+
+        if self.use_score() {
+            write_field(f, USE_SCORE, "1")?;
+        }
+
+        for (key, value) in self.other_fields() {
+            write_field(f, key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_field(f: &mut fmt::Formatter<'_>, key: &str, value: &str) -> fmt::Result {
+    if value.is_empty() || value.contains(|c: char| c.is_ascii_whitespace()) {
+        write!(f, " {}=\"{}\"", key, value)
+    } else {
+        write!(f, " {}={}", key, value)
+    }
+}
+
+/// An error returned when a raw BED header track line fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The prefix (`track`) is missing.
+    MissingPrefix,
+    /// A field is invalid.
+    ///
+    /// A field is expected to be `key=value`, where the value is optionally quoted.
+    InvalidField(String),
+    /// The visibility is invalid.
+    InvalidVisibility(visibility::ParseError),
+    /// The color is invalid.
+    InvalidColor(color::ParseError),
+    /// The item RGB flag is invalid.
+    InvalidItemRgb(String),
+    /// The use score flag is invalid.
+    InvalidUseScore(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => write!(f, "missing prefix ('{}')", PREFIX),
+            Self::InvalidField(s) => write!(f, "invalid field: {}", s),
+            Self::InvalidVisibility(e) => write!(f, "invalid visibility: {}", e),
+            Self::InvalidColor(e) => write!(f, "invalid color: {}", e),
+            Self::InvalidItemRgb(s) => write!(f, "invalid itemRgb: {}", s),
+            Self::InvalidUseScore(s) => write!(f, "invalid useScore: {}", s),
+        }
+    }
+}
+
+impl FromStr for Track {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s
+            .strip_prefix(PREFIX)
+            .filter(|t| t.is_empty() || t.starts_with(|c: char| c.is_ascii_whitespace()))
+            .ok_or(ParseError::MissingPrefix)?;
+
+        let mut track = Self::default();
+
+        loop {
+            s = s.trim_start();
+
+            if s.is_empty() {
+                break;
+            }
+
+            let (key, value, rest) = parse_field(s)?;
+
+            match key {
+                NAME => track.name = Some(value.into()),
+                DESCRIPTION => track.description = Some(value.into()),
+                VISIBILITY => {
+                    track.visibility = value
+                        .parse()
+                        .map(Some)
+                        .map_err(ParseError::InvalidVisibility)?;
+                }
+                COLOR => {
+                    track.color = value.parse().map(Some).map_err(ParseError::InvalidColor)?;
+                }
+                ITEM_RGB => {
+                    track.item_rgb = if value.eq_ignore_ascii_case("on") {
+                        true
+                    } else if value.eq_ignore_ascii_case("off") {
+                        false
+                    } else {
+                        return Err(ParseError::InvalidItemRgb(value.into()));
+                    };
+                }
+                USE_SCORE => {
+                    track.use_score = match value {
+                        "1" => true,
+                        "0" => false,
+                        _ => return Err(ParseError::InvalidUseScore(value.into())),
+                    };
+                }
+                _ => track.other_fields.push((key.into(), value.into())),
+            }
+
+            s = rest;
+        }
+
+        Ok(track)
+    }
+}
+
+// Parses a `key=value` field, returning the key, value, and the remaining input.
+fn parse_field(s: &str) -> Result<(&str, &str, &str), ParseError> {
+    let invalid_field = || {
+        let raw_field = s.split_ascii_whitespace().next().unwrap_or_default();
+        ParseError::InvalidField(raw_field.into())
+    };
+
+    let (key, s) = s.split_once('=').ok_or_else(invalid_field)?;
+
+    if key.is_empty() || key.contains(|c: char| c.is_ascii_whitespace()) {
+        return Err(invalid_field());
+    }
+
+    if let Some(t) = s.strip_prefix('"') {
+        let i = t.find('"').ok_or_else(invalid_field)?;
+        Ok((key, &t[..i], &t[i + 1..]))
+    } else {
+        let i = s.find(|c: char| c.is_ascii_whitespace()).unwrap_or(s.len());
+        Ok((key, &s[..i], &s[i..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Track::default().to_string(), "track");
+
+        let track = Track {
+            name: Some(String::from("ndls")),
+            description: Some(String::from("noodles track")),
+            visibility: Some(Visibility::Full),
+            color: Some(Color::RED),
+            item_rgb: true,
+            use_score: true,
+            other_fields: vec![(String::from("type"), String::from("bedDetail"))],
+        };
+
+        assert_eq!(
+            track.to_string(),
+            "track name=ndls description=\"noodles track\" visibility=full color=255,0,0 itemRgb=On useScore=1 type=bedDetail"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("track".parse(), Ok(Track::default()));
+
+        assert_eq!(
+            "track name=ndls description=\"noodles track\" visibility=2 color=255,0,0 itemRgb=\"On\" useScore=1 type=bedDetail".parse(),
+            Ok(Track {
+                name: Some(String::from("ndls")),
+                description: Some(String::from("noodles track")),
+                visibility: Some(Visibility::Full),
+                color: Some(Color::RED),
+                item_rgb: true,
+                use_score: true,
+                other_fields: vec![(String::from("type"), String::from("bedDetail"))],
+            })
+        );
+
+        assert_eq!("".parse::<Track>(), Err(ParseError::MissingPrefix));
+        assert_eq!(
+            "trackname=ndls".parse::<Track>(),
+            Err(ParseError::MissingPrefix)
+        );
+        assert_eq!(
+            "track ndls".parse::<Track>(),
+            Err(ParseError::InvalidField(String::from("ndls")))
+        );
+        assert_eq!(
+            "track =ndls".parse::<Track>(),
+            Err(ParseError::InvalidField(String::from("=ndls")))
+        );
+        assert_eq!(
+            "track description=\"noodles".parse::<Track>(),
+            Err(ParseError::InvalidField(String::from(
+                "description=\"noodles"
+            )))
+        );
+        assert_eq!(
+            "track visibility=ndls".parse::<Track>(),
+            Err(ParseError::InvalidVisibility(
+                visibility::ParseError::Invalid
+            ))
+        );
+        assert!(matches!(
+            "track color=red".parse::<Track>(),
+            Err(ParseError::InvalidColor(_))
+        ));
+        assert_eq!(
+            "track itemRgb=yes".parse::<Track>(),
+            Err(ParseError::InvalidItemRgb(String::from("yes")))
+        );
+        assert_eq!(
+            "track useScore=2".parse::<Track>(),
+            Err(ParseError::InvalidUseScore(String::from("2")))
+        );
+    }
+
+    #[test]
+    fn test_fmt_and_from_str_round_trip() -> Result<(), ParseError> {
+        let s = "track name=ndls description=\"noodles track\" visibility=pack itemRgb=On";
+        let track: Track = s.parse()?;
+        assert_eq!(track.to_string(), s);
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+//! BED header track line visibility.
+
+use std::{error, fmt, str::FromStr};
+
+/// A BED header track line visibility.
+///
+/// This is the initial display mode of the track.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Visibility {
+    /// The track is not displayed (`hide` or `0`).
+    Hide,
+    /// Features are collapsed into a single row (`dense` or `1`).
+    Dense,
+    /// Each feature is displayed on its own row (`full` or `2`).
+    Full,
+    /// Features are packed into as few rows as possible (`pack` or `3`).
+    Pack,
+    /// Features are packed and displayed at a reduced height (`squish` or `4`).
+    Squish,
+}
+
+impl AsRef<str> for Visibility {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Hide => "hide",
+            Self::Dense => "dense",
+            Self::Full => "full",
+            Self::Pack => "pack",
+            Self::Squish => "squish",
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw BED header track line visibility fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "hide" | "0" => Ok(Self::Hide),
+            "dense" | "1" => Ok(Self::Dense),
+            "full" | "2" => Ok(Self::Full),
+            "pack" | "3" => Ok(Self::Pack),
+            "squish" | "4" => Ok(Self::Squish),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Visibility::Hide.to_string(), "hide");
+        assert_eq!(Visibility::Dense.to_string(), "dense");
+        assert_eq!(Visibility::Full.to_string(), "full");
+        assert_eq!(Visibility::Pack.to_string(), "pack");
+        assert_eq!(Visibility::Squish.to_string(), "squish");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("hide".parse(), Ok(Visibility::Hide));
+        assert_eq!("0".parse(), Ok(Visibility::Hide));
+        assert_eq!("dense".parse(), Ok(Visibility::Dense));
+        assert_eq!("1".parse(), Ok(Visibility::Dense));
+        assert_eq!("full".parse(), Ok(Visibility::Full));
+        assert_eq!("2".parse(), Ok(Visibility::Full));
+        assert_eq!("pack".parse(), Ok(Visibility::Pack));
+        assert_eq!("3".parse(), Ok(Visibility::Pack));
+        assert_eq!("squish".parse(), Ok(Visibility::Squish));
+        assert_eq!("4".parse(), Ok(Visibility::Squish));
+
+        assert_eq!("".parse::<Visibility>(), Err(ParseError::Empty));
+        assert_eq!("5".parse::<Visibility>(), Err(ParseError::Invalid));
+        assert_eq!("ndls".parse::<Visibility>(), Err(ParseError::Invalid));
+    }
+}
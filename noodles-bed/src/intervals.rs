@@ -0,0 +1,632 @@
+//! BED interval set operations.
+//!
+//! These are bedtools-style operations over collections of intervals: merge, intersect,
+//! subtract, and complement. Intervals are grouped by reference sequence and, optionally, strand.
+//!
+//! The results are sets, i.e., they are sorted and nonoverlapping within each group. Groups are
+//! ordered by first appearance in the input.
+
+use std::collections::HashMap;
+
+use noodles_core::Position;
+
+use crate::{
+    record::{BedN, Strand},
+    Record,
+};
+
+/// A BED interval.
+///
+/// This is a reference sequence name, a 1-based, inclusive start and end position, and an
+/// optional strand.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Interval {
+    reference_sequence_name: String,
+    start: Position,
+    end: Position,
+    strand: Option<Strand>,
+}
+
+impl Interval {
+    /// Creates a BED interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::intervals::Interval;
+    /// use noodles_core::Position;
+    ///
+    /// let interval = Interval::new(
+    ///     "sq0",
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     None,
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new<N>(
+        reference_sequence_name: N,
+        start: Position,
+        end: Position,
+        strand: Option<Strand>,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            reference_sequence_name: reference_sequence_name.into(),
+            start,
+            end,
+            strand,
+        }
+    }
+
+    /// Creates a BED interval from a record.
+    ///
+    /// The strand is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, intervals::Interval};
+    /// use noodles_core::Position;
+    ///
+    /// let record: bed::Record<3> = "sq0\t7\t13".parse()?;
+    /// let interval = Interval::from_record(&record);
+    ///
+    /// assert_eq!(interval.reference_sequence_name(), "sq0");
+    /// assert_eq!(interval.start(), Position::try_from(8)?);
+    /// assert_eq!(interval.end(), Position::try_from(13)?);
+    /// assert!(interval.strand().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_record<const N: u8>(record: &Record<N>) -> Self
+    where
+        Record<N>: BedN<3>,
+    {
+        Self::new(
+            record.reference_sequence_name(),
+            record.start_position(),
+            record.end_position(),
+            None,
+        )
+    }
+
+    /// Creates a BED interval from a record with a strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, intervals::Interval, record::Strand};
+    ///
+    /// let record: bed::Record<6> = "sq0\t7\t13\tndls1\t0\t-".parse()?;
+    /// let interval = Interval::from_stranded_record(&record);
+    ///
+    /// assert_eq!(interval.strand(), Some(Strand::Reverse));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_stranded_record<const N: u8>(record: &Record<N>) -> Self
+    where
+        Record<N>: BedN<3> + BedN<6>,
+    {
+        Self::new(
+            record.reference_sequence_name(),
+            record.start_position(),
+            record.end_position(),
+            record.strand(),
+        )
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+}
+
+// A 1-based, inclusive range.
+type Range = (usize, usize);
+
+// A group of ranges on the same reference sequence and, optionally, strand.
+struct Group {
+    reference_sequence_name: String,
+    strand: Option<Strand>,
+    ranges: Vec<Range>,
+}
+
+/// Merges overlapping, adjacent, or nearby intervals.
+///
+/// Intervals that are at most `distance` bases apart are merged. A distance of 0 merges
+/// overlapping and book-ended intervals. If `stranded` is true, only intervals on the same strand
+/// are merged; otherwise, the strand of the results is not set.
+///
+/// This is similar to `bedtools merge -d <distance> [-s]`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::intervals::{self, Interval};
+/// use noodles_core::Position;
+///
+/// let intervals = [
+///     Interval::new("sq0", Position::try_from(21)?, Position::try_from(34)?, None),
+///     Interval::new("sq0", Position::try_from(8)?, Position::try_from(13)?, None),
+///     Interval::new("sq0", Position::try_from(13)?, Position::try_from(18)?, None),
+/// ];
+///
+/// assert_eq!(
+///     intervals::merge(intervals.clone(), 0, false),
+///     [
+///         Interval::new("sq0", Position::try_from(8)?, Position::try_from(18)?, None),
+///         Interval::new("sq0", Position::try_from(21)?, Position::try_from(34)?, None),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     intervals::merge(intervals, 2, false),
+///     [Interval::new("sq0", Position::try_from(8)?, Position::try_from(34)?, None)]
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn merge<I>(intervals: I, distance: usize, stranded: bool) -> Vec<Interval>
+where
+    I: IntoIterator<Item = Interval>,
+{
+    let mut groups = group(intervals, stranded);
+
+    for group in &mut groups {
+        group.ranges = union(&group.ranges, distance);
+    }
+
+    ungroup(groups)
+}
+
+/// Returns the regions covered by both sets of intervals.
+///
+/// If `stranded` is true, only intervals on the same strand intersect.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::intervals::{self, Interval};
+/// use noodles_core::Position;
+///
+/// let a = [Interval::new("sq0", Position::try_from(8)?, Position::try_from(21)?, None)];
+/// let b = [
+///     Interval::new("sq0", Position::try_from(5)?, Position::try_from(10)?, None),
+///     Interval::new("sq0", Position::try_from(13)?, Position::try_from(34)?, None),
+/// ];
+///
+/// assert_eq!(
+///     intervals::intersect(a, b, false),
+///     [
+///         Interval::new("sq0", Position::try_from(8)?, Position::try_from(10)?, None),
+///         Interval::new("sq0", Position::try_from(13)?, Position::try_from(21)?, None),
+///     ]
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn intersect<I, J>(a: I, b: J, stranded: bool) -> Vec<Interval>
+where
+    I: IntoIterator<Item = Interval>,
+    J: IntoIterator<Item = Interval>,
+{
+    combine(a, b, stranded, intersect_ranges)
+}
+
+/// Returns the regions covered by the first set of intervals but not the second.
+///
+/// If `stranded` is true, only intervals on the same strand are subtracted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::intervals::{self, Interval};
+/// use noodles_core::Position;
+///
+/// let a = [Interval::new("sq0", Position::try_from(8)?, Position::try_from(21)?, None)];
+/// let b = [Interval::new("sq0", Position::try_from(13)?, Position::try_from(15)?, None)];
+///
+/// assert_eq!(
+///     intervals::subtract(a, b, false),
+///     [
+///         Interval::new("sq0", Position::try_from(8)?, Position::try_from(12)?, None),
+///         Interval::new("sq0", Position::try_from(16)?, Position::try_from(21)?, None),
+///     ]
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn subtract<I, J>(a: I, b: J, stranded: bool) -> Vec<Interval>
+where
+    I: IntoIterator<Item = Interval>,
+    J: IntoIterator<Item = Interval>,
+{
+    combine(a, b, stranded, subtract_ranges)
+}
+
+/// Returns the regions of the reference sequences that are not covered by any interval.
+///
+/// Reference sequences are (name, length) pairs, and the results are in the same order.
+/// Intervals on other reference sequences are ignored. Strands are ignored.
+///
+/// This is similar to `bedtools complement`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bed::intervals::{self, Interval};
+/// use noodles_core::Position;
+///
+/// let intervals = [Interval::new("sq0", Position::try_from(8)?, Position::try_from(13)?, None)];
+///
+/// assert_eq!(
+///     intervals::complement(intervals, &[("sq0", 21), ("sq1", 8)]),
+///     [
+///         Interval::new("sq0", Position::try_from(1)?, Position::try_from(7)?, None),
+///         Interval::new("sq0", Position::try_from(14)?, Position::try_from(21)?, None),
+///         Interval::new("sq1", Position::try_from(1)?, Position::try_from(8)?, None),
+///     ]
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn complement<I, N>(intervals: I, reference_sequences: &[(N, usize)]) -> Vec<Interval>
+where
+    I: IntoIterator<Item = Interval>,
+    N: AsRef<str>,
+{
+    let groups = group(intervals, false);
+
+    let mut ranges_by_name: HashMap<String, Vec<Range>> = groups
+        .into_iter()
+        .map(|group| (group.reference_sequence_name, group.ranges))
+        .collect();
+
+    let mut complement_groups = Vec::with_capacity(reference_sequences.len());
+
+    for (name, len) in reference_sequences {
+        let name = name.as_ref();
+        let ranges = ranges_by_name.remove(name).unwrap_or_default();
+
+        let mut ranges = subtract_ranges(&[(1, *len)], &union(&ranges, 0));
+        ranges.retain(|&(start, end)| start <= end);
+
+        complement_groups.push(Group {
+            reference_sequence_name: name.into(),
+            strand: None,
+            ranges,
+        });
+    }
+
+    ungroup(complement_groups)
+}
+
+fn combine<I, J, F>(a: I, b: J, stranded: bool, f: F) -> Vec<Interval>
+where
+    I: IntoIterator<Item = Interval>,
+    J: IntoIterator<Item = Interval>,
+    F: Fn(&[Range], &[Range]) -> Vec<Range>,
+{
+    let mut a_groups = group(a, stranded);
+
+    let b_groups: HashMap<_, _> = group(b, stranded)
+        .into_iter()
+        .map(|group| {
+            let key = (group.reference_sequence_name, strand_key(group.strand));
+            (key, union(&group.ranges, 0))
+        })
+        .collect();
+
+    for group in &mut a_groups {
+        let key = (
+            group.reference_sequence_name.clone(),
+            strand_key(group.strand),
+        );
+
+        let a_ranges = union(&group.ranges, 0);
+
+        group.ranges = match b_groups.get(&key) {
+            Some(b_ranges) => f(&a_ranges, b_ranges),
+            None => f(&a_ranges, &[]),
+        };
+    }
+
+    ungroup(a_groups)
+}
+
+fn strand_key(strand: Option<Strand>) -> u8 {
+    match strand {
+        None => 0,
+        Some(Strand::Forward) => 1,
+        Some(Strand::Reverse) => 2,
+    }
+}
+
+fn group<I>(intervals: I, stranded: bool) -> Vec<Group>
+where
+    I: IntoIterator<Item = Interval>,
+{
+    let mut groups: Vec<Group> = Vec::new();
+    let mut indices: HashMap<(String, u8), usize> = HashMap::new();
+
+    for interval in intervals {
+        let strand = if stranded { interval.strand } else { None };
+        let range = (usize::from(interval.start), usize::from(interval.end));
+
+        let key = (interval.reference_sequence_name, strand_key(strand));
+
+        if let Some(&i) = indices.get(&key) {
+            groups[i].ranges.push(range);
+        } else {
+            indices.insert(key.clone(), groups.len());
+
+            groups.push(Group {
+                reference_sequence_name: key.0,
+                strand,
+                ranges: vec![range],
+            });
+        }
+    }
+
+    groups
+}
+
+fn ungroup(groups: Vec<Group>) -> Vec<Interval> {
+    let mut intervals = Vec::new();
+
+    for group in groups {
+        for (start, end) in group.ranges {
+            // Ranges are 1-based, so both positions are always valid.
+            if let (Some(start), Some(end)) = (Position::new(start), Position::new(end)) {
+                intervals.push(Interval::new(
+                    group.reference_sequence_name.clone(),
+                    start,
+                    end,
+                    group.strand,
+                ));
+            }
+        }
+    }
+
+    intervals
+}
+
+// Sorts and merges ranges that are at most `distance` bases apart.
+fn union(ranges: &[Range], distance: usize) -> Vec<Range> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable();
+
+    let mut merged_ranges: Vec<Range> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        if let Some(last) = merged_ranges.last_mut() {
+            if start <= last.1.saturating_add(distance).saturating_add(1) {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+
+        merged_ranges.push((start, end));
+    }
+
+    merged_ranges
+}
+
+// Both lists of ranges are expected to be sorted and nonoverlapping.
+fn intersect_ranges(a: &[Range], b: &[Range]) -> Vec<Range> {
+    let mut ranges = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+
+        if start <= end {
+            ranges.push((start, end));
+        }
+
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    ranges
+}
+
+// Both lists of ranges are expected to be sorted and nonoverlapping.
+fn subtract_ranges(a: &[Range], b: &[Range]) -> Vec<Range> {
+    let mut ranges = Vec::new();
+    let mut j = 0;
+
+    for &(a_start, a_end) in a {
+        let mut start = a_start;
+
+        while j < b.len() && b[j].1 < start {
+            j += 1;
+        }
+
+        let mut k = j;
+
+        while k < b.len() && b[k].0 <= a_end {
+            if b[k].0 > start {
+                ranges.push((start, b[k].0 - 1));
+            }
+
+            start = start.max(b[k].1 + 1);
+            k += 1;
+        }
+
+        if start <= a_end {
+            ranges.push((start, a_end));
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(
+        name: &str,
+        start: usize,
+        end: usize,
+        strand: Option<Strand>,
+    ) -> Result<Interval, noodles_core::position::TryFromIntError> {
+        Ok(Interval::new(
+            name,
+            Position::try_from(start)?,
+            Position::try_from(end)?,
+            strand,
+        ))
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), noodles_core::position::TryFromIntError> {
+        let intervals = [
+            interval("sq0", 8, 13, Some(Strand::Forward))?,
+            interval("sq1", 1, 5, None)?,
+            interval("sq0", 10, 21, Some(Strand::Reverse))?,
+            interval("sq0", 22, 34, Some(Strand::Forward))?,
+        ];
+
+        assert_eq!(
+            merge(intervals.clone(), 0, false),
+            [interval("sq0", 8, 34, None)?, interval("sq1", 1, 5, None)?,]
+        );
+
+        assert_eq!(
+            merge(intervals.clone(), 8, true),
+            [
+                interval("sq0", 8, 34, Some(Strand::Forward))?,
+                interval("sq1", 1, 5, None)?,
+                interval("sq0", 10, 21, Some(Strand::Reverse))?,
+            ]
+        );
+
+        assert_eq!(
+            merge(intervals, 0, true),
+            [
+                interval("sq0", 8, 13, Some(Strand::Forward))?,
+                interval("sq0", 22, 34, Some(Strand::Forward))?,
+                interval("sq1", 1, 5, None)?,
+                interval("sq0", 10, 21, Some(Strand::Reverse))?,
+            ]
+        );
+
+        assert!(merge(Vec::new(), 0, false).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() -> Result<(), noodles_core::position::TryFromIntError> {
+        let a = [
+            interval("sq0", 8, 21, Some(Strand::Forward))?,
+            interval("sq1", 1, 5, None)?,
+        ];
+
+        let b = [
+            interval("sq0", 13, 34, Some(Strand::Reverse))?,
+            interval("sq2", 1, 5, None)?,
+        ];
+
+        assert_eq!(
+            intersect(a.clone(), b.clone(), false),
+            [interval("sq0", 13, 21, None)?]
+        );
+
+        assert!(intersect(a, b, true).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subtract() -> Result<(), noodles_core::position::TryFromIntError> {
+        let a = [
+            interval("sq0", 8, 21, Some(Strand::Forward))?,
+            interval("sq0", 30, 34, Some(Strand::Forward))?,
+            interval("sq1", 1, 5, None)?,
+        ];
+
+        let b = [
+            interval("sq0", 5, 8, Some(Strand::Reverse))?,
+            interval("sq0", 13, 14, Some(Strand::Forward))?,
+            interval("sq0", 21, 40, Some(Strand::Forward))?,
+        ];
+
+        assert_eq!(
+            subtract(a.clone(), b.clone(), false),
+            [
+                interval("sq0", 9, 12, None)?,
+                interval("sq0", 15, 20, None)?,
+                interval("sq1", 1, 5, None)?,
+            ]
+        );
+
+        assert_eq!(
+            subtract(a, b, true),
+            [
+                interval("sq0", 8, 12, Some(Strand::Forward))?,
+                interval("sq0", 15, 20, Some(Strand::Forward))?,
+                interval("sq1", 1, 5, None)?,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement() -> Result<(), noodles_core::position::TryFromIntError> {
+        let intervals = [
+            interval("sq0", 1, 5, None)?,
+            interval("sq0", 8, 13, Some(Strand::Forward))?,
+            interval("sq0", 10, 21, Some(Strand::Reverse))?,
+            interval("sq1", 1, 8, None)?,
+            interval("sq2", 1, 8, None)?,
+        ];
+
+        assert_eq!(
+            complement(intervals, &[("sq0", 21), ("sq1", 8), ("sq3", 5)]),
+            [interval("sq0", 6, 7, None)?, interval("sq3", 1, 5, None)?,]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() {
+        assert_eq!(union(&[(8, 13), (1, 5), (14, 21)], 0), [(1, 5), (8, 21)]);
+        assert_eq!(union(&[(8, 13), (1, 5), (14, 21)], 2), [(1, 21)]);
+        assert_eq!(union(&[(1, 21), (8, 13)], 0), [(1, 21)]);
+    }
+
+    #[test]
+    fn test_subtract_ranges() {
+        assert_eq!(
+            subtract_ranges(&[(1, 10)], &[(3, 4), (6, 6)]),
+            [(1, 2), (5, 5), (7, 10)]
+        );
+        assert_eq!(subtract_ranges(&[(1, 10)], &[(1, 10)]), []);
+        assert_eq!(
+            subtract_ranges(&[(1, 5), (8, 10)], &[(4, 9)]),
+            [(1, 3), (10, 10)]
+        );
+    }
+}
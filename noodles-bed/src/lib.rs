@@ -2,8 +2,16 @@
 
 //! **noodles-bed** handles the reading and writing of the BED (Browser Extensible Data) format.
 
-mod reader;
+mod bbi;
+pub mod big_bed;
+pub mod big_wig;
+pub mod header;
+pub mod intervals;
+pub mod name_index;
+pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    header::Header, name_index::NameIndex, reader::Reader, record::Record, writer::Writer,
+};
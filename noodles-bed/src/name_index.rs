@@ -0,0 +1,191 @@
+//! BED name index.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::collections::HashMap;
+
+use super::{record::BedN, Record};
+
+/// An in-memory BED name index.
+///
+/// A name index maps record names to the records with that name. Names are not necessarily
+/// unique, so each name maps to a list of records in the order they were added.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NameIndex<const N: u8> {
+    records: HashMap<String, Vec<Record<N>>>,
+    unnamed_record_count: usize,
+}
+
+impl<const N: u8> NameIndex<N>
+where
+    Record<N>: BedN<3> + BedN<4>,
+{
+    /// Returns a builder to create a name index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::NameIndex;
+    /// let builder = NameIndex::<4>::builder();
+    /// ```
+    pub fn builder() -> Builder<N> {
+        Builder::default()
+    }
+
+    /// Returns the records with the given name.
+    ///
+    /// This returns `None` if no records have the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let records: Vec<bed::Record<4>> = vec![
+    ///     "sq0\t7\t13\tndls0".parse()?,
+    ///     "sq0\t20\t34\tndls1".parse()?,
+    ///     "sq1\t7\t13\tndls0".parse()?,
+    /// ];
+    ///
+    /// let index: NameIndex<4> = records.into_iter().collect();
+    ///
+    /// assert_eq!(index.get("ndls0").map(|records| records.len()), Some(2));
+    /// assert_eq!(index.get("ndls1").map(|records| records.len()), Some(1));
+    /// assert!(index.get("ndls2").is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&[Record<N>]> {
+        self.records.get(name).map(|records| records.as_slice())
+    }
+
+    /// Returns whether any records have the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let record: bed::Record<4> = "sq0\t7\t13\tndls0".parse()?;
+    /// let index: NameIndex<4> = [record].into_iter().collect();
+    ///
+    /// assert!(index.contains("ndls0"));
+    /// assert!(!index.contains("ndls1"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contains(&self, name: &str) -> bool {
+        self.records.contains_key(name)
+    }
+
+    /// Returns the number of distinct names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::NameIndex;
+    /// let index = NameIndex::<4>::default();
+    /// assert_eq!(index.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the index has no names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::NameIndex;
+    /// let index = NameIndex::<4>::default();
+    /// assert!(index.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns an iterator over the names in the index.
+    ///
+    /// The order of the names is arbitrary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let record: bed::Record<4> = "sq0\t7\t13\tndls0".parse()?;
+    /// let index: NameIndex<4> = [record].into_iter().collect();
+    ///
+    /// assert_eq!(index.names().collect::<Vec<_>>(), ["ndls0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.records.keys().map(|name| name.as_str())
+    }
+
+    /// Returns an iterator over the names that have more than one record.
+    ///
+    /// The order of the names is arbitrary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let records: Vec<bed::Record<4>> = vec![
+    ///     "sq0\t7\t13\tndls0".parse()?,
+    ///     "sq0\t20\t34\tndls1".parse()?,
+    ///     "sq1\t7\t13\tndls0".parse()?,
+    /// ];
+    ///
+    /// let index: NameIndex<4> = records.into_iter().collect();
+    ///
+    /// assert_eq!(index.duplicate_names().collect::<Vec<_>>(), ["ndls0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn duplicate_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.records
+            .iter()
+            .filter(|(_, records)| records.len() > 1)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the number of records that were not indexed because they have no name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let records: Vec<bed::Record<4>> = vec![
+    ///     "sq0\t7\t13\tndls0".parse()?,
+    ///     "sq0\t20\t34\t.".parse()?,
+    /// ];
+    ///
+    /// let index: NameIndex<4> = records.into_iter().collect();
+    ///
+    /// assert_eq!(index.unnamed_record_count(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unnamed_record_count(&self) -> usize {
+        self.unnamed_record_count
+    }
+}
+
+impl<const N: u8> FromIterator<Record<N>> for NameIndex<N>
+where
+    Record<N>: BedN<3> + BedN<4>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Record<N>>,
+    {
+        let mut builder = Builder::default();
+
+        for record in iter {
+            builder = builder.add_record(record);
+        }
+
+        builder.build()
+    }
+}
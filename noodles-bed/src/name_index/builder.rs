@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use super::NameIndex;
+use crate::{record::BedN, Record};
+
+/// A BED name index builder.
+#[derive(Debug, Default)]
+pub struct Builder<const N: u8> {
+    records: HashMap<String, Vec<Record<N>>>,
+    unnamed_record_count: usize,
+}
+
+impl<const N: u8> Builder<N>
+where
+    Record<N>: BedN<3> + BedN<4>,
+{
+    /// Adds a record to the index.
+    ///
+    /// Records without a name are not indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, NameIndex};
+    ///
+    /// let record: bed::Record<4> = "sq0\t7\t13\tndls0".parse()?;
+    /// let index = NameIndex::builder().add_record(record).build();
+    ///
+    /// assert!(index.contains("ndls0"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(mut self, record: Record<N>) -> Self {
+        match record.name() {
+            Some(name) => {
+                let name = name.to_string();
+                self.records.entry(name).or_default().push(record);
+            }
+            None => self.unnamed_record_count += 1,
+        }
+
+        self
+    }
+
+    /// Builds a name index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::NameIndex;
+    /// let index = NameIndex::<4>::builder().build();
+    /// assert!(index.is_empty());
+    /// ```
+    pub fn build(self) -> NameIndex<N> {
+        NameIndex {
+            records: self.records,
+            unnamed_record_count: self.unnamed_record_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let index = Builder::default()
+            .add_record("sq0\t7\t13\tndls0".parse::<Record<4>>()?)
+            .add_record("sq0\t20\t34\t.".parse()?)
+            .add_record("sq1\t7\t13\tndls0".parse()?)
+            .build();
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.unnamed_record_count(), 1);
+
+        let records = index.get("ndls0").unwrap_or_default();
+        let reference_sequence_names: Vec<_> = records
+            .iter()
+            .map(|record| record.reference_sequence_name())
+            .collect();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+
+        Ok(())
+    }
+}
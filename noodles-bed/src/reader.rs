@@ -1,10 +1,27 @@
+//! BED reader and iterators.
+
+mod query;
+
+pub use self::query::Query;
+
 use std::{
-    io::{self, BufRead},
+    io::{self, BufRead, Read, Seek},
     iter,
     str::FromStr,
 };
 
-use super::Record;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+use noodles_tabix as tabix;
+
+use super::{
+    header::{browser, track},
+    record::BedN,
+    Header, Record,
+};
+
+const COMMENT_PREFIX: &str = "#";
 
 /// A BED reader.
 pub struct Reader<R> {
@@ -70,6 +87,69 @@ where
         Self { inner }
     }
 
+    /// Reads the BED header.
+    ///
+    /// The header is the list of `browser` lines and the `track` line at the start of the
+    /// stream. Comment lines (`#`) are skipped. Reading stops after the track line or at the first
+    /// record. The header is empty if the stream does not start with any header lines.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    ///
+    /// let data = b"browser position sq0:8-13
+    /// track name=ndls itemRgb=On
+    /// sq0\t7\t13
+    /// ";
+    /// let mut reader = bed::Reader::new(&data[..]);
+    ///
+    /// let header = reader.read_header()?;
+    /// assert_eq!(header.browsers().len(), 1);
+    /// assert_eq!(header.track().and_then(|track| track.name()), Some("ndls"));
+    ///
+    /// assert_eq!(reader.records::<3>().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<Header> {
+        let mut header = Header::default();
+        let mut buf = String::new();
+
+        loop {
+            let src = self.inner.fill_buf()?;
+
+            if !starts_with_header_line(src) {
+                break;
+            }
+
+            buf.clear();
+            read_line(&mut self.inner, &mut buf)?;
+
+            if buf.starts_with(COMMENT_PREFIX) {
+                continue;
+            } else if starts_with_word(buf.as_bytes(), browser::PREFIX) {
+                let browser = buf
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                header.browsers_mut().push(browser);
+            } else {
+                let track = buf
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                *header.track_mut() = Some(track);
+
+                break;
+            }
+        }
+
+        Ok(header)
+    }
+
     /// Reads a raw BED line.
     ///
     /// # Examples
@@ -93,6 +173,8 @@ where
 
     /// Returns an iterator over records starting from the current stream position.
     ///
+    /// Comment (`#`), `browser`, and `track` lines are skipped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -116,8 +198,6 @@ where
     where
         Record<N>: FromStr<Err = super::record::ParseError>,
     {
-        const COMMENT_PREFIX: &str = "#";
-
         let mut buf = String::new();
 
         iter::from_fn(move || loop {
@@ -126,7 +206,7 @@ where
             match self.read_line(&mut buf) {
                 Ok(0) => return None,
                 Ok(_) => {
-                    if buf.starts_with(COMMENT_PREFIX) {
+                    if is_header_line(&buf) {
                         continue;
                     } else {
                         return Some(
@@ -141,6 +221,91 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// The input must be bgzipped and coordinate-sorted and have an associated tabix index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bed as bed;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut reader = File::open("repeats.bed.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(bed::Reader::new)?;
+    ///
+    /// let index = tabix::read("repeats.bed.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query::<3>(&index, &region)? {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<const N: u8>(
+        &mut self,
+        index: &tabix::Index,
+        region: &Region,
+    ) -> io::Result<Query<'_, R, N>>
+    where
+        Record<N>: FromStr<Err = super::record::ParseError> + BedN<3>,
+    {
+        let reference_sequence_id = resolve_region(index, region)?;
+        let chunks = index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            self,
+            chunks,
+            region.name().into(),
+            region.interval(),
+        ))
+    }
+}
+
+fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<usize> {
+    index
+        .header()
+        .reference_sequence_names()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {:?}",
+                    region
+                ),
+            )
+        })
+}
+
+fn is_header_line(s: &str) -> bool {
+    starts_with_header_line(s.as_bytes())
+}
+
+fn starts_with_header_line(src: &[u8]) -> bool {
+    src.starts_with(COMMENT_PREFIX.as_bytes())
+        || starts_with_word(src, browser::PREFIX)
+        || starts_with_word(src, track::PREFIX)
+}
+
+fn starts_with_word(src: &[u8], word: &str) -> bool {
+    src.strip_prefix(word.as_bytes())
+        .map(|rest| {
+            rest.first()
+                .map(|b| b.is_ascii_whitespace())
+                .unwrap_or(true)
+        })
+        .unwrap_or(false)
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
@@ -169,6 +334,103 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_header() -> io::Result<()> {
+        let data = b"# noodles
+browser position sq0:8-13
+browser hide all
+track name=ndls description=\"noodles track\"
+sq0\t7\t13
+track name=ndls2
+sq0\t20\t34
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        assert_eq!(
+            header.to_string(),
+            "browser position sq0:8-13\nbrowser hide all\ntrack name=ndls description=\"noodles track\"\n"
+        );
+
+        assert_eq!(reader.records::<3>().count(), 2);
+
+        let data = b"sq0\t7\t13\n";
+        let mut reader = Reader::new(&data[..]);
+        assert!(reader.read_header()?.is_empty());
+        assert_eq!(reader.records::<3>().count(), 1);
+
+        let data = b"track name=\"ndls\n";
+        let mut reader = Reader::new(&data[..]);
+        assert!(matches!(
+            reader.read_header(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_header_line() {
+        assert!(is_header_line("# noodles"));
+        assert!(is_header_line("browser hide all"));
+        assert!(is_header_line("track"));
+        assert!(is_header_line("track name=ndls"));
+
+        assert!(!is_header_line("sq0\t7\t13"));
+        assert!(!is_header_line("tracks\t7\t13"));
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let lines = ["sq0\t7\t13\tr0", "sq0\t20\t34\tr1", "sq1\t4\t8\tr2"];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"# repeats\n")?;
+
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::bed().build());
+
+        for line in lines {
+            let record: Record<4> = line.parse()?;
+
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{}", line)?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start_position(),
+                record.end_position(),
+                Chunk::new(start_position, end_position),
+            );
+        }
+
+        let index = indexer.build();
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let region = "sq0:10-20".parse()?;
+        let records: Vec<Record<4>> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name().map(|name| name.as_ref()), Some("r0"));
+
+        let region = "sq1".parse()?;
+        let records: Vec<Record<3>> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].reference_sequence_name(), "sq1");
+
+        let region = "sq2".parse()?;
+        assert!(reader.query::<3>(&index, &region).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
@@ -0,0 +1,136 @@
+use std::{
+    io::{self, Read, Seek},
+    str::FromStr,
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+
+use super::{is_header_line, Reader};
+use crate::record::{self, BedN, Record};
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+/// An iterator over records of a BED reader that intersect a given region.
+///
+/// This is created by calling [`Reader::query`].
+pub struct Query<'a, R, const N: u8>
+where
+    R: Read + Seek,
+{
+    reader: &'a mut Reader<bgzf::Reader<R>>,
+
+    chunks: vec::IntoIter<Chunk>,
+
+    reference_sequence_name: String,
+    interval: Interval,
+
+    state: State,
+    line_buf: String,
+}
+
+impl<'a, R, const N: u8> Query<'a, R, N>
+where
+    R: Read + Seek,
+    Record<N>: FromStr<Err = record::ParseError> + BedN<3>,
+{
+    pub(super) fn new(
+        reader: &'a mut Reader<bgzf::Reader<R>>,
+        chunks: Vec<Chunk>,
+        reference_sequence_name: String,
+        interval: Interval,
+    ) -> Self {
+        Self {
+            reader,
+
+            chunks: chunks.into_iter(),
+
+            reference_sequence_name,
+            interval,
+
+            state: State::Seek,
+            line_buf: String::new(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<Record<N>>> {
+        loop {
+            self.line_buf.clear();
+
+            if self.reader.read_line(&mut self.line_buf)? == 0 {
+                return Ok(None);
+            }
+
+            if is_header_line(&self.line_buf) {
+                continue;
+            }
+
+            return self
+                .line_buf
+                .parse()
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+        }
+    }
+}
+
+impl<'a, R, const N: u8> Iterator for Query<'a, R, N>
+where
+    R: Read + Seek,
+    Record<N>: FromStr<Err = record::ParseError> + BedN<3>,
+{
+    type Item = io::Result<Record<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                State::Seek => {
+                    self.state = match self.chunks.next() {
+                        Some(chunk) => {
+                            if let Err(e) = self.reader.get_mut().seek(chunk.start()) {
+                                return Some(Err(e));
+                            }
+
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    }
+                }
+                State::Read(chunk_end) => match self.read_record() {
+                    Ok(Some(record)) => {
+                        if self.reader.get_ref().virtual_position() >= chunk_end {
+                            self.state = State::Seek;
+                        }
+
+                        if intersects(&record, &self.reference_sequence_name, self.interval) {
+                            return Some(Ok(record));
+                        }
+                    }
+                    Ok(None) => self.state = State::Seek,
+                    Err(e) => return Some(Err(e)),
+                },
+                State::Done => return None,
+            }
+        }
+    }
+}
+
+fn intersects<const N: u8>(
+    record: &Record<N>,
+    reference_sequence_name: &str,
+    region_interval: Interval,
+) -> bool
+where
+    Record<N>: BedN<3>,
+{
+    let record_interval = Interval::from(record.start_position()..=record.end_position());
+
+    record.reference_sequence_name() == reference_sequence_name
+        && record_interval.intersects(region_interval)
+}
@@ -1,5 +1,6 @@
 //! BED record and fields.
 
+pub mod blocks;
 pub mod builder;
 pub mod color;
 pub mod name;
@@ -463,6 +464,92 @@ where
     pub fn blocks(&self) -> &[Block] {
         &self.standard_fields.blocks
     }
+
+    /// Validates the blocks.
+    ///
+    /// The blocks must be sorted and not overlap, have nonzero sizes, and fit within the record
+    /// interval. The first block must start at the record start position, and the last block must
+    /// end at the record end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{self as bed, record::blocks::ValidationError};
+    /// use noodles_core::Position;
+    ///
+    /// let end = Position::try_from(5)?;
+    /// let builder = || bed::Record::<12>::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_start_position(Position::MIN)
+    ///     .set_end_position(end);
+    ///
+    /// let record = builder().set_blocks(vec![(0, 2), (4, 1)]).build()?;
+    /// assert!(record.validate_blocks().is_ok());
+    ///
+    /// let record = builder().set_blocks(vec![(0, 2), (4, 2)]).build()?;
+    /// assert_eq!(record.validate_blocks(), Err(ValidationError::OutOfBounds(1)));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_blocks(&self) -> Result<(), blocks::ValidationError> {
+        let start = usize::from(self.standard_fields.start_position);
+        let end = usize::from(self.standard_fields.end_position);
+        let len = (end + 1).saturating_sub(start);
+        blocks::validate(self.blocks(), len)
+    }
+
+    /// Returns an iterator over the blocks as absolute intervals.
+    ///
+    /// For a transcript, these are its exons. Each interval is 1-based, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed as bed;
+    /// use noodles_core::Position;
+    ///
+    /// let record = bed::Record::<12>::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_start_position(Position::try_from(8)?)
+    ///     .set_end_position(Position::try_from(13)?)
+    ///     .set_blocks(vec![(0, 2), (4, 2)])
+    ///     .build()?;
+    ///
+    /// let exons: Vec<_> = record.exons().collect();
+    ///
+    /// assert_eq!(exons, [
+    ///     (Position::try_from(8)?, Position::try_from(9)?),
+    ///     (Position::try_from(12)?, Position::try_from(13)?),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exons(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        blocks::exons(self.standard_fields.start_position, self.blocks())
+    }
+
+    /// Returns an iterator over the gaps between blocks as absolute intervals.
+    ///
+    /// For a transcript, these are its introns. Each interval is 1-based, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed as bed;
+    /// use noodles_core::Position;
+    ///
+    /// let record = bed::Record::<12>::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_start_position(Position::try_from(8)?)
+    ///     .set_end_position(Position::try_from(13)?)
+    ///     .set_blocks(vec![(0, 2), (4, 2)])
+    ///     .build()?;
+    ///
+    /// let introns: Vec<_> = record.introns().collect();
+    /// assert_eq!(introns, [(Position::try_from(10)?, Position::try_from(11)?)]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn introns(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        blocks::introns(self.standard_fields.start_position, self.blocks())
+    }
 }
 
 impl fmt::Display for Record<3> {
@@ -538,7 +625,7 @@ where
         "{}{}{}{}{}",
         record.reference_sequence_name(),
         DELIMITER,
-        usize::from(record.start_position()) - 1,
+        record.start_position().to_zero_based(),
         DELIMITER,
         record.end_position()
     )
@@ -595,7 +682,7 @@ where
 {
     format_bed_6_fields(f, record)?;
     f.write_char(DELIMITER)?;
-    write!(f, "{}", usize::from(record.thick_start()) - 1)
+    write!(f, "{}", record.thick_start().to_zero_based())
 }
 
 fn format_bed_8_fields<const N: u8>(f: &mut fmt::Formatter<'_>, record: &Record<N>) -> fmt::Result
@@ -713,6 +800,8 @@ pub enum ParseError {
     MissingBlockStarts,
     /// A block start is invalid.
     InvalidBlockStart(num::ParseIntError),
+    /// The number of block sizes or block starts does not match the block count.
+    BlockCountMismatch,
 }
 
 impl error::Error for ParseError {}
@@ -743,6 +832,7 @@ impl fmt::Display for ParseError {
             Self::InvalidBlockSize(e) => write!(f, "invalid block size: {}", e),
             Self::MissingBlockStarts => f.write_str("missing block starts"),
             Self::InvalidBlockStart(e) => write!(f, "invalid block start: {}", e),
+            Self::BlockCountMismatch => f.write_str("block count mismatch"),
         }
     }
 }
@@ -918,14 +1008,9 @@ where
         .ok_or(ParseError::MissingStartPosition)
         .and_then(|s| {
             s.parse()
-                .map_err(|_| ParseError::InvalidStartPosition)
-                .and_then(|n: usize| {
-                    n.checked_add(1)
-                        .ok_or(ParseError::InvalidStartPosition)
-                        .and_then(|m| {
-                            Position::try_from(m).map_err(|_| ParseError::InvalidStartPosition)
-                        })
-                })
+                .ok()
+                .and_then(Position::from_zero_based)
+                .ok_or(ParseError::InvalidStartPosition)
         })?;
 
     let end_position = fields
@@ -988,14 +1073,9 @@ where
         .ok_or(ParseError::MissingThickStart)
         .and_then(|s| {
             s.parse()
-                .map_err(|_| ParseError::InvalidThickStart)
-                .and_then(|n: usize| {
-                    n.checked_add(1)
-                        .ok_or(ParseError::InvalidThickStart)
-                        .and_then(|m| {
-                            Position::try_from(m).map_err(|_| ParseError::InvalidThickStart)
-                        })
-                })
+                .ok()
+                .and_then(Position::from_zero_based)
+                .ok_or(ParseError::InvalidThickStart)
         })
 }
 
@@ -1037,19 +1117,32 @@ where
         })
         .map(usize::from)?;
 
-    let raw_sizes = fields
+    // Lists may have a trailing delimiter, e.g., `2,2,`.
+    let split_list = |s: &'a str| {
+        s.strip_suffix(LIST_DELIMITER)
+            .unwrap_or(s)
+            .split(LIST_DELIMITER)
+    };
+
+    let raw_sizes: Vec<_> = fields
         .next()
         .ok_or(ParseError::MissingBlockSizes)
-        .map(|s| s.splitn(len, LIST_DELIMITER))?;
+        .map(split_list)?
+        .collect();
 
-    let raw_starts = fields
+    let raw_starts: Vec<_> = fields
         .next()
         .ok_or(ParseError::MissingBlockStarts)
-        .map(|s| s.splitn(len, LIST_DELIMITER))?;
+        .map(split_list)?
+        .collect();
+
+    if raw_sizes.len() != len || raw_starts.len() != len {
+        return Err(ParseError::BlockCountMismatch);
+    }
 
     let mut blocks = Vec::with_capacity(len);
 
-    for (raw_start, raw_size) in raw_starts.zip(raw_sizes) {
+    for (raw_start, raw_size) in raw_starts.into_iter().zip(raw_sizes) {
         let start = raw_start.parse().map_err(ParseError::InvalidBlockStart)?;
         let size = raw_size.parse().map_err(ParseError::InvalidBlockSize)?;
         blocks.push((start, size));
@@ -1397,4 +1490,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_str_for_record_12_with_block_lists() {
+        let record = "sq0\t7\t13\t.\t0\t.\t7\t13\t0\t2\t2,2,\t0,4,".parse::<Record<12>>();
+        assert_eq!(
+            record.map(|r| r.blocks().to_vec()),
+            Ok(vec![(0, 2), (4, 2)])
+        );
+
+        assert_eq!(
+            "sq0\t7\t13\t.\t0\t.\t7\t13\t0\t2\t2\t0,4".parse::<Record<12>>(),
+            Err(ParseError::BlockCountMismatch)
+        );
+
+        assert_eq!(
+            "sq0\t7\t13\t.\t0\t.\t7\t13\t0\t1\t2,2\t0,4".parse::<Record<12>>(),
+            Err(ParseError::BlockCountMismatch)
+        );
+    }
 }
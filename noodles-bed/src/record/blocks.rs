@@ -0,0 +1,163 @@
+//! BED record blocks.
+
+use std::{error, fmt};
+
+use noodles_core::Position;
+
+/// An error returned when the blocks of a BED record are invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// There are no blocks.
+    Empty,
+    /// The first block does not start at the record start position.
+    InvalidFirstBlockStart,
+    /// A block is empty.
+    ///
+    /// This holds the index of the block.
+    EmptyBlock(usize),
+    /// A block ends after the record end position.
+    ///
+    /// This holds the index of the block.
+    OutOfBounds(usize),
+    /// A block starts before the end of the previous block.
+    ///
+    /// This holds the index of the block.
+    Overlapping(usize),
+    /// The last block does not end at the record end position.
+    InvalidLastBlockEnd,
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty blocks"),
+            Self::InvalidFirstBlockStart => f.write_str("invalid first block start"),
+            Self::EmptyBlock(i) => write!(f, "empty block at index {}", i),
+            Self::OutOfBounds(i) => write!(f, "block out of bounds at index {}", i),
+            Self::Overlapping(i) => write!(f, "overlapping block at index {}", i),
+            Self::InvalidLastBlockEnd => f.write_str("invalid last block end"),
+        }
+    }
+}
+
+// Validates blocks of 0-based, relative (start, size) pairs for a feature of the given length.
+pub(super) fn validate(blocks: &[(usize, usize)], len: usize) -> Result<(), ValidationError> {
+    let (first_start, _) = blocks.first().ok_or(ValidationError::Empty)?;
+
+    if *first_start != 0 {
+        return Err(ValidationError::InvalidFirstBlockStart);
+    }
+
+    let mut prev_end = 0;
+
+    for (i, &(start, size)) in blocks.iter().enumerate() {
+        if size == 0 {
+            return Err(ValidationError::EmptyBlock(i));
+        }
+
+        if i > 0 && start < prev_end {
+            return Err(ValidationError::Overlapping(i));
+        }
+
+        let end = start
+            .checked_add(size)
+            .filter(|&end| end <= len)
+            .ok_or(ValidationError::OutOfBounds(i))?;
+
+        prev_end = end;
+    }
+
+    if prev_end == len {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidLastBlockEnd)
+    }
+}
+
+// Converts blocks of 0-based, relative (start, size) pairs to 1-based, absolute, inclusive
+// intervals.
+pub(super) fn exons(
+    start_position: Position,
+    blocks: &[(usize, usize)],
+) -> impl Iterator<Item = (Position, Position)> + '_ {
+    blocks.iter().filter_map(move |&(start, size)| {
+        let exon_start = start_position.checked_add(start)?;
+        let exon_end = exon_start.checked_add(size.checked_sub(1)?)?;
+        Some((exon_start, exon_end))
+    })
+}
+
+// Returns the gaps between consecutive exons as 1-based, absolute, inclusive intervals.
+pub(super) fn introns(
+    start_position: Position,
+    blocks: &[(usize, usize)],
+) -> impl Iterator<Item = (Position, Position)> + '_ {
+    let mut exons = exons(start_position, blocks).peekable();
+
+    std::iter::from_fn(move || loop {
+        let (_, prev_end) = exons.next()?;
+        let &(next_start, _) = exons.peek()?;
+
+        let intron_start = prev_end.checked_add(1)?;
+
+        if intron_start < next_start {
+            let intron_end = Position::new(usize::from(next_start) - 1)?;
+            return Some((intron_start, intron_end));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(&[(0, 2), (4, 1)], 5), Ok(()));
+        assert_eq!(validate(&[(0, 5)], 5), Ok(()));
+
+        assert_eq!(validate(&[], 5), Err(ValidationError::Empty));
+        assert_eq!(
+            validate(&[(1, 4)], 5),
+            Err(ValidationError::InvalidFirstBlockStart)
+        );
+        assert_eq!(
+            validate(&[(0, 2), (3, 0), (4, 1)], 5),
+            Err(ValidationError::EmptyBlock(1))
+        );
+        assert_eq!(
+            validate(&[(0, 2), (4, 2)], 5),
+            Err(ValidationError::OutOfBounds(1))
+        );
+        assert_eq!(
+            validate(&[(0, 3), (2, 3)], 5),
+            Err(ValidationError::Overlapping(1))
+        );
+        assert_eq!(
+            validate(&[(0, 2), (3, 1)], 5),
+            Err(ValidationError::InvalidLastBlockEnd)
+        );
+    }
+
+    #[test]
+    fn test_exons_and_introns() -> Result<(), noodles_core::position::TryFromIntError> {
+        let start = Position::try_from(8)?;
+        let blocks = [(0, 2), (2, 1), (5, 3)];
+
+        let actual: Vec<_> = exons(start, &blocks).collect();
+        let expected = [
+            (Position::try_from(8)?, Position::try_from(9)?),
+            (Position::try_from(10)?, Position::try_from(10)?),
+            (Position::try_from(13)?, Position::try_from(15)?),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = introns(start, &blocks).collect();
+        let expected = [(Position::try_from(11)?, Position::try_from(12)?)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
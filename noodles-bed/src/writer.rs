@@ -3,7 +3,7 @@ use std::{
     io::{self, Write},
 };
 
-use super::Record;
+use super::{Header, Record};
 
 /// A BED writer.
 pub struct Writer<W> {
@@ -65,6 +65,32 @@ where
         self.inner
     }
 
+    /// Writes a BED header.
+    ///
+    /// This writes the browser lines followed by the track line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed::{self as bed, header::Track};
+    ///
+    /// let mut writer = bed::Writer::new(Vec::new());
+    ///
+    /// let mut track = Track::default();
+    /// *track.name_mut() = Some(String::from("ndls"));
+    /// *track.item_rgb_mut() = true;
+    ///
+    /// let header = bed::Header::new(Vec::new(), Some(track));
+    /// writer.write_header(&header)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"track name=ndls itemRgb=On\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        write!(self.inner, "{}", header)
+    }
+
     /// Writes a BED record.
     ///
     /// # Examples
@@ -114,4 +140,34 @@ mod tests {
         assert_eq!(buf, b"sq0\t8\t13\n");
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_each_bed_n() -> Result<(), Box<dyn std::error::Error>> {
+        fn t<const N: u8>(s: &str) -> Result<(), Box<dyn std::error::Error>>
+        where
+            Record<N>: fmt::Display + std::str::FromStr,
+            <Record<N> as std::str::FromStr>::Err: std::error::Error + 'static,
+        {
+            let record: Record<N> = s.parse()?;
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_record(&record)?;
+
+            assert_eq!(writer.into_inner(), format!("{}\n", s).into_bytes());
+
+            Ok(())
+        }
+
+        t::<3>("sq0\t7\t13")?;
+        t::<4>("sq0\t7\t13\tndls1")?;
+        t::<5>("sq0\t7\t13\t.\t0")?;
+        t::<6>("sq0\t7\t13\tndls1\t21\t.")?;
+        t::<7>("sq0\t7\t13\tndls1\t21\t+\t7")?;
+        t::<8>("sq0\t7\t13\tndls1\t21\t+\t7\t13")?;
+        t::<9>("sq0\t7\t13\tndls1\t21\t+\t7\t13\t255,0,0")?;
+        t::<12>("sq0\t7\t13\tndls1\t21\t+\t7\t13\t0\t2\t2,2\t0,4")?;
+        t::<3>("sq0\t7\t13\tnoodles")?;
+
+        Ok(())
+    }
 }
@@ -6,7 +6,6 @@ use std::{
 };
 
 use bytes::{Buf, Bytes};
-use flate2::Crc;
 use pin_project_lite::pin_project;
 use tokio::task::JoinHandle;
 
@@ -64,10 +63,7 @@ fn inflate(mut src: Bytes) -> io::Result<Block> {
 
     inflate_data(&cdata, data.as_mut())?;
 
-    let mut crc = Crc::new();
-    crc.update(data.as_ref());
-
-    if crc.sum() == crc32 {
+    if gz::crc32(data.as_ref()) == crc32 {
         Ok(block)
     } else {
         Err(io::Error::new(
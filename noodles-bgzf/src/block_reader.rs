@@ -0,0 +1,198 @@
+use std::io::{self, BufRead, Read};
+
+use super::{virtual_position, Block, InflatedBlock, VirtualPosition, BGZF_MAX_ISIZE};
+
+/// A BGZF reader over inflated blocks.
+///
+/// This reads the uncompressed data of blocks that were inflated externally, e.g., in parallel or
+/// from a network stream, and tracks virtual positions the same as [`crate::Reader`]. Blocks with
+/// no data, e.g., the EOF block, are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let blocks = bgzf::RawBlocks::new(&data[..]).map(|result| result.and_then(|block| block.inflate()));
+/// let mut reader = bgzf::BlockReader::new(blocks);
+///
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct BlockReader<I> {
+    blocks: I,
+    block: Block,
+}
+
+impl<I> BlockReader<I>
+where
+    I: Iterator<Item = io::Result<InflatedBlock>>,
+{
+    /// Creates a BGZF reader over inflated blocks.
+    ///
+    /// The blocks are expected to be in stream order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::BlockReader::new(std::iter::empty());
+    /// ```
+    pub fn new(blocks: I) -> Self {
+        Self {
+            blocks,
+            block: Block::default(),
+        }
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::BlockReader::new(std::iter::empty());
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.block.virtual_position()
+    }
+
+    /// Returns the underlying iterator of blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::BlockReader::new(std::iter::empty());
+    /// let blocks = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> I {
+        self.blocks
+    }
+
+    fn read_next_block(&mut self) -> io::Result<()> {
+        loop {
+            let block = match self.blocks.next() {
+                Some(result) => result?,
+                None => return Ok(()),
+            };
+
+            let end = block.position().checked_add(block.size());
+
+            if end.map_or(true, |n| n > virtual_position::MAX_COMPRESSED_POSITION) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid block position",
+                ));
+            } else if block.data().len() > BGZF_MAX_ISIZE {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "expected block data length <= {}, got {}",
+                        BGZF_MAX_ISIZE,
+                        block.data().len()
+                    ),
+                ));
+            }
+
+            self.block.set_position(block.position());
+            self.block.set_size(block.size());
+
+            let data = self.block.data_mut();
+            data.set_position(0);
+            data.resize(block.data().len());
+            data.as_mut().copy_from_slice(block.data());
+
+            if data.has_remaining() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<I> Read for BlockReader<I>
+where
+    I: Iterator<Item = io::Result<InflatedBlock>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut remaining = self.fill_buf()?;
+            remaining.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<I> BufRead for BlockReader<I>
+where
+    I: Iterator<Item = io::Result<InflatedBlock>>,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.block.data().has_remaining() {
+            self.read_next_block()?;
+        }
+
+        Ok(self.block.data().as_ref())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.block.data_mut().consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> Result<(), Box<dyn std::error::Error>> {
+        let blocks = vec![
+            Ok(InflatedBlock::new(0, 35, b"noodles".to_vec())),
+            Ok(InflatedBlock::new(35, 28, Vec::new())),
+            Ok(InflatedBlock::new(63, 31, b"bgzf".to_vec())),
+        ];
+
+        let mut reader = BlockReader::new(blocks.into_iter());
+
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"nood");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((0, 4))?
+        );
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"lesbgzf");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((94, 0))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_invalid_block() {
+        let blocks = vec![Ok(InflatedBlock::new(0, 34, vec![0; BGZF_MAX_ISIZE + 1]))];
+        let mut reader = BlockReader::new(blocks.into_iter());
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
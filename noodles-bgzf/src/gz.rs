@@ -18,3 +18,28 @@ pub(crate) enum CompressionMethod {
 pub(crate) enum OperatingSystem {
     Unknown = 255,
 }
+
+/// Calculates the CRC32 of the given data.
+#[cfg(feature = "libdeflate")]
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    libdeflater::crc32(data)
+}
+
+/// Calculates the CRC32 of the given data.
+#[cfg(not(feature = "libdeflate"))]
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0x00000000);
+        assert_eq!(crc32(b"noodles"), 0x802a58a1);
+    }
+}
@@ -0,0 +1,82 @@
+/// An inflated (uncompressed) BGZF block.
+///
+/// This is the uncompressed data of a block with its position and size in the compressed stream,
+/// which are used to track virtual positions when reading the block using [`crate::BlockReader`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InflatedBlock {
+    position: u64,
+    size: u64,
+    data: Vec<u8>,
+}
+
+impl InflatedBlock {
+    /// Creates an inflated BGZF block.
+    ///
+    /// The position and size are the position and size (`BSIZE` + 1) of the compressed block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::InflatedBlock::new(0, 35, b"noodles".to_vec());
+    /// ```
+    pub fn new(position: u64, size: u64, data: Vec<u8>) -> Self {
+        Self {
+            position,
+            size,
+            data,
+        }
+    }
+
+    /// Returns the position of the block in the compressed stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::InflatedBlock::new(0, 35, b"noodles".to_vec());
+    /// assert_eq!(block.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the size of the compressed block (`BSIZE` + 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::InflatedBlock::new(0, 35, b"noodles".to_vec());
+    /// assert_eq!(block.size(), 35);
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the uncompressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::InflatedBlock::new(0, 35, b"noodles".to_vec());
+    /// assert_eq!(block.data(), b"noodles");
+    /// ```
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the uncompressed data, consuming the block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::InflatedBlock::new(0, 35, b"noodles".to_vec());
+    /// assert_eq!(block.into_data(), b"noodles");
+    /// ```
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
@@ -38,12 +38,28 @@
 mod r#async;
 
 mod block;
+mod block_reader;
 mod gz;
-mod reader;
+mod inflated_block;
+mod multithreaded_reader;
+mod multithreaded_writer;
+mod raw_block;
+mod raw_blocks;
+pub mod reader;
 pub mod virtual_position;
 pub mod writer;
 
-pub use self::{reader::Reader, virtual_position::VirtualPosition, writer::Writer};
+pub use self::{
+    block_reader::BlockReader,
+    inflated_block::InflatedBlock,
+    multithreaded_reader::MultithreadedReader,
+    multithreaded_writer::MultithreadedWriter,
+    raw_block::RawBlock,
+    raw_blocks::RawBlocks,
+    reader::{has_eof, Reader},
+    virtual_position::VirtualPosition,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -120,7 +136,7 @@ mod tests {
         let expected_lines = vec!["noodles\n", "-\n", "bgzf\n", "buffered"];
         assert_eq!(lines, expected_lines);
 
-        let expected_upos = vec![0, 8, 10, 15];
+        let expected_upos = [0, 8, 10, 15];
         let expected_virtual_positions: Vec<VirtualPosition> = expected_upos
             .iter()
             .map(|x| VirtualPosition::try_from((0, *x)).unwrap())
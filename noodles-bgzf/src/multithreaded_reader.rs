@@ -0,0 +1,450 @@
+use std::{
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+    sync::{
+        mpsc::{self, Receiver, Sender, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use super::{gz, reader::inflate_data, Block, VirtualPosition};
+
+// The number of blocks read ahead per worker.
+const READ_AHEAD_BLOCKS_PER_WORKER: usize = 2;
+
+type BlockResult = io::Result<(u64, u64, Vec<u8>)>;
+type BlockResultRx = Receiver<BlockResult>;
+
+struct Job {
+    position: u64,
+    clen: u64,
+    cdata: Vec<u8>,
+    crc32: u32,
+    ulen: usize,
+    tx: SyncSender<BlockResult>,
+}
+
+/// A multithreaded BGZF reader.
+///
+/// This reads compressed blocks ahead on a separate thread and inflates them on a pool of worker
+/// threads. Blocks are returned in order, so (virtual) positions are tracked the same as
+/// [`crate::Reader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, Read}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let worker_count = NonZeroUsize::new(4).unwrap();
+/// let file = File::open("data.gz")?;
+/// let mut reader = bgzf::MultithreadedReader::with_worker_count(worker_count, file);
+///
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedReader<R> {
+    reader_handle: Option<JoinHandle<R>>,
+    queue_rx: Option<Receiver<BlockResultRx>>,
+    job_tx: Option<Sender<Job>>,
+    worker_handles: Vec<JoinHandle<()>>,
+    worker_count: NonZeroUsize,
+    block: Block,
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Send + 'static,
+{
+    /// Creates a multithreaded BGZF reader with a worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let reader = bgzf::MultithreadedReader::with_worker_count(worker_count, io::empty());
+    /// ```
+    pub fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
+        let (job_tx, job_rx) = mpsc::channel();
+        let worker_handles = spawn_workers(worker_count, job_rx);
+
+        let mut reader = Self {
+            reader_handle: None,
+            queue_rx: None,
+            job_tx: Some(job_tx),
+            worker_handles,
+            worker_count,
+            block: Block::default(),
+        };
+
+        reader.start(inner, 0);
+
+        reader
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let reader = bgzf::MultithreadedReader::with_worker_count(worker_count, io::empty());
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.block.virtual_position()
+    }
+
+    /// Stops the worker threads and returns the underlying reader.
+    ///
+    /// The position of the underlying reader is undefined, as blocks may have been read ahead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let reader = bgzf::MultithreadedReader::with_worker_count(worker_count, io::empty());
+    /// let inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(mut self) -> R {
+        self.stop().expect("missing reader")
+    }
+
+    fn start(&mut self, inner: R, position: u64) {
+        let queue_capacity = self.worker_count.get() * READ_AHEAD_BLOCKS_PER_WORKER;
+        let (queue_tx, queue_rx) = mpsc::sync_channel(queue_capacity);
+
+        let job_tx = self.job_tx.clone().expect("missing job sender");
+        let reader_handle = thread::spawn(move || read_blocks(inner, position, queue_tx, job_tx));
+
+        self.reader_handle = Some(reader_handle);
+        self.queue_rx = Some(queue_rx);
+    }
+
+    fn stop(&mut self) -> Option<R> {
+        // Dropping the queue receiver signals the reader thread to stop.
+        self.queue_rx.take();
+
+        self.reader_handle
+            .take()
+            .map(|handle| handle.join().expect("reader thread panicked"))
+    }
+
+    fn read_next_block(&mut self) -> io::Result<()> {
+        let result = self
+            .queue_rx
+            .as_ref()
+            .and_then(|queue_rx| queue_rx.recv().ok())
+            .and_then(|block_rx| block_rx.recv().ok());
+
+        let data = self.block.data_mut();
+        data.set_position(0);
+
+        match result {
+            Some(Ok((position, clen, buf))) => {
+                data.resize(buf.len());
+                data.as_mut().copy_from_slice(&buf);
+                self.block.set_position(position);
+                self.block.set_size(clen);
+                Ok(())
+            }
+            Some(Err(e)) => Err(e),
+            None => {
+                // EOF
+                data.resize(0);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Seek + Send + 'static,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// This stops reading ahead, seeks the underlying reader, and restarts reading from the block
+    /// at the compressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::{self, Cursor}, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let mut reader =
+    ///     bgzf::MultithreadedReader::with_worker_count(worker_count, Cursor::new(Vec::new()));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::from(0);
+    /// reader.seek(virtual_position)?;
+    /// assert_eq!(reader.virtual_position(), virtual_position);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        let mut inner = self.stop().expect("missing reader");
+        let result = inner.seek(SeekFrom::Start(cpos));
+
+        self.block = Block::default();
+        self.block.set_position(cpos);
+
+        // The reader is restarted regardless of the seek result to keep the underlying reader.
+        self.start(inner, cpos);
+        result?;
+
+        self.read_next_block()?;
+        self.block.data_mut().set_position(usize::from(upos));
+
+        Ok(pos)
+    }
+}
+
+impl<R> Drop for MultithreadedReader<R> {
+    fn drop(&mut self) {
+        self.queue_rx.take();
+
+        if let Some(handle) = self.reader_handle.take() {
+            let _ = handle.join();
+        }
+
+        self.job_tx.take();
+
+        for handle in self.worker_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<R> Read for MultithreadedReader<R>
+where
+    R: Read + Send + 'static,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut remaining = self.fill_buf()?;
+            remaining.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<R> BufRead for MultithreadedReader<R>
+where
+    R: Read + Send + 'static,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.block.data().has_remaining() {
+            self.read_next_block()?;
+        }
+
+        Ok(self.block.data().as_ref())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.block.data_mut().consume(amt);
+    }
+}
+
+fn spawn_workers(worker_count: NonZeroUsize, job_rx: Receiver<Job>) -> Vec<JoinHandle<()>> {
+    let job_rx = Arc::new(Mutex::new(job_rx));
+
+    (0..worker_count.get())
+        .map(|_| {
+            let job_rx = Arc::clone(&job_rx);
+
+            thread::spawn(move || loop {
+                let job = match job_rx.lock() {
+                    Ok(job_rx) => match job_rx.recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    },
+                    Err(_) => break,
+                };
+
+                let result = inflate(&job.cdata, job.crc32, job.ulen)
+                    .map(|buf| (job.position, job.clen, buf));
+
+                // The receiver is dropped when the reader is stopped.
+                let _ = job.tx.send(result);
+            })
+        })
+        .collect()
+}
+
+fn inflate(cdata: &[u8], crc32: u32, ulen: usize) -> io::Result<Vec<u8>> {
+    let mut buf = vec![0; ulen];
+    inflate_data(cdata, &mut buf)?;
+
+    if gz::crc32(&buf) == crc32 {
+        Ok(buf)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "block data checksum mismatch",
+        ))
+    }
+}
+
+// Reads compressed blocks and queues them for inflating until EOF, an error, or the queue is
+// closed. The underlying reader is returned.
+fn read_blocks<R>(
+    mut reader: R,
+    mut position: u64,
+    queue_tx: SyncSender<BlockResultRx>,
+    job_tx: Sender<Job>,
+) -> R
+where
+    R: Read,
+{
+    loop {
+        let mut cdata = Vec::new();
+
+        let (tx, rx) = mpsc::sync_channel(1);
+
+        // This blocks when the queue is full and fails when the queue is closed.
+        if queue_tx.send(rx).is_err() {
+            break;
+        }
+
+        match super::reader::read_compressed_block(&mut reader, &mut cdata) {
+            Ok((0, _)) => break,
+            Ok((clen, (crc32, ulen))) => {
+                let clen = clen as u64;
+
+                let job = Job {
+                    position,
+                    clen,
+                    cdata,
+                    crc32,
+                    ulen,
+                    tx,
+                };
+
+                if job_tx.send(job).is_err() {
+                    break;
+                }
+
+                position += clen;
+            }
+            Err(e) => {
+                let _ = tx.send(Err(e));
+                break;
+            }
+        }
+    }
+
+    reader
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::Writer;
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+
+        for i in 0..8 {
+            writer.write_all(format!("noodles-bgzf-{}\n", i).as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_read_to_end() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut expected = Vec::new();
+        crate::Reader::new(&data[..]).read_to_end(&mut expected)?;
+
+        for worker_count in [1, 2, 3] {
+            let worker_count = NonZeroUsize::new(worker_count).unwrap();
+            let mut reader =
+                MultithreadedReader::with_worker_count(worker_count, Cursor::new(data.clone()));
+
+            let mut actual = Vec::new();
+            reader.read_to_end(&mut actual)?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_positions() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut expected = Vec::new();
+        let mut reader = crate::Reader::new(&data[..]);
+
+        loop {
+            let virtual_position = reader.virtual_position();
+            let mut line = String::new();
+
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            expected.push((virtual_position, line));
+        }
+
+        let worker_count = NonZeroUsize::new(2).unwrap();
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+
+        let mut actual = Vec::new();
+
+        loop {
+            let virtual_position = reader.virtual_position();
+            let mut line = String::new();
+
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            actual.push((virtual_position, line));
+        }
+
+        assert_eq!(actual, expected);
+
+        let (virtual_position, line) = &expected[5];
+        reader.seek(*virtual_position)?;
+
+        let mut actual_line = String::new();
+        reader.read_line(&mut actual_line)?;
+        assert_eq!(&actual_line, line);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_invalid_data() {
+        let worker_count = NonZeroUsize::new(2).unwrap();
+        let data = b"noodles".repeat(4);
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(data));
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    cmp,
+    io::{self, Write},
+    mem,
+    num::NonZeroUsize,
+    sync::{
+        mpsc::{self, Receiver, Sender, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use super::{
+    gz,
+    writer::{
+        deflate_data, write_header, write_trailer, CompressionLevelImpl, BGZF_EOF, DEFAULT_BUF_SIZE,
+    },
+    BGZF_HEADER_SIZE,
+};
+
+// The number of blocks queued for deflating per worker.
+const QUEUED_BLOCKS_PER_WORKER: usize = 2;
+
+type BlockResult = io::Result<Vec<u8>>;
+type BlockResultRx = Receiver<BlockResult>;
+
+struct Job {
+    data: Vec<u8>,
+    tx: SyncSender<BlockResult>,
+}
+
+/// A multithreaded BGZF writer.
+///
+/// This deflates blocks on a pool of worker threads and writes them in order on a separate
+/// thread. The output is the same as [`crate::Writer`] with the same compression level.
+///
+/// Because blocks are compressed asynchronously, the writer does not track (virtual) positions.
+/// Use [`crate::Writer`] when positions are needed, e.g., when building an index.
+///
+/// The output stream must be finished, either explicitly, using [`Self::finish`], or implicitly,
+/// when the writer is dropped. Only the former returns any errors.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{io::{self, Write}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let worker_count = NonZeroUsize::new(4).unwrap();
+/// let mut writer = bgzf::MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+/// writer.write_all(b"noodles-bgzf")?;
+///
+/// let data = writer.finish()?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedWriter<W> {
+    writer_handle: Option<JoinHandle<io::Result<W>>>,
+    queue_tx: Option<SyncSender<BlockResultRx>>,
+    job_tx: Option<Sender<Job>>,
+    worker_handles: Vec<JoinHandle<()>>,
+    buf: Vec<u8>,
+}
+
+impl<W> MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    /// Creates a multithreaded BGZF writer with a worker count and a default compression level.
+    ///
+    /// To set a compression level, use [`crate::writer::Builder::build_with_worker_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let writer = bgzf::MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+    /// ```
+    pub fn with_worker_count(worker_count: NonZeroUsize, inner: W) -> Self {
+        crate::Writer::builder(inner).build_with_worker_count(worker_count)
+    }
+
+    pub(crate) fn new(
+        worker_count: NonZeroUsize,
+        compression_level: CompressionLevelImpl,
+        inner: W,
+    ) -> Self {
+        let (job_tx, job_rx) = mpsc::channel();
+        let worker_handles = spawn_workers(worker_count, compression_level, job_rx);
+
+        let queue_capacity = worker_count.get() * QUEUED_BLOCKS_PER_WORKER;
+        let (queue_tx, queue_rx) = mpsc::sync_channel(queue_capacity);
+        let writer_handle = thread::spawn(move || write_blocks(inner, queue_rx));
+
+        Self {
+            writer_handle: Some(writer_handle),
+            queue_tx: Some(queue_tx),
+            job_tx: Some(job_tx),
+            worker_handles,
+            buf: Vec::with_capacity(DEFAULT_BUF_SIZE),
+        }
+    }
+
+    /// Finishes the output stream and returns the underlying writer.
+    ///
+    /// This queues any buffered data as a final block, waits for all blocks to be written, and
+    /// then appends the BGZF EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::{self, Write}, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let mut writer = bgzf::MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+    /// writer.write_all(b"noodles-bgzf")?;
+    ///
+    /// let data = writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        self.try_finish()
+    }
+
+    fn try_finish(&mut self) -> io::Result<W> {
+        let result = self.send_block();
+
+        // Dropping the queue sender signals the writer thread to finish.
+        self.queue_tx.take();
+
+        let inner = self.join_writer();
+
+        result.and(inner)
+    }
+
+    fn send_block(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+
+        let data = mem::replace(&mut self.buf, Vec::with_capacity(DEFAULT_BUF_SIZE));
+        let (tx, rx) = mpsc::sync_channel(1);
+
+        let queue_tx = self.queue_tx.as_ref().ok_or_else(writer_stopped)?;
+
+        // This blocks when the queue is full and fails when the writer thread stopped.
+        if queue_tx.send(rx).is_err() {
+            self.queue_tx.take();
+            return self.join_writer().and_then(|_| Err(writer_stopped()));
+        }
+
+        self.job_tx
+            .as_ref()
+            .ok_or_else(writer_stopped)?
+            .send(Job { data, tx })
+            .map_err(|_| writer_stopped())
+    }
+
+    fn join_writer(&mut self) -> io::Result<W> {
+        self.writer_handle
+            .take()
+            .ok_or_else(writer_stopped)?
+            .join()
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "writer thread panicked"))?
+    }
+}
+
+impl<W> Drop for MultithreadedWriter<W> {
+    fn drop(&mut self) {
+        // Any buffered data is queued as a final block. Errors are ignored.
+        if !self.buf.is_empty() {
+            if let (Some(queue_tx), Some(job_tx)) = (self.queue_tx.as_ref(), self.job_tx.as_ref()) {
+                let data = mem::take(&mut self.buf);
+                let (tx, rx) = mpsc::sync_channel(1);
+
+                if queue_tx.send(rx).is_ok() {
+                    let _ = job_tx.send(Job { data, tx });
+                }
+            }
+        }
+
+        self.queue_tx.take();
+
+        if let Some(handle) = self.writer_handle.take() {
+            let _ = handle.join();
+        }
+
+        self.job_tx.take();
+
+        for handle in self.worker_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<W> Write for MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let max_write_len = cmp::min(DEFAULT_BUF_SIZE - self.buf.len(), buf.len());
+
+        self.buf.extend_from_slice(&buf[..max_write_len]);
+
+        if self.buf.len() >= DEFAULT_BUF_SIZE {
+            self.send_block()?;
+        }
+
+        Ok(max_write_len)
+    }
+
+    /// Queues any buffered data as a block.
+    ///
+    /// Like [`crate::Writer`], this does not flush the underlying writer. It also does not wait
+    /// for the block to be written.
+    fn flush(&mut self) -> io::Result<()> {
+        self.send_block()
+    }
+}
+
+fn writer_stopped() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "writer thread stopped")
+}
+
+fn spawn_workers(
+    worker_count: NonZeroUsize,
+    compression_level: CompressionLevelImpl,
+    job_rx: Receiver<Job>,
+) -> Vec<JoinHandle<()>> {
+    let job_rx = Arc::new(Mutex::new(job_rx));
+
+    (0..worker_count.get())
+        .map(|_| {
+            let job_rx = Arc::clone(&job_rx);
+
+            thread::spawn(move || loop {
+                let job = match job_rx.lock() {
+                    Ok(job_rx) => match job_rx.recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    },
+                    Err(_) => break,
+                };
+
+                let result = deflate(&job.data, compression_level);
+
+                // The receiver is dropped when the writer thread stopped.
+                let _ = job.tx.send(result);
+            })
+        })
+        .collect()
+}
+
+fn deflate(data: &[u8], compression_level: CompressionLevelImpl) -> io::Result<Vec<u8>> {
+    let (cdata, crc32, r#isize) = deflate_data(data, compression_level)?;
+
+    let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
+    let mut buf = Vec::with_capacity(block_size);
+
+    write_header(&mut buf, block_size)?;
+    buf.extend_from_slice(&cdata);
+    write_trailer(&mut buf, crc32, r#isize)?;
+
+    Ok(buf)
+}
+
+// Writes deflated blocks in queue order until the queue is closed, then appends the EOF block. The
+// underlying writer is returned.
+fn write_blocks<W>(mut writer: W, queue_rx: Receiver<BlockResultRx>) -> io::Result<W>
+where
+    W: Write,
+{
+    while let Ok(block_rx) = queue_rx.recv() {
+        let block = block_rx
+            .recv()
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "worker thread stopped"))??;
+
+        writer.write_all(&block)?;
+    }
+
+    writer.write_all(BGZF_EOF)?;
+    writer.flush()?;
+
+    Ok(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn build_data() -> Vec<u8> {
+        (0..(3 * DEFAULT_BUF_SIZE + 8))
+            .map(|i| b"noodles-bgzf"[i % 12].wrapping_add((i / 4096) as u8))
+            .collect()
+    }
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let data = build_data();
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let expected = writer.finish()?;
+
+        for worker_count in [1, 2, 3] {
+            let worker_count = NonZeroUsize::new(worker_count).unwrap();
+            let mut writer = MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+            writer.write_all(&data)?;
+            let actual = writer.finish()?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush() -> io::Result<()> {
+        let worker_count = NonZeroUsize::new(2).unwrap();
+        let mut writer = MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+
+        for i in 0..8 {
+            writer.write_all(format!("noodles-bgzf-{}\n", i).as_bytes())?;
+            writer.flush()?;
+        }
+
+        let data = writer.finish()?;
+
+        let blocks = crate::RawBlocks::new(&data[..]).collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(blocks.len(), 9);
+
+        let mut actual = String::new();
+        crate::Reader::new(&data[..]).read_to_string(&mut actual)?;
+
+        let expected: String = (0..8).map(|i| format!("noodles-bgzf-{}\n", i)).collect();
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop() -> io::Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buf = SharedBuf::default();
+
+        {
+            let worker_count = NonZeroUsize::new(2).unwrap();
+            let mut writer = MultithreadedWriter::with_worker_count(worker_count, buf.clone());
+            writer.write_all(b"noodles-bgzf")?;
+        }
+
+        let data = buf.0.lock().unwrap().clone();
+        assert!(data.ends_with(BGZF_EOF));
+
+        let mut actual = Vec::new();
+        crate::Reader::new(&data[..]).read_to_end(&mut actual)?;
+        assert_eq!(actual, b"noodles-bgzf");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use std::io;
+
+use super::{gz, reader::inflate_data, InflatedBlock};
+
+/// A raw (compressed) BGZF block.
+///
+/// This is the compressed data (`CDATA`) of a block with the fields needed to inflate and verify
+/// it, i.e., the checksum (`CRC32`) and uncompressed data size (`ISIZE`), and its position in the
+/// compressed stream.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RawBlock {
+    position: u64,
+    size: u64,
+    cdata: Vec<u8>,
+    crc32: u32,
+    uncompressed_size: usize,
+}
+
+impl RawBlock {
+    pub(crate) fn new(
+        position: u64,
+        size: u64,
+        cdata: Vec<u8>,
+        crc32: u32,
+        uncompressed_size: usize,
+    ) -> Self {
+        Self {
+            position,
+            size,
+            cdata,
+            crc32,
+            uncompressed_size,
+        }
+    }
+
+    /// Returns the position of the block in the compressed stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::RawBlock::default();
+    /// assert_eq!(block.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the size of the block (`BSIZE` + 1).
+    ///
+    /// This includes the header and trailer. The next block starts at the position of this block
+    /// plus its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::RawBlock::default();
+    /// assert_eq!(block.size(), 0);
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the compressed data (`CDATA`).
+    ///
+    /// This is a raw DEFLATE stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::RawBlock::default();
+    /// assert!(block.cdata().is_empty());
+    /// ```
+    pub fn cdata(&self) -> &[u8] {
+        &self.cdata
+    }
+
+    /// Returns the CRC32 checksum of the uncompressed data (`CRC32`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::RawBlock::default();
+    /// assert_eq!(block.crc32(), 0);
+    /// ```
+    pub fn crc32(&self) -> u32 {
+        self.crc32
+    }
+
+    /// Returns the size of the uncompressed data (`ISIZE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let block = bgzf::RawBlock::default();
+    /// assert_eq!(block.uncompressed_size(), 0);
+    /// ```
+    pub fn uncompressed_size(&self) -> usize {
+        self.uncompressed_size
+    }
+
+    /// Inflates the compressed data and verifies its checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block = bgzf::RawBlock::default();
+    /// let inflated_block = block.inflate()?;
+    ///
+    /// assert_eq!(inflated_block.position(), block.position());
+    /// assert!(inflated_block.data().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn inflate(&self) -> io::Result<InflatedBlock> {
+        let mut data = Vec::new();
+        self.inflate_into(&mut data)?;
+        Ok(InflatedBlock::new(self.position, self.size, data))
+    }
+
+    /// Inflates the compressed data into the given buffer and verifies its checksum.
+    ///
+    /// The buffer is resized to the uncompressed data size. This reuses the capacity of the given
+    /// buffer rather than allocating internally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block = bgzf::RawBlock::default();
+    /// let mut buf = vec![0; 8];
+    /// block.inflate_into(&mut buf)?;
+    ///
+    /// assert!(buf.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn inflate_into(&self, buf: &mut Vec<u8>) -> io::Result<()> {
+        buf.resize(self.uncompressed_size, 0);
+        inflate_data(&self.cdata, buf)?;
+
+        if gz::crc32(buf) == self.crc32 {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "block data checksum mismatch",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflate() -> io::Result<()> {
+        // deflate(b"noodles")
+        let cdata = vec![0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00];
+
+        let block = RawBlock::new(8, 35, cdata.clone(), 0x802a58a1, 7);
+        let inflated_block = block.inflate()?;
+        assert_eq!(inflated_block.position(), 8);
+        assert_eq!(inflated_block.size(), 35);
+        assert_eq!(inflated_block.data(), b"noodles");
+
+        let block = RawBlock::new(8, 35, cdata, 0, 7);
+        assert!(matches!(
+            block.inflate(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
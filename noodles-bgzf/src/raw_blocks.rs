@@ -0,0 +1,153 @@
+use std::io::{self, Read};
+
+use super::{reader::read_compressed_block, RawBlock};
+
+/// An iterator over raw (compressed) BGZF blocks.
+///
+/// This reads the block framing but does not inflate the block data, e.g., to inflate blocks
+/// in parallel or with a custom decompressor. Raw blocks can be inflated using
+/// [`RawBlock::inflate`] and read back as a stream using [`crate::BlockReader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bgzf as bgzf;
+///
+/// let file = File::open("data.gz")?;
+///
+/// for result in bgzf::RawBlocks::new(file) {
+///     let block = result?;
+///     println!("{}\t{}", block.position(), block.uncompressed_size());
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct RawBlocks<R> {
+    inner: R,
+    position: u64,
+}
+
+impl<R> RawBlocks<R>
+where
+    R: Read,
+{
+    /// Creates an iterator over raw BGZF blocks.
+    ///
+    /// The underlying reader is expected to be at the start of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let blocks = bgzf::RawBlocks::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::with_position(inner, 0)
+    }
+
+    /// Creates an iterator over raw BGZF blocks starting at the given compressed position.
+    ///
+    /// The underlying reader is expected to be at the start of a block. The given position is
+    /// only used to set the positions of the blocks, e.g., when reading a byte range of a stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let blocks = bgzf::RawBlocks::with_position(&data[..], 8);
+    /// ```
+    pub fn with_position(inner: R, position: u64) -> Self {
+        Self { inner, position }
+    }
+
+    /// Returns the current position in the compressed stream.
+    ///
+    /// This is the position of the next block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let blocks = bgzf::RawBlocks::new(&data[..]);
+    /// assert_eq!(blocks.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let blocks = bgzf::RawBlocks::new(&data[..]);
+    /// assert!(blocks.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Iterator for RawBlocks<R>
+where
+    R: Read,
+{
+    type Item = io::Result<RawBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cdata = Vec::new();
+
+        match read_compressed_block(&mut self.inner, &mut cdata) {
+            Ok((0, _)) => None,
+            Ok((clen, (crc32, ulen))) => {
+                let position = self.position;
+                let size = clen as u64;
+                self.position += size;
+                Some(Ok(RawBlock::new(position, size, cdata, crc32, ulen)))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut blocks = RawBlocks::new(&data[..]);
+
+        let block = blocks.next().transpose()?.expect("missing block");
+        assert_eq!(block.position(), 0);
+        assert_eq!(block.uncompressed_size(), 7);
+        assert_eq!(block.inflate()?.data(), b"noodles");
+
+        let block = blocks.next().transpose()?.expect("missing block");
+        assert_eq!(block.position(), 35);
+        assert_eq!(block.inflate()?.data(), b"bgzf");
+
+        // EOF block
+        let block = blocks.next().transpose()?.expect("missing block");
+        assert_eq!(block.uncompressed_size(), 0);
+
+        assert!(blocks.next().is_none());
+        assert_eq!(blocks.position(), data.len() as u64);
+
+        Ok(())
+    }
+}
@@ -1,14 +1,21 @@
+//! BGZF reader.
+
+mod builder;
+mod validation;
+
+pub use self::{builder::Builder, validation::Validation};
+
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
 use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
-use flate2::Crc;
 
-use super::{gz, Block, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+use super::{gz, writer::BGZF_EOF, Block, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
 ///
-/// Due to the static structure of a BGZF block, gzip headers are mostly discarded. CRC32
-/// validation is also disabled when decompressing data.
+/// Due to the static structure of a BGZF block, gzip headers are mostly discarded. By default,
+/// the checksum (CRC32) of each block is validated when decompressing data (see
+/// [`Builder::set_validation`]).
 ///
 /// The reader implements both [`std::io::Read`] and [`std::io::BufRead`], consuming compressed
 /// data and emitting uncompressed data. It is internally buffered by a single block, and to
@@ -30,12 +37,27 @@ pub struct Reader<R> {
     position: u64,
     cdata: Vec<u8>,
     block: Block,
+    validation: Validation,
+    block_count: u64,
 }
 
 impl<R> Reader<R>
 where
     R: Read,
 {
+    /// Creates a BGZF reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
+    }
+
     /// Creates a BGZF reader.
     ///
     /// # Examples
@@ -46,12 +68,7 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self {
-            inner,
-            position: 0,
-            cdata: Vec::new(),
-            block: Block::default(),
-        }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying reader.
@@ -123,6 +140,61 @@ where
     pub fn virtual_position(&self) -> VirtualPosition {
         self.block.virtual_position()
     }
+
+    /// Reads the uncompressed data of a block into the given buffer.
+    ///
+    /// The buffer is cleared and filled with the remaining data of the current block or, if the
+    /// current block is consumed, all of the data of the next block. This reuses the capacity of
+    /// the given buffer rather than allocating internally.
+    ///
+    /// The number of bytes read is returned. If 0 is returned, the stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let mut buf = Vec::new();
+    ///
+    /// assert_eq!(reader.read_block(&mut buf)?, 7);
+    /// assert_eq!(buf, b"noodles");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_block(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        buf.clear();
+
+        if self.block.data().has_remaining() {
+            buf.extend_from_slice(self.block.data().as_ref());
+            self.consume(buf.len());
+            return Ok(buf.len());
+        }
+
+        let (clen, crc32, ulen) = match read_compressed_block(&mut self.inner, &mut self.cdata)? {
+            (0, _) => return Ok(0),
+            (clen, (crc32, ulen)) => (clen, crc32, ulen),
+        };
+
+        buf.resize(ulen, 0);
+        inflate_block_data(&self.cdata, crc32, buf, self.validation, self.block_count)?;
+        self.block_count += 1;
+
+        self.block.set_size(clen as u64);
+
+        let data = self.block.data_mut();
+        data.resize(ulen);
+        data.set_position(ulen);
+
+        self.block.set_position(self.position);
+        self.position += self.block.size();
+
+        Ok(ulen)
+    }
 }
 
 impl<R> Reader<R>
@@ -149,7 +221,14 @@ where
 
         self.inner.seek(SeekFrom::Start(cpos))?;
 
-        read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+        read_block(
+            &mut self.inner,
+            &mut self.cdata,
+            &mut self.block,
+            self.validation,
+            self.block_count,
+        )?;
+        self.block_count += 1;
         self.position = cpos + self.block.size();
 
         self.block.set_position(cpos);
@@ -168,7 +247,15 @@ where
         // next block, reading to the block buffer can be skipped. The uncompressed data is read
         // directly to the given buffer to avoid double copying.
         if !self.block.data().has_remaining() && buf.len() >= BGZF_MAX_ISIZE {
-            read_block_into(&mut self.inner, &mut self.cdata, &mut self.block, buf)?;
+            read_block_into(
+                &mut self.inner,
+                &mut self.cdata,
+                &mut self.block,
+                buf,
+                self.validation,
+                self.block_count,
+            )?;
+            self.block_count += 1;
             self.block.set_position(self.position);
             self.position += self.block.size();
             return Ok(self.block.data().len());
@@ -207,7 +294,14 @@ where
 
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if !self.block.data().has_remaining() {
-            read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+            read_block(
+                &mut self.inner,
+                &mut self.cdata,
+                &mut self.block,
+                self.validation,
+                self.block_count,
+            )?;
+            self.block_count += 1;
             self.block.set_position(self.position);
             self.position += self.block.size();
         }
@@ -216,6 +310,44 @@ where
     }
 }
 
+/// Returns whether the stream ends with a BGZF EOF block.
+///
+/// This seeks to the end of the stream. The position of the stream is undefined after checking.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// assert!(bgzf::has_eof(&mut Cursor::new(&data))?);
+/// assert!(!bgzf::has_eof(&mut Cursor::new(&data[..data.len() - 1]))?);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn has_eof<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let eof_len = BGZF_EOF.len() as u64;
+
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    if len < eof_len {
+        return Ok(false);
+    }
+
+    reader.seek(SeekFrom::Start(len - eof_len))?;
+
+    let mut buf = vec![0; BGZF_EOF.len()];
+    reader.read_exact(&mut buf)?;
+
+    Ok(buf == BGZF_EOF)
+}
+
 /// Reads a BGZF block header.
 ///
 /// The position of the stream is expected to be at the start of a block.
@@ -309,7 +441,80 @@ pub(crate) fn inflate_data(reader: &[u8], writer: &mut [u8]) -> io::Result<()> {
     decoder.read_exact(writer)
 }
 
-fn read_compressed_block<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<(usize, (u32, usize))>
+// Inflates data that must inflate to exactly the length of the output buffer.
+#[cfg(feature = "libdeflate")]
+fn inflate_data_exact(reader: &[u8], writer: &mut [u8]) -> io::Result<()> {
+    use libdeflater::Decompressor;
+
+    let mut decoder = Decompressor::new();
+
+    let len = decoder
+        .deflate_decompress(reader, writer)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if len == writer.len() {
+        Ok(())
+    } else {
+        Err(uncompressed_size_mismatch_error())
+    }
+}
+
+// Inflates data that must inflate to exactly the length of the output buffer.
+#[cfg(not(feature = "libdeflate"))]
+fn inflate_data_exact(reader: &[u8], writer: &mut [u8]) -> io::Result<()> {
+    use flate2::bufread::DeflateDecoder;
+
+    let mut decoder = DeflateDecoder::new(reader);
+
+    decoder.read_exact(writer).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => uncompressed_size_mismatch_error(),
+        _ => e,
+    })?;
+
+    let mut buf = [0];
+
+    if decoder.read(&mut buf)? == 0 {
+        Ok(())
+    } else {
+        Err(uncompressed_size_mismatch_error())
+    }
+}
+
+fn uncompressed_size_mismatch_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "block data uncompressed size mismatch",
+    )
+}
+
+// Inflates block data, validating it as configured. `i` is the index of the block in read order.
+fn inflate_block_data(
+    cdata: &[u8],
+    crc32: u32,
+    buf: &mut [u8],
+    validation: Validation,
+    i: u64,
+) -> io::Result<()> {
+    if validation == Validation::Strict {
+        inflate_data_exact(cdata, buf)?;
+    } else {
+        inflate_data(cdata, buf)?;
+    }
+
+    if validation.validates_checksum(i) && gz::crc32(buf) != crc32 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "block data checksum mismatch",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn read_compressed_block<R>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+) -> io::Result<(usize, (u32, usize))>
 where
     R: Read,
 {
@@ -339,7 +544,13 @@ where
     Ok((clen, trailer))
 }
 
-fn read_block<R>(reader: &mut R, cdata: &mut Vec<u8>, block: &mut Block) -> io::Result<usize>
+fn read_block<R>(
+    reader: &mut R,
+    cdata: &mut Vec<u8>,
+    block: &mut Block,
+    validation: Validation,
+    i: u64,
+) -> io::Result<usize>
 where
     R: Read,
 {
@@ -355,19 +566,9 @@ where
     data.set_position(0);
     data.resize(ulen);
 
-    inflate_data(cdata, data.as_mut())?;
-
-    let mut crc = Crc::new();
-    crc.update(data.as_ref());
+    inflate_block_data(cdata, crc32, data.as_mut(), validation, i)?;
 
-    if crc.sum() == crc32 {
-        Ok(clen)
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "block data checksum mismatch",
-        ))
-    }
+    Ok(clen)
 }
 
 fn read_block_into<R>(
@@ -375,6 +576,8 @@ fn read_block_into<R>(
     cdata: &mut Vec<u8>,
     block: &mut Block,
     buf: &mut [u8],
+    validation: Validation,
+    i: u64,
 ) -> io::Result<usize>
 where
     R: Read,
@@ -391,19 +594,9 @@ where
     data.resize(ulen);
     data.set_position(ulen);
 
-    inflate_data(cdata, &mut buf[..ulen])?;
-
-    let mut crc = Crc::new();
-    crc.update(&buf[..ulen]);
+    inflate_block_data(cdata, crc32, &mut buf[..ulen], validation, i)?;
 
-    if crc.sum() == crc32 {
-        Ok(clen)
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "block data checksum mismatch",
-        ))
-    }
+    Ok(clen)
 }
 
 /// This is effectively the same as `std::io::default_read_exact`.
@@ -471,6 +664,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reader_read_block() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+
+        let mut prefix = [0; 3];
+        reader.read_exact(&mut prefix)?;
+
+        assert_eq!(reader.read_block(&mut buf)?, 4);
+        assert_eq!(buf, b"dles");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((35, 0))?
+        );
+
+        assert_eq!(reader.read_block(&mut buf)?, 4);
+        assert_eq!(buf, b"bgzf");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((67, 0))?
+        );
+
+        // EOF block
+        assert_eq!(reader.read_block(&mut buf)?, 0);
+        assert_eq!(reader.read_block(&mut buf)?, 0);
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_validation() -> io::Result<()> {
+        use std::num::NonZeroUsize;
+
+        fn read(data: &[u8], validation: Validation) -> io::Result<Vec<u8>> {
+            let mut reader = Reader::builder(data).set_validation(validation).build();
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+
+        #[rustfmt::skip]
+        let mut data = [
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(read(&data, Validation::Strict)?, b"noodles");
+
+        // CRC32
+        data[27] = 0x00;
+
+        assert!(matches!(
+            read(&data, Validation::Checksum),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            read(&data, Validation::Sampled(NonZeroUsize::new(2).unwrap())),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(read(&data, Validation::None)?, b"noodles");
+
+        // ISIZE
+        data[31] = 0x06;
+
+        assert_eq!(read(&data, Validation::None)?, b"noodle");
+
+        assert!(matches!(
+            read(&data, Validation::Strict),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_eof() -> io::Result<()> {
+        assert!(has_eof(&mut Cursor::new(BGZF_EOF))?);
+        assert!(!has_eof(&mut Cursor::new(&BGZF_EOF[1..]))?);
+        assert!(!has_eof(&mut Cursor::new(Vec::new()))?);
+
+        let mut data = BGZF_EOF.to_vec();
+        data[20] = 0x01;
+        assert!(!has_eof(&mut Cursor::new(data))?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         let mut reader = BGZF_EOF;
@@ -524,7 +816,13 @@ mod tests {
         let mut cdata = Vec::new();
         let mut block = Block::default();
 
-        let block_size = read_block(&mut reader, &mut cdata, &mut block)?;
+        let block_size = read_block(
+            &mut reader,
+            &mut cdata,
+            &mut block,
+            Validation::default(),
+            0,
+        )?;
         assert_eq!(block_size, BGZF_EOF.len());
 
         Ok(())
@@ -544,6 +842,13 @@ mod tests {
         let mut cdata = Vec::new();
         let mut block = Block::default();
 
-        assert!(read_block(&mut reader, &mut cdata, &mut block).is_err());
+        assert!(read_block(
+            &mut reader,
+            &mut cdata,
+            &mut block,
+            Validation::default(),
+            0
+        )
+        .is_err());
     }
 }
@@ -0,0 +1,59 @@
+use std::io::Read;
+
+use super::{Reader, Validation};
+
+/// A BGZF reader builder.
+#[derive(Debug)]
+pub struct Builder<R> {
+    inner: R,
+    validation: Validation,
+}
+
+impl<R> Builder<R>
+where
+    R: Read,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            validation: Validation::default(),
+        }
+    }
+
+    /// Sets the validation of block data.
+    ///
+    /// By default, the checksum of every block is validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::Validation};
+    ///
+    /// let data = [];
+    /// let builder = bgzf::Reader::builder(&data[..]).set_validation(Validation::None);
+    /// ```
+    pub fn set_validation(mut self, validation: Validation) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Builds a BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            position: 0,
+            cdata: Vec::new(),
+            block: Default::default(),
+            validation: self.validation,
+            block_count: 0,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::num::NonZeroUsize;
+
+/// The validation of BGZF block data when reading.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Validation {
+    /// Block data is not validated.
+    ///
+    /// This may be used for trusted data, as computing checksums is a measurable fraction of the
+    /// time to read a block.
+    None,
+    /// The checksum (`CRC32`) of every nth block is validated, starting with the first block.
+    Sampled(NonZeroUsize),
+    /// The checksum (`CRC32`) of every block is validated.
+    Checksum,
+    /// The checksum (`CRC32`) of every block is validated, and the compressed data must inflate
+    /// to exactly the uncompressed data size (`ISIZE`).
+    Strict,
+}
+
+impl Validation {
+    // Returns whether the block at the given index (in read order) has its checksum validated.
+    pub(crate) fn validates_checksum(&self, i: u64) -> bool {
+        match self {
+            Self::None => false,
+            Self::Sampled(n) => i % (n.get() as u64) == 0,
+            Self::Checksum | Self::Strict => true,
+        }
+    }
+}
+
+impl Default for Validation {
+    fn default() -> Self {
+        Self::Checksum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validates_checksum() {
+        assert!(!Validation::None.validates_checksum(0));
+
+        let validation = Validation::Sampled(NonZeroUsize::new(3).unwrap());
+        assert!(validation.validates_checksum(0));
+        assert!(!validation.validates_checksum(1));
+        assert!(!validation.validates_checksum(2));
+        assert!(validation.validates_checksum(3));
+
+        assert!(Validation::Checksum.validates_checksum(1));
+        assert!(Validation::Strict.validates_checksum(1));
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Validation::default(), Validation::Checksum);
+    }
+}
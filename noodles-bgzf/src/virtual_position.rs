@@ -1,6 +1,6 @@
 //! BGZF virtual position.
 
-use std::{error, fmt};
+use std::{error, fmt, num, str::FromStr};
 
 pub(crate) const MAX_COMPRESSED_POSITION: u64 = (1 << 48) - 1;
 pub(crate) const MAX_UNCOMPRESSED_POSITION: u16 = u16::MAX;
@@ -37,6 +37,26 @@ const MAX_VIRTUAL_POSITION: u64 = u64::MAX;
 pub struct VirtualPosition(u64);
 
 impl VirtualPosition {
+    /// Creates a virtual position from a compressed position and an uncompressed position.
+    ///
+    /// This returns `None` if the compressed position is larger than 2^48 - 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// assert_eq!(
+    ///     bgzf::VirtualPosition::new(57, 6086),
+    ///     Some(bgzf::VirtualPosition::from(3741638))
+    /// );
+    ///
+    /// assert!(bgzf::VirtualPosition::new(1 << 48, 0).is_none());
+    /// ```
+    pub fn new(compressed_pos: u64, uncompressed_pos: u16) -> Option<Self> {
+        Self::try_from((compressed_pos, uncompressed_pos)).ok()
+    }
+
     /// Creates the largest value that can be represented as a virtual position.
     ///
     /// # Examples
@@ -81,6 +101,103 @@ impl VirtualPosition {
     pub fn uncompressed(self) -> u16 {
         (self.0 & UNCOMPRESSED_POSITION_MASK) as u16
     }
+
+    /// Returns whether the given virtual position has the same compressed position.
+    ///
+    /// That is, both virtual positions are in the same block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let a = bgzf::VirtualPosition::from(3741638);
+    /// assert!(a.is_in_same_block(bgzf::VirtualPosition::from(3735552)));
+    /// assert!(!a.is_in_same_block(bgzf::VirtualPosition::from(3801088)));
+    /// ```
+    pub fn is_in_same_block(self, other: Self) -> bool {
+        self.compressed() == other.compressed()
+    }
+}
+
+impl fmt::Display for VirtualPosition {
+    /// Formats the virtual position as `<compressed position>:<uncompressed position>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let virtual_position = bgzf::VirtualPosition::from(3741638);
+    /// assert_eq!(virtual_position.to_string(), "57:6086");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.compressed(), self.uncompressed())
+    }
+}
+
+/// An error returned when a raw virtual position fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The delimiter is missing.
+    MissingDelimiter,
+    /// The compressed position is invalid.
+    InvalidCompressedPosition(num::ParseIntError),
+    /// The uncompressed position is invalid.
+    InvalidUncompressedPosition(num::ParseIntError),
+    /// The virtual position is invalid.
+    Invalid(TryFromU64U16TupleError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingDelimiter => f.write_str("missing delimiter"),
+            Self::InvalidCompressedPosition(e) => write!(f, "invalid compressed position: {}", e),
+            Self::InvalidUncompressedPosition(e) => {
+                write!(f, "invalid uncompressed position: {}", e)
+            }
+            Self::Invalid(e) => write!(f, "invalid virtual position: {}", e),
+        }
+    }
+}
+
+impl FromStr for VirtualPosition {
+    type Err = ParseError;
+
+    /// Parses a virtual position from `<compressed position>:<uncompressed position>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// assert_eq!("57:6086".parse(), Ok(bgzf::VirtualPosition::from(3741638)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const DELIMITER: char = ':';
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (raw_compressed_pos, raw_uncompressed_pos) = s
+            .split_once(DELIMITER)
+            .ok_or(ParseError::MissingDelimiter)?;
+
+        let compressed_pos = raw_compressed_pos
+            .parse()
+            .map_err(ParseError::InvalidCompressedPosition)?;
+
+        let uncompressed_pos = raw_uncompressed_pos
+            .parse()
+            .map_err(ParseError::InvalidUncompressedPosition)?;
+
+        Self::try_from((compressed_pos, uncompressed_pos)).map_err(ParseError::Invalid)
+    }
 }
 
 impl From<u64> for VirtualPosition {
@@ -164,6 +281,50 @@ mod tests {
         assert_eq!(pos.uncompressed(), 321);
     }
 
+    #[test]
+    fn test_is_in_same_block() {
+        let pos = VirtualPosition::from(88384945211);
+        assert!(pos.is_in_same_block(VirtualPosition::from(88384929792)));
+        assert!(!pos.is_in_same_block(VirtualPosition::from(188049630896)));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(VirtualPosition::from(0).to_string(), "0:0");
+        assert_eq!(
+            VirtualPosition::from(88384945211).to_string(),
+            "1348647:15419"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "1348647:15419".parse(),
+            Ok(VirtualPosition::from(88384945211))
+        );
+
+        assert_eq!("".parse::<VirtualPosition>(), Err(ParseError::Empty));
+        assert_eq!(
+            "1348647".parse::<VirtualPosition>(),
+            Err(ParseError::MissingDelimiter)
+        );
+        assert!(matches!(
+            "ndls:15419".parse::<VirtualPosition>(),
+            Err(ParseError::InvalidCompressedPosition(_))
+        ));
+        assert!(matches!(
+            "1348647:65536".parse::<VirtualPosition>(),
+            Err(ParseError::InvalidUncompressedPosition(_))
+        ));
+        assert_eq!(
+            "281474976710656:0".parse::<VirtualPosition>(),
+            Err(ParseError::Invalid(
+                TryFromU64U16TupleError::CompressedPositionOverflow
+            ))
+        );
+    }
+
     #[test]
     fn test_try_from_u64_u16_tuple_for_virtual_position() {
         assert_eq!(
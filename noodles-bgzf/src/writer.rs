@@ -7,11 +7,10 @@ pub use self::{builder::Builder, compression_level::CompressionLevel};
 
 use std::{
     cmp,
-    io::{self, Write},
+    io::{self, Read, Seek, Write},
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
-use flate2::Crc;
 
 use super::{gz, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
@@ -54,9 +53,9 @@ pub(crate) static BGZF_EOF: &[u8] = &[
 ];
 
 #[cfg(feature = "libdeflate")]
-type CompressionLevelImpl = libdeflater::CompressionLvl;
+pub(crate) type CompressionLevelImpl = libdeflater::CompressionLvl;
 #[cfg(not(feature = "libdeflate"))]
-type CompressionLevelImpl = flate2::Compression;
+pub(crate) type CompressionLevelImpl = flate2::Compression;
 
 /// A BZGF writer.
 ///
@@ -172,7 +171,33 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
-    fn flush_block(&mut self) -> io::Result<()> {
+    /// Ends the current block by writing any buffered data as a block.
+    ///
+    /// The next write starts a new block, i.e., the virtual position is at the start of a block.
+    /// This is useful to align record boundaries with block boundaries. If there is no buffered
+    /// data, no block is written.
+    ///
+    /// This does not flush the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// writer.flush_block()?;
+    ///
+    /// let position = writer.get_ref().len() as u64;
+    /// assert_eq!(writer.virtual_position(), bgzf::VirtualPosition::try_from((position, 0))?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn flush_block(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+
         let (cdata, crc32, r#isize) = deflate_data(&self.buf, self.compression_level)?;
 
         let inner = self.inner.as_mut().unwrap();
@@ -239,6 +264,31 @@ where
     }
 }
 
+impl<W> Writer<W>
+where
+    W: Read + Write + Seek,
+{
+    /// Creates a writer that appends to the end of the stream with a default compression level.
+    ///
+    /// See [`Builder::build_for_append`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::OpenOptions, io::{self, Write}};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let file = OpenOptions::new().read(true).write(true).open("data.gz")?;
+    /// let mut writer = bgzf::Writer::append(file)?;
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn append(inner: W) -> io::Result<Self> {
+        Self::builder(inner).build_for_append()
+    }
+}
+
 impl<W> Drop for Writer<W>
 where
     W: Write,
@@ -267,15 +317,11 @@ where
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        if self.buf.is_empty() {
-            Ok(())
-        } else {
-            self.flush_block()
-        }
+        self.flush_block()
     }
 }
 
-fn write_header<W>(writer: &mut W, block_size: usize) -> io::Result<()>
+pub(crate) fn write_header<W>(writer: &mut W, block_size: usize) -> io::Result<()>
 where
     W: Write,
 {
@@ -298,7 +344,11 @@ where
     Ok(())
 }
 
-fn write_trailer<W>(writer: &mut W, checksum: u32, uncompressed_size: u32) -> io::Result<()>
+pub(crate) fn write_trailer<W>(
+    writer: &mut W,
+    checksum: u32,
+    uncompressed_size: u32,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -327,10 +377,11 @@ pub(crate) fn deflate_data(
 
     compressed_data.resize(len, Default::default());
 
-    let mut crc = Crc::new();
-    crc.update(data);
+    let crc32 = gz::crc32(data);
+    let r#isize =
+        u32::try_from(data.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    Ok((compressed_data, crc.sum(), crc.amount()))
+    Ok((compressed_data, crc32, r#isize))
 }
 
 #[cfg(not(feature = "libdeflate"))]
@@ -344,10 +395,11 @@ pub(crate) fn deflate_data(
     encoder.write_all(data)?;
     let compressed_data = encoder.finish()?;
 
-    let mut crc = Crc::new();
-    crc.update(data);
+    let crc32 = gz::crc32(data);
+    let r#isize =
+        u32::try_from(data.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    Ok((compressed_data, crc.sum(), crc.amount()))
+    Ok((compressed_data, crc32, r#isize))
 }
 
 #[cfg(test)]
@@ -377,6 +429,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_flush_block() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+
+        writer.flush_block()?;
+        assert!(writer.get_ref().is_empty());
+
+        writer.write_all(b"noodles")?;
+        writer.flush_block()?;
+        let position = writer.position();
+        assert_eq!(position, writer.get_ref().len() as u64);
+
+        writer.flush_block()?;
+        assert_eq!(writer.position(), position);
+
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let blocks: Vec<_> = crate::RawBlocks::new(&data[..]).collect::<io::Result<_>>()?;
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1].position(), position);
+        assert_eq!(blocks[1].uncompressed_size(), 4);
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
@@ -390,6 +468,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_append() -> io::Result<()> {
+        use std::io::Cursor;
+
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        writer.write_all(b"noodles")?;
+        let inner = writer.finish()?;
+        let eof_start = inner.get_ref().len() - BGZF_EOF.len();
+
+        let mut writer = Writer::append(inner)?;
+        assert_eq!(writer.position(), eof_start as u64);
+        writer.write_all(b"-bgzf")?;
+        let mut inner = writer.finish()?;
+
+        let data = inner.get_ref();
+        assert_eq!(&data[data.len() - BGZF_EOF.len()..], BGZF_EOF);
+        assert_eq!(
+            data.windows(BGZF_EOF.len())
+                .filter(|w| *w == BGZF_EOF)
+                .count(),
+            1
+        );
+
+        assert!(crate::has_eof(&mut inner)?);
+
+        let mut reader = crate::Reader::new(&inner.get_ref()[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_without_eof() -> io::Result<()> {
+        use std::io::Cursor;
+
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let inner = writer.into_inner();
+        let len = inner.get_ref().len() as u64;
+
+        let writer = Writer::append(inner)?;
+        assert_eq!(writer.position(), len);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_header() {
         let mut writer = io::sink();
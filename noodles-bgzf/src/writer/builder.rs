@@ -1,6 +1,10 @@
-use std::io::Write;
+use std::{
+    io::{self, Read, Seek, SeekFrom, Write},
+    num::NonZeroUsize,
+};
 
-use super::{CompressionLevel, Writer, DEFAULT_BUF_SIZE};
+use super::{CompressionLevel, Writer, BGZF_EOF, DEFAULT_BUF_SIZE};
+use crate::MultithreadedWriter;
 
 /// A BGZF writer builder.
 #[derive(Debug)]
@@ -37,13 +41,13 @@ where
         self
     }
 
-    /// Builds an async BGZF writer.
+    /// Builds a BGZF writer.
     ///
     /// # Examples
     ///
     /// ```
     /// use noodles_bgzf as bgzf;
-    /// let writer = bgzf::AsyncWriter::builder(Vec::new()).build();
+    /// let writer = bgzf::Writer::builder(Vec::new()).build();
     /// ```
     pub fn build(self) -> Writer<W> {
         let compression_level = self.compression_level.unwrap_or_default();
@@ -56,3 +60,70 @@ where
         }
     }
 }
+
+impl<W> Builder<W>
+where
+    W: Write + Send + 'static,
+{
+    /// Builds a multithreaded BGZF writer with a worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+    ///
+    /// let worker_count = NonZeroUsize::new(4).unwrap();
+    ///
+    /// let writer = bgzf::Writer::builder(Vec::new())
+    ///     .set_compression_level(CompressionLevel::fast())
+    ///     .build_with_worker_count(worker_count);
+    /// ```
+    pub fn build_with_worker_count(self, worker_count: NonZeroUsize) -> MultithreadedWriter<W> {
+        let compression_level = self.compression_level.unwrap_or_default();
+        MultithreadedWriter::new(worker_count, compression_level.into(), self.inner)
+    }
+}
+
+impl<W> Builder<W>
+where
+    W: Read + Write + Seek,
+{
+    /// Builds a BGZF writer that appends to the end of the stream.
+    ///
+    /// If the stream ends with a BGZF EOF block, it is overwritten by the appended blocks, and a
+    /// new EOF block is written when the writer is finished. Otherwise, blocks are appended after
+    /// the end of the stream. The positions of the writer are relative to the start of the stream.
+    ///
+    /// The underlying stream must be readable and seekable, e.g., a file opened for reading and
+    /// writing but _not_ in append mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Cursor::new(Vec::new()));
+    /// writer.write_all(b"noodles")?;
+    /// let inner = writer.finish()?;
+    ///
+    /// let mut writer = bgzf::Writer::builder(inner).build_for_append()?;
+    /// writer.write_all(b"bgzf")?;
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_for_append(mut self) -> io::Result<Writer<W>> {
+        let position = if crate::has_eof(&mut self.inner)? {
+            let eof_len = BGZF_EOF.len() as u64;
+            self.inner.seek(SeekFrom::End(-(eof_len as i64)))?
+        } else {
+            self.inner.seek(SeekFrom::End(0))?
+        };
+
+        let mut writer = self.build();
+        writer.position = position;
+
+        Ok(writer)
+    }
+}
@@ -0,0 +1,325 @@
+//! Reference sequence name aliases.
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead},
+};
+
+use super::Region;
+
+const COMMENT_PREFIX: char = '#';
+const DELIMITER: char = '\t';
+
+/// A reference sequence assembly with a known naming convention.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Assembly {
+    /// Human genome assembly GRCh37 (hg19).
+    ///
+    /// The UCSC mitochondrial sequence (`chrM`) is not the same sequence as the Ensembl
+    /// mitochondrial sequence (`MT`), so they are not aliased.
+    Grch37,
+    /// Human genome assembly GRCh38 (hg38).
+    Grch38,
+    /// Mouse genome assembly GRCm38 (mm10).
+    Grcm38,
+    /// Mouse genome assembly GRCm39 (mm39).
+    Grcm39,
+}
+
+impl Assembly {
+    fn autosome_count(&self) -> usize {
+        match self {
+            Self::Grch37 | Self::Grch38 => 22,
+            Self::Grcm38 | Self::Grcm39 => 19,
+        }
+    }
+
+    fn has_mitochondrial_alias(&self) -> bool {
+        !matches!(self, Self::Grch37)
+    }
+}
+
+/// Reference sequence name aliases.
+///
+/// Reference sequence names that refer to the same sequence are often named differently by
+/// different sources, e.g., `chr1` (UCSC) and `1` (Ensembl). Aliases group equivalent names, which
+/// can be used to resolve a name to one that is known to a header or index, e.g., when querying.
+///
+/// Aliases are transitive: if a is an alias of b and b is an alias of c, a is an alias of c.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::aliases::{Aliases, Assembly};
+///
+/// let aliases = Aliases::from(Assembly::Grch38);
+///
+/// let names = ["1", "2", "MT"];
+/// let is_known = |name: &str| names.contains(&name);
+///
+/// assert_eq!(aliases.resolve("chr1", is_known), Some("1"));
+/// assert_eq!(aliases.resolve("chrM", is_known), Some("MT"));
+/// assert_eq!(aliases.resolve("chrX", is_known), None);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Aliases {
+    groups: Vec<Vec<String>>,
+    indices: HashMap<String, usize>,
+}
+
+impl Aliases {
+    /// Reads aliases from a tab-delimited alias table.
+    ///
+    /// Each line is a list of names that are aliases of each other, e.g., the UCSC `chromAlias.txt`
+    /// format. Empty lines and lines that start with `#` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::aliases::Aliases;
+    ///
+    /// let data = b"#ucsc\tensembl\tgenbank\nchr1\t1\tCM000663.2\n";
+    /// let aliases = Aliases::read(&data[..])?;
+    ///
+    /// let names: Vec<_> = aliases.aliases("CM000663.2").collect();
+    /// assert_eq!(names, ["chr1", "1"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read<R>(reader: R) -> io::Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut aliases = Self::default();
+
+        for result in reader.lines() {
+            let line = result?;
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let mut names = line.split(DELIMITER).filter(|s| !s.is_empty());
+
+            if let Some(name) = names.next() {
+                aliases.insert_name(name);
+
+                for alias in names {
+                    aliases.insert(name, alias);
+                }
+            }
+        }
+
+        Ok(aliases)
+    }
+
+    /// Adds an alias.
+    ///
+    /// If either name already has aliases, the aliases of both names are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::aliases::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert("chr1", "1");
+    /// aliases.insert("1", "NC_000001.11");
+    ///
+    /// let names: Vec<_> = aliases.aliases("chr1").collect();
+    /// assert_eq!(names, ["1", "NC_000001.11"]);
+    /// ```
+    pub fn insert(&mut self, name: &str, alias: &str) {
+        let i = self.insert_name(name);
+        let j = self.insert_name(alias);
+
+        if i == j {
+            return;
+        }
+
+        // Merge the smaller group into the larger group.
+        let (dst, src) = if self.groups[i].len() >= self.groups[j].len() {
+            (i, j)
+        } else {
+            (j, i)
+        };
+
+        let names = std::mem::take(&mut self.groups[src]);
+
+        for name in &names {
+            self.indices.insert(name.clone(), dst);
+        }
+
+        self.groups[dst].extend(names);
+    }
+
+    /// Returns an iterator over the aliases of the given name.
+    ///
+    /// The given name is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::aliases::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert("chr1", "1");
+    ///
+    /// assert_eq!(aliases.aliases("1").collect::<Vec<_>>(), ["chr1"]);
+    /// assert_eq!(aliases.aliases("2").count(), 0);
+    /// ```
+    pub fn aliases<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.indices
+            .get(name)
+            .map(|&i| self.groups[i].as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|s| s.as_str())
+            .filter(move |s| *s != name)
+    }
+
+    /// Resolves a name to a known name.
+    ///
+    /// If the given name is known, it is returned. Otherwise, the first alias that is known is
+    /// returned, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::aliases::Aliases;
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert("chr1", "1");
+    ///
+    /// let is_known = |name: &str| name == "1";
+    /// assert_eq!(aliases.resolve("chr1", is_known), Some("1"));
+    /// assert_eq!(aliases.resolve("1", is_known), Some("1"));
+    /// assert_eq!(aliases.resolve("chr2", is_known), None);
+    /// ```
+    pub fn resolve<'a, F>(&'a self, name: &'a str, is_known: F) -> Option<&'a str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        if is_known(name) {
+            Some(name)
+        } else {
+            self.aliases(name).find(|alias| is_known(alias))
+        }
+    }
+
+    /// Resolves the name of a region to a known name.
+    ///
+    /// This returns a region with the same interval and a resolved name (see [`Self::resolve`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{aliases::Aliases, Region};
+    ///
+    /// let mut aliases = Aliases::default();
+    /// aliases.insert("chr1", "1");
+    ///
+    /// let region: Region = "chr1:100-200".parse()?;
+    /// let is_known = |name: &str| name == "1";
+    ///
+    /// assert_eq!(
+    ///     aliases.resolve_region(&region, is_known),
+    ///     Some("1:100-200".parse()?)
+    /// );
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn resolve_region<F>(&self, region: &Region, is_known: F) -> Option<Region>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.resolve(region.name(), is_known)
+            .map(|name| Region::new(name, region.interval()))
+    }
+
+    fn insert_name(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.indices.get(name) {
+            return i;
+        }
+
+        let i = self.groups.len();
+        self.groups.push(vec![name.into()]);
+        self.indices.insert(name.into(), i);
+
+        i
+    }
+}
+
+impl From<Assembly> for Aliases {
+    /// Creates aliases between the UCSC and Ensembl names of the primary chromosomes of an
+    /// assembly, e.g., `chr1` and `1`.
+    fn from(assembly: Assembly) -> Self {
+        let mut aliases = Self::default();
+
+        let names = (1..=assembly.autosome_count())
+            .map(|n| n.to_string())
+            .chain(["X", "Y"].iter().map(|s| s.to_string()));
+
+        for name in names {
+            aliases.insert(&format!("chr{}", name), &name);
+        }
+
+        if assembly.has_mitochondrial_alias() {
+            aliases.insert("chrM", "MT");
+        }
+
+        aliases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data = b"# comment\n\nchr1\t1\tNC_000001.11\nchrM\tMT\nchrUn\n";
+        let aliases = Aliases::read(&data[..])?;
+
+        assert_eq!(
+            aliases.aliases("1").collect::<Vec<_>>(),
+            ["chr1", "NC_000001.11"]
+        );
+        assert_eq!(aliases.aliases("chrM").collect::<Vec<_>>(), ["MT"]);
+        assert_eq!(aliases.aliases("chrUn").count(), 0);
+        assert_eq!(aliases.aliases("# comment").count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut aliases = Aliases::default();
+
+        aliases.insert("chr1", "1");
+        aliases.insert("NC_000001.11", "CM000663.2");
+        aliases.insert("1", "CM000663.2");
+        aliases.insert("chr1", "1");
+
+        let mut names: Vec<_> = aliases.aliases("chr1").collect();
+        names.sort_unstable();
+        assert_eq!(names, ["1", "CM000663.2", "NC_000001.11"]);
+
+        assert_eq!(aliases.groups.iter().filter(|g| !g.is_empty()).count(), 1);
+    }
+
+    #[test]
+    fn test_from_assembly_for_aliases() {
+        let aliases = Aliases::from(Assembly::Grch38);
+        assert_eq!(aliases.aliases("chr22").collect::<Vec<_>>(), ["22"]);
+        assert_eq!(aliases.aliases("chrM").collect::<Vec<_>>(), ["MT"]);
+        assert_eq!(aliases.aliases("chr23").count(), 0);
+
+        let aliases = Aliases::from(Assembly::Grch37);
+        assert_eq!(aliases.aliases("Y").collect::<Vec<_>>(), ["chrY"]);
+        assert_eq!(aliases.aliases("chrM").count(), 0);
+
+        let aliases = Aliases::from(Assembly::Grcm39);
+        assert_eq!(aliases.aliases("chr19").collect::<Vec<_>>(), ["19"]);
+        assert_eq!(aliases.aliases("chr20").count(), 0);
+    }
+}
@@ -0,0 +1,303 @@
+//! Coordinate systems.
+//!
+//! noodles uses 1-based, closed intervals ([`OneBasedInterval`]) throughout, e.g., SAM, VCF, and
+//! GFF positions. Some formats and protocols, e.g., BED, BAM, and htsget, instead use 0-based,
+//! half-open intervals ([`ZeroBasedInterval`]). The interval types make the coordinate system
+//! explicit and convert between each other with checked conversions.
+//!
+//! # Examples
+//!
+//! ```
+//! use noodles_core::{
+//!     coordinates::{OneBasedInterval, ZeroBasedInterval},
+//!     Position,
+//! };
+//!
+//! // BED `chromStart` and `chromEnd`
+//! let interval = ZeroBasedInterval::new(7, 13).expect("invalid interval");
+//! let interval = OneBasedInterval::try_from(interval)?;
+//!
+//! assert_eq!(interval.start(), Position::try_from(8)?);
+//! assert_eq!(interval.end(), Position::try_from(13)?);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{error, fmt, ops::RangeInclusive};
+
+use super::{region::Interval, Position};
+
+/// A 0-based, half-open interval.
+///
+/// The start position is inclusive, and the end position is exclusive, i.e., `[start, end)`. The
+/// interval is empty when the start and end positions are equal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ZeroBasedInterval {
+    start: usize,
+    end: usize,
+}
+
+impl ZeroBasedInterval {
+    /// Creates a 0-based, half-open interval.
+    ///
+    /// This returns `None` if the start position is greater than the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::coordinates::ZeroBasedInterval;
+    /// assert!(ZeroBasedInterval::new(7, 13).is_some());
+    /// assert!(ZeroBasedInterval::new(13, 13).is_some());
+    /// assert!(ZeroBasedInterval::new(13, 7).is_none());
+    /// ```
+    pub const fn new(start: usize, end: usize) -> Option<Self> {
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the start position (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::coordinates::ZeroBasedInterval;
+    /// let interval = ZeroBasedInterval::new(7, 13).expect("invalid interval");
+    /// assert_eq!(interval.start(), 7);
+    /// ```
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the end position (exclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::coordinates::ZeroBasedInterval;
+    /// let interval = ZeroBasedInterval::new(7, 13).expect("invalid interval");
+    /// assert_eq!(interval.end(), 13);
+    /// ```
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the number of positions in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::coordinates::ZeroBasedInterval;
+    /// let interval = ZeroBasedInterval::new(7, 13).expect("invalid interval");
+    /// assert_eq!(interval.len(), 6);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns whether the interval is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::coordinates::ZeroBasedInterval;
+    /// assert!(!ZeroBasedInterval::new(7, 13).expect("invalid interval").is_empty());
+    /// assert!(ZeroBasedInterval::new(13, 13).expect("invalid interval").is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<OneBasedInterval> for ZeroBasedInterval {
+    fn from(interval: OneBasedInterval) -> Self {
+        Self {
+            start: interval.start.to_zero_based(),
+            end: usize::from(interval.end),
+        }
+    }
+}
+
+/// A 1-based, closed interval.
+///
+/// Both the start and end positions are inclusive, i.e., `[start, end]`. The interval is never
+/// empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OneBasedInterval {
+    start: Position,
+    end: Position,
+}
+
+impl OneBasedInterval {
+    /// Creates a 1-based, closed interval.
+    ///
+    /// This returns `None` if the start position is greater than the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{coordinates::OneBasedInterval, Position};
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// assert!(OneBasedInterval::new(start, end).is_some());
+    /// assert!(OneBasedInterval::new(end, end).is_some());
+    /// assert!(OneBasedInterval::new(end, start).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new(start: Position, end: Position) -> Option<Self> {
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the start position (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{coordinates::OneBasedInterval, Position};
+    /// let start = Position::try_from(8)?;
+    /// let interval = OneBasedInterval::new(start, Position::try_from(13)?).expect("invalid interval");
+    /// assert_eq!(interval.start(), start);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{coordinates::OneBasedInterval, Position};
+    /// let end = Position::try_from(13)?;
+    /// let interval = OneBasedInterval::new(Position::try_from(8)?, end).expect("invalid interval");
+    /// assert_eq!(interval.end(), end);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the number of positions in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{coordinates::OneBasedInterval, Position};
+    ///
+    /// let interval = OneBasedInterval::new(Position::try_from(8)?, Position::try_from(13)?)
+    ///     .expect("invalid interval");
+    ///
+    /// assert_eq!(interval.len(), 6);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        usize::from(self.end) - usize::from(self.start) + 1
+    }
+}
+
+/// An error returned when a 0-based, half-open interval fails to convert to a 1-based, closed
+/// interval.
+///
+/// A 1-based, closed interval cannot be empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TryFromZeroBasedIntervalError(ZeroBasedInterval);
+
+impl error::Error for TryFromZeroBasedIntervalError {}
+
+impl fmt::Display for TryFromZeroBasedIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "empty interval: [{}, {})", self.0.start, self.0.end)
+    }
+}
+
+impl TryFrom<ZeroBasedInterval> for OneBasedInterval {
+    type Error = TryFromZeroBasedIntervalError;
+
+    fn try_from(interval: ZeroBasedInterval) -> Result<Self, Self::Error> {
+        if interval.is_empty() {
+            return Err(TryFromZeroBasedIntervalError(interval));
+        }
+
+        // `start < end`, so `start + 1` does not overflow, and `end` is nonzero.
+        match (
+            Position::from_zero_based(interval.start),
+            Position::new(interval.end),
+        ) {
+            (Some(start), Some(end)) => Ok(Self { start, end }),
+            _ => Err(TryFromZeroBasedIntervalError(interval)),
+        }
+    }
+}
+
+impl From<OneBasedInterval> for RangeInclusive<Position> {
+    fn from(interval: OneBasedInterval) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+impl From<OneBasedInterval> for Interval {
+    fn from(interval: OneBasedInterval) -> Self {
+        Self::from(RangeInclusive::from(interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_zero_based_interval_for_one_based_interval(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let interval = ZeroBasedInterval::new(0, 1).expect("invalid interval");
+        assert_eq!(
+            OneBasedInterval::try_from(interval)?,
+            OneBasedInterval::new(Position::MIN, Position::MIN).expect("invalid interval")
+        );
+
+        let interval =
+            ZeroBasedInterval::new(usize::MAX - 1, usize::MAX).expect("invalid interval");
+        assert_eq!(
+            OneBasedInterval::try_from(interval)?,
+            OneBasedInterval::new(Position::MAX, Position::MAX).expect("invalid interval")
+        );
+
+        let interval = ZeroBasedInterval::new(0, 0).expect("invalid interval");
+        assert_eq!(
+            OneBasedInterval::try_from(interval),
+            Err(TryFromZeroBasedIntervalError(interval))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_one_based_interval_for_zero_based_interval(
+    ) -> Result<(), crate::position::TryFromIntError> {
+        let interval = OneBasedInterval::new(Position::try_from(8)?, Position::try_from(13)?)
+            .expect("invalid interval");
+
+        assert_eq!(
+            ZeroBasedInterval::from(interval),
+            ZeroBasedInterval::new(7, 13).expect("invalid interval")
+        );
+
+        let interval =
+            OneBasedInterval::new(Position::MAX, Position::MAX).expect("invalid interval");
+
+        assert_eq!(
+            ZeroBasedInterval::from(interval),
+            ZeroBasedInterval::new(usize::MAX - 1, usize::MAX).expect("invalid interval")
+        );
+
+        Ok(())
+    }
+}
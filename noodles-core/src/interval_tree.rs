@@ -0,0 +1,422 @@
+//! Static interval tree.
+
+use std::{cmp, iter::FromIterator, ops::RangeInclusive};
+
+use super::{region::Interval, Position};
+
+// Subtrees at or below this level are scanned linearly rather than traversed.
+const MAX_SCAN_LEVEL: usize = 3;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Node<T> {
+    start: Position,
+    end: Position,
+    max_end: Position,
+    value: T,
+}
+
+/// A static interval tree.
+///
+/// An interval tree holds closed intervals, each with an associated value, and answers overlap
+/// queries. It is built once from a collection of intervals (see [`FromIterator`]).
+///
+/// This is an implicit augmented interval tree: the intervals are sorted by start position in a
+/// single array that is laid out as a binary search tree, where each node stores the max end
+/// position of its subtree. A query visits O(log n + m) nodes, where m is the number of
+/// overlapping intervals.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{interval_tree::IntervalTree, Position};
+///
+/// let tree: IntervalTree<&str> = [
+///     (Position::try_from(8)?..=Position::try_from(13)?, "r0"),
+///     (Position::try_from(21)?..=Position::try_from(34)?, "r1"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let values: Vec<_> = tree
+///     .query(Position::try_from(13)?..=Position::try_from(21)?)
+///     .map(|(_, value)| *value)
+///     .collect();
+///
+/// assert_eq!(values, ["r0", "r1"]);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntervalTree<T> {
+    nodes: Vec<Node<T>>,
+    max_level: usize,
+}
+
+impl<T> IntervalTree<T> {
+    /// Returns the number of intervals in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::interval_tree::IntervalTree;
+    /// let tree: IntervalTree<()> = IntervalTree::default();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether there are any intervals in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::interval_tree::IntervalTree;
+    /// let tree: IntervalTree<()> = IntervalTree::default();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over all intervals in the tree, sorted by start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{interval_tree::IntervalTree, Position};
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let tree: IntervalTree<_> = [(start..=end, "r0")].into_iter().collect();
+    ///
+    /// let mut iter = tree.iter();
+    /// assert_eq!(iter.next(), Some((start..=end, &"r0")));
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (RangeInclusive<Position>, &T)> {
+        self.nodes
+            .iter()
+            .map(|node| (node.start..=node.end, &node.value))
+    }
+
+    /// Returns an iterator over the intervals that overlap the given interval, sorted by start
+    /// position.
+    ///
+    /// An unbounded start or end of the given interval is treated as the min or max position,
+    /// respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{interval_tree::IntervalTree, Position};
+    ///
+    /// let tree: IntervalTree<_> = [
+    ///     (Position::try_from(8)?..=Position::try_from(13)?, "r0"),
+    ///     (Position::try_from(21)?..=Position::try_from(34)?, "r1"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut query = tree.query(Position::try_from(5)?..=Position::try_from(8)?);
+    /// assert_eq!(
+    ///     query.next(),
+    ///     Some((Position::try_from(8)?..=Position::try_from(13)?, &"r0"))
+    /// );
+    /// assert!(query.next().is_none());
+    ///
+    /// assert_eq!(tree.query(Position::try_from(21)?..).count(), 1);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn query<I>(&self, interval: I) -> Query<'_, T>
+    where
+        I: Into<Interval>,
+    {
+        let interval = interval.into();
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        let mut stack = Vec::new();
+
+        if !self.nodes.is_empty() {
+            let k = self.max_level;
+            stack.push(Frame {
+                x: (1 << k) - 1,
+                k,
+                is_left_visited: false,
+            });
+        }
+
+        Query {
+            nodes: &self.nodes,
+            start,
+            end,
+            stack,
+            scan: None,
+        }
+    }
+}
+
+impl<T> Default for IntervalTree<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            max_level: 0,
+        }
+    }
+}
+
+impl<T> FromIterator<(RangeInclusive<Position>, T)> for IntervalTree<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (RangeInclusive<Position>, T)>,
+    {
+        let mut nodes: Vec<_> = iter
+            .into_iter()
+            .map(|(interval, value)| {
+                let (start, end) = interval.into_inner();
+
+                Node {
+                    start,
+                    end,
+                    max_end: end,
+                    value,
+                }
+            })
+            .collect();
+
+        nodes.sort_by_key(|node| node.start);
+
+        let max_level = index(&mut nodes);
+
+        Self { nodes, max_level }
+    }
+}
+
+#[derive(Debug)]
+struct Frame {
+    x: usize,
+    k: usize,
+    is_left_visited: bool,
+}
+
+/// An iterator over the intervals in an interval tree that overlap a query interval.
+///
+/// This is created by calling [`IntervalTree::query`].
+#[derive(Debug)]
+pub struct Query<'a, T> {
+    nodes: &'a [Node<T>],
+    start: Position,
+    end: Position,
+    stack: Vec<Frame>,
+    scan: Option<RangeInclusive<usize>>,
+}
+
+impl<'a, T> Iterator for Query<'a, T> {
+    type Item = (RangeInclusive<Position>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let nodes = self.nodes;
+
+        loop {
+            if let Some(scan) = self.scan.as_mut() {
+                for i in scan.by_ref() {
+                    let node = &nodes[i];
+
+                    if node.start > self.end {
+                        break;
+                    } else if self.start <= node.end {
+                        return Some((node.start..=node.end, &node.value));
+                    }
+                }
+
+                self.scan = None;
+            }
+
+            let frame = self.stack.pop()?;
+            let (x, k) = (frame.x, frame.k);
+
+            if k <= MAX_SCAN_LEVEL {
+                let i0 = x >> k << k;
+                let i1 = cmp::min(i0 + (1 << (k + 1)) - 1, nodes.len());
+
+                if i0 < i1 {
+                    self.scan = Some(i0..=i1 - 1);
+                }
+            } else if !frame.is_left_visited {
+                self.stack.push(Frame {
+                    is_left_visited: true,
+                    ..frame
+                });
+
+                let y = x - (1 << (k - 1));
+
+                if y >= nodes.len() || nodes[y].max_end >= self.start {
+                    self.stack.push(Frame {
+                        x: y,
+                        k: k - 1,
+                        is_left_visited: false,
+                    });
+                }
+            } else if x < nodes.len() && nodes[x].start <= self.end {
+                self.stack.push(Frame {
+                    x: x + (1 << (k - 1)),
+                    k: k - 1,
+                    is_left_visited: false,
+                });
+
+                let node = &nodes[x];
+
+                if self.start <= node.end {
+                    return Some((node.start..=node.end, &node.value));
+                }
+            }
+        }
+    }
+}
+
+// Sets the max end position of each node in the implicit tree and returns the max level.
+//
+// A node at index i is at the level of the number of trailing 1 bits of i. Subtrees on the right
+// edge of the tree may be incomplete, in which case the max end position of the rightmost node is
+// used for missing children.
+fn index<T>(nodes: &mut [Node<T>]) -> usize {
+    let n = nodes.len();
+
+    if n == 0 {
+        return 0;
+    }
+
+    let mut last_i = 0;
+    let mut last = nodes[0].end;
+
+    for i in (0..n).step_by(2) {
+        nodes[i].max_end = nodes[i].end;
+        last_i = i;
+        last = nodes[i].max_end;
+    }
+
+    let mut k = 1;
+
+    while 1 << k <= n {
+        let x = 1 << (k - 1);
+        let i0 = (x << 1) - 1;
+        let step = x << 2;
+
+        for i in (i0..n).step_by(step) {
+            let left_max_end = nodes[i - x].max_end;
+            let right_max_end = if i + x < n {
+                nodes[i + x].max_end
+            } else {
+                last
+            };
+
+            nodes[i].max_end = cmp::max(nodes[i].end, cmp::max(left_max_end, right_max_end));
+        }
+
+        last_i = if (last_i >> k) & 1 == 1 {
+            last_i - x
+        } else {
+            last_i + x
+        };
+
+        if last_i < n {
+            last = cmp::max(last, nodes[last_i].max_end);
+        }
+
+        k += 1;
+    }
+
+    k - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(intervals: &[(usize, usize)]) -> IntervalTree<usize> {
+        intervals
+            .iter()
+            .enumerate()
+            .map(|(i, &(start, end))| {
+                let start = Position::new(start).unwrap();
+                let end = Position::new(end).unwrap();
+                (start..=end, i)
+            })
+            .collect()
+    }
+
+    fn query(tree: &IntervalTree<usize>, start: usize, end: usize) -> Vec<usize> {
+        let start = Position::new(start).unwrap();
+        let end = Position::new(end).unwrap();
+        let mut values: Vec<_> = tree.query(start..=end).map(|(_, i)| *i).collect();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn test_query() {
+        let tree = build(&[(8, 13), (21, 34), (5, 55), (13, 13), (89, 144)]);
+
+        assert_eq!(query(&tree, 1, 4), Vec::<usize>::new());
+        assert_eq!(query(&tree, 13, 13), [0, 2, 3]);
+        assert_eq!(query(&tree, 14, 20), [2]);
+        assert_eq!(query(&tree, 56, 88), Vec::<usize>::new());
+        assert_eq!(query(&tree, 144, 233), [4]);
+        assert_eq!(query(&tree, 1, 1000), [0, 1, 2, 3, 4]);
+
+        let tree = IntervalTree::<usize>::default();
+        assert_eq!(query(&tree, 1, 1000), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_query_is_sorted_by_start() {
+        let tree = build(&[(21, 34), (8, 13), (5, 55)]);
+
+        let starts: Vec<_> = tree
+            .query(..)
+            .map(|(interval, _)| usize::from(*interval.start()))
+            .collect();
+
+        assert_eq!(starts, [5, 8, 21]);
+    }
+
+    #[test]
+    fn test_query_against_linear_scan() {
+        // A linear congruential generator for deterministic pseudorandom intervals.
+        let mut state: u64 = 0x6e6f6f646c6573;
+        let mut next = |n: usize| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+
+        for n in [1, 2, 3, 7, 8, 9, 15, 16, 17, 31, 33, 100, 257, 1000] {
+            let intervals: Vec<_> = (0..n)
+                .map(|_| {
+                    let start = next(10000) + 1;
+                    let len = if next(10) == 0 { next(5000) } else { next(100) };
+                    (start, start + len)
+                })
+                .collect();
+
+            let tree = build(&intervals);
+            assert_eq!(tree.len(), n);
+
+            for _ in 0..50 {
+                let start = next(12000) + 1;
+                let end = start + next(500);
+
+                let expected: Vec<_> = intervals
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &(s, e))| s <= end && start <= e)
+                    .map(|(i, _)| i)
+                    .collect();
+
+                assert_eq!(query(&tree, start, end), expected);
+            }
+        }
+    }
+}
@@ -2,7 +2,15 @@
 
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
+pub mod aliases;
+pub mod coordinates;
+pub mod interval_tree;
 pub mod position;
 pub mod region;
+pub mod region_map;
+pub mod sequence_dictionary;
+pub mod strand;
 
-pub use self::{position::Position, region::Region};
+pub use self::{
+    position::Position, region::Region, sequence_dictionary::SequenceDictionary, strand::Strand,
+};
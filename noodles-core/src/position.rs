@@ -59,6 +59,38 @@ impl Position {
     pub fn checked_add(self, other: usize) -> Option<Self> {
         usize::from(self).checked_add(other).and_then(Self::new)
     }
+
+    /// Creates a position from a 0-based position.
+    ///
+    /// This returns `None` if the operation overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert_eq!(Position::from_zero_based(7), Position::new(8));
+    /// assert!(Position::from_zero_based(usize::MAX).is_none());
+    /// ```
+    pub const fn from_zero_based(n: usize) -> Option<Self> {
+        match n.checked_add(1) {
+            Some(m) => Self::new(m),
+            None => None,
+        }
+    }
+
+    /// Converts the position to a 0-based position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.to_zero_based(), 7);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn to_zero_based(self) -> usize {
+        self.0.get() - 1
+    }
 }
 
 impl fmt::Display for Position {
@@ -1,8 +1,9 @@
 //! Genomic region.
 
 pub mod interval;
+mod selector;
 
-pub use self::interval::Interval;
+pub use self::{interval::Interval, selector::Selector};
 
 use std::{
     error, fmt,
@@ -117,6 +118,143 @@ impl Region {
     pub fn interval(&self) -> Interval {
         self.interval
     }
+
+    /// Returns whether the given region overlaps this region.
+    ///
+    /// Regions overlap if they have the same reference sequence name and their intervals
+    /// intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?);
+    ///
+    /// let b = Region::new("sq0", Position::try_from(8)?..);
+    /// assert!(a.overlaps(&b));
+    ///
+    /// let c = Region::new("sq1", Position::try_from(8)?..);
+    /// assert!(!a.overlaps(&c));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.name == other.name && self.interval.intersects(other.interval)
+    }
+
+    /// Returns the intersection of this region and the given region.
+    ///
+    /// This returns `None` if the regions do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?);
+    /// let b = Region::new("sq0", Position::try_from(8)?..);
+    ///
+    /// assert_eq!(
+    ///     a.intersect(&b),
+    ///     Some(Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?))
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        if self.name != other.name {
+            return None;
+        }
+
+        self.interval
+            .intersect(other.interval)
+            .map(|interval| Self::new(self.name.clone(), interval))
+    }
+
+    /// Returns the union of this region and the given region.
+    ///
+    /// This returns `None` if the regions have different reference sequence names or their
+    /// intervals neither intersect nor touch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?);
+    /// let b = Region::new("sq0", Position::try_from(9)?..=Position::try_from(13)?);
+    ///
+    /// assert_eq!(
+    ///     a.union(&b),
+    ///     Some(Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?))
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.name != other.name {
+            return None;
+        }
+
+        self.interval
+            .union(other.interval)
+            .map(|interval| Self::new(self.name.clone(), interval))
+    }
+
+    /// Returns whether the given region is entirely within this region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let a = Region::new("sq0", ..);
+    /// let b = Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(a.contains(&b));
+    /// assert!(!b.contains(&a));
+    ///
+    /// let c = Region::new("sq1", Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(!a.contains(&c));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains(&self, other: &Self) -> bool {
+        self.name == other.name && self.interval.contains_interval(other.interval)
+    }
+
+    /// Returns the number of positions in the region.
+    ///
+    /// This returns `None` if the end is unbounded. See [`Interval::len`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let region = Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert_eq!(region.len(), Some(6));
+    ///
+    /// let region = Region::new("sq0", ..);
+    /// assert!(region.len().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        self.interval.len()
+    }
+
+    /// Returns whether the region has no positions.
+    ///
+    /// See [`Interval::is_empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let region = Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(!region.is_empty());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.interval.is_empty()
+    }
 }
 
 impl fmt::Display for Region {
@@ -161,11 +299,44 @@ impl fmt::Display for ParseError {
 impl FromStr for Region {
     type Err = ParseError;
 
+    /// Parses a region.
+    ///
+    /// A region is a reference sequence name and an optional interval, separated by a colon
+    /// (`NAME[:INTERVAL]`). See [`Interval`] for the accepted interval forms. A name that includes
+    /// a colon can be wrapped in braces (`{NAME}[:INTERVAL]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let start = Position::try_from(10000)?;
+    /// let end = Position::try_from(20000)?;
+    ///
+    /// assert_eq!("sq0".parse(), Ok(Region::new("sq0", ..)));
+    /// assert_eq!("sq0:10,000-20,000".parse(), Ok(Region::new("sq0", start..=end)));
+    /// assert_eq!("sq0:10000-".parse(), Ok(Region::new("sq0", start..)));
+    /// assert_eq!("{HLA-A*01:01}:1-8".parse::<Region>()?.name(), "HLA-A*01:01");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
             return Err(ParseError::Empty);
         }
 
+        if let Some(t) = s.strip_prefix('{') {
+            let (name, suffix) = t.split_once('}').ok_or(ParseError::Invalid)?;
+
+            if suffix.is_empty() {
+                return Ok(Self::new(name, ..));
+            }
+
+            let raw_interval = suffix.strip_prefix(':').ok_or(ParseError::Invalid)?;
+            let interval: Interval = raw_interval.parse().map_err(ParseError::InvalidInterval)?;
+
+            return Ok(Self::new(name, interval));
+        }
+
         if let Some((name, suffix)) = s.rsplit_once(':') {
             let interval: Interval = suffix.parse().map_err(ParseError::InvalidInterval)?;
             Ok(Self::new(name, interval))
@@ -179,6 +350,61 @@ impl FromStr for Region {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_overlaps() -> Result<(), crate::position::TryFromIntError> {
+        let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?);
+        assert!(a.overlaps(&Region::new("sq0", ..)));
+        assert!(!a.overlaps(&Region::new("sq1", ..)));
+        assert!(!a.overlaps(&Region::new("sq0", Position::try_from(9)?..)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() -> Result<(), crate::position::TryFromIntError> {
+        let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?);
+
+        assert_eq!(
+            a.intersect(&Region::new("sq0", ..=Position::try_from(6)?)),
+            Some(Region::new(
+                "sq0",
+                Position::try_from(5)?..=Position::try_from(6)?
+            ))
+        );
+
+        assert!(a.intersect(&Region::new("sq1", ..)).is_none());
+        assert!(a
+            .intersect(&Region::new("sq0", Position::try_from(9)?..))
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() -> Result<(), crate::position::TryFromIntError> {
+        let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?);
+
+        assert_eq!(
+            a.union(&Region::new("sq0", Position::try_from(9)?..)),
+            Some(Region::new("sq0", Position::try_from(5)?..))
+        );
+
+        assert!(a.union(&Region::new("sq1", ..)).is_none());
+        assert!(a
+            .union(&Region::new("sq0", Position::try_from(10)?..))
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains() -> Result<(), crate::position::TryFromIntError> {
+        let a = Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?);
+        assert!(Region::new("sq0", ..).contains(&a));
+        assert!(!Region::new("sq1", ..).contains(&a));
+        assert!(!a.contains(&Region::new("sq0", ..)));
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), crate::position::TryFromIntError> {
         let start = Position::try_from(5)?;
@@ -203,6 +429,24 @@ mod tests {
         let end = Position::try_from(8)?;
         assert_eq!("sq3:5-8".parse(), Ok(Region::new("sq3", start..=end)));
 
+        assert_eq!("sq4:5-".parse(), Ok(Region::new("sq4", start..)));
+        assert_eq!("sq5:-8".parse(), Ok(Region::new("sq5", ..=end)));
+
+        let start = Position::try_from(10000)?;
+        let end = Position::try_from(20000)?;
+        assert_eq!(
+            "sq6:10,000-20,000".parse(),
+            Ok(Region::new("sq6", start..=end))
+        );
+
+        assert_eq!("{sq7:1}".parse(), Ok(Region::new("sq7:1", ..)));
+        assert_eq!(
+            "{sq8:1}:10000-20000".parse(),
+            Ok(Region::new("sq8:1", start..=end))
+        );
+        assert_eq!("{sq9".parse::<Region>(), Err(ParseError::Invalid));
+        assert_eq!("{sq9}10".parse::<Region>(), Err(ParseError::Invalid));
+
         assert_eq!("".parse::<Region>(), Err(ParseError::Empty));
 
         Ok(())
@@ -87,6 +87,163 @@ impl Interval {
 
         a_start <= b_end && b_start <= a_end
     }
+
+    /// Returns the intersection of this interval and the given interval.
+    ///
+    /// This returns `None` if the intervals do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// let b = Interval::from(Position::try_from(8)?..);
+    /// assert_eq!(
+    ///     a.intersect(b),
+    ///     Some(Interval::from(Position::try_from(8)?..=Position::try_from(13)?))
+    /// );
+    ///
+    /// let c = Interval::from(Position::try_from(2)?..=Position::try_from(3)?);
+    /// assert!(a.intersect(c).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersect(&self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let start = match (self.start, other.start) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+
+        let end = match (self.end, other.end) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        Some(Self { start, end })
+    }
+
+    /// Returns the union of this interval and the given interval.
+    ///
+    /// This returns `None` if the intervals neither intersect nor touch, i.e., the union would not
+    /// be a single interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+    /// let b = Interval::from(Position::try_from(9)?..=Position::try_from(13)?);
+    /// assert_eq!(
+    ///     a.union(b),
+    ///     Some(Interval::from(Position::try_from(5)?..=Position::try_from(13)?))
+    /// );
+    ///
+    /// let c = Interval::from(Position::try_from(2)?..=Position::try_from(3)?);
+    /// assert!(a.union(c).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn union(&self, other: Self) -> Option<Self> {
+        fn is_adjacent(a: Interval, b: Interval) -> bool {
+            match (a.end, b.start) {
+                (Some(end), Some(start)) => end.checked_add(1) == Some(start),
+                _ => false,
+            }
+        }
+
+        if !self.intersects(other) && !is_adjacent(*self, other) && !is_adjacent(other, *self) {
+            return None;
+        }
+
+        let start = self.start.zip(other.start).map(|(a, b)| a.min(b));
+        let end = self.end.zip(other.end).map(|(a, b)| a.max(b));
+
+        Some(Self { start, end })
+    }
+
+    /// Returns whether the given interval is entirely within this interval.
+    ///
+    /// To check whether a position is within this interval, use [`RangeBounds::contains`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..);
+    /// let b = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(a.contains_interval(b));
+    /// assert!(!b.contains_interval(a));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains_interval(&self, other: Self) -> bool {
+        let contains_start = match (self.start, other.start) {
+            (Some(a), Some(b)) => a <= b,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        let contains_end = match (self.end, other.end) {
+            (Some(a), Some(b)) => b <= a,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        contains_start && contains_end
+    }
+
+    /// Returns the number of positions in the interval.
+    ///
+    /// An unbounded start is treated as the minimum position (1). This returns `None` if the end
+    /// is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert_eq!(a.len(), Some(6));
+    ///
+    /// let b = Interval::from(..=Position::try_from(13)?);
+    /// assert_eq!(b.len(), Some(13));
+    ///
+    /// let c = Interval::from(Position::try_from(8)?..);
+    /// assert!(c.len().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        let start = self.start.unwrap_or(Position::MIN);
+        let end = self.end?;
+
+        if start > end {
+            Some(0)
+        } else {
+            Some(usize::from(end) - usize::from(start) + 1)
+        }
+    }
+
+    /// Returns whether the interval has no positions, i.e., the start is after the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(!a.is_empty());
+    ///
+    /// let b = Interval::from(Position::try_from(13)?..=Position::try_from(8)?);
+    /// assert!(b.is_empty());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        matches!((self.start, self.end), (Some(start), Some(end)) if start > end)
+    }
 }
 
 impl fmt::Display for Interval {
@@ -139,6 +296,25 @@ impl fmt::Display for ParseError {
 impl FromStr for Interval {
     type Err = ParseError;
 
+    /// Parses an interval.
+    ///
+    /// The interval can be empty (`(-∞, ∞)`), a start position (`START`, `START-`; `[START, ∞)`),
+    /// an end position (`-END`; `(-∞, END]`), or both (`START-END`; `[START, END]`). Positions can
+    /// include thousands separators, e.g., `10,000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::try_from(10000)?;
+    /// let end = Position::try_from(20000)?;
+    ///
+    /// assert_eq!("10,000-20,000".parse(), Ok(Interval::from(start..=end)));
+    /// assert_eq!("10000-".parse(), Ok(Interval::from(start..)));
+    /// assert_eq!("-20000".parse(), Ok(Interval::from(..=end)));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
             return Ok(Self::from(..));
@@ -147,25 +323,34 @@ impl FromStr for Interval {
         let mut components = s.splitn(2, '-');
 
         let start = match components.next() {
-            Some(t) => t
-                .parse()
+            Some("") | None => None,
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidStartPosition)?,
-            None => None,
         };
 
         let end = match components.next() {
-            Some(t) => t
-                .parse()
+            Some("") | None => None,
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidEndPosition)?,
-            None => None,
         };
 
         Ok(Self { start, end })
     }
 }
 
+// Parses a position that may include thousands separators.
+fn parse_position(s: &str) -> Result<Position, position::ParseError> {
+    const THOUSANDS_SEPARATOR: char = ',';
+
+    if s.contains(THOUSANDS_SEPARATOR) {
+        s.replace(THOUSANDS_SEPARATOR, "").parse()
+    } else {
+        s.parse()
+    }
+}
+
 impl From<RangeFrom<Position>> for Interval {
     fn from(range: RangeFrom<Position>) -> Self {
         Self {
@@ -279,6 +464,107 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_intersect() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+
+        let b = Interval::from(Position::try_from(4)?..=Position::try_from(6)?);
+        let expected = Interval::from(Position::try_from(5)?..=Position::try_from(6)?);
+        assert_eq!(a.intersect(b), Some(expected));
+        assert_eq!(b.intersect(a), Some(expected));
+
+        let c = Interval::from(..=Position::try_from(6)?);
+        assert_eq!(a.intersect(c), Some(expected));
+
+        let d = Interval::from(Position::try_from(7)?..);
+        let expected = Interval::from(Position::try_from(7)?..=Position::try_from(8)?);
+        assert_eq!(a.intersect(d), Some(expected));
+
+        assert!(c.intersect(d).is_none());
+
+        let e = Interval::from(..);
+        assert_eq!(a.intersect(e), Some(a));
+        assert_eq!(e.intersect(e), Some(e));
+        assert_eq!(c.intersect(e), Some(c));
+
+        let f = Interval::from(Position::try_from(9)?..);
+        assert!(a.intersect(f).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+
+        let b = Interval::from(Position::try_from(4)?..=Position::try_from(6)?);
+        let expected = Interval::from(Position::try_from(4)?..=Position::try_from(8)?);
+        assert_eq!(a.union(b), Some(expected));
+        assert_eq!(b.union(a), Some(expected));
+
+        let c = Interval::from(Position::try_from(9)?..);
+        assert_eq!(a.union(c), Some(Interval::from(Position::try_from(5)?..)));
+        assert_eq!(c.union(a), Some(Interval::from(Position::try_from(5)?..)));
+
+        let d = Interval::from(..=Position::try_from(4)?);
+        assert_eq!(a.union(d), Some(Interval::from(..=Position::try_from(8)?)));
+        assert_eq!(c.union(d), None);
+
+        let e = Interval::from(..);
+        assert_eq!(a.union(e), Some(e));
+
+        let f = Interval::from(Position::try_from(10)?..=Position::try_from(13)?);
+        assert!(a.union(f).is_none());
+        assert!(f.union(a).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains_interval() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+        assert!(a.contains_interval(a));
+
+        let b = Interval::from(Position::try_from(6)?..=Position::try_from(7)?);
+        assert!(a.contains_interval(b));
+        assert!(!b.contains_interval(a));
+
+        let c = Interval::from(Position::try_from(6)?..=Position::try_from(9)?);
+        assert!(!a.contains_interval(c));
+
+        let d = Interval::from(Position::try_from(5)?..);
+        assert!(d.contains_interval(a));
+        assert!(!a.contains_interval(d));
+
+        let e = Interval::from(..=Position::try_from(8)?);
+        assert!(e.contains_interval(a));
+        assert!(!a.contains_interval(e));
+
+        let f = Interval::from(..);
+        assert!(f.contains_interval(a));
+        assert!(f.contains_interval(d));
+        assert!(f.contains_interval(e));
+        assert!(!d.contains_interval(f));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_len() -> Result<(), crate::position::TryFromIntError> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        assert_eq!(Interval::from(start..=end).len(), Some(6));
+        assert_eq!(Interval::from(start..=start).len(), Some(1));
+        assert_eq!(Interval::from(end..=start).len(), Some(0));
+        assert_eq!(Interval::from(..=end).len(), Some(13));
+        assert_eq!(Interval::from(..=Position::MAX).len(), Some(usize::MAX));
+        assert!(Interval::from(start..).len().is_none());
+        assert!(Interval::from(..).len().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), crate::position::TryFromIntError> {
         let start = Position::try_from(8)?;
@@ -307,6 +593,13 @@ mod tests {
         assert_eq!("".parse(), Ok(Interval::from(..)));
         assert_eq!("8".parse(), Ok(Interval::from(start..)));
         assert_eq!("8-13".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("8-".parse(), Ok(Interval::from(start..)));
+        assert_eq!("-13".parse(), Ok(Interval::from(..=end)));
+        assert_eq!("-".parse(), Ok(Interval::from(..)));
+
+        let start = Position::try_from(10000)?;
+        let end = Position::try_from(1000000)?;
+        assert_eq!("10,000-1,000,000".parse(), Ok(Interval::from(start..=end)));
 
         assert!(matches!(
             "x".parse::<Interval>(),
@@ -0,0 +1,74 @@
+use std::{fmt, str::FromStr};
+
+use super::{ParseError, Region};
+
+const ALL: &str = ".";
+const UNMAPPED: &str = "*";
+
+/// A region selector.
+///
+/// A region selector is a genomic region or one of the special selectors for all records (`.`) or
+/// unplaced, unmapped records (`*`), as used by, e.g., samtools.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Selector {
+    /// All records (`.`).
+    All,
+    /// Unplaced, unmapped records (`*`).
+    Unmapped,
+    /// Records in a genomic region.
+    Region(Region),
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => f.write_str(ALL),
+            Self::Unmapped => f.write_str(UNMAPPED),
+            Self::Region(region) => region.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = ParseError;
+
+    /// Parses a region selector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::Selector;
+    ///
+    /// assert_eq!(".".parse(), Ok(Selector::All));
+    /// assert_eq!("*".parse(), Ok(Selector::Unmapped));
+    /// assert_eq!("sq0:5-8".parse(), Ok(Selector::Region("sq0:5-8".parse()?)));
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ALL => Ok(Self::All),
+            UNMAPPED => Ok(Self::Unmapped),
+            _ => s.parse().map(Self::Region),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Selector::All.to_string(), ".");
+        assert_eq!(Selector::Unmapped.to_string(), "*");
+        assert_eq!(Selector::Region(Region::new("sq0", ..)).to_string(), "sq0");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(".".parse(), Ok(Selector::All));
+        assert_eq!("*".parse(), Ok(Selector::Unmapped));
+        assert_eq!("sq0".parse(), Ok(Selector::Region(Region::new("sq0", ..))));
+        assert_eq!("".parse::<Selector>(), Err(ParseError::Empty));
+    }
+}
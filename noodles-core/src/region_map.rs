@@ -0,0 +1,185 @@
+//! Intervals keyed by reference sequence name.
+
+use std::{collections::HashMap, iter::FromIterator, ops::RangeInclusive};
+
+use super::{interval_tree::IntervalTree, Position, Region};
+
+/// A map of reference sequence names to interval trees.
+///
+/// This holds closed intervals on multiple reference sequences, each with an associated value,
+/// and answers region overlap queries. It is built once from a collection of reference sequence
+/// names and intervals (see [`FromIterator`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region_map::RegionMap, Position, Region};
+///
+/// let map: RegionMap<&str> = [
+///     (String::from("sq0"), Position::try_from(8)?..=Position::try_from(13)?, "r0"),
+///     (String::from("sq1"), Position::try_from(8)?..=Position::try_from(13)?, "r1"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let region = "sq1:5-8".parse()?;
+/// let values: Vec<_> = map.query(&region).map(|(_, value)| *value).collect();
+/// assert_eq!(values, ["r1"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegionMap<T> {
+    trees: HashMap<String, IntervalTree<T>>,
+}
+
+impl<T> RegionMap<T> {
+    /// Returns the number of intervals in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region_map::RegionMap;
+    /// let map: RegionMap<()> = RegionMap::default();
+    /// assert_eq!(map.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.trees.values().map(|tree| tree.len()).sum()
+    }
+
+    /// Returns whether there are any intervals in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region_map::RegionMap;
+    /// let map: RegionMap<()> = RegionMap::default();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.trees.values().all(|tree| tree.is_empty())
+    }
+
+    /// Returns the interval tree of the given reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region_map::RegionMap, Position};
+    ///
+    /// let map: RegionMap<_> = [(
+    ///     String::from("sq0"),
+    ///     Position::try_from(8)?..=Position::try_from(13)?,
+    ///     "r0",
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(map.get("sq0").map(|tree| tree.len()), Some(1));
+    /// assert!(map.get("sq1").is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn get(&self, reference_sequence_name: &str) -> Option<&IntervalTree<T>> {
+        self.trees.get(reference_sequence_name)
+    }
+
+    /// Returns an iterator over the intervals that overlap the given region, sorted by start
+    /// position.
+    ///
+    /// If the region's reference sequence name is not in the map, the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region_map::RegionMap, Position, Region};
+    ///
+    /// let map: RegionMap<_> = [(
+    ///     String::from("sq0"),
+    ///     Position::try_from(8)?..=Position::try_from(13)?,
+    ///     "r0",
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(map.query(&Region::new("sq0", ..)).count(), 1);
+    /// assert_eq!(map.query(&Region::new("sq1", ..)).count(), 0);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn query<'a>(
+        &'a self,
+        region: &Region,
+    ) -> impl Iterator<Item = (RangeInclusive<Position>, &'a T)> + 'a {
+        let interval = region.interval();
+
+        self.trees
+            .get(region.name())
+            .into_iter()
+            .flat_map(move |tree| tree.query(interval))
+    }
+}
+
+impl<T> Default for RegionMap<T> {
+    fn default() -> Self {
+        Self {
+            trees: HashMap::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(String, RangeInclusive<Position>, T)> for RegionMap<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, RangeInclusive<Position>, T)>,
+    {
+        let mut intervals: HashMap<String, Vec<_>> = HashMap::new();
+
+        for (reference_sequence_name, interval, value) in iter {
+            intervals
+                .entry(reference_sequence_name)
+                .or_default()
+                .push((interval, value));
+        }
+
+        let trees = intervals
+            .into_iter()
+            .map(|(name, intervals)| (name, intervals.into_iter().collect()))
+            .collect();
+
+        Self { trees }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let map: RegionMap<_> = [
+            ("sq0", 8, 13, "r0"),
+            ("sq0", 21, 34, "r1"),
+            ("sq1", 5, 8, "r2"),
+        ]
+        .into_iter()
+        .map(|(name, start, end, value)| {
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+            Ok((name.into(), start..=end, value))
+        })
+        .collect::<Result<_, crate::position::TryFromIntError>>()?;
+
+        assert_eq!(map.len(), 3);
+
+        let query = |s: &str| -> Result<Vec<&str>, Box<dyn std::error::Error>> {
+            let region: Region = s.parse()?;
+            Ok(map.query(&region).map(|(_, value)| *value).collect())
+        };
+
+        assert_eq!(query("sq0")?, ["r0", "r1"]);
+        assert_eq!(query("sq0:13-21")?, ["r0", "r1"]);
+        assert_eq!(query("sq0:14-20")?, Vec::<&str>::new());
+        assert_eq!(query("sq1:8")?, ["r2"]);
+        assert_eq!(query("sq2")?, Vec::<&str>::new());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,517 @@
+//! Sequence dictionary.
+//!
+//! A sequence dictionary is an ordered list of reference sequences, each with a name, an optional
+//! length, an optional MD5 checksum, and aliases. It is a format-independent view of, e.g., SAM
+//! header `@SQ` records, FASTA index records, and VCF header `##contig` records, which allows
+//! reference sequence IDs and names to be mapped and the headers of different formats to be
+//! compared.
+
+use std::{collections::HashMap, fmt};
+
+/// A reference sequence in a sequence dictionary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sequence {
+    name: String,
+    len: Option<usize>,
+    md5: Option<[u8; 16]>,
+    aliases: Vec<String>,
+}
+
+impl Sequence {
+    /// Creates a sequence dictionary reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0", Some(8));
+    /// ```
+    pub fn new<N>(name: N, len: Option<usize>) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            len,
+            md5: None,
+            aliases: Vec::new(),
+        }
+    }
+
+    /// Returns the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0", Some(8));
+    /// assert_eq!(sequence.name(), "sq0");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the length, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0", Some(8));
+    /// assert_eq!(sequence.len(), Some(8));
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        self.len
+    }
+
+    /// Returns whether the sequence is empty, if the length is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    ///
+    /// assert_eq!(Sequence::new("sq0", Some(8)).is_empty(), Some(false));
+    /// assert_eq!(Sequence::new("sq0", Some(0)).is_empty(), Some(true));
+    /// assert!(Sequence::new("sq0", None).is_empty().is_none());
+    /// ```
+    pub fn is_empty(&self) -> Option<bool> {
+        self.len.map(|len| len == 0)
+    }
+
+    /// Returns the MD5 checksum of the sequence, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0", Some(8));
+    /// assert!(sequence.md5().is_none());
+    /// ```
+    pub fn md5(&self) -> Option<[u8; 16]> {
+        self.md5
+    }
+
+    /// Returns a mutable reference to the MD5 checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    ///
+    /// let mut sequence = Sequence::new("sq0", Some(8));
+    /// *sequence.md5_mut() = Some([0; 16]);
+    ///
+    /// assert_eq!(sequence.md5(), Some([0; 16]));
+    /// ```
+    pub fn md5_mut(&mut self) -> &mut Option<[u8; 16]> {
+        &mut self.md5
+    }
+
+    /// Returns the alternative names of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    /// let sequence = Sequence::new("sq0", Some(8));
+    /// assert!(sequence.aliases().is_empty());
+    /// ```
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Returns a mutable reference to the alternative names of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence_dictionary::Sequence;
+    ///
+    /// let mut sequence = Sequence::new("chr1", Some(8));
+    /// sequence.aliases_mut().push(String::from("1"));
+    ///
+    /// assert_eq!(sequence.aliases(), [String::from("1")]);
+    /// ```
+    pub fn aliases_mut(&mut self) -> &mut Vec<String> {
+        &mut self.aliases
+    }
+}
+
+/// A sequence dictionary.
+///
+/// The position of a sequence in the dictionary is its reference sequence ID.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+///
+/// let mut sq1 = Sequence::new("chr1", Some(13));
+/// sq1.aliases_mut().push(String::from("1"));
+///
+/// let dictionary: SequenceDictionary = [Sequence::new("chr0", Some(8)), sq1]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(dictionary.id("chr1"), Some(1));
+/// assert_eq!(dictionary.id("1"), Some(1));
+/// assert_eq!(dictionary.get(0).map(|sequence| sequence.name()), Some("chr0"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceDictionary {
+    sequences: Vec<Sequence>,
+    ids: HashMap<String, usize>,
+}
+
+impl SequenceDictionary {
+    /// Creates an empty sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let dictionary = SequenceDictionary::new();
+    /// assert!(dictionary.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of sequences.
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    /// Returns whether there are no sequences.
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+
+    /// Adds a sequence.
+    ///
+    /// If a sequence with the same name exists, it is replaced in place, and the old sequence is
+    /// returned. Otherwise, the sequence is appended.
+    ///
+    /// Names take precedence over aliases when mapping a name to an ID, and an alias that is
+    /// already used by another sequence is not remapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    ///
+    /// let mut dictionary = SequenceDictionary::new();
+    /// assert!(dictionary.insert(Sequence::new("sq0", None)).is_none());
+    ///
+    /// let old_sequence = dictionary.insert(Sequence::new("sq0", Some(8)));
+    /// assert_eq!(old_sequence, Some(Sequence::new("sq0", None)));
+    /// assert_eq!(dictionary.len(), 1);
+    /// ```
+    pub fn insert(&mut self, sequence: Sequence) -> Option<Sequence> {
+        match self.position(sequence.name()) {
+            Some(i) => {
+                let old_sequence = std::mem::replace(&mut self.sequences[i], sequence);
+
+                // Removing or adding an alias can change which sequence other aliases map to.
+                if old_sequence.aliases() != self.sequences[i].aliases() {
+                    self.rebuild_ids();
+                }
+
+                Some(old_sequence)
+            }
+            None => {
+                let id = self.sequences.len();
+
+                self.ids.insert(sequence.name().into(), id);
+
+                for alias in sequence.aliases() {
+                    self.ids.entry(alias.clone()).or_insert(id);
+                }
+
+                self.sequences.push(sequence);
+
+                None
+            }
+        }
+    }
+
+    /// Returns the sequence with the given ID.
+    pub fn get(&self, id: usize) -> Option<&Sequence> {
+        self.sequences.get(id)
+    }
+
+    /// Returns the sequence with the given name or alias.
+    pub fn get_by_name(&self, name: &str) -> Option<&Sequence> {
+        self.id(name).and_then(|id| self.get(id))
+    }
+
+    /// Returns the ID of the sequence with the given name or alias.
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name of the sequence with the given ID.
+    pub fn name(&self, id: usize) -> Option<&str> {
+        self.get(id).map(|sequence| sequence.name())
+    }
+
+    /// Returns an iterator over the sequences in ID order.
+    pub fn iter(&self) -> impl Iterator<Item = &Sequence> {
+        self.sequences.iter()
+    }
+
+    /// Compares this dictionary with another dictionary.
+    ///
+    /// Sequences are matched by name or alias. Lengths and MD5 checksums are only compared when
+    /// both are known. An empty list means the dictionaries are consistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{
+    ///     sequence_dictionary::{Difference, Sequence},
+    ///     SequenceDictionary,
+    /// };
+    ///
+    /// let a: SequenceDictionary = [Sequence::new("sq0", Some(8)), Sequence::new("sq1", Some(13))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let b: SequenceDictionary = [Sequence::new("sq0", Some(8)), Sequence::new("sq1", None)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(a.differences(&b).is_empty());
+    ///
+    /// let b: SequenceDictionary = [Sequence::new("sq0", Some(5))].into_iter().collect();
+    ///
+    /// assert_eq!(
+    ///     a.differences(&b),
+    ///     [
+    ///         Difference::LengthMismatch {
+    ///             name: String::from("sq0"),
+    ///             expected: 8,
+    ///             actual: 5,
+    ///         },
+    ///         Difference::Missing(String::from("sq1")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn differences(&self, other: &Self) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        let mut other_ids = Vec::with_capacity(self.len());
+        let mut is_matched = vec![false; other.len()];
+
+        for sequence in self.iter() {
+            let other_id = match find_id(other, sequence) {
+                Some(id) => id,
+                None => {
+                    differences.push(Difference::Missing(sequence.name().into()));
+                    continue;
+                }
+            };
+
+            other_ids.push(other_id);
+            is_matched[other_id] = true;
+
+            let other_sequence = &other.sequences[other_id];
+
+            if let (Some(expected), Some(actual)) = (sequence.len(), other_sequence.len()) {
+                if expected != actual {
+                    differences.push(Difference::LengthMismatch {
+                        name: sequence.name().into(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+
+            if let (Some(expected), Some(actual)) = (sequence.md5(), other_sequence.md5()) {
+                if expected != actual {
+                    differences.push(Difference::Md5Mismatch(sequence.name().into()));
+                }
+            }
+        }
+
+        for (id, sequence) in other.iter().enumerate() {
+            if !is_matched[id] && find_id(self, sequence).is_none() {
+                differences.push(Difference::Unexpected(sequence.name().into()));
+            }
+        }
+
+        if differences.is_empty() && other_ids.windows(2).any(|ids| ids[0] > ids[1]) {
+            differences.push(Difference::OrderMismatch);
+        }
+
+        differences
+    }
+
+    // Names take precedence over aliases in `ids`, so an ID that maps to a sequence with a
+    // different name means the name is only used as an alias.
+    fn position(&self, name: &str) -> Option<usize> {
+        self.ids
+            .get(name)
+            .copied()
+            .filter(|&id| self.sequences[id].name() == name)
+    }
+
+    fn rebuild_ids(&mut self) {
+        self.ids.clear();
+
+        for (id, sequence) in self.sequences.iter().enumerate() {
+            self.ids.insert(sequence.name().into(), id);
+        }
+
+        for (id, sequence) in self.sequences.iter().enumerate() {
+            for alias in sequence.aliases() {
+                self.ids.entry(alias.clone()).or_insert(id);
+            }
+        }
+    }
+}
+
+// Finds the ID of a sequence in a dictionary by its name or any of its aliases.
+fn find_id(dictionary: &SequenceDictionary, sequence: &Sequence) -> Option<usize> {
+    dictionary.id(sequence.name()).or_else(|| {
+        sequence
+            .aliases()
+            .iter()
+            .find_map(|alias| dictionary.id(alias))
+    })
+}
+
+impl Extend<Sequence> for SequenceDictionary {
+    fn extend<T: IntoIterator<Item = Sequence>>(&mut self, iter: T) {
+        for sequence in iter {
+            self.insert(sequence);
+        }
+    }
+}
+
+impl FromIterator<Sequence> for SequenceDictionary {
+    fn from_iter<T: IntoIterator<Item = Sequence>>(iter: T) -> Self {
+        let mut dictionary = Self::new();
+        dictionary.extend(iter);
+        dictionary
+    }
+}
+
+/// A difference between two sequence dictionaries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Difference {
+    /// A sequence is missing from the other dictionary.
+    Missing(String),
+    /// The other dictionary has a sequence that is not in this dictionary.
+    Unexpected(String),
+    /// The lengths of a sequence differ.
+    LengthMismatch {
+        /// The sequence name.
+        name: String,
+        /// The length in this dictionary.
+        expected: usize,
+        /// The length in the other dictionary.
+        actual: usize,
+    },
+    /// The MD5 checksums of a sequence differ.
+    Md5Mismatch(String),
+    /// The dictionaries have the same sequences but in a different order.
+    ///
+    /// This means the reference sequence IDs are not interchangeable.
+    OrderMismatch,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "missing sequence: {}", name),
+            Self::Unexpected(name) => write!(f, "unexpected sequence: {}", name),
+            Self::LengthMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "length mismatch for {}: expected {}, got {}",
+                name, expected, actual
+            ),
+            Self::Md5Mismatch(name) => write!(f, "MD5 checksum mismatch for {}", name),
+            Self::OrderMismatch => f.write_str("sequence order mismatch"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut sq0 = Sequence::new("chr0", Some(8));
+        sq0.aliases_mut().push(String::from("0"));
+
+        let mut sq1 = Sequence::new("chr1", Some(13));
+        sq1.aliases_mut().push(String::from("chr0"));
+
+        let mut dictionary = SequenceDictionary::new();
+        dictionary.insert(sq0);
+        dictionary.insert(sq1);
+
+        assert_eq!(dictionary.id("chr0"), Some(0));
+        assert_eq!(dictionary.id("0"), Some(0));
+        assert_eq!(dictionary.id("chr1"), Some(1));
+        assert_eq!(dictionary.name(1), Some("chr1"));
+        assert!(dictionary.id("chr2").is_none());
+
+        dictionary.insert(Sequence::new("chr0", Some(8)));
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(dictionary.id("chr0"), Some(0));
+        assert!(dictionary.id("0").is_none());
+
+        let mut sq2 = Sequence::new("chr2", Some(21));
+        sq2.aliases_mut().push(String::from("chr3"));
+        dictionary.insert(sq2);
+        assert_eq!(dictionary.id("chr3"), Some(2));
+
+        dictionary.insert(Sequence::new("chr3", Some(34)));
+        assert_eq!(dictionary.len(), 4);
+        assert_eq!(dictionary.id("chr3"), Some(3));
+
+        dictionary.insert(Sequence::new("chr3", None));
+        assert_eq!(dictionary.len(), 4);
+        assert_eq!(dictionary.get(3), Some(&Sequence::new("chr3", None)));
+    }
+
+    #[test]
+    fn test_differences() {
+        let mut sq0 = Sequence::new("chr0", Some(8));
+        *sq0.md5_mut() = Some([0; 16]);
+
+        let a: SequenceDictionary = [sq0, Sequence::new("chr1", Some(13))].into_iter().collect();
+
+        let mut sq1 = Sequence::new("1", Some(13));
+        sq1.aliases_mut().push(String::from("chr1"));
+
+        let mut sq0 = Sequence::new("chr0", None);
+        *sq0.md5_mut() = Some([1; 16]);
+
+        let b: SequenceDictionary = [sq1, sq0, Sequence::new("chr2", Some(21))]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            a.differences(&b),
+            [
+                Difference::Md5Mismatch(String::from("chr0")),
+                Difference::Unexpected(String::from("chr2")),
+            ]
+        );
+
+        let b: SequenceDictionary = [Sequence::new("chr1", None), Sequence::new("chr0", None)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(a.differences(&b), [Difference::OrderMismatch]);
+        assert!(a.differences(&a).is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Strand.
+
+use std::{error, fmt, str::FromStr};
+
+/// A strand.
+///
+/// This is the strand of a feature or sequence on a reference sequence. An unstranded feature is
+/// typically represented as `Option::None`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Strand {
+    /// Forward (sense or coding) strand (`+`).
+    Forward,
+    /// Reverse (antisense or complementary) strand (`-`).
+    Reverse,
+}
+
+impl Strand {
+    /// Returns the opposite strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Strand;
+    /// assert_eq!(Strand::Forward.reverse(), Strand::Reverse);
+    /// assert_eq!(Strand::Reverse.reverse(), Strand::Forward);
+    /// ```
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+        }
+    }
+}
+
+impl AsRef<str> for Strand {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Forward => "+",
+            Self::Reverse => "-",
+        }
+    }
+}
+
+impl fmt::Display for Strand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw strand fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid(s) => write!(f, "expected {{+, -}}, got {}", s),
+        }
+    }
+}
+
+impl FromStr for Strand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "+" => Ok(Self::Forward),
+            "-" => Ok(Self::Reverse),
+            _ => Err(ParseError::Invalid(s.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Strand::Forward.to_string(), "+");
+        assert_eq!(Strand::Reverse.to_string(), "-");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("+".parse(), Ok(Strand::Forward));
+        assert_eq!("-".parse(), Ok(Strand::Reverse));
+
+        assert_eq!("".parse::<Strand>(), Err(ParseError::Empty));
+        assert_eq!(
+            "!".parse::<Strand>(),
+            Err(ParseError::Invalid(String::from("!")))
+        );
+    }
+}
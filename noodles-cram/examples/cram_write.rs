@@ -52,7 +52,7 @@ fn build_header(
         let len = sequence.len();
 
         let mut hasher = Md5::new();
-        hasher.update(&sequence);
+        hasher.update(sequence);
         let md5_checksum = Md5Checksum::from(<[u8; 16]>::from(hasher.finalize()));
 
         let reference_sequence = ReferenceSequence::builder()
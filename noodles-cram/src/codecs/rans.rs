@@ -2,7 +2,10 @@ mod decode;
 mod encode;
 mod order;
 
-pub use self::{decode::rans_decode, encode::rans_encode, order::Order};
+pub use self::{decode::rans_decode, order::Order};
+
+#[cfg(test)]
+use self::encode::rans_encode;
 
 #[cfg(test)]
 mod tests {
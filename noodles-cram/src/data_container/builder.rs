@@ -19,8 +19,8 @@ pub struct Builder {
 #[derive(Clone, Debug, PartialEq)]
 pub enum AddRecordError {
     InvalidRecordReadLength(num::TryFromIntError),
-    ContainerFull(Record),
-    SliceFull(Record),
+    ContainerFull(Box<Record>),
+    SliceFull(Box<Record>),
 }
 
 impl Builder {
@@ -43,7 +43,7 @@ impl Builder {
 
     pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
         if self.slice_builders.len() >= MAX_SLICE_COUNT {
-            return Err(AddRecordError::ContainerFull(record));
+            return Err(AddRecordError::ContainerFull(Box::new(record)));
         }
 
         match self.slice_builder.add_record(record) {
@@ -90,6 +90,7 @@ impl Builder {
             .into_iter()
             .map(|builder| {
                 builder.build(
+                    &options,
                     reference_sequence_repository,
                     header,
                     &compression_header,
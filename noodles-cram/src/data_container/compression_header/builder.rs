@@ -1,6 +1,6 @@
 use super::{
-    data_series_encoding_map::DataSeriesEncodingMap, preservation_map, tag_encoding_map,
-    CompressionHeader,
+    data_series_encoding_map::{self, DataSeriesEncodingMap},
+    preservation_map, tag_encoding_map, CompressionHeader,
 };
 use crate::{writer::Options, Record};
 
@@ -8,21 +8,32 @@ use crate::{writer::Options, Record};
 pub struct Builder {
     preservation_map_builder: preservation_map::Builder,
     tag_encoding_map_builder: tag_encoding_map::Builder,
+    data_series_stats: data_series_encoding_map::Stats,
+    discard_quality_scores: bool,
 }
 
 impl Builder {
     pub fn apply_options(&mut self, options: &Options) {
         self.preservation_map_builder.apply_options(options);
+        self.discard_quality_scores = !options.preserve_quality_scores;
     }
 
     pub fn update(&mut self, record: &Record) {
         self.preservation_map_builder.update(record);
         self.tag_encoding_map_builder.update(record);
+        self.data_series_stats.update(record);
     }
 
     pub fn build(self) -> CompressionHeader {
         let preservation_map = self.preservation_map_builder.build();
-        let data_series_encoding_map = DataSeriesEncodingMap::default();
+
+        let mut data_series_encoding_map = DataSeriesEncodingMap::default();
+        data_series_encoding_map.select_encodings(&self.data_series_stats);
+
+        if self.discard_quality_scores {
+            data_series_encoding_map.remove_quality_scores_encodings();
+        }
+
         let tag_encoding_map = self.tag_encoding_map_builder.build();
         CompressionHeader::new(preservation_map, data_series_encoding_map, tag_encoding_map)
     }
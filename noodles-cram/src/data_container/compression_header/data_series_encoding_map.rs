@@ -1,7 +1,8 @@
 mod builder;
 pub mod data_series;
+mod stats;
 
-pub use self::{builder::Builder, data_series::DataSeries};
+pub use self::{builder::Builder, data_series::DataSeries, stats::Stats};
 
 use super::{
     encoding::codec::{Byte, ByteArray, Integer},
@@ -255,6 +256,63 @@ impl DataSeriesEncodingMap {
     pub fn quality_scores_encoding(&self) -> Option<&Encoding<Byte>> {
         self.quality_scores_encoding.as_ref()
     }
+
+    // Replaces the encodings of the data series in the given stats with the encodings that are
+    // estimated to be the smallest, e.g., a core bit encoding instead of an external block.
+    pub(crate) fn select_encodings(&mut self, stats: &Stats) {
+        use self::stats::{select_byte_encoding, select_integer_encoding};
+
+        fn select_optional_integer_encoding(
+            encoding: &mut Option<Encoding<Integer>>,
+            histogram: &stats::Histogram,
+        ) {
+            if let Some(e) = encoding.take() {
+                *encoding = Some(select_integer_encoding(histogram, e));
+            }
+        }
+
+        self.bam_bit_flags_encoding =
+            select_integer_encoding(&stats.bam_bit_flags, self.bam_bit_flags_encoding.clone());
+        self.read_lengths_encoding =
+            select_integer_encoding(&stats.read_lengths, self.read_lengths_encoding.clone());
+        self.read_groups_encoding =
+            select_integer_encoding(&stats.read_groups, self.read_groups_encoding.clone());
+
+        select_optional_integer_encoding(
+            &mut self.number_of_read_features_encoding,
+            &stats.number_of_read_features,
+        );
+
+        if let Some(encoding) = self.read_features_codes_encoding.take() {
+            self.read_features_codes_encoding =
+                Some(select_byte_encoding(&stats.read_features_codes, encoding));
+        }
+
+        select_optional_integer_encoding(
+            &mut self.in_read_positions_encoding,
+            &stats.in_read_positions,
+        );
+        select_optional_integer_encoding(
+            &mut self.deletion_lengths_encoding,
+            &stats.deletion_lengths,
+        );
+        select_optional_integer_encoding(
+            &mut self.reference_skip_length_encoding,
+            &stats.reference_skip_length,
+        );
+        select_optional_integer_encoding(&mut self.padding_encoding, &stats.padding);
+        select_optional_integer_encoding(&mut self.hard_clip_encoding, &stats.hard_clip);
+        select_optional_integer_encoding(
+            &mut self.mapping_qualities_encoding,
+            &stats.mapping_qualities,
+        );
+    }
+
+    // Removes the encodings of the data series that store quality scores (`QQ` and `QS`).
+    pub(crate) fn remove_quality_scores_encodings(&mut self) {
+        self.stretches_of_quality_scores_encoding = None;
+        self.quality_scores_encoding = None;
+    }
 }
 
 impl Default for DataSeriesEncodingMap {
@@ -0,0 +1,334 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    data_container::compression_header::{
+        encoding::codec::{Byte, Integer},
+        Encoding,
+    },
+    huffman,
+    record::Feature,
+    Record,
+};
+
+// The maximum number of symbols in a Huffman alphabet.
+const MAX_HUFFMAN_ALPHABET_LEN: usize = 16;
+
+// The estimated size of an external block header and its compression overhead, in bits.
+const EXTERNAL_BLOCK_OVERHEAD: u64 = 8 * 24;
+
+// The maximum bit length of a value written to the core data block.
+const MAX_CORE_BIT_LEN: u32 = 31;
+
+pub(super) type Histogram = BTreeMap<i32, u64>;
+
+// An alphabet and its bit lengths.
+type HuffmanCodeLengths = (Vec<i32>, Vec<u32>);
+
+/// Value frequencies of data series.
+///
+/// These are the values of the data series that can be determined from the records alone, i.e.,
+/// without the state of a slice writer.
+#[derive(Debug, Default)]
+pub struct Stats {
+    pub(super) bam_bit_flags: Histogram,
+    pub(super) read_lengths: Histogram,
+    pub(super) read_groups: Histogram,
+    pub(super) number_of_read_features: Histogram,
+    pub(super) read_features_codes: Histogram,
+    pub(super) in_read_positions: Histogram,
+    pub(super) deletion_lengths: Histogram,
+    pub(super) reference_skip_length: Histogram,
+    pub(super) padding: Histogram,
+    pub(super) hard_clip: Histogram,
+    pub(super) mapping_qualities: Histogram,
+}
+
+impl Stats {
+    pub fn update(&mut self, record: &Record) {
+        // § 10.2 "CRAM positional data" (2021-10-15): "-1 for no group".
+        const MISSING_READ_GROUP: i32 = -1;
+
+        add(
+            &mut self.bam_bit_flags,
+            i32::from(u16::from(record.bam_flags())),
+        );
+
+        add_usize(&mut self.read_lengths, record.read_length());
+
+        match record.read_group_id() {
+            Some(id) => add_usize(&mut self.read_groups, id),
+            None => add(&mut self.read_groups, MISSING_READ_GROUP),
+        }
+
+        if record.bam_flags().is_unmapped() {
+            return;
+        }
+
+        add_usize(&mut self.number_of_read_features, record.features().len());
+
+        let mut prev_position = 0;
+
+        for feature in record.features().iter() {
+            let position = usize::from(feature.position());
+            add_usize(&mut self.in_read_positions, position - prev_position);
+            prev_position = position;
+
+            add(
+                &mut self.read_features_codes,
+                i32::from(u8::from(feature.code())),
+            );
+
+            match feature {
+                Feature::Deletion(_, len) => add_usize(&mut self.deletion_lengths, *len),
+                Feature::ReferenceSkip(_, len) => add_usize(&mut self.reference_skip_length, *len),
+                Feature::Padding(_, len) => add_usize(&mut self.padding, *len),
+                Feature::HardClip(_, len) => add_usize(&mut self.hard_clip, *len),
+                _ => {}
+            }
+        }
+
+        let mapping_quality = record
+            .mapping_quality()
+            .map(u8::from)
+            .unwrap_or(noodles_sam::record::mapping_quality::MISSING);
+
+        add(&mut self.mapping_qualities, i32::from(mapping_quality));
+    }
+}
+
+fn add(histogram: &mut Histogram, value: i32) {
+    *histogram.entry(value).or_default() += 1;
+}
+
+// Values that do not fit in an ITF8 integer fail to write, regardless of their encoding, so they
+// are not counted.
+fn add_usize(histogram: &mut Histogram, value: usize) {
+    if let Ok(n) = i32::try_from(value) {
+        add(histogram, n);
+    }
+}
+
+// Selects the integer encoding with the smallest estimated size.
+//
+// The candidates are the given external encoding and the core bit encodings huffman, beta, and
+// gamma. The external encoding is kept when there are no values or when no core bit encoding is
+// estimated to be smaller.
+pub(super) fn select_integer_encoding(
+    histogram: &Histogram,
+    external_encoding: Encoding<Integer>,
+) -> Encoding<Integer> {
+    if histogram.is_empty() {
+        return external_encoding;
+    }
+
+    let mut best = (external_cost(histogram), external_encoding);
+
+    let candidates = [
+        huffman_codec(histogram).map(|(cost, (alphabet, bit_lens))| {
+            (cost, Encoding::new(Integer::Huffman(alphabet, bit_lens)))
+        }),
+        beta_codec(histogram)
+            .map(|(cost, (offset, len))| (cost, Encoding::new(Integer::Beta(offset, len)))),
+        gamma_codec(histogram).map(|(cost, offset)| (cost, Encoding::new(Integer::Gamma(offset)))),
+    ];
+
+    for (cost, encoding) in candidates.into_iter().flatten() {
+        if cost < best.0 {
+            best = (cost, encoding);
+        }
+    }
+
+    best.1
+}
+
+// Selects the byte encoding with the smallest estimated size.
+//
+// The candidates are the given external encoding and huffman.
+pub(super) fn select_byte_encoding(
+    histogram: &Histogram,
+    external_encoding: Encoding<Byte>,
+) -> Encoding<Byte> {
+    if histogram.is_empty() {
+        return external_encoding;
+    }
+
+    match huffman_codec(histogram) {
+        Some((cost, (alphabet, bit_lens))) if cost < external_cost(histogram) => {
+            Encoding::new(Byte::Huffman(alphabet, bit_lens))
+        }
+        _ => external_encoding,
+    }
+}
+
+// The order-0 entropy of the values, which approximates the size of a compressed external block,
+// plus the block overhead.
+fn external_cost(histogram: &Histogram) -> u64 {
+    let n = histogram.values().sum::<u64>() as f64;
+
+    let bits: f64 = histogram
+        .values()
+        .map(|&frequency| {
+            let frequency = frequency as f64;
+            frequency * (n / frequency).log2()
+        })
+        .sum();
+
+    bits.ceil() as u64 + EXTERNAL_BLOCK_OVERHEAD
+}
+
+fn huffman_codec(histogram: &Histogram) -> Option<(u64, HuffmanCodeLengths)> {
+    if histogram.len() > MAX_HUFFMAN_ALPHABET_LEN {
+        return None;
+    }
+
+    let alphabet: Vec<_> = histogram.keys().copied().collect();
+    let frequencies: Vec<_> = histogram.values().copied().collect();
+    let bit_lens = huffman::build_bit_lens(&frequencies);
+
+    if bit_lens.iter().any(|&len| len > MAX_CORE_BIT_LEN) {
+        return None;
+    }
+
+    let data_cost: u64 = frequencies
+        .iter()
+        .zip(&bit_lens)
+        .map(|(&frequency, &len)| frequency * u64::from(len))
+        .sum();
+
+    // The alphabet and bit lengths are written in the compression header, each at least 1 byte
+    // per entry.
+    let header_cost = 8 * (2 + 2 * alphabet.len() as u64);
+
+    Some((data_cost + header_cost, (alphabet, bit_lens)))
+}
+
+fn beta_codec(histogram: &Histogram) -> Option<(u64, (i32, u32))> {
+    let (min, max) = min_max(histogram)?;
+
+    let offset = min.checked_neg()?;
+    let range = u32::try_from(i64::from(max) - i64::from(min)).ok()?;
+    let len = u32::BITS - range.leading_zeros();
+
+    // A single value is better encoded as a huffman code with a single symbol.
+    if len == 0 || len > MAX_CORE_BIT_LEN {
+        return None;
+    }
+
+    let n: u64 = histogram.values().sum();
+
+    Some((n * u64::from(len), (offset, len)))
+}
+
+fn gamma_codec(histogram: &Histogram) -> Option<(u64, i32)> {
+    let (min, max) = min_max(histogram)?;
+
+    let offset = i32::try_from(1 - i64::from(min)).ok()?;
+    let max_n = u32::try_from(i64::from(max) + i64::from(offset)).ok()?;
+
+    if u32::BITS - max_n.leading_zeros() > MAX_CORE_BIT_LEN {
+        return None;
+    }
+
+    let cost = histogram
+        .iter()
+        .map(|(&value, &frequency)| {
+            let n = (i64::from(value) + i64::from(offset)) as u32;
+            let len = u32::BITS - n.leading_zeros();
+            frequency * u64::from(2 * len - 1)
+        })
+        .sum();
+
+    Some((cost, offset))
+}
+
+fn min_max(histogram: &Histogram) -> Option<(i32, i32)> {
+    let min = histogram.keys().next().copied()?;
+    let max = histogram.keys().next_back().copied()?;
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_histogram(values: &[(i32, u64)]) -> Histogram {
+        values.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_select_integer_encoding() {
+        let external_encoding = Encoding::new(Integer::External(1));
+
+        let histogram = Histogram::new();
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            external_encoding
+        );
+
+        let histogram = build_histogram(&[(151, 10000)]);
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            Encoding::new(Integer::Huffman(vec![151], vec![0]))
+        );
+
+        let histogram = build_histogram(&[(0, 500), (1, 500)]);
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            Encoding::new(Integer::Beta(0, 1))
+        );
+
+        let histogram = build_histogram(&[(0, 500), (5, 500)]);
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            Encoding::new(Integer::Huffman(vec![0, 5], vec![1, 1]))
+        );
+
+        let histogram: Histogram = (0..1024).map(|i| (i, 1)).collect();
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            Encoding::new(Integer::Beta(0, 10))
+        );
+
+        let histogram: Histogram = (0..10000).map(|i| (i, 100)).collect();
+        assert_eq!(
+            select_integer_encoding(&histogram, external_encoding.clone()),
+            external_encoding
+        );
+    }
+
+    #[test]
+    fn test_select_byte_encoding() {
+        let external_encoding = Encoding::new(Byte::External(1));
+
+        let histogram = build_histogram(&[(i32::from(b'X'), 10000)]);
+        assert_eq!(
+            select_byte_encoding(&histogram, external_encoding.clone()),
+            Encoding::new(Byte::Huffman(vec![i32::from(b'X')], vec![0]))
+        );
+
+        let histogram = build_histogram(&[(i32::from(b'X'), 10000), (i32::from(b'I'), 10)]);
+        assert_eq!(
+            select_byte_encoding(&histogram, external_encoding.clone()),
+            external_encoding
+        );
+    }
+
+    #[test]
+    fn test_beta_codec() {
+        let histogram = build_histogram(&[(-2, 1), (5, 3)]);
+        assert_eq!(beta_codec(&histogram), Some((12, (2, 3))));
+
+        let histogram = build_histogram(&[(i32::MIN, 1)]);
+        assert!(beta_codec(&histogram).is_none());
+    }
+
+    #[test]
+    fn test_gamma_codec() {
+        // n = 1 (1 bit), n = 4 (5 bits)
+        let histogram = build_histogram(&[(-1, 2), (2, 1)]);
+        assert_eq!(gamma_codec(&histogram), Some((7, 2)));
+
+        let histogram = build_histogram(&[(0, 1), (i32::MAX, 1)]);
+        assert!(gamma_codec(&histogram).is_none());
+    }
+}
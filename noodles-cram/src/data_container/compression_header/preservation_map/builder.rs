@@ -14,6 +14,7 @@ impl Builder {
     pub fn apply_options(&mut self, options: &Options) {
         self.read_names_included = options.preserve_read_names;
         self.ap_data_series_delta = options.encode_alignment_start_positions_as_deltas;
+        self.reference_required = options.reference_required && !options.embed_reference_sequences;
     }
 
     pub fn update(&mut self, record: &Record) {
@@ -12,6 +12,7 @@ use noodles_sam as sam;
 use super::{CompressionHeader, ReferenceSequenceContext};
 use crate::{
     container::Block,
+    reader::Fields,
     record::resolve::{resolve_bases, resolve_quality_scores},
     BitReader, Record,
 };
@@ -74,6 +75,40 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.records_with_fields(compression_header, Fields::default())
+    }
+
+    /// Reads and returns a list of raw records in this slice, decoding only the given fields.
+    ///
+    /// External blocks that only hold data series of skipped fields are not decompressed. See
+    /// [`Fields`] for which fields can be skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_cram::{self as cram, reader::Fields};
+    ///
+    /// let data = [];
+    /// let mut reader = cram::Reader::new(&data[..]);
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// let fields = Fields::default().set_quality_scores(false);
+    ///
+    /// while let Some(container) = reader.read_data_container()? {
+    ///     for slice in container.slices() {
+    ///         let records = slice.records_with_fields(container.compression_header(), fields)?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_with_fields(
+        &self,
+        compression_header: &CompressionHeader,
+        fields: Fields,
+    ) -> io::Result<Vec<Record>> {
         use crate::reader::record::ExternalDataReaders;
 
         let core_data_reader = self
@@ -81,9 +116,15 @@ impl Slice {
             .decompressed_data()
             .map(BitReader::new)?;
 
+        let (fields, skipped_block_content_ids) = fields.resolve(compression_header);
+
         let mut external_data_readers = ExternalDataReaders::new();
 
         for block in self.external_blocks() {
+            if skipped_block_content_ids.contains(&block.content_id()) {
+                continue;
+            }
+
             let reader = block.decompressed_data()?;
             external_data_readers.insert(block.content_id(), reader);
         }
@@ -93,6 +134,7 @@ impl Slice {
             core_data_reader,
             external_data_readers,
             self.header.reference_sequence_context(),
+            fields,
         );
 
         let record_count = self.header().record_count();
@@ -129,7 +171,7 @@ impl Slice {
             records,
         )?;
 
-        self.resolve_quality_scores(records);
+        self.resolve_quality_scores(compression_header, records);
 
         Ok(())
     }
@@ -254,7 +296,21 @@ impl Slice {
         Ok(())
     }
 
-    fn resolve_quality_scores(&self, records: &mut [Record]) {
+    fn resolve_quality_scores(
+        &self,
+        compression_header: &CompressionHeader,
+        records: &mut [Record],
+    ) {
+        // When quality scores are discarded on write, the quality score data series are omitted
+        // from the compression header, and the quality scores of all records are missing.
+        if compression_header
+            .data_series_encoding_map()
+            .quality_scores_encoding()
+            .is_none()
+        {
+            return;
+        }
+
         for record in records {
             if !record.flags().is_unmapped()
                 && !record.cram_flags().are_quality_scores_stored_as_array()
@@ -338,7 +394,7 @@ fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
     Ok(())
 }
 
-fn set_mate(mut record: &mut Record, mate: &mut Record) {
+fn set_mate(record: &mut Record, mate: &mut Record) {
     let mate_bam_flags = mate.bam_flags();
 
     if mate_bam_flags.is_reverse_complemented() {
@@ -384,6 +440,136 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_records_with_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use md5::{Digest, Md5};
+        use noodles_sam::AlignmentWriter;
+
+        use crate::Writer;
+
+        let reference_sequence = b"ACGTACGT";
+        let md5 = Md5::digest(reference_sequence);
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(reference_sequence.to_vec()),
+        )]);
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        for (name, cigar, sequence) in [("r0", "4M", "GTAC"), ("r1", "1S3M", "TTAC")] {
+            let record = sam::alignment::Record::builder()
+                .set_read_name(name.parse()?)
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar(cigar.parse()?)
+                .set_sequence(sequence.parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("NH:i:1".parse()?)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container = reader
+            .read_data_container()?
+            .expect("missing data container");
+        let compression_header = container.compression_header();
+        let slice = &container.slices()[0];
+
+        let expected = slice.records(compression_header)?;
+
+        let fields = Fields::default()
+            .set_read_names(false)
+            .set_quality_scores(false)
+            .set_tags(false);
+        let actual = slice.records_with_fields(compression_header, fields)?;
+
+        assert_eq!(actual.len(), expected.len());
+
+        for (a, b) in actual.iter().zip(&expected) {
+            assert_eq!(a.flags(), b.flags());
+            assert_eq!(a.alignment_start(), b.alignment_start());
+            assert_eq!(a.read_length(), b.read_length());
+            assert_eq!(
+                a.features().try_into_cigar(a.read_length())?,
+                b.features().try_into_cigar(b.read_length())?
+            );
+
+            assert!(b.read_name().is_some());
+            assert!(a.read_name().is_none());
+            assert!(!b.tags().is_empty());
+            assert!(a.tags().is_empty());
+            assert!(a.quality_scores().is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{record::quality_scores::Score, AlignmentWriter};
+
+        use crate::{record::Features, Writer};
+
+        let header: sam::Header = "@HD\tVN:1.6\n".parse()?;
+
+        let mut writer = Writer::builder(Vec::new()).build();
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &sam::alignment::Record::default())?;
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container = reader
+            .read_data_container()?
+            .expect("missing data container");
+        let compression_header = container.compression_header();
+        let slice = &container.slices()[0];
+
+        // A mapped record with no quality score read features
+        let build_records = || {
+            vec![Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_read_length(4)
+                .set_features(Features::default())
+                .build()]
+        };
+
+        let mut records = build_records();
+        slice.resolve_quality_scores(compression_header, &mut records);
+        assert_eq!(records[0].quality_scores().as_ref(), [Score::default(); 4]);
+
+        let mut data_series_encoding_map = compression_header.data_series_encoding_map().clone();
+        data_series_encoding_map.remove_quality_scores_encodings();
+        let compression_header = CompressionHeader::new(
+            compression_header.preservation_map().clone(),
+            data_series_encoding_map,
+            compression_header.tag_encoding_map().clone(),
+        );
+
+        let mut records = build_records();
+        slice.resolve_quality_scores(&compression_header, &mut records);
+        assert!(records[0].quality_scores().is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_mates() -> Result<(), Box<dyn std::error::Error>> {
         use sam::record::ReadName;
@@ -14,12 +14,15 @@ use crate::{
         ReferenceSequenceContext,
     },
     record::Flags,
-    writer, BitWriter, Record,
+    writer::{self, Options},
+    BitWriter, Record,
 };
 
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
+// Data series use block content IDs 1..=DataSeries::LEN, and tags, IDs derived from their keys.
+const EMBEDDED_REFERENCE_BASES_BLOCK_CONTENT_ID: i32 = DataSeries::LEN as i32 + 1;
 const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
@@ -30,7 +33,7 @@ pub struct Builder {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum AddRecordError {
-    SliceFull(Record),
+    SliceFull(Box<Record>),
 }
 
 impl Builder {
@@ -48,7 +51,7 @@ impl Builder {
 
     pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
         if self.records.len() >= MAX_RECORD_COUNT {
-            return Err(AddRecordError::SliceFull(record));
+            return Err(AddRecordError::SliceFull(Box::new(record)));
         }
 
         if self.is_empty() {
@@ -77,26 +80,26 @@ impl Builder {
 
     pub fn build(
         mut self,
+        options: &Options,
         reference_sequence_repostitory: &fasta::repository::Repository,
         header: &sam::Header,
         compression_header: &CompressionHeader,
         record_counter: u64,
     ) -> io::Result<Slice> {
-        let (core_data_block, external_blocks) = write_records(
+        let (core_data_block, mut external_blocks) = write_records(
             compression_header,
             self.reference_sequence_context,
             &mut self.records,
         )?;
 
-        let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
-        block_content_ids.push(core_data_block.content_id());
-
-        for block in &external_blocks {
-            block_content_ids.push(block.content_id());
-        }
+        let is_reference_required = compression_header
+            .preservation_map()
+            .is_reference_required();
 
-        let reference_md5 = match self.reference_sequence_context {
-            ReferenceSequenceContext::Some(context) => {
+        let reference_sequence = match self.reference_sequence_context {
+            ReferenceSequenceContext::Some(context)
+                if is_reference_required || options.embed_reference_sequences =>
+            {
                 let reference_sequence_name = header
                     .reference_sequences()
                     .get_index(context.reference_sequence_id())
@@ -109,21 +112,50 @@ impl Builder {
                     .expect("invalid reference sequence");
 
                 let (start, end) = (context.alignment_start(), context.alignment_end());
-                let sequence = &reference_sequence[start..=end];
+                Some(reference_sequence[start..=end].to_vec())
+            }
+            _ => None,
+        };
+
+        let embedded_reference_bases_block_content_id = match &reference_sequence {
+            Some(sequence) if options.embed_reference_sequences => {
+                let block = Block::builder()
+                    .set_content_type(block::ContentType::ExternalData)
+                    .set_content_id(EMBEDDED_REFERENCE_BASES_BLOCK_CONTENT_ID)
+                    .compress_and_set_data(sequence.clone(), CompressionMethod::Gzip)?
+                    .build();
 
-                calculate_normalized_sequence_digest(sequence)
+                external_blocks.push(block);
+
+                Some(EMBEDDED_REFERENCE_BASES_BLOCK_CONTENT_ID)
             }
-            _ => [0; 16],
+            _ => None,
         };
 
-        let header = Header::builder()
+        let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
+        block_content_ids.push(core_data_block.content_id());
+
+        for block in &external_blocks {
+            block_content_ids.push(block.content_id());
+        }
+
+        let reference_md5 = reference_sequence
+            .map(|sequence| calculate_normalized_sequence_digest(&sequence))
+            .unwrap_or([0; 16]);
+
+        let mut builder = Header::builder()
             .set_reference_sequence_context(self.reference_sequence_context)
             .set_record_count(self.records.len())
             .set_record_counter(record_counter)
             .set_block_count(block_content_ids.len())
             .set_block_content_ids(block_content_ids)
-            .set_reference_md5(reference_md5)
-            .build();
+            .set_reference_md5(reference_md5);
+
+        if let Some(id) = embedded_reference_bases_block_content_id {
+            builder = builder.set_embedded_reference_bases_block_content_id(id);
+        }
+
+        let header = builder.build();
 
         Ok(Slice::new(header, core_data_block, external_blocks))
     }
@@ -237,7 +269,7 @@ pub(super) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16]
         // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
         if b.is_ascii_graphic() {
             // "All lowercase characters are converted to uppercase."
-            hasher.update(&[b.to_ascii_uppercase()]);
+            hasher.update([b.to_ascii_uppercase()]);
         }
     }
 
@@ -1,8 +1,12 @@
-use std::{collections::HashMap, io};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    io::{self, Write},
+};
 
 use bytes::Buf;
 
-use crate::BitReader;
+use crate::{BitReader, BitWriter};
 
 type CodeBook = HashMap<i32, (i32, u32)>;
 
@@ -60,6 +64,73 @@ impl CanonicalHuffmanDecoder {
     }
 }
 
+pub struct CanonicalHuffmanEncoder {
+    code_book: CodeBook,
+}
+
+impl CanonicalHuffmanEncoder {
+    pub fn new(alphabet: &[i32], bit_lens: &[u32]) -> Self {
+        let code_book = build_canonical_code_book(alphabet, bit_lens);
+        Self { code_book }
+    }
+
+    pub fn encode<W>(&self, writer: &mut BitWriter<W>, symbol: i32) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let (code, len) = self.code_book.get(&symbol).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("symbol not in alphabet: {}", symbol),
+            )
+        })?;
+
+        writer.write_u32(code as u32, len as usize)
+    }
+}
+
+// Builds the code lengths of a Huffman code from the frequencies of its symbols.
+//
+// A single symbol has a code length of 0.
+pub fn build_bit_lens(frequencies: &[u64]) -> Vec<u32> {
+    if frequencies.len() <= 1 {
+        return vec![0; frequencies.len()];
+    }
+
+    // Nodes 0..n are leaves, and the rest are internal nodes.
+    let mut parents = vec![0; frequencies.len()];
+    let mut heap: BinaryHeap<_> = frequencies
+        .iter()
+        .enumerate()
+        .map(|(i, &frequency)| Reverse((frequency, i)))
+        .collect();
+
+    while let (Some(Reverse((a_frequency, a))), Some(Reverse((b_frequency, b)))) =
+        (heap.pop(), heap.pop())
+    {
+        let node = parents.len();
+        parents.push(node);
+
+        parents[a] = node;
+        parents[b] = node;
+
+        heap.push(Reverse((a_frequency + b_frequency, node)));
+    }
+
+    (0..frequencies.len())
+        .map(|mut i| {
+            let mut len = 0;
+
+            while parents[i] != i {
+                i = parents[i];
+                len += 1;
+            }
+
+            len
+        })
+        .collect()
+}
+
 fn build_canonical_code_book(alphabet: &[i32], bit_lens: &[u32]) -> CodeBook {
     let sorted_alphabet = {
         let mut pairs: Vec<_> = alphabet.iter().zip(bit_lens.iter()).collect();
@@ -107,6 +178,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encode() -> io::Result<()> {
+        let symbols = [0x4e, 0x44, 0x4c];
+        let bit_lens = [1, 2, 2];
+        let encoder = CanonicalHuffmanEncoder::new(&symbols, &bit_lens);
+
+        let mut writer = BitWriter::new(Vec::new());
+
+        for &symbol in &[0x4e, 0x44, 0x4c, 0x4e] {
+            encoder.encode(&mut writer, symbol)?;
+        }
+
+        assert!(encoder.encode(&mut writer, 0x00).is_err());
+
+        let data = writer.finish()?;
+        assert_eq!(data, [0b01011000]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_bit_lens() {
+        assert!(build_bit_lens(&[]).is_empty());
+        assert_eq!(build_bit_lens(&[8]), [0]);
+        assert_eq!(build_bit_lens(&[8, 13]), [1, 1]);
+        assert_eq!(build_bit_lens(&[8, 2, 1, 1]), [1, 2, 3, 3]);
+    }
+
     #[test]
     fn test_build_canonical_code_book() {
         let symbols = [65, 66, 67, 68, 69, 70];
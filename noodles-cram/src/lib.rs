@@ -17,11 +17,12 @@ mod indexer;
 mod num;
 pub mod reader;
 pub mod record;
+mod reheader;
 pub(crate) mod writer;
 
 pub use self::{
     data_container::DataContainer, file_definition::FileDefinition, indexer::index, reader::Reader,
-    record::Record, writer::Writer,
+    record::Record, reheader::reheader, writer::Writer,
 };
 
 #[cfg(feature = "async")]
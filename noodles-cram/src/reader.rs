@@ -1,25 +1,28 @@
 //! CRAM reader and record iterator.
 
+mod builder;
 pub(crate) mod container;
 pub(crate) mod data_container;
+mod fields;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod query;
 pub(crate) mod record;
 mod records;
+pub mod table_of_contents;
 
-pub use self::records::Records;
+pub use self::{builder::Builder, fields::Fields, records::Records};
 
 use std::io::{self, Read, Seek, SeekFrom};
 
 use bytes::BytesMut;
 use noodles_core::Region;
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, record::quality_scores::Score};
 
 pub use self::query::Query;
 use super::{crai, file_definition::Version, FileDefinition, MAGIC_NUMBER};
-use crate::data_container::DataContainer;
+use crate::{data_container::DataContainer, Record};
 
 /// A CRAM reader.
 ///
@@ -50,6 +53,8 @@ use crate::data_container::DataContainer;
 pub struct Reader<R> {
     inner: R,
     buf: BytesMut,
+    default_quality_score: Option<Score>,
+    fields: Fields,
 }
 
 impl<R> Reader<R>
@@ -67,10 +72,20 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn new(reader: R) -> Self {
-        Self {
-            inner: reader,
-            buf: BytesMut::new(),
-        }
+        Builder::new(reader).build()
+    }
+
+    /// Creates a CRAM reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Reader::builder(&[][..]);
+    /// let reader = builder.build();
+    /// ```
+    pub fn builder(reader: R) -> Builder<R> {
+        Builder::new(reader)
     }
 
     /// Returns a reference to the underlying reader.
@@ -267,7 +282,38 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn position(&mut self) -> io::Result<u64> {
-        self.inner.seek(SeekFrom::Current(0))
+        self.inner.stream_position()
+    }
+
+    /// Reads a table of contents of the data containers starting from the current stream
+    /// position.
+    ///
+    /// Only container headers and slice headers are read. All other blocks, including the
+    /// compression headers and record data, are skipped using their lengths, which makes this
+    /// much faster than reading data containers. The stream is left at the end of the EOF
+    /// container.
+    ///
+    /// The entries include the positions, sizes, reference sequence spans, and record counts of
+    /// each container and slice, e.g., to partition work or build an index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// for container in reader.read_table_of_contents()? {
+    ///     println!("{} {}", container.position(), container.record_count());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_table_of_contents(&mut self) -> io::Result<Vec<table_of_contents::Container>> {
+        use self::table_of_contents::read_table_of_contents;
+        read_table_of_contents(&mut self.inner, &mut self.buf)
     }
 
     /// Returns an iterator over records that intersects the given region.
@@ -300,7 +346,7 @@ where
         header: &'a sam::Header,
         index: &'a crai::Index,
         region: &Region,
-    ) -> io::Result<Query<'_, R>> {
+    ) -> io::Result<Query<'a, R>> {
         let reference_sequence_id = header
             .reference_sequences()
             .get_index_of(region.name())
@@ -347,6 +393,21 @@ where
     }
 }
 
+// Substitutes the default quality score, if set, for missing quality scores.
+fn resolve_default_quality_scores(default_quality_score: Option<Score>, records: &mut [Record]) {
+    let score = match default_quality_score {
+        Some(score) => score,
+        None => return,
+    };
+
+    for record in records {
+        if record.quality_scores.is_empty() && record.read_length() > 0 {
+            record.quality_scores =
+                sam::record::QualityScores::from(vec![score; record.read_length()]);
+        }
+    }
+}
+
 fn read_magic_number<R>(reader: &mut R) -> io::Result<()>
 where
     R: Read,
@@ -0,0 +1,81 @@
+use bytes::BytesMut;
+use noodles_sam::record::quality_scores::Score;
+
+use super::{Fields, Reader};
+
+/// A CRAM reader builder.
+pub struct Builder<R> {
+    inner: R,
+    default_quality_score: Option<Score>,
+    fields: Fields,
+}
+
+impl<R> Builder<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            default_quality_score: None,
+            fields: Fields::default(),
+        }
+    }
+
+    /// Sets a quality score to substitute for missing quality scores.
+    ///
+    /// Records that were written without quality scores, e.g., by a writer that does not preserve
+    /// quality scores, are decoded with this score at every position of the read.
+    ///
+    /// By default, missing quality scores are left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// use noodles_sam::record::quality_scores::Score;
+    ///
+    /// let builder = cram::Reader::builder(&[][..])
+    ///     .set_default_quality_score(Score::try_from(30)?);
+    /// # Ok::<_, noodles_sam::record::quality_scores::score::TryFromUByteError>(())
+    /// ```
+    pub fn set_default_quality_score(mut self, score: Score) -> Self {
+        self.default_quality_score = Some(score);
+        self
+    }
+
+    /// Sets the record fields to decode.
+    ///
+    /// This applies to records read with [`Reader::records`] and [`Reader::query`]. Fields that
+    /// are not decoded are left empty, and the external blocks that only hold their data are not
+    /// decompressed.
+    ///
+    /// By default, all fields are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, reader::Fields};
+    ///
+    /// let fields = Fields::default().set_quality_scores(false).set_tags(false);
+    /// let builder = cram::Reader::builder(&[][..]).set_fields(fields);
+    /// ```
+    pub fn set_fields(mut self, fields: Fields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Builds a CRAM reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let reader = cram::Reader::builder(&[][..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            buf: BytesMut::new(),
+            default_quality_score: self.default_quality_score,
+            fields: self.fields,
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::HashSet;
+
+use crate::data_container::{
+    compression_header::{
+        data_series_encoding_map::DataSeriesEncodingMap,
+        encoding::codec::{Byte, ByteArray, Integer},
+        Encoding,
+    },
+    CompressionHeader,
+};
+
+/// Optional record fields to decode.
+///
+/// By default, all fields are decoded. A field that is skipped is left empty in the record, and
+/// the external blocks that only hold its data series are not decompressed. This reduces the work
+/// done when only, e.g., positions and flags are needed.
+///
+/// Skipping a field is best effort. If any of its data series are encoded in the core data block
+/// or share an external block with another data series, the field is decoded as usual.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::reader::Fields;
+///
+/// let fields = Fields::default()
+///     .set_read_names(false)
+///     .set_quality_scores(false);
+///
+/// assert!(!fields.read_names());
+/// assert!(!fields.quality_scores());
+/// assert!(fields.tags());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fields {
+    read_names: bool,
+    quality_scores: bool,
+    tags: bool,
+}
+
+impl Fields {
+    /// Returns whether read names are decoded.
+    pub fn read_names(&self) -> bool {
+        self.read_names
+    }
+
+    /// Sets whether read names are decoded.
+    ///
+    /// Read names that are skipped are missing in raw records and are generated from record IDs
+    /// when records are resolved.
+    pub fn set_read_names(mut self, read_names: bool) -> Self {
+        self.read_names = read_names;
+        self
+    }
+
+    /// Returns whether quality scores are decoded.
+    pub fn quality_scores(&self) -> bool {
+        self.quality_scores
+    }
+
+    /// Sets whether quality scores are decoded.
+    ///
+    /// Quality scores that are skipped are treated as missing. This includes quality scores
+    /// stored in read features.
+    pub fn set_quality_scores(mut self, quality_scores: bool) -> Self {
+        self.quality_scores = quality_scores;
+        self
+    }
+
+    /// Returns whether tags are decoded.
+    pub fn tags(&self) -> bool {
+        self.tags
+    }
+
+    /// Sets whether tags are decoded.
+    ///
+    /// The tag line of each record is still read, but the tag values are not.
+    pub fn set_tags(mut self, tags: bool) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Returns the fields that can be skipped using the given compression header and the content
+    /// IDs of the external blocks that are then unused.
+    pub(crate) fn resolve(self, compression_header: &CompressionHeader) -> (Self, HashSet<i32>) {
+        let data_series_encoding_map = compression_header.data_series_encoding_map();
+
+        let read_names_block_content_ids = data_series_encoding_map
+            .read_names_encoding()
+            .map(byte_array_block_content_ids)
+            .unwrap_or_else(|| Some(Vec::new()));
+
+        let quality_scores_block_content_ids = merge(
+            data_series_encoding_map
+                .quality_scores_encoding()
+                .map(byte_block_content_ids)
+                .unwrap_or_else(|| Some(Vec::new())),
+            data_series_encoding_map
+                .stretches_of_quality_scores_encoding()
+                .map(byte_array_block_content_ids)
+                .unwrap_or_else(|| Some(Vec::new())),
+        );
+
+        let tags_block_content_ids = compression_header
+            .tag_encoding_map()
+            .values()
+            .map(byte_array_block_content_ids)
+            .fold(Some(Vec::new()), merge);
+
+        let mut groups = [
+            (!self.read_names, read_names_block_content_ids),
+            (!self.quality_scores, quality_scores_block_content_ids),
+            (!self.tags, tags_block_content_ids),
+        ];
+
+        // A group that is encoded in the core data block must always be read.
+        for (skip, ids) in &mut groups {
+            if ids.is_none() {
+                *skip = false;
+            }
+        }
+
+        // Skipping a group must not affect the position of another data series in a shared
+        // external block. Requiring a group may require another, so this repeats until no more
+        // groups change.
+        loop {
+            let mut required_ids = HashSet::new();
+            insert_required_block_content_ids(data_series_encoding_map, &mut required_ids);
+
+            for (skip, ids) in &groups {
+                if !*skip {
+                    required_ids.extend(ids.iter().flatten().copied());
+                }
+            }
+
+            let mut is_changed = false;
+
+            for (skip, ids) in &mut groups {
+                if *skip && ids.iter().flatten().any(|id| required_ids.contains(id)) {
+                    *skip = false;
+                    is_changed = true;
+                }
+            }
+
+            if !is_changed {
+                break;
+            }
+        }
+
+        let [(skip_read_names, _), (skip_quality_scores, _), (skip_tags, _)] = &groups;
+
+        let fields = Self {
+            read_names: !*skip_read_names,
+            quality_scores: !*skip_quality_scores,
+            tags: !*skip_tags,
+        };
+
+        let skipped_ids = groups
+            .iter()
+            .filter(|(skip, _)| *skip)
+            .flat_map(|(_, ids)| ids.iter().flatten().copied())
+            .collect();
+
+        (fields, skipped_ids)
+    }
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Self {
+            read_names: true,
+            quality_scores: true,
+            tags: true,
+        }
+    }
+}
+
+// Adds the external block content IDs of all data series that are not in an optional group.
+fn insert_required_block_content_ids(
+    data_series_encoding_map: &DataSeriesEncodingMap,
+    ids: &mut HashSet<i32>,
+) {
+    let integer_encodings = [
+        Some(data_series_encoding_map.bam_bit_flags_encoding()),
+        Some(data_series_encoding_map.cram_bit_flags_encoding()),
+        data_series_encoding_map.reference_id_encoding(),
+        Some(data_series_encoding_map.read_lengths_encoding()),
+        Some(data_series_encoding_map.in_seq_positions_encoding()),
+        Some(data_series_encoding_map.read_groups_encoding()),
+        data_series_encoding_map.next_mate_bit_flags_encoding(),
+        data_series_encoding_map.next_fragment_reference_sequence_id_encoding(),
+        data_series_encoding_map.next_mate_alignment_start_encoding(),
+        data_series_encoding_map.template_size_encoding(),
+        data_series_encoding_map.distance_to_next_fragment_encoding(),
+        Some(data_series_encoding_map.tag_ids_encoding()),
+        data_series_encoding_map.number_of_read_features_encoding(),
+        data_series_encoding_map.in_read_positions_encoding(),
+        data_series_encoding_map.deletion_lengths_encoding(),
+        data_series_encoding_map.reference_skip_length_encoding(),
+        data_series_encoding_map.padding_encoding(),
+        data_series_encoding_map.hard_clip_encoding(),
+        data_series_encoding_map.mapping_qualities_encoding(),
+    ];
+
+    let byte_encodings = [
+        data_series_encoding_map.read_features_codes_encoding(),
+        data_series_encoding_map.base_substitution_codes_encoding(),
+        data_series_encoding_map.bases_encoding(),
+    ];
+
+    let byte_array_encodings = [
+        data_series_encoding_map.stretches_of_bases_encoding(),
+        data_series_encoding_map.insertion_encoding(),
+        data_series_encoding_map.soft_clip_encoding(),
+    ];
+
+    let block_content_ids = integer_encodings
+        .into_iter()
+        .flatten()
+        .map(integer_block_content_ids)
+        .chain(
+            byte_encodings
+                .into_iter()
+                .flatten()
+                .map(byte_block_content_ids),
+        )
+        .chain(
+            byte_array_encodings
+                .into_iter()
+                .flatten()
+                .map(byte_array_block_content_ids),
+        );
+
+    for block_content_id in block_content_ids.flatten().flatten() {
+        ids.insert(block_content_id);
+    }
+}
+
+// The following return the external block content IDs an encoding reads from or `None` if it
+// reads from the core data block.
+
+fn integer_block_content_ids(encoding: &Encoding<Integer>) -> Option<Vec<i32>> {
+    match encoding.get() {
+        Integer::External(block_content_id) => Some(vec![*block_content_id]),
+        Integer::Huffman(alphabet, _) if alphabet.len() == 1 => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+fn byte_block_content_ids(encoding: &Encoding<Byte>) -> Option<Vec<i32>> {
+    match encoding.get() {
+        Byte::External(block_content_id) => Some(vec![*block_content_id]),
+        Byte::Huffman(alphabet, _) if alphabet.len() == 1 => Some(Vec::new()),
+        Byte::Huffman(..) => None,
+    }
+}
+
+fn byte_array_block_content_ids(encoding: &Encoding<ByteArray>) -> Option<Vec<i32>> {
+    match encoding.get() {
+        ByteArray::ByteArrayLen(len_encoding, value_encoding) => merge(
+            integer_block_content_ids(len_encoding),
+            byte_block_content_ids(value_encoding),
+        ),
+        ByteArray::ByteArrayStop(_, block_content_id) => Some(vec![*block_content_id]),
+    }
+}
+
+fn merge(a: Option<Vec<i32>>, b: Option<Vec<i32>>) -> Option<Vec<i32>> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            a.extend(b);
+            Some(a)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use noodles_sam::record::data::field::{value::Type, Tag};
+
+    use super::*;
+    use crate::data_container::compression_header::{
+        preservation_map::{self, tag_ids_dictionary},
+        TagEncodingMap,
+    };
+
+    #[test]
+    fn test_resolve() {
+        let external = |block_content_id| Encoding::new(Integer::External(block_content_id));
+
+        let data_series_encoding_map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(external(1))
+            .set_cram_bit_flags_encoding(external(2))
+            .set_read_lengths_encoding(external(3))
+            .set_in_seq_positions_encoding(external(4))
+            .set_read_groups_encoding(external(5))
+            .set_tag_ids_encoding(external(6))
+            .set_read_names_encoding(Encoding::new(ByteArray::ByteArrayStop(0x00, 7)))
+            .set_quality_scores_encoding(Encoding::new(Byte::External(8)))
+            // FN shares a block with QS.
+            .set_number_of_read_features_encoding(external(8))
+            .build()
+            .unwrap();
+
+        let tag_encoding_map = TagEncodingMap::from(HashMap::from([(
+            tag_ids_dictionary::Key::new(Tag::ReadGroup, Type::String).id(),
+            Encoding::new(ByteArray::ByteArrayStop(0x00, 9)),
+        )]));
+
+        let compression_header = CompressionHeader::new(
+            preservation_map::Builder::default().build(),
+            data_series_encoding_map,
+            tag_encoding_map,
+        );
+
+        let fields = Fields::default()
+            .set_read_names(false)
+            .set_quality_scores(false)
+            .set_tags(false);
+
+        let (actual, skipped_block_content_ids) = fields.resolve(&compression_header);
+
+        let expected = Fields::default().set_read_names(false).set_tags(false);
+        assert_eq!(actual, expected);
+        assert_eq!(skipped_block_content_ids, HashSet::from([7, 9]));
+
+        let (actual, skipped_block_content_ids) = Fields::default().resolve(&compression_header);
+        assert_eq!(actual, Fields::default());
+        assert!(skipped_block_content_ids.is_empty());
+    }
+}
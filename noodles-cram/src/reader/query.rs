@@ -7,7 +7,7 @@ use noodles_core::region::Interval;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::Reader;
+use super::{resolve_default_quality_scores, Reader};
 use crate::{crai, Record};
 
 /// An iterator over records that intersect a given region.
@@ -80,16 +80,23 @@ where
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reference_sequence_repository,
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
+                slice
+                    .records_with_fields(compression_header, self.reader.fields)
+                    .and_then(|mut records| {
+                        slice.resolve_records(
+                            self.reference_sequence_repository,
+                            self.header,
+                            compression_header,
+                            &mut records,
+                        )?;
+
+                        resolve_default_quality_scores(
+                            self.reader.default_quality_score,
+                            &mut records,
+                        );
+
+                        Ok(records)
+                    })
             })
             .collect::<Result<Vec<_>, _>>();
 
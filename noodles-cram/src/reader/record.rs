@@ -33,6 +33,8 @@ use crate::{
     BitReader, Record,
 };
 
+use super::Fields;
+
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReadRecordError {
@@ -66,6 +68,7 @@ where
     core_data_reader: BitReader<CDR>,
     external_data_readers: ExternalDataReaders<EDR>,
     reference_sequence_context: ReferenceSequenceContext,
+    fields: Fields,
     prev_alignment_start: Option<Position>,
 }
 
@@ -79,6 +82,7 @@ where
         core_data_reader: BitReader<CDR>,
         external_data_readers: ExternalDataReaders<EDR>,
         reference_sequence_context: ReferenceSequenceContext,
+        fields: Fields,
     ) -> Self {
         let initial_alignment_start = match reference_sequence_context {
             ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
@@ -90,6 +94,7 @@ where
             core_data_reader,
             external_data_readers,
             reference_sequence_context,
+            fields,
             prev_alignment_start: initial_alignment_start,
         }
     }
@@ -108,7 +113,11 @@ where
         self.read_read_names(&mut record)?;
         self.read_mate_data(&mut record, bam_bit_flags, cram_bit_flags)?;
 
-        record.tags = self.read_tag_data()?;
+        if self.fields.tags() {
+            record.tags = self.read_tag_data()?;
+        } else {
+            self.read_tag_line()?;
+        }
 
         if bam_bit_flags.is_unmapped() {
             self.read_unmapped_read(&mut record, cram_bit_flags, read_length)?;
@@ -269,7 +278,7 @@ where
         let preservation_map = self.compression_header.preservation_map();
 
         // Missing read names are generated when resolving mates.
-        if preservation_map.read_names_included() {
+        if preservation_map.read_names_included() && self.fields.read_names() {
             record.read_name = self.read_read_name()?;
         }
 
@@ -326,7 +335,7 @@ where
 
             let preservation_map = self.compression_header.preservation_map();
 
-            if !preservation_map.read_names_included() {
+            if !preservation_map.read_names_included() && self.fields.read_names() {
                 record.read_name = self.read_read_name()?;
             }
 
@@ -520,14 +529,17 @@ where
         let mut prev_position = 0;
 
         for _ in 0..feature_count {
-            let feature = self.read_feature(prev_position)?;
-            prev_position = usize::from(feature.position());
-            record.add_feature(feature);
+            let (position, feature) = self.read_feature(prev_position)?;
+            prev_position = usize::from(position);
+
+            if let Some(feature) = feature {
+                record.add_feature(feature);
+            }
         }
 
         record.mapping_quality = self.read_mapping_quality()?;
 
-        if flags.are_quality_scores_stored_as_array() {
+        if flags.are_quality_scores_stored_as_array() && self.fields.quality_scores() {
             record.quality_scores.as_mut().reserve(read_length);
 
             for _ in 0..read_length {
@@ -559,7 +571,11 @@ where
         .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
     }
 
-    fn read_feature(&mut self, prev_position: usize) -> io::Result<Feature> {
+    // Returns the position of the feature and, unless the feature is skipped, the feature.
+    //
+    // When quality scores are not decoded, quality score features (`q`, `Q`) are skipped, and a
+    // read base feature (`B`) is replaced by a bases feature (`b`) of length 1.
+    fn read_feature(&mut self, prev_position: usize) -> io::Result<(Position, Option<Feature>)> {
         use feature::Code;
 
         let code = self.read_feature_code()?;
@@ -568,57 +584,72 @@ where
         let position = Position::try_from(prev_position + delta)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-        match code {
+        let feature = match code {
             Code::Bases => {
                 let bases = self.read_stretches_of_bases()?;
-                Ok(Feature::Bases(position, bases))
+                Feature::Bases(position, bases)
             }
             Code::Scores => {
+                if !self.fields.quality_scores() {
+                    return Ok((position, None));
+                }
+
                 let quality_scores = self.read_stretches_of_quality_scores()?;
-                Ok(Feature::Scores(position, quality_scores))
+                Feature::Scores(position, quality_scores)
             }
             Code::ReadBase => {
                 let base = self.read_base()?;
+
+                if !self.fields.quality_scores() {
+                    return Ok((position, Some(Feature::Bases(position, vec![base]))));
+                }
+
                 let quality_score = self.read_quality_score()?;
-                Ok(Feature::ReadBase(position, base, quality_score))
+                Feature::ReadBase(position, base, quality_score)
             }
             Code::Substitution => {
                 let code = self.read_base_substitution_code()?;
-                Ok(Feature::Substitution(position, code))
+                Feature::Substitution(position, code)
             }
             Code::Insertion => {
                 let bases = self.read_insertion()?;
-                Ok(Feature::Insertion(position, bases))
+                Feature::Insertion(position, bases)
             }
             Code::Deletion => {
                 let len = self.read_deletion_length()?;
-                Ok(Feature::Deletion(position, len))
+                Feature::Deletion(position, len)
             }
             Code::InsertBase => {
                 let base = self.read_base()?;
-                Ok(Feature::InsertBase(position, base))
+                Feature::InsertBase(position, base)
             }
             Code::QualityScore => {
+                if !self.fields.quality_scores() {
+                    return Ok((position, None));
+                }
+
                 let score = self.read_quality_score()?;
-                Ok(Feature::QualityScore(position, score))
+                Feature::QualityScore(position, score)
             }
             Code::ReferenceSkip => {
                 let len = self.read_reference_skip_length()?;
-                Ok(Feature::ReferenceSkip(position, len))
+                Feature::ReferenceSkip(position, len)
             }
             Code::SoftClip => {
                 let bases = self.read_soft_clip()?;
-                Ok(Feature::SoftClip(position, bases))
+                Feature::SoftClip(position, bases)
             }
             Code::Padding => {
                 let len = self.read_padding()?;
-                Ok(Feature::Padding(position, len))
+                Feature::Padding(position, len)
             }
             Code::HardClip => {
                 let len = self.read_hard_clip()?;
-                Ok(Feature::HardClip(position, len))
+                Feature::HardClip(position, len)
             }
-        }
+        };
+
+        Ok((position, Some(feature)))
     }
 
     fn read_feature_code(&mut self) -> io::Result<feature::Code> {
@@ -935,7 +966,7 @@ where
             record.bases.push(base);
         }
 
-        if flags.are_quality_scores_stored_as_array() {
+        if flags.are_quality_scores_stored_as_array() && self.fields.quality_scores() {
             record.quality_scores.as_mut().reserve(read_length);
 
             for _ in 0..read_length {
@@ -1015,7 +1046,28 @@ where
                 decoder.decode(core_data_reader)
             }
         }
-        Integer::Beta(offset, len) => core_data_reader.read_u32(*len).map(|i| (i as i32 - offset)),
+        Integer::Beta(offset, len) => core_data_reader.read_u32(*len).map(|i| i as i32 - offset),
+        Integer::Gamma(offset) => {
+            let mut len = 0;
+
+            while core_data_reader.read_u32(1)? == 0 {
+                len += 1;
+
+                if len >= 32 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid gamma code",
+                    ));
+                }
+            }
+
+            let n = (1 << len) | core_data_reader.read_u32(len)?;
+
+            i32::try_from(n)
+                .ok()
+                .and_then(|n| n.checked_sub(*offset))
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid gamma value"))
+        }
         _ => todo!("decode_itf8: {:?}", encoding),
     }
 }
@@ -1119,10 +1171,25 @@ mod tests {
         t(&Encoding::new(Integer::External(1)), 13)?;
         t(&Encoding::new(Integer::Huffman(vec![0x4e], vec![0])), 0x4e)?;
         t(&Encoding::new(Integer::Beta(1, 3)), 3)?;
+        t(&Encoding::new(Integer::Gamma(0)), 1)?;
 
         Ok(())
     }
 
+    #[test]
+    fn test_decode_itf8_with_gamma_overflow() {
+        let core_data = [0b10000000];
+        let mut core_data_reader = BitReader::new(&core_data[..]);
+        let mut external_data_readers = ExternalDataReaders::<&[u8]>::new();
+
+        let encoding = Encoding::new(Integer::Gamma(i32::MIN));
+
+        assert!(matches!(
+            decode_itf8(&encoding, &mut core_data_reader, &mut external_data_readers),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[test]
     fn test_decode_byte_array() -> io::Result<()> {
         fn t(
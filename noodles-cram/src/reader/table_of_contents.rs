@@ -0,0 +1,375 @@
+//! CRAM table of contents.
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use bytes::BytesMut;
+use flate2::CrcReader;
+use noodles_core::Position;
+
+use super::{
+    data_container::{header::read_header, slice::get_header},
+    num::read_itf8,
+};
+use crate::{
+    container::{
+        block::{CompressionMethod, ContentType},
+        Block,
+    },
+    data_container::{slice, ReferenceSequenceContext},
+};
+
+/// The reference sequence span of a container or slice.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferenceSequenceSpan {
+    /// The records are on a single reference sequence.
+    Some {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The alignment start.
+        alignment_start: Position,
+        /// The alignment span.
+        alignment_span: usize,
+    },
+    /// The records are unmapped and unplaced.
+    None,
+    /// The records are on multiple reference sequences.
+    Many,
+}
+
+impl From<ReferenceSequenceContext> for ReferenceSequenceSpan {
+    fn from(reference_sequence_context: ReferenceSequenceContext) -> Self {
+        match reference_sequence_context {
+            ReferenceSequenceContext::Some(context) => Self::Some {
+                reference_sequence_id: context.reference_sequence_id(),
+                alignment_start: context.alignment_start(),
+                alignment_span: context.alignment_span(),
+            },
+            ReferenceSequenceContext::None => Self::None,
+            ReferenceSequenceContext::Many => Self::Many,
+        }
+    }
+}
+
+/// A table of contents entry of a data container.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Container {
+    position: u64,
+    len: u64,
+    reference_sequence_span: ReferenceSequenceSpan,
+    record_count: usize,
+    record_counter: u64,
+    base_count: u64,
+    slices: Vec<Slice>,
+}
+
+impl Container {
+    /// Returns the position of the container in the stream.
+    ///
+    /// This is the position of the start of the container header, i.e., the container offset in
+    /// a CRAM index.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the size of the container, including its header.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the reference sequence span of the container.
+    pub fn reference_sequence_span(&self) -> ReferenceSequenceSpan {
+        self.reference_sequence_span
+    }
+
+    /// Returns the number of records in the container.
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Returns the index of the first record in the container.
+    pub fn record_counter(&self) -> u64 {
+        self.record_counter
+    }
+
+    /// Returns the number of read bases in the container.
+    pub fn base_count(&self) -> u64 {
+        self.base_count
+    }
+
+    /// Returns the slices in the container.
+    pub fn slices(&self) -> &[Slice] {
+        &self.slices
+    }
+}
+
+/// A table of contents entry of a slice.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slice {
+    landmark: u64,
+    len: u64,
+    reference_sequence_span: ReferenceSequenceSpan,
+    record_count: usize,
+    record_counter: u64,
+}
+
+impl Slice {
+    /// Returns the position of the slice relative to the end of the container header.
+    ///
+    /// This is the slice landmark in a CRAM index.
+    pub fn landmark(&self) -> u64 {
+        self.landmark
+    }
+
+    /// Returns the size of the slice.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the reference sequence span of the slice.
+    pub fn reference_sequence_span(&self) -> ReferenceSequenceSpan {
+        self.reference_sequence_span
+    }
+
+    /// Returns the number of records in the slice.
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Returns the index of the first record in the slice.
+    pub fn record_counter(&self) -> u64 {
+        self.record_counter
+    }
+}
+
+pub(super) fn read_table_of_contents<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+) -> io::Result<Vec<Container>>
+where
+    R: Read + Seek,
+{
+    let mut containers = Vec::new();
+
+    loop {
+        let position = reader.stream_position()?;
+
+        let header = match read_header(reader)? {
+            Some(header) => header,
+            None => break,
+        };
+
+        let data_position = reader.stream_position()?;
+        let data_len = header.len() as u64;
+
+        let landmarks = header.landmarks();
+        let mut slices = Vec::with_capacity(landmarks.len());
+
+        for (i, &landmark) in landmarks.iter().enumerate() {
+            let landmark = landmark as u64;
+
+            let end = landmarks
+                .get(i + 1)
+                .map(|&next_landmark| next_landmark as u64)
+                .unwrap_or(data_len);
+
+            let len = end
+                .checked_sub(landmark)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid landmarks"))?;
+
+            reader.seek(SeekFrom::Start(data_position + landmark))?;
+            let slice_header = read_slice_header(reader, buf)?;
+
+            slices.push(Slice {
+                landmark,
+                len,
+                reference_sequence_span: slice_header.reference_sequence_context().into(),
+                record_count: slice_header.record_count(),
+                record_counter: slice_header.record_counter(),
+            });
+        }
+
+        let end_position = reader.seek(SeekFrom::Start(data_position + data_len))?;
+
+        let record_count = usize::try_from(header.record_count())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        containers.push(Container {
+            position,
+            len: end_position - position,
+            reference_sequence_span: header.reference_sequence_context().into(),
+            record_count,
+            record_counter: header.record_counter(),
+            base_count: header.base_count(),
+            slices,
+        });
+    }
+
+    Ok(containers)
+}
+
+fn read_slice_header<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<slice::Header>
+where
+    R: Read,
+{
+    let block = read_block(reader, buf)?;
+
+    if block.content_type() != ContentType::SliceHeader {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid block content type: expected {:?}, got {:?}",
+                ContentType::SliceHeader,
+                block.content_type()
+            ),
+        ));
+    }
+
+    let mut data = block.decompressed_data()?;
+    get_header(&mut data)
+}
+
+// Reads a single block from the stream, in contrast to reading blocks from a buffered container.
+fn read_block<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<Block>
+where
+    R: Read,
+{
+    let mut crc_reader = CrcReader::new(reader);
+
+    let method = crc_reader.read_u8().and_then(|n| {
+        CompressionMethod::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let content_type = crc_reader.read_u8().and_then(|n| {
+        ContentType::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let content_id = read_itf8(&mut crc_reader)?;
+
+    let size_in_bytes = read_itf8(&mut crc_reader).and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let raw_size_in_bytes = read_itf8(&mut crc_reader).and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    buf.resize(size_in_bytes, 0);
+    crc_reader.read_exact(buf)?;
+    let data = buf.split().freeze();
+
+    let actual_crc32 = crc_reader.crc().sum();
+
+    let reader = crc_reader.into_inner();
+    let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+
+    if actual_crc32 != expected_crc32 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "container block checksum mismatch: expected {:08x}, got {:08x}",
+                expected_crc32, actual_crc32
+            ),
+        ));
+    }
+
+    Ok(Block::builder()
+        .set_compression_method(method)
+        .set_content_type(content_type)
+        .set_content_id(content_id)
+        .set_uncompressed_len(raw_size_in_bytes)
+        .set_data(data)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use md5::{Digest, Md5};
+    use noodles_sam::{self as sam, AlignmentWriter};
+
+    use super::*;
+    use crate::{Reader, Writer};
+
+    #[test]
+    fn test_read_table_of_contents() -> Result<(), Box<dyn std::error::Error>> {
+        let md5 = Md5::digest(b"ACGTACGT");
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let records = [
+            sam::alignment::Record::builder()
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("CGTA".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+        ];
+
+        let mut writer = Writer::builder(Vec::new())
+            .require_reference_sequences(false)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref().clone();
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let position = reader.position()?;
+        let containers = reader.read_table_of_contents()?;
+
+        assert_eq!(containers.len(), 1);
+
+        let container = &containers[0];
+        assert_eq!(container.position(), position);
+        assert_eq!(container.record_count(), 2);
+        assert_eq!(container.record_counter(), 0);
+        assert_eq!(container.base_count(), 8);
+
+        let expected_span = ReferenceSequenceSpan::Some {
+            reference_sequence_id: 0,
+            alignment_start: Position::try_from(2)?,
+            alignment_span: 5,
+        };
+        assert_eq!(container.reference_sequence_span(), expected_span);
+
+        let slices = container.slices();
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].record_count(), 2);
+        assert_eq!(slices[0].reference_sequence_span(), expected_span);
+
+        reader.seek(SeekFrom::Start(position))?;
+        let (container_header, _) = reader
+            .read_data_container_with_container_header()?
+            .expect("missing data container");
+        assert_eq!(slices[0].landmark(), container_header.landmarks()[0] as u64);
+        assert_eq!(reader.position()?, position + container.len());
+
+        // The next container is the EOF container.
+        assert!(reader.read_data_container()?.is_none());
+
+        Ok(())
+    }
+}
@@ -107,8 +107,7 @@ impl fmt::Display for TryFromCharError {
 impl TryFrom<char> for Code {
     type Error = TryFromCharError;
 
-    #[allow(useless_deprecated)]
-    #[deprecated(since = "0.13.0", note = "Use `TryFrom<u8>` instead.")]
+    // Deprecated since 0.13.0. Use `TryFrom<u8>` instead.
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             'b' => Ok(Self::Bases),
@@ -129,8 +128,7 @@ impl TryFrom<char> for Code {
 }
 
 impl From<Code> for char {
-    #[allow(useless_deprecated)]
-    #[deprecated(since = "0.13.0", note = "Convert to a `u8` instead.")]
+    // Deprecated since 0.13.0. Convert to a `u8` instead.
     fn from(code: Code) -> Self {
         match code {
             Code::Bases => 'b',
@@ -0,0 +1,149 @@
+use std::io::{self, Read, Write};
+
+use noodles_sam as sam;
+
+use super::{
+    writer::{header_container::write_header_container, write_file_definition},
+    Reader,
+};
+
+/// Replaces the header of a CRAM stream.
+///
+/// This is equivalent to `samtools reheader`. The file definition is kept as is, the CRAM header
+/// container is replaced with one that holds the given header, and all data containers are copied
+/// without being decoded.
+///
+/// Reference sequence dictionary entries must have MD5 checksums (`M5`) set, and they are expected
+/// to be compatible with the original header, i.e., in the same order.
+///
+/// Because the positions of the data containers change, any existing index of the original stream
+/// (`.crai`) is invalidated and must be rebuilt for the output.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_cram as cram;
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+/// reader.read_file_definition()?;
+/// let mut header: sam::Header = reader.read_file_header()?.parse()?;
+/// header.add_comment("noodles-cram");
+///
+/// let src = File::open("sample.cram")?;
+/// let dst = File::create("out.cram")?;
+/// cram::reheader(src, dst, &header)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reheader<R, W>(reader: R, mut writer: W, header: &sam::Header) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = Reader::new(reader);
+
+    let file_definition = reader.read_file_definition()?;
+    let version = file_definition.version();
+
+    if version.major() != 3 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported version: {}.{}",
+                version.major(),
+                version.minor()
+            ),
+        ));
+    }
+
+    reader.read_file_header()?;
+
+    write_file_definition(&mut writer, &file_definition)?;
+    write_header_container(&mut writer, header)?;
+    io::copy(reader.get_mut(), &mut writer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use md5::{Digest, Md5};
+    use noodles_core::Position;
+    use noodles_fasta as fasta;
+    use noodles_sam::AlignmentWriter;
+
+    use super::*;
+    use crate::{Record, Writer};
+
+    fn read_cram(
+        src: &[u8],
+        reference_sequence_repository: &fasta::Repository,
+    ) -> Result<(String, u64, Vec<Record>), Box<dyn std::error::Error>> {
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_file_definition()?;
+
+        let header_text = reader.read_file_header()?;
+        let header: sam::Header = header_text.parse()?;
+        let position = reader.position()?;
+
+        let records = reader
+            .records(reference_sequence_repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        Ok((header_text, position, records))
+    }
+
+    #[test]
+    fn test_reheader() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = b"ACGTACGT";
+        let md5 = Md5::digest(reference_sequence);
+
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(reference_sequence.to_vec()),
+        )]);
+
+        let record = sam::alignment::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("GTAC".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_reference_sequence_repository(reference_sequence_repository.clone())
+            .build();
+
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let src = writer.get_ref();
+
+        let mut new_header = header.clone();
+        new_header.add_comment("noodles-cram");
+
+        let mut dst = Vec::new();
+        reheader(&src[..], &mut dst, &new_header)?;
+
+        let (_, src_position, expected_records) = read_cram(src, &reference_sequence_repository)?;
+        let (actual_header_text, dst_position, actual_records) =
+            read_cram(&dst, &reference_sequence_repository)?;
+
+        assert_eq!(actual_header_text, new_header.to_string());
+        assert_eq!(actual_records, expected_records);
+
+        assert_eq!(dst[..26], src[..26]);
+        assert_eq!(dst[dst_position as usize..], src[src_position as usize..]);
+
+        Ok(())
+    }
+}
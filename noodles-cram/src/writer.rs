@@ -1,7 +1,7 @@
 mod builder;
 mod container;
 pub(crate) mod data_container;
-mod header_container;
+pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
 pub(crate) mod record;
@@ -145,7 +145,20 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
-        let file_definition = FileDefinition::default();
+        let version = self.options.version;
+
+        if version.major() != 3 || version.minor() > 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported version: {}.{}",
+                    version.major(),
+                    version.minor()
+                ),
+            ));
+        }
+
+        let file_definition = FileDefinition::new(version, [0; 20]);
         write_file_definition(&mut self.inner, &file_definition)
     }
 
@@ -202,6 +215,10 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use super::data_container::builder::AddRecordError;
 
+        if !self.options.preserve_quality_scores {
+            discard_quality_scores(&mut record);
+        }
+
         loop {
             match self.data_container_builder.add_record(record) {
                 Ok(_) => {
@@ -210,11 +227,11 @@ where
                 }
                 Err(e) => match e {
                     AddRecordError::ContainerFull(r) => {
-                        record = r;
+                        record = *r;
                         self.flush(header)?;
                     }
                     AddRecordError::SliceFull(r) => {
-                        record = r;
+                        record = *r;
                     }
                     _ => return Err(io::Error::from(io::ErrorKind::InvalidInput)),
                 },
@@ -270,7 +287,36 @@ where
     }
 }
 
-fn write_file_definition<W>(writer: &mut W, file_definition: &FileDefinition) -> io::Result<()>
+// Removes quality scores from a record, including those in read features.
+//
+// A read base feature (`B`) carries both a base and a quality score, so it is replaced by a bases
+// feature (`b`) of length 1.
+fn discard_quality_scores(record: &mut Record) {
+    use crate::record::{Feature, Flags};
+
+    record
+        .cram_bit_flags
+        .remove(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+
+    record.quality_scores = sam::record::QualityScores::default();
+
+    let features: Vec<_> = record
+        .features
+        .iter()
+        .filter_map(|feature| match feature {
+            Feature::ReadBase(position, base, _) => Some(Feature::Bases(*position, vec![*base])),
+            Feature::Scores(..) | Feature::QualityScore(..) => None,
+            _ => Some(feature.clone()),
+        })
+        .collect();
+
+    record.features = features.into();
+}
+
+pub(crate) fn write_file_definition<W>(
+    writer: &mut W,
+    file_definition: &FileDefinition,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -287,3 +333,283 @@ where
     let format = [version.major(), version.minor()];
     writer.write_all(&format)
 }
+
+#[cfg(test)]
+mod tests {
+    use md5::{Digest, Md5};
+    use noodles_core::Position;
+    use noodles_sam::AlignmentWriter;
+
+    use super::*;
+
+    fn write_and_read(builder: Builder<Vec<u8>>) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let reference_sequence = b"ACGTACGT";
+        let md5 = Md5::digest(reference_sequence);
+
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let record = sam::alignment::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("GTAC".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(reference_sequence.to_vec()),
+        )]);
+
+        let mut writer = builder
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        // The reference sequences are not available to the reader.
+        let mut reader = crate::Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let reference_sequence_repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(records.len(), 1);
+
+        Ok(records[0]
+            .bases()
+            .as_ref()
+            .iter()
+            .map(|&b| u8::from(b))
+            .collect())
+    }
+
+    #[test]
+    fn test_write_without_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        let builder = Writer::builder(Vec::new()).require_reference_sequences(false);
+        assert_eq!(write_and_read(builder)?, b"GTAC");
+
+        let builder = Writer::builder(Vec::new()).embed_reference_sequences(true);
+        assert_eq!(write_and_read(builder)?, b"GTAC");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_without_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::quality_scores::Score;
+
+        let md5 = Md5::digest(b"ACGTACGT");
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let records = [
+            sam::alignment::Record::builder()
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("1M1I2M".parse()?)
+                .set_sequence("GTAC".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_sequence("ACG".parse()?)
+                .set_quality_scores("NDL".parse()?)
+                .build(),
+        ];
+
+        let mut writer = Writer::builder(Vec::new())
+            .require_reference_sequences(false)
+            .preserve_quality_scores(false)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let reference_sequence_repository = fasta::Repository::default();
+
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+        let data_series_encoding_map = data_container
+            .compression_header()
+            .data_series_encoding_map();
+        assert!(data_series_encoding_map.quality_scores_encoding().is_none());
+        assert!(data_series_encoding_map
+            .stretches_of_quality_scores_encoding()
+            .is_none());
+
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].bases(), records[0].sequence());
+        assert!(actual[0].quality_scores().is_empty());
+        assert_eq!(actual[1].bases(), records[1].sequence());
+        assert!(actual[1].quality_scores().is_empty());
+
+        let score = Score::try_from(30)?;
+
+        let mut reader = crate::Reader::builder(&data[..])
+            .set_default_quality_score(score)
+            .build();
+
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(actual[0].quality_scores().as_ref(), [score; 4]);
+        assert_eq!(actual[1].quality_scores().as_ref(), [score; 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_core_data_series_encodings() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::data_container::compression_header::encoding::codec::Integer;
+
+        let md5 = Md5::digest(b"ACGTACGT");
+        let header: sam::Header =
+            format!("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\tM5:{:x}\n", md5).parse()?;
+
+        let mapping_qualities = [0, 30, 60];
+
+        let records: Vec<_> = (0..64)
+            .map(|i| {
+                let (cigar, sequence) = if i % 2 == 0 {
+                    ("4M", "ACGT")
+                } else {
+                    ("2M1D2M", "ACTA")
+                };
+
+                Ok(sam::alignment::Record::builder()
+                    .set_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(1 + i % 4)?)
+                    .set_cigar(cigar.parse()?)
+                    .set_mapping_quality(
+                        sam::record::MappingQuality::new(mapping_qualities[i % 3])
+                            .expect("invalid mapping quality"),
+                    )
+                    .set_sequence(sequence.parse()?)
+                    .set_quality_scores("NDLS".parse()?)
+                    .build())
+            })
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+
+        let mut writer = Writer::builder(Vec::new())
+            .require_reference_sequences(false)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+        let data_series_encoding_map = data_container
+            .compression_header()
+            .data_series_encoding_map();
+
+        assert_eq!(
+            data_series_encoding_map.read_lengths_encoding().get(),
+            &Integer::Huffman(vec![4], vec![0])
+        );
+
+        assert!(!matches!(
+            data_series_encoding_map
+                .mapping_qualities_encoding()
+                .map(|encoding| encoding.get()),
+            Some(Integer::External(_))
+        ));
+
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let reference_sequence_repository = fasta::Repository::default();
+
+        let actual: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .map(|result| result.and_then(|record| record.try_into_alignment_record(&header)))
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(actual.len(), records.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&records) {
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+            assert_eq!(actual_record.cigar(), expected_record.cigar());
+            assert_eq!(
+                actual_record.mapping_quality(),
+                expected_record.mapping_quality()
+            );
+            assert_eq!(actual_record.sequence(), expected_record.sequence());
+            assert_eq!(
+                actual_record.quality_scores(),
+                expected_record.quality_scores()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_file_definition_with_version() -> io::Result<()> {
+        let mut writer = Writer::builder(Vec::new())
+            .set_version(Version::new(3, 1))
+            .build();
+
+        writer.write_file_definition()?;
+        assert_eq!(&writer.get_ref()[4..6], [0x03, 0x01]);
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_version(Version::new(2, 1))
+            .build();
+
+        assert!(matches!(
+            writer.write_file_definition(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
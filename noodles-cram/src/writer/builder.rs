@@ -3,7 +3,7 @@ use std::io::Write;
 use noodles_fasta as fasta;
 
 use super::{Options, Writer};
-use crate::DataContainer;
+use crate::{file_definition::Version, DataContainer};
 
 /// A CRAM writer builder.
 pub struct Builder<W> {
@@ -33,6 +33,24 @@ where
         self
     }
 
+    /// Sets the format version.
+    ///
+    /// Only CRAM 3.0 and 3.1 can be written. Writing the file definition fails with an
+    /// unsupported version.
+    ///
+    /// The default is 3.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, file_definition::Version};
+    /// let builder = cram::Writer::builder(Vec::new()).set_version(Version::new(3, 1));
+    /// ```
+    pub fn set_version(mut self, version: Version) -> Self {
+        self.options.version = version;
+        self
+    }
+
     /// Sets whether to preserve read names.
     ///
     /// If `false`, read names are discarded.
@@ -43,6 +61,26 @@ where
         self
     }
 
+    /// Sets whether to preserve quality scores.
+    ///
+    /// If `false`, quality scores are discarded, and the quality score data series (`QS` and `QQ`)
+    /// are omitted from the compression header. Decoded records have missing quality scores unless
+    /// the reader substitutes a default quality score (see
+    /// [`crate::reader::Builder::set_default_quality_score`]).
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).preserve_quality_scores(false);
+    /// ```
+    pub fn preserve_quality_scores(mut self, value: bool) -> Self {
+        self.options.preserve_quality_scores = value;
+        self
+    }
+
     /// Sets whether to encode alignment start positions as deltas.
     ///
     /// If `false`, record alignment start positions are written with their actual values.
@@ -53,6 +91,45 @@ where
         self
     }
 
+    /// Sets whether a reference sequence is required to decode records.
+    ///
+    /// If `false`, slices are written without reference sequence checksums, and the reference
+    /// sequence repository is not used. Bases are always stored in the records, so they can be
+    /// decoded without the reference sequences. This is also called no-ref mode.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).require_reference_sequences(false);
+    /// ```
+    pub fn require_reference_sequences(mut self, value: bool) -> Self {
+        self.options.reference_required = value;
+        self
+    }
+
+    /// Sets whether to embed reference sequences.
+    ///
+    /// If `true`, the region of the reference sequence a slice spans is stored in the slice, so
+    /// the output can be read without the reference sequences. This implies a reference sequence
+    /// is not required (see [`Self::require_reference_sequences`]), but the reference sequence
+    /// repository is still used to read the reference sequences to embed.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new()).embed_reference_sequences(true);
+    /// ```
+    pub fn embed_reference_sequences(mut self, value: bool) -> Self {
+        self.options.embed_reference_sequences = value;
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -49,6 +49,10 @@ fn build_container(
         .set_data(buf.into())
         .build();
 
+    // Landmarks are the positions of the slices relative to the end of the container header, so
+    // the first slice starts after the compression header block.
+    let mut landmark = block.len();
+
     let mut blocks = vec![block];
     let mut landmarks = Vec::new();
 
@@ -89,9 +93,8 @@ fn build_container(
             slice_len += external_block.len();
         }
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_len;
         landmarks.push(landmark);
+        landmark += slice_len;
     }
 
     let len = blocks.iter().map(|b| b.len()).sum();
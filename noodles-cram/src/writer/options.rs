@@ -1,14 +1,24 @@
+use crate::file_definition::Version;
+
 #[derive(Clone, Debug)]
 pub struct Options {
+    pub version: Version,
     pub preserve_read_names: bool,
+    pub preserve_quality_scores: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub reference_required: bool,
+    pub embed_reference_sequences: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
+            version: Version::default(),
             preserve_read_names: true,
+            preserve_quality_scores: true,
             encode_alignment_start_positions_as_deltas: true,
+            reference_required: true,
+            embed_reference_sequences: false,
         }
     }
 }
@@ -24,6 +24,7 @@ use crate::{
         },
         CompressionHeader, ReferenceSequenceContext,
     },
+    huffman::CanonicalHuffmanEncoder,
     record::{
         feature::{self, substitution},
         Feature, Flags, NextMateFlags,
@@ -55,10 +56,17 @@ impl fmt::Display for WriteRecordError {
     }
 }
 
+// Canonical Huffman encoders keyed by the address of their alphabet in the compression header.
+//
+// Building an encoder builds its code book, so encoders are cached rather than built for each
+// encoded value.
+type HuffmanEncoders = HashMap<usize, CanonicalHuffmanEncoder>;
+
 pub struct Writer<'a, W, X> {
     compression_header: &'a CompressionHeader,
     core_data_writer: &'a mut BitWriter<W>,
     external_data_writers: &'a mut HashMap<i32, X>,
+    huffman_encoders: HuffmanEncoders,
     reference_sequence_context: ReferenceSequenceContext,
     prev_alignment_start: Option<Position>,
 }
@@ -83,6 +91,7 @@ where
             compression_header,
             core_data_writer,
             external_data_writers,
+            huffman_encoders: HuffmanEncoders::new(),
             reference_sequence_context,
             prev_alignment_start: initial_alignment_start,
         }
@@ -126,6 +135,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             bam_bit_flags,
         )
     }
@@ -142,6 +152,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             cram_bit_flags,
         )
     }
@@ -182,6 +193,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             reference_id,
         )
     }
@@ -199,6 +211,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             len,
         )
     }
@@ -245,6 +258,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             alignment_start_or_delta,
         )
     }
@@ -268,6 +282,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             read_group,
         )
     }
@@ -292,6 +307,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             read_name,
         )
     }
@@ -336,6 +352,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     next_mate_bit_flags,
                 )
             })
@@ -371,6 +388,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             raw_next_fragment_reference_sequence_id,
         )
     }
@@ -401,6 +419,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             position,
         )
     }
@@ -420,6 +439,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     template_size,
                 )
             })
@@ -447,6 +467,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -491,6 +512,7 @@ where
                 encoding,
                 self.core_data_writer,
                 self.external_data_writers,
+                &mut self.huffman_encoders,
                 &buf,
             )?;
         }
@@ -511,6 +533,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -556,6 +579,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             number_of_read_features,
         )
     }
@@ -624,6 +648,7 @@ where
                     encoding,
                     self.core_data_writer,
                     self.external_data_writers,
+                    &mut self.huffman_encoders,
                     feature_code,
                 )
             })
@@ -648,6 +673,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             position,
         )
     }
@@ -670,6 +696,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -694,6 +721,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &scores,
         )
     }
@@ -716,6 +744,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             raw_base,
         )
     }
@@ -738,6 +767,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             score,
         )
     }
@@ -775,6 +805,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             code,
         )
     }
@@ -797,6 +828,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -819,6 +851,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -841,6 +874,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -863,6 +897,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             &raw_bases,
         )
     }
@@ -885,6 +920,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -907,6 +943,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             n,
         )
     }
@@ -936,6 +973,7 @@ where
             encoding,
             self.core_data_writer,
             self.external_data_writers,
+            &mut self.huffman_encoders,
             mapping_quality,
         )
     }
@@ -957,8 +995,9 @@ where
 
 fn encode_byte<W, X>(
     encoding: &Encoding<Byte>,
-    _core_data_writer: &mut BitWriter<W>,
+    core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     value: u8,
 ) -> io::Result<()>
 where
@@ -978,14 +1017,21 @@ where
 
             writer.write_u8(value)
         }
-        _ => todo!("encode_byte: {:?}", encoding),
+        Byte::Huffman(alphabet, bit_lens) => encode_huffman(
+            core_data_writer,
+            huffman_encoders,
+            alphabet,
+            bit_lens,
+            i32::from(value),
+        ),
     }
 }
 
 fn encode_itf8<W, X>(
     encoding: &Encoding<Integer>,
-    _core_data_writer: &mut BitWriter<W>,
+    core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     value: i32,
 ) -> io::Result<()>
 where
@@ -1005,14 +1051,80 @@ where
 
             write_itf8(writer, value)
         }
+        Integer::Huffman(alphabet, bit_lens) => encode_huffman(
+            core_data_writer,
+            huffman_encoders,
+            alphabet,
+            bit_lens,
+            value,
+        ),
+        Integer::Beta(offset, len) => {
+            let n = value
+                .checked_add(*offset)
+                .and_then(|n| u32::try_from(n).ok())
+                .filter(|&n| *len >= 32 || n >> len == 0)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("value out of range for beta encoding: {}", value),
+                    )
+                })?;
+
+            core_data_writer.write_u32(n, *len as usize)
+        }
+        Integer::Gamma(offset) => {
+            let n = value
+                .checked_add(*offset)
+                .and_then(|n| u32::try_from(n).ok())
+                .filter(|&n| n > 0)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("value out of range for gamma encoding: {}", value),
+                    )
+                })?;
+
+            let len = (u32::BITS - n.leading_zeros()) as usize;
+            core_data_writer.write_u32(0, len - 1)?;
+            core_data_writer.write_u32(n, len)
+        }
         _ => todo!("encode_itf8: {:?}", encoding),
     }
 }
 
+fn encode_huffman<W>(
+    core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders,
+    alphabet: &[i32],
+    bit_lens: &[u32],
+    value: i32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    if let [symbol] = alphabet {
+        if *symbol == value {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("symbol not in alphabet: {}", value),
+            ))
+        }
+    } else {
+        let encoder = huffman_encoders
+            .entry(alphabet.as_ptr() as usize)
+            .or_insert_with(|| CanonicalHuffmanEncoder::new(alphabet, bit_lens));
+
+        encoder.encode(core_data_writer, value)
+    }
+}
+
 fn encode_byte_array<W, X>(
     encoding: &Encoding<ByteArray>,
     core_data_writer: &mut BitWriter<W>,
     external_data_writers: &mut HashMap<i32, X>,
+    huffman_encoders: &mut HuffmanEncoders,
     data: &[u8],
 ) -> io::Result<()>
 where
@@ -1023,13 +1135,20 @@ where
         ByteArray::ByteArrayLen(len_encoding, value_encoding) => {
             let len = i32::try_from(data.len())
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-            encode_itf8(len_encoding, core_data_writer, external_data_writers, len)?;
+            encode_itf8(
+                len_encoding,
+                core_data_writer,
+                external_data_writers,
+                huffman_encoders,
+                len,
+            )?;
 
             for &value in data {
                 encode_byte(
                     value_encoding,
                     core_data_writer,
                     external_data_writers,
+                    huffman_encoders,
                     value,
                 )?;
             }
@@ -9,7 +9,7 @@ use std::io;
 use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
 
-use super::index::reference_sequence::bin::Chunk;
+use super::index::reference_sequence::{bin::Chunk, Metadata};
 
 /// A binning index.
 pub trait BinningIndex {
@@ -137,6 +137,93 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Estimates the number of records in each window of a linear index.
+///
+/// A linear index is a list of the start positions of the first record that overlaps each window
+/// of a reference sequence, e.g., [`crate::index::ReferenceSequence::linear_index`]. The record
+/// count of the reference sequence metadata is distributed over the windows by the number of
+/// compressed bytes between the start positions of consecutive windows. No records are read.
+///
+/// The resolution of the estimate is a BGZF block. Records in windows that start in the same block
+/// are attributed to the last of these windows. If all records are in a single block, they are
+/// attributed to the first window.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{binning_index::estimate_record_counts, index::reference_sequence::Metadata};
+///
+/// let linear_index = [
+///     bgzf::VirtualPosition::from(0),
+///     bgzf::VirtualPosition::from(3 << 16),
+///     bgzf::VirtualPosition::from(3 << 16),
+/// ];
+///
+/// let metadata = Metadata::new(
+///     bgzf::VirtualPosition::from(0),
+///     bgzf::VirtualPosition::from(4 << 16),
+///     7,
+///     1,
+/// );
+///
+/// assert_eq!(estimate_record_counts(&linear_index, &metadata), [6, 0, 2]);
+/// ```
+pub fn estimate_record_counts(
+    linear_index: &[bgzf::VirtualPosition],
+    metadata: &Metadata,
+) -> Vec<u64> {
+    let mut counts = vec![0; linear_index.len()];
+
+    if counts.is_empty() {
+        return counts;
+    }
+
+    let start_position = metadata.start_position().compressed();
+    let end_position = metadata.end_position().compressed();
+
+    let positions: Vec<_> = linear_index
+        .iter()
+        .map(|pos| {
+            pos.compressed()
+                .clamp(start_position, end_position.max(start_position))
+        })
+        .chain([end_position])
+        .collect();
+
+    let sizes: Vec<_> = positions
+        .windows(2)
+        .map(|w| w[1].saturating_sub(w[0]))
+        .collect();
+
+    let total_size: u64 = sizes.iter().sum();
+    let record_count = metadata.mapped_record_count() + metadata.unmapped_record_count();
+
+    if total_size == 0 {
+        counts[0] = record_count;
+        return counts;
+    }
+
+    // Counts are rounded by the largest remainder method so that their sum is the record count.
+    let mut remainders = Vec::with_capacity(sizes.len());
+    let mut n = 0;
+
+    for (i, (count, &size)) in counts.iter_mut().zip(&sizes).enumerate() {
+        let q = u128::from(record_count) * u128::from(size);
+        *count = (q / u128::from(total_size)) as u64;
+        n += *count;
+        remainders.push((q % u128::from(total_size), i));
+    }
+
+    remainders.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    for &(_, i) in remainders.iter().take((record_count - n) as usize) {
+        counts[i] += 1;
+    }
+
+    counts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +305,46 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_estimate_record_counts() {
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(2 << 16),
+            bgzf::VirtualPosition::from(12 << 16),
+            9,
+            1,
+        );
+
+        assert!(estimate_record_counts(&[], &metadata).is_empty());
+
+        let linear_index = [
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(5 << 16),
+            bgzf::VirtualPosition::from(7 << 16),
+            bgzf::VirtualPosition::from(7 << 16),
+        ];
+        assert_eq!(
+            estimate_record_counts(&linear_index, &metadata),
+            [3, 2, 0, 5]
+        );
+
+        let linear_index = [
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(5 << 16),
+            bgzf::VirtualPosition::from(8 << 16),
+        ];
+        assert_eq!(estimate_record_counts(&linear_index, &metadata), [3, 3, 4]);
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(2 << 16),
+            bgzf::VirtualPosition::from((2 << 16) | 8),
+            5,
+            0,
+        );
+        let linear_index = [
+            bgzf::VirtualPosition::from(2 << 16),
+            bgzf::VirtualPosition::from((2 << 16) | 5),
+        ];
+        assert_eq!(estimate_record_counts(&linear_index, &metadata), [5, 0]);
+    }
 }
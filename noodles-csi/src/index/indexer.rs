@@ -0,0 +1,342 @@
+use std::{cmp, io};
+
+use noodles_core::Position;
+
+use super::{
+    reference_sequence::{self, bin::Chunk},
+    Index,
+};
+
+const DEFAULT_MIN_SHIFT: u8 = 14;
+const MAX_DEPTH: u8 = 10;
+
+/// A coordinate-sorted index (CSI) indexer.
+///
+/// An indexer creates an index from a stream of records, given as reference sequence IDs, start
+/// and end positions, and the chunks that contain them. Records must be sorted by reference
+/// sequence ID and start position.
+///
+/// Unless set, the depth is the smallest depth that can represent the largest end position.
+#[derive(Debug)]
+pub struct Indexer {
+    min_shift: u8,
+    depth: Option<u8>,
+    aux: Vec<u8>,
+    reference_sequence_builders: Vec<reference_sequence::Builder>,
+    last_record: Option<(usize, Position)>,
+    max_position: usize,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl Indexer {
+    /// Creates a CSI indexer with the given min shift.
+    ///
+    /// The depth is chosen when the index is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::new(12);
+    /// ```
+    pub fn new(min_shift: u8) -> Self {
+        Self {
+            min_shift,
+            depth: None,
+            aux: Vec::new(),
+            reference_sequence_builders: Vec::new(),
+            last_record: None,
+            max_position: 0,
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+
+    /// Creates a CSI indexer with the given min shift and depth.
+    ///
+    /// Adding a record that ends after the max position of the min shift and depth is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::with_depth(14, 6);
+    /// ```
+    pub fn with_depth(min_shift: u8, depth: u8) -> Self {
+        Self {
+            depth: Some(depth),
+            ..Self::new(min_shift)
+        }
+    }
+
+    /// Sets the auxiliary data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.set_aux(b"ndls".to_vec());
+    /// ```
+    pub fn set_aux(&mut self, aux: Vec<u8>) {
+        self.aux = aux;
+    }
+
+    /// Adds a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// indexer.add_record(
+    ///     0,
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(144), bgzf::VirtualPosition::from(233)),
+    /// )?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(
+        &mut self,
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        if end < start {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid record interval: {}-{}", start, end),
+            ));
+        }
+
+        if let Some(last_record) = self.last_record {
+            if (reference_sequence_id, start) < last_record {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "records are not coordinate-sorted",
+                ));
+            }
+        }
+
+        let depth = self.depth.unwrap_or(MAX_DEPTH);
+
+        if usize::from(end) > max_position(self.min_shift, depth) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "record end position ({}) is greater than the max position for the given min shift ({}) and depth ({})",
+                    end, self.min_shift, depth
+                ),
+            ));
+        }
+
+        if reference_sequence_id >= self.reference_sequence_builders.len() {
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_id + 1, Default::default);
+        }
+
+        self.reference_sequence_builders[reference_sequence_id].add_record(
+            self.min_shift,
+            start,
+            end,
+            chunk,
+        );
+
+        self.last_record = Some((reference_sequence_id, start));
+        self.max_position = cmp::max(self.max_position, usize::from(end));
+
+        Ok(())
+    }
+
+    /// Adds an unplaced, unmapped record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.add_unplaced_unmapped_record();
+    ///
+    /// let index = indexer.build();
+    /// assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+    /// ```
+    pub fn add_unplaced_unmapped_record(&mut self) {
+        self.unplaced_unmapped_record_count += 1;
+    }
+
+    /// Builds a CSI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// indexer.add_record(
+    ///     0,
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(144), bgzf::VirtualPosition::from(233)),
+    /// )?;
+    ///
+    /// let index = indexer.build();
+    ///
+    /// assert_eq!(index.min_shift(), 14);
+    /// assert_eq!(index.depth(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self) -> Index {
+        let min_shift = self.min_shift;
+
+        let depth = self
+            .depth
+            .unwrap_or_else(|| calculate_depth(min_shift, self.max_position));
+
+        let reference_sequences = self
+            .reference_sequence_builders
+            .into_iter()
+            .map(|builder| builder.build(depth))
+            .collect();
+
+        Index::builder()
+            .set_min_shift(min_shift)
+            .set_depth(depth)
+            .set_aux(self.aux)
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
+            .build()
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_SHIFT)
+    }
+}
+
+fn max_position(min_shift: u8, depth: u8) -> usize {
+    let bits = u32::from(min_shift) + 3 * u32::from(depth);
+    1usize
+        .checked_shl(bits)
+        .map(|n| n - 1)
+        .unwrap_or(usize::MAX)
+}
+
+// Returns the smallest depth (at least 1) whose max position is at least the given position.
+fn calculate_depth(min_shift: u8, position: usize) -> u8 {
+    let mut depth = 1;
+
+    while depth < MAX_DEPTH && max_position(min_shift, depth) < position {
+        depth += 1;
+    }
+
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::BinningIndex;
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(144),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        let mut indexer = Indexer::default();
+        indexer.add_record(1, Position::try_from(13)?, Position::try_from(21)?, chunk)?;
+
+        assert!(matches!(
+            indexer.add_record(1, Position::try_from(8)?, Position::try_from(13)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            indexer.add_record(0, Position::try_from(21)?, Position::try_from(34)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            indexer.add_record(1, Position::try_from(21)?, Position::try_from(13)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::with_depth(4, 2);
+        indexer.add_record(0, Position::try_from(8)?, Position::try_from(1023)?, chunk)?;
+
+        assert!(matches!(
+            indexer.add_record(0, Position::try_from(8)?, Position::try_from(1024)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            0,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(21),
+            ),
+        )?;
+
+        indexer.add_record(
+            2,
+            Position::try_from(1 << 30)?,
+            Position::try_from((1 << 30) + 5)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(21),
+                bgzf::VirtualPosition::from(34),
+            ),
+        )?;
+
+        indexer.add_unplaced_unmapped_record();
+
+        let index = indexer.build();
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 6);
+        assert_eq!(index.reference_sequences().len(), 3);
+        assert!(index.reference_sequences()[1].bins().is_empty());
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let start = Position::try_from(1 << 30)?;
+        let chunks = index.query(2, start..=start)?;
+        assert_eq!(
+            chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(21),
+                bgzf::VirtualPosition::from(34)
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_depth() {
+        assert_eq!(calculate_depth(14, 0), 1);
+        assert_eq!(calculate_depth(14, 1 << 17), 2);
+        assert_eq!(calculate_depth(14, (1 << 29) - 1), 5);
+        assert_eq!(calculate_depth(14, 1 << 29), 6);
+    }
+}
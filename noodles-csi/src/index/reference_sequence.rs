@@ -1,10 +1,13 @@
 //! Coordinate-sorted index (CSI) reference sequence and fields.
 
 pub mod bin;
+mod builder;
 mod metadata;
 
 pub use self::{bin::Bin, metadata::Metadata};
 
+pub(crate) use self::builder::Builder;
+
 use std::{io, num::NonZeroUsize};
 
 use bit_vec::BitVec;
@@ -12,7 +15,7 @@ use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
 
 use super::resolve_interval;
-use crate::binning_index::ReferenceSequenceExt;
+use crate::binning_index::{estimate_record_counts, ReferenceSequenceExt};
 
 /// A CSI reference sequence.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -135,6 +138,97 @@ impl ReferenceSequence {
 
         bgzf::VirtualPosition::default()
     }
+
+    /// Builds a linear index from the leaf bins of the reference sequence.
+    ///
+    /// Each window of the linear index is the size of a leaf bin, i.e., 2^`min_shift` positions,
+    /// and its value is the start position of the first record that overlaps the window. Empty
+    /// windows take the value of the next nonempty window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::index::{reference_sequence::Bin, ReferenceSequence};
+    ///
+    /// const DEPTH: u8 = 5;
+    ///
+    /// let bins = vec![
+    ///     Bin::new(0, bgzf::VirtualPosition::from(8), Vec::new()),
+    ///     Bin::new(4681, bgzf::VirtualPosition::from(13), Vec::new()),
+    ///     Bin::new(4683, bgzf::VirtualPosition::from(21), Vec::new()),
+    /// ];
+    /// let reference_sequence = ReferenceSequence::new(bins, None);
+    ///
+    /// assert_eq!(
+    ///     reference_sequence.linear_index(DEPTH),
+    ///     [
+    ///         bgzf::VirtualPosition::from(13),
+    ///         bgzf::VirtualPosition::from(21),
+    ///         bgzf::VirtualPosition::from(21),
+    ///     ]
+    /// );
+    /// ```
+    pub fn linear_index(&self, depth: u8) -> Vec<bgzf::VirtualPosition> {
+        let first_leaf_id = match depth.checked_sub(1) {
+            Some(d) => Bin::max_id(d),
+            None => 0,
+        };
+        let max_id = Bin::max_id(depth);
+
+        let leaf_bins: Vec<_> = self
+            .bins
+            .iter()
+            .filter(|bin| (first_leaf_id..max_id).contains(&bin.id()))
+            .map(|bin| (bin.id() - first_leaf_id, bin.loffset()))
+            .collect();
+
+        let len = match leaf_bins.iter().map(|(i, _)| i + 1).max() {
+            Some(len) => len,
+            None => return Vec::new(),
+        };
+
+        let mut intervals = vec![None; len];
+
+        for (i, loffset) in leaf_bins {
+            intervals[i] = Some(loffset);
+        }
+
+        let mut next_loffset = bgzf::VirtualPosition::default();
+
+        let mut linear_index: Vec<_> = intervals
+            .into_iter()
+            .rev()
+            .map(|loffset| {
+                if let Some(loffset) = loffset {
+                    next_loffset = loffset;
+                }
+
+                next_loffset
+            })
+            .collect();
+
+        linear_index.reverse();
+
+        linear_index
+    }
+
+    /// Estimates the number of records in each window of the linear index.
+    ///
+    /// See [`crate::binning_index::estimate_record_counts`]. This returns `None` if the reference
+    /// sequence does not have metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::index::ReferenceSequence;
+    /// let reference_sequence = ReferenceSequence::new(Vec::new(), None);
+    /// assert!(reference_sequence.estimate_record_counts(5).is_none());
+    /// ```
+    pub fn estimate_record_counts(&self, depth: u8) -> Option<Vec<u64>> {
+        self.metadata()
+            .map(|metadata| estimate_record_counts(&self.linear_index(depth), metadata))
+    }
 }
 
 impl ReferenceSequenceExt for ReferenceSequence {
@@ -4,7 +4,13 @@ mod chunk;
 
 pub use self::chunk::Chunk;
 
+use std::{io, ops::RangeInclusive};
+
+use bit_vec::BitVec;
 use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::ReferenceSequence;
 
 pub(crate) const METADATA_CHUNK_COUNT: u32 = 2;
 
@@ -41,6 +47,173 @@ impl Bin {
         Self::max_id(depth) + 1
     }
 
+    /// Calculates the ID of the smallest bin that contains the given interval.
+    ///
+    /// This is `reg2bin` in the CSI specification. The interval is 1-based and inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::Bin;
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// assert_eq!(Bin::interval_to_id(4, 2, start, end)?, 9);
+    ///
+    /// let end = Position::try_from(17)?;
+    /// assert_eq!(Bin::interval_to_id(4, 2, start, end)?, 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn interval_to_id(
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+    ) -> io::Result<usize> {
+        validate_interval(min_shift, depth, start, end)?;
+        Ok(super::reg2bin(start, end, min_shift, depth))
+    }
+
+    /// Calculates the IDs of all bins that overlap the given interval.
+    ///
+    /// This is `reg2bins` in the CSI specification. The interval is 1-based and inclusive. The
+    /// returned IDs are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::Bin;
+    ///
+    /// let start = Position::try_from(36)?;
+    /// let end = Position::try_from(67)?;
+    /// assert_eq!(Bin::interval_to_ids(4, 2, start, end)?, [0, 1, 11, 12, 13]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn interval_to_ids(
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+    ) -> io::Result<Vec<usize>> {
+        validate_interval(min_shift, depth, start, end)?;
+
+        let mut bins = BitVec::from_elem(Self::max_id(depth), false);
+        super::reg2bins(start, end, min_shift, depth, &mut bins);
+
+        Ok(bins
+            .iter()
+            .enumerate()
+            .filter(|(_, is_set)| *is_set)
+            .map(|(id, _)| id)
+            .collect())
+    }
+
+    /// Returns the ID of the parent bin.
+    ///
+    /// The root bin (0) has no parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::index::reference_sequence::Bin;
+    /// assert_eq!(Bin::parent_id(0), None);
+    /// assert_eq!(Bin::parent_id(8), Some(0));
+    /// assert_eq!(Bin::parent_id(9), Some(1));
+    /// assert_eq!(Bin::parent_id(4681), Some(585));
+    /// ```
+    pub fn parent_id(id: usize) -> Option<usize> {
+        super::parent_id(id)
+    }
+
+    /// Returns the IDs of the child bins.
+    ///
+    /// Bins in the last level and invalid bin IDs have no children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::index::reference_sequence::Bin;
+    /// assert_eq!(Bin::child_ids(2, 0), Some(1..=8));
+    /// assert_eq!(Bin::child_ids(2, 1), Some(9..=16));
+    /// assert_eq!(Bin::child_ids(2, 9), None);
+    /// assert_eq!(Bin::child_ids(2, 73), None);
+    /// ```
+    pub fn child_ids(depth: u8, id: usize) -> Option<RangeInclusive<usize>> {
+        if id >= Self::max_id(depth) || level(id) >= depth {
+            return None;
+        }
+
+        let first_child_id = id * 8 + 1;
+        Some(first_child_id..=first_child_id + 7)
+    }
+
+    /// Returns the level of the bin.
+    ///
+    /// The root bin (0) is at level 0. IDs that are too large for any level a `usize` can address
+    /// are placed in the last such level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::index::reference_sequence::Bin;
+    /// assert_eq!(Bin::level(0), 0);
+    /// assert_eq!(Bin::level(8), 1);
+    /// assert_eq!(Bin::level(9), 2);
+    /// assert_eq!(Bin::level(4681), 5);
+    /// ```
+    pub fn level(id: usize) -> u8 {
+        level(id)
+    }
+
+    /// Returns the interval covered by the bin.
+    ///
+    /// The interval is 1-based and inclusive. This returns `None` if the bin ID is invalid for the
+    /// given depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::Bin;
+    ///
+    /// assert_eq!(
+    ///     Bin::id_to_interval(4, 2, 0),
+    ///     Some(Position::try_from(1)?..=Position::try_from(1024)?)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Bin::id_to_interval(4, 2, 2),
+    ///     Some(Position::try_from(129)?..=Position::try_from(256)?)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Bin::id_to_interval(4, 2, 10),
+    ///     Some(Position::try_from(17)?..=Position::try_from(32)?)
+    /// );
+    ///
+    /// assert!(Bin::id_to_interval(4, 2, 73).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn id_to_interval(min_shift: u8, depth: u8, id: usize) -> Option<RangeInclusive<Position>> {
+        if id >= Self::max_id(depth) {
+            return None;
+        }
+
+        let l = level(id);
+        let offset = id - first_id(l)?;
+        let shift = usize::from(min_shift) + 3 * usize::from(depth - l);
+
+        let start = offset << shift;
+        let end = (offset + 1) << shift;
+
+        let start = Position::new(start + 1)?;
+        let end = Position::new(end)?;
+
+        Some(start..=end)
+    }
+
     /// Creates a new bin.
     ///
     /// # Examples
@@ -101,8 +274,85 @@ impl Bin {
     }
 }
 
+// The ID of the first bin in level `l`, i.e., (8^l - 1) / 7.
+//
+// This returns `None` if 8^l overflows a `usize`.
+fn first_id(l: u8) -> Option<usize> {
+    let shift = u32::from(l) * 3;
+    1usize.checked_shl(shift).map(|n| (n - 1) / 7)
+}
+
+fn level(id: usize) -> u8 {
+    let mut l = 0;
+
+    while let Some(next_first_id) = first_id(l + 1) {
+        if id < next_first_id {
+            break;
+        }
+
+        l += 1;
+    }
+
+    l
+}
+
+fn validate_interval(min_shift: u8, depth: u8, start: Position, end: Position) -> io::Result<()> {
+    if start > end {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid interval: {}-{}", start, end),
+        ));
+    }
+
+    let max_position = ReferenceSequence::max_position(min_shift, depth)?;
+
+    if end > max_position {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "interval end ({}) is greater than the max position ({})",
+                end, max_position
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 // `CSIv1.pdf` (2020-07-21)
 fn bin_limit(depth: u8) -> i32 {
     assert!(depth <= 10);
     (1 << ((depth + 1) * 3)) / 7
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_id() {
+        assert_eq!(first_id(0), Some(0));
+        assert_eq!(first_id(1), Some(1));
+        assert_eq!(first_id(2), Some(9));
+        assert_eq!(first_id(5), Some(4681));
+        assert_eq!(first_id(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), 0);
+        assert_eq!(level(1), 1);
+        assert_eq!(level(8), 1);
+        assert_eq!(level(9), 2);
+        assert_eq!(level(4681), 5);
+
+        let max_level = (usize::BITS - 1) / 3;
+        assert_eq!(u32::from(level(usize::MAX)), max_level);
+    }
+
+    #[test]
+    fn test_id_to_interval_with_large_id() {
+        assert!(Bin::id_to_interval(14, 5, usize::MAX).is_none());
+        assert!(Bin::child_ids(5, usize::MAX).is_none());
+    }
+}
@@ -0,0 +1,250 @@
+use std::{cmp, collections::HashMap};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{bin::Chunk, Bin, Metadata, ReferenceSequence};
+
+// Bins are keyed by their level above the leaves and their offset in that level. This is
+// independent of the depth, which is only known when the index is built.
+type BinKey = (u8, usize);
+
+#[derive(Debug, Default)]
+pub(crate) struct Builder {
+    bins: HashMap<BinKey, Vec<Chunk>>,
+    intervals: Vec<Option<bgzf::VirtualPosition>>,
+    start_position: bgzf::VirtualPosition,
+    end_position: bgzf::VirtualPosition,
+    mapped_record_count: u64,
+}
+
+impl Builder {
+    pub fn add_record(
+        &mut self,
+        min_shift: u8,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> &mut Self {
+        self.update_bins(min_shift, start, end, chunk);
+        self.update_linear_index(min_shift, start, end, chunk);
+        self.update_metadata(chunk);
+        self
+    }
+
+    pub fn build(self, depth: u8) -> ReferenceSequence {
+        if self.bins.is_empty() {
+            return ReferenceSequence::new(Vec::new(), None);
+        }
+
+        let intervals = fill_intervals(self.intervals);
+
+        let mut bins: Vec<_> = self
+            .bins
+            .into_iter()
+            .map(|((k, offset), chunks)| {
+                let l = depth - k;
+                let id = ((1 << (l * 3)) - 1) / 7 + offset;
+
+                // The offset of the first window in the bin.
+                let i = offset << (k * 3);
+                let loffset = intervals.get(i).copied().unwrap_or_default();
+
+                Bin::new(id, loffset, chunks)
+            })
+            .collect();
+
+        bins.sort_unstable_by_key(|bin| bin.id());
+
+        let metadata = Metadata::new(
+            self.start_position,
+            self.end_position,
+            self.mapped_record_count,
+            0,
+        );
+
+        ReferenceSequence::new(bins, Some(metadata))
+    }
+
+    fn update_bins(&mut self, min_shift: u8, start: Position, end: Position, chunk: Chunk) {
+        let key = region_to_bin_key(min_shift, start, end);
+        let chunks = self.bins.entry(key).or_default();
+
+        if let Some(last_chunk) = chunks.last_mut() {
+            if chunk.start() <= last_chunk.end() {
+                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+                return;
+            }
+        }
+
+        chunks.push(chunk);
+    }
+
+    fn update_linear_index(&mut self, min_shift: u8, start: Position, end: Position, chunk: Chunk) {
+        let linear_index_start_offset = (usize::from(start) - 1) >> min_shift;
+        let linear_index_end_offset = (usize::from(end) - 1) >> min_shift;
+
+        if linear_index_end_offset >= self.intervals.len() {
+            self.intervals
+                .resize(linear_index_end_offset + 1, Default::default());
+        }
+
+        for i in linear_index_start_offset..=linear_index_end_offset {
+            self.intervals[i].get_or_insert(chunk.start());
+        }
+    }
+
+    fn update_metadata(&mut self, chunk: Chunk) {
+        if self.mapped_record_count == 0 {
+            self.start_position = chunk.start();
+        }
+
+        self.mapped_record_count += 1;
+        self.start_position = cmp::min(self.start_position, chunk.start());
+        self.end_position = cmp::max(self.end_position, chunk.end());
+    }
+}
+
+// Returns the level above the leaves and the offset in that level of the smallest bin that
+// contains the given interval.
+fn region_to_bin_key(min_shift: u8, start: Position, end: Position) -> BinKey {
+    // [beg, end], 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end) - 1;
+
+    let mut k = 0;
+    let mut s = min_shift;
+
+    while beg >> s != end >> s {
+        k += 1;
+        s += 3;
+    }
+
+    (k, beg >> s)
+}
+
+// Windows with no records use the offset of the previous window, which is a lower bound.
+fn fill_intervals(intervals: Vec<Option<bgzf::VirtualPosition>>) -> Vec<bgzf::VirtualPosition> {
+    let mut last_position = bgzf::VirtualPosition::default();
+
+    intervals
+        .into_iter()
+        .map(|position| {
+            if let Some(position) = position {
+                last_position = position;
+            }
+
+            last_position
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        let mut builder = Builder::default();
+
+        builder.add_record(
+            MIN_SHIFT,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            Position::try_from(13)?,
+            Position::try_from(21)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(21),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            Position::try_from(100)?,
+            Position::try_from(144)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(34),
+                bgzf::VirtualPosition::from(55),
+            ),
+        );
+
+        let actual = builder.build(DEPTH);
+
+        let expected = ReferenceSequence::new(
+            vec![
+                Bin::new(
+                    0,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(34),
+                        bgzf::VirtualPosition::from(55),
+                    )],
+                ),
+                Bin::new(
+                    1,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(9),
+                        bgzf::VirtualPosition::from(21),
+                    )],
+                ),
+                Bin::new(
+                    9,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(0),
+                        bgzf::VirtualPosition::from(9),
+                    )],
+                ),
+            ],
+            Some(Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(55),
+                3,
+                0,
+            )),
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_no_bins() {
+        let reference_sequence = Builder::default().build(5);
+        assert_eq!(reference_sequence, ReferenceSequence::new(Vec::new(), None));
+    }
+
+    #[test]
+    fn test_region_to_bin_key() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 4;
+
+        let start = Position::try_from(8)?;
+        assert_eq!(region_to_bin_key(MIN_SHIFT, start, start), (0, 0));
+
+        let end = Position::try_from(16)?;
+        assert_eq!(region_to_bin_key(MIN_SHIFT, start, end), (0, 0));
+
+        let end = Position::try_from(17)?;
+        assert_eq!(region_to_bin_key(MIN_SHIFT, start, end), (1, 0));
+
+        let start = Position::try_from(144)?;
+        let end = Position::try_from(233)?;
+        assert_eq!(region_to_bin_key(MIN_SHIFT, start, end), (1, 1));
+
+        Ok(())
+    }
+}
@@ -2,8 +2,11 @@ use noodles_bgzf as bgzf;
 
 /// Index reference sequence metadata.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Metadata {
+    #[cfg_attr(feature = "serde", serde(with = "virtual_position"))]
     start_position: bgzf::VirtualPosition,
+    #[cfg_attr(feature = "serde", serde(with = "virtual_position"))]
     end_position: bgzf::VirtualPosition,
     mapped_record_count: u64,
     unmapped_record_count: u64,
@@ -123,3 +126,54 @@ impl Metadata {
         self.unmapped_record_count
     }
 }
+
+/// (De)serializes a virtual position as its raw value.
+#[cfg(feature = "serde")]
+mod virtual_position {
+    use noodles_bgzf as bgzf;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S>(
+        position: &bgzf::VirtualPosition,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        u64::from(*position).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<bgzf::VirtualPosition, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(bgzf::VirtualPosition::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> serde_json::Result<()> {
+        use super::*;
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(610),
+            bgzf::VirtualPosition::from(1597),
+            55,
+            0,
+        );
+
+        let json = serde_json::to_string(&metadata)?;
+        assert_eq!(
+            json,
+            r#"{"start_position":610,"end_position":1597,"mapped_record_count":55,"unmapped_record_count":0}"#
+        );
+
+        let actual: Metadata = serde_json::from_str(&json)?;
+        assert_eq!(actual, metadata);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Extracts subsequences of regions from an indexed FASTA.
+//!
+//! The input FASTA must have an index in the same directory.
+//!
+//! The result is similar to the output of `samtools faidx --length 80 <src> [<region> ...]`.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    path::PathBuf,
+};
+
+use noodles_core::Region;
+use noodles_fasta::{self as fasta, fai};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().map(PathBuf::from).expect("missing src");
+    let regions = args
+        .map(|s| s.parse())
+        .collect::<Result<Vec<Region>, _>>()?;
+
+    let index = fai::read(src.with_extension("fa.fai"))?;
+    let mut reader = File::open(&src)
+        .map(BufReader::new)
+        .map(|inner| fasta::IndexedReader::new(inner, index))?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = fasta::Writer::new(handle);
+
+    for result in reader.query_regions(&regions, |region| region.to_string()) {
+        let record = result?;
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+//! Async FASTA reader and repository.
+
 pub mod reader;
+pub mod repository;
 
-pub use self::reader::Reader;
+pub use self::{reader::Reader, repository::Repository};
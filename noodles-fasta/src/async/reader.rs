@@ -1,7 +1,18 @@
+//! Async FASTA reader.
+
+use noodles_core::Region;
 use tokio::io::{
     self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncSeek, AsyncSeekExt, SeekFrom,
 };
 
+use crate::{
+    fai,
+    indexed_reader::{compute_offset, read_sequence_line_limit, resolve_interval},
+    reader::{read_sequence_line, trim_newline},
+    record::{Definition, Sequence},
+    Record,
+};
+
 /// An async FASTA reader.
 pub struct Reader<R> {
     inner: R,
@@ -86,47 +97,80 @@ where
     }
 }
 
-async fn read_sequence<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+impl<R> Reader<R>
 where
-    R: AsyncBufRead + Unpin,
+    R: AsyncBufRead + AsyncSeek + Unpin,
 {
-    use memchr::memchr;
+    /// Returns a record of the given region.
+    ///
+    /// The region interval is 1-based and clamped to the bounds of the reference sequence. Only
+    /// the bases covered by the region are read from the underlying stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::io::Cursor;
+    /// use noodles_fasta::{self as fasta, fai, record::{Definition, Sequence}};
+    ///
+    /// let data = b">sq0\nNNNN\n>sq1\nACGT\n>sq2\nNNNN\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+    ///     fai::Record::new(String::from("sq2"), 4, 25, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::AsyncReader::new(Cursor::new(data));
+    ///
+    /// let region = "sq1:2-3".parse()?;
+    /// let record = reader.query(&index, &region).await?;
+    /// assert_eq!(record, fasta::Record::new(
+    ///     Definition::new("sq1:2-3", None),
+    ///     Sequence::from(b"CG".to_vec()),
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query(&mut self, index: &[fai::Record], region: &Region) -> io::Result<Record> {
+        let index_record = index
+            .iter()
+            .find(|record| record.name() == region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence name: {}", region.name()),
+                )
+            })?;
+
+        let (start, end) = resolve_interval(region, index_record.len());
+
+        let mut buf = Vec::new();
+
+        if start < end {
+            let pos = compute_offset(index_record, start)?;
+            self.inner.seek(SeekFrom::Start(pos)).await?;
+            read_sequence_limit(&mut self.inner, end - start, &mut buf).await?;
+        }
 
-    use crate::reader::DEFINITION_PREFIX;
+        let definition = Definition::new(region.to_string(), None);
 
-    const LINE_FEED: u8 = b'\n';
-    const CARRIAGE_RETURN: u8 = b'\r';
+        Ok(Record::new(definition, Sequence::from(buf)))
+    }
+}
 
+async fn read_sequence<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
     let mut n = 0;
 
     loop {
         let reader_buf = reader.fill_buf().await?;
 
-        if reader_buf
-            .first()
-            .map(|&b| b == DEFINITION_PREFIX)
-            .unwrap_or(true)
-        {
-            break;
-        }
-
-        let len = match memchr(LINE_FEED, reader_buf) {
-            Some(i) => {
-                let line = &reader_buf[..i];
-
-                if line.ends_with(&[CARRIAGE_RETURN]) {
-                    let end = line.len() - 1;
-                    buf.extend_from_slice(&line[..end]);
-                } else {
-                    buf.extend_from_slice(line);
-                }
-
-                i + 1
-            }
-            None => {
-                buf.extend(reader_buf);
-                reader_buf.len()
-            }
+        let len = match read_sequence_line(reader_buf, buf) {
+            Some(len) => len,
+            None => break,
         };
 
         reader.consume(len);
@@ -137,24 +181,34 @@ where
     Ok(n)
 }
 
-pub(crate) async fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+// Reads at most `max_bases` bases of a sequence, skipping line breaks.
+async fn read_sequence_limit<R>(reader: &mut R, max_bases: u64, buf: &mut Vec<u8>) -> io::Result<()>
 where
     R: AsyncBufRead + Unpin,
 {
-    const LINE_FEED: char = '\n';
-    const CARRIAGE_RETURN: char = '\r';
+    let max_bases =
+        usize::try_from(max_bases).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    while buf.len() < max_bases {
+        let reader_buf = reader.fill_buf().await?;
 
+        match read_sequence_line_limit(reader_buf, max_bases, buf) {
+            Some(len) => reader.consume(len),
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
     match reader.read_line(buf).await? {
         0 => Ok(0),
         n => {
-            if buf.ends_with(LINE_FEED) {
-                buf.pop();
-
-                if buf.ends_with(CARRIAGE_RETURN) {
-                    buf.pop();
-                }
-            }
-
+            trim_newline(buf);
             Ok(n)
         }
     }
@@ -0,0 +1,165 @@
+//! Async sequence repository and adapters.
+
+mod adapter;
+pub mod adapters;
+
+pub use self::adapter::Adapter;
+
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use tokio::{
+    io,
+    sync::{Mutex, RwLock},
+};
+
+use crate::record::Sequence;
+
+// The adapter and cache are locked separately so that cache reads are not blocked while the
+// adapter fetches a sequence.
+struct AdapterCache {
+    adapter: Mutex<Box<dyn Adapter + Send + Sync>>,
+    cache: RwLock<HashMap<String, Sequence>>,
+}
+
+/// An async caching sequence repository.
+///
+/// This is the async analog of [`crate::Repository`]. Sequences are fetched from the adapter
+/// without blocking the runtime and are cached for the lifetime of the repository.
+pub struct Repository(Arc<AdapterCache>);
+
+impl Repository {
+    /// Creates an async sequence repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, r#async::repository::adapters::Empty};
+    /// let repository = fasta::AsyncRepository::new(Empty::new());
+    /// ```
+    pub fn new<A>(adapter: A) -> Self
+    where
+        A: Adapter + Send + Sync + 'static,
+    {
+        Self(Arc::new(AdapterCache {
+            adapter: Mutex::new(Box::new(adapter)),
+            cache: RwLock::new(HashMap::new()),
+        }))
+    }
+
+    /// Returns the sequence of the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let sq0 = fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// );
+    ///
+    /// let repository = fasta::AsyncRepository::new(vec![sq0.clone()]);
+    ///
+    /// assert_eq!(
+    ///     repository.get("sq0").await.transpose()?,
+    ///     Some(sq0.sequence().clone())
+    /// );
+    /// assert!(repository.get("sq1").await.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, name: &str) -> Option<io::Result<Sequence>> {
+        if let Some(sequence) = self.0.cache.read().await.get(name) {
+            return Some(Ok(sequence.clone()));
+        }
+
+        let result = {
+            let mut adapter = self.0.adapter.lock().await;
+            adapter.get(name).await?
+        };
+
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let sequence = record.sequence().clone();
+
+        self.0
+            .cache
+            .write()
+            .await
+            .entry(name.into())
+            .or_insert_with(|| sequence.clone());
+
+        Some(Ok(sequence))
+    }
+
+    /// Returns the number of cached sequences.
+    pub async fn len(&self) -> usize {
+        self.0.cache.read().await.len()
+    }
+
+    /// Returns whether any sequences are cached.
+    pub async fn is_empty(&self) -> bool {
+        self.0.cache.read().await.is_empty()
+    }
+
+    /// Clears the sequence cache.
+    pub async fn clear(&self) {
+        self.0.cache.write().await.clear();
+    }
+}
+
+impl Clone for Repository {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl fmt::Debug for Repository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.cache.try_read() {
+            Ok(cache) => f.debug_struct("Repository").field("cache", &cache).finish(),
+            Err(_) => f
+                .debug_struct("Repository")
+                .field("cache", &format_args!("<locked>"))
+                .finish(),
+        }
+    }
+}
+
+impl Default for Repository {
+    fn default() -> Self {
+        Self::new(adapters::Empty::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::Definition, Record};
+
+    #[tokio::test]
+    async fn test_get() -> io::Result<()> {
+        let sq0 = Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+        let repository = Repository::new(vec![sq0.clone()]);
+
+        assert_eq!(
+            repository.get("sq0").await.transpose()?,
+            Some(sq0.sequence().clone())
+        );
+        assert_eq!(repository.get("sq1").await.transpose()?, None);
+        assert_eq!(repository.len().await, 1);
+
+        repository.clear().await;
+        assert!(repository.is_empty().await);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use std::{future::Future, pin::Pin};
+
+use tokio::io;
+
+use crate::Record;
+
+/// An async repository adapter.
+pub trait Adapter {
+    /// Returns the record with the given name.
+    fn get<'a>(
+        &'a mut self,
+        name: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Option<io::Result<Record>>> + Send + 'a>>;
+}
@@ -0,0 +1,85 @@
+use std::{future::Future, pin::Pin};
+
+use noodles_core::Region;
+use tokio::io::{self, AsyncBufRead, AsyncSeek};
+
+use crate::{fai, r#async::repository::Adapter, record::Definition, AsyncReader, Record};
+
+/// An async indexed reader adapter.
+pub struct IndexedReader<R> {
+    reader: AsyncReader<R>,
+    index: fai::Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: AsyncBufRead + AsyncSeek + Unpin,
+{
+    /// Creates an async indexed reader adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai, r#async::repository::adapters::IndexedReader};
+    /// let reader = fasta::AsyncReader::new(tokio::io::empty());
+    /// let index = fai::Index::default();
+    /// let adapter = IndexedReader::new(reader, index);
+    /// ```
+    pub fn new(reader: AsyncReader<R>, index: fai::Index) -> Self {
+        Self { reader, index }
+    }
+}
+
+impl<R> Adapter for IndexedReader<R>
+where
+    R: AsyncBufRead + AsyncSeek + Unpin + Send,
+{
+    fn get<'a>(
+        &'a mut self,
+        name: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Option<io::Result<Record>>> + Send + 'a>> {
+        Box::pin(async move {
+            if !self.index.iter().any(|record| record.name() == name) {
+                return None;
+            }
+
+            let region = Region::new(name, ..);
+
+            let record = self.reader.query(&self.index, &region).await.map(|record| {
+                let definition = Definition::new(name, None);
+                Record::new(definition, record.sequence().clone())
+            });
+
+            Some(record)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::record::Sequence;
+
+    #[tokio::test]
+    async fn test_get() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+        ];
+
+        let mut adapter = IndexedReader::new(AsyncReader::new(Cursor::new(&data[..])), index);
+
+        let record = adapter.get("sq1").await.transpose()?;
+        assert_eq!(
+            record.map(|record| (record.name().to_string(), record.sequence().clone())),
+            Some((String::from("sq1"), Sequence::from(b"NNNNNNNNNN".to_vec())))
+        );
+
+        assert!(adapter.get("sq2").await.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use std::{future::Future, pin::Pin};
+
+use tokio::io;
+
+use crate::{r#async::repository::Adapter, Record};
+
+impl Adapter for Vec<Record> {
+    fn get<'a>(
+        &'a mut self,
+        name: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Option<io::Result<Record>>> + Send + 'a>> {
+        Box::pin(async move {
+            self.iter()
+                .find(|record| record.name() == name)
+                .cloned()
+                .map(Ok)
+        })
+    }
+}
@@ -0,0 +1,176 @@
+//! FASTA record sequence digests.
+//!
+//! This computes the MD5 and GA4GH `sha512t24u` digests of sequences. These are the identifiers
+//! used by CRAM (`@SQ` `M5`) and refget, respectively.
+//!
+//! Both digests are calculated from the normalized sequence: all characters outside of the
+//! inclusive range 33 (`!`) to 126 (`~`) are stripped out, and all lowercase characters are
+//! converted to uppercase.
+
+use md5::Md5;
+use sha2::{Digest, Sha512};
+
+const SHA512T24U_LEN: usize = 24;
+
+/// Sequence digests.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Digests {
+    len: u64,
+    md5: [u8; 16],
+    sha512t24u: [u8; SHA512T24U_LEN],
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl Digests {
+    /// Returns the length of the normalized sequence.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the MD5 digest.
+    pub fn md5(&self) -> [u8; 16] {
+        self.md5
+    }
+
+    /// Returns the MD5 digest as a lowercase hexadecimal string.
+    ///
+    /// This is the format used by the `M5` field of a SAM header reference sequence record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Hasher;
+    ///
+    /// let mut hasher = Hasher::new();
+    /// hasher.update(b"ACGT");
+    /// let digests = hasher.finalize();
+    ///
+    /// assert_eq!(digests.md5_hex(), "f1f8f4bf413b16ad135722aa4591043e");
+    /// ```
+    pub fn md5_hex(&self) -> String {
+        self.md5.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Returns the GA4GH `sha512t24u` digest.
+    ///
+    /// This is the base64url encoding of the first 24 bytes of the SHA-512 digest. Note that this
+    /// does not include any namespace prefix, e.g., `SQ.`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Hasher;
+    ///
+    /// let mut hasher = Hasher::new();
+    /// hasher.update(b"ACGT");
+    /// let digests = hasher.finalize();
+    ///
+    /// assert_eq!(digests.sha512t24u(), "aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2");
+    /// ```
+    pub fn sha512t24u(&self) -> String {
+        base64::encode_config(self.sha512t24u, base64::URL_SAFE_NO_PAD)
+    }
+}
+
+/// A streaming sequence hasher.
+///
+/// This incrementally calculates the digests of a sequence without needing to hold the entire
+/// sequence in memory.
+#[derive(Clone, Default)]
+pub struct Hasher {
+    len: u64,
+    md5: Md5,
+    sha512: Sha512,
+    buf: Vec<u8>,
+}
+
+impl Hasher {
+    /// Creates a sequence hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Hasher;
+    /// let hasher = Hasher::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (a part of) a sequence to the digests.
+    ///
+    /// The input is normalized before it is hashed, so it may include, e.g., line breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Hasher;
+    ///
+    /// let mut hasher = Hasher::new();
+    /// hasher.update(b"AC\n");
+    /// hasher.update(b"gt\n");
+    ///
+    /// let digests = hasher.finalize();
+    /// assert_eq!(digests.len(), 4);
+    /// assert_eq!(digests.md5_hex(), "f1f8f4bf413b16ad135722aa4591043e");
+    /// ```
+    pub fn update(&mut self, buf: &[u8]) {
+        self.buf.clear();
+
+        self.buf.extend(
+            buf.iter()
+                .filter(|b| b.is_ascii_graphic())
+                .map(|b| b.to_ascii_uppercase()),
+        );
+
+        self.len += self.buf.len() as u64;
+        self.md5.update(&self.buf);
+        self.sha512.update(&self.buf);
+    }
+
+    /// Returns the digests of the sequence.
+    pub fn finalize(self) -> Digests {
+        let sha512 = self.sha512.finalize();
+
+        let mut sha512t24u = [0; SHA512T24U_LEN];
+        sha512t24u.copy_from_slice(&sha512[..SHA512T24U_LEN]);
+
+        Digests {
+            len: self.len,
+            md5: self.md5.finalize().into(),
+            sha512t24u,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finalize() {
+        let hasher = Hasher::new();
+        let digests = hasher.finalize();
+
+        assert_eq!(digests.len(), 0);
+        assert_eq!(digests.md5_hex(), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(digests.sha512t24u(), "z4PhNX7vuL3xVChQ1m2AB9Yg5AULVxXc");
+    }
+
+    #[test]
+    fn test_update() {
+        let mut hasher = Hasher::new();
+        hasher.update(b"acgt\r\n");
+        let digests = hasher.finalize();
+
+        assert_eq!(digests.len(), 4);
+        assert_eq!(
+            digests.md5(),
+            [
+                0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
+                0x04, 0x3e
+            ]
+        );
+        assert_eq!(digests.sha512t24u(), "aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2");
+    }
+}
@@ -2,6 +2,8 @@ mod field;
 
 use std::{error, fmt, str::FromStr};
 
+use noodles_core::sequence_dictionary;
+
 use self::field::Field;
 
 const FIELD_DELIMITER: char = '\t';
@@ -131,6 +133,28 @@ where
         .and_then(|s| s.parse().map_err(|e| ParseError::InvalidField(field, e)))
 }
 
+impl From<&Record> for sequence_dictionary::Sequence {
+    /// Creates a sequence dictionary reference sequence from a FASTA index record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{sequence_dictionary::Sequence, SequenceDictionary};
+    /// use noodles_fasta::fai;
+    ///
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 8, 4, 8, 9),
+    ///     fai::Record::new(String::from("sq1"), 13, 18, 80, 81),
+    /// ];
+    ///
+    /// let dictionary: SequenceDictionary = index.iter().map(Sequence::from).collect();
+    /// assert_eq!(dictionary.get(1), Some(&Sequence::new("sq1", Some(13))));
+    /// ```
+    fn from(record: &Record) -> Self {
+        Self::new(record.name(), usize::try_from(record.len()).ok())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
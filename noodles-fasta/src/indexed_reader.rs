@@ -0,0 +1,410 @@
+//! Indexed FASTA reader.
+
+mod query_regions;
+
+pub use self::query_regions::QueryRegions;
+
+use std::{
+    borrow::Borrow,
+    io::{self, BufRead, Seek},
+};
+
+use memchr::memchr;
+use noodles_core::Region;
+
+use super::{
+    fai,
+    reader::{DEFINITION_PREFIX, NEWLINE},
+    repository::Adapter,
+    Reader, Record,
+};
+
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// An indexed FASTA reader.
+///
+/// This pairs a FASTA reader with its associated index (FAI) to allow random access to
+/// subsequences without reading whole records.
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: fai::Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    ///
+    /// let reader = fasta::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        Self::from_reader(Reader::new(inner), index)
+    }
+
+    pub(crate) fn from_reader(inner: Reader<R>, index: fai::Index) -> Self {
+        Self { inner, index }
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(&[][..], index);
+    ///
+    /// assert_eq!(reader.index().len(), 1);
+    /// ```
+    pub fn index(&self) -> &fai::Index {
+        &self.index
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let mut reader = fasta::IndexedReader::new(&[][..], Vec::new());
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let reader = fasta::IndexedReader::new(&[][..], Vec::new());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Returns a record of the subsequence of the given region.
+    ///
+    /// This is the same as calling [`crate::Reader::query`] with the associated index. The record
+    /// name is the string representation of the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, fai, record::{Definition, Sequence}};
+    ///
+    /// let data = b">sq0\nNNNN\n>sq1\nACGT\nAC\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 6, 15, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::IndexedReader::new(Cursor::new(data), index);
+    ///
+    /// let region = "sq1:3-5".parse()?;
+    /// let record = reader.query(&region)?;
+    /// assert_eq!(record, fasta::Record::new(
+    ///     Definition::new("sq1:3-5", None),
+    ///     Sequence::from(b"GTA".to_vec()),
+    /// ));
+    ///
+    /// let region = "sq1:5-8".parse()?;
+    /// let record = reader.query(&region)?;
+    /// assert_eq!(record.sequence(), &Sequence::from(b"AC".to_vec()));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Record> {
+        self.inner.query(&self.index, region)
+    }
+
+    /// Returns an iterator over records of the subsequences of the given regions.
+    ///
+    /// This is the equivalent of `samtools faidx <src> [<region> ...]`. Each region is queried
+    /// using [`Self::query`], and the resulting record is named using the given function. Use
+    /// `Region::to_string` to name records by region, e.g., `sq0:2-5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nNNNN\n>sq1\nACGT\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::IndexedReader::new(Cursor::new(data), index);
+    ///
+    /// let regions: Vec<Region> = vec!["sq0:1-2".parse()?, "sq1:2-3".parse()?];
+    ///
+    /// let records: Vec<_> = reader
+    ///     .query_regions(&regions, |region| region.to_string())
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0].name(), "sq0:1-2");
+    /// assert_eq!(records[0].sequence().as_ref(), b"NN");
+    /// assert_eq!(records[1].name(), "sq1:2-3");
+    /// assert_eq!(records[1].sequence().as_ref(), b"CG");
+    ///
+    /// let records: Vec<_> = reader
+    ///     .query_regions(&regions, |region| region.name().into())
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(records[0].name(), "sq0");
+    /// assert_eq!(records[1].name(), "sq1");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<I, F>(
+        &mut self,
+        regions: I,
+        name_fn: F,
+    ) -> QueryRegions<'_, R, I::IntoIter, F>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Region>,
+        F: FnMut(&Region) -> String,
+    {
+        QueryRegions::new(self, regions.into_iter(), name_fn)
+    }
+}
+
+impl<R> Adapter for IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        if !self.index.iter().any(|record| record.name() == name) {
+            return None;
+        }
+
+        let region = Region::new(name, ..);
+        Some(self.query(&region))
+    }
+}
+
+// Resolves a 1-based region interval to a 0-based half-open range clamped to `[0, len)`.
+pub(crate) fn resolve_interval(region: &Region, len: u64) -> (u64, u64) {
+    let interval = region.interval();
+
+    let end = interval
+        .end()
+        .map(|position| usize::from(position) as u64)
+        .unwrap_or(len)
+        .min(len);
+
+    let start = interval
+        .start()
+        .map(|position| usize::from(position) as u64 - 1)
+        .unwrap_or(0)
+        .min(end);
+
+    (start, end)
+}
+
+// Computes the stream position of the given 0-based position of a reference sequence.
+pub(crate) fn compute_offset(index_record: &fai::Record, pos: u64) -> io::Result<u64> {
+    let line_bases = index_record.line_bases();
+
+    if line_bases == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid index record: line bases is 0",
+        ));
+    }
+
+    let line = pos / line_bases;
+    let line_pos = pos % line_bases;
+
+    Ok(index_record.offset() + line * index_record.line_width() + line_pos)
+}
+
+// Reads at most `max_bases` bases of a sequence, skipping line breaks.
+pub(crate) fn read_sequence_limit<R>(
+    reader: &mut R,
+    max_bases: u64,
+    buf: &mut Vec<u8>,
+) -> io::Result<()>
+where
+    R: BufRead,
+{
+    let max_bases =
+        usize::try_from(max_bases).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    while buf.len() < max_bases {
+        let reader_buf = reader.fill_buf()?;
+
+        match read_sequence_line_limit(reader_buf, max_bases, buf) {
+            Some(len) => reader.consume(len),
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
+// Appends the bases of the first line in `src` to `buf` until `buf` holds `max_bases` bases.
+//
+// This returns the number of bytes to consume from `src` or `None` if `src` is at the end of the
+// sequence.
+pub(crate) fn read_sequence_line_limit(
+    src: &[u8],
+    max_bases: usize,
+    buf: &mut Vec<u8>,
+) -> Option<usize> {
+    if src.is_empty() || src[0] == DEFINITION_PREFIX {
+        return None;
+    }
+
+    let (line, line_len) = match memchr(NEWLINE, src) {
+        Some(i) => {
+            let line = &src[..i];
+
+            match line.split_last() {
+                Some((&CARRIAGE_RETURN, rest)) => (rest, i + 1),
+                _ => (line, i + 1),
+            }
+        }
+        None => (src, src.len()),
+    };
+
+    let remaining = max_bases.saturating_sub(buf.len());
+
+    if line.len() > remaining {
+        buf.extend(&line[..remaining]);
+        Some(remaining)
+    } else {
+        buf.extend(line);
+        Some(line_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    fn build_reader() -> IndexedReader<Cursor<&'static [u8]>> {
+        let data = b">sq0\nNNNN\n>sq1\nACGT\nGC\n>sq2\r\nTTTT\r\nAAAA\r\n";
+
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 6, 15, 4, 5),
+            fai::Record::new(String::from("sq2"), 8, 29, 4, 6),
+        ];
+
+        IndexedReader::new(Cursor::new(&data[..]), index)
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            reader: &mut IndexedReader<Cursor<&'static [u8]>>,
+            region: &str,
+            expected: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+            let record = reader.query(&region)?;
+            assert_eq!(record.sequence(), &Sequence::from(expected.to_vec()));
+            Ok(())
+        }
+
+        let mut reader = build_reader();
+
+        t(&mut reader, "sq0", b"NNNN")?;
+        t(&mut reader, "sq1", b"ACGTGC")?;
+        t(&mut reader, "sq1:2", b"CGTGC")?;
+        t(&mut reader, "sq1:3-5", b"GTG")?;
+        t(&mut reader, "sq1:5-5", b"G")?;
+        t(&mut reader, "sq1:4-13", b"TGC")?;
+        t(&mut reader, "sq1:8-13", b"")?;
+        t(&mut reader, "sq2:3-6", b"TTAA")?;
+
+        let region = "sq3".parse()?;
+        assert!(matches!(
+            reader.query(&region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let mut reader = build_reader();
+
+        assert_eq!(
+            reader.get("sq1").transpose()?,
+            Some(Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"ACGTGC".to_vec())
+            ))
+        );
+
+        assert!(reader.get("sq3").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_offset() -> io::Result<()> {
+        let index_record = fai::Record::new(String::from("sq0"), 10, 5, 4, 5);
+
+        assert_eq!(compute_offset(&index_record, 0)?, 5);
+        assert_eq!(compute_offset(&index_record, 3)?, 8);
+        assert_eq!(compute_offset(&index_record, 4)?, 10);
+        assert_eq!(compute_offset(&index_record, 9)?, 16);
+
+        let index_record = fai::Record::new(String::from("sq0"), 10, 5, 0, 0);
+        assert!(compute_offset(&index_record, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sequence_line_limit() {
+        let mut buf = Vec::new();
+        assert_eq!(
+            read_sequence_line_limit(b"ACGT\r\nNNNN\n", 8, &mut buf),
+            Some(6)
+        );
+        assert_eq!(buf, b"ACGT");
+
+        let mut buf = Vec::new();
+        assert_eq!(
+            read_sequence_line_limit(b"ACGT\nNNNN\n", 2, &mut buf),
+            Some(2)
+        );
+        assert_eq!(buf, b"AC");
+
+        let mut buf = Vec::new();
+        assert!(read_sequence_line_limit(b">sq1\n", 8, &mut buf).is_none());
+        assert!(read_sequence_line_limit(b"", 8, &mut buf).is_none());
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    borrow::Borrow,
+    io::{self, BufRead, Seek},
+};
+
+use noodles_core::Region;
+
+use super::IndexedReader;
+use crate::{record::Definition, Record};
+
+/// An iterator over records of subsequences of regions.
+///
+/// This is created by calling [`IndexedReader::query_regions`].
+pub struct QueryRegions<'a, R, I, F> {
+    reader: &'a mut IndexedReader<R>,
+    regions: I,
+    name_fn: F,
+}
+
+impl<'a, R, I, F> QueryRegions<'a, R, I, F>
+where
+    R: BufRead + Seek,
+    I: Iterator,
+    I::Item: Borrow<Region>,
+    F: FnMut(&Region) -> String,
+{
+    pub(super) fn new(reader: &'a mut IndexedReader<R>, regions: I, name_fn: F) -> Self {
+        Self {
+            reader,
+            regions,
+            name_fn,
+        }
+    }
+}
+
+impl<'a, R, I, F> Iterator for QueryRegions<'a, R, I, F>
+where
+    R: BufRead + Seek,
+    I: Iterator,
+    I::Item: Borrow<Region>,
+    F: FnMut(&Region) -> String,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let region = self.regions.next()?;
+        let region = region.borrow();
+
+        let record = self.reader.query(region).map(|record| {
+            let name = (self.name_fn)(region);
+            let definition = Definition::new(name, None);
+            Record::new(definition, record.sequence().clone())
+        });
+
+        Some(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::fai;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b">sq0\nACGT\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let regions: Vec<Region> = vec!["sq0:2-3".parse()?, "sq1".parse()?, "sq0".parse()?];
+        let mut records = reader.query_regions(regions, |region| format!("{}/1", region));
+
+        let record = records.next().transpose()?.expect("missing record");
+        assert_eq!(record.name(), "sq0:2-3/1");
+        assert_eq!(record.sequence().as_ref(), b"CG");
+
+        assert!(matches!(records.next(), Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let record = records.next().transpose()?.expect("missing record");
+        assert_eq!(record.name(), "sq0/1");
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
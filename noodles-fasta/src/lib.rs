@@ -53,19 +53,26 @@
 //! ```
 
 #[cfg(feature = "async")]
-pub(crate) mod r#async;
+pub mod r#async;
 
+pub mod digest;
 pub mod fai;
+pub mod indexed_reader;
 mod indexer;
+pub mod mask;
 pub mod reader;
 pub mod record;
 pub mod repository;
+pub mod two_bit;
 pub mod writer;
 
-pub use self::{reader::Reader, record::Record, repository::Repository, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, reader::Reader, record::Record, repository::Repository,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
-pub use self::r#async::Reader as AsyncReader;
+pub use self::r#async::{Reader as AsyncReader, Repository as AsyncRepository};
 
 use std::{
     fs::File,
@@ -0,0 +1,146 @@
+//! FASTA record sequence masking.
+
+use std::{collections::HashMap, io};
+
+use noodles_core::region::Interval;
+
+use super::Record;
+
+/// A sequence masking transform.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mask {
+    /// Replace soft-masked (lowercase) bases with `N`.
+    Hard,
+    /// Soft-mask (lowercase) bases in the given 1-based intervals of each reference sequence.
+    Soft(HashMap<String, Vec<Interval>>),
+    /// Remove soft-masking, i.e., uppercase all bases.
+    Unmask,
+}
+
+impl Mask {
+    /// Returns a copy of the record with the mask applied to its sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, mask::Mask, record::{Definition, Sequence}};
+    ///
+    /// let record = fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACgt".to_vec()),
+    /// );
+    ///
+    /// let actual = Mask::Hard.apply(&record);
+    /// assert_eq!(actual.sequence(), &Sequence::from(b"ACNN".to_vec()));
+    /// ```
+    pub fn apply(&self, record: &Record) -> Record {
+        let sequence = record.sequence();
+
+        let masked_sequence = match self {
+            Self::Hard => sequence.hard_mask(),
+            Self::Soft(intervals) => match intervals.get(record.name()) {
+                Some(intervals) => sequence.soft_mask(intervals.iter().copied()),
+                None => sequence.clone(),
+            },
+            Self::Unmask => sequence.unmask(),
+        };
+
+        Record::new(record.definition().clone(), masked_sequence)
+    }
+}
+
+/// An iterator adapter that masks records.
+///
+/// This wraps a record iterator, e.g., [`crate::Reader::records`], which allows masking records
+/// while streaming them from a reader to a writer.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, mask::{Mask, Masked}};
+///
+/// let data = b">sq0\nACgt\n>sq1\nnnNN\n";
+/// let mut reader = fasta::Reader::new(&data[..]);
+/// let mut writer = fasta::Writer::new(Vec::new());
+///
+/// for result in Masked::new(reader.records(), Mask::Unmask) {
+///     let record = result?;
+///     writer.write_record(&record)?;
+/// }
+///
+/// assert_eq!(writer.get_ref(), b">sq0\nACGT\n>sq1\nNNNN\n");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Masked<I> {
+    records: I,
+    mask: Mask,
+}
+
+impl<I> Masked<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    /// Creates an iterator adapter that masks records.
+    pub fn new(records: I, mask: Mask) -> Self {
+        Self { records, mask }
+    }
+}
+
+impl<I> Iterator for Masked<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.records
+            .next()
+            .map(|result| result.map(|record| self.mask.apply(&record)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    #[test]
+    fn test_apply() -> Result<(), noodles_core::position::TryFromIntError> {
+        let sq0 = Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACgtACGT".to_vec()),
+        );
+        let sq1 = Record::new(
+            Definition::new("sq1", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+
+        assert_eq!(
+            Mask::Hard.apply(&sq0).sequence(),
+            &Sequence::from(b"ACNNACGT".to_vec())
+        );
+
+        assert_eq!(
+            Mask::Unmask.apply(&sq0).sequence(),
+            &Sequence::from(b"ACGTACGT".to_vec())
+        );
+
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(6)?;
+        let intervals = [(String::from("sq0"), vec![Interval::from(start..=end)])]
+            .into_iter()
+            .collect();
+        let mask = Mask::Soft(intervals);
+
+        assert_eq!(
+            mask.apply(&sq0).sequence(),
+            &Sequence::from(b"ACgtacGT".to_vec())
+        );
+        assert_eq!(mask.apply(&sq1), sq1);
+
+        Ok(())
+    }
+}
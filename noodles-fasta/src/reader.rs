@@ -1,19 +1,21 @@
 //! FASTA reader and iterators.
 
+mod digests;
 mod records;
 
-pub use self::records::Records;
+pub use self::{digests::RecordDigests, records::Records};
 
-use std::{
-    io::{self, BufRead, Read, Seek, SeekFrom},
-    ops::Range,
-};
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
 use memchr::memchr;
 use noodles_bgzf as bgzf;
-use noodles_core::{region::Interval, Region};
+use noodles_core::Region;
 
-use super::{fai, Record};
+use super::{
+    fai,
+    indexed_reader::{compute_offset, read_sequence_limit, resolve_interval},
+    Record,
+};
 
 pub(crate) const DEFINITION_PREFIX: u8 = b'>';
 pub(crate) const NEWLINE: u8 = b'\n';
@@ -43,6 +45,32 @@ where
         Self { inner }
     }
 
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let mut reader = fasta::Reader::new(&[][..]);
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let reader = fasta::Reader::new(&[][..]);
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
     /// Reads a raw definition line.
     ///
     /// The given buffer will not include the trailing newline. It can subsequently be parsed as a
@@ -134,6 +162,39 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Returns an iterator over record definitions and sequence digests starting from the current
+    /// stream position.
+    ///
+    /// Unlike [`Self::records`], sequences are hashed while they are read and are never fully
+    /// held in memory. See [`crate::digest`] for the calculated digests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{self as fasta, record::Definition};
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+    /// let mut reader = fasta::Reader::new(&data[..]);
+    ///
+    /// let mut digests = reader.digests();
+    ///
+    /// let (definition, sq0_digests) = digests.next().transpose()?.unwrap();
+    /// assert_eq!(definition, Definition::new("sq0", None));
+    /// assert_eq!(sq0_digests.md5_hex(), "f1f8f4bf413b16ad135722aa4591043e");
+    /// assert_eq!(sq0_digests.sha512t24u(), "aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2");
+    ///
+    /// let (definition, sq1_digests) = digests.next().transpose()?.unwrap();
+    /// assert_eq!(definition, Definition::new("sq1", None));
+    /// assert_eq!(sq1_digests.len(), 10);
+    ///
+    /// assert!(digests.next().is_none());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn digests(&mut self) -> RecordDigests<'_, R> {
+        RecordDigests::new(self)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -168,6 +229,9 @@ where
 {
     /// Returns a record of the given region.
     ///
+    /// The region interval is 1-based and clamped to the bounds of the reference sequence. Only
+    /// the bases covered by the region are read from the underlying stream.
+    ///
     /// # Examples
     ///
     /// ```
@@ -205,18 +269,19 @@ where
         let i = resolve_region(index, region)?;
         let index_record = &index[i];
 
-        let pos = index_record.offset();
-        self.seek(SeekFrom::Start(pos))?;
+        let (start, end) = resolve_interval(region, index_record.len());
 
-        let definition = Definition::new(region.to_string(), None);
+        let mut buf = Vec::new();
 
-        let mut raw_sequence = Vec::new();
-        self.read_sequence(&mut raw_sequence)?;
+        if start < end {
+            let pos = compute_offset(index_record, start)?;
+            self.inner.seek(SeekFrom::Start(pos))?;
+            read_sequence_limit(&mut self.inner, end - start, &mut buf)?;
+        }
 
-        let range = interval_to_slice_range(region.interval(), raw_sequence.len());
-        let sequence = Sequence::from(raw_sequence[range].to_vec());
+        let definition = Definition::new(region.to_string(), None);
 
-        Ok(Record::new(definition, sequence))
+        Ok(Record::new(definition, Sequence::from(buf)))
     }
 }
 
@@ -289,20 +354,24 @@ where
     match reader.read_line(buf) {
         Ok(0) => Ok(0),
         Ok(n) => {
-            if buf.ends_with(LINE_FEED) {
-                buf.pop();
-
-                if buf.ends_with(CARRIAGE_RETURN) {
-                    buf.pop();
-                }
-            }
-
+            trim_newline(buf);
             Ok(n)
         }
         Err(e) => Err(e),
     }
 }
 
+// Removes a trailing newline ('\n' or '\r\n'), if present.
+pub(crate) fn trim_newline(buf: &mut String) {
+    if buf.ends_with(LINE_FEED) {
+        buf.pop();
+
+        if buf.ends_with(CARRIAGE_RETURN) {
+            buf.pop();
+        }
+    }
+}
+
 fn read_sequence<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
@@ -312,27 +381,9 @@ where
     loop {
         let reader_buf = reader.fill_buf()?;
 
-        if reader_buf.is_empty() || reader_buf[0] == DEFINITION_PREFIX {
-            break;
-        }
-
-        let len = match memchr(NEWLINE, reader_buf) {
-            Some(i) => {
-                let line = &reader_buf[..i];
-
-                if line.ends_with(&[CARRIAGE_RETURN as u8]) {
-                    let end = line.len() - 1;
-                    buf.extend(&line[..end]);
-                } else {
-                    buf.extend(line);
-                }
-
-                i + 1
-            }
-            None => {
-                buf.extend(reader_buf);
-                reader_buf.len()
-            }
+        let len = match read_sequence_line(reader_buf, buf) {
+            Some(len) => len,
+            None => break,
         };
 
         reader.consume(len);
@@ -343,6 +394,37 @@ where
     Ok(bytes_read)
 }
 
+// Appends the bases of the first line in `src` to `buf`.
+//
+// This returns the number of bytes to consume from `src` or `None` if `src` is at the end of the
+// sequence.
+pub(crate) fn read_sequence_line(src: &[u8], buf: &mut Vec<u8>) -> Option<usize> {
+    if src.is_empty() || src[0] == DEFINITION_PREFIX {
+        return None;
+    }
+
+    let len = match memchr(NEWLINE, src) {
+        Some(i) => {
+            let line = &src[..i];
+
+            if line.ends_with(&[CARRIAGE_RETURN as u8]) {
+                let end = line.len() - 1;
+                buf.extend(&line[..end]);
+            } else {
+                buf.extend(line);
+            }
+
+            i + 1
+        }
+        None => {
+            buf.extend(src);
+            src.len()
+        }
+    };
+
+    Some(len)
+}
+
 fn resolve_region(index: &[fai::Record], region: &Region) -> io::Result<usize> {
     index
         .iter()
@@ -355,23 +437,6 @@ fn resolve_region(index: &[fai::Record], region: &Region) -> io::Result<usize> {
         })
 }
 
-// Shifts a 1-based interval to a 0-based range for slicing.
-fn interval_to_slice_range<I>(interval: I, len: usize) -> Range<usize>
-where
-    I: Into<Interval>,
-{
-    let interval = interval.into();
-
-    let start = interval
-        .start()
-        .map(|position| usize::from(position) - 1)
-        .unwrap_or(usize::MIN);
-
-    let end = interval.end().map(usize::from).unwrap_or(len);
-
-    start..end
-}
-
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
@@ -471,21 +536,4 @@ mod tests {
 
         Ok(())
     }
-
-    #[test]
-    fn test_interval_to_slice_range() -> Result<(), noodles_core::position::TryFromIntError> {
-        use noodles_core::Position;
-
-        const LENGTH: usize = 21;
-
-        let start = Position::try_from(8)?;
-        let end = Position::try_from(13)?;
-
-        assert_eq!(interval_to_slice_range(start..=end, LENGTH), 7..13);
-        assert_eq!(interval_to_slice_range(start.., LENGTH), 7..21);
-        assert_eq!(interval_to_slice_range(..=end, LENGTH), 0..13);
-        assert_eq!(interval_to_slice_range(.., LENGTH), 0..21);
-
-        Ok(())
-    }
 }
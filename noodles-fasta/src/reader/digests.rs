@@ -0,0 +1,134 @@
+use std::io::{self, BufRead};
+
+use memchr::memchr;
+
+use super::{read_line, Reader, DEFINITION_PREFIX, NEWLINE};
+use crate::{
+    digest::{Digests, Hasher},
+    record::Definition,
+};
+
+/// An iterator over record definitions and sequence digests of a FASTA reader.
+///
+/// Sequences are hashed as they are read and are never fully held in memory.
+///
+/// This is created by calling [`Reader::digests`].
+pub struct RecordDigests<'a, R> {
+    inner: &'a mut Reader<R>,
+    line_buf: String,
+}
+
+impl<'a, R> RecordDigests<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: &'a mut Reader<R>) -> Self {
+        Self {
+            inner,
+            line_buf: String::new(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for RecordDigests<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<(Definition, Digests)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line_buf.clear();
+
+        match read_line(&mut self.inner.inner, &mut self.line_buf) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(e)),
+        }
+
+        let definition = match self.line_buf.parse() {
+            Ok(definition) => definition,
+            Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+        };
+
+        match hash_sequence(&mut self.inner.inner) {
+            Ok(digests) => Some(Ok((definition, digests))),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn hash_sequence<R>(reader: &mut R) -> io::Result<Digests>
+where
+    R: BufRead,
+{
+    let mut hasher = Hasher::new();
+    let mut is_at_line_start = true;
+
+    loop {
+        let reader_buf = reader.fill_buf()?;
+
+        if reader_buf.is_empty() || (is_at_line_start && reader_buf[0] == DEFINITION_PREFIX) {
+            break;
+        }
+
+        let len = match memchr(NEWLINE, reader_buf) {
+            Some(i) => {
+                is_at_line_start = true;
+                i + 1
+            }
+            None => {
+                is_at_line_start = false;
+                reader_buf.len()
+            }
+        };
+
+        hasher.update(&reader_buf[..len]);
+        reader.consume(len);
+    }
+
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let data = b">sq0\nAC\ngt\n>sq1 LN:4\r\nACGT\r\n";
+        let mut reader = Reader::new(&data[..]);
+        let mut digests = reader.digests();
+
+        let (definition, sq0_digests) = digests.next().transpose()?.expect("missing record");
+        assert_eq!(definition, Definition::new("sq0", None));
+        assert_eq!(sq0_digests.len(), 4);
+        assert_eq!(sq0_digests.md5_hex(), "f1f8f4bf413b16ad135722aa4591043e");
+
+        let (definition, sq1_digests) = digests.next().transpose()?.expect("missing record");
+        assert_eq!(
+            definition,
+            Definition::new("sq1", Some(String::from("LN:4")))
+        );
+        assert_eq!(sq1_digests, sq0_digests);
+
+        assert!(digests.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_sequence_with_small_buffer() -> io::Result<()> {
+        let data = b"AC\nGT\n>sq1\n";
+        let mut reader = io::BufReader::with_capacity(2, &data[..]);
+
+        let digests = hash_sequence(&mut reader)?;
+        assert_eq!(digests.len(), 4);
+        assert_eq!(digests.md5_hex(), "f1f8f4bf413b16ad135722aa4591043e");
+
+        let mut buf = String::new();
+        read_line(&mut reader, &mut buf)?;
+        assert_eq!(buf, ">sq1");
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 //! FASTA record definition and components.
 
+pub mod description;
+
+pub use self::description::Description;
+
 use std::{error, fmt, str::FromStr};
 
 const PREFIX: char = '>';
@@ -76,6 +80,25 @@ impl Definition {
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    /// Parses the description, if it is set, into tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Definition;
+    ///
+    /// let definition = Definition::new("sq0", None);
+    /// assert!(definition.parse_description().is_none());
+    ///
+    /// let definition = Definition::new("sq0", Some(String::from("dna LN=13")));
+    /// let description = definition.parse_description().unwrap();
+    /// assert_eq!(description.get("LN"), Some("13"));
+    /// assert_eq!(description.words().collect::<Vec<_>>(), ["dna"]);
+    /// ```
+    pub fn parse_description(&self) -> Option<Description> {
+        self.description().map(Description::from)
+    }
 }
 
 impl fmt::Display for Definition {
@@ -0,0 +1,239 @@
+//! FASTA record definition description.
+
+use std::fmt;
+
+const KEY_VALUE_DELIMITER: char = '=';
+const ID_DELIMITER: char = '|';
+
+/// A FASTA record definition description token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Token {
+    /// A `key=value` pair.
+    Pair(String, String),
+    /// Any other whitespace-separated word.
+    Word(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pair(key, value) => write!(f, "{}{}{}", key, KEY_VALUE_DELIMITER, value),
+            Self::Word(word) => f.write_str(word),
+        }
+    }
+}
+
+/// A parsed FASTA record definition description.
+///
+/// A description is split into whitespace-separated tokens. A token of the form `key=value` is
+/// a pair; any other token is a word.
+///
+/// The raw description is kept, and it is written as is when formatted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Description {
+    raw: String,
+    tokens: Vec<Token>,
+}
+
+impl Description {
+    /// Returns the raw description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::definition::Description;
+    /// let description = Description::from("dna:chromosome  LN=13");
+    /// assert_eq!(description.as_str(), "dna:chromosome  LN=13");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the list of tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::definition::{description::Token, Description};
+    ///
+    /// let description = Description::from("dna:chromosome LN=13");
+    ///
+    /// assert_eq!(description.tokens(), [
+    ///     Token::Word(String::from("dna:chromosome")),
+    ///     Token::Pair(String::from("LN"), String::from("13")),
+    /// ]);
+    /// ```
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Returns the value of the first pair with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::definition::Description;
+    ///
+    /// let description = Description::from("Cytochrome c OX=9606 GN=CYCS");
+    ///
+    /// assert_eq!(description.get("OX"), Some("9606"));
+    /// assert_eq!(description.get("GN"), Some("CYCS"));
+    /// assert!(description.get("PE").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tokens.iter().find_map(|token| match token {
+            Token::Pair(k, v) if k == key => Some(v.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the words, i.e., tokens that are not pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::definition::Description;
+    /// let description = Description::from("dna:chromosome chromosome:GRCh38:1:1:13:1 REF");
+    /// assert_eq!(
+    ///     description.words().collect::<Vec<_>>(),
+    ///     ["dna:chromosome", "chromosome:GRCh38:1:1:13:1", "REF"],
+    /// );
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &str> + '_ {
+        self.tokens.iter().filter_map(|token| match token {
+            Token::Word(word) => Some(word.as_str()),
+            Token::Pair(..) => None,
+        })
+    }
+
+    /// Returns the identifier of the given database in a pipe-delimited ID.
+    ///
+    /// A pipe-delimited ID is a word of database and identifier fields separated by `|`, e.g.,
+    /// NCBI's `gi|5524211|gb|AAD44166.1|` or UniProt's `sp|P99999|CYC_HUMAN`. The identifier is
+    /// the field following the first field equal to the database.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::definition::Description;
+    ///
+    /// let description = Description::from("gi|5524211|gb|AAD44166.1| cytochrome b");
+    ///
+    /// assert_eq!(description.id("gi"), Some("5524211"));
+    /// assert_eq!(description.id("gb"), Some("AAD44166.1"));
+    /// assert!(description.id("ref").is_none());
+    /// ```
+    pub fn id(&self, database: &str) -> Option<&str> {
+        self.words()
+            .filter(|word| word.contains(ID_DELIMITER))
+            .find_map(|word| {
+                let mut fields = word.split(ID_DELIMITER);
+                fields.find(|&field| field == database)?;
+                fields.next().filter(|id| !id.is_empty())
+            })
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl From<&str> for Description {
+    fn from(s: &str) -> Self {
+        let tokens = s
+            .split_ascii_whitespace()
+            .map(|raw_token| match split_pair(raw_token) {
+                Some((key, value)) => Token::Pair(key.into(), value.into()),
+                None => Token::Word(raw_token.into()),
+            })
+            .collect();
+
+        Self {
+            raw: s.into(),
+            tokens,
+        }
+    }
+}
+
+fn split_pair(s: &str) -> Option<(&str, &str)> {
+    let i = s.find(KEY_VALUE_DELIMITER)?;
+    let (key, value) = (&s[..i], &s[i + 1..]);
+
+    if key.is_empty() {
+        None
+    } else {
+        Some((key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let description = Description::from("sq0\tOS=Homo  sapiens OX=9606 ");
+        assert_eq!(description.to_string(), "sq0\tOS=Homo  sapiens OX=9606 ");
+    }
+
+    #[test]
+    fn test_id() {
+        let description = Description::from("gi|5524211|gb|AAD44166.1| cytochrome b");
+        assert_eq!(description.id("gi"), Some("5524211"));
+        assert_eq!(description.id("gb"), Some("AAD44166.1"));
+        assert!(description.id("AAD44166.1").is_none());
+        assert!(description.id("cytochrome").is_none());
+
+        let description = Description::from("ref|NC_000001.11| sp|P99999|CYC_HUMAN");
+        assert_eq!(description.id("ref"), Some("NC_000001.11"));
+        assert_eq!(description.id("sp"), Some("P99999"));
+
+        let description = Description::from("LN=13");
+        assert!(description.id("LN").is_none());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Description::from(""), Description::default());
+
+        let description = Description::from("LN:13  md5=d7eba311421bbc9d3ada44709dd61534");
+        assert_eq!(
+            description.tokens(),
+            [
+                Token::Word(String::from("LN:13")),
+                Token::Pair(
+                    String::from("md5"),
+                    String::from("d7eba311421bbc9d3ada44709dd61534")
+                ),
+            ]
+        );
+
+        let description = Description::from("Protein OS=Homo sapiens OX=9606 GN= =x");
+        assert_eq!(
+            description.tokens(),
+            [
+                Token::Word(String::from("Protein")),
+                Token::Pair(String::from("OS"), String::from("Homo")),
+                Token::Word(String::from("sapiens")),
+                Token::Pair(String::from("OX"), String::from("9606")),
+                Token::Pair(String::from("GN"), String::new()),
+                Token::Word(String::from("=x")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for s in [
+            "dna:chromosome chromosome:GRCh38:1:1:248956422:1 REF",
+            "Cytochrome c OS=Homo sapiens OX=9606 GN=CYCS PE=1 SV=2",
+            "gi|5524211|gb|AAD44166.1| cytochrome b",
+            "LN:13  md5=d7eba311421bbc9d3ada44709dd61534",
+            " a\tb=c ",
+        ] {
+            assert_eq!(Description::from(s).to_string(), s);
+        }
+    }
+}
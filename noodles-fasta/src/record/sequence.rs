@@ -1,8 +1,9 @@
 //! FASTA record sequence.
 
 pub mod complement;
+pub mod contigs;
 
-pub use self::complement::Complement;
+pub use self::{complement::Complement, contigs::Contigs};
 
 use std::ops::Index;
 
@@ -136,6 +137,179 @@ impl Sequence {
     pub fn complement(&self) -> Complement<'_> {
         Complement::new(self.0.iter())
     }
+
+    /// Returns the reverse complement of the sequence.
+    ///
+    /// This is a convenience method for collecting the reverse of [`Self::complement`] as a
+    /// [`Sequence`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"AACGTn".to_vec());
+    /// let actual = sequence.reverse_complement()?;
+    /// let expected = Sequence::from(b"nACGTT".to_vec());
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, noodles_fasta::record::sequence::complement::ComplementError>(())
+    /// ```
+    pub fn reverse_complement(&self) -> Result<Self, complement::ComplementError> {
+        self.complement().rev().collect()
+    }
+
+    /// Returns the GC content of the sequence.
+    ///
+    /// The GC content is the fraction of `G` and `C` bases out of all `A`, `C`, `G`, and `T`
+    /// bases. Bases are case-insensitive, and all other bases (e.g., `N`) are ignored.
+    ///
+    /// This returns `None` if the sequence has no `A`, `C`, `G`, or `T` bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACGgNNNN".to_vec());
+    /// assert_eq!(sequence.gc_content(), Some(0.75));
+    ///
+    /// let sequence = Sequence::from(b"NNNN".to_vec());
+    /// assert!(sequence.gc_content().is_none());
+    /// ```
+    pub fn gc_content(&self) -> Option<f64> {
+        let mut gc_count = 0;
+        let mut base_count = 0;
+
+        for &b in self.0.iter() {
+            match b.to_ascii_uppercase() {
+                b'G' | b'C' => {
+                    gc_count += 1;
+                    base_count += 1;
+                }
+                b'A' | b'T' => base_count += 1,
+                _ => {}
+            }
+        }
+
+        if base_count > 0 {
+            Some(gc_count as f64 / base_count as f64)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the contigs of the sequence.
+    ///
+    /// The sequence is split at runs of unknown bases (`N` or `n`) of at least `min_gap_len`
+    /// bases. Each contig is returned with its 1-based start position in the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"NNACNGTNNNCA".to_vec());
+    /// let mut contigs = sequence.contigs(2);
+    ///
+    /// assert_eq!(
+    ///     contigs.next(),
+    ///     Some((Position::try_from(3)?, Sequence::from(b"ACNGT".to_vec())))
+    /// );
+    /// assert_eq!(
+    ///     contigs.next(),
+    ///     Some((Position::try_from(11)?, Sequence::from(b"CA".to_vec())))
+    /// );
+    /// assert!(contigs.next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contigs(&self, min_gap_len: usize) -> Contigs<'_> {
+        Contigs::new(self, min_gap_len)
+    }
+
+    /// Returns a hard-masked copy of the sequence.
+    ///
+    /// Soft-masked (lowercase) bases are replaced with `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACgtNn".to_vec());
+    /// assert_eq!(sequence.hard_mask(), Sequence::from(b"ACNNNN".to_vec()));
+    /// ```
+    pub fn hard_mask(&self) -> Self {
+        self.0
+            .iter()
+            .map(|&b| if b.is_ascii_lowercase() { b'N' } else { b })
+            .collect()
+    }
+
+    /// Returns a copy of the sequence with bases in the given intervals soft-masked.
+    ///
+    /// Bases in the given 1-based intervals are lowercased. Intervals are clamped to the bounds of
+    /// the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACGTACGT".to_vec());
+    ///
+    /// let start = Position::try_from(2)?;
+    /// let end = Position::try_from(3)?;
+    /// let intervals = [
+    ///     Interval::from(start..=end),
+    ///     Interval::from(Position::try_from(7)?..),
+    /// ];
+    /// let actual = sequence.soft_mask(intervals);
+    ///
+    /// assert_eq!(actual, Sequence::from(b"AcgTACgt".to_vec()));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn soft_mask<I, J>(&self, intervals: I) -> Self
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<Interval>,
+    {
+        let mut buf = self.0.to_vec();
+
+        for interval in intervals {
+            let interval = interval.into();
+
+            let end = interval
+                .end()
+                .map(usize::from)
+                .unwrap_or(buf.len())
+                .min(buf.len());
+
+            let start = interval
+                .start()
+                .map(|position| usize::from(position) - 1)
+                .unwrap_or(usize::MIN)
+                .min(end);
+
+            buf[start..end].make_ascii_lowercase();
+        }
+
+        Self::from(buf)
+    }
+
+    /// Returns an unmasked copy of the sequence.
+    ///
+    /// All bases are uppercased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACgtNn".to_vec());
+    /// assert_eq!(sequence.unmask(), Sequence::from(b"ACGTNN".to_vec()));
+    /// ```
+    pub fn unmask(&self) -> Self {
+        Self::from(self.0.to_ascii_uppercase())
+    }
 }
 
 impl AsRef<[u8]> for Sequence {
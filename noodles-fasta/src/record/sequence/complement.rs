@@ -44,6 +44,12 @@ impl<'a> ExactSizeIterator for Complement<'a> {}
 impl<'a> FusedIterator for Complement<'a> {}
 
 fn complement(b: u8) -> Result<u8, ComplementError> {
+    if b.is_ascii_lowercase() {
+        return complement(b.to_ascii_uppercase())
+            .map(|c| c.to_ascii_lowercase())
+            .map_err(|_| ComplementError(b));
+    }
+
     match b {
         b'A' => Ok(b'T'),
         b'C' => Ok(b'G'),
@@ -97,5 +103,10 @@ mod tests {
         assert_eq!(complement(b'V'), Ok(b'B'));
         assert_eq!(complement(b'N'), Ok(b'N'));
         assert_eq!(complement(b'X'), Err(ComplementError(b'X')));
+
+        assert_eq!(complement(b'a'), Ok(b't'));
+        assert_eq!(complement(b'c'), Ok(b'g'));
+        assert_eq!(complement(b'n'), Ok(b'n'));
+        assert_eq!(complement(b'x'), Err(ComplementError(b'x')));
     }
 }
@@ -0,0 +1,127 @@
+//! FASTA record sequence contigs.
+
+use std::iter::FusedIterator;
+
+use noodles_core::Position;
+
+use super::Sequence;
+
+/// An iterator over the contigs of a sequence.
+///
+/// A contig is a subsequence delimited by runs of unknown bases (`N` or `n`), i.e., gaps. Runs
+/// shorter than the minimum gap length are considered part of a contig.
+///
+/// This is created by calling [`Sequence::contigs`].
+pub struct Contigs<'a> {
+    sequence: &'a Sequence,
+    min_gap_len: usize,
+    pos: usize,
+}
+
+impl<'a> Contigs<'a> {
+    pub(super) fn new(sequence: &'a Sequence, min_gap_len: usize) -> Self {
+        Self {
+            sequence,
+            min_gap_len: min_gap_len.max(1),
+            pos: 0,
+        }
+    }
+
+    fn build_contig(&self, start: usize, end: usize) -> (Position, Sequence) {
+        // `start + 1` is never 0.
+        let position = Position::new(start + 1).unwrap();
+        let sequence = Sequence::from(self.sequence.0.slice(start..end));
+        (position, sequence)
+    }
+}
+
+impl<'a> Iterator for Contigs<'a> {
+    type Item = (Position, Sequence);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = self.sequence.as_ref();
+
+        let mut start = self.pos;
+        let mut i = start;
+
+        while i < buf.len() {
+            if !is_unknown_base(buf[i]) {
+                i += 1;
+                continue;
+            }
+
+            let gap_end = buf[i..]
+                .iter()
+                .position(|&b| !is_unknown_base(b))
+                .map(|n| i + n)
+                .unwrap_or(buf.len());
+
+            if gap_end - i < self.min_gap_len {
+                i = gap_end;
+                continue;
+            }
+
+            if start < i {
+                self.pos = gap_end;
+                return Some(self.build_contig(start, i));
+            }
+
+            start = gap_end;
+            i = gap_end;
+        }
+
+        self.pos = buf.len();
+
+        if start < buf.len() {
+            Some(self.build_contig(start, buf.len()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> FusedIterator for Contigs<'a> {}
+
+fn is_unknown_base(b: u8) -> bool {
+    matches!(b, b'N' | b'n')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), noodles_core::position::TryFromIntError> {
+        fn t(
+            data: &[u8],
+            min_gap_len: usize,
+            expected: &[(usize, &[u8])],
+        ) -> Result<(), noodles_core::position::TryFromIntError> {
+            let sequence = Sequence::from(data.to_vec());
+
+            let actual: Vec<_> = Contigs::new(&sequence, min_gap_len).collect();
+
+            let expected = expected
+                .iter()
+                .map(|&(start, buf)| {
+                    Position::try_from(start).map(|start| (start, Sequence::from(buf.to_vec())))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        t(b"", 1, &[])?;
+        t(b"NNNN", 1, &[])?;
+        t(b"ACGT", 1, &[(1, b"ACGT")])?;
+        t(b"ACNNGT", 1, &[(1, b"AC"), (5, b"GT")])?;
+        t(b"NNACnGTNN", 1, &[(3, b"AC"), (6, b"GT")])?;
+        t(b"ACNGTNNNCA", 2, &[(1, b"ACNGT"), (9, b"CA")])?;
+        t(b"NACNGTN", 2, &[(1, b"NACNGTN")])?;
+        t(b"ACNNGT", 0, &[(1, b"AC"), (5, b"GT")])?;
+
+        Ok(())
+    }
+}
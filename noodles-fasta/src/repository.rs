@@ -8,28 +8,140 @@ pub use self::adapter::Adapter;
 use std::{
     collections::HashMap,
     fmt, io,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{self, AtomicU64},
+        Arc, RwLock,
+    },
 };
 
 use super::record::Sequence;
 
+#[derive(Debug)]
+struct Entry {
+    sequence: Sequence,
+    last_access: AtomicU64,
+}
+
 struct AdapterCache {
     adapter: Box<dyn Adapter>,
-    cache: HashMap<String, Sequence>,
+    cache: HashMap<String, Entry>,
+    max_size: Option<usize>,
+    size: usize,
+    clock: AtomicU64,
+}
+
+impl AdapterCache {
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, atomic::Ordering::Relaxed)
+    }
+
+    fn insert(&mut self, name: &str, sequence: Sequence) {
+        if self.cache.contains_key(name) {
+            return;
+        }
+
+        let len = sequence.len();
+
+        if let Some(max_size) = self.max_size {
+            if len > max_size {
+                return;
+            }
+
+            while self.size + len > max_size {
+                if !self.evict() {
+                    break;
+                }
+            }
+        }
+
+        let last_access = AtomicU64::new(self.tick());
+
+        self.cache.insert(
+            name.into(),
+            Entry {
+                sequence,
+                last_access,
+            },
+        );
+
+        self.size += len;
+    }
+
+    // Removes the least recently used sequence.
+    fn evict(&mut self) -> bool {
+        let name = self
+            .cache
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_access.load(atomic::Ordering::Relaxed))
+            .map(|(name, _)| name.clone());
+
+        match name.and_then(|name| self.cache.remove(&name)) {
+            Some(entry) => {
+                self.size -= entry.sequence.len();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.cache.clear();
+        self.size = 0;
+    }
 }
 
 /// A caching sequence repository.
+///
+/// By default, every sequence returned by the adapter is cached for the lifetime of the
+/// repository. Use [`Self::with_max_cache_size`] to bound the size of the cache.
 pub struct Repository(Arc<RwLock<AdapterCache>>);
 
 impl Repository {
     /// Creates a sequence repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, repository::adapters::Empty};
+    /// let repository = fasta::Repository::new(Empty::new());
+    /// ```
     pub fn new<A>(adapter: A) -> Self
     where
         A: Adapter + 'static,
     {
+        Self::build(adapter, None)
+    }
+
+    /// Creates a sequence repository with a bounded cache.
+    ///
+    /// The max cache size is the total number of bases that are held in the cache. When adding a
+    /// sequence would exceed this limit, the least recently used sequences are evicted. A
+    /// sequence larger than the max cache size is never cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, repository::adapters::Empty};
+    /// let repository = fasta::Repository::with_max_cache_size(Empty::new(), 1 << 30);
+    /// ```
+    pub fn with_max_cache_size<A>(adapter: A, max_cache_size: usize) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::build(adapter, Some(max_cache_size))
+    }
+
+    fn build<A>(adapter: A, max_size: Option<usize>) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        #[allow(clippy::arc_with_non_send_sync)]
         Self(Arc::new(RwLock::new(AdapterCache {
             adapter: Box::new(adapter),
             cache: HashMap::new(),
+            max_size,
+            size: 0,
+            clock: AtomicU64::new(0),
         })))
     }
 
@@ -38,8 +150,12 @@ impl Repository {
         {
             let lock = self.0.read().unwrap();
 
-            if let Some(sequence) = lock.cache.get(name) {
-                return Some(Ok(sequence.clone()));
+            if let Some(entry) = lock.cache.get(name) {
+                entry
+                    .last_access
+                    .store(lock.tick(), atomic::Ordering::Relaxed);
+
+                return Some(Ok(entry.sequence.clone()));
             }
         }
 
@@ -50,9 +166,7 @@ impl Repository {
             Err(e) => return Some(Err(e)),
         };
 
-        lock.cache
-            .entry(name.into())
-            .or_insert_with(|| record.sequence().clone());
+        lock.insert(name, record.sequence().clone());
 
         Some(Ok(record.sequence().clone()))
     }
@@ -67,9 +181,31 @@ impl Repository {
         self.0.read().unwrap().cache.is_empty()
     }
 
+    /// Returns the max cache size, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, repository::adapters::Empty};
+    ///
+    /// let repository = fasta::Repository::default();
+    /// assert!(repository.max_cache_size().is_none());
+    ///
+    /// let repository = fasta::Repository::with_max_cache_size(Empty::new(), 8);
+    /// assert_eq!(repository.max_cache_size(), Some(8));
+    /// ```
+    pub fn max_cache_size(&self) -> Option<usize> {
+        self.0.read().unwrap().max_size
+    }
+
+    /// Returns the total number of cached bases.
+    pub fn cache_size(&self) -> usize {
+        self.0.read().unwrap().size
+    }
+
     /// Clears the sequence cache.
     pub fn clear(&self) {
-        self.0.write().unwrap().cache.clear();
+        self.0.write().unwrap().clear();
     }
 }
 
@@ -81,8 +217,17 @@ impl Clone for Repository {
 
 impl fmt::Debug for Repository {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lock = self.0.read().unwrap();
+
+        let cache: HashMap<_, _> = lock
+            .cache
+            .iter()
+            .map(|(name, entry)| (name, &entry.sequence))
+            .collect();
+
         f.debug_struct("Repository")
-            .field("cache", &self.0.read().unwrap().cache)
+            .field("cache", &cache)
+            .field("max_cache_size", &lock.max_size)
             .finish()
     }
 }
@@ -117,4 +262,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_max_cache_size() -> io::Result<()> {
+        let records = vec![
+            Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGT".to_vec()),
+            ),
+            Record::new(Definition::new("sq1", None), Sequence::from(b"NN".to_vec())),
+            Record::new(
+                Definition::new("sq2", None),
+                Sequence::from(b"TTTT".to_vec()),
+            ),
+            Record::new(
+                Definition::new("sq3", None),
+                Sequence::from(b"ACGTACGTA".to_vec()),
+            ),
+        ];
+
+        let repository = Repository::with_max_cache_size(records, 8);
+
+        repository.get("sq0").transpose()?;
+        repository.get("sq1").transpose()?;
+        assert_eq!(repository.len(), 2);
+        assert_eq!(repository.cache_size(), 6);
+
+        // sq0 is now more recently used than sq1.
+        repository.get("sq0").transpose()?;
+
+        repository.get("sq2").transpose()?;
+        assert_eq!(repository.len(), 2);
+        assert_eq!(repository.cache_size(), 8);
+
+        let lock = repository.0.read().unwrap();
+        assert!(lock.cache.contains_key("sq0"));
+        assert!(!lock.cache.contains_key("sq1"));
+        assert!(lock.cache.contains_key("sq2"));
+        drop(lock);
+
+        assert_eq!(
+            repository.get("sq3").transpose()?,
+            Some(Sequence::from(b"ACGTACGTA".to_vec()))
+        );
+        assert_eq!(repository.len(), 2);
+        assert_eq!(repository.cache_size(), 8);
+
+        repository.clear();
+        assert!(repository.is_empty());
+        assert_eq!(repository.cache_size(), 0);
+
+        Ok(())
+    }
 }
@@ -1,7 +1,12 @@
 //! Sequence repository adapters.
 
+mod chain;
 mod empty;
 mod indexed_reader;
 mod records;
 
-pub use self::{empty::Empty, indexed_reader::IndexedReader};
+pub use self::{
+    chain::{Chain, Sources},
+    empty::Empty,
+    indexed_reader::IndexedReader,
+};
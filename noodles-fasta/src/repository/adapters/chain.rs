@@ -0,0 +1,199 @@
+use std::{
+    collections::HashMap,
+    io,
+    sync::{Arc, RwLock},
+};
+
+use crate::{repository::Adapter, Record};
+
+/// A chained adapter.
+///
+/// This adapter tries a list of named adapters in order and returns the first record found, e.g.,
+/// a local FASTA, then an on-disk cache, then a remote service. The name of the adapter that
+/// served each sequence is recorded in [`Sources`].
+///
+/// If an adapter fails, the next adapter is tried. When no adapter returns the record, the first
+/// error is returned, if any.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{
+///     self as fasta,
+///     record::{Definition, Sequence},
+///     repository::adapters::{Chain, Empty},
+/// };
+///
+/// let sq0 = fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec()));
+///
+/// let adapter = Chain::new()
+///     .add_adapter("local", Empty::new())
+///     .add_adapter("fallback", vec![sq0]);
+///
+/// let sources = adapter.sources();
+/// let repository = fasta::Repository::new(adapter);
+///
+/// assert!(repository.get("sq0").transpose()?.is_some());
+/// assert_eq!(sources.get("sq0"), Some(String::from("fallback")));
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Default)]
+pub struct Chain {
+    adapters: Vec<(String, Box<dyn Adapter>)>,
+    sources: Sources,
+}
+
+impl Chain {
+    /// Creates an empty chained adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::Chain;
+    /// let adapter = Chain::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a named adapter to the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::{Chain, Empty};
+    /// let adapter = Chain::new().add_adapter("local", Empty::new());
+    /// ```
+    pub fn add_adapter<N, A>(mut self, name: N, adapter: A) -> Self
+    where
+        N: Into<String>,
+        A: Adapter + 'static,
+    {
+        self.adapters.push((name.into(), Box::new(adapter)));
+        self
+    }
+
+    /// Returns the names of the adapters in the chain, in the order they are tried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::{Chain, Empty};
+    /// let adapter = Chain::new().add_adapter("local", Empty::new());
+    /// assert_eq!(adapter.adapter_names().collect::<Vec<_>>(), ["local"]);
+    /// ```
+    pub fn adapter_names(&self) -> impl Iterator<Item = &str> {
+        self.adapters.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns a handle to the sources of the served sequences.
+    ///
+    /// The handle is shared with the adapter, so it can be used after the adapter is moved into a
+    /// repository.
+    pub fn sources(&self) -> Sources {
+        self.sources.clone()
+    }
+}
+
+impl Adapter for Chain {
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        let mut first_error = None;
+
+        for (adapter_name, adapter) in &mut self.adapters {
+            match adapter.get(name) {
+                Some(Ok(record)) => {
+                    self.sources.insert(name, adapter_name);
+                    return Some(Ok(record));
+                }
+                Some(Err(e)) => {
+                    first_error.get_or_insert(e);
+                }
+                None => {}
+            }
+        }
+
+        first_error.map(Err)
+    }
+}
+
+/// The sources of sequences served by a chained adapter.
+///
+/// This maps sequence names to the names of the adapters that served them.
+#[derive(Clone, Debug, Default)]
+pub struct Sources(Arc<RwLock<HashMap<String, String>>>);
+
+impl Sources {
+    /// Returns the name of the adapter that served the sequence with the given name.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.0.read().unwrap().get(name).cloned()
+    }
+
+    /// Returns a copy of all recorded sources.
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.0.read().unwrap().clone()
+    }
+
+    fn insert(&self, name: &str, adapter_name: &str) {
+        self.0
+            .write()
+            .unwrap()
+            .insert(name.into(), adapter_name.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    struct Failing;
+
+    impl Adapter for Failing {
+        fn get(&mut self, _: &str) -> Option<io::Result<Record>> {
+            Some(Err(io::Error::new(io::ErrorKind::Other, "unavailable")))
+        }
+    }
+
+    fn build_record(name: &str, sequence: &[u8]) -> Record {
+        Record::new(
+            Definition::new(name, None),
+            Sequence::from(sequence.to_vec()),
+        )
+    }
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let sq0 = build_record("sq0", b"ACGT");
+        let sq1 = build_record("sq1", b"NNNN");
+        let sq0_alt = build_record("sq0", b"TTTT");
+
+        let mut adapter = Chain::new()
+            .add_adapter("local", vec![sq0.clone()])
+            .add_adapter("remote", Failing)
+            .add_adapter("cache", vec![sq0_alt, sq1.clone()]);
+
+        let sources = adapter.sources();
+
+        assert_eq!(adapter.get("sq0").transpose()?, Some(sq0));
+        assert_eq!(adapter.get("sq1").transpose()?, Some(sq1));
+
+        assert!(matches!(
+            adapter.get("sq2"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::Other
+        ));
+
+        assert_eq!(sources.get("sq0"), Some(String::from("local")));
+        assert_eq!(sources.get("sq1"), Some(String::from("cache")));
+        assert!(sources.get("sq2").is_none());
+        assert_eq!(sources.to_map().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_no_adapters() {
+        let mut adapter = Chain::new();
+        assert!(adapter.get("sq0").is_none());
+    }
+}
@@ -13,8 +13,7 @@ use crate::{fai, repository::Adapter, Reader, Record};
 
 /// An indexed reader adapter.
 pub struct IndexedReader<R> {
-    reader: Reader<R>,
-    index: fai::Index,
+    inner: crate::IndexedReader<R>,
 }
 
 impl IndexedReader<BufReader<File>> {
@@ -47,7 +46,9 @@ where
     /// let adapter = IndexedReader::new(reader, index);
     /// ```
     pub fn new(reader: Reader<R>, index: fai::Index) -> Self {
-        Self { reader, index }
+        Self {
+            inner: crate::IndexedReader::from_reader(reader, index),
+        }
     }
 }
 
@@ -57,6 +58,6 @@ where
 {
     fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
         let region = Region::new(name, ..);
-        Some(self.reader.query(&self.index, &region))
+        Some(self.inner.query(&region))
     }
 }
@@ -0,0 +1,15 @@
+//! UCSC 2bit format reader and writer.
+//!
+//! The 2bit format is a binary format that packs nucleotides into 2 bits each. Runs of unknown
+//! bases (`N`) and soft-masked (lowercase) bases are stored as blocks alongside each sequence.
+//! The format includes an index, which allows random access by sequence name and range.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
+
+pub(crate) const MAGIC_NUMBER: u32 = 0x1a412743;
+
+// T, C, A, G
+pub(crate) const BASES: [u8; 4] = *b"TCAG";
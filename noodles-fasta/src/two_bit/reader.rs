@@ -0,0 +1,383 @@
+use std::{
+    cmp,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use noodles_core::Region;
+
+use super::{BASES, MAGIC_NUMBER};
+use crate::{
+    indexed_reader::resolve_interval,
+    record::{Definition, Sequence},
+    repository::Adapter,
+    Record,
+};
+
+// The block count is read from the input, so it is not trusted to preallocate the block list.
+const MAX_PREALLOCATED_BLOCK_COUNT: usize = 1 << 16;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+struct IndexRecord {
+    name: String,
+    offset: u64,
+}
+
+/// A 2bit reader.
+///
+/// The header and index are read when the reader is created. Sequences are then read on demand
+/// using the index.
+pub struct Reader<R> {
+    inner: R,
+    byte_order: ByteOrder,
+    index: Vec<IndexRecord>,
+}
+
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a 2bit reader.
+    ///
+    /// This reads the header and index from the start of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}, two_bit};
+    ///
+    /// let mut writer = two_bit::Writer::new(Vec::new());
+    /// writer.write_records(&[fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )])?;
+    ///
+    /// let reader = two_bit::Reader::new(Cursor::new(writer.into_inner()))?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(0))?;
+
+        let mut buf = [0; 4];
+        inner.read_exact(&mut buf)?;
+
+        let byte_order = if u32::from_le_bytes(buf) == MAGIC_NUMBER {
+            ByteOrder::LittleEndian
+        } else if u32::from_be_bytes(buf) == MAGIC_NUMBER {
+            ByteOrder::BigEndian
+        } else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid 2bit header",
+            ));
+        };
+
+        let version = read_u32(&mut inner, byte_order)?;
+
+        if version > 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid 2bit version: {}", version),
+            ));
+        }
+
+        let sequence_count = read_u32(&mut inner, byte_order)?;
+        let _reserved = read_u32(&mut inner, byte_order)?;
+
+        let mut index = Vec::new();
+
+        for _ in 0..sequence_count {
+            let name_len = inner.read_u8().map(usize::from)?;
+            let mut buf = vec![0; name_len];
+            inner.read_exact(&mut buf)?;
+
+            let name = String::from_utf8(buf)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let offset = if version == 0 {
+                read_u32(&mut inner, byte_order).map(u64::from)?
+            } else {
+                read_u64(&mut inner, byte_order)?
+            };
+
+            index.push(IndexRecord { name, offset });
+        }
+
+        Ok(Self {
+            inner,
+            byte_order,
+            index,
+        })
+    }
+
+    /// Returns an iterator over the sequence names in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}, two_bit};
+    ///
+    /// let mut writer = two_bit::Writer::new(Vec::new());
+    /// writer.write_records(&[
+    ///     fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec())),
+    ///     fasta::Record::new(Definition::new("sq1", None), Sequence::from(b"NNNN".to_vec())),
+    /// ])?;
+    ///
+    /// let reader = two_bit::Reader::new(Cursor::new(writer.into_inner()))?;
+    /// assert_eq!(reader.names().collect::<Vec<_>>(), ["sq0", "sq1"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.index.iter().map(|record| record.name.as_str())
+    }
+
+    /// Returns a record of the subsequence of the given region.
+    ///
+    /// The region interval is 1-based and clamped to the bounds of the sequence. Unknown bases
+    /// are returned as `N`, and soft-masked bases are returned as lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}, two_bit};
+    ///
+    /// let mut writer = two_bit::Writer::new(Vec::new());
+    /// writer.write_records(&[fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACgtNNAC".to_vec()),
+    /// )])?;
+    ///
+    /// let mut reader = two_bit::Reader::new(Cursor::new(writer.into_inner()))?;
+    ///
+    /// let region = "sq0:2-6".parse()?;
+    /// let record = reader.query(&region)?;
+    ///
+    /// assert_eq!(record, fasta::Record::new(
+    ///     Definition::new("sq0:2-6", None),
+    ///     Sequence::from(b"CgtNN".to_vec()),
+    /// ));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Record> {
+        let offset = self
+            .index
+            .iter()
+            .find(|record| record.name == region.name())
+            .map(|record| record.offset)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence name: {}", region.name()),
+                )
+            })?;
+
+        let byte_order = self.byte_order;
+
+        self.inner.seek(SeekFrom::Start(offset))?;
+
+        let dna_size = read_u32(&mut self.inner, byte_order).map(u64::from)?;
+        let n_blocks = read_blocks(&mut self.inner, byte_order)?;
+        let mask_blocks = read_blocks(&mut self.inner, byte_order)?;
+        let _reserved = read_u32(&mut self.inner, byte_order)?;
+
+        let (start, end) = resolve_interval(region, dna_size);
+
+        let mut buf = Vec::new();
+
+        if start < end {
+            let packed_start = start / 4;
+            let packed_end = (end + 3) / 4;
+
+            self.inner.seek(SeekFrom::Current(packed_start as i64))?;
+
+            let mut packed_buf = vec![0; (packed_end - packed_start) as usize];
+            self.inner.read_exact(&mut packed_buf)?;
+
+            buf.extend((start..end).map(|i| {
+                let b = packed_buf[(i / 4 - packed_start) as usize];
+                let shift = 6 - 2 * (i % 4);
+                BASES[usize::from((b >> shift) & 0x03)]
+            }));
+
+            for (block_start, block_end) in n_blocks {
+                for b in &mut buf[intersect(start, end, block_start, block_end)] {
+                    *b = b'N';
+                }
+            }
+
+            for (block_start, block_end) in mask_blocks {
+                buf[intersect(start, end, block_start, block_end)].make_ascii_lowercase();
+            }
+        }
+
+        let definition = Definition::new(region.to_string(), None);
+
+        Ok(Record::new(definition, Sequence::from(buf)))
+    }
+}
+
+impl<R> Adapter for Reader<R>
+where
+    R: Read + Seek,
+{
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        if !self.index.iter().any(|record| record.name == name) {
+            return None;
+        }
+
+        let region = Region::new(name, ..);
+
+        let record = self.query(&region).map(|record| {
+            let definition = Definition::new(name, None);
+            Record::new(definition, record.sequence().clone())
+        });
+
+        Some(record)
+    }
+}
+
+fn read_u32<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<u32>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_u32::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_u32::<BigEndian>(),
+    }
+}
+
+fn read_u64<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<u64>
+where
+    R: Read,
+{
+    match byte_order {
+        ByteOrder::LittleEndian => reader.read_u64::<LittleEndian>(),
+        ByteOrder::BigEndian => reader.read_u64::<BigEndian>(),
+    }
+}
+
+// Reads a list of blocks as 0-based half-open intervals.
+fn read_blocks<R>(reader: &mut R, byte_order: ByteOrder) -> io::Result<Vec<(u64, u64)>>
+where
+    R: Read,
+{
+    let count = read_u32(reader, byte_order).and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let mut starts = Vec::with_capacity(cmp::min(count, MAX_PREALLOCATED_BLOCK_COUNT));
+
+    for _ in 0..count {
+        let start = read_u32(reader, byte_order).map(u64::from)?;
+        starts.push(start);
+    }
+
+    let mut blocks = Vec::with_capacity(starts.len());
+
+    for start in starts {
+        let len = read_u32(reader, byte_order).map(u64::from)?;
+        blocks.push((start, start + len));
+    }
+
+    Ok(blocks)
+}
+
+// Returns the intersection of a block with the query range, relative to the query start.
+fn intersect(start: u64, end: u64, block_start: u64, block_end: u64) -> std::ops::Range<usize> {
+    let s = block_start.max(start);
+    let e = block_end.min(end);
+
+    if s < e {
+        ((s - start) as usize)..((e - start) as usize)
+    } else {
+        0..0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_new_with_big_endian_data() -> io::Result<()> {
+        let data = [
+            0x1a, 0x41, 0x27, 0x43, // signature
+            0x00, 0x00, 0x00, 0x00, // version = 0
+            0x00, 0x00, 0x00, 0x01, // sequence count = 1
+            0x00, 0x00, 0x00, 0x00, // reserved
+            0x03, b's', b'q', b'0', // name = "sq0"
+            0x00, 0x00, 0x00, 0x18, // offset = 24
+            0x00, 0x00, 0x00, 0x06, // DNA size = 6
+            0x00, 0x00, 0x00, 0x00, // N block count = 0
+            0x00, 0x00, 0x00, 0x01, // mask block count = 1
+            0x00, 0x00, 0x00, 0x04, // mask block starts[0] = 4
+            0x00, 0x00, 0x00, 0x02, // mask block sizes[0] = 2
+            0x00, 0x00, 0x00, 0x00, // reserved
+            0x1b, 0x90, // packed DNA = TCAGAC
+        ];
+
+        let mut reader = Reader::new(Cursor::new(&data[..]))?;
+        assert_eq!(reader.byte_order, ByteOrder::BigEndian);
+
+        let record = reader.query(&Region::new("sq0", ..))?;
+        assert_eq!(record.sequence(), &Sequence::from(b"TCAGac".to_vec()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_invalid_signature() {
+        let data = [0x00; 16];
+        assert!(matches!(
+            Reader::new(Cursor::new(&data[..])),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_blocks() -> io::Result<()> {
+        let data = [
+            0x02, 0x00, 0x00, 0x00, // count = 2
+            0x00, 0x00, 0x00, 0x00, // starts[0] = 0
+            0x08, 0x00, 0x00, 0x00, // starts[1] = 8
+            0x02, 0x00, 0x00, 0x00, // sizes[0] = 2
+            0x03, 0x00, 0x00, 0x00, // sizes[1] = 3
+        ];
+        let mut reader = &data[..];
+        assert_eq!(
+            read_blocks(&mut reader, ByteOrder::LittleEndian)?,
+            [(0, 2), (8, 11)]
+        );
+
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // count = 4294967295
+            0x00, 0x00, 0x00, 0x00, // starts[0] = 0
+        ];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_blocks(&mut reader, ByteOrder::LittleEndian),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() {
+        assert_eq!(intersect(2, 8, 0, 4), 0..2);
+        assert_eq!(intersect(2, 8, 4, 6), 2..4);
+        assert_eq!(intersect(2, 8, 6, 10), 4..6);
+        assert_eq!(intersect(2, 8, 8, 10), 0..0);
+        assert_eq!(intersect(2, 8, 0, 2), 0..0);
+    }
+}
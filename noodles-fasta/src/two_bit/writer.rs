@@ -0,0 +1,319 @@
+use std::io::{self, Write};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::MAGIC_NUMBER;
+use crate::Record;
+
+const HEADER_SIZE: u64 = 16;
+
+/// A 2bit writer.
+///
+/// Because the index is written before the sequences, all records are written at once. Bases that
+/// are not `A`, `C`, `G`, or `T` (case-insensitive) are stored as unknown (`N`), and lowercase bases
+/// are stored as soft-masked.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a 2bit writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::two_bit;
+    /// let writer = two_bit::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::two_bit;
+    /// let writer = two_bit::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::two_bit;
+    /// let writer = two_bit::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes a 2bit file with the given records.
+    ///
+    /// This writes the header, index, and all sequences. A version 1 file (64-bit offsets) is only
+    /// written if a sequence offset does not fit in 32 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}, two_bit};
+    ///
+    /// let mut writer = two_bit::Writer::new(Vec::new());
+    ///
+    /// writer.write_records(&[fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )])?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_records(&mut self, records: &[Record]) -> io::Result<()> {
+        let sequence_count = u32::try_from(records.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let encoded_records = records
+            .iter()
+            .map(|record| encode_sequence(record.sequence().as_ref()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let names = records
+            .iter()
+            .map(|record| {
+                let name = record.name().as_bytes();
+
+                u8::try_from(name.len())
+                    .map(|_| name)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let version = if needs_64_bit_offsets(&names, &encoded_records, 4) {
+            1
+        } else {
+            0
+        };
+
+        let offset_size = if version == 0 { 4 } else { 8 };
+
+        self.inner.write_u32::<LittleEndian>(MAGIC_NUMBER)?;
+        self.inner.write_u32::<LittleEndian>(version)?;
+        self.inner.write_u32::<LittleEndian>(sequence_count)?;
+        self.inner.write_u32::<LittleEndian>(0)?;
+
+        let mut offset = index_end(&names, offset_size);
+
+        for (name, encoded_record) in names.iter().zip(&encoded_records) {
+            self.inner.write_u8(name.len() as u8)?;
+            self.inner.write_all(name)?;
+
+            if version == 0 {
+                self.inner.write_u32::<LittleEndian>(offset as u32)?;
+            } else {
+                self.inner.write_u64::<LittleEndian>(offset)?;
+            }
+
+            offset += encoded_record.len() as u64;
+        }
+
+        for encoded_record in encoded_records {
+            self.inner.write_all(&encoded_record)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn index_end(names: &[&[u8]], offset_size: u64) -> u64 {
+    names.iter().fold(HEADER_SIZE, |n, name| {
+        n + 1 + name.len() as u64 + offset_size
+    })
+}
+
+fn needs_64_bit_offsets(names: &[&[u8]], encoded_records: &[Vec<u8>], offset_size: u64) -> bool {
+    let mut offset = index_end(names, offset_size);
+
+    for encoded_record in encoded_records {
+        if offset > u64::from(u32::MAX) {
+            return true;
+        }
+
+        offset += encoded_record.len() as u64;
+    }
+
+    false
+}
+
+fn encode_sequence(sequence: &[u8]) -> io::Result<Vec<u8>> {
+    fn invalid_input<E>(e: E) -> io::Error
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        io::Error::new(io::ErrorKind::InvalidInput, e)
+    }
+
+    let dna_size = u32::try_from(sequence.len()).map_err(invalid_input)?;
+
+    let n_blocks = find_blocks(sequence, |b| {
+        !matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T')
+    });
+
+    let mask_blocks = find_blocks(sequence, |b| b.is_ascii_lowercase());
+
+    let mut buf = Vec::new();
+
+    buf.write_u32::<LittleEndian>(dna_size)?;
+    write_blocks(&mut buf, &n_blocks)?;
+    write_blocks(&mut buf, &mask_blocks)?;
+    buf.write_u32::<LittleEndian>(0)?;
+
+    for chunk in sequence.chunks(4) {
+        let mut b = 0;
+
+        for (i, &base) in chunk.iter().enumerate() {
+            b |= encode_base(base) << (6 - 2 * i);
+        }
+
+        buf.push(b);
+    }
+
+    Ok(buf)
+}
+
+fn encode_base(b: u8) -> u8 {
+    match b.to_ascii_uppercase() {
+        b'C' => 0b01,
+        b'A' => 0b10,
+        b'G' => 0b11,
+        _ => 0b00,
+    }
+}
+
+// Finds runs of bases that match the given predicate as (start, length) pairs.
+fn find_blocks<F>(sequence: &[u8], f: F) -> Vec<(u32, u32)>
+where
+    F: Fn(u8) -> bool,
+{
+    let mut blocks = Vec::new();
+    let mut block_start = None;
+
+    for (i, &b) in sequence.iter().enumerate() {
+        match (f(b), block_start) {
+            (true, None) => block_start = Some(i),
+            (false, Some(start)) => {
+                blocks.push((start as u32, (i - start) as u32));
+                block_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(start) = block_start {
+        blocks.push((start as u32, (sequence.len() - start) as u32));
+    }
+
+    blocks
+}
+
+fn write_blocks(buf: &mut Vec<u8>, blocks: &[(u32, u32)]) -> io::Result<()> {
+    buf.write_u32::<LittleEndian>(blocks.len() as u32)?;
+
+    for &(start, _) in blocks {
+        buf.write_u32::<LittleEndian>(start)?;
+    }
+
+    for &(_, len) in blocks {
+        buf.write_u32::<LittleEndian>(len)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Region;
+
+    use super::*;
+    use crate::{
+        record::{Definition, Sequence},
+        two_bit::Reader,
+    };
+
+    #[test]
+    fn test_write_records() -> io::Result<()> {
+        let records = [
+            Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGTNNacgtnACGTA".to_vec()),
+            ),
+            Record::new(Definition::new("sq1", None), Sequence::default()),
+            Record::new(
+                Definition::new("sq2", None),
+                Sequence::from(b"RYACG".to_vec()),
+            ),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_records(&records)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.into_inner()))?;
+        assert_eq!(reader.names().collect::<Vec<_>>(), ["sq0", "sq1", "sq2"]);
+
+        let record = reader.query(&Region::new("sq0", ..))?;
+        assert_eq!(record.sequence(), records[0].sequence());
+
+        let record = reader.query(&Region::new("sq1", ..))?;
+        assert!(record.sequence().is_empty());
+
+        let record = reader.query(&Region::new("sq2", ..))?;
+        assert_eq!(record.sequence(), &Sequence::from(b"NNACG".to_vec()));
+
+        let region = "sq0:6-12".parse().unwrap();
+        let record = reader.query(&region)?;
+        assert_eq!(record.sequence(), &Sequence::from(b"NacgtnA".to_vec()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_sequence() -> io::Result<()> {
+        let actual = encode_sequence(b"TCAGnA")?;
+
+        let expected = [
+            0x06, 0x00, 0x00, 0x00, // DNA size = 6
+            0x01, 0x00, 0x00, 0x00, // N block count = 1
+            0x04, 0x00, 0x00, 0x00, // N block starts[0] = 4
+            0x01, 0x00, 0x00, 0x00, // N block sizes[0] = 1
+            0x01, 0x00, 0x00, 0x00, // mask block count = 1
+            0x04, 0x00, 0x00, 0x00, // mask block starts[0] = 4
+            0x01, 0x00, 0x00, 0x00, // mask block sizes[0] = 1
+            0x00, 0x00, 0x00, 0x00, // reserved
+            0x1b, 0x20, // packed DNA = TCAGTA
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_blocks() {
+        let is_n = |b| b == b'N';
+
+        assert!(find_blocks(b"", is_n).is_empty());
+        assert!(find_blocks(b"ACGT", is_n).is_empty());
+        assert_eq!(find_blocks(b"NNACGTN", is_n), [(0, 2), (6, 1)]);
+        assert_eq!(find_blocks(b"ANNNA", is_n), [(1, 3)]);
+    }
+}
@@ -0,0 +1,36 @@
+//! Queries a FASTQ file for a record by name.
+//!
+//! The input FASTQ must have an associated index in the same directory.
+//!
+//! The result matches the output of `samtools fqidx <src> <name>`.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_fastq::{self as fastq, fai};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let name = args.next().expect("missing name");
+
+    let index = fai::read(format!("{}.fai", src))?;
+
+    let mut reader = File::open(&src)
+        .map(BufReader::new)
+        .map(|inner| fastq::IndexedReader::new(inner, index))?;
+
+    let record = reader.query(&name)?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = fastq::Writer::new(handle);
+
+    writer.write_record(&record)?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Validates a FASTQ file.
+//!
+//! Each invalid record is printed to stderr with its line and column. The exit status is nonzero
+//! if any record is invalid.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    process,
+};
+
+use noodles_fastq as fastq;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(&src)
+        .map(BufReader::new)
+        .map(fastq::Reader::new)?;
+
+    let mut validator = fastq::Validator::new().set_validate_description(true);
+    let mut is_valid = true;
+
+    for result in reader.records() {
+        let record = result?;
+
+        if let Err(e) = validator.validate(&record) {
+            eprintln!("{}:{}", src, e);
+            is_valid = false;
+        }
+    }
+
+    if !is_valid {
+        process::exit(1);
+    }
+
+    Ok(())
+}
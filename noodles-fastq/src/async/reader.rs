@@ -1,14 +1,19 @@
 use futures::{stream, Stream};
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
 
-use crate::Record;
+use crate::{reader::validate_quality_scores_len, Record};
 
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
+const DESCRIPTION_PREFIX: u8 = b'+';
 
 /// An async FASTQ reader.
+///
+/// By default, the reader expects each record to span four lines. To accept records with sequences
+/// and quality scores wrapped over multiple lines, see [`Self::set_multiline`].
 pub struct Reader<R> {
     inner: R,
+    multiline: bool,
 }
 
 impl<R> Reader<R>
@@ -25,7 +30,27 @@ where
     /// let reader = fastq::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            multiline: false,
+        }
+    }
+
+    /// Sets whether records with multiline sequences and quality scores are accepted.
+    ///
+    /// See [`crate::Reader::set_multiline`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = [];
+    /// let mut reader = fastq::AsyncReader::new(&data[..]);
+    /// reader.set_multiline(true);
+    /// ```
+    pub fn set_multiline(&mut self, multiline: bool) -> &mut Self {
+        self.multiline = multiline;
+        self
     }
 
     /// Reads a FASTQ record.
@@ -50,7 +75,7 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record).await
+        read_record(&mut self.inner, record, self.multiline).await
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -75,19 +100,23 @@ where
     /// # }
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        let multiline = self.multiline;
+
         Box::pin(stream::try_unfold(
             (&mut self.inner, Record::default()),
-            |(mut reader, mut buf)| async {
-                read_record(&mut reader, &mut buf).await.map(|n| match n {
-                    0 => None,
-                    _ => Some((buf.clone(), (reader, buf))),
-                })
+            move |(mut reader, mut buf)| async move {
+                read_record(&mut reader, &mut buf, multiline)
+                    .await
+                    .map(|n| match n {
+                        0 => None,
+                        _ => Some((buf.clone(), (reader, buf))),
+                    })
             },
         ))
     }
 }
 
-async fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+async fn read_record<R>(reader: &mut R, record: &mut Record, multiline: bool) -> io::Result<usize>
 where
     R: AsyncBufRead + Unpin,
 {
@@ -98,9 +127,59 @@ where
         n => n,
     };
 
-    len += read_line(reader, record.sequence_mut()).await?;
+    if multiline {
+        len += read_multiline_sequence(reader, record.sequence_mut()).await?;
+    } else {
+        len += read_line(reader, record.sequence_mut()).await?;
+    }
+
     len += read_description(reader, record.description_mut()).await?;
-    len += read_line(reader, record.quality_scores_mut()).await?;
+
+    if multiline {
+        let sequence_len = record.sequence().len();
+        len += read_multiline_quality_scores(reader, sequence_len, record.quality_scores_mut())
+            .await?;
+    } else {
+        len += read_line(reader, record.quality_scores_mut()).await?;
+    }
+
+    Ok(len)
+}
+
+async fn read_multiline_sequence<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut len = read_line(reader, buf).await?;
+
+    loop {
+        match reader.fill_buf().await?.first() {
+            Some(&DESCRIPTION_PREFIX) | None => break,
+            Some(_) => len += read_line(reader, buf).await?,
+        }
+    }
+
+    Ok(len)
+}
+
+async fn read_multiline_quality_scores<R>(
+    reader: &mut R,
+    sequence_len: usize,
+    buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut len = read_line(reader, buf).await?;
+
+    while buf.len() < sequence_len {
+        match read_line(reader, buf).await? {
+            0 => break,
+            n => len += n,
+        }
+    }
+
+    validate_quality_scores_len(sequence_len, buf)?;
 
     Ok(len)
 }
@@ -126,8 +205,6 @@ async fn read_description<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<us
 where
     R: AsyncBufRead + Unpin,
 {
-    const DESCRIPTION_PREFIX: u8 = b'+';
-
     match reader.read_u8().await? {
         DESCRIPTION_PREFIX => read_line(reader, buf).await.map(|n| n + 1),
         _ => Err(io::Error::new(
@@ -177,21 +254,50 @@ dcba
         let mut reader = &data[..];
         let mut record = Record::default();
 
-        read_record(&mut reader, &mut record).await?;
+        read_record(&mut reader, &mut record, false).await?;
         let expected = Record::new("noodles:1/1", "AGCT", "abcd");
         assert_eq!(record, expected);
 
-        read_record(&mut reader, &mut record).await?;
+        read_record(&mut reader, &mut record, false).await?;
         let mut expected = Record::new("noodles:2/1", "TCGA", "dcba");
         expected.description_mut().extend_from_slice(b"noodles:2/1");
         assert_eq!(record, expected);
 
-        let n = read_record(&mut reader, &mut record).await?;
+        let n = read_record(&mut reader, &mut record, false).await?;
         assert_eq!(n, 0);
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_record_with_multiline_record() -> io::Result<()> {
+        let data = b"@r0\nAG\nCT\n+\n@+\ncd\n@r1\nA\n+\nN\n";
+
+        let mut reader = &data[..];
+        let mut record = Record::default();
+
+        read_record(&mut reader, &mut record, true).await?;
+        assert_eq!(record, Record::new("r0", "AGCT", "@+cd"));
+
+        read_record(&mut reader, &mut record, true).await?;
+        assert_eq!(record, Record::new("r1", "A", "N"));
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"@r0\nAG\nCT\n+\nND\n@r1\nACGT\n+\nNDLS\n";
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, true).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_name() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -0,0 +1,207 @@
+use std::io::{self, Write};
+
+use super::{interleave::is_mate, Record, Writer};
+
+/// A FASTQ deinterleaver.
+///
+/// A deinterleaver routes records from an interleaved stream to R1 and R2 writers. Consecutive
+/// records are considered to be a pair when their read names are of mates (see
+/// [`crate::Interleave`]).
+///
+/// A record that is not followed by its mate is a singleton. If a singletons writer is set, it
+/// receives all singletons; otherwise, a singleton is an error.
+pub struct Deinterleaver<W, X, Y = io::Sink> {
+    r1: Writer<W>,
+    r2: Writer<X>,
+    singletons: Option<Writer<Y>>,
+    pending: Option<Record>,
+}
+
+impl<W, X> Deinterleaver<W, X>
+where
+    W: Write,
+    X: Write,
+{
+    /// Creates a deinterleaver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let deinterleaver = fastq::Deinterleaver::new(
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    /// );
+    /// ```
+    pub fn new(r1: Writer<W>, r2: Writer<X>) -> Self {
+        Self {
+            r1,
+            r2,
+            singletons: None,
+            pending: None,
+        }
+    }
+}
+
+impl<W, X, Y> Deinterleaver<W, X, Y>
+where
+    W: Write,
+    X: Write,
+    Y: Write,
+{
+    /// Creates a deinterleaver that writes singletons to the given writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let deinterleaver = fastq::Deinterleaver::with_singletons(
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    /// );
+    /// ```
+    pub fn with_singletons(r1: Writer<W>, r2: Writer<X>, singletons: Writer<Y>) -> Self {
+        Self {
+            r1,
+            r2,
+            singletons: Some(singletons),
+            pending: None,
+        }
+    }
+
+    /// Writes a record from an interleaved stream.
+    ///
+    /// A record is buffered until its mate or the next record is written. Call
+    /// [`Self::finish`] after the last record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let mut deinterleaver = fastq::Deinterleaver::new(
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    /// );
+    ///
+    /// deinterleaver.write_record(fastq::Record::new("r0/1", "AC", "NN"))?;
+    /// deinterleaver.write_record(fastq::Record::new("r0/2", "GT", "NN"))?;
+    /// deinterleaver.finish()?;
+    ///
+    /// let (r1, r2, _) = deinterleaver.into_inner();
+    /// assert_eq!(r1.get_ref(), b"@r0/1\nAC\n+\nNN\n");
+    /// assert_eq!(r2.get_ref(), b"@r0/2\nGT\n+\nNN\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: Record) -> io::Result<()> {
+        match self.pending.take() {
+            Some(mate) if is_mate(mate.name(), record.name()) => {
+                self.r1.write_record(&mate)?;
+                self.r2.write_record(&record)?;
+            }
+            Some(singleton) => {
+                self.pending = Some(record);
+                self.write_singleton(&singleton)?;
+            }
+            None => self.pending = Some(record),
+        }
+
+        Ok(())
+    }
+
+    /// Writes all records from an interleaved stream and finishes the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nAC\n+\nNN\n@r1/1\nAA\n+\nNN\n@r2/1\nCC\n+\nNN\n@r2/2\nGG\n+\nNN\n";
+    /// let mut reader = fastq::Reader::new(&data[..]);
+    ///
+    /// let mut deinterleaver = fastq::Deinterleaver::with_singletons(
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    ///     fastq::Writer::new(Vec::new()),
+    /// );
+    ///
+    /// deinterleaver.deinterleave(reader.records())?;
+    ///
+    /// let (r1, r2, singletons) = deinterleaver.into_inner();
+    /// assert_eq!(r1.get_ref(), b"@r2/1\nCC\n+\nNN\n");
+    /// assert_eq!(r2.get_ref(), b"@r2/2\nGG\n+\nNN\n");
+    /// assert_eq!(
+    ///     singletons.map(|writer| writer.get_ref().clone()),
+    ///     Some(b"@r0/1\nAC\n+\nNN\n@r1/1\nAA\n+\nNN\n".to_vec())
+    /// );
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn deinterleave<I>(&mut self, records: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = io::Result<Record>>,
+    {
+        for result in records {
+            let record = result?;
+            self.write_record(record)?;
+        }
+
+        self.finish()
+    }
+
+    /// Writes the last buffered record, if any, as a singleton.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(singleton) = self.pending.take() {
+            self.write_singleton(&singleton)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the R1, R2, and singletons writers.
+    pub fn into_inner(self) -> (Writer<W>, Writer<X>, Option<Writer<Y>>) {
+        (self.r1, self.r2, self.singletons)
+    }
+
+    fn write_singleton(&mut self, record: &Record) -> io::Result<()> {
+        match self.singletons.as_mut() {
+            Some(writer) => writer.write_record(record),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "missing mate for record: {}",
+                    String::from_utf8_lossy(record.name())
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record_with_singleton_without_singletons_writer() -> io::Result<()> {
+        let mut deinterleaver =
+            Deinterleaver::new(Writer::new(Vec::new()), Writer::new(Vec::new()));
+
+        deinterleaver.write_record(Record::new("r0/1", "A", "N"))?;
+
+        assert!(matches!(
+            deinterleaver.write_record(Record::new("r1/1", "C", "N")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            deinterleaver.finish(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use std::io::{self, BufRead};
 
+use super::Index;
+
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
 
@@ -55,6 +57,49 @@ where
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
         read_line(&mut self.inner, buf)
     }
+
+    /// Reads a FASTQ index.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::fai;
+    ///
+    /// let data = b"r0\t4\t4\t4\t5\t11\nr1\t2\t20\t2\t3\t25\n";
+    /// let mut reader = fai::Reader::new(&data[..]);
+    /// let index = reader.read_index()?;
+    ///
+    /// assert_eq!(index, vec![
+    ///     fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11),
+    ///     fai::Record::new(String::from("r1"), 2, 20, 2, 3, 25),
+    /// ]);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_index(&mut self) -> io::Result<Index> {
+        let mut buf = String::new();
+        let mut index = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match read_line(&mut self.inner, &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {
+                    let record = buf
+                        .parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                    index.push(record);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(index)
+    }
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -0,0 +1,325 @@
+//! Illumina (CASAVA 1.8+) read names.
+//!
+//! An Illumina read name has the form
+//!
+//! ```text
+//! <instrument>:<run number>:<flowcell ID>:<lane>:<tile>:<x>:<y> <read>:<is filtered>:<control number>:<index>
+//! ```
+//!
+//! where the comment (the content after the space) is optional.
+
+pub mod comment;
+
+pub use self::comment::Comment;
+
+use std::{error, fmt, num, str::FromStr};
+
+const DELIMITER: char = ':';
+const COMMENT_DELIMITER: char = ' ';
+
+/// An Illumina read name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Name {
+    instrument: String,
+    run_number: u32,
+    flowcell_id: String,
+    lane: u32,
+    tile: u32,
+    x: u32,
+    y: u32,
+    comment: Option<Comment>,
+}
+
+impl Name {
+    /// Returns the instrument ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.instrument(), "EAS139");
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    /// Returns the run number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.run_number(), 136);
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn run_number(&self) -> u32 {
+        self.run_number
+    }
+
+    /// Returns the flowcell ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.flowcell_id(), "FC706VJ");
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn flowcell_id(&self) -> &str {
+        &self.flowcell_id
+    }
+
+    /// Returns the flowcell lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.lane(), 2);
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    /// Returns the tile number within the flowcell lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.tile(), 2104);
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn tile(&self) -> u32 {
+        self.tile
+    }
+
+    /// Returns the x-coordinate of the cluster within the tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.x(), 15343);
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Returns the y-coordinate of the cluster within the tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert_eq!(name.y(), 197393);
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Returns the comment, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina;
+    ///
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393".parse()?;
+    /// assert!(name.comment().is_none());
+    ///
+    /// let name: illumina::Name = "EAS139:136:FC706VJ:2:2104:15343:197393 1:Y:18:ATCACG".parse()?;
+    /// assert_eq!(name.comment().map(|comment| comment.read_number()), Some(1));
+    /// # Ok::<_, illumina::ParseError>(())
+    /// ```
+    pub fn comment(&self) -> Option<&Comment> {
+        self.comment.as_ref()
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{instrument}{d}{run_number}{d}{flowcell_id}{d}{lane}{d}{tile}{d}{x}{d}{y}",
+            instrument = self.instrument,
+            run_number = self.run_number,
+            flowcell_id = self.flowcell_id,
+            lane = self.lane,
+            tile = self.tile,
+            x = self.x,
+            y = self.y,
+            d = DELIMITER,
+        )?;
+
+        if let Some(comment) = self.comment() {
+            write!(f, "{}{}", COMMENT_DELIMITER, comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when an Illumina read name fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// A field is missing.
+    MissingField(&'static str),
+    /// A numeric field is invalid.
+    InvalidField(&'static str, num::ParseIntError),
+    /// The input has unexpected trailing fields.
+    UnexpectedField,
+    /// The comment is invalid.
+    InvalidComment(comment::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidField(_, e) => Some(e),
+            Self::InvalidComment(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingField(name) => write!(f, "missing field: {}", name),
+            Self::InvalidField(name, _) => write!(f, "invalid field: {}", name),
+            Self::UnexpectedField => f.write_str("unexpected field"),
+            Self::InvalidComment(_) => f.write_str("invalid comment"),
+        }
+    }
+}
+
+impl FromStr for Name {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (raw_name, raw_comment) = match s.split_once(COMMENT_DELIMITER) {
+            Some((raw_name, raw_comment)) => (raw_name, Some(raw_comment)),
+            None => (s, None),
+        };
+
+        let mut fields = raw_name.split(DELIMITER);
+
+        let instrument = parse_string(&mut fields, "instrument")?;
+        let run_number = parse_u32(&mut fields, "run number")?;
+        let flowcell_id = parse_string(&mut fields, "flowcell ID")?;
+        let lane = parse_u32(&mut fields, "lane")?;
+        let tile = parse_u32(&mut fields, "tile")?;
+        let x = parse_u32(&mut fields, "x")?;
+        let y = parse_u32(&mut fields, "y")?;
+
+        if fields.next().is_some() {
+            return Err(ParseError::UnexpectedField);
+        }
+
+        let comment = raw_comment
+            .map(|s| s.parse().map_err(ParseError::InvalidComment))
+            .transpose()?;
+
+        Ok(Self {
+            instrument,
+            run_number,
+            flowcell_id,
+            lane,
+            tile,
+            x,
+            y,
+            comment,
+        })
+    }
+}
+
+fn parse_string<'a, I>(fields: &mut I, name: &'static str) -> Result<String, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    fields
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .ok_or(ParseError::MissingField(name))
+}
+
+fn parse_u32<'a, I>(fields: &mut I, name: &'static str) -> Result<u32, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    fields
+        .next()
+        .ok_or(ParseError::MissingField(name))
+        .and_then(|s| s.parse().map_err(|e| ParseError::InvalidField(name, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        let s = "EAS139:136:FC706VJ:2:2104:15343:197393";
+        assert_eq!(s.parse::<Name>()?.to_string(), s);
+
+        let s = "EAS139:136:FC706VJ:2:2104:15343:197393 1:Y:18:ATCACG";
+        assert_eq!(s.parse::<Name>()?.to_string(), s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "EAS139:136:FC706VJ:2:2104:15343:197393 1:N:0:ATCACG+GTTTCG".parse(),
+            Ok(Name {
+                instrument: String::from("EAS139"),
+                run_number: 136,
+                flowcell_id: String::from("FC706VJ"),
+                lane: 2,
+                tile: 2104,
+                x: 15343,
+                y: 197393,
+                comment: "1:N:0:ATCACG+GTTTCG".parse().ok(),
+            })
+        );
+
+        assert_eq!("".parse::<Name>(), Err(ParseError::Empty));
+        assert_eq!(
+            "EAS139:136:FC706VJ:2".parse::<Name>(),
+            Err(ParseError::MissingField("tile"))
+        );
+        assert!(matches!(
+            "EAS139:136:FC706VJ:2:2104:15343:n".parse::<Name>(),
+            Err(ParseError::InvalidField("y", _))
+        ));
+        assert_eq!(
+            "EAS139:136:FC706VJ:2:2104:15343:197393:0".parse::<Name>(),
+            Err(ParseError::UnexpectedField)
+        );
+        assert!(matches!(
+            "EAS139:136:FC706VJ:2:2104:15343:197393 1:X:0:ATCACG".parse::<Name>(),
+            Err(ParseError::InvalidComment(_))
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+//! Illumina read name comment.
+
+use std::{error, fmt, num, str::FromStr};
+
+use super::DELIMITER;
+
+const FILTERED: &str = "Y";
+const NOT_FILTERED: &str = "N";
+
+/// An Illumina read name comment.
+///
+/// This has the form `<read>:<is filtered>:<control number>:<index>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Comment {
+    read_number: u8,
+    is_filtered: bool,
+    control_number: u16,
+    index: String,
+}
+
+impl Comment {
+    /// Returns the read number (1 or 2 for paired reads).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina::Comment;
+    /// let comment: Comment = "1:Y:18:ATCACG".parse()?;
+    /// assert_eq!(comment.read_number(), 1);
+    /// # Ok::<_, noodles_fastq::illumina::comment::ParseError>(())
+    /// ```
+    pub fn read_number(&self) -> u8 {
+        self.read_number
+    }
+
+    /// Returns whether the read is filtered, i.e., did not pass filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina::Comment;
+    /// let comment: Comment = "1:Y:18:ATCACG".parse()?;
+    /// assert!(comment.is_filtered());
+    /// # Ok::<_, noodles_fastq::illumina::comment::ParseError>(())
+    /// ```
+    pub fn is_filtered(&self) -> bool {
+        self.is_filtered
+    }
+
+    /// Returns the control number.
+    ///
+    /// This is 0 when none of the control bits are on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina::Comment;
+    /// let comment: Comment = "1:Y:18:ATCACG".parse()?;
+    /// assert_eq!(comment.control_number(), 18);
+    /// # Ok::<_, noodles_fastq::illumina::comment::ParseError>(())
+    /// ```
+    pub fn control_number(&self) -> u16 {
+        self.control_number
+    }
+
+    /// Returns the index sequence or sample number.
+    ///
+    /// For dual indices, this is both sequences delimited by a `+`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::illumina::Comment;
+    /// let comment: Comment = "1:Y:18:ATCACG".parse()?;
+    /// assert_eq!(comment.index(), "ATCACG");
+    /// # Ok::<_, noodles_fastq::illumina::comment::ParseError>(())
+    /// ```
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_filtered = if self.is_filtered {
+            FILTERED
+        } else {
+            NOT_FILTERED
+        };
+
+        write!(
+            f,
+            "{}{d}{}{d}{}{d}{}",
+            self.read_number,
+            is_filtered,
+            self.control_number,
+            self.index,
+            d = DELIMITER
+        )
+    }
+}
+
+/// An error returned when an Illumina read name comment fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The read number is missing.
+    MissingReadNumber,
+    /// The read number is invalid.
+    InvalidReadNumber(num::ParseIntError),
+    /// The filter flag is missing.
+    MissingIsFiltered,
+    /// The filter flag is invalid.
+    ///
+    /// It must be either `Y` or `N`.
+    InvalidIsFiltered(String),
+    /// The control number is missing.
+    MissingControlNumber,
+    /// The control number is invalid.
+    InvalidControlNumber(num::ParseIntError),
+    /// The index is missing.
+    MissingIndex,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidReadNumber(e) | Self::InvalidControlNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReadNumber => f.write_str("missing read number"),
+            Self::InvalidReadNumber(_) => f.write_str("invalid read number"),
+            Self::MissingIsFiltered => f.write_str("missing filter flag"),
+            Self::InvalidIsFiltered(s) => {
+                write!(f, "invalid filter flag: expected {{Y, N}}, got {}", s)
+            }
+            Self::MissingControlNumber => f.write_str("missing control number"),
+            Self::InvalidControlNumber(_) => f.write_str("invalid control number"),
+            Self::MissingIndex => f.write_str("missing index"),
+        }
+    }
+}
+
+impl FromStr for Comment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.splitn(4, DELIMITER);
+
+        let read_number = fields
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(ParseError::MissingReadNumber)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidReadNumber))?;
+
+        let is_filtered = fields
+            .next()
+            .ok_or(ParseError::MissingIsFiltered)
+            .and_then(|s| match s {
+                FILTERED => Ok(true),
+                NOT_FILTERED => Ok(false),
+                _ => Err(ParseError::InvalidIsFiltered(s.into())),
+            })?;
+
+        let control_number = fields
+            .next()
+            .ok_or(ParseError::MissingControlNumber)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidControlNumber))?;
+
+        let index = fields
+            .next()
+            .map(String::from)
+            .ok_or(ParseError::MissingIndex)?;
+
+        Ok(Self {
+            read_number,
+            is_filtered,
+            control_number,
+            index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        for s in ["1:Y:18:ATCACG", "2:N:0:ATCACG+GTTTCG", "1:N:0:2", "1:N:0:"] {
+            assert_eq!(s.parse::<Comment>()?.to_string(), s);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "2:N:0:ATCACG+GTTTCG".parse(),
+            Ok(Comment {
+                read_number: 2,
+                is_filtered: false,
+                control_number: 0,
+                index: String::from("ATCACG+GTTTCG"),
+            })
+        );
+
+        assert_eq!("".parse::<Comment>(), Err(ParseError::MissingReadNumber));
+        assert!(matches!(
+            "n:Y:0:ATCACG".parse::<Comment>(),
+            Err(ParseError::InvalidReadNumber(_))
+        ));
+        assert_eq!("1".parse::<Comment>(), Err(ParseError::MissingIsFiltered));
+        assert_eq!(
+            "1:X:0:ATCACG".parse::<Comment>(),
+            Err(ParseError::InvalidIsFiltered(String::from("X")))
+        );
+        assert_eq!(
+            "1:Y".parse::<Comment>(),
+            Err(ParseError::MissingControlNumber)
+        );
+        assert!(matches!(
+            "1:Y:n:ATCACG".parse::<Comment>(),
+            Err(ParseError::InvalidControlNumber(_))
+        ));
+        assert_eq!("1:Y:0".parse::<Comment>(), Err(ParseError::MissingIndex));
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Seek, SeekFrom},
+};
+
+use super::{fai, Record};
+
+const LINE_FEED: u8 = b'\n';
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// An indexed FASTQ reader.
+///
+/// This pairs a FASTQ reader with its associated index (FAI) to allow random access to records
+/// by name without scanning the file.
+///
+/// Like [`crate::Indexer`], this only supports records that span four lines.
+pub struct IndexedReader<R> {
+    inner: R,
+    index: fai::Index,
+    // Record names mapped to their positions in the index.
+    positions: HashMap<String, usize>,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n";
+    /// let index = vec![fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11)];
+    ///
+    /// let reader = fastq::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        let mut positions = HashMap::with_capacity(index.len());
+
+        for (i, record) in index.iter().enumerate() {
+            positions.entry(record.name().into()).or_insert(i);
+        }
+
+        Self {
+            inner,
+            index,
+            positions,
+        }
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let index = vec![fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11)];
+    /// let reader = fastq::IndexedReader::new(&[][..], index);
+    ///
+    /// assert_eq!(reader.index().len(), 1);
+    /// ```
+    pub fn index(&self) -> &fai::Index {
+        &self.index
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let mut reader = fastq::IndexedReader::new(&[][..], Vec::new());
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::IndexedReader::new(&[][..], Vec::new());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Returns the record with the given name.
+    ///
+    /// The sequence and quality scores are read directly from their offsets in the index. The
+    /// record description is not stored in the index and is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nTG\n+\nNN\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11),
+    ///     fai::Record::new(String::from("r1"), 2, 20, 2, 3, 25),
+    /// ];
+    ///
+    /// let mut reader = fastq::IndexedReader::new(Cursor::new(data), index);
+    ///
+    /// let record = reader.query("r1")?;
+    /// assert_eq!(record, fastq::Record::new("r1", "TG", "NN"));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query(&mut self, name: &str) -> io::Result<Record> {
+        let index_record = self
+            .positions
+            .get(name)
+            .map(|&i| &self.index[i])
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid record name: {}", name),
+                )
+            })?;
+
+        let len = usize::try_from(index_record.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut record = Record::default();
+        record.name_mut().extend(index_record.name().as_bytes());
+
+        self.inner
+            .seek(SeekFrom::Start(index_record.sequence_offset()))?;
+        read_lines_limit(&mut self.inner, len, record.sequence_mut())?;
+
+        self.inner
+            .seek(SeekFrom::Start(index_record.quality_scores_offset()))?;
+        read_lines_limit(&mut self.inner, len, record.quality_scores_mut())?;
+
+        Ok(record)
+    }
+}
+
+// Reads lines, without line endings, until `len` bytes are read.
+fn read_lines_limit<R>(reader: &mut R, len: usize, buf: &mut Vec<u8>) -> io::Result<()>
+where
+    R: BufRead,
+{
+    while buf.len() < len {
+        match reader.read_until(LINE_FEED, buf)? {
+            0 => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "unexpected EOF",
+                ))
+            }
+            _ => {
+                if buf.ends_with(&[LINE_FEED]) {
+                    buf.pop();
+
+                    if buf.ends_with(&[CARRIAGE_RETURN]) {
+                        buf.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    if buf.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "record length mismatch",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::Indexer;
+
+    #[test]
+    fn test_query() -> io::Result<()> {
+        let data = b"@r0\nACGT\n+\nNDLS\n@r1\nNNNNNNNNNN\n+\nNDLSNDLSND\n";
+        let mut indexer = Indexer::new(&data[..]);
+        let mut index = Vec::new();
+
+        while let Some(record) = indexer.index_record()? {
+            index.push(record);
+        }
+
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        assert_eq!(
+            reader.query("r1")?,
+            Record::new("r1", "NNNNNNNNNN", "NDLSNDLSND")
+        );
+        assert_eq!(reader.query("r0")?, Record::new("r0", "ACGT", "NDLS"));
+
+        assert!(matches!(
+            reader.query("r2"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lines_limit() -> io::Result<()> {
+        let mut buf = Vec::new();
+        read_lines_limit(&mut &b"AC\r\nGT\n+\n"[..], 4, &mut buf)?;
+        assert_eq!(buf, b"ACGT");
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            read_lines_limit(&mut &b"AC\n"[..], 4, &mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
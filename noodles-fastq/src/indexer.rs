@@ -3,8 +3,12 @@ use std::io::{self, BufRead};
 use super::{fai::Record, reader::read_name};
 
 const LINE_FEED: u8 = b'\n';
+const DESCRIPTION_PREFIX: u8 = b'+';
 
 /// A FASTQ indexer.
+///
+/// The indexer expects each record to span four lines. Records with sequences or quality scores
+/// wrapped over multiple lines are an error.
 #[derive(Debug)]
 pub struct Indexer<R> {
     inner: R,
@@ -75,12 +79,26 @@ where
         self.line_buf.clear();
         self.offset += read_line(&mut self.inner, &mut self.line_buf)? as u64;
 
+        if !self.line_buf.starts_with(&[DESCRIPTION_PREFIX]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid description prefix (multiline records are not supported)",
+            ));
+        }
+
         // quality scores
         let quality_scores_offset = self.offset;
 
         self.line_buf.clear();
         self.offset += read_line(&mut self.inner, &mut self.line_buf)? as u64;
 
+        if len_with_right_trim(&self.line_buf) as u64 != line_bases {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "quality scores length mismatch (multiline records are not supported)",
+            ));
+        }
+
         Ok(Some(Record::new(
             name,
             line_bases,
@@ -141,4 +159,23 @@ NDLSNDLSND
 
         Ok(())
     }
+
+    #[test]
+    fn test_index_record_with_multiline_record() -> io::Result<()> {
+        let data = b"@r0\nAC\nGT\n+\nNDLS\n";
+        let mut indexer = Indexer::new(&data[..]);
+        assert!(matches!(
+            indexer.index_record(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"@r0\nACGT\n+\nND\nLS\n";
+        let mut indexer = Indexer::new(&data[..]);
+        assert!(matches!(
+            indexer.index_record(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,183 @@
+use std::io;
+
+use super::Record;
+
+/// An iterator adapter that interleaves two streams of paired records.
+///
+/// Each record from the first stream (R1) is followed by its mate from the second stream (R2).
+/// Mates are expected to have the same read name, ignoring any comment (i.e., the content
+/// following the first whitespace character) and a `/1` or `/2` suffix.
+///
+/// It is an error if the streams have a different number of records or if a pair of records are
+/// not mates.
+pub struct Interleave<A, B> {
+    r1: A,
+    r2: B,
+    mate: Option<Record>,
+}
+
+impl<A, B> Interleave<A, B>
+where
+    A: Iterator<Item = io::Result<Record>>,
+    B: Iterator<Item = io::Result<Record>>,
+{
+    /// Creates an interleave iterator adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let mut r1_reader = fastq::Reader::new(&b"@r0/1\nAC\n+\nNN\n"[..]);
+    /// let mut r2_reader = fastq::Reader::new(&b"@r0/2\nGT\n+\nNN\n"[..]);
+    ///
+    /// let records: Vec<_> = fastq::Interleave::new(r1_reader.records(), r2_reader.records())
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records, [
+    ///     fastq::Record::new("r0/1", "AC", "NN"),
+    ///     fastq::Record::new("r0/2", "GT", "NN"),
+    /// ]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(r1: A, r2: B) -> Self {
+        Self { r1, r2, mate: None }
+    }
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator<Item = io::Result<Record>>,
+    B: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(record) = self.mate.take() {
+            return Some(Ok(record));
+        }
+
+        let (r1, r2) = match (self.r1.next(), self.r2.next()) {
+            (None, None) => return None,
+            (Some(Err(e)), _) | (_, Some(Err(e))) => return Some(Err(e)),
+            (Some(Ok(r1)), Some(Ok(r2))) => (r1, r2),
+            _ => {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "mismatched number of paired records",
+                )))
+            }
+        };
+
+        if !is_mate(r1.name(), r2.name()) {
+            return Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "record names are not mates: {} != {}",
+                    String::from_utf8_lossy(r1.name()),
+                    String::from_utf8_lossy(r2.name())
+                ),
+            )));
+        }
+
+        self.mate = Some(r2);
+
+        Some(Ok(r1))
+    }
+}
+
+/// Returns whether two read names are of the first and second mates of a pair.
+///
+/// If the names have mate suffixes, the first must end with `/1` and the second with `/2`.
+pub(crate) fn is_mate(a: &[u8], b: &[u8]) -> bool {
+    let (a_name, a_mate) = split_name(a);
+    let (b_name, b_mate) = split_name(b);
+
+    a_name == b_name && matches!((a_mate, b_mate), (None, None) | (Some(b'1'), Some(b'2')))
+}
+
+// Splits the read name without a comment into its base name and mate suffix (`/1` or `/2`).
+fn split_name(name: &[u8]) -> (&[u8], Option<u8>) {
+    let name = name
+        .iter()
+        .position(|b| b.is_ascii_whitespace())
+        .map(|i| &name[..i])
+        .unwrap_or(name);
+
+    match name {
+        [base_name @ .., b'/', mate @ (b'1' | b'2')] => (base_name, Some(*mate)),
+        _ => (name, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let r1 = vec![
+            Ok(Record::new("r0/1", "A", "N")),
+            Ok(Record::new("r1/1", "C", "N")),
+        ];
+        let r2 = vec![
+            Ok(Record::new("r0/2", "G", "N")),
+            Ok(Record::new("r1/2", "T", "N")),
+        ];
+
+        let actual: Vec<_> =
+            Interleave::new(r1.into_iter(), r2.into_iter()).collect::<io::Result<_>>()?;
+
+        let expected = [
+            Record::new("r0/1", "A", "N"),
+            Record::new("r0/2", "G", "N"),
+            Record::new("r1/1", "C", "N"),
+            Record::new("r1/2", "T", "N"),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_mismatched_streams() {
+        let r1 = vec![
+            Ok(Record::new("r0", "A", "N")),
+            Ok(Record::new("r1", "C", "N")),
+        ];
+        let r2 = vec![Ok(Record::new("r0", "G", "N"))];
+
+        let mut iter = Interleave::new(r1.into_iter(), r2.into_iter());
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let r1 = vec![Ok(Record::new("r0", "A", "N"))];
+        let r2 = vec![Ok(Record::new("r1", "G", "N"))];
+
+        let mut iter = Interleave::new(r1.into_iter(), r2.into_iter());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_is_mate() {
+        assert!(is_mate(b"r0", b"r0"));
+        assert!(is_mate(b"r0/1", b"r0/2"));
+        assert!(is_mate(b"r0 1:N:0:1", b"r0 2:N:0:1"));
+        assert!(is_mate(b"r0/1 comment", b"r0/2"));
+        assert!(!is_mate(b"r0", b"r1"));
+        assert!(!is_mate(b"r0/1", b"r1/2"));
+        assert!(!is_mate(b"r0/1", b"r0/1"));
+        assert!(!is_mate(b"r0/2", b"r0/2"));
+        assert!(!is_mate(b"r0/2", b"r0/1"));
+        assert!(!is_mate(b"r0/1", b"r0"));
+    }
+}
@@ -31,13 +31,22 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+mod deinterleaver;
 pub mod fai;
+pub mod illumina;
+mod indexed_reader;
 mod indexer;
+mod interleave;
 mod reader;
 mod record;
+pub mod transform;
+pub mod validator;
 mod writer;
 
-pub use self::{indexer::Indexer, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    deinterleaver::Deinterleaver, indexed_reader::IndexedReader, indexer::Indexer,
+    interleave::Interleave, reader::Reader, record::Record, validator::Validator, writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
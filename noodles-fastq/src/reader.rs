@@ -8,10 +8,15 @@ use super::Record;
 
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
+const DESCRIPTION_PREFIX: u8 = b'+';
 
 /// A FASTQ reader.
+///
+/// By default, the reader expects each record to span four lines. To accept records with sequences
+/// and quality scores wrapped over multiple lines, see [`Self::set_multiline`].
 pub struct Reader<R> {
     inner: R,
+    multiline: bool,
 }
 
 impl<R> Reader<R>
@@ -28,7 +33,42 @@ where
     /// let reader = fastq::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            multiline: false,
+        }
+    }
+
+    /// Sets whether records with multiline sequences and quality scores are accepted.
+    ///
+    /// This is disabled by default, i.e., the sequence and quality scores must each be on a single
+    /// line, and a wrapped record is an error.
+    ///
+    /// When enabled, sequence lines are read until the plus line, and quality score lines are read
+    /// until there are as many quality scores as bases. It is an error if the quality scores
+    /// length differs from the sequence length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nAT\nCG\n+\nND\nLS\n";
+    ///
+    /// let mut reader = fastq::Reader::new(&data[..]);
+    /// let mut record = fastq::Record::default();
+    /// assert!(reader.read_record(&mut record).is_err());
+    ///
+    /// let mut reader = fastq::Reader::new(&data[..]);
+    /// reader.set_multiline(true);
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record, fastq::Record::new("r0", "ATCG", "NDLS"));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_multiline(&mut self, multiline: bool) -> &mut Self {
+        self.multiline = multiline;
+        self
     }
 
     /// Returns a reference to the underlying reader.
@@ -101,7 +141,7 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        read_record(&mut self.inner, record, self.multiline)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -132,7 +172,7 @@ where
     }
 }
 
-fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+fn read_record<R>(reader: &mut R, record: &mut Record, multiline: bool) -> io::Result<usize>
 where
     R: BufRead,
 {
@@ -144,13 +184,83 @@ where
         Err(e) => return Err(e),
     };
 
-    len += read_line(reader, record.sequence_mut())?;
+    if multiline {
+        len += read_multiline_sequence(reader, record.sequence_mut())?;
+    } else {
+        len += read_line(reader, record.sequence_mut())?;
+    }
+
     len += read_description(reader, record.description_mut())?;
-    len += read_line(reader, record.quality_scores_mut())?;
+
+    if multiline {
+        let sequence_len = record.sequence().len();
+        len += read_multiline_quality_scores(reader, sequence_len, record.quality_scores_mut())?;
+    } else {
+        len += read_line(reader, record.quality_scores_mut())?;
+    }
+
+    Ok(len)
+}
+
+// Reads sequence lines until the plus line.
+fn read_multiline_sequence<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = read_line(reader, buf)?;
+
+    loop {
+        match reader.fill_buf()?.first() {
+            Some(&DESCRIPTION_PREFIX) | None => break,
+            Some(_) => len += read_line(reader, buf)?,
+        }
+    }
+
+    Ok(len)
+}
+
+// Reads quality score lines until there are as many quality scores as bases.
+//
+// The end of the quality scores cannot be determined by a line prefix, as quality scores may
+// start with `@` or `+`. It is an error if the number of quality scores differs from the number
+// of bases, e.g., when the quality scores are truncated and the next record is read instead.
+fn read_multiline_quality_scores<R>(
+    reader: &mut R,
+    sequence_len: usize,
+    buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = read_line(reader, buf)?;
+
+    while buf.len() < sequence_len {
+        match read_line(reader, buf)? {
+            0 => break,
+            n => len += n,
+        }
+    }
+
+    validate_quality_scores_len(sequence_len, buf)?;
 
     Ok(len)
 }
 
+pub(crate) fn validate_quality_scores_len(sequence_len: usize, buf: &[u8]) -> io::Result<()> {
+    if buf.len() == sequence_len {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "quality scores length mismatch: expected {}, got {}",
+                sequence_len,
+                buf.len()
+            ),
+        ))
+    }
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
@@ -193,8 +303,6 @@ fn read_description<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
 {
-    const DESCRIPTION_PREFIX: u8 = b'+';
-
     match read_u8(reader)? {
         DESCRIPTION_PREFIX => read_line(reader, buf).map(|n| n + 1),
         _ => Err(io::Error::new(
@@ -228,26 +336,84 @@ abcd
 TCGA
 +noodles:2/1
 dcba
+";
+
+        let mut record = Record::default();
+
+        for multiline in [false, true] {
+            let mut reader = &data[..];
+
+            read_record(&mut reader, &mut record, multiline)?;
+            let expected = Record::new("noodles:1/1", "AGCT", "abcd");
+            assert_eq!(record, expected);
+
+            read_record(&mut reader, &mut record, multiline)?;
+            let mut expected = Record::new("noodles:2/1", "TCGA", "dcba");
+            expected.description_mut().extend_from_slice(b"noodles:2/1");
+            assert_eq!(record, expected);
+
+            let n = read_record(&mut reader, &mut record, multiline)?;
+            assert_eq!(n, 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_multiline_record() -> io::Result<()> {
+        let data = b"\
+@r0
+AG
+CT
++
+@+
+cd
+@r1
+
++
+
 ";
 
         let mut reader = &data[..];
         let mut record = Record::default();
 
-        read_record(&mut reader, &mut record)?;
-        let expected = Record::new("noodles:1/1", "AGCT", "abcd");
-        assert_eq!(record, expected);
+        let n = read_record(&mut reader, &mut record, true)?;
+        assert_eq!(n, 18);
+        assert_eq!(record, Record::new("r0", "AGCT", "@+cd"));
 
-        read_record(&mut reader, &mut record)?;
-        let mut expected = Record::new("noodles:2/1", "TCGA", "dcba");
-        expected.description_mut().extend_from_slice(b"noodles:2/1");
-        assert_eq!(record, expected);
+        read_record(&mut reader, &mut record, true)?;
+        assert_eq!(record, Record::new("r1", "", ""));
 
-        let n = read_record(&mut reader, &mut record)?;
-        assert_eq!(n, 0);
+        assert_eq!(read_record(&mut reader, &mut record, true)?, 0);
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
 
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_multiline_record_and_quality_scores_length_mismatch() {
+        let mut record = Record::default();
+
+        let data = b"@r0\nAG\nCT\n+\nND\n";
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"@r0\nAG\nCT\n+\nND\n@r1\nACGT\n+\nNDLS\n";
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = Vec::new();
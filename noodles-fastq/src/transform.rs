@@ -0,0 +1,260 @@
+//! FASTQ record transforms.
+//!
+//! A transform is a map/filter stage that is applied to each record of a stream. Transforms can be
+//! chained using a [`Pipeline`] and applied to a stream of records without buffering it, either
+//! with [`Transformed`] (sync) or [`transform_stream`] (async).
+//!
+//! Quality scores are assumed to be Phred quality scores offset by 33.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::io;
+//! use noodles_fastq::{
+//!     self as fastq,
+//!     transform::{LengthFilter, Pipeline, SlidingWindowTrim, Transformed},
+//! };
+//!
+//! let data = b"@r0\nACGTACGT\n+\nIIIIII##\n@r1\nACGT\n+\n####\n";
+//! let mut reader = fastq::Reader::new(&data[..]);
+//!
+//! let pipeline = Pipeline::new()
+//!     .push(SlidingWindowTrim::new(2, 20))
+//!     .push(LengthFilter::new(2, None));
+//!
+//! let records: Vec<_> = Transformed::new(reader.records(), pipeline).collect::<io::Result<_>>()?;
+//! assert_eq!(records, [fastq::Record::new("r0", "ACGTAC", "IIIIII")]);
+//! # Ok::<_, io::Error>(())
+//! ```
+
+mod length_filter;
+mod sliding_window_trim;
+mod trim;
+
+pub use self::{length_filter::LengthFilter, sliding_window_trim::SlidingWindowTrim, trim::Trim};
+
+#[cfg(feature = "async")]
+use futures::{future, Stream, TryStreamExt};
+
+use std::io;
+
+use super::Record;
+
+const QUALITY_SCORE_OFFSET: u8 = b'!';
+
+/// A record transform.
+pub trait Transform {
+    /// Applies the transform to a record.
+    ///
+    /// This returns `None` if the record is filtered out.
+    fn apply(&self, record: Record) -> Option<Record>;
+}
+
+impl<F> Transform for F
+where
+    F: Fn(Record) -> Option<Record>,
+{
+    fn apply(&self, record: Record) -> Option<Record> {
+        self(record)
+    }
+}
+
+/// A list of transforms that are applied in order.
+#[derive(Default)]
+pub struct Pipeline {
+    transforms: Vec<Box<dyn Transform + Send + Sync>>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::transform::Pipeline;
+    /// let pipeline = Pipeline::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a transform to the end of the pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::transform::{LengthFilter, Pipeline, Trim};
+    /// let pipeline = Pipeline::new().push(Trim::new(1, 1)).push(LengthFilter::new(30, None));
+    /// ```
+    pub fn push<T>(mut self, transform: T) -> Self
+    where
+        T: Transform + Send + Sync + 'static,
+    {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+}
+
+impl Transform for Pipeline {
+    fn apply(&self, record: Record) -> Option<Record> {
+        self.transforms
+            .iter()
+            .try_fold(record, |record, transform| transform.apply(record))
+    }
+}
+
+/// An iterator adapter that applies a transform to each record.
+pub struct Transformed<I, T> {
+    records: I,
+    transform: T,
+}
+
+impl<I, T> Transformed<I, T>
+where
+    I: Iterator<Item = io::Result<Record>>,
+    T: Transform,
+{
+    /// Creates an iterator adapter that applies a transform to each record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, transform::{Trim, Transformed}};
+    /// let mut reader = fastq::Reader::new(&[][..]);
+    /// let records = Transformed::new(reader.records(), Trim::new(1, 0));
+    /// ```
+    pub fn new(records: I, transform: T) -> Self {
+        Self { records, transform }
+    }
+}
+
+impl<I, T> Iterator for Transformed<I, T>
+where
+    I: Iterator<Item = io::Result<Record>>,
+    T: Transform,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next()? {
+                Ok(record) => {
+                    if let Some(record) = self.transform.apply(record) {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Applies a transform to each record of an async stream.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() -> std::io::Result<()> {
+/// use futures::TryStreamExt;
+/// use noodles_fastq::{self as fastq, transform::{self, Trim}};
+///
+/// let data = b"@r0\nACGT\n+\nNDLS\n";
+/// let mut reader = fastq::AsyncReader::new(&data[..]);
+///
+/// let records: Vec<_> = transform::transform_stream(reader.records(), Trim::new(1, 1))
+///     .try_collect()
+///     .await?;
+///
+/// assert_eq!(records, [fastq::Record::new("r0", "CG", "DL")]);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub fn transform_stream<S, T>(stream: S, transform: T) -> impl Stream<Item = io::Result<Record>>
+where
+    S: Stream<Item = io::Result<Record>>,
+    T: Transform,
+{
+    stream.try_filter_map(move |record| future::ready(Ok(transform.apply(record))))
+}
+
+// Keeps the bases and quality scores in the given range.
+fn truncate(mut record: Record, start: usize, end: usize) -> Record {
+    fn slice(buf: &mut Vec<u8>, start: usize, end: usize) {
+        let end = end.min(buf.len());
+        let start = start.min(end);
+        buf.truncate(end);
+        buf.drain(..start);
+    }
+
+    slice(record.sequence_mut(), start, end);
+    slice(record.quality_scores_mut(), start, end);
+
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline() {
+        let pipeline = Pipeline::new()
+            .push(Trim::new(1, 0))
+            .push(|record: Record| {
+                if record.sequence().starts_with(b"C") {
+                    Some(record)
+                } else {
+                    None
+                }
+            });
+
+        let record = Record::new("r0", "ACGT", "NDLS");
+        assert_eq!(
+            pipeline.apply(record),
+            Some(Record::new("r0", "CGT", "DLS"))
+        );
+
+        let record = Record::new("r1", "AAGT", "NDLS");
+        assert!(pipeline.apply(record).is_none());
+
+        let record = Record::new("r2", "ACGT", "NDLS");
+        assert_eq!(Pipeline::new().apply(record.clone()), Some(record));
+    }
+
+    #[test]
+    fn test_transformed() -> io::Result<()> {
+        let records = vec![
+            Ok(Record::new("r0", "ACGT", "NDLS")),
+            Ok(Record::new("r1", "A", "N")),
+            Ok(Record::new("r2", "AC", "ND")),
+        ];
+
+        let actual: Vec<_> = Transformed::new(records.into_iter(), LengthFilter::new(2, None))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            Record::new("r0", "ACGT", "NDLS"),
+            Record::new("r2", "AC", "ND"),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate() {
+        let record = Record::new("r0", "ACGT", "NDLS");
+        assert_eq!(
+            truncate(record.clone(), 1, 3),
+            Record::new("r0", "CG", "DL")
+        );
+        assert_eq!(
+            truncate(record.clone(), 2, 8),
+            Record::new("r0", "GT", "LS")
+        );
+        assert_eq!(truncate(record, 3, 1), Record::new("r0", "", ""));
+    }
+}
@@ -0,0 +1,60 @@
+use super::Transform;
+use crate::Record;
+
+/// A sequence length filter.
+///
+/// This drops records with sequences shorter than the minimum length or longer than the maximum
+/// length.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LengthFilter {
+    min_len: usize,
+    max_len: Option<usize>,
+}
+
+impl LengthFilter {
+    /// Creates a sequence length filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, transform::{LengthFilter, Transform}};
+    ///
+    /// let filter = LengthFilter::new(2, Some(3));
+    ///
+    /// assert!(filter.apply(fastq::Record::new("r0", "A", "N")).is_none());
+    /// assert!(filter.apply(fastq::Record::new("r0", "ACG", "NDL")).is_some());
+    /// assert!(filter.apply(fastq::Record::new("r0", "ACGT", "NDLS")).is_none());
+    /// ```
+    pub fn new(min_len: usize, max_len: Option<usize>) -> Self {
+        Self { min_len, max_len }
+    }
+}
+
+impl Transform for LengthFilter {
+    fn apply(&self, record: Record) -> Option<Record> {
+        let len = record.sequence().len();
+
+        if len < self.min_len || self.max_len.map(|max_len| len > max_len).unwrap_or(false) {
+            None
+        } else {
+            Some(record)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let record = Record::new("r0", "ACGT", "NDLS");
+
+        assert!(LengthFilter::new(0, None).apply(record.clone()).is_some());
+        assert!(LengthFilter::new(4, Some(4))
+            .apply(record.clone())
+            .is_some());
+        assert!(LengthFilter::new(5, None).apply(record.clone()).is_none());
+        assert!(LengthFilter::new(0, Some(3)).apply(record).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use super::{truncate, Transform, QUALITY_SCORE_OFFSET};
+use crate::Record;
+
+/// A sliding window quality trim.
+///
+/// This scans a window over the quality scores from the start (5') of the record and cuts the
+/// record at the first window whose average quality score is below the minimum. This is similar
+/// to the `SLIDINGWINDOW` step in Trimmomatic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SlidingWindowTrim {
+    window_size: usize,
+    min_quality_score: u8,
+}
+
+impl SlidingWindowTrim {
+    /// Creates a sliding window quality trim.
+    ///
+    /// The minimum quality score is a Phred quality score, i.e., it is not offset by 33. A window
+    /// size of 0 is treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, transform::{SlidingWindowTrim, Transform}};
+    ///
+    /// let trim = SlidingWindowTrim::new(2, 20);
+    ///
+    /// let record = fastq::Record::new("r0", "ACGTAC", "IIII##");
+    /// assert_eq!(trim.apply(record), Some(fastq::Record::new("r0", "ACGT", "IIII")));
+    /// ```
+    pub fn new(window_size: usize, min_quality_score: u8) -> Self {
+        Self {
+            window_size: window_size.max(1),
+            min_quality_score,
+        }
+    }
+}
+
+impl Transform for SlidingWindowTrim {
+    fn apply(&self, record: Record) -> Option<Record> {
+        let end = find_cut(
+            record.quality_scores(),
+            self.window_size,
+            self.min_quality_score,
+        );
+
+        Some(truncate(record, 0, end))
+    }
+}
+
+// Returns the end position of the bases to keep.
+fn find_cut(quality_scores: &[u8], window_size: usize, min_quality_score: u8) -> usize {
+    let scores: Vec<u64> = quality_scores
+        .iter()
+        .map(|&b| u64::from(b.saturating_sub(QUALITY_SCORE_OFFSET)))
+        .collect();
+
+    let window_size = window_size.min(scores.len());
+
+    if window_size == 0 {
+        return 0;
+    }
+
+    let min_sum = u64::from(min_quality_score) * window_size as u64;
+    let mut sum: u64 = scores[..window_size].iter().sum();
+
+    for start in 0..=(scores.len() - window_size) {
+        if start > 0 {
+            sum = sum - scores[start - 1] + scores[start + window_size - 1];
+        }
+
+        if sum < min_sum {
+            // Keep the leading bases of the window that individually pass.
+            let offset = scores[start..start + window_size]
+                .iter()
+                .position(|&score| score < u64::from(min_quality_score))
+                .unwrap_or(0);
+
+            return start + offset;
+        }
+    }
+
+    scores.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cut() {
+        // '5' = 20, '+' = 10, 'I' = 40, '#' = 2
+        assert_eq!(find_cut(b"", 4, 20), 0);
+        assert_eq!(find_cut(b"IIII", 4, 20), 4);
+        assert_eq!(find_cut(b"IIII####", 4, 20), 4);
+        assert_eq!(find_cut(b"##II", 2, 20), 0);
+        assert_eq!(find_cut(b"II+5+5", 2, 20), 2);
+        assert_eq!(find_cut(b"I#", 4, 20), 2);
+        assert_eq!(find_cut(b"5555", 1, 20), 4);
+    }
+}
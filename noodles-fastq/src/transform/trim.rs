@@ -0,0 +1,62 @@
+use super::{truncate, Transform};
+use crate::Record;
+
+/// A fixed-length trim.
+///
+/// This removes a fixed number of bases (and their quality scores) from the start (5') and end
+/// (3') of each record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Trim {
+    start: usize,
+    end: usize,
+}
+
+impl Trim {
+    /// Creates a fixed-length trim.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, transform::{Transform, Trim}};
+    ///
+    /// let trim = Trim::new(1, 2);
+    ///
+    /// let record = fastq::Record::new("r0", "ACGTA", "NDLSN");
+    /// assert_eq!(trim.apply(record), Some(fastq::Record::new("r0", "CG", "DL")));
+    /// ```
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Transform for Trim {
+    fn apply(&self, record: Record) -> Option<Record> {
+        let len = record.sequence().len();
+        let end = len.saturating_sub(self.end);
+        Some(truncate(record, self.start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let record = Record::new("r0", "ACGT", "NDLS");
+
+        assert_eq!(Trim::new(0, 0).apply(record.clone()), Some(record.clone()));
+        assert_eq!(
+            Trim::new(2, 0).apply(record.clone()),
+            Some(Record::new("r0", "GT", "LS"))
+        );
+        assert_eq!(
+            Trim::new(0, 1).apply(record.clone()),
+            Some(Record::new("r0", "ACG", "NDL"))
+        );
+        assert_eq!(
+            Trim::new(3, 3).apply(record),
+            Some(Record::new("r0", "", ""))
+        );
+    }
+}
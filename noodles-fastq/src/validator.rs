@@ -0,0 +1,231 @@
+//! FASTQ record validator.
+
+mod alphabet;
+mod error;
+
+pub use self::{
+    alphabet::Alphabet,
+    error::{ValidationError, ValidationErrorKind},
+};
+
+use std::ops::RangeInclusive;
+
+use super::Record;
+
+const LINES_PER_RECORD: u64 = 4;
+
+const SEQUENCE_LINE: u64 = 2;
+const DESCRIPTION_LINE: u64 = 3;
+const QUALITY_SCORES_LINE: u64 = 4;
+
+/// A FASTQ record validator.
+///
+/// The validator checks that
+///
+///   * the sequence and quality scores have the same length,
+///   * each base is in the allowed alphabet,
+///   * each quality score (the raw ASCII value) is in the allowed range, and
+///   * (optionally) the description (line 3) is either empty or repeats the read name.
+///
+/// Records are expected to be validated in stream order. Error positions are reported as 1-based
+/// line and column numbers, assuming each record spans four lines. Positions are incorrect for
+/// multiline records (see [`crate::Reader::set_multiline`]).
+#[derive(Clone, Debug)]
+pub struct Validator {
+    alphabet: Alphabet,
+    quality_score_range: RangeInclusive<u8>,
+    validate_description: bool,
+    record_count: u64,
+}
+
+impl Validator {
+    /// Creates a FASTQ record validator with the default settings.
+    ///
+    /// By default, bases are validated using the IUPAC alphabet; quality scores, the printable
+    /// ASCII range (`!`..=`~`); and the description is not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let validator = fastq::Validator::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the allowed alphabet of bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, validator::Alphabet};
+    /// let validator = fastq::Validator::new().set_alphabet(Alphabet::Dna);
+    /// ```
+    pub fn set_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Sets the allowed range of raw quality score values.
+    ///
+    /// For example, Phred+33 scores from 0 to 41 are `b'!'..=b'J'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let validator = fastq::Validator::new().set_quality_score_range(b'!'..=b'J');
+    /// ```
+    pub fn set_quality_score_range(mut self, range: RangeInclusive<u8>) -> Self {
+        self.quality_score_range = range;
+        self
+    }
+
+    /// Sets whether the description (line 3) is validated.
+    ///
+    /// When enabled, a nonempty description must be the same as the read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let validator = fastq::Validator::new().set_validate_description(true);
+    /// ```
+    pub fn set_validate_description(mut self, validate_description: bool) -> Self {
+        self.validate_description = validate_description;
+        self
+    }
+
+    /// Validates the next record in the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, validator::ValidationErrorKind};
+    ///
+    /// let mut validator = fastq::Validator::new();
+    ///
+    /// let record = fastq::Record::new("r0", "ACGT", "NDLS");
+    /// assert!(validator.validate(&record).is_ok());
+    ///
+    /// let record = fastq::Record::new("r1", "AC!T", "NDLS");
+    /// let e = validator.validate(&record).unwrap_err();
+    /// assert_eq!(e.kind(), &ValidationErrorKind::InvalidBase(b'!'));
+    /// assert_eq!((e.line(), e.column()), (6, 3));
+    /// ```
+    pub fn validate(&mut self, record: &Record) -> Result<(), ValidationError> {
+        let start_line = self.record_count * LINES_PER_RECORD;
+        self.record_count += 1;
+
+        let error = |line, column, kind| ValidationError::new(kind, start_line + line, column);
+
+        if let Some(i) = record
+            .sequence()
+            .iter()
+            .position(|&b| !self.alphabet.contains(b))
+        {
+            let kind = ValidationErrorKind::InvalidBase(record.sequence()[i]);
+            return Err(error(SEQUENCE_LINE, i + 1, kind));
+        }
+
+        if self.validate_description
+            && !record.description().is_empty()
+            && record.description() != record.name()
+        {
+            // The column skips the description prefix (`+`).
+            return Err(error(
+                DESCRIPTION_LINE,
+                2,
+                ValidationErrorKind::DescriptionMismatch,
+            ));
+        }
+
+        if let Some(i) = record
+            .quality_scores()
+            .iter()
+            .position(|b| !self.quality_score_range.contains(b))
+        {
+            let kind = ValidationErrorKind::InvalidQualityScore(record.quality_scores()[i]);
+            return Err(error(QUALITY_SCORES_LINE, i + 1, kind));
+        }
+
+        let sequence_len = record.sequence().len();
+        let quality_scores_len = record.quality_scores().len();
+
+        if sequence_len != quality_scores_len {
+            let column = sequence_len.min(quality_scores_len) + 1;
+
+            let kind = ValidationErrorKind::LengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            };
+
+            return Err(error(QUALITY_SCORES_LINE, column, kind));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self {
+            alphabet: Alphabet::default(),
+            quality_score_range: b'!'..=b'~',
+            validate_description: false,
+            record_count: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let mut validator = Validator::new()
+            .set_alphabet(Alphabet::Dna)
+            .set_quality_score_range(b'!'..=b'J')
+            .set_validate_description(true);
+
+        assert!(validator
+            .validate(&Record::new("r0", "ACGTN", "!!!!J"))
+            .is_ok());
+
+        let e = validator
+            .validate(&Record::new("r1", "ACRT", "!!!!"))
+            .unwrap_err();
+        assert_eq!(e.kind(), &ValidationErrorKind::InvalidBase(b'R'));
+        assert_eq!((e.line(), e.column()), (6, 3));
+
+        let e = validator
+            .validate(&Record::new("r2", "ACGT", "!!K!"))
+            .unwrap_err();
+        assert_eq!(e.kind(), &ValidationErrorKind::InvalidQualityScore(b'K'));
+        assert_eq!((e.line(), e.column()), (12, 3));
+
+        let e = validator
+            .validate(&Record::new("r3", "ACGT", "!!!"))
+            .unwrap_err();
+        assert_eq!(
+            e.kind(),
+            &ValidationErrorKind::LengthMismatch {
+                sequence_len: 4,
+                quality_scores_len: 3
+            }
+        );
+        assert_eq!((e.line(), e.column()), (16, 4));
+
+        let mut record = Record::new("r4", "ACGT", "!!!!");
+        record.description_mut().extend_from_slice(b"r4");
+        assert!(validator.validate(&record).is_ok());
+
+        let mut record = Record::new("r5", "ACGT", "!!!!");
+        record.description_mut().extend_from_slice(b"r0");
+        let e = validator.validate(&record).unwrap_err();
+        assert_eq!(e.kind(), &ValidationErrorKind::DescriptionMismatch);
+        assert_eq!((e.line(), e.column()), (23, 2));
+    }
+}
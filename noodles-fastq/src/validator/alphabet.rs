@@ -0,0 +1,76 @@
+/// An alphabet of allowed bases.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Alphabet {
+    /// `A`, `C`, `G`, `T`, and `N` (case-insensitive).
+    Dna,
+    /// IUPAC nucleotide codes, including `U`, `.`, and `-` (case-insensitive).
+    Iupac,
+    /// Any byte.
+    Any,
+}
+
+impl Alphabet {
+    /// Returns whether the given base is in the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::validator::Alphabet;
+    ///
+    /// assert!(Alphabet::Dna.contains(b'a'));
+    /// assert!(!Alphabet::Dna.contains(b'R'));
+    /// assert!(Alphabet::Iupac.contains(b'R'));
+    /// ```
+    pub fn contains(&self, b: u8) -> bool {
+        match self {
+            Self::Dna => matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T' | b'N'),
+            Self::Iupac => matches!(
+                b.to_ascii_uppercase(),
+                b'A' | b'C'
+                    | b'G'
+                    | b'T'
+                    | b'U'
+                    | b'R'
+                    | b'Y'
+                    | b'S'
+                    | b'W'
+                    | b'K'
+                    | b'M'
+                    | b'B'
+                    | b'D'
+                    | b'H'
+                    | b'V'
+                    | b'N'
+                    | b'.'
+                    | b'-'
+            ),
+            Self::Any => true,
+        }
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::Iupac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        for &b in b"ACGTNacgtn" {
+            assert!(Alphabet::Dna.contains(b));
+        }
+
+        for &b in b"URYSWKMBDHV.-" {
+            assert!(!Alphabet::Dna.contains(b));
+            assert!(Alphabet::Iupac.contains(b));
+        }
+
+        assert!(!Alphabet::Iupac.contains(b'X'));
+        assert!(Alphabet::Any.contains(b'X'));
+    }
+}
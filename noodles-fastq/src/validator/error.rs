@@ -0,0 +1,98 @@
+use std::{error, fmt};
+
+/// The kind of a validation error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationErrorKind {
+    /// A base is not in the allowed alphabet.
+    InvalidBase(u8),
+    /// A quality score is not in the allowed range.
+    InvalidQualityScore(u8),
+    /// The sequence and quality scores lengths differ.
+    LengthMismatch {
+        /// The sequence length.
+        sequence_len: usize,
+        /// The quality scores length.
+        quality_scores_len: usize,
+    },
+    /// The description is not the same as the read name.
+    DescriptionMismatch,
+}
+
+/// An error returned when a FASTQ record fails validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    kind: ValidationErrorKind,
+    line: u64,
+    column: usize,
+}
+
+impl ValidationError {
+    pub(super) fn new(kind: ValidationErrorKind, line: u64, column: usize) -> Self {
+        Self { kind, line, column }
+    }
+
+    /// Returns the kind of error.
+    pub fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+
+    /// Returns the 1-based line number of the error.
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the 1-based column number of the error.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+
+        match &self.kind {
+            ValidationErrorKind::InvalidBase(b) => write!(f, "invalid base: {:#04x}", b),
+            ValidationErrorKind::InvalidQualityScore(b) => {
+                write!(f, "invalid quality score: {:#04x}", b)
+            }
+            ValidationErrorKind::LengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            } => write!(
+                f,
+                "sequence length ({}) != quality scores length ({})",
+                sequence_len, quality_scores_len
+            ),
+            ValidationErrorKind::DescriptionMismatch => {
+                f.write_str("description does not match name")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let error = ValidationError::new(ValidationErrorKind::InvalidBase(b'!'), 2, 3);
+        assert_eq!(error.to_string(), "2:3: invalid base: 0x21");
+
+        let error = ValidationError::new(
+            ValidationErrorKind::LengthMismatch {
+                sequence_len: 4,
+                quality_scores_len: 3,
+            },
+            4,
+            4,
+        );
+        assert_eq!(
+            error.to_string(),
+            "4:4: sequence length (4) != quality scores length (3)"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use futures::{stream, Stream, TryStreamExt};
+use tokio::io::{self, AsyncBufRead, AsyncBufReadExt};
+
+use crate::{Directive, Line, Record};
+
+const LINE_FEED: char = '\n';
+const CARRIAGE_RETURN: char = '\r';
+
+/// An async GFF reader.
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Creates an async GFF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let data = [];
+    /// let reader = gff::AsyncReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let data = [];
+    /// let reader = gff::AsyncReader::new(&data[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let data = [];
+    /// let mut reader = gff::AsyncReader::new(&data[..]);
+    /// assert!(reader.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let data = [];
+    /// let reader = gff::AsyncReader::new(&data[..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads a raw GFF line.
+    ///
+    /// This reads from the underlying stream until a newline is reached and appends it to the
+    /// given buffer, sans the final newline character. The buffer can subsequently be parsed as a
+    /// [`crate::Line`].
+    ///
+    /// It is more ergonomic to read records using an iterator (see [`Self::lines`]), but using
+    /// this method allows control of the line buffer and whether the raw line should be parsed.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ";
+    /// let mut reader = gff::AsyncReader::new(&data[..]);
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_line(&mut buf).await?;
+    /// assert_eq!(buf, "##gff-version 3");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        read_line(&mut self.inner, buf).await
+    }
+
+    /// Returns a stream over lines starting from the current stream position.
+    ///
+    /// When using this, the caller is responsible to stop reading at either EOF or when the
+    /// `FASTA` directive is read, whichever comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ";
+    /// let mut reader = gff::AsyncReader::new(&data[..]);
+    /// let mut lines = reader.lines();
+    ///
+    /// let line = lines.try_next().await?;
+    /// assert!(matches!(line, Some(gff::Line::Directive(_))));
+    ///
+    /// let line = lines.try_next().await?;
+    /// assert!(matches!(line, Some(gff::Line::Record(_))));
+    ///
+    /// assert!(lines.try_next().await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lines(&mut self) -> impl Stream<Item = io::Result<Line>> + '_ {
+        Box::pin(stream::try_unfold(
+            (&mut self.inner, String::new()),
+            |(mut reader, mut buf)| async {
+                buf.clear();
+
+                match read_line(&mut reader, &mut buf).await? {
+                    0 => Ok(None),
+                    _ => buf
+                        .parse()
+                        .map(|line| Some((line, (reader, buf))))
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                }
+            },
+        ))
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    ///
+    /// The stream ends at either EOF or when the `FASTA` directive is read, whichever comes
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ";
+    /// let mut reader = gff::AsyncReader::new(&data[..]);
+    /// let mut records = reader.records();
+    ///
+    /// let record = records.try_next().await?;
+    /// assert!(record.is_some());
+    ///
+    /// assert!(records.try_next().await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        Box::pin(
+            self.lines()
+                .try_take_while(|line| {
+                    futures::future::ready(Ok(!matches!(
+                        line,
+                        Line::Directive(Directive::StartOfFasta)
+                    )))
+                })
+                .try_filter_map(|line| {
+                    futures::future::ready(Ok(match line {
+                        Line::Record(record) => Some(record),
+                        _ => None,
+                    }))
+                }),
+        )
+    }
+}
+
+async fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    match reader.read_line(buf).await? {
+        0 => Ok(0),
+        n => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_records_with_fasta_directive() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records().try_collect().await?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_line() -> io::Result<()> {
+        async fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
+            buf.clear();
+            read_line(&mut reader, buf).await?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = String::new();
+
+        t(&mut buf, b"noodles\n", "noodles").await?;
+        t(&mut buf, b"noodles\r\n", "noodles").await?;
+        t(&mut buf, b"noodles", "noodles").await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+use crate::{Directive, Record};
+
+/// An async GFF writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async GFF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let writer = gff::AsyncWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let writer = gff::AsyncWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let writer = gff::AsyncWriter::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes a GFF directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_gff as gff;
+    ///
+    /// let mut writer = gff::AsyncWriter::new(Vec::new());
+    ///
+    /// let version = gff::Directive::GffVersion(Default::default());
+    /// writer.write_directive(&version).await?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"##gff-version 3\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_directive(&mut self, directive: &Directive) -> io::Result<()> {
+        let s = format!("{}\n", directive);
+        self.inner.write_all(s.as_bytes()).await
+    }
+
+    /// Writes a GFF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_gff as gff;
+    ///
+    /// let mut writer = gff::AsyncWriter::new(Vec::new());
+    ///
+    /// let record = gff::Record::default();
+    /// writer.write_record(&record).await?;
+    ///
+    /// assert_eq!(writer.get_ref(), b".\t.\t.\t1\t1\t.\t.\t.\t.\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let s = format!("{}\n", record);
+        self.inner.write_all(s.as_bytes()).await
+    }
+
+    /// Shuts down the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_gff as gff;
+    /// let mut writer = gff::AsyncWriter::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
+    }
+}
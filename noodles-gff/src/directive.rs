@@ -36,6 +36,11 @@ pub enum Directive {
     /// A marker indicating the end of the records list and start of a bundled reference sequences
     /// (`FASTA`).
     StartOfFasta,
+    /// Any other directive.
+    ///
+    /// This is a directive that is not defined in the GFF3 specification, e.g., a
+    /// program-specific directive. It holds the name and, optionally, the value.
+    Other(String, Option<String>),
 }
 
 impl fmt::Display for Directive {
@@ -50,6 +55,15 @@ impl fmt::Display for Directive {
             Self::GenomeBuild(genome_build) => write!(f, "{}", genome_build),
             Self::ForwardReferencesAreResolved => write!(f, "{}#", PREFIX),
             Self::StartOfFasta => write!(f, "{}FASTA", PREFIX),
+            Self::Other(name, value) => {
+                write!(f, "{}{}", PREFIX, name)?;
+
+                if let Some(value) = value {
+                    write!(f, " {}", value)?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -62,6 +76,10 @@ pub enum ParseError {
     /// The directive name is missing.
     MissingName,
     /// The directive name is invalid.
+    #[deprecated(
+        since = "0.7.0",
+        note = "Unknown directives are parsed as `Directive::Other`."
+    )]
     InvalidName(String),
     /// The directive value is missing.
     MissingValue,
@@ -80,6 +98,7 @@ impl fmt::Display for ParseError {
         match self {
             Self::MissingPrefix => f.write_str("directive prefix is missing"),
             Self::MissingName => f.write_str("directive name is missing"),
+            #[allow(deprecated)]
             Self::InvalidName(s) => write!(f, "invalid directive name: {}", s),
             Self::MissingValue => f.write_str("directive value is missing"),
             Self::InvalidGffVersion(e) => write!(f, "{}", e),
@@ -99,7 +118,10 @@ impl FromStr for Directive {
 
         let mut components = s[PREFIX.len()..].splitn(2, |c: char| c.is_ascii_whitespace());
 
-        let name = components.next().ok_or(ParseError::MissingName)?;
+        let name = components
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(ParseError::MissingName)?;
 
         match name {
             "gff-version" => components
@@ -135,7 +157,10 @@ impl FromStr for Directive {
                 .map(Self::GenomeBuild),
             "#" => Ok(Self::ForwardReferencesAreResolved),
             "FASTA" => Ok(Self::StartOfFasta),
-            _ => Err(ParseError::InvalidName(name.into())),
+            _ => Ok(Self::Other(
+                name.into(),
+                components.next().map(String::from),
+            )),
         }
     }
 }
@@ -180,5 +205,60 @@ mod tests {
 
         assert_eq!(Directive::ForwardReferencesAreResolved.to_string(), "###");
         assert_eq!(Directive::StartOfFasta.to_string(), "##FASTA");
+
+        assert_eq!(
+            Directive::Other(String::from("noodles"), None).to_string(),
+            "##noodles"
+        );
+        assert_eq!(
+            Directive::Other(String::from("noodles"), Some(String::from("0.1.0 x"))).to_string(),
+            "##noodles 0.1.0 x"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "##gff-version 3".parse(),
+            Ok(Directive::GffVersion(GffVersion::default()))
+        );
+        assert_eq!(
+            "##sequence-region sq0 8 13".parse(),
+            Ok(Directive::SequenceRegion(SequenceRegion::new(
+                String::from("sq0"),
+                8,
+                13
+            )))
+        );
+        assert_eq!(
+            "##species https://example.com/species?id=1".parse(),
+            Ok(Directive::Species(String::from(
+                "https://example.com/species?id=1"
+            )))
+        );
+        assert_eq!("###".parse(), Ok(Directive::ForwardReferencesAreResolved));
+        assert_eq!("##FASTA".parse(), Ok(Directive::StartOfFasta));
+
+        assert_eq!(
+            "##noodles".parse(),
+            Ok(Directive::Other(String::from("noodles"), None))
+        );
+        assert_eq!(
+            "##noodles 0.1.0 x".parse(),
+            Ok(Directive::Other(
+                String::from("noodles"),
+                Some(String::from("0.1.0 x"))
+            ))
+        );
+
+        assert_eq!(
+            "#noodles".parse::<Directive>(),
+            Err(ParseError::MissingPrefix)
+        );
+        assert_eq!("##".parse::<Directive>(), Err(ParseError::MissingName));
+        assert_eq!(
+            "##species".parse::<Directive>(),
+            Err(ParseError::MissingValue)
+        );
     }
 }
@@ -0,0 +1,513 @@
+//! GFF feature hierarchy.
+//!
+//! A feature hierarchy is a graph of features linked by their `ID` and `Parent` attributes, e.g.,
+//! gene → mRNA → exon/CDS.
+//!
+//! Records that share the same `ID` are parts of a single discontinuous feature, e.g., a CDS that
+//! spans multiple exons. Records without an `ID` are each a separate feature. A feature can have
+//! multiple parents, e.g., an exon shared by multiple transcripts.
+//!
+//! A feature cannot be its own ancestor. Building a hierarchy with a cycle, e.g., a record whose
+//! `Parent` is its own `ID`, fails.
+
+use std::{collections::HashMap, error, fmt};
+
+use super::Record;
+
+const ID: &str = "ID";
+const PARENT: &str = "Parent";
+
+/// A feature in a hierarchy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature {
+    id: Option<String>,
+    records: Vec<Record>,
+    parents: Vec<usize>,
+    children: Vec<usize>,
+}
+
+impl Feature {
+    /// Returns the feature ID, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let gene = hierarchy.get("gene0").unwrap();
+    /// assert_eq!(gene.id(), Some("gene0"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the records of the feature.
+    ///
+    /// This is typically a single record. A discontinuous feature has one record per part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let gene = hierarchy.get("gene0").unwrap();
+    /// assert_eq!(gene.records().len(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the feature type.
+    ///
+    /// This is the type of the first record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let tx = hierarchy.get("tx0").unwrap();
+    /// assert_eq!(tx.ty(), "mRNA");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ty(&self) -> &str {
+        self.records[0].ty()
+    }
+}
+
+/// A GFF feature hierarchy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hierarchy {
+    features: Vec<Feature>,
+    ids: HashMap<String, usize>,
+}
+
+impl Hierarchy {
+    /// Builds a feature hierarchy from a list of records.
+    ///
+    /// Parent references are resolved after all records are read, so parents are not required to
+    /// be defined before their children.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a `Parent` references an undefined `ID` or if a feature is its own
+    /// ancestor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    ///     "sq0\t.\texon\t1\t3\t.\t+\t.\tParent=tx0",
+    ///     "sq0\t.\texon\t6\t8\t.\t+\t.\tParent=tx0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    ///
+    /// let gene = hierarchy.roots().next().unwrap();
+    /// assert_eq!(gene.id(), Some("gene0"));
+    ///
+    /// let tx = hierarchy.children(gene).next().unwrap();
+    /// assert_eq!(tx.ty(), "mRNA");
+    /// assert_eq!(hierarchy.children(tx).count(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build<I>(records: I) -> Result<Self, BuildError>
+    where
+        I: IntoIterator<Item = Record>,
+    {
+        let mut hierarchy = Self::default();
+        let mut parent_ids: Vec<Vec<String>> = Vec::new();
+
+        for record in records {
+            let id = record
+                .attributes()
+                .get(ID)
+                .map(|entry| entry.value().to_string());
+
+            let record_parent_ids: Vec<String> = record
+                .attributes()
+                .get(PARENT)
+                .map(|entry| entry.values().to_vec())
+                .unwrap_or_default();
+
+            if let Some(&i) = id.as_ref().and_then(|id| hierarchy.ids.get(id)) {
+                hierarchy.features[i].records.push(record);
+
+                for parent_id in record_parent_ids {
+                    if !parent_ids[i].contains(&parent_id) {
+                        parent_ids[i].push(parent_id);
+                    }
+                }
+
+                continue;
+            }
+
+            let i = hierarchy.features.len();
+
+            if let Some(id) = id.clone() {
+                hierarchy.ids.insert(id, i);
+            }
+
+            hierarchy.features.push(Feature {
+                id,
+                records: vec![record],
+                parents: Vec::new(),
+                children: Vec::new(),
+            });
+
+            parent_ids.push(record_parent_ids);
+        }
+
+        for (i, ids) in parent_ids.into_iter().enumerate() {
+            for parent_id in ids {
+                let j = hierarchy
+                    .ids
+                    .get(&parent_id)
+                    .copied()
+                    .ok_or(BuildError::MissingParent(parent_id))?;
+
+                hierarchy.features[i].parents.push(j);
+                hierarchy.features[j].children.push(i);
+            }
+        }
+
+        hierarchy.check_cycles()?;
+
+        Ok(hierarchy)
+    }
+
+    // Ensures no feature is its own ancestor, e.g., by a self-referencing `Parent`.
+    fn check_cycles(&self) -> Result<(), BuildError> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            Visiting,
+            Visited,
+        }
+
+        let mut states = vec![State::Unvisited; self.features.len()];
+        let mut stack = Vec::new();
+
+        for start in 0..self.features.len() {
+            if states[start] != State::Unvisited {
+                continue;
+            }
+
+            states[start] = State::Visiting;
+            stack.push((start, 0));
+
+            while let Some((i, k)) = stack.last_mut() {
+                let i = *i;
+
+                if let Some(&j) = self.features[i].children.get(*k) {
+                    *k += 1;
+
+                    match states[j] {
+                        State::Unvisited => {
+                            states[j] = State::Visiting;
+                            stack.push((j, 0));
+                        }
+                        State::Visiting => {
+                            let id = self.features[j].id.clone().unwrap_or_default();
+                            return Err(BuildError::Cycle(id));
+                        }
+                        State::Visited => {}
+                    }
+                } else {
+                    states[i] = State::Visited;
+                    stack.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// assert_eq!(hierarchy.len(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns whether there are no features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::hierarchy::Hierarchy;
+    ///
+    /// let hierarchy = Hierarchy::default();
+    /// assert!(hierarchy.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Returns the feature with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// assert!(hierarchy.get("tx0").is_some());
+    /// assert!(hierarchy.get("tx1").is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, id: &str) -> Option<&Feature> {
+        self.ids.get(id).map(|&i| &self.features[i])
+    }
+
+    /// Returns an iterator over all features in input order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let ids: Vec<_> = hierarchy.features().filter_map(|f| f.id()).collect();
+    /// assert_eq!(ids, ["gene0", "tx0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn features(&self) -> impl Iterator<Item = &Feature> {
+        self.features.iter()
+    }
+
+    /// Returns an iterator over features without parents, e.g., genes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let ids: Vec<_> = hierarchy.roots().filter_map(|f| f.id()).collect();
+    /// assert_eq!(ids, ["gene0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn roots(&self) -> impl Iterator<Item = &Feature> {
+        self.features
+            .iter()
+            .filter(|feature| feature.parents.is_empty())
+    }
+
+    /// Returns an iterator over the parents of the given feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let tx = hierarchy.get("tx0").unwrap();
+    /// let ids: Vec<_> = hierarchy.parents(tx).filter_map(|f| f.id()).collect();
+    /// assert_eq!(ids, ["gene0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parents<'a>(&'a self, feature: &'a Feature) -> impl Iterator<Item = &'a Feature> {
+        feature.parents.iter().map(move |&i| &self.features[i])
+    }
+
+    /// Returns an iterator over the children of the given feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Hierarchy};
+    ///
+    /// let records = [
+    ///     "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+    ///     "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+    /// ]
+    /// .iter()
+    /// .map(|s| s.parse())
+    /// .collect::<Result<Vec<gff::Record>, _>>()?;
+    ///
+    /// let hierarchy = Hierarchy::build(records)?;
+    /// let gene = hierarchy.get("gene0").unwrap();
+    /// let ids: Vec<_> = hierarchy.children(gene).filter_map(|f| f.id()).collect();
+    /// assert_eq!(ids, ["tx0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn children<'a>(&'a self, feature: &'a Feature) -> impl Iterator<Item = &'a Feature> {
+        feature.children.iter().map(move |&i| &self.features[i])
+    }
+}
+
+/// An error returned when a feature hierarchy fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A parent ID does not reference a feature.
+    MissingParent(String),
+    /// A feature is its own ancestor.
+    Cycle(String),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingParent(id) => write!(f, "missing parent: {}", id),
+            Self::Cycle(id) => write!(f, "cycle: {}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_records(lines: &[&str]) -> Vec<Record> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let records = parse_records(&[
+            "sq0\t.\texon\t1\t3\t.\t+\t.\tID=exon0;Parent=tx0,tx1",
+            "sq0\t.\tgene\t1\t20\t.\t+\t.\tID=gene0",
+            "sq0\t.\tmRNA\t1\t20\t.\t+\t.\tID=tx0;Parent=gene0",
+            "sq0\t.\tmRNA\t1\t20\t.\t+\t.\tID=tx1;Parent=gene0",
+            "sq0\t.\tCDS\t2\t3\t.\t+\t0\tID=cds0;Parent=tx0",
+            "sq0\t.\tCDS\t8\t10\t.\t+\t2\tID=cds0;Parent=tx0",
+        ]);
+
+        let hierarchy = Hierarchy::build(records)?;
+        assert_eq!(hierarchy.len(), 5);
+
+        let roots: Vec<_> = hierarchy.roots().filter_map(|f| f.id()).collect();
+        assert_eq!(roots, ["gene0"]);
+
+        let exon = hierarchy.get("exon0").unwrap();
+        let parents: Vec<_> = hierarchy.parents(exon).filter_map(|f| f.id()).collect();
+        assert_eq!(parents, ["tx0", "tx1"]);
+
+        let tx0 = hierarchy.get("tx0").unwrap();
+        let children: Vec<_> = hierarchy.children(tx0).filter_map(|f| f.id()).collect();
+        assert_eq!(children, ["exon0", "cds0"]);
+
+        let cds = hierarchy.get("cds0").unwrap();
+        assert_eq!(cds.records().len(), 2);
+        assert_eq!(hierarchy.parents(cds).count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_parent() {
+        let records = parse_records(&["sq0\t.\texon\t1\t3\t.\t+\t.\tParent=tx0"]);
+
+        assert_eq!(
+            Hierarchy::build(records),
+            Err(BuildError::MissingParent(String::from("tx0")))
+        );
+    }
+
+    #[test]
+    fn test_build_with_cycle() {
+        let records = parse_records(&["sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0;Parent=gene0"]);
+
+        assert_eq!(
+            Hierarchy::build(records),
+            Err(BuildError::Cycle(String::from("gene0")))
+        );
+
+        let records = parse_records(&[
+            "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0;Parent=tx0",
+            "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0;Parent=gene0",
+        ]);
+
+        assert!(matches!(
+            Hierarchy::build(records),
+            Err(BuildError::Cycle(_))
+        ));
+    }
+}
@@ -31,10 +31,18 @@
 //! # Ok::<(), io::Error>(())
 //! ```
 
+#[cfg(feature = "async")]
+mod r#async;
+
 pub mod directive;
+pub mod hierarchy;
 pub mod line;
 pub mod reader;
 pub mod record;
+pub mod validator;
 mod writer;
 
 pub use self::{directive::Directive, line::Line, reader::Reader, record::Record, writer::Writer};
+
+#[cfg(feature = "async")]
+pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -2,7 +2,11 @@
 
 use std::{error, fmt, str::FromStr};
 
-use super::{directive, record, Directive, Record};
+use super::{
+    directive,
+    record::{self, attributes::DuplicateKeyPolicy},
+    Directive, Record,
+};
 
 /// A GFF line.
 #[derive(Clone, Debug, PartialEq)]
@@ -35,10 +39,12 @@ impl fmt::Display for ParseError {
     }
 }
 
-impl FromStr for Line {
-    type Err = ParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Line {
+    /// Parses a raw GFF line with the given record attributes duplicate key policy.
+    pub fn parse_with(
+        s: &str,
+        duplicate_key_policy: DuplicateKeyPolicy,
+    ) -> Result<Self, ParseError> {
         if s.starts_with(directive::PREFIX) {
             s.parse()
                 .map(Self::Directive)
@@ -46,13 +52,21 @@ impl FromStr for Line {
         } else if let Some(t) = s.strip_prefix('#') {
             Ok(Self::Comment(t.into()))
         } else {
-            s.parse()
+            Record::parse_with(s, duplicate_key_policy)
                 .map(Self::Record)
                 .map_err(ParseError::InvalidRecord)
         }
     }
 }
 
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, DuplicateKeyPolicy::default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
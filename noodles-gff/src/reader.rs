@@ -1,11 +1,19 @@
 //! GFF reader and iterators.
 
 mod lines;
+mod query;
 mod records;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{lines::Lines, query::Query, records::Records};
 
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+use noodles_tabix as tabix;
+
+use super::record::attributes::DuplicateKeyPolicy;
 
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
@@ -13,6 +21,7 @@ const CARRIAGE_RETURN: char = '\r';
 /// A GFF reader.
 pub struct Reader<R> {
     inner: R,
+    duplicate_key_policy: DuplicateKeyPolicy,
 }
 
 impl<R> Reader<R>
@@ -29,7 +38,43 @@ where
     /// let mut reader = gff::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            duplicate_key_policy: DuplicateKeyPolicy::default(),
+        }
+    }
+
+    /// Returns the duplicate key policy used when parsing record attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::attributes::DuplicateKeyPolicy};
+    /// let reader = gff::Reader::new(&[][..]);
+    /// assert_eq!(reader.duplicate_key_policy(), DuplicateKeyPolicy::Preserve);
+    /// ```
+    pub fn duplicate_key_policy(&self) -> DuplicateKeyPolicy {
+        self.duplicate_key_policy
+    }
+
+    /// Sets the duplicate key policy used when parsing record attributes.
+    ///
+    /// This applies to records parsed by [`Self::lines`], [`Self::records`], and
+    /// [`Reader::query`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::attributes::DuplicateKeyPolicy};
+    ///
+    /// let data = b"sq0\t.\texon\t1\t8\t.\t+\t.\tParent=tx0;Parent=tx1\n";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// reader.set_duplicate_key_policy(DuplicateKeyPolicy::Error);
+    ///
+    /// assert!(reader.records().next().unwrap().is_err());
+    /// ```
+    pub fn set_duplicate_key_policy(&mut self, duplicate_key_policy: DuplicateKeyPolicy) {
+        self.duplicate_key_policy = duplicate_key_policy;
     }
 
     /// Returns a reference to the underlying reader.
@@ -159,6 +204,111 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = Vec::new();
+    /// let reader = gff::Reader::new(bgzf::Reader::new(&data[..]));
+    /// let virtual_position = reader.virtual_position();
+    ///
+    /// assert_eq!(virtual_position.compressed(), 0);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the underlying BGZF reader to the given virtual position.
+    ///
+    /// Virtual positions typically come from an associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = Cursor::new(Vec::new());
+    /// let mut reader = gff::Reader::new(bgzf::Reader::new(data));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek(pos)
+    }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// The input must be bgzipped and coordinate-sorted and have an associated tabix index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gff as gff;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut reader = File::open("annotations.gff3.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gff::Reader::new)?;
+    ///
+    /// let index = tabix::read("annotations.gff3.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&index, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, index: &tabix::Index, region: &Region) -> io::Result<Query<'_, R>> {
+        let reference_sequence_id = resolve_region(index, region)?;
+        let chunks = index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            self,
+            chunks,
+            region.name().into(),
+            region.interval(),
+        ))
+    }
+}
+
+fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<usize> {
+    index
+        .header()
+        .reference_sequence_names()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {:?}",
+                    region
+                ),
+            )
+        })
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
@@ -227,6 +377,61 @@ ACGT
         Ok(())
     }
 
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_core::Position;
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let lines = [
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=ndls0",
+            "sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=ndls1",
+            "sq1\tNOODLES\tgene\t5\t8\t.\t+\t.\tID=ndls2",
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"##gff-version 3\n")?;
+
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::gff().build());
+
+        for line in lines {
+            let record: crate::Record = line.parse()?;
+
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{}", line)?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start(),
+                record.end(),
+                Chunk::new(start_position, end_position),
+            );
+        }
+
+        let index = indexer.build();
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let region = "sq0:10-20".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].start(), Position::try_from(8)?);
+
+        let region = "sq1".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].reference_sequence_name(), "sq1");
+
+        let region = "sq2".parse()?;
+        assert!(reader.query(&index, &region).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
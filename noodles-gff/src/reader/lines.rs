@@ -38,7 +38,7 @@ where
 
         match self.inner.read_line(&mut self.line_buf) {
             Ok(0) => None,
-            Ok(_) => match self.line_buf.parse() {
+            Ok(_) => match Line::parse_with(&self.line_buf, self.inner.duplicate_key_policy) {
                 Ok(line) => Some(Ok(line)),
                 Err(e) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
             },
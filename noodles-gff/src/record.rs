@@ -3,18 +3,25 @@
 pub mod attributes;
 mod builder;
 mod field;
-mod phase;
+pub mod phase;
 mod strand;
 
 pub use self::{
-    attributes::Attributes, builder::Builder, field::Field, phase::Phase, strand::Strand,
+    attributes::Attributes,
+    builder::{BuildError, Builder},
+    field::Field,
+    phase::Phase,
+    strand::Strand,
 };
 
 use std::{error, fmt, num, str::FromStr};
 
 use noodles_core::Position;
 
+use self::attributes::DuplicateKeyPolicy;
+
 pub(crate) const NULL_FIELD: &str = ".";
+const CDS: &str = "CDS";
 const FIELD_DELIMITER: char = '\t';
 const MAX_FIELDS: usize = 9;
 
@@ -172,6 +179,136 @@ impl Record {
     pub fn attributes(&self) -> &Attributes {
         &self.attributes
     }
+
+    /// Returns a mutable reference to the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.reference_sequence_name_mut() = String::from("sq0");
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// ```
+    pub fn reference_sequence_name_mut(&mut self) -> &mut String {
+        &mut self.reference_sequence_name
+    }
+
+    /// Returns a mutable reference to the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.source_mut() = String::from("NOODLES");
+    /// assert_eq!(record.source(), "NOODLES");
+    /// ```
+    pub fn source_mut(&mut self) -> &mut String {
+        &mut self.source
+    }
+
+    /// Returns a mutable reference to the feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.ty_mut() = String::from("gene");
+    /// assert_eq!(record.ty(), "gene");
+    /// ```
+    pub fn ty_mut(&mut self) -> &mut String {
+        &mut self.ty
+    }
+
+    /// Returns a mutable reference to the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.start_mut() = Position::try_from(8)?;
+    /// assert_eq!(record.start(), Position::try_from(8)?);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start_mut(&mut self) -> &mut Position {
+        &mut self.start
+    }
+
+    /// Returns a mutable reference to the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.end_mut() = Position::try_from(13)?;
+    /// assert_eq!(record.end(), Position::try_from(13)?);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end_mut(&mut self) -> &mut Position {
+        &mut self.end
+    }
+
+    /// Returns a mutable reference to the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut record = gff::Record::default();
+    /// *record.score_mut() = Some(21.0);
+    /// assert_eq!(record.score(), Some(21.0));
+    /// ```
+    pub fn score_mut(&mut self) -> &mut Option<f32> {
+        &mut self.score
+    }
+
+    /// Returns a mutable reference to the strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::Strand};
+    /// let mut record = gff::Record::default();
+    /// *record.strand_mut() = Strand::Forward;
+    /// assert_eq!(record.strand(), Strand::Forward);
+    /// ```
+    pub fn strand_mut(&mut self) -> &mut Strand {
+        &mut self.strand
+    }
+
+    /// Returns a mutable reference to the phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::Phase};
+    /// let mut record = gff::Record::default();
+    /// *record.phase_mut() = Some(Phase::Zero);
+    /// assert_eq!(record.phase(), Some(Phase::Zero));
+    /// ```
+    pub fn phase_mut(&mut self) -> &mut Option<Phase> {
+        &mut self.phase
+    }
+
+    /// Returns a mutable reference to the attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::attributes::Entry};
+    /// let mut record = gff::Record::default();
+    /// record.attributes_mut().insert(Entry::new("gene_id", "ndls0"));
+    /// assert_eq!(record.attributes().get("gene_id").map(|entry| entry.value()), Some("ndls0"));
+    /// ```
+    pub fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
 }
 
 impl Default for Record {
@@ -206,7 +343,11 @@ impl fmt::Display for Record {
             write!(f, "\t{}", NULL_FIELD)?;
         }
 
-        if self.attributes().is_empty() {
+        if self
+            .attributes()
+            .iter()
+            .all(|entry| entry.values().is_empty())
+        {
             write!(f, "\t{}", NULL_FIELD)?;
         } else {
             write!(f, "\t{}", self.attributes())?;
@@ -265,10 +406,23 @@ impl fmt::Display for ParseError {
     }
 }
 
-impl FromStr for Record {
-    type Err = ParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Record {
+    /// Parses a raw GFF record with the given attributes duplicate key policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::attributes::DuplicateKeyPolicy};
+    ///
+    /// let s = "sq0\t.\texon\t1\t8\t.\t+\t.\tParent=tx0;Parent=tx1";
+    /// let record = gff::Record::parse_with(s, DuplicateKeyPolicy::FirstWins)?;
+    /// assert_eq!(record.attributes().to_string(), "Parent=tx0");
+    /// # Ok::<_, gff::record::ParseError>(())
+    /// ```
+    pub fn parse_with(
+        s: &str,
+        duplicate_key_policy: DuplicateKeyPolicy,
+    ) -> Result<Self, ParseError> {
         let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
 
         let reference_sequence_name = parse_string(&mut fields, Field::ReferenceSequenceName)
@@ -296,7 +450,7 @@ impl FromStr for Record {
 
         let phase = parse_string(&mut fields, Field::Phase).and_then(|s| {
             if s == NULL_FIELD {
-                if ty == "CDS" {
+                if ty == CDS {
                     Err(ParseError::MissingPhase)
                 } else {
                     Ok(None)
@@ -307,7 +461,8 @@ impl FromStr for Record {
         })?;
 
         let attributes = match fields.next() {
-            Some(s) => s.parse().map_err(ParseError::InvalidAttributes)?,
+            Some(s) => Attributes::parse_with(s, duplicate_key_policy)
+                .map_err(ParseError::InvalidAttributes)?,
             None => Attributes::default(),
         };
 
@@ -325,6 +480,14 @@ impl FromStr for Record {
     }
 }
 
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, DuplicateKeyPolicy::default())
+    }
+}
+
 fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
 where
     I: Iterator<Item = &'a str>,
@@ -348,6 +511,12 @@ mod tests {
     fn test_fmt() {
         let record = Record::default();
         assert_eq!(record.to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.");
+
+        let mut record = Record::default();
+        record
+            .attributes_mut()
+            .insert(attributes::Entry::with_values("Note", Vec::new()));
+        assert_eq!(record.to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.");
     }
 
     #[test]
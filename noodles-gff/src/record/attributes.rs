@@ -4,14 +4,172 @@ pub mod entry;
 
 pub use self::entry::Entry;
 
-use std::{error, fmt, ops::Deref, str::FromStr};
+use std::{collections::HashMap, error, fmt, ops::Deref, str::FromStr};
 
 const DELIMITER: char = ';';
 
 /// GFF record attributes.
+///
+/// Entries are kept in the order they are inserted or, when parsed, the order they appear in the
+/// raw attributes. This order is preserved when written. Entries with no values are not written.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Attributes(Vec<Entry>);
 
+/// The handling of duplicate keys when parsing attributes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuplicateKeyPolicy {
+    /// Keep entries with duplicate keys as separate entries.
+    ///
+    /// Only the first entry with a key is returned by [`Attributes::get`]. This is the default.
+    Preserve,
+    /// Fail with [`ParseError::DuplicateKey`].
+    Error,
+    /// Keep the first entry and discard subsequent entries with the same key.
+    FirstWins,
+    /// Append the values of subsequent entries to the first entry with the same key.
+    Collect,
+}
+
+impl Default for DuplicateKeyPolicy {
+    fn default() -> Self {
+        Self::Preserve
+    }
+}
+
+impl Attributes {
+    /// Parses raw attributes with the given duplicate key policy.
+    ///
+    /// The entry order is preserved. An entry with a duplicate key is handled according to the
+    /// policy; when it is merged, it takes the position of the first entry with that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{
+    ///     attributes::{DuplicateKeyPolicy, Entry, ParseError},
+    ///     Attributes,
+    /// };
+    ///
+    /// let s = "Parent=tx0;ID=exon0;Parent=tx1";
+    ///
+    /// let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::Preserve)?;
+    /// assert_eq!(attributes.to_string(), s);
+    ///
+    /// let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::Collect)?;
+    /// assert_eq!(attributes.to_string(), "Parent=tx0,tx1;ID=exon0");
+    ///
+    /// let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::FirstWins)?;
+    /// assert_eq!(attributes.to_string(), "Parent=tx0;ID=exon0");
+    ///
+    /// assert_eq!(
+    ///     Attributes::parse_with(s, DuplicateKeyPolicy::Error),
+    ///     Err(ParseError::DuplicateKey(String::from("Parent")))
+    /// );
+    /// # Ok::<_, ParseError>(())
+    /// ```
+    pub fn parse_with(
+        s: &str,
+        duplicate_key_policy: DuplicateKeyPolicy,
+    ) -> Result<Self, ParseError> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut entries: Vec<Entry> = Vec::new();
+        let mut indices: HashMap<String, usize> = HashMap::new();
+
+        for t in s.split(DELIMITER) {
+            let entry: Entry = t.parse().map_err(ParseError::InvalidEntry)?;
+
+            match indices.get(entry.key()) {
+                Some(&i) => match duplicate_key_policy {
+                    DuplicateKeyPolicy::Preserve => entries.push(entry),
+                    DuplicateKeyPolicy::Error => {
+                        return Err(ParseError::DuplicateKey(entry.key().into()))
+                    }
+                    DuplicateKeyPolicy::FirstWins => {}
+                    DuplicateKeyPolicy::Collect => {
+                        entries[i]
+                            .values_mut()
+                            .extend(entry.values().iter().cloned());
+                    }
+                },
+                None => {
+                    indices.insert(entry.key().into(), entries.len());
+                    entries.push(entry);
+                }
+            }
+        }
+
+        Ok(Self(entries))
+    }
+
+    /// Returns the entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes: Attributes = "ID=exon0;Parent=tx0,tx1".parse()?;
+    ///
+    /// let entry = attributes.get("Parent");
+    /// assert_eq!(entry.map(|entry| entry.values()), Some(&[String::from("tx0"), String::from("tx1")][..]));
+    ///
+    /// assert!(attributes.get("Name").is_none());
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.0.iter().find(|entry| entry.key() == key)
+    }
+
+    /// Inserts an entry.
+    ///
+    /// If an entry with the same key exists, it is replaced, and the old entry is returned.
+    /// Otherwise, the entry is appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::Entry, Attributes};
+    ///
+    /// let mut attributes = Attributes::default();
+    /// assert!(attributes.insert(Entry::new("gene_id", "ndls0")).is_none());
+    ///
+    /// let old_entry = attributes.insert(Entry::new("gene_id", "ndls1"));
+    /// assert_eq!(old_entry, Some(Entry::new("gene_id", "ndls0")));
+    ///
+    /// assert_eq!(attributes.to_string(), "gene_id=ndls1");
+    /// ```
+    pub fn insert(&mut self, entry: Entry) -> Option<Entry> {
+        match self.0.iter_mut().find(|e| e.key() == entry.key()) {
+            Some(e) => Some(std::mem::replace(e, entry)),
+            None => {
+                self.0.push(entry);
+                None
+            }
+        }
+    }
+
+    /// Removes the entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::Entry, Attributes};
+    ///
+    /// let mut attributes = Attributes::from(vec![Entry::new("gene_id", "ndls0")]);
+    /// assert_eq!(attributes.remove("gene_id"), Some(Entry::new("gene_id", "ndls0")));
+    /// assert!(attributes.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<Entry> {
+        self.0
+            .iter()
+            .position(|entry| entry.key() == key)
+            .map(|i| self.0.remove(i))
+    }
+}
+
 impl Deref for Attributes {
     type Target = [Entry];
 
@@ -22,7 +180,9 @@ impl Deref for Attributes {
 
 impl fmt::Display for Attributes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, entry) in self.iter().enumerate() {
+        let entries = self.iter().filter(|entry| !entry.values().is_empty());
+
+        for (i, entry) in entries.enumerate() {
             if i > 0 {
                 write!(f, "{}", DELIMITER)?;
             }
@@ -39,6 +199,10 @@ impl fmt::Display for Attributes {
 pub enum ParseError {
     /// The input attributes has an invalid entry.
     InvalidEntry(entry::ParseError),
+    /// A key is duplicated.
+    ///
+    /// This is only returned when using [`DuplicateKeyPolicy::Error`].
+    DuplicateKey(String),
 }
 
 impl error::Error for ParseError {}
@@ -47,6 +211,7 @@ impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidEntry(e) => write!(f, "invalid entry: {}", e),
+            Self::DuplicateKey(key) => write!(f, "duplicate key: {}", key),
         }
     }
 }
@@ -61,15 +226,7 @@ impl FromStr for Attributes {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Ok(Self::default());
-        }
-
-        s.split(DELIMITER)
-            .map(|t| t.parse())
-            .collect::<Result<Vec<_>, _>>()
-            .map(Self::from)
-            .map_err(ParseError::InvalidEntry)
+        Self::parse_with(s, DuplicateKeyPolicy::default())
     }
 }
 
@@ -91,7 +248,15 @@ mod tests {
             Entry::new("gene_name", "gene0"),
         ]);
 
-        assert_eq!(attributes.to_string(), "gene_id=ndls0;gene_name=gene0")
+        assert_eq!(attributes.to_string(), "gene_id=ndls0;gene_name=gene0");
+
+        let attributes = Attributes::from(vec![
+            Entry::with_values("Note", Vec::new()),
+            Entry::new("gene_id", "ndls0"),
+            Entry::with_values("Parent", Vec::new()),
+        ]);
+
+        assert_eq!(attributes.to_string(), "gene_id=ndls0");
     }
 
     #[test]
@@ -116,6 +281,46 @@ mod tests {
         let expected = Attributes::default();
         assert_eq!(actual, expected);
 
+        let s = "Parent=tx0;ID=exon0;Parent=tx1";
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![
+            Entry::new("Parent", "tx0"),
+            Entry::new("ID", "exon0"),
+            Entry::new("Parent", "tx1"),
+        ]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with() -> Result<(), ParseError> {
+        let s = "gene_name=gene0;gene_id=ndls0;Note=a;tag=b;Note=c";
+
+        let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::Preserve)?;
+        assert_eq!(attributes.to_string(), s);
+
+        let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::Collect)?;
+        assert_eq!(
+            attributes.to_string(),
+            "gene_name=gene0;gene_id=ndls0;Note=a,c;tag=b"
+        );
+
+        let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::FirstWins)?;
+        assert_eq!(
+            attributes.to_string(),
+            "gene_name=gene0;gene_id=ndls0;Note=a;tag=b"
+        );
+
+        assert_eq!(
+            Attributes::parse_with(s, DuplicateKeyPolicy::Error),
+            Err(ParseError::DuplicateKey(String::from("Note")))
+        );
+
+        let s = "gene_name=gene0;gene_id=ndls0;Note=a";
+        let attributes = Attributes::parse_with(s, DuplicateKeyPolicy::Error)?;
+        assert_eq!(attributes.to_string(), s);
+
         Ok(())
     }
 }
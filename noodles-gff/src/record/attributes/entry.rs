@@ -19,12 +19,16 @@ const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b',');
 
 const SEPARATOR: char = '=';
+const VALUE_DELIMITER: char = ',';
 
 /// A GFF record attribute entry.
+///
+/// An entry has a key and one or more values. In the raw form, multiple values are delimited by a
+/// comma (`,`), and reserved characters in the key and values are percent-encoded.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Entry {
     key: String,
-    value: String,
+    values: Vec<String>,
 }
 
 impl Entry {
@@ -43,7 +47,31 @@ impl Entry {
     {
         Self {
             key: key.into(),
-            value: value.into(),
+            values: vec![value.into()],
+        }
+    }
+
+    /// Creates a GFF record attribute entry with multiple values.
+    ///
+    /// An entry with no values has no raw form and is not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Entry;
+    /// let entry = Entry::with_values("Parent", vec![String::from("tx0"), String::from("tx1")]);
+    /// assert_eq!(entry.to_string(), "Parent=tx0,tx1");
+    ///
+    /// let entry = Entry::with_values("Parent", Vec::new());
+    /// assert!(entry.to_string().is_empty());
+    /// ```
+    pub fn with_values<K>(key: K, values: Vec<String>) -> Self
+    where
+        K: Into<String>,
+    {
+        Self {
+            key: key.into(),
+            values,
         }
     }
 
@@ -60,29 +88,79 @@ impl Entry {
         &self.key
     }
 
-    /// Returns the value of the entry.
+    /// Returns the (first) value of the entry.
+    ///
+    /// If the entry has multiple values, this only returns the first value. Use [`Self::values`]
+    /// to get all values. If the entry has no values, this returns an empty string.
     ///
     /// # Examples
     ///
     /// ```
     /// use noodles_gff::record::attributes::Entry;
+    ///
     /// let entry = Entry::new("gene_name", "gene0");
     /// assert_eq!(entry.value(), "gene0");
+    ///
+    /// let entry: Entry = "Parent=tx0,tx1".parse()?;
+    /// assert_eq!(entry.value(), "tx0");
+    /// # Ok::<_, noodles_gff::record::attributes::entry::ParseError>(())
     /// ```
     pub fn value(&self) -> &str {
-        &self.value
+        self.values.first().map(|s| s.as_str()).unwrap_or_default()
+    }
+
+    /// Returns the values of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Entry;
+    ///
+    /// let entry = Entry::new("gene_name", "gene0");
+    /// assert_eq!(entry.values(), ["gene0"]);
+    ///
+    /// let entry: Entry = "Parent=tx0,tx1%2C2".parse()?;
+    /// assert_eq!(entry.values(), ["tx0", "tx1,2"]);
+    /// # Ok::<_, noodles_gff::record::attributes::entry::ParseError>(())
+    /// ```
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Returns a mutable reference to the values of the entry.
+    ///
+    /// If all values are removed, the entry is no longer written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Entry;
+    /// let mut entry = Entry::new("Parent", "tx0");
+    /// entry.values_mut().push(String::from("tx1"));
+    /// assert_eq!(entry.to_string(), "Parent=tx0,tx1");
+    /// ```
+    pub fn values_mut(&mut self) -> &mut Vec<String> {
+        &mut self.values
     }
 }
 
 impl fmt::Display for Entry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}{}{}",
-            percent_encode(self.key()),
-            SEPARATOR,
-            percent_encode(self.value())
-        )
+        if self.values().is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "{}{}", percent_encode(self.key()), SEPARATOR)?;
+
+        for (i, value) in self.values().iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", VALUE_DELIMITER)?;
+            }
+
+            write!(f, "{}", percent_encode(value))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -129,8 +207,8 @@ impl FromStr for Entry {
         match s.split_once(SEPARATOR) {
             Some((k, v)) => {
                 let key = parse_key(k)?;
-                let value = parse_value(v)?;
-                Ok(Self::new(key, value))
+                let values = parse_values(v)?;
+                Ok(Self::with_values(key, values))
             }
             None => Err(ParseError::Invalid),
         }
@@ -145,11 +223,18 @@ fn parse_key(s: &str) -> Result<Cow<'_, str>, ParseError> {
     }
 }
 
-fn parse_value(s: &str) -> Result<Cow<'_, str>, ParseError> {
+// Values are split before being percent-decoded so that encoded delimiters (`%2C`) are kept.
+fn parse_values(s: &str) -> Result<Vec<String>, ParseError> {
     if s.is_empty() {
         Err(ParseError::MissingValue)
     } else {
-        percent_decode(s).map_err(ParseError::InvalidValue)
+        s.split(VALUE_DELIMITER)
+            .map(|t| {
+                percent_decode(t)
+                    .map(String::from)
+                    .map_err(ParseError::InvalidValue)
+            })
+            .collect()
     }
 }
 
@@ -172,6 +257,19 @@ mod tests {
 
         let entry = Entry::new("%s", "13,21");
         assert_eq!(entry.to_string(), "%25s=13%2C21");
+
+        let entry = Entry::with_values(
+            "Note",
+            vec![
+                String::from("a;b"),
+                String::from("c=d"),
+                String::from("e,f"),
+            ],
+        );
+        assert_eq!(entry.to_string(), "Note=a%3Bb,c%3Dd,e%2Cf");
+
+        let entry = Entry::with_values("Note", Vec::new());
+        assert!(entry.to_string().is_empty());
     }
 
     #[test]
@@ -181,6 +279,13 @@ mod tests {
             Ok(Entry::new("gene_name", "gene0"))
         );
         assert_eq!("%25s=13%2C21".parse(), Ok(Entry::new("%s", "13,21")));
+        assert_eq!(
+            "Parent=tx0,tx%3B1".parse(),
+            Ok(Entry::with_values(
+                "Parent",
+                vec![String::from("tx0"), String::from("tx;1")]
+            ))
+        );
 
         assert_eq!("".parse::<Entry>(), Err(ParseError::Empty));
         assert_eq!("gene_name".parse::<Entry>(), Err(ParseError::Invalid));
@@ -1,6 +1,8 @@
+use std::{error, fmt};
+
 use noodles_core::Position;
 
-use super::{Attributes, Phase, Record, Strand, NULL_FIELD};
+use super::{Attributes, Phase, Record, Strand, CDS, NULL_FIELD};
 
 /// A GFF record builder.
 #[derive(Debug)]
@@ -186,8 +188,52 @@ impl Builder {
         self
     }
 
+    /// Builds a validated GFF record.
+    ///
+    /// The record is checked to have a valid reference sequence name, a start position that is
+    /// less than or equal to the end position, and a phase if it is a CDS feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff::{self as gff, record::BuildError};
+    ///
+    /// let record = gff::Record::builder()
+    ///     .set_reference_sequence_name(String::from("sq0"))
+    ///     .set_start(Position::try_from(8)?)
+    ///     .set_end(Position::try_from(13)?)
+    ///     .try_build();
+    /// assert!(record.is_ok());
+    ///
+    /// let record = gff::Record::builder()
+    ///     .set_start(Position::try_from(13)?)
+    ///     .set_end(Position::try_from(8)?)
+    ///     .try_build();
+    /// assert_eq!(record, Err(BuildError::InvalidInterval));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn try_build(self) -> Result<Record, BuildError> {
+        if self.reference_sequence_name.is_empty() || self.reference_sequence_name.starts_with('>')
+        {
+            return Err(BuildError::InvalidReferenceSequenceName);
+        }
+
+        if self.start > self.end {
+            return Err(BuildError::InvalidInterval);
+        }
+
+        if self.ty == CDS && self.phase.is_none() {
+            return Err(BuildError::MissingPhase);
+        }
+
+        Ok(self.build())
+    }
+
     /// Builds a GFF record.
     ///
+    /// This does not validate the record. See [`Self::try_build`].
+    ///
     /// # Example
     ///
     /// ```
@@ -209,6 +255,29 @@ impl Builder {
     }
 }
 
+/// An error returned when a GFF record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The reference sequence name is invalid.
+    InvalidReferenceSequenceName,
+    /// The start position is greater than the end position.
+    InvalidInterval,
+    /// The phase is missing for a CDS feature.
+    MissingPhase,
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidReferenceSequenceName => f.write_str("invalid reference sequence name"),
+            Self::InvalidInterval => f.write_str("invalid interval: start > end"),
+            Self::MissingPhase => f.write_str("missing phase"),
+        }
+    }
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self {
@@ -274,4 +343,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_build() -> Result<(), noodles_core::position::TryFromIntError> {
+        assert!(Builder::new().try_build().is_ok());
+
+        assert_eq!(
+            Builder::new()
+                .set_reference_sequence_name(String::from(">sq0"))
+                .try_build(),
+            Err(BuildError::InvalidReferenceSequenceName)
+        );
+
+        assert_eq!(
+            Builder::new()
+                .set_start(Position::try_from(13)?)
+                .set_end(Position::try_from(8)?)
+                .try_build(),
+            Err(BuildError::InvalidInterval)
+        );
+
+        assert_eq!(
+            Builder::new().set_type(String::from("CDS")).try_build(),
+            Err(BuildError::MissingPhase)
+        );
+
+        assert!(Builder::new()
+            .set_type(String::from("CDS"))
+            .set_phase(Phase::Zero)
+            .try_build()
+            .is_ok());
+
+        Ok(())
+    }
 }
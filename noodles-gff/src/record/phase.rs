@@ -1,6 +1,8 @@
 //! GFF record phase.
 
-use std::{error, fmt, str::FromStr};
+use std::{cmp, error, fmt, str::FromStr};
+
+use super::{Record, Strand, CDS};
 
 /// A GFF record phase.
 ///
@@ -16,6 +18,39 @@ pub enum Phase {
     Two,
 }
 
+impl Phase {
+    /// Returns the phase of the CDS feature that follows a CDS feature with this phase and the
+    /// given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Phase;
+    /// assert_eq!(Phase::Zero.next(8), Phase::One);
+    /// assert_eq!(Phase::One.next(13), Phase::Zero);
+    /// assert_eq!(Phase::Two.next(9), Phase::Two);
+    /// ```
+    pub fn next(self, len: usize) -> Self {
+        let n = usize::from(self);
+
+        match (n + 3 - len % 3) % 3 {
+            0 => Self::Zero,
+            1 => Self::One,
+            _ => Self::Two,
+        }
+    }
+}
+
+impl From<Phase> for usize {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::Zero => 0,
+            Phase::One => 1,
+            Phase::Two => 2,
+        }
+    }
+}
+
 impl AsRef<str> for Phase {
     fn as_ref(&self) -> &str {
         match self {
@@ -66,6 +101,130 @@ impl FromStr for Phase {
     }
 }
 
+/// An error returned when the phases of CDS features are inconsistent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The CDS features are not all on the same strand.
+    MixedStrands,
+    /// A CDS feature is missing a phase.
+    ///
+    /// This holds the index of the record in the input.
+    MissingPhase(usize),
+    /// The phase of a CDS feature does not follow from the previous CDS feature.
+    InvalidPhase {
+        /// The index of the record in the input.
+        index: usize,
+        /// The expected phase.
+        expected: Phase,
+        /// The actual phase.
+        actual: Phase,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MixedStrands => f.write_str("mixed strands"),
+            Self::MissingPhase(i) => write!(f, "missing phase at record {}", i),
+            Self::InvalidPhase {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid phase at record {}: expected {}, got {}",
+                index, expected, actual
+            ),
+        }
+    }
+}
+
+/// Validates the phases of the CDS features of a transcript.
+///
+/// Only records with the feature type `CDS` are checked. They are ordered in the direction of
+/// transcription, i.e., by start position on the forward strand and by descending end position on
+/// the reverse strand. The phase of each CDS feature must then follow from the phase and length of
+/// the previous one (see [`Phase::next`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_gff::{self as gff, record::{phase, Phase, Strand}};
+///
+/// let builder = || gff::Record::builder()
+///     .set_type(String::from("CDS"))
+///     .set_strand(Strand::Forward);
+///
+/// let records = [
+///     builder()
+///         .set_start(Position::try_from(1)?)
+///         .set_end(Position::try_from(8)?)
+///         .set_phase(Phase::Zero)
+///         .build(),
+///     builder()
+///         .set_start(Position::try_from(13)?)
+///         .set_end(Position::try_from(21)?)
+///         .set_phase(Phase::One)
+///         .build(),
+/// ];
+///
+/// assert!(phase::validate(&records).is_ok());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate(records: &[Record]) -> Result<(), ValidationError> {
+    let mut cds: Vec<_> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| record.ty() == CDS)
+        .collect();
+
+    let is_reverse = match cds.first() {
+        Some((_, record)) => record.strand() == Strand::Reverse,
+        None => return Ok(()),
+    };
+
+    if cds
+        .iter()
+        .any(|(_, record)| (record.strand() == Strand::Reverse) != is_reverse)
+    {
+        return Err(ValidationError::MixedStrands);
+    }
+
+    if is_reverse {
+        cds.sort_by_key(|(_, record)| cmp::Reverse(record.end()));
+    } else {
+        cds.sort_by_key(|(_, record)| record.start());
+    }
+
+    let mut expected_phase = None;
+
+    for (i, record) in cds {
+        let phase = record.phase().ok_or(ValidationError::MissingPhase(i))?;
+
+        if let Some(expected) = expected_phase {
+            if phase != expected {
+                return Err(ValidationError::InvalidPhase {
+                    index: i,
+                    expected,
+                    actual: phase,
+                });
+            }
+        }
+
+        let len = usize::from(record.end())
+            .checked_sub(usize::from(record.start()))
+            .map(|n| n + 1)
+            .unwrap_or_default();
+
+        expected_phase = Some(phase.next(len));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +250,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(Phase::Zero.next(3), Phase::Zero);
+        assert_eq!(Phase::Zero.next(4), Phase::Two);
+        assert_eq!(Phase::Zero.next(5), Phase::One);
+        assert_eq!(Phase::One.next(1), Phase::Zero);
+        assert_eq!(Phase::Two.next(1), Phase::One);
+        assert_eq!(Phase::Two.next(0), Phase::Two);
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), noodles_core::position::TryFromIntError> {
+        use noodles_core::Position;
+
+        fn build(
+            strand: Strand,
+            start: usize,
+            end: usize,
+            phase: Option<Phase>,
+        ) -> Result<Record, noodles_core::position::TryFromIntError> {
+            let mut builder = Record::builder()
+                .set_type(String::from(CDS))
+                .set_strand(strand)
+                .set_start(Position::try_from(start)?)
+                .set_end(Position::try_from(end)?);
+
+            if let Some(phase) = phase {
+                builder = builder.set_phase(phase);
+            }
+
+            Ok(builder.build())
+        }
+
+        assert_eq!(validate(&[]), Ok(()));
+
+        // lengths: 8, 9, 4
+        let records = [
+            build(Strand::Forward, 30, 33, Some(Phase::One))?,
+            build(Strand::Forward, 1, 8, Some(Phase::Zero))?,
+            build(Strand::Forward, 13, 21, Some(Phase::One))?,
+        ];
+        assert_eq!(validate(&records), Ok(()));
+
+        let records = [
+            build(Strand::Reverse, 30, 33, Some(Phase::Zero))?,
+            build(Strand::Reverse, 13, 21, Some(Phase::Two))?,
+            build(Strand::Reverse, 1, 8, Some(Phase::Two))?,
+        ];
+        assert_eq!(validate(&records), Ok(()));
+
+        let records = [
+            build(Strand::Forward, 1, 8, Some(Phase::Zero))?,
+            build(Strand::Forward, 13, 21, Some(Phase::Zero))?,
+        ];
+        assert_eq!(
+            validate(&records),
+            Err(ValidationError::InvalidPhase {
+                index: 1,
+                expected: Phase::One,
+                actual: Phase::Zero
+            })
+        );
+
+        let records = [
+            build(Strand::Forward, 1, 8, Some(Phase::Zero))?,
+            build(Strand::Forward, 13, 21, None)?,
+        ];
+        assert_eq!(validate(&records), Err(ValidationError::MissingPhase(1)));
+
+        let records = [
+            build(Strand::Forward, 1, 8, Some(Phase::Zero))?,
+            build(Strand::Reverse, 13, 21, Some(Phase::One))?,
+        ];
+        assert_eq!(validate(&records), Err(ValidationError::MixedStrands));
+
+        Ok(())
+    }
 }
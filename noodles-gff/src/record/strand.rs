@@ -82,6 +82,28 @@ impl From<Strand> for char {
     }
 }
 
+impl From<Strand> for Option<noodles_core::Strand> {
+    /// Converts a GFF record strand to an optional strand.
+    ///
+    /// Unstranded (`.`) and unknown (`?`) strands are converted to `None`.
+    fn from(strand: Strand) -> Self {
+        match strand {
+            Strand::None | Strand::Unknown => None,
+            Strand::Forward => Some(noodles_core::Strand::Forward),
+            Strand::Reverse => Some(noodles_core::Strand::Reverse),
+        }
+    }
+}
+
+impl From<noodles_core::Strand> for Strand {
+    fn from(strand: noodles_core::Strand) -> Self {
+        match strand {
+            noodles_core::Strand::Forward => Self::Forward,
+            noodles_core::Strand::Reverse => Self::Reverse,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +144,24 @@ mod tests {
         assert_eq!(char::from(Strand::Reverse), '-');
         assert_eq!(char::from(Strand::Unknown), '?');
     }
+
+    #[test]
+    fn test_from_strand_for_option_noodles_core_strand() {
+        assert_eq!(Option::<noodles_core::Strand>::from(Strand::None), None);
+        assert_eq!(
+            Option::<noodles_core::Strand>::from(Strand::Forward),
+            Some(noodles_core::Strand::Forward)
+        );
+        assert_eq!(
+            Option::<noodles_core::Strand>::from(Strand::Reverse),
+            Some(noodles_core::Strand::Reverse)
+        );
+        assert_eq!(Option::<noodles_core::Strand>::from(Strand::Unknown), None);
+    }
+
+    #[test]
+    fn test_from_noodles_core_strand_for_strand() {
+        assert_eq!(Strand::from(noodles_core::Strand::Forward), Strand::Forward);
+        assert_eq!(Strand::from(noodles_core::Strand::Reverse), Strand::Reverse);
+    }
 }
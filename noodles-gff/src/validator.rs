@@ -0,0 +1,454 @@
+//! GFF3 structural validation.
+//!
+//! The validator checks the relationships between records that the record parser cannot check on
+//! its own, e.g., whether `Parent` attributes reference defined features. Each problem is
+//! reported as a [`Diagnostic`] with the line number of the offending record.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, BufRead},
+};
+
+use noodles_core::Position;
+
+use super::{
+    line,
+    record::{self, Phase, Strand},
+    Directive, Line, Reader, Record,
+};
+
+const ID: &str = "ID";
+const PARENT: &str = "Parent";
+const CDS: &str = "CDS";
+const MRNA: &str = "mRNA";
+
+/// A validation diagnostic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    line_number: usize,
+    kind: Kind,
+}
+
+impl Diagnostic {
+    /// Returns the 1-based line number of the offending line.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the kind of problem.
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line_number, self.kind)
+    }
+}
+
+/// A kind of validation problem.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The line is invalid.
+    InvalidLine(line::ParseError),
+    /// A `Parent` attribute references an ID that is not defined.
+    MissingParent(String),
+    /// A feature is not contained by its parent, i.e., it is on a different reference sequence or
+    /// extends past the bounds of the parent.
+    OutOfParentBounds {
+        /// The parent ID.
+        parent_id: String,
+        /// The line number of the first record of the parent.
+        parent_line_number: usize,
+    },
+    /// A CDS feature does not have an mRNA parent.
+    CdsWithoutMrnaParent,
+    /// A CDS feature does not have a phase.
+    MissingPhase,
+    /// A CDS feature is unstranded or has an unknown strand.
+    ///
+    /// The phase is relative to the 5' end, so a CDS feature must be on the forward or reverse
+    /// strand.
+    UnstrandedCds(Strand),
+    /// A feature that is not a CDS has a phase.
+    UnexpectedPhase(Phase),
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLine(e) => write!(f, "invalid line: {}", e),
+            Self::MissingParent(id) => write!(f, "missing parent: {}", id),
+            Self::OutOfParentBounds {
+                parent_id,
+                parent_line_number,
+            } => write!(
+                f,
+                "feature is outside the bounds of parent {} (line {})",
+                parent_id, parent_line_number
+            ),
+            Self::CdsWithoutMrnaParent => f.write_str("CDS feature does not have an mRNA parent"),
+            Self::MissingPhase => f.write_str("CDS feature does not have a phase"),
+            Self::UnstrandedCds(strand) => {
+                write!(f, "invalid CDS strand: {}", strand.as_ref())
+            }
+            Self::UnexpectedPhase(phase) => {
+                write!(f, "unexpected phase for non-CDS feature: {}", phase)
+            }
+        }
+    }
+}
+
+struct Entry {
+    line_number: usize,
+    reference_sequence_name: String,
+    ty: String,
+    start: Position,
+    end: Position,
+    parent_ids: Vec<String>,
+}
+
+/// A GFF3 structural validator.
+///
+/// Records are added with their line numbers using [`Self::add_record`]. Checks that only depend
+/// on a single record are done immediately. Parent references are resolved in [`Self::finish`],
+/// so parents are not required to be defined before their children.
+#[derive(Default)]
+pub struct Validator {
+    entries: Vec<Entry>,
+    ids: HashMap<String, Vec<usize>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    /// Creates a GFF3 validator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::validator::Validator;
+    /// let validator = Validator::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a record at the given 1-based line number.
+    pub fn add_record(&mut self, line_number: usize, record: &Record) {
+        if record.ty() == CDS {
+            if matches!(record.strand(), Strand::None | Strand::Unknown) {
+                self.push_diagnostic(line_number, Kind::UnstrandedCds(record.strand()));
+            }
+
+            if record.phase().is_none() {
+                self.push_diagnostic(line_number, Kind::MissingPhase);
+            }
+        } else if let Some(phase) = record.phase() {
+            self.push_diagnostic(line_number, Kind::UnexpectedPhase(phase));
+        }
+
+        let i = self.entries.len();
+
+        if let Some(entry) = record.attributes().get(ID) {
+            self.ids.entry(entry.value().into()).or_default().push(i);
+        }
+
+        let parent_ids = record
+            .attributes()
+            .get(PARENT)
+            .map(|entry| entry.values().to_vec())
+            .unwrap_or_default();
+
+        self.entries.push(Entry {
+            line_number,
+            reference_sequence_name: record.reference_sequence_name().into(),
+            ty: record.ty().into(),
+            start: record.start(),
+            end: record.end(),
+            parent_ids,
+        });
+    }
+
+    /// Adds a line that failed to parse at the given 1-based line number.
+    ///
+    /// A CDS record without a phase fails to parse and is reported as [`Kind::MissingPhase`].
+    pub fn add_invalid_line(&mut self, line_number: usize, error: line::ParseError) {
+        let kind = match error {
+            line::ParseError::InvalidRecord(record::ParseError::MissingPhase) => Kind::MissingPhase,
+            _ => Kind::InvalidLine(error),
+        };
+
+        self.push_diagnostic(line_number, kind);
+    }
+
+    /// Resolves parent references and returns all diagnostics, sorted by line number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, validator::{Kind, Validator}};
+    ///
+    /// let mut validator = Validator::new();
+    ///
+    /// let record: gff::Record = "sq0\t.\texon\t1\t8\t.\t+\t.\tParent=tx0".parse()?;
+    /// validator.add_record(1, &record);
+    ///
+    /// let diagnostics = validator.finish();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].line_number(), 1);
+    /// assert_eq!(diagnostics[0].kind(), &Kind::MissingParent(String::from("tx0")));
+    /// # Ok::<_, gff::record::ParseError>(())
+    /// ```
+    pub fn finish(mut self) -> Vec<Diagnostic> {
+        for entry in &self.entries {
+            let mut has_parent = false;
+            let mut has_mrna_parent = false;
+
+            for parent_id in &entry.parent_ids {
+                let parents: Vec<_> = match self.ids.get(parent_id) {
+                    Some(indices) => indices.iter().map(|&j| &self.entries[j]).collect(),
+                    None => {
+                        self.diagnostics.push(Diagnostic {
+                            line_number: entry.line_number,
+                            kind: Kind::MissingParent(parent_id.clone()),
+                        });
+
+                        continue;
+                    }
+                };
+
+                has_parent = true;
+                has_mrna_parent |= parents.iter().any(|parent| parent.ty == MRNA);
+
+                if !is_contained(entry, &parents) {
+                    self.diagnostics.push(Diagnostic {
+                        line_number: entry.line_number,
+                        kind: Kind::OutOfParentBounds {
+                            parent_id: parent_id.clone(),
+                            parent_line_number: parents[0].line_number,
+                        },
+                    });
+                }
+            }
+
+            // A CDS whose parents are all missing is already reported.
+            let check_mrna_parent = entry.parent_ids.is_empty() || has_parent;
+
+            if entry.ty == CDS && check_mrna_parent && !has_mrna_parent {
+                self.diagnostics.push(Diagnostic {
+                    line_number: entry.line_number,
+                    kind: Kind::CdsWithoutMrnaParent,
+                });
+            }
+        }
+
+        self.diagnostics
+            .sort_by_key(|diagnostic| diagnostic.line_number);
+        self.diagnostics
+    }
+
+    fn push_diagnostic(&mut self, line_number: usize, kind: Kind) {
+        self.diagnostics.push(Diagnostic { line_number, kind });
+    }
+}
+
+// A parent with multiple records, i.e., a discontinuous feature, spans all of its parts on the
+// same reference sequence.
+fn is_contained(entry: &Entry, parents: &[&Entry]) -> bool {
+    let span = parents
+        .iter()
+        .filter(|parent| parent.reference_sequence_name == entry.reference_sequence_name)
+        .map(|parent| (parent.start, parent.end))
+        .reduce(|(a_start, a_end), (b_start, b_end)| (a_start.min(b_start), a_end.max(b_end)));
+
+    match span {
+        Some((start, end)) => start <= entry.start && entry.end <= end,
+        None => false,
+    }
+}
+
+/// Validates the lines read from a GFF reader.
+///
+/// Lines are read until EOF or the `FASTA` directive. Lines that fail to parse are reported as
+/// [`Kind::InvalidLine`] diagnostics rather than errors; only I/O errors are returned as errors.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_gff::{self as gff, validator::{self, Kind}};
+///
+/// let data = b"##gff-version 3
+/// sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+/// sq0\t.\tCDS\t1\t8\t.\t+\t0\tParent=gene0
+/// ";
+/// let mut reader = gff::Reader::new(&data[..]);
+///
+/// let diagnostics = validator::validate(&mut reader)?;
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].line_number(), 3);
+/// assert_eq!(diagnostics[0].kind(), &Kind::CdsWithoutMrnaParent);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn validate<R>(reader: &mut Reader<R>) -> io::Result<Vec<Diagnostic>>
+where
+    R: BufRead,
+{
+    let mut validator = Validator::new();
+    let mut buf = String::new();
+    let mut line_number = 0;
+
+    loop {
+        buf.clear();
+
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+
+        line_number += 1;
+
+        match buf.parse() {
+            Ok(Line::Directive(Directive::StartOfFasta)) => break,
+            Ok(Line::Record(record)) => validator.add_record(line_number, &record),
+            Ok(_) => {}
+            Err(e) => validator.add_invalid_line(line_number, e),
+        }
+    }
+
+    Ok(validator.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate_records(lines: &[&str]) -> Vec<Diagnostic> {
+        let mut validator = Validator::new();
+
+        for (i, s) in lines.iter().enumerate() {
+            let record = s.parse().unwrap();
+            validator.add_record(i + 1, &record);
+        }
+
+        validator.finish()
+    }
+
+    #[test]
+    fn test_finish() {
+        let diagnostics = validate_records(&[
+            "sq0\t.\tgene\t1\t20\t.\t+\t.\tID=gene0",
+            "sq0\t.\tmRNA\t1\t20\t.\t+\t.\tID=tx0;Parent=gene0",
+            "sq0\t.\texon\t1\t5\t.\t+\t.\tParent=tx0",
+            "sq0\t.\tCDS\t2\t5\t.\t+\t0\tID=cds0;Parent=tx0",
+            "sq0\t.\tCDS\t8\t10\t.\t+\t0\tID=cds0;Parent=tx0",
+        ]);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_finish_with_invalid_records() {
+        let diagnostics = validate_records(&[
+            "sq0\t.\tgene\t1\t20\t.\t+\t.\tID=gene0",
+            "sq0\t.\tmRNA\t5\t25\t.\t+\t.\tID=tx0;Parent=gene0",
+            "sq1\t.\texon\t1\t5\t.\t+\t.\tParent=tx0",
+            "sq0\t.\texon\t5\t8\t.\t+\t1\tParent=tx1",
+            "sq0\t.\tCDS\t5\t8\t.\t.\t0\tParent=gene0",
+        ]);
+
+        let expected = [
+            Diagnostic {
+                line_number: 2,
+                kind: Kind::OutOfParentBounds {
+                    parent_id: String::from("gene0"),
+                    parent_line_number: 1,
+                },
+            },
+            Diagnostic {
+                line_number: 3,
+                kind: Kind::OutOfParentBounds {
+                    parent_id: String::from("tx0"),
+                    parent_line_number: 2,
+                },
+            },
+            Diagnostic {
+                line_number: 4,
+                kind: Kind::UnexpectedPhase(Phase::One),
+            },
+            Diagnostic {
+                line_number: 4,
+                kind: Kind::MissingParent(String::from("tx1")),
+            },
+            Diagnostic {
+                line_number: 5,
+                kind: Kind::UnstrandedCds(Strand::None),
+            },
+            Diagnostic {
+                line_number: 5,
+                kind: Kind::CdsWithoutMrnaParent,
+            },
+        ];
+
+        assert_eq!(diagnostics, expected);
+    }
+
+    #[test]
+    fn test_finish_with_cds_without_phase() {
+        let mut validator = Validator::new();
+
+        let record = "sq0\t.\tmRNA\t1\t20\t.\t+\t.\tID=tx0".parse().unwrap();
+        validator.add_record(1, &record);
+
+        let record = Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_type(String::from(CDS))
+            .set_start(Position::MIN)
+            .set_end(Position::try_from(8).unwrap())
+            .set_strand(Strand::Forward)
+            .set_attributes("Parent=tx0".parse().unwrap())
+            .build();
+        validator.add_record(2, &record);
+
+        let diagnostics = validator.finish();
+
+        assert_eq!(
+            diagnostics,
+            [Diagnostic {
+                line_number: 2,
+                kind: Kind::MissingPhase,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate() -> io::Result<()> {
+        let data = b"##gff-version 3
+sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+sq0\t.\tgene\t1\tx\t.\t+\t.\tID=gene1
+##FASTA
+>sq0
+ACGTACGT
+";
+        let mut reader = Reader::new(&data[..]);
+        let diagnostics = validate(&mut reader)?;
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_number(), 3);
+        assert!(matches!(diagnostics[0].kind(), Kind::InvalidLine(_)));
+
+        let data = b"##gff-version 3
+sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=tx0
+sq0\t.\tCDS\t1\t8\t.\t+\t.\tParent=tx0
+";
+        let mut reader = Reader::new(&data[..]);
+        let diagnostics = validate(&mut reader)?;
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_number(), 3);
+        assert_eq!(diagnostics[0].kind(), &Kind::MissingPhase);
+
+        Ok(())
+    }
+}
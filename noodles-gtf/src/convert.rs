@@ -0,0 +1,359 @@
+//! Conversions between GTF and GFF3 records.
+//!
+//! GTF identifies features by their `gene_id` and `transcript_id` attributes, whereas GFF3 uses
+//! `ID` and `Parent` attributes to build a feature hierarchy. When converting to GFF3, `gene` and
+//! `transcript` features are given an `ID`, and all other features are given a `Parent` that
+//! points to their transcript (or gene). When converting from GFF3, the `gene_id` and
+//! `transcript_id` attributes are resolved by following the `Parent` attributes.
+//!
+//! The frame of a GTF record and the phase of a GFF3 record are equivalent.
+
+use std::{collections::HashMap, error, fmt};
+
+use noodles_gff as gff;
+
+use crate::{
+    record::{attributes::Entry, Attributes, Strand},
+    Record,
+};
+
+const GENE: &str = "gene";
+const TRANSCRIPT: &str = "transcript";
+
+const GENE_ID: &str = "gene_id";
+const TRANSCRIPT_ID: &str = "transcript_id";
+
+const GFF_ID: &str = "ID";
+const GFF_PARENT: &str = "Parent";
+
+/// Converts a GTF record to a GFF3 record.
+///
+/// `gene` features are given an `ID` from their `gene_id`; `transcript` features, an `ID` from
+/// their `transcript_id` and a `Parent` from their `gene_id`; and all other features, a `Parent`
+/// from their `transcript_id` (or `gene_id`, if there is no `transcript_id`). All GTF attributes are
+/// kept, and repeated keys are grouped into a single entry with multiple values.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gtf::{self as gtf, convert};
+///
+/// let record: gtf::Record =
+///     "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";".parse()?;
+///
+/// let gff_record = convert::to_gff_record(&record);
+///
+/// assert_eq!(
+///     gff_record.to_string(),
+///     "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=t0;gene_id=g0;transcript_id=t0",
+/// );
+/// # Ok::<_, gtf::record::ParseError>(())
+/// ```
+pub fn to_gff_record(record: &Record) -> gff::Record {
+    let attributes = record.attributes();
+    let gene_id = attributes.get(GENE_ID);
+    let transcript_id = attributes.get(TRANSCRIPT_ID);
+
+    let mut gff_entries = Vec::with_capacity(attributes.len() + 2);
+
+    match record.ty() {
+        GENE => {
+            if let Some(id) = gene_id {
+                gff_entries.push(gff::record::attributes::Entry::new(GFF_ID, id));
+            }
+        }
+        TRANSCRIPT => {
+            if let Some(id) = transcript_id {
+                gff_entries.push(gff::record::attributes::Entry::new(GFF_ID, id));
+            }
+
+            if let Some(parent) = gene_id {
+                gff_entries.push(gff::record::attributes::Entry::new(GFF_PARENT, parent));
+            }
+        }
+        _ => {
+            if let Some(parent) = transcript_id.or(gene_id) {
+                gff_entries.push(gff::record::attributes::Entry::new(GFF_PARENT, parent));
+            }
+        }
+    }
+
+    let mut gtf_entry_indices: HashMap<&str, usize> = HashMap::new();
+
+    for entry in attributes.iter() {
+        match gtf_entry_indices.get(entry.key()) {
+            Some(&i) => gff_entries[i].values_mut().push(entry.value().into()),
+            None => {
+                gtf_entry_indices.insert(entry.key(), gff_entries.len());
+                gff_entries.push(gff::record::attributes::Entry::new(
+                    entry.key(),
+                    entry.value(),
+                ));
+            }
+        }
+    }
+
+    let strand = match record.strand() {
+        Some(Strand::Forward) => gff::record::Strand::Forward,
+        Some(Strand::Reverse) => gff::record::Strand::Reverse,
+        None => gff::record::Strand::None,
+    };
+
+    let mut builder = gff::Record::builder()
+        .set_reference_sequence_name(record.reference_sequence_name().into())
+        .set_source(record.source().into())
+        .set_type(record.ty().into())
+        .set_start(record.start())
+        .set_end(record.end())
+        .set_strand(strand)
+        .set_attributes(gff::record::Attributes::from(gff_entries));
+
+    if let Some(score) = record.score() {
+        builder = builder.set_score(score);
+    }
+
+    if let Some(frame) = record.frame() {
+        builder = builder.set_phase(frame.into());
+    }
+
+    builder.build()
+}
+
+/// An error returned when GFF3 records fail to convert to GTF records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConvertError {
+    /// The gene ID of a record cannot be resolved.
+    ///
+    /// This holds the index of the record in the input.
+    MissingGeneId(usize),
+}
+
+impl error::Error for ConvertError {}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingGeneId(i) => write!(f, "missing gene ID at record {}", i),
+        }
+    }
+}
+
+/// Converts a list of GFF3 records to GTF records.
+///
+/// The `gene_id` and `transcript_id` of each record are taken from its attributes, if present.
+/// Otherwise, they are resolved using the `ID` and `Parent` attributes of the given records: a
+/// `gene` uses its `ID` as the `gene_id`; a `transcript` (or `mRNA`) uses its `ID` as the
+/// `transcript_id` and its parent as the `gene`; and all other features use their parent as the
+/// transcript.
+///
+/// The `ID` and `Parent` attributes are not kept. Attributes with multiple values are written as
+/// repeated keys.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff as gff;
+/// use noodles_gtf::convert;
+///
+/// let gff_records: Vec<gff::Record> = [
+///     "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g0",
+///     "sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=t0;Parent=g0",
+///     "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=t0",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()?;
+///
+/// let records = convert::from_gff_records(&gff_records)?;
+///
+/// assert_eq!(
+///     records[2].to_string(),
+///     "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn from_gff_records<'a, I>(records: I) -> Result<Vec<Record>, ConvertError>
+where
+    I: IntoIterator<Item = &'a gff::Record>,
+{
+    let records: Vec<_> = records.into_iter().collect();
+
+    let mut features: HashMap<&str, &gff::Record> = HashMap::new();
+
+    for record in &records {
+        if let Some(id) = get(record, GFF_ID) {
+            features.entry(id).or_insert(record);
+        }
+    }
+
+    let parent_of =
+        |record: &'a gff::Record| get(record, GFF_PARENT).and_then(|id| features.get(id));
+
+    records
+        .iter()
+        .enumerate()
+        .map(|(i, &record)| {
+            let (gene_id, transcript_id) = match record.ty() {
+                GENE => (get(record, GFF_ID), None),
+                ty if is_transcript(ty) => (
+                    parent_of(record).and_then(|gene| get(gene, GFF_ID)),
+                    get(record, GFF_ID),
+                ),
+                _ => match parent_of(record) {
+                    Some(parent) if parent.ty() == GENE => (get(parent, GFF_ID), None),
+                    Some(transcript) => (
+                        parent_of(transcript).and_then(|gene| get(gene, GFF_ID)),
+                        get(transcript, GFF_ID),
+                    ),
+                    None => (None, None),
+                },
+            };
+
+            let gene_id = get(record, GENE_ID)
+                .or(gene_id)
+                .ok_or(ConvertError::MissingGeneId(i))?;
+
+            let transcript_id = get(record, TRANSCRIPT_ID).or(transcript_id);
+
+            Ok(from_gff_record(record, gene_id, transcript_id))
+        })
+        .collect()
+}
+
+fn from_gff_record(record: &gff::Record, gene_id: &str, transcript_id: Option<&str>) -> Record {
+    let mut entries = vec![Entry::new(GENE_ID, gene_id)];
+
+    if let Some(id) = transcript_id {
+        entries.push(Entry::new(TRANSCRIPT_ID, id));
+    }
+
+    for entry in record.attributes().iter() {
+        match entry.key() {
+            GFF_ID | GFF_PARENT | GENE_ID | TRANSCRIPT_ID => {}
+            key => entries.extend(entry.values().iter().map(|value| Entry::new(key, value))),
+        }
+    }
+
+    let mut builder = Record::builder()
+        .set_reference_sequence_name(record.reference_sequence_name())
+        .set_source(record.source())
+        .set_type(record.ty())
+        .set_start(record.start())
+        .set_end(record.end())
+        .set_attributes(Attributes::from(entries));
+
+    if let Some(score) = record.score() {
+        builder = builder.set_score(score);
+    }
+
+    match record.strand() {
+        gff::record::Strand::Forward => builder = builder.set_strand(Strand::Forward),
+        gff::record::Strand::Reverse => builder = builder.set_strand(Strand::Reverse),
+        gff::record::Strand::None | gff::record::Strand::Unknown => {}
+    }
+
+    if let Some(phase) = record.phase() {
+        builder = builder.set_frame(phase.into());
+    }
+
+    builder.build()
+}
+
+fn get<'a>(record: &'a gff::Record, key: &str) -> Option<&'a str> {
+    record.attributes().get(key).map(|entry| entry.value())
+}
+
+fn is_transcript(ty: &str) -> bool {
+    matches!(ty, TRANSCRIPT | "mRNA")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_gff_record() -> Result<(), crate::record::ParseError> {
+        let record: Record = "sq0\tNOODLES\tgene\t8\t34\t.\t-\t.\tgene_id \"g0\";".parse()?;
+        assert_eq!(
+            to_gff_record(&record).to_string(),
+            "sq0\tNOODLES\tgene\t8\t34\t.\t-\t.\tID=g0;gene_id=g0"
+        );
+
+        let record: Record =
+            "sq0\tNOODLES\ttranscript\t8\t34\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";"
+                .parse()?;
+        assert_eq!(
+            to_gff_record(&record).to_string(),
+            "sq0\tNOODLES\ttranscript\t8\t34\t.\t-\t.\tID=t0;Parent=g0;gene_id=g0;transcript_id=t0"
+        );
+
+        let record: Record =
+            "sq0\tNOODLES\tCDS\t8\t13\t5\t.\t2\tgene_id \"g0\"; transcript_id \"t0\";".parse()?;
+        assert_eq!(
+            to_gff_record(&record).to_string(),
+            "sq0\tNOODLES\tCDS\t8\t13\t5\t.\t2\tParent=t0;gene_id=g0;transcript_id=t0"
+        );
+
+        let record: Record =
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; tag \"basic\"; transcript_id \"t0\"; tag \"CCDS\";"
+                .parse()?;
+        assert_eq!(
+            to_gff_record(&record).to_string(),
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=t0;gene_id=g0;tag=basic,CCDS;transcript_id=t0"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_gff_records() -> Result<(), Box<dyn std::error::Error>> {
+        let gff_records: Vec<gff::Record> = [
+            "sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tID=g0;Name=gene0",
+            "sq0\tNOODLES\ttranscript\t8\t34\t.\t+\t.\tID=t0;Parent=g0;tag=basic,CCDS",
+            "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t1\tParent=t0",
+        ]
+        .iter()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()?;
+
+        let records = from_gff_records(&gff_records)?;
+        let actual: Vec<_> = records.iter().map(|record| record.to_string()).collect();
+
+        let expected = [
+            "sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tgene_id \"g0\"; Name \"gene0\";",
+            "sq0\tNOODLES\ttranscript\t8\t34\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\"; tag \"basic\"; tag \"CCDS\";",
+            "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t1\tgene_id \"g0\"; transcript_id \"t0\";",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_gff_records_with_missing_gene_id() -> Result<(), Box<dyn std::error::Error>> {
+        let gff_records: Vec<gff::Record> = ["sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=t0"]
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(
+            from_gff_records(&gff_records),
+            Err(ConvertError::MissingGeneId(0))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let record: Record =
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\"; exon_number \"1\";"
+                .parse()?;
+
+        let gff_record = to_gff_record(&record);
+        assert_eq!(from_gff_records([&gff_record])?, [record]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+//! GTF gene models.
+//!
+//! A gene model groups records into genes, each with a list of transcripts, each with its exons,
+//! coding sequences (CDS), UTRs, and other features (e.g., start and stop codons). Records are
+//! grouped by their `gene_id` and `transcript_id` attributes.
+
+use std::{collections::HashMap, error, fmt};
+
+use noodles_core::Position;
+
+use crate::{record::Strand, Record};
+
+const GENE: &str = "gene";
+const TRANSCRIPT: &str = "transcript";
+const EXON: &str = "exon";
+const CDS: &str = "CDS";
+
+const GENE_ID: &str = "gene_id";
+const TRANSCRIPT_ID: &str = "transcript_id";
+
+/// A gene.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gene {
+    id: String,
+    reference_sequence_name: String,
+    strand: Option<Strand>,
+    start: Position,
+    end: Position,
+    record: Option<Record>,
+    transcripts: Vec<Transcript>,
+}
+
+impl Gene {
+    /// Returns the gene ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+
+    /// Returns the start position.
+    ///
+    /// This is the minimum start position of the gene record and all its transcripts.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// This is the maximum end position of the gene record and all its transcripts.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the `gene` record, if one was given.
+    pub fn record(&self) -> Option<&Record> {
+        self.record.as_ref()
+    }
+
+    /// Returns the transcripts.
+    ///
+    /// Transcripts are in the order they first appear in the input.
+    pub fn transcripts(&self) -> &[Transcript] {
+        &self.transcripts
+    }
+}
+
+/// A transcript.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transcript {
+    id: String,
+    start: Position,
+    end: Position,
+    record: Option<Record>,
+    exons: Vec<Record>,
+    cds: Vec<Record>,
+    utrs: Vec<Record>,
+    others: Vec<Record>,
+}
+
+impl Transcript {
+    fn new(id: String, start: Position, end: Position) -> Self {
+        Self {
+            id,
+            start,
+            end,
+            record: None,
+            exons: Vec::new(),
+            cds: Vec::new(),
+            utrs: Vec::new(),
+            others: Vec::new(),
+        }
+    }
+
+    /// Returns the transcript ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the start position.
+    ///
+    /// This is the minimum start position of the transcript record and all its features.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// This is the maximum end position of the transcript record and all its features.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the `transcript` record, if one was given.
+    pub fn record(&self) -> Option<&Record> {
+        self.record.as_ref()
+    }
+
+    /// Returns the exons in the direction of transcription.
+    pub fn exons(&self) -> &[Record] {
+        &self.exons
+    }
+
+    /// Returns the coding sequences (`CDS`) in the direction of transcription.
+    pub fn cds(&self) -> &[Record] {
+        &self.cds
+    }
+
+    /// Returns the UTRs in the direction of transcription.
+    pub fn utrs(&self) -> &[Record] {
+        &self.utrs
+    }
+
+    /// Returns all other features, e.g., `start_codon` and `stop_codon`, in the direction of
+    /// transcription.
+    pub fn others(&self) -> &[Record] {
+        &self.others
+    }
+
+    fn features_mut(&mut self) -> [&mut Vec<Record>; 4] {
+        [
+            &mut self.exons,
+            &mut self.cds,
+            &mut self.utrs,
+            &mut self.others,
+        ]
+    }
+}
+
+/// An error returned when records fail to assemble into gene models.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssembleError {
+    /// A record is missing a gene ID (`gene_id`).
+    ///
+    /// This holds the index of the record in the input.
+    MissingGeneId(usize),
+    /// A record is missing a transcript ID (`transcript_id`).
+    ///
+    /// This holds the index of the record in the input.
+    MissingTranscriptId(usize),
+    /// The records of a gene are on different reference sequences or strands.
+    ///
+    /// This holds the gene ID.
+    InconsistentGene(String),
+}
+
+impl error::Error for AssembleError {}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingGeneId(i) => write!(f, "missing gene ID at record {}", i),
+            Self::MissingTranscriptId(i) => write!(f, "missing transcript ID at record {}", i),
+            Self::InconsistentGene(id) => write!(
+                f,
+                "inconsistent reference sequence name or strand for gene {}",
+                id
+            ),
+        }
+    }
+}
+
+/// Assembles records into gene models.
+///
+/// Every record must have a `gene_id` attribute, and every record that is not a `gene` must have
+/// a `transcript_id` attribute. The `gene` and `transcript` records are optional. Genes are in the
+/// order they first appear in the input, and transcript features are ordered by position in the
+/// direction of transcription, i.e., ascending on the forward strand and descending on the
+/// reverse strand.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gtf::{self as gtf, gene_model};
+///
+/// let records: Vec<gtf::Record> = [
+///     "sq0\tNOODLES\texon\t21\t34\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+///     "sq0\tNOODLES\texon\t8\t13\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+///     "sq0\tNOODLES\tCDS\t8\t13\t.\t-\t0\tgene_id \"g0\"; transcript_id \"t0\";",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()?;
+///
+/// let genes = gene_model::assemble(records)?;
+/// assert_eq!(genes.len(), 1);
+///
+/// let transcript = &genes[0].transcripts()[0];
+/// assert_eq!(usize::from(transcript.start()), 8);
+/// assert_eq!(usize::from(transcript.end()), 34);
+/// assert_eq!(transcript.exons().len(), 2);
+/// assert_eq!(usize::from(transcript.exons()[0].start()), 21);
+/// assert_eq!(transcript.cds().len(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn assemble<I>(records: I) -> Result<Vec<Gene>, AssembleError>
+where
+    I: IntoIterator<Item = Record>,
+{
+    let mut genes: Vec<Gene> = Vec::new();
+    let mut gene_indices: HashMap<String, usize> = HashMap::new();
+    let mut transcript_indices: HashMap<(usize, String), usize> = HashMap::new();
+
+    for (i, record) in records.into_iter().enumerate() {
+        let gene_id = record
+            .attributes()
+            .get(GENE_ID)
+            .ok_or(AssembleError::MissingGeneId(i))?;
+
+        let gene_index = match gene_indices.get(gene_id) {
+            Some(&j) => {
+                let gene = &mut genes[j];
+
+                if gene.reference_sequence_name != record.reference_sequence_name()
+                    || gene.strand != record.strand()
+                {
+                    return Err(AssembleError::InconsistentGene(gene.id.clone()));
+                }
+
+                gene.start = gene.start.min(record.start());
+                gene.end = gene.end.max(record.end());
+
+                j
+            }
+            None => {
+                let j = genes.len();
+
+                genes.push(Gene {
+                    id: gene_id.into(),
+                    reference_sequence_name: record.reference_sequence_name().into(),
+                    strand: record.strand(),
+                    start: record.start(),
+                    end: record.end(),
+                    record: None,
+                    transcripts: Vec::new(),
+                });
+
+                gene_indices.insert(gene_id.into(), j);
+
+                j
+            }
+        };
+
+        let gene = &mut genes[gene_index];
+
+        if record.ty() == GENE {
+            gene.record = Some(record);
+            continue;
+        }
+
+        let transcript_id = record
+            .attributes()
+            .get(TRANSCRIPT_ID)
+            .ok_or(AssembleError::MissingTranscriptId(i))?;
+
+        let key = (gene_index, transcript_id.to_string());
+
+        let transcript = match transcript_indices.get(&key) {
+            Some(&k) => {
+                let transcript = &mut gene.transcripts[k];
+                transcript.start = transcript.start.min(record.start());
+                transcript.end = transcript.end.max(record.end());
+                transcript
+            }
+            None => {
+                let k = gene.transcripts.len();
+
+                gene.transcripts.push(Transcript::new(
+                    transcript_id.into(),
+                    record.start(),
+                    record.end(),
+                ));
+
+                transcript_indices.insert(key, k);
+
+                &mut gene.transcripts[k]
+            }
+        };
+
+        match record.ty() {
+            TRANSCRIPT => transcript.record = Some(record),
+            EXON => transcript.exons.push(record),
+            CDS => transcript.cds.push(record),
+            ty if is_utr(ty) => transcript.utrs.push(record),
+            _ => transcript.others.push(record),
+        }
+    }
+
+    for gene in &mut genes {
+        let is_reverse = gene.strand == Some(Strand::Reverse);
+
+        for transcript in &mut gene.transcripts {
+            for features in transcript.features_mut() {
+                sort_features(features, is_reverse);
+            }
+        }
+    }
+
+    Ok(genes)
+}
+
+fn is_utr(ty: &str) -> bool {
+    matches!(
+        ty,
+        "UTR" | "5UTR" | "3UTR" | "five_prime_utr" | "three_prime_utr"
+    )
+}
+
+fn sort_features(features: &mut [Record], is_reverse: bool) {
+    if is_reverse {
+        features.sort_by(|a, b| b.start().cmp(&a.start()).then(b.end().cmp(&a.end())));
+    } else {
+        features.sort_by(|a, b| a.start().cmp(&b.start()).then(a.end().cmp(&b.end())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(lines: &[&str]) -> Vec<Record> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_assemble() -> Result<(), AssembleError> {
+        let records = parse(&[
+            "sq0\tNOODLES\tgene\t5\t55\t.\t+\t.\tgene_id \"g0\";",
+            "sq0\tNOODLES\texon\t21\t34\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\tfive_prime_utr\t8\t10\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\tstart_codon\t11\t13\t.\t+\t0\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\texon\t40\t60\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t1\";",
+            "sq1\tNOODLES\texon\t1\t5\t.\t-\t.\tgene_id \"g1\"; transcript_id \"t2\";",
+            "sq1\tNOODLES\texon\t8\t13\t.\t-\t.\tgene_id \"g1\"; transcript_id \"t2\";",
+        ]);
+
+        let genes = assemble(records)?;
+        assert_eq!(genes.len(), 2);
+
+        let gene = &genes[0];
+        assert_eq!(gene.id(), "g0");
+        assert!(gene.record().is_some());
+        assert_eq!(usize::from(gene.start()), 5);
+        assert_eq!(usize::from(gene.end()), 60);
+        assert_eq!(gene.transcripts().len(), 2);
+
+        let transcript = &gene.transcripts()[0];
+        assert_eq!(transcript.id(), "t0");
+        assert!(transcript.record().is_none());
+        assert_eq!(usize::from(transcript.start()), 8);
+        assert_eq!(usize::from(transcript.end()), 34);
+
+        let starts: Vec<_> = transcript
+            .exons()
+            .iter()
+            .map(|record| usize::from(record.start()))
+            .collect();
+        assert_eq!(starts, [8, 21]);
+
+        assert_eq!(transcript.utrs().len(), 1);
+        assert_eq!(transcript.others().len(), 1);
+        assert!(transcript.cds().is_empty());
+
+        let gene = &genes[1];
+        assert_eq!(gene.strand(), Some(Strand::Reverse));
+
+        let starts: Vec<_> = gene.transcripts()[0]
+            .exons()
+            .iter()
+            .map(|record| usize::from(record.start()))
+            .collect();
+        assert_eq!(starts, [8, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_with_invalid_records() {
+        let records = parse(&["sq0\tNOODLES\texon\t8\t13\t.\t+\t.\ttranscript_id \"t0\";"]);
+        assert_eq!(assemble(records), Err(AssembleError::MissingGeneId(0)));
+
+        let records = parse(&["sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\";"]);
+        assert_eq!(
+            assemble(records),
+            Err(AssembleError::MissingTranscriptId(0))
+        );
+
+        let records = parse(&[
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";",
+            "sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";",
+        ]);
+        assert_eq!(
+            assemble(records),
+            Err(AssembleError::InconsistentGene(String::from("g0")))
+        );
+    }
+}
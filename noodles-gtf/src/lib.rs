@@ -2,9 +2,11 @@
 
 //! **noodles-gtf** handles the reading and writing of the Gene Transfer Format (GTF).
 
+pub mod convert;
+pub mod gene_model;
 pub mod line;
-mod reader;
+pub mod reader;
 pub mod record;
-mod writer;
+pub mod writer;
 
 pub use self::{line::Line, reader::Reader, record::Record, writer::Writer};
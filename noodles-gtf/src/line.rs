@@ -4,6 +4,8 @@ use std::{error, fmt, str::FromStr};
 
 use super::{record, Record};
 
+pub(crate) const COMMENT_PREFIX: char = '#';
+
 /// A GTF line.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Line {
@@ -13,6 +15,15 @@ pub enum Line {
     Record(Record),
 }
 
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Comment(s) => write!(f, "{}{}", COMMENT_PREFIX, s),
+            Self::Record(record) => write!(f, "{}", record),
+        }
+    }
+}
+
 /// An error returns when a raw GFF line fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -34,7 +45,7 @@ impl FromStr for Line {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(t) = s.strip_prefix('#') {
+        if let Some(t) = s.strip_prefix(COMMENT_PREFIX) {
             Ok(Self::Comment(t.into()))
         } else {
             s.parse()
@@ -48,6 +59,16 @@ impl FromStr for Line {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        let line = Line::Comment(String::from("!genome-build NDLS-r1"));
+        assert_eq!(line.to_string(), "#!genome-build NDLS-r1");
+
+        let s = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"ndls0\"; transcript_id \"ndls0\";";
+        let line: Line = s.parse().unwrap();
+        assert_eq!(line.to_string(), s);
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(
@@ -1,9 +1,20 @@
+//! GTF reader and iterators.
+
+mod query;
+
+pub use self::query::Query;
+
 use std::{
-    io::{self, BufRead},
+    io::{self, BufRead, Read, Seek},
     iter,
 };
 
-use super::{Line, Record};
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+use noodles_tabix as tabix;
+
+use super::{line::COMMENT_PREFIX, Line, Record};
 
 /// A GTF reader.
 pub struct Reader<R> {
@@ -27,6 +38,53 @@ where
         Self { inner }
     }
 
+    /// Reads the raw GTF header.
+    ///
+    /// The header is the list of comment lines (`#`) at the start of the stream, e.g.,
+    /// `#!genome-build` lines. Each line in the returned header includes its prefix and a trailing
+    /// newline. The header is empty if the stream does not start with a comment.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#!genome-build NDLS-r1
+    /// #!genome-version NDLS
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    ///
+    /// let header = reader.read_header()?;
+    /// assert_eq!(header, "#!genome-build NDLS-r1\n#!genome-version NDLS\n");
+    ///
+    /// assert_eq!(reader.records().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<String> {
+        let mut header = String::new();
+        let mut buf = String::new();
+
+        loop {
+            let src = self.inner.fill_buf()?;
+
+            if src.first() != Some(&(COMMENT_PREFIX as u8)) {
+                break;
+            }
+
+            buf.clear();
+            read_line(&mut self.inner, &mut buf)?;
+
+            header.push_str(&buf);
+            header.push('\n');
+        }
+
+        Ok(header)
+    }
+
     /// Reads a raw GTF line.
     ///
     /// # Examples
@@ -128,6 +186,64 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// The input must be bgzipped and coordinate-sorted and have an associated tabix index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gtf as gtf;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut reader = File::open("annotations.gtf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gtf::Reader::new)?;
+    ///
+    /// let index = tabix::read("annotations.gtf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query(&index, &region)? {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, index: &tabix::Index, region: &Region) -> io::Result<Query<'_, R>> {
+        let reference_sequence_id = resolve_region(index, region)?;
+        let chunks = index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            self,
+            chunks,
+            region.name().into(),
+            region.interval(),
+        ))
+    }
+}
+
+fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<usize> {
+    index
+        .header()
+        .reference_sequence_names()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {:?}",
+                    region
+                ),
+            )
+        })
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
@@ -156,6 +272,78 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_header() -> io::Result<()> {
+        let data = b"#!genome-build NDLS-r1\n#!genome-version NDLS\r\n";
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(
+            reader.read_header()?,
+            "#!genome-build NDLS-r1\n#!genome-version NDLS\n"
+        );
+        assert_eq!(reader.lines().count(), 0);
+
+        let data = b"sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";\n";
+        let mut reader = Reader::new(&data[..]);
+        assert!(reader.read_header()?.is_empty());
+        assert_eq!(reader.records().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let lines = [
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\texon\t21\t34\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq1\tNOODLES\texon\t5\t8\t.\t+\t.\tgene_id \"g1\"; transcript_id \"t1\";",
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"#format: gtf\n")?;
+
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::gff().build());
+
+        for line in lines {
+            let record: Record = line.parse()?;
+
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{}", line)?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start(),
+                record.end(),
+                Chunk::new(start_position, end_position),
+            );
+        }
+
+        let index = indexer.build();
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let region = "sq0:10-20".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(usize::from(records[0].start()), 8);
+
+        let region = "sq1".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].reference_sequence_name(), "sq1");
+
+        let region = "sq2".parse()?;
+        assert!(reader.query(&index, &region).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
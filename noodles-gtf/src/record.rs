@@ -166,6 +166,137 @@ impl Record {
     pub fn attributes(&self) -> &Attributes {
         &self.attributes
     }
+
+    /// Returns a mutable reference to the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.reference_sequence_name_mut() = String::from("sq0");
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// ```
+    pub fn reference_sequence_name_mut(&mut self) -> &mut String {
+        &mut self.reference_sequence_name
+    }
+
+    /// Returns a mutable reference to the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.source_mut() = String::from("NOODLES");
+    /// assert_eq!(record.source(), "NOODLES");
+    /// ```
+    pub fn source_mut(&mut self) -> &mut String {
+        &mut self.source
+    }
+
+    /// Returns a mutable reference to the feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.ty_mut() = String::from("gene");
+    /// assert_eq!(record.ty(), "gene");
+    /// ```
+    pub fn ty_mut(&mut self) -> &mut String {
+        &mut self.ty
+    }
+
+    /// Returns a mutable reference to the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.start_mut() = Position::try_from(8)?;
+    /// assert_eq!(record.start(), Position::try_from(8)?);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start_mut(&mut self) -> &mut Position {
+        &mut self.start
+    }
+
+    /// Returns a mutable reference to the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.end_mut() = Position::try_from(13)?;
+    /// assert_eq!(record.end(), Position::try_from(13)?);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end_mut(&mut self) -> &mut Position {
+        &mut self.end
+    }
+
+    /// Returns a mutable reference to the confidence score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let mut record = gtf::Record::default();
+    /// *record.score_mut() = Some(21.0);
+    /// assert_eq!(record.score(), Some(21.0));
+    /// ```
+    pub fn score_mut(&mut self) -> &mut Option<f32> {
+        &mut self.score
+    }
+
+    /// Returns a mutable reference to the strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Strand};
+    /// let mut record = gtf::Record::default();
+    /// *record.strand_mut() = Some(Strand::Forward);
+    /// assert_eq!(record.strand(), Some(Strand::Forward));
+    /// ```
+    pub fn strand_mut(&mut self) -> &mut Option<Strand> {
+        &mut self.strand
+    }
+
+    /// Returns a mutable reference to the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Frame};
+    /// let mut record = gtf::Record::default();
+    /// *record.frame_mut() = Some(Frame::try_from(0)?);
+    /// assert_eq!(record.frame(), Some(Frame::try_from(0)?));
+    /// # Ok::<_, noodles_gtf::record::frame::ParseError>(())
+    /// ```
+    pub fn frame_mut(&mut self) -> &mut Option<Frame> {
+        &mut self.frame
+    }
+
+    /// Returns a mutable reference to the attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::attributes::Entry};
+    /// let mut record = gtf::Record::default();
+    /// record.attributes_mut().insert(Entry::new("gene_id", "g0"));
+    /// assert_eq!(record.attributes().get("gene_id"), Some("g0"));
+    /// ```
+    pub fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
 }
 
 impl Default for Record {
@@ -399,6 +530,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_built_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = Record::builder()
+            .set_reference_sequence_name("sq0")
+            .set_source("NOODLES")
+            .set_type("exon")
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_strand(Strand::Reverse)
+            .set_frame(Frame::try_from(1)?)
+            .set_attributes(Attributes::from(vec![Entry::new("gene_id", "g0")]))
+            .build();
+
+        *record.score_mut() = Some(5.0);
+        record
+            .attributes_mut()
+            .push(Entry::new("transcript_id", "t0"));
+
+        let actual = record.to_string();
+        let expected = "sq0\tNOODLES\texon\t8\t13\t5\t-\t1\tgene_id \"g0\"; transcript_id \"t0\";";
+        assert_eq!(actual, expected);
+
+        assert_eq!(actual.parse(), Ok(record));
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), noodles_core::position::TryFromIntError> {
         let s = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";";
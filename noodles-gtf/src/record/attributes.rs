@@ -7,16 +7,173 @@ pub use self::entry::Entry;
 use std::{
     error,
     fmt::{self, Write},
+    num,
     ops::Deref,
     str::FromStr,
 };
 
 const DELIMITER: char = ' ';
+const EXON_NUMBER: &str = "exon_number";
 
 /// GTF record attributes.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Attributes(Vec<Entry>);
 
+impl Attributes {
+    /// Returns the value of the first entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![Entry::new("gene_id", "g0")]);
+    ///
+    /// assert_eq!(attributes.get("gene_id"), Some("g0"));
+    /// assert!(attributes.get("transcript_id").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|entry| entry.key() == key)
+            .map(|entry| entry.value())
+    }
+
+    /// Returns an iterator over the values of all entries with the given key.
+    ///
+    /// GTF allows repeated keys, e.g., multiple `tag` attributes. The values are in the order they
+    /// appear in the attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![
+    ///     Entry::new("gene_id", "g0"),
+    ///     Entry::new("tag", "basic"),
+    ///     Entry::new("tag", "CCDS"),
+    /// ]);
+    ///
+    /// assert_eq!(attributes.get_all("tag").collect::<Vec<_>>(), ["basic", "CCDS"]);
+    /// assert_eq!(attributes.get_all("level").count(), 0);
+    /// ```
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |entry| entry.key() == key)
+            .map(|entry| entry.value())
+    }
+
+    /// Parses the value of the first entry with the given key.
+    ///
+    /// This returns `None` if the key is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![
+    ///     Entry::new("level", "2"),
+    ///     Entry::new("transcript_support_level", "NA"),
+    /// ]);
+    ///
+    /// assert_eq!(attributes.get_parsed::<u8>("level"), Some(Ok(2)));
+    /// assert!(matches!(attributes.get_parsed::<u8>("transcript_support_level"), Some(Err(_))));
+    /// assert!(attributes.get_parsed::<u8>("exon_number").is_none());
+    /// ```
+    pub fn get_parsed<T>(&self, key: &str) -> Option<Result<T, T::Err>>
+    where
+        T: FromStr,
+    {
+        self.get(key).map(|value| value.parse())
+    }
+
+    /// Returns the exon number (`exon_number`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![Entry::new("exon_number", "3")]);
+    /// assert_eq!(attributes.exon_number(), Some(Ok(3)));
+    ///
+    /// let attributes = Attributes::default();
+    /// assert!(attributes.exon_number().is_none());
+    /// ```
+    pub fn exon_number(&self) -> Option<Result<usize, num::ParseIntError>> {
+        self.get_parsed(EXON_NUMBER)
+    }
+
+    /// Inserts an entry.
+    ///
+    /// If an entry with the same key exists, it is replaced, and the old entry is returned.
+    /// Otherwise, the entry is appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let mut attributes = Attributes::default();
+    /// assert!(attributes.insert(Entry::new("gene_id", "g0")).is_none());
+    ///
+    /// let old_entry = attributes.insert(Entry::new("gene_id", "g1"));
+    /// assert_eq!(old_entry, Some(Entry::new("gene_id", "g0")));
+    ///
+    /// assert_eq!(attributes.get("gene_id"), Some("g1"));
+    /// ```
+    pub fn insert(&mut self, entry: Entry) -> Option<Entry> {
+        match self.0.iter_mut().find(|e| e.key() == entry.key()) {
+            Some(e) => Some(std::mem::replace(e, entry)),
+            None => {
+                self.0.push(entry);
+                None
+            }
+        }
+    }
+
+    /// Appends an entry.
+    ///
+    /// Unlike [`Self::insert`], this does not replace an existing entry with the same key. GTF
+    /// allows repeated keys, e.g., multiple `tag` attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let mut attributes = Attributes::default();
+    /// attributes.push(Entry::new("tag", "basic"));
+    /// attributes.push(Entry::new("tag", "CCDS"));
+    ///
+    /// assert_eq!(attributes.len(), 2);
+    /// ```
+    pub fn push(&mut self, entry: Entry) {
+        self.0.push(entry);
+    }
+
+    /// Removes the first entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let mut attributes = Attributes::from(vec![Entry::new("gene_id", "g0")]);
+    /// assert_eq!(attributes.remove("gene_id"), Some(Entry::new("gene_id", "g0")));
+    /// assert!(attributes.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<Entry> {
+        self.0
+            .iter()
+            .position(|entry| entry.key() == key)
+            .map(|i| self.0.remove(i))
+    }
+}
+
 impl Deref for Attributes {
     type Target = [Entry];
 
@@ -78,8 +235,8 @@ impl FromStr for Attributes {
 
         let mut entries = Vec::new();
 
-        while let Some(i) = s.chars().position(|c| c == entry::TERMINATOR) {
-            let (raw_entry, tail) = s.split_at(i + 1);
+        while let Some(i) = find_terminator(s) {
+            let (raw_entry, tail) = s.split_at(i + entry::TERMINATOR.len_utf8());
 
             let entry = raw_entry.parse().map_err(ParseError::InvalidEntry)?;
             entries.push(entry);
@@ -95,6 +252,21 @@ impl FromStr for Attributes {
     }
 }
 
+// Finds the byte index of the first entry terminator that is not in a quoted value.
+fn find_terminator(s: &str) -> Option<usize> {
+    let mut is_quoted = false;
+
+    for (i, c) in s.char_indices() {
+        if c == entry::QUOTATION_MARK {
+            is_quoted = !is_quoted;
+        } else if c == entry::TERMINATOR && !is_quoted {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
 // _GTF2.2: A Gene Annotation Format_ (2013-02-25): "Attributes must end in a semicolon which must
 // then be separated from the start of any subsequent attribute by exactly one space character (NOT
 // a tab character)."
@@ -162,4 +334,39 @@ mod tests {
             Err(ParseError::InvalidEntry(_))
         ));
     }
+
+    #[test]
+    fn test_from_str_with_escaped_values() {
+        assert_eq!(
+            r#"gene_id "g0"; note "a %22b%22%3B c\d"; level 2;"#.parse(),
+            Ok(Attributes::from(vec![
+                Entry::new("gene_id", "g0"),
+                Entry::new("note", r#"a "b"; c\d"#),
+                Entry::new("level", "2"),
+            ]))
+        );
+
+        assert_eq!(
+            r#"gene_id "g0"; note "a;b";"#.parse(),
+            Ok(Attributes::from(vec![
+                Entry::new("gene_id", "g0"),
+                Entry::new("note", "a;b"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_repeated_keys() -> Result<(), ParseError> {
+        let s = r#"gene_id "g0"; tag "basic"; exon_number "2"; tag "CCDS";"#;
+        let attributes: Attributes = s.parse()?;
+
+        assert_eq!(
+            attributes.get_all("tag").collect::<Vec<_>>(),
+            ["basic", "CCDS"]
+        );
+        assert_eq!(attributes.exon_number(), Some(Ok(2)));
+        assert_eq!(attributes.to_string(), s);
+
+        Ok(())
+    }
 }
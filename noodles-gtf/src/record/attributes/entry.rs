@@ -1,9 +1,18 @@
 //! GTF record attribute entry.
 
-use std::{error, fmt, str::FromStr};
+use std::{
+    borrow::Cow,
+    error, fmt,
+    str::{self, FromStr},
+};
 
-const SEPARATOR: char = ' ';
-pub(super) const TERMINATOR: char = ';';
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+
+const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS.add(b'"').add(b'%').add(b';');
+
+pub(crate) const SEPARATOR: char = ' ';
+pub(crate) const TERMINATOR: char = ';';
+pub(crate) const QUOTATION_MARK: char = '"';
 
 /// A GTF record attribute entry.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -57,16 +66,36 @@ impl Entry {
     pub fn value(&self) -> &str {
         &self.value
     }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    /// let mut entry = Entry::new("gene_id", "gene0");
+    /// *entry.value_mut() = String::from("gene1");
+    /// assert_eq!(entry.value(), "gene1");
+    /// ```
+    pub fn value_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
 }
 
 impl fmt::Display for Entry {
+    /// Formats the entry with a quoted value.
+    ///
+    /// Quotation marks (`"`), semicolons (`;`), percent signs (`%`), and control characters in the
+    /// value are percent-encoded.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            r#"{}{}"{}"{}"#,
+            "{}{}{}{}{}{}",
             self.key(),
             SEPARATOR,
-            self.value(),
+            QUOTATION_MARK,
+            percent_encode(self.value()),
+            QUOTATION_MARK,
             TERMINATOR
         )
     }
@@ -79,6 +108,8 @@ pub enum ParseError {
     Empty,
     /// The input is invalid.
     Invalid,
+    /// The value is invalid.
+    InvalidValue(str::Utf8Error),
     /// The terminator is missing.
     MissingTerminator,
 }
@@ -90,6 +121,7 @@ impl fmt::Display for ParseError {
         match self {
             Self::Empty => write!(f, "empty input"),
             Self::Invalid => write!(f, "invalid input"),
+            Self::InvalidValue(e) => write!(f, "invalid value: {}", e),
             Self::MissingTerminator => write!(f, "missing terminator"),
         }
     }
@@ -113,15 +145,29 @@ impl FromStr for Entry {
 fn parse_entry(s: &str) -> Result<Entry, ParseError> {
     match s.split_once(SEPARATOR) {
         Some((k, v)) => {
-            let value = parse_value(v);
+            let value = parse_value(v)?;
             Ok(Entry::new(k, value))
         }
         None => Err(ParseError::Invalid),
     }
 }
 
-fn parse_value(s: &str) -> String {
-    s.trim_matches('"').into()
+// Quoted values are percent-decoded.
+fn parse_value(s: &str) -> Result<String, ParseError> {
+    match s
+        .strip_prefix(QUOTATION_MARK)
+        .and_then(|t| t.strip_suffix(QUOTATION_MARK))
+    {
+        Some(t) => percent_decode_str(t)
+            .decode_utf8()
+            .map(|value| value.into_owned())
+            .map_err(ParseError::InvalidValue),
+        None => Ok(s.trim_matches(QUOTATION_MARK).into()),
+    }
+}
+
+pub(crate) fn percent_encode(s: &str) -> Cow<'_, str> {
+    utf8_percent_encode(s, PERCENT_ENCODE_SET).into()
 }
 
 #[cfg(test)]
@@ -132,6 +178,9 @@ mod tests {
     fn test_fmt() {
         let entry = Entry::new("gene_id", "g0");
         assert_eq!(entry.to_string(), r#"gene_id "g0";"#);
+
+        let entry = Entry::new("note", r#"a "b"; 100% c\d"#);
+        assert_eq!(entry.to_string(), r#"note "a %22b%22%3B 100%25 c\d";"#);
     }
 
     #[test]
@@ -148,6 +197,14 @@ mod tests {
             r#"gene_id 0;"#.parse::<Entry>(),
             Ok(Entry::new("gene_id", "0"))
         );
+        assert_eq!(
+            r#"note "a %22b%22%3B 100%25 c\d";"#.parse::<Entry>(),
+            Ok(Entry::new("note", r#"a "b"; 100% c\d"#))
+        );
+        assert_eq!(
+            r#"note "c\\d\";"#.parse::<Entry>(),
+            Ok(Entry::new("note", r#"c\\d\"#))
+        );
 
         assert_eq!("".parse::<Entry>(), Err(ParseError::Empty));
         assert_eq!(
@@ -157,5 +214,9 @@ mod tests {
         assert_eq!(r#""""#.parse::<Entry>(), Err(ParseError::MissingTerminator));
         assert_eq!("gene_id;".parse::<Entry>(), Err(ParseError::Invalid));
         assert_eq!(r#""";"#.parse::<Entry>(), Err(ParseError::Invalid));
+        assert!(matches!(
+            r#"note "%ff";"#.parse::<Entry>(),
+            Err(ParseError::InvalidValue(_))
+        ));
     }
 }
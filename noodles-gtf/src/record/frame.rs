@@ -2,10 +2,15 @@
 
 use std::{error, fmt, num, str::FromStr};
 
+use noodles_gff as gff;
+
 const MIN: u8 = 0;
 const MAX: u8 = 2;
 
 /// A GTF record frame.
+///
+/// The frame is the number of bases to remove from the start of a CDS feature to reach the first
+/// base of the next codon, i.e., 0, 1, or 2. This is equivalent to the phase of a GFF3 record.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Frame(u8);
 
@@ -72,6 +77,26 @@ impl From<Frame> for u8 {
     }
 }
 
+impl From<gff::record::Phase> for Frame {
+    fn from(phase: gff::record::Phase) -> Self {
+        match phase {
+            gff::record::Phase::Zero => Self(0),
+            gff::record::Phase::One => Self(1),
+            gff::record::Phase::Two => Self(2),
+        }
+    }
+}
+
+impl From<Frame> for gff::record::Phase {
+    fn from(frame: Frame) -> Self {
+        match frame.0 {
+            0 => Self::Zero,
+            1 => Self::One,
+            _ => Self::Two,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +131,18 @@ mod tests {
     fn test_from_frame_for_u8() {
         assert_eq!(u8::from(Frame(0)), 0);
     }
+
+    #[test]
+    fn test_from_phase_for_frame() {
+        assert_eq!(Frame::from(gff::record::Phase::Zero), Frame(0));
+        assert_eq!(Frame::from(gff::record::Phase::One), Frame(1));
+        assert_eq!(Frame::from(gff::record::Phase::Two), Frame(2));
+    }
+
+    #[test]
+    fn test_from_frame_for_phase() {
+        assert_eq!(gff::record::Phase::from(Frame(0)), gff::record::Phase::Zero);
+        assert_eq!(gff::record::Phase::from(Frame(1)), gff::record::Phase::One);
+        assert_eq!(gff::record::Phase::from(Frame(2)), gff::record::Phase::Two);
+    }
 }
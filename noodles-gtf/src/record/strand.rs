@@ -59,6 +59,24 @@ impl FromStr for Strand {
     }
 }
 
+impl From<Strand> for noodles_core::Strand {
+    fn from(strand: Strand) -> Self {
+        match strand {
+            Strand::Forward => Self::Forward,
+            Strand::Reverse => Self::Reverse,
+        }
+    }
+}
+
+impl From<noodles_core::Strand> for Strand {
+    fn from(strand: noodles_core::Strand) -> Self {
+        match strand {
+            noodles_core::Strand::Forward => Self::Forward,
+            noodles_core::Strand::Reverse => Self::Reverse,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +100,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_strand_for_noodles_core_strand() {
+        assert_eq!(
+            noodles_core::Strand::from(Strand::Forward),
+            noodles_core::Strand::Forward
+        );
+        assert_eq!(
+            noodles_core::Strand::from(Strand::Reverse),
+            noodles_core::Strand::Reverse
+        );
+    }
+
+    #[test]
+    fn test_from_noodles_core_strand_for_strand() {
+        assert_eq!(Strand::from(noodles_core::Strand::Forward), Strand::Forward);
+        assert_eq!(Strand::from(noodles_core::Strand::Reverse), Strand::Reverse);
+    }
 }
@@ -1,16 +1,43 @@
+//! GTF writer.
+
+mod attribute_value_quoting;
+mod builder;
+
+pub use self::{attribute_value_quoting::AttributeValueQuoting, builder::Builder};
+
 use std::io::{self, Write};
 
-use super::Record;
+use super::{
+    line::COMMENT_PREFIX,
+    record::{
+        attributes::entry::{percent_encode, QUOTATION_MARK, SEPARATOR, TERMINATOR},
+        NULL_FIELD,
+    },
+    Line, Record,
+};
 
 /// A GTF writer.
 pub struct Writer<W> {
     inner: W,
+    attribute_value_quoting: AttributeValueQuoting,
 }
 
 impl<W> Writer<W>
 where
     W: Write,
 {
+    /// Creates a GTF writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let writer = gtf::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
     /// Creates a GTF writer.
     ///
     /// # Examples
@@ -20,7 +47,7 @@ where
     /// let writer = gtf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying writer.
@@ -65,6 +92,46 @@ where
         self.inner
     }
 
+    /// Writes a GTF line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let mut writer = gtf::Writer::new(Vec::new());
+    ///
+    /// let line = gtf::Line::Comment(String::from("!genome-build NDLS-r1"));
+    /// writer.write_line(&line)?;
+    ///
+    /// assert_eq!(writer.into_inner(), b"#!genome-build NDLS-r1\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_line(&mut self, line: &Line) -> io::Result<()> {
+        writeln!(self.inner, "{}", line)
+    }
+
+    /// Writes a GTF comment.
+    ///
+    /// The comment prefix (`#`) is prepended to the given comment. Header lines, e.g.,
+    /// `#!genome-build`, are written as comments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let mut writer = gtf::Writer::new(Vec::new());
+    /// writer.write_comment("!genome-build NDLS-r1")?;
+    /// assert_eq!(writer.into_inner(), b"#!genome-build NDLS-r1\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_comment(&mut self, comment: &str) -> io::Result<()> {
+        writeln!(self.inner, "{}{}", COMMENT_PREFIX, comment)
+    }
+
     /// Writes a GTF record.
     ///
     /// # Examples
@@ -83,6 +150,96 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        writeln!(self.inner, "{}", record)
+        write_record(&mut self.inner, record, self.attribute_value_quoting)
+    }
+}
+
+fn write_record<W>(
+    writer: &mut W,
+    record: &Record,
+    attribute_value_quoting: AttributeValueQuoting,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write!(
+        writer,
+        "{}\t{}\t{}\t{}\t{}\t",
+        record.reference_sequence_name(),
+        record.source(),
+        record.ty(),
+        record.start(),
+        record.end()
+    )?;
+
+    match record.score() {
+        Some(score) => write!(writer, "{}\t", score)?,
+        None => write!(writer, "{}\t", NULL_FIELD)?,
+    }
+
+    match record.strand() {
+        Some(strand) => write!(writer, "{}\t", strand)?,
+        None => write!(writer, "{}\t", NULL_FIELD)?,
+    }
+
+    match record.frame() {
+        Some(frame) => write!(writer, "{}\t", frame)?,
+        None => write!(writer, "{}\t", NULL_FIELD)?,
+    }
+
+    for (i, entry) in record.attributes().iter().enumerate() {
+        if i > 0 {
+            write!(writer, "{}", SEPARATOR)?;
+        }
+
+        write!(writer, "{}{}", entry.key(), SEPARATOR)?;
+
+        if attribute_value_quoting.is_quoted(entry.value()) {
+            write!(
+                writer,
+                "{}{}{}",
+                QUOTATION_MARK,
+                percent_encode(entry.value()),
+                QUOTATION_MARK
+            )?;
+        } else {
+            write!(writer, "{}", entry.value())?;
+        }
+
+        write!(writer, "{}", TERMINATOR)?;
+    }
+
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{attributes::Entry, Attributes};
+
+    #[test]
+    fn test_write_record_with_attribute_value_quoting() -> io::Result<()> {
+        let record = Record::builder()
+            .set_attributes(Attributes::from(vec![
+                Entry::new("gene_id", "g0"),
+                Entry::new("exon_number", "2"),
+                Entry::new("note", r#"a "b"; c"#),
+            ]))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&record)?;
+        let expected = b".\t.\t.\t1\t1\t.\t.\t.\tgene_id \"g0\"; exon_number \"2\"; note \"a %22b%22%3B c\";\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_attribute_value_quoting(AttributeValueQuoting::NonNumeric)
+            .build();
+        writer.write_record(&record)?;
+        let expected =
+            b".\t.\t.\t1\t1\t.\t.\t.\tgene_id \"g0\"; exon_number 2; note \"a %22b%22%3B c\";\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
     }
 }
@@ -0,0 +1,77 @@
+/// The quoting of GTF record attribute values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttributeValueQuoting {
+    /// All values are quoted, e.g., `exon_number "2";`.
+    Always,
+    /// Only non-numeric values are quoted, e.g., `exon_number 2;`.
+    ///
+    /// A value is numeric if it is an optionally signed integer or decimal number.
+    NonNumeric,
+}
+
+impl AttributeValueQuoting {
+    pub(super) fn is_quoted(self, value: &str) -> bool {
+        match self {
+            Self::Always => true,
+            Self::NonNumeric => !is_numeric(value),
+        }
+    }
+}
+
+impl Default for AttributeValueQuoting {
+    fn default() -> Self {
+        Self::Always
+    }
+}
+
+fn is_numeric(s: &str) -> bool {
+    let t = s.strip_prefix(['+', '-']).unwrap_or(s);
+
+    let (integer, fraction) = match t.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (t, None),
+    };
+
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+    match fraction {
+        Some(fraction) => {
+            (!integer.is_empty() || !fraction.is_empty())
+                && is_digits(integer)
+                && is_digits(fraction)
+        }
+        None => !integer.is_empty() && is_digits(integer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            AttributeValueQuoting::default(),
+            AttributeValueQuoting::Always
+        );
+    }
+
+    #[test]
+    fn test_is_numeric() {
+        assert!(is_numeric("0"));
+        assert!(is_numeric("13"));
+        assert!(is_numeric("-8"));
+        assert!(is_numeric("+8"));
+        assert!(is_numeric("0.5"));
+        assert!(is_numeric(".5"));
+        assert!(is_numeric("5."));
+
+        assert!(!is_numeric(""));
+        assert!(!is_numeric("-"));
+        assert!(!is_numeric("."));
+        assert!(!is_numeric("g0"));
+        assert!(!is_numeric("1e5"));
+        assert!(!is_numeric("1.2.3"));
+        assert!(!is_numeric("NaN"));
+    }
+}
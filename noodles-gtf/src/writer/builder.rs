@@ -0,0 +1,59 @@
+use std::io::Write;
+
+use super::{AttributeValueQuoting, Writer};
+
+/// A GTF writer builder.
+pub struct Builder<W> {
+    inner: W,
+    attribute_value_quoting: AttributeValueQuoting,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(super) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            attribute_value_quoting: AttributeValueQuoting::default(),
+        }
+    }
+
+    /// Sets the quoting of record attribute values.
+    ///
+    /// Quotation marks (`"`), semicolons (`;`), percent signs (`%`), and control characters in
+    /// quoted values are always percent-encoded.
+    ///
+    /// By default, this is set to [`AttributeValueQuoting::Always`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, writer::AttributeValueQuoting};
+    ///
+    /// let builder = gtf::Writer::builder(Vec::new())
+    ///     .set_attribute_value_quoting(AttributeValueQuoting::NonNumeric);
+    /// ```
+    pub fn set_attribute_value_quoting(
+        mut self,
+        attribute_value_quoting: AttributeValueQuoting,
+    ) -> Self {
+        self.attribute_value_quoting = attribute_value_quoting;
+        self
+    }
+
+    /// Builds a GTF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let writer = gtf::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> Writer<W> {
+        Writer {
+            inner: self.inner,
+            attribute_value_quoting: self.attribute_value_quoting,
+        }
+    }
+}
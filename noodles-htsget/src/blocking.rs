@@ -0,0 +1,13 @@
+//! Blocking htsget client.
+//!
+//! The blocking client wraps the async [`crate::Client`] and runs its requests to completion on
+//! a single-threaded runtime owned by the client. It must not be used from within an async
+//! runtime.
+
+mod client;
+mod reader;
+pub mod reads;
+mod response;
+pub mod variants;
+
+pub use self::{client::Client, response::Response};
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use tokio::runtime::{self, Runtime};
+use url::Url;
+
+use super::{reads, variants};
+
+/// A blocking htsget client.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a blocking htsget client with a default HTTP client.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the runtime fails to build.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::blocking::Client::new("https://localhost/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn new(base_url: Url) -> Self {
+        Self::from(crate::Client::new(base_url))
+    }
+
+    /// Creates a reads request for the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::blocking::Client::new("https://localhost/".parse()?);
+    /// let reads = client.reads("NDLS0001");
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn reads<I>(&self, id: I) -> reads::Builder
+    where
+        I: Into<String>,
+    {
+        reads::Builder::new(self.inner.reads(id), self.runtime.clone())
+    }
+
+    /// Creates a variants request for the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::blocking::Client::new("https://localhost/".parse()?);
+    /// let variants = client.variants("NDLS0001");
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn variants<I>(&self, id: I) -> variants::Builder
+    where
+        I: Into<String>,
+    {
+        variants::Builder::new(self.inner.variants(id), self.runtime.clone())
+    }
+}
+
+impl From<crate::Client> for Client {
+    /// Creates a blocking htsget client from an async htsget client.
+    ///
+    /// This can be used to build a blocking client with the options of [`crate::client::Builder`],
+    /// e.g., authentication.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the runtime fails to build.
+    fn from(inner: crate::Client) -> Self {
+        let runtime = runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build runtime");
+
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use noodles_core::Region;
+
+    use super::*;
+
+    #[test]
+    fn test_send() -> Result<(), Box<dyn std::error::Error>> {
+        // "##fileformat=VCFv4.3\n" and "sq0\t1\t.\tA\t.\t.\tPASS\t.\n"
+        const TICKET: &str = r#"{"htsget":{"format":"VCF","urls":[
+            {"url":"data:;base64,IyNmaWxlZm9ybWF0PVZDRnY0LjMK","class":"header"},
+            {"url":"data:;base64,c3EwCTEJLglBCS4JLglQQVNTCS4K","class":"body"}
+        ]}}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url: Url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        let server = thread::spawn(move || -> std::io::Result<String> {
+            let (stream, _) = listener.accept()?;
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line)?;
+
+                if let Some(s) = line.to_lowercase().strip_prefix("content-length: ") {
+                    content_length = s.trim().parse().unwrap_or(0);
+                }
+
+                if line == "\r\n" {
+                    break;
+                }
+
+                head.push_str(&line);
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body)?;
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                TICKET.len(),
+                TICKET
+            );
+
+            let mut stream = reader.into_inner();
+            stream.write_all(response.as_bytes())?;
+
+            Ok(head)
+        });
+
+        let client = Client::new(base_url);
+
+        let response = client
+            .variants("NDLS0001")
+            .add_region(Region::new("sq0", ..))
+            .send()?;
+
+        let mut buf = String::new();
+        response.reader().read_to_string(&mut buf)?;
+        assert_eq!(buf, "##fileformat=VCFv4.3\nsq0\t1\t.\tA\t.\t.\tPASS\t.\n");
+
+        let head = server.join().unwrap()?;
+        assert!(head.starts_with("POST /variants/NDLS0001 "));
+
+        Ok(())
+    }
+}
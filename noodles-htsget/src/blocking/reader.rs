@@ -0,0 +1,51 @@
+use std::io::{self, BufRead, Read};
+
+use bytes::{Buf, Bytes};
+
+/// A reader over an iterator of data blocks.
+pub(super) struct Reader<I> {
+    chunks: I,
+    buf: Bytes,
+}
+
+impl<I> Reader<I> {
+    pub(super) fn new(chunks: I) -> Self {
+        Self {
+            chunks,
+            buf: Bytes::new(),
+        }
+    }
+}
+
+impl<I> Read for Reader<I>
+where
+    I: Iterator<Item = crate::Result<Bytes>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut src = self.fill_buf()?;
+        let amt = src.read(buf)?;
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<I> BufRead for Reader<I>
+where
+    I: Iterator<Item = crate::Result<Bytes>>,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while !self.buf.has_remaining() {
+            match self.chunks.next() {
+                Some(Ok(buf)) => self.buf = buf,
+                Some(Err(e)) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+                None => break,
+            }
+        }
+
+        Ok(self.buf.chunk())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.buf.advance(amt);
+    }
+}
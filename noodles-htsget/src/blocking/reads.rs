@@ -0,0 +1,48 @@
+//! Blocking reads endpoint.
+
+use std::sync::Arc;
+
+use noodles_core::Region;
+use tokio::runtime::Runtime;
+
+use super::Response;
+pub use crate::reads::Format;
+use crate::request::Class;
+
+/// A blocking reads endpoint builder.
+pub struct Builder {
+    inner: crate::reads::Builder,
+    runtime: Arc<Runtime>,
+}
+
+impl Builder {
+    pub(super) fn new(inner: crate::reads::Builder, runtime: Arc<Runtime>) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Sets the data format.
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.inner = self.inner.set_format(format);
+        self
+    }
+
+    /// Sets the data stream filter.
+    ///
+    /// Setting this discards all other options upon send.
+    pub fn set_class(mut self, class: Class) -> Self {
+        self.inner = self.inner.set_class(class);
+        self
+    }
+
+    /// Adds a region to query.
+    pub fn add_region(mut self, region: Region) -> Self {
+        self.inner = self.inner.add_region(region);
+        self
+    }
+
+    /// Sends the request.
+    pub fn send(self) -> crate::Result<Response> {
+        let response = self.runtime.block_on(self.inner.send())?;
+        Ok(Response::new(response, self.runtime))
+    }
+}
@@ -0,0 +1,40 @@
+use std::{io::BufRead, sync::Arc};
+
+use bytes::Bytes;
+use futures::StreamExt;
+use tokio::runtime::Runtime;
+
+use super::reader::Reader;
+
+/// A blocking htsget response.
+#[derive(Debug)]
+pub struct Response {
+    inner: crate::Response,
+    runtime: Arc<Runtime>,
+}
+
+impl Response {
+    pub(super) fn new(inner: crate::Response, runtime: Arc<Runtime>) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Returns the record ID.
+    pub fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Returns an iterator over the data from the ticket URLs.
+    pub fn chunks(&self) -> impl Iterator<Item = crate::Result<Bytes>> + '_ {
+        let mut chunks = self.inner.chunks();
+        std::iter::from_fn(move || self.runtime.block_on(chunks.next()))
+    }
+
+    /// Returns a reader over the data from the ticket URLs.
+    ///
+    /// The data blocks, including any header class block, are concatenated in ticket order, and
+    /// inline (data URI) blocks are decoded. The result is a complete file in the ticket format,
+    /// which can be passed directly to a format reader, e.g., `bam::Reader` or `vcf::Reader`.
+    pub fn reader(&self) -> impl BufRead + '_ {
+        Reader::new(self.chunks())
+    }
+}
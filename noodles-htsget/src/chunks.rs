@@ -49,7 +49,12 @@ async fn resolve_data(
             request = request.header(key, value);
         }
 
-        match request.send().await.map_err(Error::Request) {
+        match request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(Error::Request)
+        {
             Ok(response) => Box::pin(response.bytes_stream().map_err(Error::Request)),
             Err(e) => Box::pin(stream::once(async { Err(e) })),
         }
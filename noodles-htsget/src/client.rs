@@ -1,12 +1,31 @@
+//! htsget client.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    error, fmt,
+    sync::{Arc, Mutex},
+};
+
+use futures::future::BoxFuture;
+use reqwest::{header::HeaderMap, RequestBuilder, StatusCode};
 use url::Url;
 
-use super::{reads, request, request::Kind, variants};
+use super::{reads, request, request::Kind, variants, Error};
+
+type BoxError = Box<dyn error::Error + Send + Sync>;
+
+type TokenRefresh = Arc<dyn Fn() -> BoxFuture<'static, Result<String, BoxError>> + Send + Sync>;
 
 /// A htsget client.
 #[derive(Clone, Debug)]
 pub struct Client {
     http_client: reqwest::Client,
     base_url: Url,
+    headers: HeaderMap,
+    auth: Auth,
 }
 
 impl Client {
@@ -39,9 +58,24 @@ impl Client {
         Self {
             http_client,
             base_url,
+            headers: HeaderMap::new(),
+            auth: Auth::default(),
         }
     }
 
+    /// Creates an htsget client builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let builder = htsget::Client::builder("https://localhost/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn builder(base_url: Url) -> Builder {
+        Builder::new(base_url)
+    }
+
     pub(crate) fn http_client(&self) -> &reqwest::Client {
         &self.http_client
     }
@@ -50,6 +84,46 @@ impl Client {
         &self.base_url
     }
 
+    /// Sends a ticket request with the client headers and bearer token.
+    ///
+    /// If the request is unauthorized and a token refresh hook is set, the token is refreshed,
+    /// and the request is retried once.
+    pub(crate) async fn send_ticket_request(
+        &self,
+        request: RequestBuilder,
+    ) -> crate::Result<reqwest::Response> {
+        let retry_request = request.try_clone();
+
+        let request = self.authorize(request).await?;
+        let response = request.send().await.map_err(Error::Request)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED && self.auth.refresh.is_some() {
+            if let Some(retry_request) = retry_request {
+                self.auth.refresh_token().await?;
+                let request = self.authorize(retry_request).await?;
+                return request.send().await.map_err(Error::Request);
+            }
+        }
+
+        Ok(response)
+    }
+
+    async fn authorize(&self, request: RequestBuilder) -> crate::Result<RequestBuilder> {
+        let mut request = request.headers(self.headers.clone());
+
+        let token = match self.auth.token() {
+            Some(token) => Some(token),
+            None if self.auth.refresh.is_some() => Some(self.auth.refresh_token().await?),
+            None => None,
+        };
+
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        Ok(request)
+    }
+
     /// Creates a reads request for the given ID.
     ///
     /// # Examples
@@ -86,3 +160,132 @@ impl Client {
         variants::Builder::new(builder)
     }
 }
+
+#[derive(Clone, Default)]
+struct Auth {
+    token: Arc<Mutex<Option<String>>>,
+    refresh: Option<TokenRefresh>,
+}
+
+impl Auth {
+    fn token(&self) -> Option<String> {
+        self.token.lock().ok().and_then(|token| token.clone())
+    }
+
+    fn set_token(&self, token: String) {
+        if let Ok(mut t) = self.token.lock() {
+            *t = Some(token);
+        }
+    }
+
+    async fn refresh_token(&self) -> crate::Result<String> {
+        match &self.refresh {
+            Some(refresh) => {
+                let token = refresh().await.map_err(Error::Authentication)?;
+                self.set_token(token.clone());
+                Ok(token)
+            }
+            None => Err(Error::Input),
+        }
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The token is intentionally omitted.
+        f.debug_struct("Auth")
+            .field("has_token", &self.token().is_some())
+            .field("has_refresh", &self.refresh.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_ticket_request_with_token_refresh() -> Result<(), Box<dyn error::Error>> {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpListener,
+        };
+
+        const UNAUTHORIZED: &str =
+            r#"{"htsget":{"error":"InvalidAuthentication","message":"expired"}}"#;
+        const TICKET: &str =
+            r#"{"htsget":{"format":"BAM","urls":[{"url":"data:;base64,bm9vZGxlcw=="}]}}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url: Url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        let server = tokio::spawn(async move {
+            let mut heads = Vec::new();
+
+            for (status, body) in [("401 Unauthorized", UNAUTHORIZED), ("200 OK", TICKET)] {
+                let (mut stream, _) = listener.accept().await?;
+
+                let mut buf = Vec::new();
+
+                while !buf.ends_with(b"\r\n\r\n") {
+                    buf.push(stream.read_u8().await?);
+                }
+
+                let head = String::from_utf8_lossy(&buf).to_lowercase();
+
+                let content_length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+
+                let mut body_buf = vec![0; content_length];
+                stream.read_exact(&mut body_buf).await?;
+
+                heads.push(head);
+
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+
+                stream.write_all(response.as_bytes()).await?;
+                stream.shutdown().await?;
+            }
+
+            Ok::<_, std::io::Error>(heads)
+        });
+
+        let client = Client::builder(base_url)
+            .set_bearer_token("a")
+            .set_token_refresh(|| async { Ok(String::from("b")) })
+            .add_header("x-project", "ndls")
+            .build()?;
+
+        let response = client.reads("NDLS0001").send().await?;
+
+        let mut buf = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut response.reader(), &mut buf).await?;
+        assert_eq!(buf, b"noodles");
+
+        let heads = server.await??;
+        assert_eq!(heads.len(), 2);
+        assert!(heads[0].contains("authorization: bearer a\r\n"));
+        assert!(heads[0].contains("x-project: ndls\r\n"));
+        assert!(heads[1].contains("authorization: bearer b\r\n"));
+        assert!(heads[1].contains("x-project: ndls\r\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_header() {
+        let base_url: Url = "https://localhost/".parse().unwrap();
+        let result = Client::builder(base_url)
+            .add_header("x project", "ndls")
+            .build();
+        assert!(matches!(result, Err(Error::Input)));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{future::Future, sync::Arc};
+
+use futures::FutureExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use url::Url;
+
+use super::{Auth, BoxError, Client};
+use crate::Error;
+
+/// An htsget client builder.
+#[derive(Debug)]
+pub struct Builder {
+    base_url: Url,
+    http_client: Option<reqwest::Client>,
+    headers: Vec<(String, String)>,
+    auth: Auth,
+}
+
+impl Builder {
+    pub(super) fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            http_client: None,
+            headers: Vec::new(),
+            auth: Auth::default(),
+        }
+    }
+
+    /// Sets the HTTP client.
+    ///
+    /// By default, a default HTTP client is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::builder("https://localhost/".parse()?)
+    ///     .set_http_client(reqwest::Client::new())
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the bearer token used to authorize ticket requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::builder("https://localhost/".parse()?)
+    ///     .set_bearer_token("ndls")
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_bearer_token<T>(self, token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.auth.set_token(token.into());
+        self
+    }
+
+    /// Sets a hook that returns a new bearer token.
+    ///
+    /// The hook is called before the first ticket request if no bearer token is set and again
+    /// whenever a ticket request is rejected as unauthorized (401), in which case the request is
+    /// retried once with the new token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::builder("https://localhost/".parse()?)
+    ///     .set_token_refresh(|| async {
+    ///         // e.g., exchange a refresh token with an identity provider
+    ///         Ok(String::from("ndls"))
+    ///     })
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_token_refresh<F, Fut>(mut self, refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, BoxError>> + Send + 'static,
+    {
+        self.auth.refresh = Some(Arc::new(move || refresh().boxed()));
+        self
+    }
+
+    /// Adds a header that is sent with each ticket request.
+    ///
+    /// Headers are validated when the client is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::builder("https://localhost/".parse()?)
+    ///     .add_header("x-project", "ndls")
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_header<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Builds an htsget client.
+    ///
+    /// This returns an input error if a header name or value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::builder("https://localhost/".parse()?).build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self) -> crate::Result<Client> {
+        let mut headers = HeaderMap::new();
+
+        for (key, value) in self.headers {
+            let key = HeaderName::try_from(key).map_err(|_| Error::Input)?;
+            let value = HeaderValue::try_from(value).map_err(|_| Error::Input)?;
+            headers.append(key, value);
+        }
+
+        Ok(Client {
+            http_client: self.http_client.unwrap_or_default(),
+            base_url: self.base_url,
+            headers,
+            auth: self.auth,
+        })
+    }
+}
@@ -2,9 +2,12 @@
 
 //! **noodles-htsget** is an htsget client.
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub(crate) mod chunks;
-mod client;
+pub mod client;
 mod format;
+mod reader;
 pub mod reads;
 pub(crate) mod request;
 pub(crate) mod response;
@@ -31,6 +34,8 @@ pub enum Error {
     Decode(base64::DecodeError),
     /// The data URL is invalid.
     InvalidDataUrl,
+    /// The authentication token failed to refresh.
+    Authentication(Box<dyn error::Error + Send + Sync>),
 }
 
 impl error::Error for Error {}
@@ -44,6 +49,7 @@ impl fmt::Display for Error {
             Self::Response(e) => e.fmt(f),
             Self::Decode(e) => write!(f, "decode error: {}", e),
             Self::InvalidDataUrl => f.write_str("invalid data URL"),
+            Self::Authentication(e) => write!(f, "authentication error: {}", e),
         }
     }
 }
@@ -0,0 +1,98 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::{Buf, Bytes};
+use futures::{ready, Stream};
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+/// An async reader over a stream of data blocks.
+pub(crate) struct Reader<S> {
+    stream: S,
+    buf: Bytes,
+}
+
+impl<S> Reader<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buf: Bytes::new(),
+        }
+    }
+}
+
+impl<S> AsyncRead for Reader<S>
+where
+    S: Stream<Item = crate::Result<Bytes>> + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let src = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let amt = src.len().min(buf.remaining());
+        buf.put_slice(&src[..amt]);
+        self.consume(amt);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S> AsyncBufRead for Reader<S>
+where
+    S: Stream<Item = crate::Result<Bytes>> + Unpin,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+
+        while !this.buf.has_remaining() {
+            match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+                Some(Ok(buf)) => this.buf = buf,
+                Some(Err(e)) => return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e))),
+                None => break,
+            }
+        }
+
+        Poll::Ready(Ok(this.buf.chunk()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().buf.advance(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_read() -> io::Result<()> {
+        let blocks = stream::iter(vec![
+            Ok(Bytes::from("noo")),
+            Ok(Bytes::new()),
+            Ok(Bytes::from("dles")),
+        ]);
+
+        let mut reader = Reader::new(blocks);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+
+        let blocks = stream::iter(vec![Ok(Bytes::from("noo")), Err(Error::InvalidDataUrl)]);
+
+        let mut reader = Reader::new(blocks);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf).await,
+            Err(e) if e.kind() == io::ErrorKind::Other
+        ));
+
+        Ok(())
+    }
+}
@@ -54,7 +54,7 @@ impl Builder {
             None => request.json(&self.payload),
         };
 
-        let response = request.send().await.map_err(Error::Request)?;
+        let response = self.client.send_ticket_request(request).await?;
 
         if response.status().is_client_error() {
             let data: ErrorResponse = response.json().await.map_err(Error::Request)?;
@@ -1,4 +1,4 @@
-use noodles_core::{region::Interval, Region};
+use noodles_core::{region::Interval, Position, Region};
 use serde::{
     ser::{self, SerializeMap, SerializeSeq},
     Serialize, Serializer,
@@ -60,7 +60,7 @@ where
     I: Into<Interval>,
 {
     let interval = interval.into();
-    let start = interval.start().map(|position| usize::from(position) - 1);
+    let start = interval.start().map(Position::to_zero_based);
     let end = interval.end().map(usize::from);
     (start, end)
 }
@@ -71,8 +71,6 @@ mod tests {
 
     #[test]
     fn test_resolve_interval() -> std::result::Result<(), noodles_core::position::TryFromIntError> {
-        use noodles_core::Position;
-
         let start = Position::try_from(8)?;
         let end = Position::try_from(13)?;
 
@@ -6,8 +6,9 @@ pub(crate) use self::ticket::Ticket;
 
 use bytes::Bytes;
 use futures::Stream;
+use tokio::io::AsyncBufRead;
 
-use super::Client;
+use super::{reader::Reader, Client};
 
 /// An htsget response.
 #[derive(Debug)]
@@ -32,4 +33,32 @@ impl Response {
         use super::chunks::chunks;
         chunks(&self.client, self.ticket.urls())
     }
+
+    /// Returns an async reader over the data from the ticket URLs.
+    ///
+    /// The data blocks, including any header class block, are concatenated in ticket order, and
+    /// inline (data URI) blocks are decoded. The result is a complete file in the ticket format,
+    /// which can be passed directly to a format reader, e.g., `bam::AsyncReader` or
+    /// `vcf::AsyncReader`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_htsget as htsget;
+    /// use tokio::io::{self, AsyncBufReadExt};
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.variants("NDLS0001").send().await?;
+    ///
+    /// let mut reader = response.reader();
+    /// let mut line = String::new();
+    /// reader.read_line(&mut line).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader(&self) -> impl AsyncBufRead + Unpin + '_ {
+        Reader::new(self.chunks())
+    }
 }
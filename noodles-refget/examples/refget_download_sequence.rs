@@ -7,6 +7,7 @@ use std::{
     io::{self, Write},
 };
 
+use futures::TryStreamExt;
 use noodles_core::region::Interval;
 use noodles_refget as refget;
 
@@ -26,12 +27,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         request = request.set_interval(interval);
     }
 
-    let sequence = request.send().await?;
+    let mut chunks = request.send_stream().await?;
 
     let stdout = io::stdout();
     let mut writer = stdout.lock();
 
-    writer.write_all(&sequence.sequence())?;
+    while let Some(chunk) = chunks.try_next().await? {
+        writer.write_all(&chunk)?;
+    }
+
     writeln!(writer)?;
 
     Ok(())
@@ -0,0 +1,383 @@
+use std::{
+    io,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures::{stream, Stream, StreamExt};
+use md5::Md5;
+use sha2::{Digest, Sha512};
+use tokio::{
+    fs::{self, File},
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, SeekFrom},
+};
+
+use crate::Error;
+
+const READ_CHUNK_SIZE: usize = 1 << 16;
+
+const SHA512T24U_PREFIX: &str = "SQ.";
+const TRUNC512_DIGEST_LEN: usize = 24;
+
+static TEMP_FILE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// An on-disk cache of complete sequences.
+//
+// Each sequence is stored in its own file, named by the requested ID, i.e., a checksum. Only IDs
+// that are checksums (MD5, TRUNC512, or sha512t24u) are cached, and a sequence is only committed
+// to the cache when its checksum matches its ID. Other IDs (e.g., namespaced aliases) are not
+// cached.
+#[derive(Clone, Debug)]
+pub(crate) struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, id: &str) -> Option<PathBuf> {
+        if is_valid_key(id) {
+            Some(self.dir.join(id))
+        } else {
+            None
+        }
+    }
+
+    // Returns a stream over the given range of a cached sequence, if it is cached.
+    pub(crate) async fn get(
+        &self,
+        id: &str,
+        start: Option<usize>,
+        end: Option<usize>,
+        chunk_size: Option<usize>,
+    ) -> crate::Result<Option<impl Stream<Item = crate::Result<Bytes>>>> {
+        let path = match self.path(id) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let mut file = match File::open(path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::Io(e)),
+        };
+
+        let len = file.metadata().await.map_err(Error::Io)?.len();
+        let (start, end) = resolve_range(start, end, len)?;
+
+        file.seek(SeekFrom::Start(start)).await.map_err(Error::Io)?;
+
+        let chunk_size = chunk_size.unwrap_or(READ_CHUNK_SIZE) as u64;
+
+        Ok(Some(stream::unfold(
+            (file, start),
+            move |(mut file, position)| async move {
+                if position >= end {
+                    return None;
+                }
+
+                let len = (end - position).min(chunk_size);
+                let mut buf = BytesMut::new();
+                buf.resize(len as usize, 0);
+
+                match file.read_exact(&mut buf).await {
+                    Ok(_) => Some((Ok(buf.freeze()), (file, position + len))),
+                    Err(e) => Some((Err(Error::Io(e)), (file, end))),
+                }
+            },
+        )))
+    }
+
+    // Returns a cache entry to write a sequence to, if the sequence can be cached.
+    pub(crate) async fn create(&self, id: &str) -> crate::Result<Option<Entry>> {
+        let path = match self.path(id) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let hasher = match Hasher::from_id(id) {
+            Some(hasher) => hasher,
+            None => return Ok(None),
+        };
+
+        let n = TEMP_FILE_COUNT.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = self
+            .dir
+            .join(format!(".{}.{}.{}.tmp", id, process::id(), n));
+
+        let file = File::create(&tmp_path).await.map_err(Error::Io)?;
+
+        Ok(Some(Entry {
+            file,
+            hasher: Some(hasher),
+            id: id.into(),
+            tmp_path: Some(tmp_path),
+            path,
+        }))
+    }
+}
+
+// A sequence being written to the cache.
+//
+// The sequence is written to a temporary file, which is moved to the cache when finished and its
+// checksum matches the ID. An unfinished or mismatched entry is removed when dropped.
+pub(crate) struct Entry {
+    file: File,
+    hasher: Option<Hasher>,
+    id: String,
+    tmp_path: Option<PathBuf>,
+    path: PathBuf,
+}
+
+impl Entry {
+    pub(crate) async fn write(&mut self, buf: &[u8]) -> crate::Result<()> {
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.update(buf);
+        }
+
+        self.file.write_all(buf).await.map_err(Error::Io)
+    }
+
+    pub(crate) async fn finish(mut self) -> crate::Result<()> {
+        self.file.flush().await.map_err(Error::Io)?;
+
+        let is_match = self
+            .hasher
+            .take()
+            .map(|hasher| hasher.verify(&self.id))
+            .unwrap_or(false);
+
+        if !is_match {
+            return Err(Error::ChecksumMismatch);
+        }
+
+        if let Some(tmp_path) = self.tmp_path.take() {
+            fs::rename(tmp_path, &self.path).await.map_err(Error::Io)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        if let Some(tmp_path) = self.tmp_path.take() {
+            let _ = std::fs::remove_file(tmp_path);
+        }
+    }
+}
+
+// Writes each chunk of a stream to a cache entry, finishing the entry when the stream ends.
+//
+// If the stream fails, the entry is discarded.
+pub(crate) fn tee<S>(stream: S, entry: Entry) -> impl Stream<Item = crate::Result<Bytes>>
+where
+    S: Stream<Item = crate::Result<Bytes>> + Unpin,
+{
+    stream::unfold(
+        (stream, Some(entry)),
+        |(mut stream, mut entry)| async move {
+            match stream.next().await {
+                Some(Ok(buf)) => {
+                    if let Some(e) = entry.as_mut() {
+                        if let Err(e) = e.write(&buf).await {
+                            return Some((Err(e), (stream, None)));
+                        }
+                    }
+
+                    Some((Ok(buf), (stream, entry)))
+                }
+                Some(Err(e)) => Some((Err(e), (stream, None))),
+                None => match entry {
+                    Some(entry) => match entry.finish().await {
+                        Ok(()) => None,
+                        Err(e) => Some((Err(e), (stream, None))),
+                    },
+                    None => None,
+                },
+            }
+        },
+    )
+}
+
+// A hasher for the checksum algorithm of a sequence ID.
+enum Hasher {
+    Md5(Md5),
+    Trunc512(Sha512),
+    Sha512t24u(Sha512),
+}
+
+impl Hasher {
+    fn from_id(id: &str) -> Option<Self> {
+        if let Some(digest) = id.strip_prefix(SHA512T24U_PREFIX) {
+            return if digest.len() == 32 {
+                Some(Self::Sha512t24u(Sha512::new()))
+            } else {
+                None
+            };
+        }
+
+        if !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        match id.len() {
+            32 => Some(Self::Md5(Md5::new())),
+            48 => Some(Self::Trunc512(Sha512::new())),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, buf: &[u8]) {
+        match self {
+            Self::Md5(hasher) => hasher.update(buf),
+            Self::Trunc512(hasher) | Self::Sha512t24u(hasher) => hasher.update(buf),
+        }
+    }
+
+    fn verify(self, id: &str) -> bool {
+        match self {
+            Self::Md5(hasher) => encode_hex(&hasher.finalize()).eq_ignore_ascii_case(id),
+            Self::Trunc512(hasher) => {
+                let digest = hasher.finalize();
+                encode_hex(&digest[..TRUNC512_DIGEST_LEN]).eq_ignore_ascii_case(id)
+            }
+            Self::Sha512t24u(hasher) => {
+                let digest = hasher.finalize();
+                let encoded_digest =
+                    base64::encode_config(&digest[..TRUNC512_DIGEST_LEN], base64::URL_SAFE);
+                id.strip_prefix(SHA512T24U_PREFIX) == Some(encoded_digest.as_str())
+            }
+        }
+    }
+}
+
+fn encode_hex(buf: &[u8]) -> String {
+    buf.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn is_valid_key(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+fn resolve_range(start: Option<usize>, end: Option<usize>, len: u64) -> crate::Result<(u64, u64)> {
+    let start = start.map(|n| n as u64).unwrap_or(0);
+    let end = end.map(|n| n as u64).unwrap_or(len);
+
+    if start <= end && end <= len {
+        Ok((start, end))
+    } else {
+        Err(Error::Input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    #[test]
+    fn test_is_valid_key() {
+        assert!(is_valid_key("d7eba311421bbc9d3ada44709dd61534"));
+        assert!(is_valid_key("SQ.lZyxiD_ByprhOUzrR1o1bq0ezO_1gkrn"));
+
+        assert!(!is_valid_key(""));
+        assert!(!is_valid_key(".."));
+        assert!(!is_valid_key("insdc:CM000663.2"));
+        assert!(!is_valid_key("../sq0"));
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        assert_eq!(resolve_range(None, None, 8).ok(), Some((0, 8)));
+        assert_eq!(resolve_range(Some(2), Some(5), 8).ok(), Some((2, 5)));
+        assert_eq!(resolve_range(Some(8), None, 8).ok(), Some((8, 8)));
+
+        assert!(matches!(
+            resolve_range(Some(5), Some(2), 8),
+            Err(Error::Input)
+        ));
+        assert!(matches!(
+            resolve_range(None, Some(13), 8),
+            Err(Error::Input)
+        ));
+    }
+
+    #[test]
+    fn test_hasher_verify() {
+        fn verify(id: &str, data: &[u8]) -> bool {
+            let mut hasher = Hasher::from_id(id).expect("invalid ID");
+            hasher.update(data);
+            hasher.verify(id)
+        }
+
+        assert!(verify("2bc81efadaede86bd80eb90e86aca8e5", b"ACGTNNCC"));
+        assert!(verify("2BC81EFADAEDE86BD80EB90E86ACA8E5", b"ACGTNNCC"));
+        assert!(verify(
+            "7bf0e7f3b7c4e770c1029d0260d06b6a94cc701a86bbc0b4",
+            b"ACGTNNCC"
+        ));
+        assert!(verify("SQ.e_Dn87fE53DBAp0CYNBrapTMcBqGu8C0", b"ACGTNNCC"));
+
+        assert!(!verify("2bc81efadaede86bd80eb90e86aca8e5", b"ACGT"));
+        assert!(!verify("SQ.e_Dn87fE53DBAp0CYNBrapTMcBqGu8C0", b"ACGT"));
+
+        assert!(Hasher::from_id("sq0").is_none());
+        assert!(Hasher::from_id("SQ.e_Dn87fE53").is_none());
+        assert!(Hasher::from_id("2bc81efadaede86bd80eb90e86aca8eZ").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_and_create() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = std::env::temp_dir().join(format!("noodles-refget-cache-{}", process::id()));
+        fs::create_dir_all(&dir).await?;
+
+        let cache = Cache::new(dir.clone());
+        let id = "2bc81efadaede86bd80eb90e86aca8e5";
+
+        assert!(cache.get(id, None, None, None).await?.is_none());
+
+        let data = stream::iter(vec![Ok(Bytes::from("ACGT")), Ok(Bytes::from("NNCC"))]);
+        let entry = cache.create(id).await?.expect("missing entry");
+        let buf: Vec<_> = tee(data, entry).try_collect().await?;
+        assert_eq!(buf.concat(), b"ACGTNNCC");
+
+        let chunks: Vec<_> = cache
+            .get(id, Some(1), Some(7), Some(4))
+            .await?
+            .expect("missing cached sequence")
+            .try_collect()
+            .await?;
+
+        assert_eq!(chunks, [Bytes::from("CGTN"), Bytes::from("NC")]);
+
+        let id = "d7eba311421bbc9d3ada44709dd61534";
+        let data = stream::iter(vec![Ok(Bytes::from("ACGTNNCC"))]);
+        let entry = cache.create(id).await?.expect("missing entry");
+        let result: crate::Result<Vec<_>> = tee(data, entry).try_collect().await;
+        assert!(matches!(result, Err(Error::ChecksumMismatch)));
+        assert!(cache.get(id, None, None, None).await?.is_none());
+
+        let mut entries = fs::read_dir(&dir).await?;
+        let mut names = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await? {
+            names.push(entry.file_name());
+        }
+
+        assert_eq!(names, ["2bc81efadaede86bd80eb90e86aca8e5"]);
+
+        fs::remove_dir_all(&dir).await?;
+
+        Ok(())
+    }
+}
@@ -1,17 +1,40 @@
+//! refget client.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use futures::future;
+use reqwest::{RequestBuilder, Response};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use url::Url;
 
-use super::sequence;
+use super::{
+    cache::Cache,
+    sequence::{self, Metadata},
+    service_info::{ServiceInfo, ServiceInfoResponse},
+    Error,
+};
 
 /// A refget client.
 #[derive(Clone, Debug)]
 pub struct Client {
     http_client: reqwest::Client,
     base_url: Url,
+    retry_policy: RetryPolicy,
+    request_permits: Option<Arc<Semaphore>>,
+    cache: Option<Cache>,
 }
 
 impl Client {
     /// Creates a new refget client.
     ///
+    /// The client has no timeouts, does not retry failed requests, does not limit the number of
+    /// concurrent requests, and does not cache sequences. Use [`Self::builder`] to configure
+    /// these.
+    ///
     /// # Examples
     ///
     /// ```
@@ -23,9 +46,25 @@ impl Client {
         Self {
             http_client: reqwest::Client::new(),
             base_url,
+            retry_policy: RetryPolicy::default(),
+            request_permits: None,
+            cache: None,
         }
     }
 
+    /// Creates a refget client builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    /// let builder = refget::Client::builder("https://localhost/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn builder(base_url: Url) -> Builder {
+        Builder::new(base_url)
+    }
+
     pub(crate) fn http_client(&self) -> &reqwest::Client {
         &self.http_client
     }
@@ -34,6 +73,96 @@ impl Client {
         &self.base_url
     }
 
+    pub(crate) fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Sends a request and reads its response, retrying both on transient failures.
+    ///
+    /// Non-success responses are returned as errors. Unlike [`Self::send`], a failure while
+    /// reading the response, e.g., a connection reset while receiving the body, also retries the
+    /// request. If the number of concurrent requests is limited, a permit is held until the
+    /// response is read.
+    pub(crate) async fn fetch<F, Fut, T>(
+        &self,
+        request: RequestBuilder,
+        read: F,
+    ) -> crate::Result<T>
+    where
+        F: FnMut(Response) -> Fut,
+        Fut: Future<Output = reqwest::Result<T>>,
+    {
+        let _permit = self.acquire_permit().await;
+        self.send_with_retries(request, read).await
+    }
+
+    /// Sends a request, retrying it on transient failures.
+    ///
+    /// Non-success responses are returned as errors. Only sending the request and receiving the
+    /// response head are retried; reading the response body is not. Use [`Self::fetch`] to also
+    /// retry reading a buffered response.
+    ///
+    /// If the number of concurrent requests is limited, the returned permit must be held until the
+    /// response body is consumed.
+    pub(crate) async fn send(
+        &self,
+        request: RequestBuilder,
+    ) -> crate::Result<(Response, Option<OwnedSemaphorePermit>)> {
+        let permit = self.acquire_permit().await;
+
+        let response = self
+            .send_with_retries(request, |response| future::ready(Ok(response)))
+            .await?;
+
+        Ok((response, permit))
+    }
+
+    async fn acquire_permit(&self) -> Option<OwnedSemaphorePermit> {
+        match &self.request_permits {
+            // The semaphore is never closed.
+            Some(permits) => permits.clone().acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    async fn send_with_retries<F, Fut, T>(
+        &self,
+        request: RequestBuilder,
+        mut read: F,
+    ) -> crate::Result<T>
+    where
+        F: FnMut(Response) -> Fut,
+        Fut: Future<Output = reqwest::Result<T>>,
+    {
+        let mut request = request;
+        let mut attempt = 0;
+
+        loop {
+            let retry_request = if attempt < self.retry_policy.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let result = match request.send().await.and_then(Response::error_for_status) {
+                Ok(response) => read(response).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(value) => return Ok(value),
+                Err(e) => match retry_request {
+                    Some(retry_request) if is_transient(&e) => {
+                        tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                        request = retry_request;
+                        attempt += 1;
+                    }
+                    _ => return Err(Error::Request(e)),
+                },
+            }
+        }
+    }
+
     /// Creates a sequence request for the given ID.
     ///
     /// # Examples
@@ -50,4 +179,207 @@ impl Client {
     {
         sequence::Builder::new(self.clone(), id)
     }
+
+    /// Returns the metadata of the sequence with the given ID.
+    ///
+    /// This can be used to check the length, checksums, and aliases of a sequence before
+    /// downloading it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let metadata = client.sequence_metadata("d7eba311421bbc9d3ada44709dd61534").await?;
+    /// println!("{}", metadata.length());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sequence_metadata<I>(&self, id: I) -> crate::Result<Metadata>
+    where
+        I: AsRef<str>,
+    {
+        sequence::get_metadata(self, id.as_ref()).await
+    }
+
+    /// Returns information about the refget service.
+    ///
+    /// This includes the supported checksum algorithms, whether circular sequences are supported,
+    /// and the maximum subsequence length.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let service_info = client.service_info().await?;
+    /// println!("{:?}", service_info.subsequence_limit());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn service_info(&self) -> crate::Result<ServiceInfo> {
+        let endpoint = self
+            .base_url()
+            .join("sequence/service-info")
+            .map_err(Error::Url)?;
+
+        self.fetch(self.http_client().get(endpoint), |response| {
+            response.json::<ServiceInfoResponse>()
+        })
+        .await
+        .map(|data| data.service)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt)
+            .and_then(|n| self.backoff.checked_mul(n))
+            .map(|backoff| backoff.min(self.max_backoff))
+            .unwrap_or(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+fn is_transient(e: &reqwest::Error) -> bool {
+    e.is_timeout()
+        || e.is_connect()
+        || e.is_body()
+        || e.status()
+            .map(|status| status.is_server_error())
+            .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let retry_policy = RetryPolicy {
+            max_retries: 8,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        assert_eq!(retry_policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(retry_policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(retry_policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(retry_policy.backoff(4), Duration::from_secs(1));
+        assert_eq!(retry_policy.backoff(64), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retries() -> Result<(), Box<dyn std::error::Error>> {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url: Url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        let server = tokio::spawn(async move {
+            let responses: [&[u8]; 3] = [
+                b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                b"HTTP/1.1 502 Bad Gateway\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nACGT",
+            ];
+
+            for response in responses {
+                let (mut stream, _) = listener.accept().await?;
+
+                // Reads the request head.
+                let mut buf = Vec::new();
+
+                while !buf.ends_with(b"\r\n\r\n") {
+                    buf.push(stream.read_u8().await?);
+                }
+
+                stream.write_all(response).await?;
+                stream.shutdown().await?;
+            }
+
+            Ok::<_, std::io::Error>(())
+        });
+
+        let client = Client::builder(base_url)
+            .set_max_retries(2)
+            .set_retry_backoff(Duration::from_millis(1))
+            .build()?;
+
+        let sequence = client.sequence("sq0").send().await?;
+        assert_eq!(&sequence.sequence()[..], b"ACGT");
+
+        server.await??;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retries_with_incomplete_body() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url: Url = format!("http://{}/", listener.local_addr()?).parse()?;
+
+        let server = tokio::spawn(async move {
+            let responses: [&[u8]; 2] = [
+                b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nAC",
+                b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nACGT",
+            ];
+
+            for response in responses {
+                let (mut stream, _) = listener.accept().await?;
+
+                // Reads the request head.
+                let mut buf = Vec::new();
+
+                while !buf.ends_with(b"\r\n\r\n") {
+                    buf.push(stream.read_u8().await?);
+                }
+
+                stream.write_all(response).await?;
+                stream.shutdown().await?;
+            }
+
+            Ok::<_, std::io::Error>(())
+        });
+
+        let client = Client::builder(base_url)
+            .set_max_retries(1)
+            .set_retry_backoff(Duration::from_millis(1))
+            .build()?;
+
+        let sequence = client.sequence("sq0").send().await?;
+        assert_eq!(&sequence.sequence()[..], b"ACGT");
+
+        server.await??;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+use std::{fs, num::NonZeroUsize, path::PathBuf, sync::Arc, time::Duration};
+
+use tokio::sync::Semaphore;
+use url::Url;
+
+use super::{Client, RetryPolicy};
+use crate::{cache::Cache, Error};
+
+/// A refget client builder.
+#[derive(Debug)]
+pub struct Builder {
+    base_url: Url,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    max_concurrent_requests: Option<NonZeroUsize>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    pub(super) fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            timeout: None,
+            connect_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            max_concurrent_requests: None,
+            cache_dir: None,
+        }
+    }
+
+    /// Sets the total timeout of a request.
+    ///
+    /// The timeout applies from when the request is sent until the response body is read. By
+    /// default, there is no timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_timeout(Duration::from_secs(60))
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the connect timeout.
+    ///
+    /// By default, there is no connect timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_connect_timeout(Duration::from_secs(10))
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of times a failed request is retried.
+    ///
+    /// A request is retried when it times out, fails to connect, receives a server error (5xx)
+    /// response, or fails while its response body is read. Streamed sequences (see
+    /// [`crate::sequence::Builder::send_stream`]) are only retried until a response is received.
+    /// By default, requests are not retried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_max_retries(3)
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_max_retries(mut self, max_retries: u32) -> Self {
+        self.retry_policy.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// The delay doubles after each subsequent retry, up to the maximum retry backoff. The
+    /// default is 500 ms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_max_retries(3)
+    ///     .set_retry_backoff(Duration::from_secs(1))
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_policy.backoff = backoff;
+        self
+    }
+
+    /// Sets the maximum delay between retries.
+    ///
+    /// The default is 30 s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_max_retries(8)
+    ///     .set_max_retry_backoff(Duration::from_secs(60))
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_max_retry_backoff(mut self, max_backoff: Duration) -> Self {
+        self.retry_policy.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the maximum number of concurrent requests.
+    ///
+    /// This is shared by all clones of the built client. Additional requests wait until an
+    /// in-flight request completes. By default, the number of concurrent requests is not limited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_max_concurrent_requests(NonZeroUsize::new(4).unwrap())
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_max_concurrent_requests(mut self, max_concurrent_requests: NonZeroUsize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// Sets the directory of an on-disk sequence cache.
+    ///
+    /// Before requesting a sequence, the client checks the cache for a sequence with the same ID,
+    /// e.g., an MD5 or TRUNC512 checksum, and reads it from disk instead, including subsequences
+    /// of it. After a complete sequence is downloaded, it is added to the cache if its checksum
+    /// matches the ID; otherwise, the request fails with [`crate::Error::ChecksumMismatch`]. IDs
+    /// that are not checksums, e.g., namespaced aliases, are never cached.
+    ///
+    /// The directory is created when the client is built. By default, sequences are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::builder("https://localhost/".parse()?)
+    ///     .set_cache_dir("refget-cache")
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_cache_dir<P>(mut self, dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Builds a refget client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    /// let client = refget::Client::builder("https://localhost/".parse()?).build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self) -> crate::Result<Client> {
+        let mut http_client_builder = reqwest::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            http_client_builder = http_client_builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            http_client_builder = http_client_builder.connect_timeout(timeout);
+        }
+
+        let http_client = http_client_builder.build().map_err(Error::Request)?;
+
+        let request_permits = self
+            .max_concurrent_requests
+            .map(|n| Arc::new(Semaphore::new(n.get())));
+
+        let cache = match self.cache_dir {
+            Some(dir) => {
+                fs::create_dir_all(&dir).map_err(Error::Io)?;
+                Some(Cache::new(dir))
+            }
+            None => None,
+        };
+
+        Ok(Client {
+            http_client,
+            base_url: self.base_url,
+            retry_policy: self.retry_policy,
+            request_permits,
+            cache,
+        })
+    }
+}
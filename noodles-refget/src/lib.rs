@@ -2,12 +2,14 @@
 
 //! **noodles-refget** is a refget client.
 
-mod client;
-mod sequence;
+mod cache;
+pub mod client;
+pub mod sequence;
+pub mod service_info;
 
-pub use self::{client::Client, sequence::Sequence};
+pub use self::{client::Client, sequence::Sequence, service_info::ServiceInfo};
 
-use std::{error, fmt};
+use std::{error, fmt, io};
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -20,6 +22,10 @@ pub enum Error {
     Url(url::ParseError),
     /// The request failed to process.
     Request(reqwest::Error),
+    /// An I/O error, e.g., when reading from or writing to the cache.
+    Io(io::Error),
+    /// The checksum of a received sequence does not match the requested ID.
+    ChecksumMismatch,
 }
 
 impl error::Error for Error {}
@@ -30,6 +36,8 @@ impl fmt::Display for Error {
             Self::Input => f.write_str("invalid input"),
             Self::Url(e) => write!(f, "URL error: {}", e),
             Self::Request(e) => write!(f, "request error: {}", e),
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+            Self::ChecksumMismatch => f.write_str("checksum mismatch"),
         }
     }
 }
@@ -34,28 +34,24 @@ impl Sequence {
 
     /// Returns metadata related to the sequence.
     pub async fn metadata(&self) -> crate::Result<Metadata> {
-        let endpoint = self
-            .client
-            .base_url()
-            .join(&format!("sequence/{}/metadata", self.id))
-            .map_err(Error::Url)?;
-
-        let response = self
-            .client
-            .http_client()
-            .get(endpoint)
-            .send()
-            .await
-            .map_err(Error::Request)?;
-
-        response
-            .json()
-            .await
-            .map(|data: MetadataResponse| data.metadata)
-            .map_err(Error::Request)
+        get_metadata(&self.client, &self.id).await
     }
 }
 
+pub(crate) async fn get_metadata(client: &Client, id: &str) -> crate::Result<Metadata> {
+    let endpoint = client
+        .base_url()
+        .join(&format!("sequence/{}/metadata", id))
+        .map_err(Error::Url)?;
+
+    client
+        .fetch(client.http_client().get(endpoint), |response| {
+            response.json::<MetadataResponse>()
+        })
+        .await
+        .map(|data| data.metadata)
+}
+
 #[derive(Deserialize)]
 struct MetadataResponse {
     metadata: Metadata,
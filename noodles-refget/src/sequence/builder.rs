@@ -1,12 +1,18 @@
+use std::num::NonZeroUsize;
+
+use bytes::{Bytes, BytesMut};
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use noodles_core::region::Interval;
+use reqwest::Response;
 
-use crate::{Client, Error, Sequence};
+use crate::{cache, Client, Error, Sequence};
 
 /// A sequence endpoint builder.
 pub struct Builder {
     client: Client,
     id: String,
     interval: Option<Interval>,
+    chunk_size: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -18,6 +24,7 @@ impl Builder {
             client,
             id: id.into(),
             interval: None,
+            chunk_size: None,
         }
     }
 
@@ -30,7 +37,20 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of bases to request at a time.
+    ///
+    /// This is only used by [`Self::send_stream`], which splits the interval into ranged
+    /// subrequests of at most this size. By default, the sequence is requested in a single
+    /// request.
+    pub fn set_chunk_size(mut self, chunk_size: NonZeroUsize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
     /// Sends the request.
+    ///
+    /// The entire (sub)sequence is buffered in memory. Use [`Self::send_stream`] to consume the
+    /// response incrementally.
     pub async fn send(self) -> crate::Result<Sequence> {
         let endpoint = self
             .client
@@ -38,29 +58,168 @@ impl Builder {
             .join(&format!("sequence/{}", self.id))
             .map_err(Error::Url)?;
 
-        let mut request = self.client.http_client().get(endpoint);
+        let (start, end) = self.interval.map(resolve_interval).unwrap_or_default();
+
+        if let Some(cache) = self.client.cache() {
+            if let Some(chunks) = cache.get(&self.id, start, end, None).await? {
+                let sequence = chunks
+                    .try_fold(BytesMut::new(), |mut buf, chunk| async move {
+                        buf.extend_from_slice(&chunk);
+                        Ok(buf)
+                    })
+                    .await?
+                    .freeze();
+
+                return Ok(Sequence::new(self.client, self.id, sequence));
+            }
+        }
+
+        let request = build_request(&self.client, endpoint, start, end);
+
+        let sequence = self.client.fetch(request, Response::bytes).await?;
+
+        if start.is_none() && end.is_none() {
+            if let Some(cache) = self.client.cache() {
+                if let Some(mut entry) = cache.create(&self.id).await? {
+                    entry.write(&sequence).await?;
+                    entry.finish().await?;
+                }
+            }
+        }
+
+        Ok(Sequence::new(self.client, self.id, sequence))
+    }
+
+    /// Sends the request and returns the (sub)sequence as a stream of bytes.
+    ///
+    /// Unlike [`Self::send`], the sequence is not buffered in memory. If a chunk size is set, the
+    /// interval is requested in consecutive ranged subrequests, each of which is only sent after
+    /// the previous response is consumed. When the interval has no end, the sequence length is
+    /// first read from the metadata endpoint.
+    ///
+    /// If the client has a cache, a cached sequence is read from disk, and a complete sequence is
+    /// added to the cache as the stream is consumed.
+    ///
+    /// Retries (see [`crate::client::Builder::set_max_retries`]) only apply to sending each
+    /// (sub)request. A failure while a response body is streamed is returned as an error in the
+    /// stream and is not retried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use futures::TryStreamExt;
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    ///
+    /// let mut chunks = client
+    ///     .sequence("d7eba311421bbc9d3ada44709dd61534")
+    ///     .set_chunk_size(NonZeroUsize::new(1 << 20).unwrap())
+    ///     .send_stream()
+    ///     .await?;
+    ///
+    /// while let Some(chunk) = chunks.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_stream(
+        self,
+    ) -> crate::Result<impl Stream<Item = crate::Result<Bytes>> + Unpin> {
+        let endpoint = self
+            .client
+            .base_url()
+            .join(&format!("sequence/{}", self.id))
+            .map_err(Error::Url)?;
+
+        let (start, end) = self.interval.map(resolve_interval).unwrap_or_default();
 
-        if let Some(interval) = self.interval {
-            let mut query = Vec::new();
+        let cache = self.client.cache().cloned();
 
-            let (resolved_start, resolved_end) = resolve_interval(interval);
+        if let Some(cache) = &cache {
+            let chunk_size = self.chunk_size.map(NonZeroUsize::get);
 
-            if let Some(start) = resolved_start {
-                query.push(("start", start.to_string()));
+            if let Some(chunks) = cache.get(&self.id, start, end, chunk_size).await? {
+                return Ok(chunks.boxed());
             }
+        }
 
-            if let Some(end) = resolved_end {
-                query.push(("end", end.to_string()));
+        let ranges = match self.chunk_size {
+            Some(chunk_size) => {
+                let end = match end {
+                    Some(end) => end,
+                    None => {
+                        let metadata = super::get_metadata(&self.client, &self.id).await?;
+                        usize::try_from(metadata.length()).map_err(|_| Error::Input)?
+                    }
+                };
+
+                chunk_ranges(start.unwrap_or_default(), end, chunk_size)
+                    .into_iter()
+                    .map(|(start, end)| (Some(start), Some(end)))
+                    .collect()
             }
+            None => vec![(start, end)],
+        };
+
+        let client = self.client;
+
+        let responses = stream::iter(ranges).then(move |(start, end)| {
+            let client = client.clone();
+            let request = build_request(&client, endpoint.clone(), start, end);
+            Box::pin(async move { client.send(request).await })
+        });
 
-            request = request.query(&query);
+        let chunks = responses
+            .map_ok(|(response, permit)| {
+                // The permit, if any, is held until the response body is consumed.
+                response.bytes_stream().map(move |result| {
+                    let _permit = &permit;
+                    result.map_err(Error::Request)
+                })
+            })
+            .try_flatten();
+
+        if start.is_none() && end.is_none() {
+            if let Some(cache) = &cache {
+                if let Some(entry) = cache.create(&self.id).await? {
+                    return Ok(cache::tee(chunks, entry).boxed());
+                }
+            }
         }
 
-        let response = request.send().await.map_err(Error::Request)?;
-        let sequence = response.bytes().await.map_err(Error::Request)?;
+        Ok(chunks.boxed())
+    }
+}
+
+fn build_request(
+    client: &Client,
+    endpoint: url::Url,
+    start: Option<usize>,
+    end: Option<usize>,
+) -> reqwest::RequestBuilder {
+    let mut request = client.http_client().get(endpoint);
 
-        Ok(Sequence::new(self.client, self.id, sequence))
+    let mut query = Vec::new();
+
+    if let Some(start) = start {
+        query.push(("start", start.to_string()));
+    }
+
+    if let Some(end) = end {
+        query.push(("end", end.to_string()));
     }
+
+    if !query.is_empty() {
+        request = request.query(&query);
+    }
+
+    request
 }
 
 fn resolve_interval<I>(interval: I) -> (Option<usize>, Option<usize>)
@@ -73,6 +232,20 @@ where
     (start, end)
 }
 
+fn chunk_ranges(start: usize, end: usize, chunk_size: NonZeroUsize) -> Vec<(usize, usize)> {
+    let chunk_size = chunk_size.get();
+    let mut ranges = Vec::new();
+    let mut chunk_start = start;
+
+    while chunk_start < end {
+        let chunk_end = chunk_start.saturating_add(chunk_size).min(end);
+        ranges.push((chunk_start, chunk_end));
+        chunk_start = chunk_end;
+    }
+
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +264,13 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_chunk_ranges() {
+        let chunk_size = NonZeroUsize::new(4).unwrap();
+
+        assert_eq!(chunk_ranges(0, 10, chunk_size), [(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(chunk_ranges(3, 11, chunk_size), [(3, 7), (7, 11)]);
+        assert_eq!(chunk_ranges(5, 5, chunk_size), []);
+    }
 }
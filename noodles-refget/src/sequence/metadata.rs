@@ -1,3 +1,5 @@
+//! Sequence metadata.
+
 mod alias;
 
 pub use self::alias::Alias;
@@ -9,7 +11,9 @@ use serde::Deserialize;
 pub struct Metadata {
     md5: String,
     trunc512: Option<String>,
+    ga4gh: Option<String>,
     length: u32,
+    #[serde(default)]
     aliases: Vec<Alias>,
 }
 
@@ -24,6 +28,14 @@ impl Metadata {
         self.trunc512.as_deref()
     }
 
+    /// Returns the GA4GH identifier.
+    ///
+    /// This is the refget v2 sequence identifier, i.e., the base64url-encoded truncated SHA-512
+    /// digest prefixed with `SQ.`.
+    pub fn ga4gh(&self) -> Option<&str> {
+        self.ga4gh.as_deref()
+    }
+
     /// Returns the length.
     pub fn length(&self) -> u32 {
         self.length
@@ -34,3 +46,44 @@ impl Metadata {
         &self.aliases
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> serde_json::Result<()> {
+        let s = r#"{
+            "md5": "6aef897c3d6ff0c78aff06ac189178dd",
+            "trunc512": "959cb1883fc1ca9ae1394ceb475a356ead1ecceff5824ae7",
+            "ga4gh": "SQ.lZyxiD_ByprhOUzrR1o1bq0ezO_1gkrn",
+            "length": 8,
+            "aliases": [{ "alias": "chr1", "naming_authority": "UCSC" }]
+        }"#;
+
+        let metadata: Metadata = serde_json::from_str(s)?;
+
+        assert_eq!(metadata.md5(), "6aef897c3d6ff0c78aff06ac189178dd");
+        assert_eq!(
+            metadata.trunc512(),
+            Some("959cb1883fc1ca9ae1394ceb475a356ead1ecceff5824ae7")
+        );
+        assert_eq!(
+            metadata.ga4gh(),
+            Some("SQ.lZyxiD_ByprhOUzrR1o1bq0ezO_1gkrn")
+        );
+        assert_eq!(metadata.length(), 8);
+        assert_eq!(metadata.aliases().len(), 1);
+        assert_eq!(metadata.aliases()[0].alias(), "chr1");
+        assert_eq!(metadata.aliases()[0].naming_authority(), "UCSC");
+
+        let s = r#"{ "md5": "6aef897c3d6ff0c78aff06ac189178dd", "length": 8 }"#;
+        let metadata: Metadata = serde_json::from_str(s)?;
+
+        assert!(metadata.trunc512().is_none());
+        assert!(metadata.ga4gh().is_none());
+        assert!(metadata.aliases().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Service info endpoint.
+
+use serde::Deserialize;
+
+/// Information about a refget service.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ServiceInfo {
+    circular_supported: bool,
+    algorithms: Vec<String>,
+    subsequence_limit: Option<u32>,
+    #[serde(default)]
+    supported_api_versions: Vec<String>,
+}
+
+impl ServiceInfo {
+    /// Returns whether the service supports querying circular sequences.
+    pub fn circular_supported(&self) -> bool {
+        self.circular_supported
+    }
+
+    /// Returns the names of the supported checksum algorithms, e.g., `md5`, `trunc512`, and
+    /// `ga4gh`.
+    pub fn algorithms(&self) -> &[String] {
+        &self.algorithms
+    }
+
+    /// Returns the maximum length of a subsequence that can be requested.
+    ///
+    /// This is `None` if the service does not limit the length of subsequences.
+    pub fn subsequence_limit(&self) -> Option<u32> {
+        self.subsequence_limit
+    }
+
+    /// Returns the supported refget API versions.
+    pub fn supported_api_versions(&self) -> &[String] {
+        &self.supported_api_versions
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ServiceInfoResponse {
+    #[serde(alias = "refget")]
+    pub(crate) service: ServiceInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> serde_json::Result<()> {
+        let s = r#"{
+            "service": {
+                "circular_supported": false,
+                "algorithms": ["md5", "trunc512"],
+                "subsequence_limit": 4000000,
+                "supported_api_versions": ["1.0"]
+            }
+        }"#;
+
+        let response: ServiceInfoResponse = serde_json::from_str(s)?;
+        let service_info = response.service;
+
+        assert!(!service_info.circular_supported());
+        assert_eq!(service_info.algorithms(), ["md5", "trunc512"]);
+        assert_eq!(service_info.subsequence_limit(), Some(4000000));
+        assert_eq!(service_info.supported_api_versions(), ["1.0"]);
+
+        let s = r#"{
+            "id": "org.ga4gh.refget",
+            "refget": {
+                "circular_supported": true,
+                "algorithms": ["md5", "ga4gh"],
+                "subsequence_limit": null
+            }
+        }"#;
+
+        let response: ServiceInfoResponse = serde_json::from_str(s)?;
+        let service_info = response.service;
+
+        assert!(service_info.circular_supported());
+        assert_eq!(service_info.algorithms(), ["md5", "ga4gh"]);
+        assert!(service_info.subsequence_limit().is_none());
+        assert!(service_info.supported_api_versions().is_empty());
+
+        Ok(())
+    }
+}
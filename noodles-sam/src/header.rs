@@ -68,10 +68,13 @@ pub mod program;
 pub mod read_group;
 pub mod record;
 pub mod reference_sequence;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 use std::{fmt, str::FromStr};
 
 use indexmap::IndexMap;
+use noodles_core::{sequence_dictionary, SequenceDictionary};
 
 pub use self::{
     builder::Builder, parser::ParseError, program::Program, read_group::ReadGroup,
@@ -94,10 +97,23 @@ pub type Programs = IndexMap<String, Program>;
 /// Records are grouped by their types: header, reference seqeuence, read group, program, and
 /// comment.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Header {
     header: Option<header::Header>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     reference_sequences: ReferenceSequences,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     read_groups: ReadGroups,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     programs: Programs,
     comments: Vec<String>,
 }
@@ -469,6 +485,31 @@ impl FromStr for Header {
     }
 }
 
+impl From<&Header> for SequenceDictionary {
+    /// Creates a sequence dictionary from the reference sequences (`@SQ`) of a SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// use noodles_sam as sam;
+    ///
+    /// let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n@SQ\tSN:sq1\tLN:13\n".parse()?;
+    /// let dictionary = SequenceDictionary::from(&header);
+    ///
+    /// assert_eq!(dictionary.id("sq1"), Some(1));
+    /// assert_eq!(dictionary.get(1).and_then(|sequence| sequence.len()), Some(13));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    fn from(header: &Header) -> Self {
+        header
+            .reference_sequences()
+            .values()
+            .map(sequence_dictionary::Sequence::from)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,6 +26,7 @@ use super::{
 ///
 /// The header describes file-level metadata. The format version is guaranteed to be set.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Header {
     version: Version,
     sort_order: Option<SortOrder>,
@@ -17,6 +17,7 @@ use super::{
 /// A program describes any program that created, viewed, or mutated a SAM file. The program ID is
 /// guaranteed to be set.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Program {
     id: String,
     name: Option<String>,
@@ -18,6 +18,7 @@ use super::{
 /// A read group typically defines the set of reads that came from the same run on a sequencing
 /// instrument. The read group ID is guaranteed to be set.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ReadGroup {
     id: String,
     barcode: Option<String>,
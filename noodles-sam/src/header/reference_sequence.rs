@@ -10,6 +10,8 @@ pub mod tag;
 
 use std::{collections::HashMap, error, fmt, num::NonZeroUsize};
 
+use noodles_core::sequence_dictionary;
+
 pub use self::{
     alternative_locus::AlternativeLocus, alternative_names::AlternativeNames, builder::Builder,
     md5_checksum::Md5Checksum, molecule_topology::MoleculeTopology, name::Name, tag::Tag,
@@ -27,6 +29,7 @@ use super::{
 ///
 /// A list of reference sequences creates a reference sequence dictionary.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ReferenceSequence {
     name: Name,
     len: NonZeroUsize,
@@ -493,10 +496,57 @@ fn parse_map(raw_fields: Fields) -> Result<ReferenceSequence, TryFromRecordError
     }
 }
 
+impl From<&ReferenceSequence> for sequence_dictionary::Sequence {
+    fn from(reference_sequence: &ReferenceSequence) -> Self {
+        let name = reference_sequence.name().to_string();
+        let len = usize::from(reference_sequence.len());
+
+        let mut sequence = Self::new(name, Some(len));
+
+        *sequence.md5_mut() = reference_sequence.md5_checksum().map(<[u8; 16]>::from);
+
+        if let Some(alternative_names) = reference_sequence.alternative_names() {
+            sequence
+                .aliases_mut()
+                .extend(alternative_names.iter().cloned());
+        }
+
+        sequence
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_reference_sequence_for_sequence_dictionary_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let md5_checksum = [
+            0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+            0x15, 0x34,
+        ];
+
+        let reference_sequence = ReferenceSequence::builder()
+            .set_name("chr1".parse()?)
+            .set_length(13)
+            .set_md5_checksum(Md5Checksum::from(md5_checksum))
+            .set_alternative_names("1,ndls1".parse()?)
+            .build()?;
+
+        let actual = sequence_dictionary::Sequence::from(&reference_sequence);
+
+        let mut expected = sequence_dictionary::Sequence::new("chr1", Some(13));
+        *expected.md5_mut() = Some(md5_checksum);
+        expected
+            .aliases_mut()
+            .extend([String::from("1"), String::from("ndls1")]);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_new() -> Result<(), name::ParseError> {
         assert_eq!(
@@ -0,0 +1,152 @@
+//! Serialization and deserialization of SAM headers.
+//!
+//! Header structures are (de)serialized by their fields. Values that have a SAM text
+//! representation, e.g., tags, versions, and reference sequence names, are (de)serialized as
+//! strings and validated when deserialized. Records in a map must be keyed by their name or ID.
+
+use indexmap::IndexMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    header, program, read_group, reference_sequence, Program, ReadGroup, ReferenceSequence,
+};
+
+macro_rules! impl_serde_from_str {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let s = String::deserialize(deserializer)?;
+                    s.parse().map_err(de::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_from_str!(
+    header::GroupOrder,
+    header::SortOrder,
+    header::SubsortOrder,
+    header::Tag,
+    header::Version,
+    program::Tag,
+    read_group::Platform,
+    read_group::Tag,
+    reference_sequence::AlternativeLocus,
+    reference_sequence::AlternativeNames,
+    reference_sequence::Md5Checksum,
+    reference_sequence::MoleculeTopology,
+    reference_sequence::Name,
+    reference_sequence::Tag,
+);
+
+// A record that is keyed by its name or ID in a header map.
+pub(crate) trait Keyed {
+    fn key(&self) -> &str;
+}
+
+impl Keyed for ReferenceSequence {
+    fn key(&self) -> &str {
+        self.name().as_str()
+    }
+}
+
+impl Keyed for ReadGroup {
+    fn key(&self) -> &str {
+        self.id()
+    }
+}
+
+impl Keyed for Program {
+    fn key(&self) -> &str {
+        self.id()
+    }
+}
+
+/// Deserializes a map of records and validates that each record is keyed by its name or ID.
+pub(crate) fn deserialize_keyed_map<'de, D, V>(
+    deserializer: D,
+) -> Result<IndexMap<String, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de> + Keyed,
+{
+    let map = IndexMap::<String, V>::deserialize(deserializer)?;
+
+    for (key, value) in &map {
+        if value.key() != key {
+            return Err(de::Error::custom(format!(
+                "map key mismatch: expected {}, got {}",
+                value.key(),
+                key
+            )));
+        }
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Header;
+
+    #[test]
+    fn test_serde_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "\
+@HD\tVN:1.6\tSO:coordinate\tGO:none\tzn:noodles
+@SQ\tSN:sq1\tLN:13\tM5:d7eba311421bbc9d3ada44709dd61534\tTP:linear
+@SQ\tSN:sq0\tLN:8\tAN:chr0,0
+@RG\tID:rg0\tPL:ILLUMINA\tSM:sample0
+@PG\tID:pg0\tPN:noodles
+@CO\tnoodles-sam
+"
+        .parse()?;
+
+        let json = serde_json::to_string(&header)?;
+        let actual: Header = serde_json::from_str(&json)?;
+        assert_eq!(actual, header);
+
+        let names: Vec<_> = actual.reference_sequences().keys().collect();
+        assert_eq!(names, ["sq1", "sq0"]);
+
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["header"]["version"], "1.6");
+        assert_eq!(value["reference_sequences"]["sq1"]["len"], 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_with_invalid_values() {
+        assert!(serde_json::from_str::<Header>(
+            r#"{"header":{"version":"1","sort_order":null,"group_order":null,"subsort_order":null,"fields":{}},"reference_sequences":{},"read_groups":{},"programs":{},"comments":[]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_deserialize_with_mismatched_map_key() {
+        assert!(serde_json::from_str::<Header>(
+            r#"{"header":null,"reference_sequences":{},"read_groups":{"rg0":{"id":"rg0","fields":{}}},"programs":{},"comments":[]}"#
+        )
+        .is_ok());
+
+        assert!(serde_json::from_str::<Header>(
+            r#"{"header":null,"reference_sequences":{},"read_groups":{"rg1":{"id":"rg0","fields":{}}},"programs":{},"comments":[]}"#
+        )
+        .is_err());
+    }
+}
@@ -135,11 +135,31 @@ impl Flags {
     /// assert!(Flags::REVERSE_COMPLEMENTED.is_reverse_complemented());
     /// assert!(!Flags::UNMAPPED.is_reverse_complemented());
     /// ```
-
     pub fn is_reverse_complemented(self) -> bool {
         self.contains(Self::REVERSE_COMPLEMENTED)
     }
 
+    /// Returns the strand of the segment.
+    ///
+    /// This is the reverse strand if the `REVERSE_COMPLEMENTED` flag is set and the forward strand
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Strand;
+    /// use noodles_sam::record::Flags;
+    /// assert_eq!(Flags::REVERSE_COMPLEMENTED.strand(), Strand::Reverse);
+    /// assert_eq!(Flags::empty().strand(), Strand::Forward);
+    /// ```
+    pub fn strand(self) -> noodles_core::Strand {
+        if self.is_reverse_complemented() {
+            noodles_core::Strand::Reverse
+        } else {
+            noodles_core::Strand::Forward
+        }
+    }
+
     /// Returns whether the `MATE_REVERSE_COMPLEMENTED` flag is set.
     /// # Examples
     ///
@@ -228,7 +248,6 @@ impl Flags {
     /// assert!(Flags::QC_FAIL.is_qc_fail());
     /// assert!(!Flags::UNMAPPED.is_qc_fail());
     /// ```
-
     pub fn is_qc_fail(self) -> bool {
         self.contains(Self::QC_FAIL)
     }
@@ -124,6 +124,15 @@ impl From<TemplateLength> for usize {
     }
 }
 
+// Returns the absolute value without overflow.
+fn abs(n: i32) -> u32 {
+    if n < 0 {
+        0u32.wrapping_sub(n as u32)
+    } else {
+        n as u32
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,12 +196,3 @@ mod tests {
         Ok(())
     }
 }
-
-// Returns the absolute value without overflow.
-fn abs(n: i32) -> u32 {
-    if n < 0 {
-        0u32.wrapping_sub(n as u32)
-    } else {
-        n as u32
-    }
-}
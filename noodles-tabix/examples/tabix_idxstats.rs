@@ -0,0 +1,29 @@
+//! Prints tabix index statistics.
+//!
+//! The data is read only from the tabix index. It is printed as a tab-delimited record with the
+//! following columns for a region: reference sequence name, number of mapped records, and number
+//! of unmapped records.
+
+use std::{env, io};
+
+use noodles_csi::BinningIndex;
+use noodles_tabix as tabix;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let tabix_src = format!("{}.tbi", src);
+    let index = tabix::read(tabix_src)?;
+
+    for (name, mapped_record_count, unmapped_record_count) in index.record_counts() {
+        println!(
+            "{}\t{}\t{}",
+            name, mapped_record_count, unmapped_record_count
+        );
+    }
+
+    let unmapped_record_count = index.unplaced_unmapped_record_count().unwrap_or_default();
+    println!("*\t0\t{}", unmapped_record_count);
+
+    Ok(())
+}
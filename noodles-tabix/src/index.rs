@@ -17,9 +17,11 @@ pub use self::header::ReferenceSequenceNames;
 
 use std::io;
 
-use noodles_core::{region::Interval, Position};
+use noodles_core::{region::Interval, Position, Region};
 use noodles_csi::{
-    binning_index::optimize_chunks, index::reference_sequence::bin::Chunk, BinningIndex,
+    binning_index::{merge_chunks, optimize_chunks, ReferenceSequenceExt},
+    index::reference_sequence::bin::Chunk,
+    BinningIndex,
 };
 
 const MIN_SHIFT: u8 = 14;
@@ -115,6 +117,130 @@ impl Index {
     pub fn unmapped_read_count(&self) -> Option<u64> {
         self.unplaced_unmapped_record_count
     }
+
+    /// Returns the number of mapped and unmapped records for each reference sequence.
+    ///
+    /// The counts are read from the metadata pseudo-bin of each reference sequence and are paired
+    /// with the reference sequence names in the header. If a reference sequence does not have
+    /// metadata, its counts are 0.
+    ///
+    /// Along with [`BinningIndex::unplaced_unmapped_record_count`], this is the equivalent of
+    /// `samtools idxstats <src>`, i.e., the data file is not read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::bin::Chunk;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    ///
+    /// indexer.add_record(
+    ///     "sq0",
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(21)),
+    /// );
+    ///
+    /// indexer.add_record(
+    ///     "sq0",
+    ///     Position::try_from(21)?,
+    ///     Position::try_from(34)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(21), bgzf::VirtualPosition::from(34)),
+    /// );
+    ///
+    /// let index = indexer.build();
+    ///
+    /// let record_counts: Vec<_> = index.record_counts().collect();
+    /// assert_eq!(record_counts, [("sq0", 2, 0)]);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn record_counts(&self) -> impl Iterator<Item = (&str, u64, u64)> + '_ {
+        self.header
+            .reference_sequence_names()
+            .iter()
+            .zip(self.reference_sequences())
+            .map(|(name, reference_sequence)| {
+                let (mapped_record_count, unmapped_record_count) = reference_sequence
+                    .metadata()
+                    .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
+                    .unwrap_or_default();
+
+                (name.as_str(), mapped_record_count, unmapped_record_count)
+            })
+    }
+
+    /// Returns the chunks that overlap any of the given regions.
+    ///
+    /// The chunks of each region are pruned using the linear index. The chunks of all regions are
+    /// then merged, resulting in a sorted list of non-overlapping chunks. Reading these chunks
+    /// visits each block at most once, but records are not filtered by region, i.e., the caller
+    /// must still check whether a record intersects any of the regions.
+    ///
+    /// This is an error if a region's reference sequence name is not in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::bin::Chunk;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    ///
+    /// indexer.add_record(
+    ///     "sq0",
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(21)),
+    /// );
+    ///
+    /// indexer.add_record(
+    ///     "sq1",
+    ///     Position::try_from(5)?,
+    ///     Position::try_from(8)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(21), bgzf::VirtualPosition::from(34)),
+    /// );
+    ///
+    /// let index = indexer.build();
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq0:10-21".parse()?, "sq1".parse()?];
+    /// let chunks = index.query_regions(&regions)?;
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     [Chunk::new(bgzf::VirtualPosition::from(0), bgzf::VirtualPosition::from(34))]
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, I>(&self, regions: I) -> io::Result<Vec<Chunk>>
+    where
+        I: IntoIterator<Item = &'r Region>,
+    {
+        let reference_sequence_names = self.header.reference_sequence_names();
+        let mut chunks = Vec::new();
+
+        for region in regions {
+            let reference_sequence_id = reference_sequence_names
+                .get_index_of(region.name())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "region reference sequence does not exist in reference sequences: {:?}",
+                            region
+                        ),
+                    )
+                })?;
+
+            chunks.extend(self.query(reference_sequence_id, region.interval())?);
+        }
+
+        Ok(merge_chunks(&chunks))
+    }
 }
 
 impl BinningIndex for Index {
@@ -214,3 +340,94 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    fn build_index() -> Result<Index, noodles_core::position::TryFromIntError> {
+        let mut indexer = Index::indexer();
+
+        indexer.add_record(
+            "sq0",
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(8),
+            ),
+        );
+
+        indexer.add_record(
+            "sq0",
+            Position::try_from(1 << 20)?,
+            Position::try_from((1 << 20) + 5)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(1 << 16),
+                bgzf::VirtualPosition::from((1 << 16) + 13),
+            ),
+        );
+
+        indexer.add_record(
+            "sq1",
+            Position::try_from(21)?,
+            Position::try_from(34)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(2 << 16),
+                bgzf::VirtualPosition::from((2 << 16) + 21),
+            ),
+        );
+
+        Ok(indexer.build())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let index = build_index()?;
+
+        let regions = [
+            "sq1:21-34".parse()?,
+            "sq0:1048576-1048581".parse()?,
+            "sq0:8-13".parse()?,
+            "sq0:9-10".parse()?,
+        ];
+
+        let actual = index.query_regions(&regions)?;
+        let expected = [
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(8),
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::from(1 << 16),
+                bgzf::VirtualPosition::from((1 << 16) + 13),
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::from(2 << 16),
+                bgzf::VirtualPosition::from((2 << 16) + 21),
+            ),
+        ];
+        assert_eq!(actual, expected);
+
+        // The linear index excludes the first chunk.
+        let regions = ["sq0:1048576-1048581".parse()?];
+        let actual = index.query_regions(&regions)?;
+        let expected = [Chunk::new(
+            bgzf::VirtualPosition::from(1 << 16),
+            bgzf::VirtualPosition::from((1 << 16) + 13),
+        )];
+        assert_eq!(actual, expected);
+
+        assert!(index.query_regions(&[])?.is_empty());
+
+        let regions = ["sq2".parse()?];
+        assert!(matches!(
+            index.query_regions(&regions),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ pub mod format;
 
 pub use self::{builder::Builder, format::Format};
 
+use std::io;
+
 use indexmap::IndexSet;
 
 /// An ordered set of reference sequence names.
@@ -12,6 +14,7 @@ pub type ReferenceSequenceNames = IndexSet<String>;
 
 /// A tabix index header.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Header {
     format: Format,
     reference_sequence_name_index: usize,
@@ -19,6 +22,7 @@ pub struct Header {
     end_position_index: Option<usize>,
     line_comment_prefix: u8,
     line_skip_count: u32,
+    #[cfg_attr(feature = "serde", serde(with = "reference_sequence_names"))]
     pub(crate) reference_sequence_names: ReferenceSequenceNames,
 }
 
@@ -35,6 +39,85 @@ impl Header {
         Builder::default()
     }
 
+    /// Creates a tabix index header for the BED format.
+    ///
+    /// Positions are 0-based, half-open, and read from the second and third columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix::{self as tabix, index::header::Format};
+    ///
+    /// let header = tabix::index::Header::bed();
+    /// assert_eq!(
+    ///     header.format(),
+    ///     Format::Generic(tabix::index::header::format::CoordinateSystem::Bed)
+    /// );
+    /// assert_eq!(header.start_position_index(), 2);
+    /// assert_eq!(header.end_position_index(), Some(3));
+    /// ```
+    pub fn bed() -> Self {
+        Builder::bed().build()
+    }
+
+    /// Creates a tabix index header for the GFF format.
+    ///
+    /// Positions are 1-based, closed, and read from the fourth and fifth columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix::{self as tabix, index::header::Format};
+    ///
+    /// let header = tabix::index::Header::gff();
+    /// assert_eq!(
+    ///     header.format(),
+    ///     Format::Generic(tabix::index::header::format::CoordinateSystem::Gff)
+    /// );
+    /// assert_eq!(header.start_position_index(), 4);
+    /// assert_eq!(header.end_position_index(), Some(5));
+    /// ```
+    pub fn gff() -> Self {
+        Builder::gff().build()
+    }
+
+    /// Creates a tabix index header for the SAM format.
+    ///
+    /// The end position is calculated from the CIGAR string, and header lines (`@`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix::{self as tabix, index::header::Format};
+    ///
+    /// let header = tabix::index::Header::sam();
+    /// assert_eq!(header.format(), Format::Sam);
+    /// assert_eq!(header.reference_sequence_name_index(), 3);
+    /// assert_eq!(header.line_comment_prefix(), b'@');
+    /// ```
+    pub fn sam() -> Self {
+        Builder::sam().build()
+    }
+
+    /// Creates a tabix index header for the VCF format.
+    ///
+    /// The end position is calculated from the reference bases (or `END` info field), and header
+    /// lines (`#`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix::{self as tabix, index::header::Format};
+    ///
+    /// let header = tabix::index::Header::vcf();
+    /// assert_eq!(header.format(), Format::Vcf);
+    /// assert_eq!(header.start_position_index(), 2);
+    /// assert!(header.end_position_index().is_none());
+    /// ```
+    pub fn vcf() -> Self {
+        Builder::vcf().build()
+    }
+
     /// Returns the format.
     ///
     /// # Examples
@@ -162,6 +245,58 @@ impl Header {
     pub fn reference_sequence_names(&self) -> &ReferenceSequenceNames {
         &self.reference_sequence_names
     }
+
+    /// Parses a tabix index header from CSI auxiliary data.
+    ///
+    /// A CSI for a tabix-indexable format, e.g., as written by `bcftools index` for VCF, stores
+    /// the tabix header (without the reference sequence count) in its auxiliary data. The
+    /// reference sequence names resolve region names to reference sequence IDs in the CSI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let header = tabix::index::Header::vcf();
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_aux(header.to_csi_aux()?)
+    ///     .build();
+    ///
+    /// let actual = tabix::index::Header::from_csi_aux(index.aux())?;
+    /// assert_eq!(actual, header);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_csi_aux(aux: &[u8]) -> io::Result<Self> {
+        let mut reader = aux;
+        let header = crate::reader::read_header(&mut reader)?;
+
+        if reader.is_empty() {
+            Ok(header)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unexpected trailing data in CSI auxiliary data",
+            ))
+        }
+    }
+
+    /// Encodes the header as CSI auxiliary data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let aux = tabix::index::Header::bed().to_csi_aux()?;
+    /// assert_eq!(aux.len(), 28);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn to_csi_aux(&self) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        crate::writer::write_header(&mut buf, self)?;
+        Ok(buf)
+    }
 }
 
 impl Default for Header {
@@ -169,3 +304,96 @@ impl Default for Header {
         Builder::default().build()
     }
 }
+
+/// (De)serializes reference sequence names as a sequence, preserving their order.
+#[cfg(feature = "serde")]
+mod reference_sequence_names {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::ReferenceSequenceNames;
+
+    pub(super) fn serialize<S>(
+        names: &ReferenceSequenceNames,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(names)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<ReferenceSequenceNames, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer).map(|names| names.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> serde_json::Result<()> {
+        let header = Builder::vcf()
+            .set_reference_sequence_names(
+                [String::from("sq1"), String::from("sq0")]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let json = serde_json::to_string(&header)?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["format"], 2);
+        assert_eq!(value["reference_sequence_names"][0], "sq1");
+
+        let actual: Header = serde_json::from_str(&json)?;
+        assert_eq!(actual, header);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_csi_aux() -> io::Result<()> {
+        let data = [
+            0x02, 0x00, 0x00, 0x00, // format = VCF
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x02, 0x00, 0x00, 0x00, // col_beg = 2
+            0x00, 0x00, 0x00, 0x00, // col_end = 0
+            0x23, 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x08, 0x00, 0x00, 0x00, // l_nm = 8
+            b's', b'q', b'0', 0x00, b's', b'q', b'1', 0x00, // names = ["sq0", "sq1"]
+        ];
+
+        let actual = Header::from_csi_aux(&data)?;
+
+        let expected = Builder::vcf()
+            .set_reference_sequence_names(
+                [String::from("sq0"), String::from("sq1")]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+        assert_eq!(expected.to_csi_aux()?, data);
+
+        assert!(matches!(
+            Header::from_csi_aux(&[]),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut data = data.to_vec();
+        data.push(0x00);
+        assert!(matches!(
+            Header::from_csi_aux(&data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -11,6 +11,11 @@ const FORMAT_MASK: i32 = 0xffff;
 
 /// A tabix index format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(try_from = "i32", into = "i32")
+)]
 pub enum Format {
     /// A generic format with a defined coordinate system.
     Generic(CoordinateSystem),
@@ -4,12 +4,17 @@ use noodles_csi::index::reference_sequence::bin::Chunk;
 use super::{reference_sequence, Header, Index, ReferenceSequence, ReferenceSequenceNames};
 
 /// A tabix indexer.
+///
+/// An indexer can also be created from an existing index (`Indexer::from(index)`) to extend it
+/// with records appended to the associated file. Appended records continue the last reference
+/// sequence or start a new one.
 #[derive(Debug, Default)]
 pub struct Indexer {
     header: Header,
     current_reference_sequence_name: String,
     reference_sequence_names: ReferenceSequenceNames,
     reference_sequence_builders: Vec<reference_sequence::Builder>,
+    unplaced_unmapped_record_count: Option<u64>,
 }
 
 impl Indexer {
@@ -87,10 +92,92 @@ impl Indexer {
             .map(|b| b.build())
             .collect();
 
-        Index::builder()
+        let mut builder = Index::builder()
             .set_header(self.header)
             .set_reference_sequence_names(self.reference_sequence_names)
-            .set_reference_sequences(reference_sequences)
-            .build()
+            .set_reference_sequences(reference_sequences);
+
+        if let Some(unplaced_unmapped_record_count) = self.unplaced_unmapped_record_count {
+            builder = builder.set_unplaced_unmapped_record_count(unplaced_unmapped_record_count);
+        }
+
+        builder.build()
+    }
+}
+
+impl From<Index> for Indexer {
+    fn from(index: Index) -> Self {
+        let Index {
+            header,
+            reference_sequences,
+            unplaced_unmapped_record_count,
+        } = index;
+
+        let reference_sequence_names = header.reference_sequence_names().clone();
+
+        let current_reference_sequence_name =
+            reference_sequence_names.last().cloned().unwrap_or_default();
+
+        let mut reference_sequence_builders: Vec<_> = reference_sequences
+            .into_iter()
+            .map(reference_sequence::Builder::from)
+            .collect();
+
+        reference_sequence_builders.resize_with(reference_sequence_names.len(), Default::default);
+
+        Self {
+            header,
+            current_reference_sequence_name,
+            reference_sequence_names,
+            reference_sequence_builders,
+            unplaced_unmapped_record_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    #[test]
+    fn test_from_index() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut indexer = Indexer::default();
+        indexer.add_record(
+            "sq0",
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        let index = indexer.build();
+        let index = Index::builder()
+            .set_header(index.header().clone())
+            .set_reference_sequences(index.reference_sequences().to_vec())
+            .set_unplaced_unmapped_record_count(2)
+            .build();
+
+        let mut indexer = Indexer::from(index);
+        indexer.add_record(
+            "sq1",
+            Position::try_from(5)?,
+            Position::try_from(8)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(21),
+            ),
+        );
+
+        let index = indexer.build();
+
+        assert_eq!(index.reference_sequences().len(), 2);
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(2));
+
+        Ok(())
     }
 }
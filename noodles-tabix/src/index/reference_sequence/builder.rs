@@ -28,11 +28,7 @@ impl Builder {
             return ReferenceSequence::default();
         }
 
-        let bins = self
-            .bin_builders
-            .into_iter()
-            .map(|(_, b)| b.build())
-            .collect();
+        let bins = self.bin_builders.into_values().map(|b| b.build()).collect();
 
         let intervals = self
             .intervals
@@ -83,6 +79,50 @@ impl Builder {
     }
 }
 
+// Seeds a builder with an existing reference sequence, e.g., to extend it with appended records.
+//
+// All existing linear index intervals are kept, as an unset interval is indistinguishable from one
+// that starts at offset 0.
+impl From<ReferenceSequence> for Builder {
+    fn from(reference_sequence: ReferenceSequence) -> Self {
+        let bin_builders = reference_sequence
+            .bins
+            .into_iter()
+            .map(|bin| {
+                let mut builder = Bin::builder();
+                builder.set_id(bin.id());
+
+                for &chunk in bin.chunks() {
+                    builder.add_chunk(chunk);
+                }
+
+                (bin.id(), builder)
+            })
+            .collect();
+
+        let intervals = reference_sequence.intervals.into_iter().map(Some).collect();
+
+        let (start_position, end_position, mapped_record_count) = reference_sequence
+            .metadata
+            .map(|metadata| {
+                (
+                    metadata.start_position(),
+                    metadata.end_position(),
+                    metadata.mapped_record_count(),
+                )
+            })
+            .unwrap_or_default();
+
+        Self {
+            bin_builders,
+            intervals,
+            start_position,
+            end_position,
+            mapped_record_count,
+        }
+    }
+}
+
 #[allow(clippy::eq_op)]
 fn region_to_bin(start: Position, end: Position) -> io::Result<u16> {
     // 0-based, [start, end)
@@ -108,6 +148,8 @@ fn region_to_bin(start: Position, end: Position) -> io::Result<u16> {
 
 #[cfg(test)]
 mod tests {
+    use noodles_csi::binning_index::ReferenceSequenceExt;
+
     use super::*;
 
     #[test]
@@ -185,6 +227,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_reference_sequence() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut builder = Builder::default();
+
+        builder.add_record(
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        let mut builder = Builder::from(builder.build());
+
+        builder.add_record(
+            Position::try_from(16389)?,
+            Position::try_from(16395)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(21),
+            ),
+        );
+
+        let actual = builder.build();
+
+        assert_eq!(actual.bins().len(), 2);
+        assert_eq!(
+            actual.intervals(),
+            [
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9)
+            ]
+        );
+
+        let metadata = actual.metadata().expect("missing metadata");
+        assert_eq!(metadata.end_position(), bgzf::VirtualPosition::from(21));
+        assert_eq!(metadata.mapped_record_count(), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_with_no_bins() {
         let reference_sequence = Builder::default().build();
@@ -0,0 +1,606 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Read, Seek},
+    str,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{index::reference_sequence::bin::Chunk, BinningIndex};
+
+use super::{
+    index::{
+        header::{format::CoordinateSystem, Format, Header},
+        Indexer, ReferenceSequence,
+    },
+    Index,
+};
+
+const DELIMITER: char = '\t';
+
+/// Indexes a bgzipped, coordinate-sorted, tab-delimited text file.
+///
+/// The header describes the layout of the input: the format (and its coordinate system), the
+/// columns of the reference sequence name and start and end positions, the line comment prefix,
+/// and the number of lines to skip at the start. Lines that start with the comment prefix are
+/// skipped.
+///
+/// If the header does not have an end position column, the end position is the start position,
+/// except for VCF, where it is calculated from the length of the reference bases, and SAM, where
+/// it is calculated from the CIGAR string.
+///
+/// The input must be grouped by reference sequence and sorted by start position.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+/// use noodles_tabix as tabix;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"# genes\nsq0\t7\t13\nsq0\t20\t34\nsq1\t4\t8\n")?;
+/// let data = writer.finish()?;
+///
+/// let header = tabix::index::Header::bed();
+/// let index = tabix::index(&data[..], header)?;
+///
+/// assert_eq!(index.header().reference_sequence_names().len(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn index<R>(reader: R, header: Header) -> io::Result<Index>
+where
+    R: Read,
+{
+    let mut reader = bgzf::Reader::new(reader);
+    let mut indexer = Index::indexer();
+
+    index_records(
+        &mut reader,
+        &header,
+        &mut indexer,
+        header.line_skip_count(),
+        HashSet::new(),
+        None,
+    )?;
+
+    indexer.set_header(header);
+
+    Ok(indexer.build())
+}
+
+/// Extends an index with records appended to a bgzipped, coordinate-sorted, tab-delimited text
+/// file.
+///
+/// `position` is the virtual position of the end of the file when the index was built, i.e., the
+/// start of the appended records. Only the records from that position are read, and they are added
+/// to the existing index, which avoids rebuilding the index from scratch.
+///
+/// Appended records must continue the last reference sequence in the index or start new reference
+/// sequences, and they must be sorted by start position, starting from the start position of the
+/// last indexed record. Lines are not skipped by the header's
+/// line skip count, but lines that start with the comment prefix are.
+///
+/// The appended blocks must directly follow the original blocks, i.e., the BGZF EOF marker of the
+/// original file must be removed before appending. Otherwise, readers stop at the marker.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor, Write};
+/// use noodles_bgzf as bgzf;
+/// use noodles_tabix as tabix;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"sq0\t7\t13\n")?;
+/// writer.flush()?;
+/// let position = writer.virtual_position();
+/// let index = tabix::index(&writer.get_ref()[..], tabix::index::Header::bed())?;
+///
+/// writer.write_all(b"sq0\t20\t34\nsq1\t4\t8\n")?;
+/// let data = writer.finish()?;
+///
+/// let index = tabix::extend_index(Cursor::new(data), index, position)?;
+/// assert_eq!(index.header().reference_sequence_names().len(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn extend_index<R>(
+    reader: R,
+    index: Index,
+    position: bgzf::VirtualPosition,
+) -> io::Result<Index>
+where
+    R: Read + Seek,
+{
+    let mut reader = bgzf::Reader::new(reader);
+
+    let names = index.header().reference_sequence_names();
+    let reference_sequence_names: HashSet<_> = names.iter().cloned().collect();
+
+    let current = match names.last() {
+        Some(name) => {
+            let start = match index.reference_sequences().get(names.len() - 1) {
+                Some(reference_sequence) => {
+                    read_last_start(&mut reader, index.header(), reference_sequence, position)?
+                }
+                None => Position::MIN,
+            };
+
+            Some((name.clone(), start))
+        }
+        None => None,
+    };
+
+    reader.seek(position)?;
+
+    let header = index.header().clone();
+    let mut indexer = Indexer::from(index);
+
+    index_records(
+        &mut reader,
+        &header,
+        &mut indexer,
+        0,
+        reference_sequence_names,
+        current,
+    )?;
+
+    Ok(indexer.build())
+}
+
+// Returns the start position of the last indexed record of a reference sequence.
+//
+// Records are sorted, so this is the maximum start position. The last record is at or after the
+// first record that overlaps the last linear index window, so only the records from there to
+// `end` are read.
+fn read_last_start<R>(
+    reader: &mut bgzf::Reader<R>,
+    header: &Header,
+    reference_sequence: &ReferenceSequence,
+    end: bgzf::VirtualPosition,
+) -> io::Result<Position>
+where
+    R: Read + Seek,
+{
+    let mut last_start = Position::MIN;
+
+    let start = match reference_sequence.intervals().last() {
+        Some(&position) => position,
+        None => return Ok(last_start),
+    };
+
+    reader.seek(start)?;
+
+    let mut buf = Vec::new();
+
+    while reader.virtual_position() < end {
+        buf.clear();
+
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+
+        if buf.first() == Some(&header.line_comment_prefix()) {
+            continue;
+        }
+
+        let line = str::from_utf8(&buf)
+            .map(|s| s.trim_end_matches(&['\n', '\r'][..]))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (_, start, _) = parse_line(header, line)?;
+        last_start = start;
+    }
+
+    Ok(last_start)
+}
+
+fn index_records<R>(
+    reader: &mut bgzf::Reader<R>,
+    header: &Header,
+    indexer: &mut Indexer,
+    line_skip_count: u32,
+    mut reference_sequence_names: HashSet<String>,
+    mut current: Option<(String, Position)>,
+) -> io::Result<()>
+where
+    R: Read,
+{
+    let mut buf = Vec::new();
+    let mut line_count = 0;
+
+    loop {
+        let start_position = reader.virtual_position();
+
+        buf.clear();
+
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+
+        line_count += 1;
+
+        if line_count <= line_skip_count || buf.first() == Some(&header.line_comment_prefix()) {
+            continue;
+        }
+
+        let line = str::from_utf8(&buf)
+            .map(|s| s.trim_end_matches(&['\n', '\r'][..]))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (reference_sequence_name, start, end) = parse_line(header, line)?;
+
+        match &current {
+            Some((name, prev_start)) if name == reference_sequence_name => {
+                if start < *prev_start {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "input is not coordinate-sorted: {}:{} is after {}:{}",
+                            reference_sequence_name, start, name, prev_start
+                        ),
+                    ));
+                }
+            }
+            _ => {
+                if !reference_sequence_names.insert(reference_sequence_name.to_string()) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "input is not grouped by reference sequence: {}",
+                            reference_sequence_name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        current = Some((reference_sequence_name.into(), start));
+
+        let chunk = Chunk::new(start_position, end_position);
+        indexer.add_record(reference_sequence_name, start, end, chunk);
+    }
+
+    Ok(())
+}
+
+// Returns the reference sequence name and 1-based start and end positions of a line.
+fn parse_line<'a>(header: &Header, line: &'a str) -> io::Result<(&'a str, Position, Position)> {
+    let fields: Vec<_> = line.split(DELIMITER).collect();
+
+    let get_field = |i: usize| {
+        i.checked_sub(1)
+            .and_then(|j| fields.get(j))
+            .copied()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("missing field {}: {}", i, line),
+                )
+            })
+    };
+
+    let parse_int = |i: usize| {
+        get_field(i).and_then(|s| {
+            s.parse::<usize>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    };
+
+    let reference_sequence_name = get_field(header.reference_sequence_name_index())?;
+
+    let coordinate_system = header.format().coordinate_system();
+    let raw_start = parse_int(header.start_position_index())?;
+
+    let start = match coordinate_system {
+        CoordinateSystem::Gff => Some(raw_start),
+        CoordinateSystem::Bed => Position::from_zero_based(raw_start).map(usize::from),
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid start position"))?;
+
+    let end = match header.end_position_index() {
+        Some(i) => parse_int(i)?,
+        None => match header.format() {
+            Format::Vcf => vcf_end(&fields, start)?,
+            Format::Sam => sam_end(&fields, start)?,
+            Format::Generic(_) => start,
+        },
+    };
+
+    // Empty intervals, e.g., insertions in BED, are indexed as covering the start position.
+    let end = end.max(start);
+
+    let start =
+        Position::try_from(start).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let end = Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok((reference_sequence_name, start, end))
+}
+
+// Uses the `END` info field, if present, or the length of the reference bases.
+fn vcf_end(fields: &[&str], start: usize) -> io::Result<usize> {
+    const REFERENCE_BASES_INDEX: usize = 3;
+    const INFO_INDEX: usize = 7;
+
+    if let Some(info) = fields.get(INFO_INDEX) {
+        for field in info.split(';') {
+            if let Some(value) = field.strip_prefix("END=") {
+                return value
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+            }
+        }
+    }
+
+    let reference_bases = fields
+        .get(REFERENCE_BASES_INDEX)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing reference bases"))?;
+
+    Ok(start + reference_bases.len().max(1) - 1)
+}
+
+// Uses the number of reference bases consumed by the CIGAR operations.
+fn sam_end(fields: &[&str], start: usize) -> io::Result<usize> {
+    const CIGAR_INDEX: usize = 5;
+
+    let cigar = fields
+        .get(CIGAR_INDEX)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing CIGAR"))?;
+
+    if *cigar == "*" {
+        return Ok(start);
+    }
+
+    let mut len = 0;
+    let mut n = 0;
+
+    for b in cigar.bytes() {
+        match b {
+            b'0'..=b'9' => n = n * 10 + usize::from(b - b'0'),
+            b'M' | b'D' | b'N' | b'=' | b'X' => {
+                len += n;
+                n = 0;
+            }
+            b'I' | b'S' | b'H' | b'P' => n = 0,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid CIGAR: {}", cigar),
+                ))
+            }
+        }
+    }
+
+    Ok(start + len.max(1) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+
+    use super::*;
+    use crate::index::header::Builder;
+
+    fn bgzip(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    fn query(
+        data: &[u8],
+        index: &Index,
+        reference_sequence_id: usize,
+        start: usize,
+        end: usize,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let start = Position::try_from(start)?;
+        let end = Position::try_from(end)?;
+        let chunks = index.query(reference_sequence_id, start..=end)?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+        let mut lines = Vec::new();
+
+        for chunk in chunks {
+            reader.seek(chunk.start())?;
+
+            while reader.virtual_position() < chunk.end() {
+                let mut line = String::new();
+                reader.read_line(&mut line)?;
+                lines.push(line.trim_end().into());
+            }
+        }
+
+        Ok(lines)
+    }
+
+    #[test]
+    fn test_index_with_bed() -> Result<(), Box<dyn std::error::Error>> {
+        let data = bgzip(b"# genes\nsq0\t7\t13\tr0\nsq0\t20\t34\tr1\nsq1\t4\t8\tr2\n")?;
+
+        let header = Builder::bed().build();
+        let index = index(&data[..], header)?;
+
+        let reference_sequence_names: Vec<_> = index
+            .header()
+            .reference_sequence_names()
+            .iter()
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+        assert_eq!(
+            index.header().format(),
+            Format::Generic(CoordinateSystem::Bed)
+        );
+
+        let lines = query(&data, &index, 0, 8, 13)?;
+        assert!(lines.contains(&String::from("sq0\t7\t13\tr0")));
+        assert!(!lines.iter().any(|line| line.starts_with("# ")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_with_custom_columns() -> Result<(), Box<dyn std::error::Error>> {
+        let data = bgzip(b"id\tchrom\tpos\nr0\tsq0\t8\nr1\tsq0\t21\n")?;
+
+        let header = Builder::gff()
+            .set_reference_sequence_name_index(2)
+            .set_start_position_index(3)
+            .set_end_position_index(None)
+            .set_line_skip_count(1)
+            .build();
+
+        let index = index(&data[..], header)?;
+
+        assert_eq!(index.header().reference_sequence_names().len(), 1);
+
+        let lines = query(&data, &index, 0, 21, 21)?;
+        assert!(lines.contains(&String::from("r1\tsq0\t21")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_with_unsorted_input() -> io::Result<()> {
+        let header = Builder::bed().build();
+
+        let data = bgzip(b"sq0\t20\t34\nsq0\t7\t13\n")?;
+        assert!(matches!(
+            index(&data[..], header.clone()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = bgzip(b"sq0\t7\t13\nsq1\t7\t13\nsq0\t20\t34\n")?;
+        assert!(matches!(
+            index(&data[..], header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_line() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Builder::bed().build();
+        assert_eq!(
+            parse_line(&header, "sq0\t7\t13")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+        assert_eq!(
+            parse_line(&header, "sq0\t7\t7")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(8)?)
+        );
+        assert!(parse_line(&header, "sq0\t7").is_err());
+        assert!(parse_line(&header, "sq0\tndls\t13").is_err());
+
+        let header = Builder::vcf().build();
+        assert_eq!(
+            parse_line(&header, "sq0\t8\t.\tACGT\tA\t.\t.\t.")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(11)?)
+        );
+        assert_eq!(
+            parse_line(&header, "sq0\t8\t.\tA\t<DEL>\t.\t.\tSVTYPE=DEL;END=13")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+
+        let header = Builder::sam().build();
+        assert_eq!(
+            parse_line(
+                &header,
+                "r0\t0\tsq0\t8\t60\t2S4M1I2D1M\t*\t0\t0\tACGTACGTAC\t*"
+            )?,
+            ("sq0", Position::try_from(8)?, Position::try_from(14)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_index() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"# genes\nsq0\t7\t13\tr0\nsq0\t20\t34\tr1\n")?;
+        writer.flush()?;
+
+        let position = writer.virtual_position();
+        let old_index = index(&writer.get_ref()[..], Builder::bed().build())?;
+
+        writer.write_all(b"sq0\t40\t55\tr2\nsq1\t4\t8\tr3\n")?;
+        let data = writer.finish()?;
+
+        let index = extend_index(Cursor::new(&data), old_index, position)?;
+
+        let reference_sequence_names: Vec<_> = index
+            .header()
+            .reference_sequence_names()
+            .iter()
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+
+        assert_eq!(
+            query(&data, &index, 0, 1, 100)?,
+            ["sq0\t7\t13\tr0", "sq0\t20\t34\tr1", "sq0\t40\t55\tr2"]
+        );
+        assert_eq!(query(&data, &index, 1, 1, 100)?, ["sq1\t4\t8\tr3"]);
+
+        let metadata = index.reference_sequences()[0]
+            .metadata()
+            .expect("missing metadata");
+        assert_eq!(metadata.mapped_record_count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_index_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"sq0\t7\t13\nsq0\t20\t34\n")?;
+        writer.flush()?;
+
+        let position = writer.virtual_position();
+        let old_index = index(&writer.get_ref()[..], Builder::bed().build())?;
+
+        writer.write_all(b"sq0\t15\t21\n")?;
+        let data = writer.finish()?;
+
+        assert!(matches!(
+            extend_index(Cursor::new(&data), old_index, position),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_index_with_ungrouped_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"sq0\t7\t13\nsq1\t4\t8\n")?;
+        writer.flush()?;
+
+        let position = writer.virtual_position();
+        let old_index = index(&writer.get_ref()[..], Builder::bed().build())?;
+
+        writer.write_all(b"sq0\t40\t55\n")?;
+        let data = writer.finish()?;
+
+        assert!(matches!(
+            extend_index(Cursor::new(&data), old_index, position),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -29,10 +29,16 @@
 pub mod r#async;
 
 pub mod index;
+mod indexer;
 mod reader;
 mod writer;
 
-pub use self::{index::Index, reader::Reader, writer::Writer};
+pub use self::{
+    index::Index,
+    indexer::{extend_index, index},
+    reader::Reader,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
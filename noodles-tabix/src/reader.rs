@@ -99,7 +99,7 @@ where
     }
 }
 
-fn read_header<R>(reader: &mut R) -> io::Result<index::Header>
+pub(crate) fn read_header<R>(reader: &mut R) -> io::Result<index::Header>
 where
     R: Read,
 {
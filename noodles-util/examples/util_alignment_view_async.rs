@@ -0,0 +1,52 @@
+//! Prints an alignment file in the SAM format.
+//!
+//! This is the async version of `util_alignment_view`.
+//!
+//! Reference sequences in the FASTA format are only required for CRAM inputs that require them.
+//!
+//! The result matches the output of `samtools view --no-PG --with-header [--reference <fasta-src>]
+//! <src>`.
+
+use std::env;
+
+use futures::TryStreamExt;
+use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+use noodles_util::alignment::{self, Format};
+use tokio::io;
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next();
+
+    let mut builder = alignment::r#async::reader::Builder::default();
+
+    if let Some(fasta_src) = fasta_src {
+        let repository = IndexedReader::builder()
+            .open(fasta_src)
+            .map(fasta::Repository::new)?;
+
+        builder = builder.set_reference_sequence_repository(repository);
+    }
+
+    let mut reader = builder.build_from_path(src).await?;
+    let header = reader.read_header().await?;
+
+    let mut writer = alignment::AsyncWriter::builder(io::stdout())
+        .set_format(Format::Sam)
+        .build()?;
+
+    writer.write_header(&header).await?;
+
+    let mut records = reader.records(&header);
+
+    while let Some(record) = records.try_next().await? {
+        writer.write_record(&header, &record).await?;
+    }
+
+    writer.shutdown().await?;
+
+    Ok(())
+}
@@ -1,7 +1,21 @@
 //! I/O for alignment formats.
 
+#[cfg(feature = "async")]
+pub mod r#async;
+mod converter;
+mod filter;
 mod format;
+pub mod primer_trimmer;
+mod read_group_reassigner;
 mod reader;
+pub mod stats;
 mod writer;
 
-pub use self::{format::Format, reader::Reader, writer::Writer};
+pub use self::{
+    converter::Converter, filter::Filter, format::Format, primer_trimmer::PrimerTrimmer,
+    read_group_reassigner::ReadGroupReassigner, reader::Reader, writer::Writer,
+};
+pub use crate::compression::Compression;
+
+#[cfg(feature = "async")]
+pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
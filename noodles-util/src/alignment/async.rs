@@ -0,0 +1,6 @@
+//! Async I/O for alignment formats.
+
+pub mod reader;
+pub mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
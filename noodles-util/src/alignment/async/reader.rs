@@ -0,0 +1,192 @@
+//! Async alignment reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use futures::{Stream, StreamExt};
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_cram as cram;
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, alignment::Record};
+use tokio::io::{self, AsyncRead, BufReader};
+
+use crate::{
+    alignment::{Compression, Format},
+    sniff,
+};
+
+enum Inner<R>
+where
+    R: AsyncRead,
+{
+    Sam(sam::AsyncReader<R>),
+    SamBgzf(sam::AsyncReader<bgzf::AsyncReader<R>>),
+    Bam(bam::AsyncReader<bgzf::AsyncReader<R>>),
+    Cram(cram::AsyncReader<R>),
+}
+
+/// An async alignment reader.
+///
+/// This is created by calling [`Builder::build_from_path`] or [`Builder::build_from_reader`].
+pub struct Reader<R>
+where
+    R: AsyncRead,
+{
+    inner: Inner<BufReader<sniff::AsyncReader<R>>>,
+    reference_sequence_repository: fasta::Repository,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Returns the format of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_reader(&b"@HD\tVN:1.6\n"[..])
+    ///     .await?;
+    ///
+    /// assert_eq!(reader.format(), Format::Sam);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn format(&self) -> Format {
+        match self.inner {
+            Inner::Sam(_) | Inner::SamBgzf(_) => Format::Sam,
+            Inner::Bam(_) => Format::Bam,
+            Inner::Cram(_) => Format::Cram,
+        }
+    }
+
+    /// Returns the compression method of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::alignment;
+    ///
+    /// let reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_reader(&b"@HD\tVN:1.6\n"[..])
+    ///     .await?;
+    ///
+    /// assert!(reader.compression().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compression(&self) -> Option<Compression> {
+        match self.inner {
+            Inner::Sam(_) | Inner::Cram(_) => None,
+            Inner::SamBgzf(_) | Inner::Bam(_) => Some(Compression::Bgzf),
+        }
+    }
+
+    /// Reads and parses an alignment header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_sam::{self as sam, header::header::Version};
+    /// use noodles_util::alignment;
+    ///
+    /// let data = b"@HD\tVN:1.6
+    /// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_reader(&data[..])
+    ///     .await?;
+    ///
+    /// let actual = reader.read_header().await?;
+    ///
+    /// let expected = sam::Header::builder()
+    ///     .set_header(sam::header::header::Header::new(Version::new(1, 6)))
+    ///     .build();
+    ///
+    /// assert_eq!(actual, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_header(&mut self) -> io::Result<sam::Header> {
+        let raw_header = match &mut self.inner {
+            Inner::Sam(inner) => inner.read_header().await?,
+            Inner::SamBgzf(inner) => inner.read_header().await?,
+            Inner::Bam(inner) => {
+                let raw_header = inner.read_header().await?;
+                inner.read_reference_sequences().await?;
+                raw_header
+            }
+            Inner::Cram(inner) => {
+                inner.read_file_definition().await?;
+                inner.read_file_header().await?
+            }
+        };
+
+        raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_util::alignment;
+    ///
+    /// let data = b"@HD\tVN:1.6
+    /// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_reader(&data[..])
+    ///     .await?;
+    ///
+    /// let header = reader.read_header().await?;
+    /// let mut records = reader.records(&header);
+    ///
+    /// assert!(records.try_next().await?.is_some());
+    /// assert!(records.try_next().await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+    ) -> impl Stream<Item = io::Result<Record>> + 'a {
+        match &mut self.inner {
+            Inner::Sam(inner) => inner.records(header).boxed_local(),
+            Inner::SamBgzf(inner) => inner.records(header).boxed_local(),
+            Inner::Bam(inner) => inner.records().boxed_local(),
+            Inner::Cram(inner) => inner
+                .records(&self.reference_sequence_repository, header)
+                .map(move |result| {
+                    result.and_then(|record| record.try_into_alignment_record(header))
+                })
+                .boxed_local(),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::path::Path;
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_cram as cram;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+use tokio::{
+    fs::File,
+    io::{self, AsyncBufReadExt, AsyncRead, BufReader},
+};
+
+use super::{Inner, Reader};
+use crate::{
+    alignment::{format::detect_format, Compression, Format},
+    compression::{detect_compression, invalid_compression},
+    sniff,
+};
+
+/// An async alignment reader builder.
+#[derive(Default)]
+pub struct Builder {
+    format: Option<Format>,
+    compression: Option<Option<Compression>>,
+    reference_sequence_repository: fasta::Repository,
+}
+
+impl Builder {
+    /// Sets the format of the input.
+    ///
+    /// By default, the format is autodetected on build. This can be used to override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, Format};
+    /// let builder = alignment::r#async::reader::Builder::default().set_format(Format::Sam);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the compression method of the input.
+    ///
+    /// By default, the compression method is autodetected on build. This can be used to override
+    /// it. `None` is uncompressed.
+    ///
+    /// gzip compression is not supported by the async reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, Compression};
+    /// let builder = alignment::r#async::reader::Builder::default().set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Sets the reference sequence repository.
+    ///
+    /// This is used to read CRAM records that require external reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::alignment;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let builder = alignment::r#async::reader::Builder::default()
+    ///     .set_reference_sequence_repository(repository);
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = reference_sequence_repository;
+        self
+    }
+
+    /// Builds an async alignment reader from a path.
+    ///
+    /// By default, the format and compression method will be autodetected. This can be
+    /// overridden by using [`Self::set_format`] and [`Self::set_compression`], respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::alignment;
+    ///
+    /// let reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_path("sample.bam")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_from_path<P>(self, path: P) -> io::Result<Reader<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).await?;
+        self.build_from_reader(file).await
+    }
+
+    /// Builds an async alignment reader from a reader.
+    ///
+    /// By default, the format and compression method will be autodetected from the content of
+    /// the input. This can be overridden by using [`Self::set_format`] and
+    /// [`Self::set_compression`], respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::alignment;
+    ///
+    /// let reader = alignment::r#async::reader::Builder::default()
+    ///     .build_from_reader(tokio::io::empty())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<R>>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let reader = sniff::read_prefix(reader).await?;
+        let mut reader = BufReader::new(reader);
+
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => detect_compression(reader.fill_buf().await?),
+        };
+
+        let format = self.format;
+
+        let inner = match compression {
+            None => {
+                let format = match format {
+                    Some(format) => format,
+                    None => detect_format(reader.fill_buf().await?, compression),
+                };
+
+                match format {
+                    Format::Sam => Inner::Sam(sam::AsyncReader::new(reader)),
+                    Format::Cram => Inner::Cram(cram::AsyncReader::new(reader)),
+                    Format::Bam => return Err(invalid_compression(format, compression)),
+                }
+            }
+            Some(Compression::Bgzf) => {
+                let mut reader = bgzf::AsyncReader::new(reader);
+
+                let format = match format {
+                    Some(format) => format,
+                    None => detect_format(reader.fill_buf().await?, compression),
+                };
+
+                match format {
+                    Format::Sam => Inner::SamBgzf(sam::AsyncReader::new(reader)),
+                    Format::Bam => Inner::Bam(bam::AsyncReader::from(reader)),
+                    Format::Cram => return Err(invalid_compression(format, compression)),
+                }
+            }
+            Some(Compression::Gzip) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "gzip compression is not supported by the async alignment reader",
+                ))
+            }
+        };
+
+        Ok(Reader {
+            inner,
+            reference_sequence_repository: self.reference_sequence_repository,
+        })
+    }
+}
@@ -0,0 +1,171 @@
+//! Async alignment writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::Record};
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+enum Inner<W>
+where
+    W: AsyncWrite,
+{
+    Sam(sam::AsyncWriter<W>),
+    Bam(bam::AsyncWriter<bgzf::AsyncWriter<W>>),
+}
+
+/// An async alignment writer.
+pub struct Writer<W>
+where
+    W: AsyncWrite,
+{
+    inner: Inner<W>,
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async alignment writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    /// let builder = alignment::AsyncWriter::builder(tokio::io::sink());
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let mut writer = alignment::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Bam)
+    ///     .build()?;
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Sam(inner) => inner.write_header(header).await,
+            Inner::Bam(inner) => {
+                inner.write_header(header).await?;
+                inner
+                    .write_reference_sequences(header.reference_sequences())
+                    .await
+            }
+        }
+    }
+
+    /// Writes an alignment record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_sam::{self as sam, alignment::Record};
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let mut writer = alignment::AsyncWriter::builder(Vec::new())
+    ///     .set_format(Format::Sam)
+    ///     .build()?;
+    ///
+    /// let header = sam::Header::default();
+    /// let record = Record::default();
+    /// writer.write_record(&header, &record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Sam(inner) => inner.write_alignment_record(header, record).await,
+            Inner::Bam(inner) => inner.write_alignment_record(header, record).await,
+        }
+    }
+
+    /// Shuts down the alignment format writer.
+    ///
+    /// For BAM, this writes the final BGZF block and EOF marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let mut writer = alignment::AsyncWriter::builder(Vec::new())
+    ///     .set_format(Format::Bam)
+    ///     .build()?;
+    ///
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Sam(inner) => inner.get_mut().shutdown().await,
+            Inner::Bam(inner) => inner.shutdown().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::alignment::{r#async::reader, Compression, Format};
+
+    #[tokio::test]
+    async fn test_write_record_with_bam_format() -> io::Result<()> {
+        let mut writer = Writer::builder(Vec::new())
+            .set_format(Format::Bam)
+            .build()?;
+
+        let header = sam::Header::default();
+        writer.write_header(&header).await?;
+        writer.write_record(&header, &Record::default()).await?;
+        writer.shutdown().await?;
+
+        let data = match writer.inner {
+            Inner::Bam(inner) => inner.into_inner().into_inner(),
+            Inner::Sam(_) => unreachable!(),
+        };
+
+        let mut reader = reader::Builder::default()
+            .build_from_reader(&data[..])
+            .await?;
+
+        assert_eq!(reader.format(), Format::Bam);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let header = reader.read_header().await?;
+        let records: Vec<_> = reader.records(&header).try_collect().await?;
+        assert_eq!(records, [Record::default()]);
+
+        Ok(())
+    }
+}
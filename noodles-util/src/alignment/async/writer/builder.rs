@@ -0,0 +1,67 @@
+use noodles_bam as bam;
+use noodles_sam as sam;
+use tokio::io::{self, AsyncWrite};
+
+use super::{Inner, Writer};
+use crate::alignment::Format;
+
+/// An async alignment writer builder.
+pub struct Builder<W> {
+    inner: W,
+    format: Format,
+}
+
+impl<W> Builder<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    pub(super) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            format: Format::Sam,
+        }
+    }
+
+    /// Sets the format of the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, Format};
+    /// let builder = alignment::AsyncWriter::builder(tokio::io::sink()).set_format(Format::Bam);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Builds an async alignment writer.
+    ///
+    /// There is no async CRAM writer, so this returns an error if the format is CRAM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let writer = alignment::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Sam)
+    ///     .build()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build(self) -> io::Result<Writer<W>> {
+        let inner = match self.format {
+            Format::Sam => Inner::Sam(sam::AsyncWriter::new(self.inner)),
+            Format::Bam => Inner::Bam(bam::AsyncWriter::new(self.inner)),
+            Format::Cram => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "CRAM cannot be written asynchronously",
+                ))
+            }
+        };
+
+        Ok(Writer { inner })
+    }
+}
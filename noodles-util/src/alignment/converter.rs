@@ -0,0 +1,309 @@
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    io::{self, BufRead, Read, Write},
+    num::NonZeroUsize,
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_cram as cram;
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, alignment::Record, AlignmentReader, AlignmentWriter};
+
+use super::{format::detect_format, Compression, Format};
+use crate::{
+    compression::{detect_compression, invalid_compression},
+    sniff,
+};
+
+type HeaderTransform = Box<dyn Fn(&mut sam::Header)>;
+
+/// An alignment format converter.
+///
+/// A converter reads an alignment format (SAM, BAM, or CRAM) and writes it as another alignment
+/// format. The input format and compression method are autodetected.
+///
+/// BGZF-compressed streams, i.e., BAM and bgzipped SAM, are decompressed and compressed on a pool
+/// of worker threads (see [`Builder::set_worker_count`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufWriter}, num::NonZeroUsize};
+/// use noodles_util::alignment::{self, Format};
+///
+/// let converter = alignment::Converter::builder()
+///     .set_format(Format::Bam)
+///     .set_worker_count(NonZeroUsize::new(8).unwrap())
+///     .build();
+///
+/// let reader = File::open("sample.sam")?;
+/// let writer = File::create("sample.bam").map(BufWriter::new)?;
+/// converter.convert(reader, writer)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Converter {
+    format: Format,
+    worker_count: NonZeroUsize,
+    reference_sequence_repository: fasta::Repository,
+    header_transform: Option<HeaderTransform>,
+}
+
+impl Converter {
+    /// Creates an alignment format converter builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    /// let builder = alignment::Converter::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Converts an alignment format to the output format.
+    ///
+    /// The header is read from the input, transformed, if a transform is set, and written to the
+    /// output. Records are then copied from the input to the output. The output stream is finished
+    /// before returning.
+    ///
+    /// This returns the number of records converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let data = b"@HD\tVN:1.6\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+    ///
+    /// let converter = alignment::Converter::builder()
+    ///     .set_format(Format::Sam)
+    ///     .build();
+    ///
+    /// let record_count = converter.convert(&data[..], io::sink())?;
+    /// assert_eq!(record_count, 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn convert<R, W>(&self, reader: R, writer: W) -> io::Result<u64>
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut reader = self.build_reader(reader)?;
+        let src_header = reader.read_header()?;
+
+        let mut dst_header = src_header.clone();
+
+        if let Some(transform) = &self.header_transform {
+            transform(&mut dst_header);
+        }
+
+        let mut writer = self.build_writer(writer);
+        writer.get_mut().write_alignment_header(&dst_header)?;
+
+        let mut record_count = 0;
+
+        for result in reader.records(&self.reference_sequence_repository, &src_header) {
+            let record = result?;
+            writer
+                .get_mut()
+                .write_alignment_record(&dst_header, &record)?;
+            record_count += 1;
+        }
+
+        writer.finish(&dst_header)?;
+
+        Ok(record_count)
+    }
+
+    fn build_reader<R>(&self, reader: R) -> io::Result<Reader<R>>
+    where
+        R: Read + Send + 'static,
+    {
+        let reader = sniff::Reader::new(reader)?;
+        let compression = detect_compression(reader.prefix());
+
+        if compression == Some(Compression::Bgzf) {
+            let mut reader =
+                bgzf::MultithreadedReader::with_worker_count(self.worker_count, reader);
+
+            let inner: Box<dyn AlignmentReader> =
+                match detect_format(reader.fill_buf()?, compression) {
+                    Format::Sam => Box::new(sam::Reader::new(reader)),
+                    Format::Bam => Box::new(bam::Reader::from(reader)),
+                    Format::Cram => return Err(invalid_compression(Format::Cram, compression)),
+                };
+
+            Ok(Reader::Bgzf(inner))
+        } else {
+            super::Reader::builder()
+                .set_compression(compression)
+                .set_reference_sequence_repository(self.reference_sequence_repository.clone())
+                .build_from_reader(reader)
+                .map(|reader| Reader::Default(Box::new(reader)))
+        }
+    }
+
+    fn build_writer<W>(&self, writer: W) -> Writer<W>
+    where
+        W: Write + Send + 'static,
+    {
+        match self.format {
+            Format::Sam => Writer::Sam(sam::Writer::new(writer)),
+            Format::Bam => {
+                let writer =
+                    bgzf::MultithreadedWriter::with_worker_count(self.worker_count, writer);
+                Writer::Bam(bam::Writer::from(writer))
+            }
+            Format::Cram => Writer::Cram(
+                cram::Writer::builder(writer)
+                    .set_reference_sequence_repository(self.reference_sequence_repository.clone())
+                    .build(),
+            ),
+        }
+    }
+}
+
+enum Reader<R> {
+    Default(Box<super::Reader<sniff::Reader<R>>>),
+    Bgzf(Box<dyn AlignmentReader>),
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    fn read_header(&mut self) -> io::Result<sam::Header> {
+        match self {
+            Self::Default(inner) => inner.read_header(),
+            Self::Bgzf(inner) => inner.read_alignment_header(),
+        }
+    }
+
+    fn records<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+    ) -> Box<dyn Iterator<Item = io::Result<Record>> + 'a> {
+        match self {
+            Self::Default(inner) => Box::new(inner.records(header)),
+            Self::Bgzf(inner) => inner.alignment_records(reference_sequence_repository, header),
+        }
+    }
+}
+
+enum Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    Sam(sam::Writer<W>),
+    Bam(bam::Writer<bgzf::MultithreadedWriter<W>>),
+    Cram(cram::Writer<W>),
+}
+
+impl<W> Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    fn get_mut(&mut self) -> &mut dyn AlignmentWriter {
+        match self {
+            Self::Sam(inner) => inner,
+            Self::Bam(inner) => inner,
+            Self::Cram(inner) => inner,
+        }
+    }
+
+    fn finish(self, header: &sam::Header) -> io::Result<()> {
+        match self {
+            Self::Sam(inner) => inner.into_inner().flush(),
+            Self::Bam(inner) => inner.into_inner().finish().map(|_| ()),
+            Self::Cram(mut inner) => inner.try_finish(header),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuf {
+        fn take(&self) -> Vec<u8> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    const SAM_HEADER: &[u8] = b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n";
+
+    const SAM_RECORDS: &[u8] = b"r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS\n\
+r1\t4\t*\t0\t255\t*\t*\t0\t0\tTTGG\tNOOD\n";
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let worker_count = NonZeroUsize::new(2).unwrap();
+
+        let bam_data = {
+            let buf = SharedBuf::default();
+
+            let converter = Converter::builder()
+                .set_format(Format::Bam)
+                .set_worker_count(worker_count)
+                .build();
+
+            let sam_data = [SAM_HEADER, SAM_RECORDS].concat();
+            assert_eq!(
+                converter.convert(io::Cursor::new(sam_data), buf.clone())?,
+                2
+            );
+
+            buf.take()
+        };
+
+        let reader = crate::alignment::Reader::builder().build_from_reader(&bam_data[..])?;
+        assert_eq!(reader.format(), Format::Bam);
+
+        let sam_data = {
+            let buf = SharedBuf::default();
+
+            let converter = Converter::builder()
+                .set_format(Format::Sam)
+                .set_worker_count(worker_count)
+                .set_header_transform(|header| {
+                    header.comments_mut().push(String::from("noodles-util"))
+                })
+                .build();
+
+            assert_eq!(
+                converter.convert(io::Cursor::new(bam_data), buf.clone())?,
+                2
+            );
+
+            buf.take()
+        };
+
+        let expected = [SAM_HEADER, b"@CO\tnoodles-util\n", SAM_RECORDS].concat();
+
+        assert_eq!(sam_data, expected);
+
+        Ok(())
+    }
+}
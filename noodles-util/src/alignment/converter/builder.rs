@@ -0,0 +1,126 @@
+use std::num::NonZeroUsize;
+
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::Converter;
+use crate::alignment::Format;
+
+/// An alignment format converter builder.
+pub struct Builder {
+    format: Format,
+    worker_count: NonZeroUsize,
+    reference_sequence_repository: fasta::Repository,
+    header_transform: Option<super::HeaderTransform>,
+}
+
+impl Builder {
+    /// Sets the format of the output.
+    ///
+    /// By default, the output format is SAM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, Format};
+    /// let builder = alignment::Converter::builder().set_format(Format::Cram);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the number of worker threads.
+    ///
+    /// Worker threads are used to decompress BGZF-compressed input and compress BGZF-compressed
+    /// output. CRAM containers are encoded and decoded on the calling thread.
+    ///
+    /// By default, the worker count is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment;
+    ///
+    /// let builder = alignment::Converter::builder()
+    ///     .set_worker_count(NonZeroUsize::new(8).unwrap());
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets the reference sequence repository.
+    ///
+    /// The repository is used to decode CRAM input and encode CRAM output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::alignment;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let builder = alignment::Converter::builder()
+    ///     .set_reference_sequence_repository(repository);
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = reference_sequence_repository;
+        self
+    }
+
+    /// Sets a header transform.
+    ///
+    /// The transform is applied to the input header before it is written to the output. Records
+    /// are written using the transformed header, so reference sequences may be renamed but must
+    /// not be reordered or removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    ///
+    /// let builder = alignment::Converter::builder()
+    ///     .set_header_transform(|header| header.comments_mut().push(String::from("noodles")));
+    /// ```
+    pub fn set_header_transform<F>(mut self, header_transform: F) -> Self
+    where
+        F: Fn(&mut sam::Header) + 'static,
+    {
+        self.header_transform = Some(Box::new(header_transform));
+        self
+    }
+
+    /// Builds an alignment format converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    /// let converter = alignment::Converter::builder().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        Converter {
+            format: self.format,
+            worker_count: self.worker_count,
+            reference_sequence_repository: self.reference_sequence_repository,
+            header_transform: self.header_transform,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            format: Format::Sam,
+            worker_count: NonZeroUsize::new(1).unwrap(),
+            reference_sequence_repository: fasta::Repository::default(),
+            header_transform: None,
+        }
+    }
+}
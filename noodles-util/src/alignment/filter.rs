@@ -0,0 +1,120 @@
+use noodles_sam::{
+    alignment::Record,
+    record::{mapping_quality, Flags, MappingQuality},
+};
+
+/// An alignment record filter.
+///
+/// A record passes the filter if it has all of the required flags, none of the excluded flags,
+/// and a mapping quality of at least the minimum mapping quality. This is the same as the `-f`,
+/// `-F`, and `-q` options of `samtools view`, respectively.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     alignment::Record,
+///     record::{Flags, MappingQuality},
+/// };
+/// use noodles_util::alignment::Filter;
+///
+/// let filter = Filter::default()
+///     .set_excluded_flags(Flags::UNMAPPED | Flags::DUPLICATE)
+///     .set_min_mapping_quality(MappingQuality::new(20).unwrap());
+///
+/// let record = Record::builder()
+///     .set_flags(Flags::empty())
+///     .set_mapping_quality(MappingQuality::new(60).unwrap())
+///     .build();
+/// assert!(filter.matches(&record));
+///
+/// let record = Record::builder()
+///     .set_flags(Flags::DUPLICATE)
+///     .set_mapping_quality(MappingQuality::new(60).unwrap())
+///     .build();
+/// assert!(!filter.matches(&record));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Filter {
+    required_flags: Flags,
+    excluded_flags: Flags,
+    min_mapping_quality: u8,
+}
+
+impl Filter {
+    /// Sets the flags a record must have.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// use noodles_util::alignment::Filter;
+    /// let filter = Filter::default().set_required_flags(Flags::PROPERLY_ALIGNED);
+    /// ```
+    pub fn set_required_flags(mut self, flags: Flags) -> Self {
+        self.required_flags = flags;
+        self
+    }
+
+    /// Sets the flags a record must not have.
+    ///
+    /// A record is excluded if it has any of these flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// use noodles_util::alignment::Filter;
+    /// let filter = Filter::default().set_excluded_flags(Flags::SECONDARY | Flags::SUPPLEMENTARY);
+    /// ```
+    pub fn set_excluded_flags(mut self, flags: Flags) -> Self {
+        self.excluded_flags = flags;
+        self
+    }
+
+    /// Sets the minimum mapping quality.
+    ///
+    /// A missing mapping quality is treated as its raw value (255), i.e., it is never excluded by
+    /// this filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::MappingQuality;
+    /// use noodles_util::alignment::Filter;
+    /// let filter = Filter::default().set_min_mapping_quality(MappingQuality::new(20).unwrap());
+    /// ```
+    pub fn set_min_mapping_quality(mut self, mapping_quality: MappingQuality) -> Self {
+        self.min_mapping_quality = u8::from(mapping_quality);
+        self
+    }
+
+    /// Returns whether a record passes the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::Record, record::Flags};
+    /// use noodles_util::alignment::Filter;
+    ///
+    /// let filter = Filter::default().set_required_flags(Flags::SEGMENTED);
+    ///
+    /// let record = Record::builder().set_flags(Flags::SEGMENTED).build();
+    /// assert!(filter.matches(&record));
+    ///
+    /// let record = Record::default();
+    /// assert!(!filter.matches(&record));
+    /// ```
+    pub fn matches(&self, record: &Record) -> bool {
+        let flags = record.flags();
+
+        let mapping_quality = record
+            .mapping_quality()
+            .map(u8::from)
+            .unwrap_or(mapping_quality::MISSING);
+
+        flags.contains(self.required_flags)
+            && !flags.intersects(self.excluded_flags)
+            && mapping_quality >= self.min_mapping_quality
+    }
+}
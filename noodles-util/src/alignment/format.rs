@@ -1,3 +1,5 @@
+use super::Compression;
+
 /// An alignment format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Format {
@@ -8,3 +10,15 @@ pub enum Format {
     /// CRAM.
     Cram,
 }
+
+// Detects the format from the start of a (decompressed) stream.
+pub(super) fn detect_format(src: &[u8], compression: Option<Compression>) -> Format {
+    const CRAM_MAGIC_NUMBER: [u8; 4] = [b'C', b'R', b'A', b'M'];
+    const BAM_MAGIC_NUMBER: [u8; 4] = [b'B', b'A', b'M', 0x01];
+
+    match compression {
+        None if src.starts_with(&CRAM_MAGIC_NUMBER) => Format::Cram,
+        Some(Compression::Bgzf) if src.starts_with(&BAM_MAGIC_NUMBER) => Format::Bam,
+        _ => Format::Sam,
+    }
+}
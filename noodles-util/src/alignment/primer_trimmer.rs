@@ -0,0 +1,548 @@
+//! Alignment primer trimming.
+
+use std::{collections::HashMap, io};
+
+use noodles_core::{Position, Region};
+use noodles_sam::{
+    alignment::Record,
+    header::ReferenceSequences,
+    record::{
+        cigar::{op::Kind, Op},
+        quality_scores::Score,
+        Cigar,
+    },
+};
+
+/// How trimmed bases are clipped.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClipMode {
+    /// Trimmed bases are soft clipped (`S`), i.e., they are kept in the sequence and quality
+    /// scores.
+    Soft,
+    /// Trimmed bases are hard clipped (`H`), i.e., they are removed from the sequence and quality
+    /// scores.
+    Hard,
+}
+
+/// An alignment primer trimmer.
+///
+/// This clips the ends of alignment records that overlap primers, e.g., for amplicon sequencing.
+/// The start of an alignment is clipped through the end of the primers that contain the alignment
+/// start, and the end of an alignment is clipped from the start of the primers that contain the
+/// alignment end. The CIGAR and alignment start of a trimmed record are updated.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{Position, Region};
+/// use noodles_sam::{self as sam, alignment::Record, header::ReferenceSequence};
+/// use noodles_util::alignment::PrimerTrimmer;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 34)?)
+///     .build();
+///
+/// let primers = ["sq0:1-4".parse()?];
+/// let trimmer = PrimerTrimmer::new(header.reference_sequences(), &primers)?;
+///
+/// let mut record = Record::builder()
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar("8M".parse()?)
+///     .set_sequence("ACGTACGT".parse()?)
+///     .build();
+///
+/// assert!(trimmer.trim(&mut record));
+/// assert_eq!(record.alignment_start(), Some(Position::try_from(5)?));
+/// assert_eq!(record.cigar().to_string(), "3S5M");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrimerTrimmer {
+    primers: HashMap<usize, Vec<(Position, Position)>>,
+    clip_mode: ClipMode,
+    mask_quality_scores: bool,
+}
+
+impl PrimerTrimmer {
+    /// Creates a primer trimmer.
+    ///
+    /// The primer regions are resolved to reference sequence IDs using the given reference
+    /// sequences. This returns an error if a primer is on a reference sequence that is not in the
+    /// reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::header::ReferenceSequences;
+    /// use noodles_util::alignment::PrimerTrimmer;
+    /// let trimmer = PrimerTrimmer::new(&ReferenceSequences::default(), &[])?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(reference_sequences: &ReferenceSequences, primers: &[Region]) -> io::Result<Self> {
+        let mut map: HashMap<_, Vec<_>> = HashMap::new();
+
+        for primer in primers {
+            let id = reference_sequences
+                .get_index_of(primer.name())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid primer reference sequence name: {}", primer.name()),
+                    )
+                })?;
+
+            let interval = primer.interval();
+            let start = interval.start().unwrap_or(Position::MIN);
+            let end = interval.end().unwrap_or(Position::MAX);
+
+            map.entry(id).or_default().push((start, end));
+        }
+
+        Ok(Self {
+            primers: map,
+            clip_mode: ClipMode::Soft,
+            mask_quality_scores: false,
+        })
+    }
+
+    /// Sets how trimmed bases are clipped.
+    ///
+    /// By default, this is set to [`ClipMode::Soft`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::header::ReferenceSequences;
+    /// use noodles_util::alignment::{primer_trimmer::ClipMode, PrimerTrimmer};
+    ///
+    /// let trimmer = PrimerTrimmer::new(&ReferenceSequences::default(), &[])?
+    ///     .set_clip_mode(ClipMode::Hard);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_clip_mode(mut self, clip_mode: ClipMode) -> Self {
+        self.clip_mode = clip_mode;
+        self
+    }
+
+    /// Sets whether the quality scores of soft clipped trimmed bases are set to 0.
+    ///
+    /// This has no effect when hard clipping. By default, this is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::header::ReferenceSequences;
+    /// use noodles_util::alignment::PrimerTrimmer;
+    ///
+    /// let trimmer = PrimerTrimmer::new(&ReferenceSequences::default(), &[])?
+    ///     .set_mask_quality_scores(true);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_mask_quality_scores(mut self, mask_quality_scores: bool) -> Self {
+        self.mask_quality_scores = mask_quality_scores;
+        self
+    }
+
+    /// Trims the ends of an alignment record that overlap primers.
+    ///
+    /// This returns `false` if the entire alignment is within primers, in which case the record
+    /// is left unchanged and is typically discarded. Otherwise, this returns `true`, including
+    /// for records that are unplaced, have no CIGAR operations, or do not overlap any primers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, alignment::Record, header::ReferenceSequence};
+    /// use noodles_util::alignment::{primer_trimmer::ClipMode, PrimerTrimmer};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 34)?)
+    ///     .build();
+    ///
+    /// let primers = ["sq0:8-13".parse()?];
+    /// let trimmer = PrimerTrimmer::new(header.reference_sequences(), &primers)?
+    ///     .set_clip_mode(ClipMode::Hard);
+    ///
+    /// let mut record = Record::builder()
+    ///     .set_reference_sequence_id(0)
+    ///     .set_alignment_start(Position::try_from(5)?)
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_sequence("ACGT".parse()?)
+    ///     .set_quality_scores("NDLS".parse()?)
+    ///     .build();
+    ///
+    /// assert!(trimmer.trim(&mut record));
+    /// assert_eq!(record.cigar().to_string(), "3M1H");
+    /// assert_eq!(record.sequence().to_string(), "ACG");
+    /// assert_eq!(record.quality_scores().to_string(), "NDL");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn trim(&self, record: &mut Record) -> bool {
+        if record.cigar().is_empty() {
+            return true;
+        }
+
+        let (primers, alignment_start) =
+            match (record.reference_sequence_id(), record.alignment_start()) {
+                (Some(id), Some(start)) => match self.primers.get(&id) {
+                    Some(primers) => (primers, start),
+                    None => return true,
+                },
+                _ => return true,
+            };
+
+        let mut alignment = Alignment {
+            start: usize::from(alignment_start),
+            ops: record.cigar().to_vec(),
+            sequence_len: record.sequence().len(),
+            clipped_query_ranges: Vec::new(),
+        };
+
+        let alignment_end = alignment.start + record.cigar().alignment_span() - 1;
+
+        let start_primers_end = primers
+            .iter()
+            .filter(|(start, end)| {
+                (usize::from(*start)..=usize::from(*end)).contains(&alignment.start)
+            })
+            .map(|(_, end)| usize::from(*end))
+            .max();
+
+        if let Some(end) = start_primers_end {
+            let len = end - alignment.start + 1;
+
+            if !alignment.clip(Side::Start, len, self.clip_mode) {
+                return false;
+            }
+        }
+
+        let end_primers_start = primers
+            .iter()
+            .filter(|(start, end)| {
+                (usize::from(*start)..=usize::from(*end)).contains(&alignment_end)
+            })
+            .map(|(start, _)| usize::from(*start))
+            .min();
+
+        if let Some(start) = end_primers_start {
+            let len = alignment_end - start.max(alignment.start) + 1;
+
+            if !alignment.clip(Side::End, len, self.clip_mode) {
+                return false;
+            }
+        }
+
+        alignment.apply(record, self.clip_mode, self.mask_quality_scores);
+
+        true
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Start,
+    End,
+}
+
+struct Alignment {
+    start: usize,
+    ops: Vec<Op>,
+    sequence_len: usize,
+    // (side, start, end) of newly clipped query bases, relative to the side.
+    clipped_query_ranges: Vec<(Side, usize, usize)>,
+}
+
+impl Alignment {
+    // Clips `len` reference positions from the given side of the alignment.
+    //
+    // This returns `false` if no aligned bases remain.
+    fn clip(&mut self, side: Side, len: usize, clip_mode: ClipMode) -> bool {
+        if let Side::End = side {
+            self.ops.reverse();
+        }
+
+        let result = clip_front(&self.ops, len);
+
+        let clip = match result {
+            Some(clip) => clip,
+            None => return false,
+        };
+
+        let mut ops = Vec::with_capacity(clip.ops.len() + 2);
+
+        match clip_mode {
+            ClipMode::Soft => {
+                push_op(&mut ops, Kind::HardClip, clip.hard_clip_len);
+                push_op(
+                    &mut ops,
+                    Kind::SoftClip,
+                    clip.soft_clip_len + clip.query_len,
+                );
+            }
+            ClipMode::Hard => {
+                let len = clip.hard_clip_len + clip.soft_clip_len + clip.query_len;
+                push_op(&mut ops, Kind::HardClip, len);
+                self.sequence_len -= clip.soft_clip_len + clip.query_len;
+            }
+        }
+
+        ops.extend(clip.ops);
+
+        self.clipped_query_ranges.push((
+            side,
+            clip.soft_clip_len,
+            clip.soft_clip_len + clip.query_len,
+        ));
+
+        if let Side::End = side {
+            ops.reverse();
+        } else {
+            self.start += clip.reference_len;
+        }
+
+        self.ops = ops;
+
+        true
+    }
+
+    fn apply(self, record: &mut Record, clip_mode: ClipMode, mask_quality_scores: bool) {
+        // Clipped query ranges are applied in reverse, so that the positions of earlier ranges
+        // are still valid.
+        for (side, start, end) in self.clipped_query_ranges.into_iter().rev() {
+            match clip_mode {
+                ClipMode::Soft => {
+                    if mask_quality_scores {
+                        let scores = record.quality_scores_mut().as_mut();
+                        let len = scores.len();
+
+                        let range = match side {
+                            Side::Start => start.min(len)..end.min(len),
+                            Side::End => len.saturating_sub(end)..len.saturating_sub(start),
+                        };
+
+                        for score in &mut scores[range] {
+                            *score = Score::default();
+                        }
+                    }
+                }
+                ClipMode::Hard => {
+                    remove_query_range(record.sequence_mut().as_mut(), side, end);
+                    remove_query_range(record.quality_scores_mut().as_mut(), side, end);
+                }
+            }
+        }
+
+        *record.alignment_start_mut() = Position::new(self.start);
+        *record.cigar_mut() = Cigar::try_from(self.ops).expect("invalid cigar");
+    }
+}
+
+// Removes `len` elements from the given side.
+fn remove_query_range<T>(buf: &mut Vec<T>, side: Side, len: usize) {
+    let len = len.min(buf.len());
+
+    match side {
+        Side::Start => {
+            buf.drain(..len);
+        }
+        Side::End => buf.truncate(buf.len() - len),
+    }
+}
+
+fn push_op(ops: &mut Vec<Op>, kind: Kind, len: usize) {
+    if len > 0 {
+        ops.push(Op::new(kind, len));
+    }
+}
+
+struct Clip {
+    hard_clip_len: usize,
+    soft_clip_len: usize,
+    query_len: usize,
+    reference_len: usize,
+    ops: Vec<Op>,
+}
+
+// Clips `len` reference positions from the front of the CIGAR operations.
+//
+// Leading insertions, deletions, skips, and pads that remain after clipping are also clipped.
+// This returns `None` if no aligned bases remain.
+fn clip_front(ops: &[Op], mut len: usize) -> Option<Clip> {
+    let mut hard_clip_len = 0;
+    let mut soft_clip_len = 0;
+    let mut i = 0;
+
+    while let Some(op) = ops.get(i) {
+        match op.kind() {
+            Kind::HardClip => hard_clip_len += op.len(),
+            Kind::SoftClip => soft_clip_len += op.len(),
+            _ => break,
+        }
+
+        i += 1;
+    }
+
+    let mut query_len = 0;
+    let mut reference_len = 0;
+    let mut remaining_ops: Vec<_> = ops[i..].iter().rev().copied().collect();
+
+    while let Some(op) = remaining_ops.pop() {
+        let kind = op.kind();
+
+        let is_aligned = matches!(
+            kind,
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch
+        );
+
+        if len == 0 && is_aligned {
+            remaining_ops.push(op);
+            break;
+        }
+
+        match kind {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let n = op.len().min(len);
+                query_len += n;
+                reference_len += n;
+                len -= n;
+
+                if op.len() > n {
+                    remaining_ops.push(Op::new(kind, op.len() - n));
+                }
+            }
+            Kind::Deletion | Kind::Skip => {
+                reference_len += op.len();
+                len = len.saturating_sub(op.len());
+            }
+            Kind::Insertion => query_len += op.len(),
+            Kind::Pad => {}
+            Kind::SoftClip | Kind::HardClip => return None,
+        }
+    }
+
+    if remaining_ops.is_empty() {
+        return None;
+    }
+
+    remaining_ops.reverse();
+
+    Some(Clip {
+        hard_clip_len,
+        soft_clip_len,
+        query_len,
+        reference_len,
+        ops: remaining_ops,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{self as sam, header::ReferenceSequence};
+
+    use super::*;
+
+    fn build_trimmer(primers: &[&str]) -> Result<PrimerTrimmer, Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 34)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 34)?)
+            .build();
+
+        let primers: Vec<Region> = primers
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+
+        PrimerTrimmer::new(header.reference_sequences(), &primers).map_err(|e| e.into())
+    }
+
+    fn build_record(
+        alignment_start: usize,
+        cigar: &str,
+        sequence: &str,
+        quality_scores: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Ok(Record::builder()
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(cigar.parse()?)
+            .set_sequence(sequence.parse()?)
+            .set_quality_scores(quality_scores.parse()?)
+            .build())
+    }
+
+    #[test]
+    fn test_new() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(matches!(
+            build_trimmer(&["sq2:1-8"]),
+            Err(e) if e.downcast_ref::<io::Error>().map(|e| e.kind()) == Some(io::ErrorKind::InvalidInput)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_with_soft_clips() -> Result<(), Box<dyn std::error::Error>> {
+        let trimmer = build_trimmer(&["sq0:1-4", "sq0:3-6", "sq0:13-16", "sq1:1-34"])?;
+
+        let mut record = build_record(3, "1S10M", "NACGTACGTAC", "ABCDEFGHIJK")?;
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record.alignment_start(), Some(Position::try_from(7)?));
+        assert_eq!(record.cigar().to_string(), "5S6M");
+        assert_eq!(record.sequence().to_string(), "NACGTACGTAC");
+        assert_eq!(record.quality_scores().to_string(), "ABCDEFGHIJK");
+
+        let mut record = build_record(8, "2M1D6M2S", "ACGTACGTNN", "ABCDEFGHIJ")?;
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record.alignment_start(), Some(Position::try_from(8)?));
+        assert_eq!(record.cigar().to_string(), "2M1D2M6S");
+
+        let mut record = build_record(3, "2M2I6M", "ACGTACGTAC", "ABCDEFGHIJ")?;
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record.alignment_start(), Some(Position::try_from(7)?));
+        assert_eq!(record.cigar().to_string(), "6S4M");
+
+        let mut record = build_record(3, "3M", "ACG", "ABC")?;
+        let expected = record.clone();
+        assert!(!trimmer.trim(&mut record));
+        assert_eq!(record, expected);
+
+        let mut record = build_record(20, "4M", "ACGT", "ABCD")?;
+        let expected = record.clone();
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_with_hard_clips() -> Result<(), Box<dyn std::error::Error>> {
+        let trimmer = build_trimmer(&["sq0:1-4", "sq0:13-16"])?.set_clip_mode(ClipMode::Hard);
+
+        let mut record = build_record(3, "1H1S12M", "NACGTACGTACGT", "ABCDEFGHIJKLM")?;
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record.alignment_start(), Some(Position::try_from(5)?));
+        assert_eq!(record.cigar().to_string(), "4H8M2H");
+        assert_eq!(record.sequence().to_string(), "GTACGTAC");
+        assert_eq!(record.quality_scores().to_string(), "DEFGHIJK");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_with_masked_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let trimmer = build_trimmer(&["sq0:1-4", "sq0:13-16"])?.set_mask_quality_scores(true);
+
+        let mut record = build_record(3, "1S12M", "NACGTACGTACGT", "ABCDEFGHIJKLM")?;
+        assert!(trimmer.trim(&mut record));
+        assert_eq!(record.alignment_start(), Some(Position::try_from(5)?));
+        assert_eq!(record.cigar().to_string(), "3S8M2S");
+        assert_eq!(record.quality_scores().to_string(), "A!!DEFGHIJK!!");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use noodles_sam::{
+    self as sam,
+    alignment::Record,
+    header::{ReadGroup, ReadGroups},
+    record::data::{
+        field::{Tag, Value},
+        Field,
+    },
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Inner {
+    Mapping(HashMap<String, String>),
+    Single(Box<ReadGroup>),
+}
+
+/// An alignment read group reassigner.
+///
+/// This rewrites the read group (`RG`) of alignment records and the read group records (`@RG`)
+/// of the associated SAM header consistently. Read groups are either renamed by a mapping of
+/// old to new read group IDs or replaced by a single read group.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::Record,
+///     header::ReadGroup,
+///     record::data::field::{Tag, Value},
+/// };
+/// use noodles_util::alignment::ReadGroupReassigner;
+///
+/// let reassigner = ReadGroupReassigner::from_mapping([("rg0", "sample0.rg0")]);
+///
+/// let mut header = sam::Header::builder()
+///     .add_read_group(ReadGroup::new("rg0"))
+///     .build();
+/// reassigner.reassign_header(&mut header);
+/// assert!(header.read_groups().contains_key("sample0.rg0"));
+///
+/// let mut record = Record::builder()
+///     .set_data("RG:Z:rg0".parse()?)
+///     .build();
+/// reassigner.reassign_record(&mut record);
+///
+/// assert_eq!(
+///     record.data().get(Tag::ReadGroup).map(|field| field.value()),
+///     Some(&Value::String(String::from("sample0.rg0")))
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadGroupReassigner(Inner);
+
+impl ReadGroupReassigner {
+    /// Creates a read group reassigner that renames read groups.
+    ///
+    /// Each pair is an old read group ID and its new read group ID. Read groups that are not in
+    /// the mapping are unchanged. Multiple old read groups can be mapped to the same new read
+    /// group, which merges them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::ReadGroupReassigner;
+    /// let reassigner = ReadGroupReassigner::from_mapping([("rg0", "rg1")]);
+    /// ```
+    pub fn from_mapping<I, K, V>(mapping: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mapping = mapping
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+
+        Self(Inner::Mapping(mapping))
+    }
+
+    /// Creates a read group reassigner that assigns every record the given read group.
+    ///
+    /// The read group replaces all read group records in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::ReadGroup;
+    /// use noodles_util::alignment::ReadGroupReassigner;
+    /// let reassigner = ReadGroupReassigner::single(ReadGroup::new("rg0"));
+    /// ```
+    pub fn single(read_group: ReadGroup) -> Self {
+        Self(Inner::Single(Box::new(read_group)))
+    }
+
+    /// Reassigns the read group records (`@RG`) of a SAM header.
+    ///
+    /// When a mapping gives multiple read groups the same ID, either by merging them or by renaming
+    /// one to the ID of another, only the first of them in header order is kept, including its
+    /// fields (e.g., sample and library). The fields of the other read groups are discarded, even
+    /// if they differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ReadGroup};
+    /// use noodles_util::alignment::ReadGroupReassigner;
+    ///
+    /// let reassigner = ReadGroupReassigner::single(ReadGroup::new("rg2"));
+    ///
+    /// let mut header = sam::Header::builder()
+    ///     .add_read_group(ReadGroup::new("rg0"))
+    ///     .add_read_group(ReadGroup::new("rg1"))
+    ///     .build();
+    /// reassigner.reassign_header(&mut header);
+    ///
+    /// let read_groups = header.read_groups();
+    /// assert_eq!(read_groups.len(), 1);
+    /// assert!(read_groups.contains_key("rg2"));
+    /// ```
+    pub fn reassign_header(&self, header: &mut sam::Header) {
+        let read_groups = header.read_groups_mut();
+
+        match &self.0 {
+            Inner::Mapping(mapping) => {
+                let mut reassigned_read_groups = ReadGroups::with_capacity(read_groups.len());
+
+                for (id, mut read_group) in read_groups.drain(..) {
+                    let new_id = mapping.get(&id).cloned().unwrap_or(id);
+                    *read_group.id_mut() = new_id.clone();
+                    reassigned_read_groups.entry(new_id).or_insert(read_group);
+                }
+
+                *read_groups = reassigned_read_groups;
+            }
+            Inner::Single(read_group) => {
+                read_groups.clear();
+                read_groups.insert(read_group.id().into(), (**read_group).clone());
+            }
+        }
+    }
+
+    /// Reassigns the read group (`RG`) of an alignment record.
+    ///
+    /// With a mapping, records without a read group or with a read group that is not in the
+    /// mapping are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     alignment::Record,
+    ///     header::ReadGroup,
+    ///     record::data::field::{Tag, Value},
+    /// };
+    /// use noodles_util::alignment::ReadGroupReassigner;
+    ///
+    /// let reassigner = ReadGroupReassigner::single(ReadGroup::new("rg0"));
+    ///
+    /// let mut record = Record::default();
+    /// reassigner.reassign_record(&mut record);
+    ///
+    /// assert_eq!(
+    ///     record.data().get(Tag::ReadGroup).map(|field| field.value()),
+    ///     Some(&Value::String(String::from("rg0")))
+    /// );
+    /// ```
+    pub fn reassign_record(&self, record: &mut Record) {
+        let data = record.data_mut();
+
+        let new_id = match &self.0 {
+            Inner::Mapping(mapping) => {
+                match data
+                    .get(Tag::ReadGroup)
+                    .and_then(|field| field.value().as_str())
+                    .and_then(|id| mapping.get(id))
+                {
+                    Some(new_id) => new_id.clone(),
+                    None => return,
+                }
+            }
+            Inner::Single(read_group) => read_group.id().into(),
+        };
+
+        data.insert(Field::new(Tag::ReadGroup, Value::String(new_id)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_group_id(record: &Record) -> Option<&str> {
+        record
+            .data()
+            .get(Tag::ReadGroup)
+            .and_then(|field| field.value().as_str())
+    }
+
+    #[test]
+    fn test_reassign_header_with_mapping() {
+        let reassigner = ReadGroupReassigner::from_mapping([("rg0", "rg2"), ("rg1", "rg2")]);
+
+        let mut header = sam::Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_read_group(ReadGroup::new("rg1"))
+            .add_read_group(ReadGroup::new("rg3"))
+            .build();
+
+        reassigner.reassign_header(&mut header);
+
+        let read_groups = header.read_groups();
+        assert_eq!(read_groups.len(), 2);
+        assert!(read_groups.keys().eq(["rg2", "rg3"]));
+        assert!(read_groups
+            .iter()
+            .all(|(id, read_group)| read_group.id() == id));
+    }
+
+    #[test]
+    fn test_reassign_header_with_mapping_and_conflicting_read_groups(
+    ) -> Result<(), sam::header::read_group::builder::BuildError> {
+        let reassigner = ReadGroupReassigner::from_mapping([("rg1", "rg0"), ("rg2", "rg0")]);
+
+        let mut header = sam::Header::builder()
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg1")
+                    .set_sample("s1")
+                    .build()?,
+            )
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg0")
+                    .set_sample("s0")
+                    .build()?,
+            )
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg2")
+                    .set_sample("s2")
+                    .build()?,
+            )
+            .build();
+
+        reassigner.reassign_header(&mut header);
+
+        let read_groups = header.read_groups();
+        assert_eq!(read_groups.len(), 1);
+        assert_eq!(
+            read_groups
+                .get("rg0")
+                .and_then(|read_group| read_group.sample()),
+            Some("s1")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reassign_record() -> Result<(), sam::record::data::ParseError> {
+        let reassigner = ReadGroupReassigner::from_mapping([("rg0", "rg2")]);
+
+        let mut record = Record::builder().set_data("RG:Z:rg0".parse()?).build();
+        reassigner.reassign_record(&mut record);
+        assert_eq!(read_group_id(&record), Some("rg2"));
+
+        let mut record = Record::builder().set_data("RG:Z:rg1".parse()?).build();
+        reassigner.reassign_record(&mut record);
+        assert_eq!(read_group_id(&record), Some("rg1"));
+
+        let mut record = Record::default();
+        reassigner.reassign_record(&mut record);
+        assert!(read_group_id(&record).is_none());
+
+        let reassigner = ReadGroupReassigner::single(ReadGroup::new("rg3"));
+
+        let mut record = Record::builder()
+            .set_data("NH:i:1\tRG:Z:rg0".parse()?)
+            .build();
+        reassigner.reassign_record(&mut record);
+        assert_eq!(read_group_id(&record), Some("rg3"));
+        assert_eq!(record.data().len(), 2);
+
+        let mut record = Record::default();
+        reassigner.reassign_record(&mut record);
+        assert_eq!(read_group_id(&record), Some("rg3"));
+
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
 mod builder;
+mod query_regions;
 
 pub use self::builder::Builder;
 
 use std::io::{self, BufReader, Read, Seek};
 
+use flate2::read::MultiGzDecoder;
 use noodles_bam::{self as bam, bai};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
@@ -12,8 +14,14 @@ use noodles_csi as csi;
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, alignment::Record, AlignmentReader};
 
+use self::query_regions::QueryRegions;
+use super::{Compression, Filter, Format};
+use crate::sniff;
+
 enum Inner<R> {
-    Sam(sam::Reader<BufReader<R>>),
+    Sam(sam::Reader<R>),
+    SamGzip(sam::Reader<BufReader<MultiGzDecoder<R>>>),
+    SamBgzf(sam::Reader<bgzf::Reader<R>>),
     Bam(bam::Reader<bgzf::Reader<R>>),
     Cram(cram::Reader<R>),
 }
@@ -26,7 +34,7 @@ enum Index {
 
 /// An alignment reader.
 pub struct Reader<R> {
-    inner: Inner<R>,
+    inner: Inner<BufReader<sniff::Reader<R>>>,
     reference_sequence_repository: fasta::Repository,
     index: Option<Index>,
 }
@@ -48,8 +56,46 @@ impl Reader<()> {
 
 impl<R> Reader<R>
 where
-    R: Read + Seek,
+    R: Read,
 {
+    /// Returns the format of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::{self, Format};
+    /// let reader = alignment::Reader::builder().build_from_reader(&b"@HD\tVN:1.6\n"[..])?;
+    /// assert_eq!(reader.format(), Format::Sam);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn format(&self) -> Format {
+        match self.inner {
+            Inner::Sam(_) | Inner::SamGzip(_) | Inner::SamBgzf(_) => Format::Sam,
+            Inner::Bam(_) => Format::Bam,
+            Inner::Cram(_) => Format::Cram,
+        }
+    }
+
+    /// Returns the compression method of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment;
+    /// let reader = alignment::Reader::builder().build_from_reader(&b"@HD\tVN:1.6\n"[..])?;
+    /// assert!(reader.compression().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn compression(&self) -> Option<Compression> {
+        match self.inner {
+            Inner::Sam(_) | Inner::Cram(_) => None,
+            Inner::SamGzip(_) => Some(Compression::Gzip),
+            Inner::SamBgzf(_) | Inner::Bam(_) => Some(Compression::Bgzf),
+        }
+    }
+
     /// Reads and parses an alignment header.
     ///
     /// # Examples
@@ -76,6 +122,8 @@ where
     pub fn read_header(&mut self) -> io::Result<sam::Header> {
         match &mut self.inner {
             Inner::Sam(inner) => inner.read_alignment_header(),
+            Inner::SamGzip(inner) => inner.read_alignment_header(),
+            Inner::SamBgzf(inner) => inner.read_alignment_header(),
             Inner::Bam(inner) => inner.read_alignment_header(),
             Inner::Cram(inner) => inner.read_alignment_header(),
         }
@@ -111,6 +159,12 @@ where
             Inner::Sam(inner) => {
                 inner.alignment_records(&self.reference_sequence_repository, header)
             }
+            Inner::SamGzip(inner) => {
+                inner.alignment_records(&self.reference_sequence_repository, header)
+            }
+            Inner::SamBgzf(inner) => {
+                inner.alignment_records(&self.reference_sequence_repository, header)
+            }
             Inner::Bam(inner) => {
                 inner.alignment_records(&self.reference_sequence_repository, header)
             }
@@ -119,37 +173,128 @@ where
             }
         }
     }
+}
 
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
     /// Returns an iterator over records that intersect the given region.
+    ///
+    /// This uses the index that was loaded when the reader was built (see
+    /// [`Builder::build_from_path`]): a BAM index (`.bai`) or coordinate-sorted index (`.csi`)
+    /// for BAM, a coordinate-sorted index for BGZF-compressed SAM, and a CRAM index (`.crai`) for
+    /// CRAM. Uncompressed and gzip-compressed SAM cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::Reader::builder().build_from_path("sample.bam")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query(&header, &region)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn query<'a>(
         &'a mut self,
         header: &'a sam::Header,
         region: &'a Region,
     ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'a> {
+        if let Inner::Sam(_) | Inner::SamGzip(_) = self.inner {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "uncompressed or gzip-compressed SAM cannot be queried",
+            ));
+        }
+
         let index = self.index.as_ref().ok_or_else(|| {
             io::Error::new(io::ErrorKind::InvalidInput, "cannot query without an index")
         })?;
 
-        let iter: Box<dyn Iterator<Item = _>> = match &mut self.inner {
-            Inner::Bam(inner) => match index {
-                Index::Bai(bai) => {
-                    Box::new(inner.query(header.reference_sequences(), bai, region)?)
-                }
-                _ => todo!(),
-            },
-            Inner::Cram(inner) => match index {
-                Index::Crai(crai) => Box::new(
-                    inner
-                        .query(&self.reference_sequence_repository, header, crai, region)?
-                        .map(|result| {
-                            result.and_then(|record| record.try_into_alignment_record(header))
-                        }),
-                ),
-                _ => todo!(),
-            },
-            _ => todo!(),
+        let iter: Box<dyn Iterator<Item = _>> = match (&mut self.inner, index) {
+            (Inner::SamBgzf(inner), Index::Csi(csi)) => Box::new(inner.query(header, csi, region)?),
+            (Inner::Bam(inner), Index::Bai(bai)) => {
+                Box::new(inner.query(header.reference_sequences(), bai, region)?)
+            }
+            (Inner::Bam(inner), Index::Csi(csi)) => {
+                Box::new(inner.query(header.reference_sequences(), csi, region)?)
+            }
+            (Inner::Cram(inner), Index::Crai(crai)) => Box::new(
+                inner
+                    .query(&self.reference_sequence_repository, header, crai, region)?
+                    .map(|result| {
+                        result.and_then(|record| record.try_into_alignment_record(header))
+                    }),
+            ),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "index does not match the input format",
+                ))
+            }
         };
 
         Ok(iter)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions and pass a
+    /// filter.
+    ///
+    /// The regions are sorted, by reference sequence (in header order) and start position, and
+    /// overlapping or adjacent regions are merged. Each record is returned once, even if it
+    /// intersects more than one region. Records are returned in coordinate order if the input is
+    /// coordinate-sorted.
+    ///
+    /// This uses the index that was loaded when the reader was built (see [`Self::query`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_sam::record::{Flags, MappingQuality};
+    /// use noodles_util::alignment::{self, Filter};
+    ///
+    /// let mut reader = alignment::Reader::builder().build_from_path("sample.bam")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?, "sq1".parse()?];
+    ///
+    /// let filter = Filter::default()
+    ///     .set_excluded_flags(Flags::UNMAPPED | Flags::DUPLICATE)
+    ///     .set_min_mapping_quality(MappingQuality::new(20).unwrap());
+    ///
+    /// for result in reader.query_regions(&header, &regions, filter)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        regions: &[Region],
+        filter: Filter,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'a> {
+        if let Inner::Sam(_) | Inner::SamGzip(_) = self.inner {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "uncompressed or gzip-compressed SAM cannot be queried",
+            ));
+        }
+
+        if self.index.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot query without an index",
+            ));
+        }
+
+        Ok(QueryRegions::new(self, header, regions, filter))
+    }
 }
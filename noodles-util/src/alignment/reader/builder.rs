@@ -1,10 +1,11 @@
 use std::{
     ffi::{OsStr, OsString},
     fs::File,
-    io::{self, BufReader, Read, Seek},
+    io::{self, BufRead, BufReader, Read},
     path::{Path, PathBuf},
 };
 
+use flate2::read::MultiGzDecoder;
 use noodles_bam::{self as bam, bai};
 use noodles_bgzf as bgzf;
 use noodles_cram::{self as cram, crai};
@@ -12,14 +13,18 @@ use noodles_csi as csi;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::Reader;
-use crate::alignment::Format;
+use super::{Index, Inner, Reader};
+use crate::{
+    alignment::{format::detect_format, Compression, Format},
+    compression::{detect_compression, invalid_compression},
+    sniff,
+};
 
 /// An alignment reader builder.
 pub struct Builder {
     format: Option<Format>,
+    compression: Option<Option<Compression>>,
     reference_sequence_repository: fasta::Repository,
-    index_src: Option<PathBuf>,
 }
 
 impl Builder {
@@ -27,13 +32,13 @@ impl Builder {
         Self {
             reference_sequence_repository: fasta::Repository::default(),
             format: None,
-            index_src: None,
+            compression: None,
         }
     }
 
     /// Sets the format of the input.
     ///
-    /// By default, the format is autodetected on [`build`]. This can be used to override it.
+    /// By default, the format is autodetected on build. This can be used to override it.
     ///
     /// # Examples
     ///
@@ -46,6 +51,22 @@ impl Builder {
         self
     }
 
+    /// Sets the compression method of the input.
+    ///
+    /// By default, the compression method is autodetected on build. This can be used to override
+    /// it. `None` is uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, Compression};
+    /// let builder = alignment::Reader::builder().set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
     /// Sets the reference sequence repository.
     ///
     /// # Examples
@@ -69,8 +90,13 @@ impl Builder {
 
     /// Builds an alignment reader from a path.
     ///
-    /// By default, the format will be autodetected. This can be overridden by using
-    /// [`set_format`]. An associated index will also attempt to be loaded.
+    /// By default, the format and compression method will be autodetected. This can be
+    /// overridden by using [`Self::set_format`] and [`Self::set_compression`], respectively.
+    ///
+    /// An associated index for the format will also attempt to be loaded, which is used by
+    /// [`Reader::query`]. For BAM, this is `<src>.bai`, `<src>.csi`, or the same paths with the
+    /// `.bam` extension replaced; for BGZF-compressed SAM, `<src>.csi`; and for CRAM,
+    /// `<src>.crai` or the same path with the `.cram` extension replaced.
     ///
     /// # Examples
     ///
@@ -80,19 +106,27 @@ impl Builder {
     /// let reader = alignment::Reader::builder().build_from_path("sample.bam")?;
     /// # Ok::<_, io::Error>(())
     /// ```
-    pub fn build_from_path<P>(mut self, path: P) -> io::Result<Reader<File>>
+    pub fn build_from_path<P>(self, path: P) -> io::Result<Reader<File>>
     where
         P: AsRef<Path>,
     {
-        self.index_src = find_index_src(&path);
-        let file = File::open(path)?;
-        self.build_from_reader(file)
+        let src = path.as_ref();
+        let file = File::open(src)?;
+        let mut reader = self.build_from_reader(file)?;
+
+        if let Some(index_src) = find_index_src(src, reader.format(), reader.compression()) {
+            reader.index = read_index(&index_src)?;
+        }
+
+        Ok(reader)
     }
 
     /// Builds an alignment reader from a reader.
     ///
-    /// By default, the format will be autodetected. This can be overridden by using
-    /// [`set_format`].
+    /// By default, the format and compression method will be autodetected from the content of
+    /// the input. This can be overridden by using [`Self::set_format`] and
+    /// [`Self::set_compression`], respectively. The input does not need to be seekable, e.g., it
+    /// can be stdin.
     ///
     /// # Examples
     ///
@@ -102,88 +136,77 @@ impl Builder {
     /// let reader = alignment::Reader::builder().build_from_reader(io::empty())?;
     /// # Ok::<_, io::Error>(())
     /// ```
-    pub fn build_from_reader<R>(self, mut reader: R) -> io::Result<Reader<R>>
+    pub fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<R>>
     where
-        R: Read + Seek,
+        R: Read,
     {
-        use super::{Index, Inner};
-
-        let format = self
-            .format
-            .map(Ok)
-            .unwrap_or_else(|| detect_format(&mut reader))?;
+        let reader = sniff::Reader::new(reader)?;
 
-        let inner = match format {
-            Format::Sam => Inner::Sam(sam::Reader::new(BufReader::new(reader))),
-            Format::Bam => Inner::Bam(bam::Reader::new(reader)),
-            Format::Cram => Inner::Cram(cram::Reader::new(reader)),
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => detect_compression(reader.prefix()),
         };
 
-        let mut index = None;
+        let reader = BufReader::new(reader);
+
+        let inner = match compression {
+            None => match self
+                .format
+                .unwrap_or_else(|| detect_format(reader.get_ref().prefix(), compression))
+            {
+                Format::Sam => Inner::Sam(sam::Reader::new(reader)),
+                Format::Cram => Inner::Cram(cram::Reader::new(reader)),
+                Format::Bam => return Err(invalid_compression(Format::Bam, compression)),
+            },
+            Some(Compression::Bgzf) => {
+                let mut reader = bgzf::Reader::new(reader);
 
-        if let Some(index_src) = self.index_src {
-            index = match index_src.extension().and_then(|ext| ext.to_str()) {
-                Some("bai") => bai::read(index_src).map(Index::Bai).map(Some)?,
-                Some("crai") => crai::read(index_src).map(Index::Crai).map(Some)?,
-                Some("csi") => csi::read(index_src).map(Index::Csi).map(Some)?,
-                _ => None,
+                match self.format.map(Ok).unwrap_or_else(|| {
+                    reader.fill_buf().map(|src| detect_format(src, compression))
+                })? {
+                    Format::Sam => Inner::SamBgzf(sam::Reader::new(reader)),
+                    Format::Bam => Inner::Bam(bam::Reader::from(reader)),
+                    Format::Cram => return Err(invalid_compression(Format::Cram, compression)),
+                }
             }
-        }
+            Some(Compression::Gzip) => match self.format.unwrap_or(Format::Sam) {
+                Format::Sam => {
+                    let reader = BufReader::new(MultiGzDecoder::new(reader));
+                    Inner::SamGzip(sam::Reader::new(reader))
+                }
+                format => return Err(invalid_compression(format, compression)),
+            },
+        };
 
         Ok(Reader {
             inner,
             reference_sequence_repository: self.reference_sequence_repository,
-            index,
+            index: None,
         })
     }
 }
 
-fn detect_format<R>(reader: &mut R) -> io::Result<Format>
-where
-    R: Read + Seek,
-{
-    const CRAM_MAGIC_NUMBER: [u8; 4] = [b'C', b'R', b'A', b'M'];
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-    const BAM_MAGIC_NUMBER: [u8; 4] = [b'B', b'A', b'M', 0x01];
-
-    let mut buf = [0; 4];
-    reader.read_exact(&mut buf).ok();
-    reader.rewind()?;
-
-    if buf == CRAM_MAGIC_NUMBER {
-        return Ok(Format::Cram);
+fn read_index(src: &Path) -> io::Result<Option<Index>> {
+    match src.extension().and_then(|ext| ext.to_str()) {
+        Some("bai") => bai::read(src).map(Index::Bai).map(Some),
+        Some("crai") => crai::read(src).map(Index::Crai).map(Some),
+        Some("csi") => csi::read(src).map(Index::Csi).map(Some),
+        _ => Ok(None),
     }
-
-    if buf[..2] == GZIP_MAGIC_NUMBER {
-        let mut reader = bgzf::Reader::new(reader);
-        reader.read_exact(&mut buf).ok();
-        reader.get_mut().rewind()?;
-
-        if buf == BAM_MAGIC_NUMBER {
-            return Ok(Format::Bam);
-        }
-    }
-
-    Ok(Format::Sam)
 }
 
-fn find_index_src<P>(src: P) -> Option<PathBuf>
-where
-    P: AsRef<Path>,
-{
-    const EXTENSIONS: [&str; 3] = ["bai", "crai", "csi"];
-
-    let src = src.as_ref();
-
-    for ext in EXTENSIONS {
-        let index_src = push_ext(src.into(), ext);
+fn find_index_src(src: &Path, format: Format, compression: Option<Compression>) -> Option<PathBuf> {
+    let extensions: &[&str] = match (format, compression) {
+        (Format::Sam, Some(Compression::Bgzf)) => &["csi"],
+        (Format::Sam, _) => &[],
+        (Format::Bam, _) => &["bai", "csi"],
+        (Format::Cram, _) => &["crai"],
+    };
 
-        if index_src.exists() {
-            return Some(index_src);
-        }
-    }
-
-    None
+    extensions
+        .iter()
+        .flat_map(|ext| [push_ext(src.into(), ext), src.with_extension(ext)])
+        .find(|index_src| index_src.exists())
 }
 
 fn push_ext<S>(path: PathBuf, ext: S) -> PathBuf
@@ -195,3 +218,63 @@ where
     s.push(ext);
     PathBuf::from(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_build_from_reader() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BAM\x01")?;
+        let data = writer.finish()?;
+        let reader = Builder::new().build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Bam);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@HD\tVN:1.6\n")?;
+        let data = writer.finish()?;
+        let reader = Builder::new().build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Sam);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(b"@HD\tVN:1.6\n")?;
+        let data = encoder.finish()?;
+        let reader = Builder::new().build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Sam);
+        assert_eq!(reader.compression(), Some(Compression::Gzip));
+
+        let reader = Builder::new().build_from_reader(&b"CRAM"[..])?;
+        assert_eq!(reader.format(), Format::Cram);
+        assert!(reader.compression().is_none());
+
+        assert!(matches!(
+            Builder::new().set_format(Format::Bam).build_from_reader(&b"BAM\x01"[..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_short_reads() -> io::Result<()> {
+        use crate::sniff::ByteReader;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BAM\x01")?;
+        let data = writer.finish()?;
+        let reader = Builder::new().build_from_reader(ByteReader(&data[..]))?;
+        assert_eq!(reader.format(), Format::Bam);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let reader = Builder::new().build_from_reader(ByteReader(&b"CRAM"[..]))?;
+        assert_eq!(reader.format(), Format::Cram);
+        assert!(reader.compression().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,501 @@
+use std::{
+    cmp,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    vec,
+};
+
+use noodles_bgzf::VirtualPosition;
+use noodles_core::{region::Interval, Position, Region};
+use noodles_cram as cram;
+use noodles_csi::{index::reference_sequence::bin::Chunk, BinningIndex};
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::{Index, Inner, Reader};
+use crate::{alignment::Filter, sniff};
+
+/// An iterator over records that intersect a list of merged regions.
+///
+/// Records are read one region at a time and streamed from the input, i.e., a region is not
+/// buffered. A record that intersects the previous region on the same reference sequence was
+/// already returned, so it is skipped.
+pub(super) struct QueryRegions<'a, R> {
+    inner: &'a mut Inner<BufReader<sniff::Reader<R>>>,
+    index: Option<&'a Index>,
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+    filter: Filter,
+    regions: vec::IntoIter<Region>,
+    previous_region: Option<Region>,
+    query: Option<RegionQuery>,
+}
+
+// The state of reading records that intersect a single region.
+struct RegionQuery {
+    reference_sequence_id: usize,
+    interval: Interval,
+    previous_interval: Option<Interval>,
+    source: Source,
+}
+
+enum Source {
+    // BAM and bgzipped SAM are read in chunks of virtual positions.
+    Chunks {
+        chunks: vec::IntoIter<Chunk>,
+        chunk_end: Option<VirtualPosition>,
+    },
+    // CRAM is read one data container at a time.
+    Containers {
+        offsets: vec::IntoIter<u64>,
+        records: vec::IntoIter<cram::Record>,
+    },
+}
+
+impl<'a, R> QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut Reader<R>,
+        header: &'a sam::Header,
+        regions: &[Region],
+        filter: Filter,
+    ) -> Self {
+        Self {
+            inner: &mut reader.inner,
+            index: reader.index.as_ref(),
+            reference_sequence_repository: &reader.reference_sequence_repository,
+            header,
+            filter,
+            regions: merge_regions(header, regions).into_iter(),
+            previous_region: None,
+            query: None,
+        }
+    }
+
+    fn query_region(&self, region: &Region) -> io::Result<RegionQuery> {
+        let reference_sequence_id = self
+            .header
+            .reference_sequences()
+            .get_index_of(region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid reference sequence name",
+                )
+            })?;
+
+        let interval = region.interval();
+
+        let previous_interval = self
+            .previous_region
+            .as_ref()
+            .filter(|previous_region| previous_region.name() == region.name())
+            .map(|previous_region| previous_region.interval());
+
+        let source = match (&*self.inner, self.index) {
+            (Inner::SamBgzf(_) | Inner::Bam(_), Some(Index::Csi(index))) => Source::Chunks {
+                chunks: index.query(reference_sequence_id, interval)?.into_iter(),
+                chunk_end: None,
+            },
+            (Inner::Bam(_), Some(Index::Bai(index))) => Source::Chunks {
+                chunks: index.query(reference_sequence_id, interval)?.into_iter(),
+                chunk_end: None,
+            },
+            (Inner::Cram(_), Some(Index::Crai(index))) => {
+                let offsets: Vec<_> = index
+                    .iter()
+                    .filter(|record| record.reference_sequence_id() == Some(reference_sequence_id))
+                    .map(|record| record.offset())
+                    .collect();
+
+                Source::Containers {
+                    offsets: offsets.into_iter(),
+                    records: Vec::new().into_iter(),
+                }
+            }
+            (_, None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "cannot query without an index",
+                ))
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "index does not match the input format",
+                ))
+            }
+        };
+
+        Ok(RegionQuery {
+            reference_sequence_id,
+            interval,
+            previous_interval,
+            source,
+        })
+    }
+}
+
+impl<'a, R> Iterator for QueryRegions<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let query = match self.query.as_mut() {
+                Some(query) => query,
+                None => {
+                    let region = self.regions.next()?;
+
+                    match self.query_region(&region) {
+                        Ok(query) => self.query = Some(query),
+                        Err(e) => return Some(Err(e)),
+                    }
+
+                    self.previous_region = Some(region);
+
+                    continue;
+                }
+            };
+
+            let result = read_record(
+                self.inner,
+                self.reference_sequence_repository,
+                self.header,
+                &mut query.source,
+            );
+
+            match result {
+                Ok(Some(record)) => {
+                    if !is_in_region(&record, query.reference_sequence_id, query.interval)
+                        || !self.filter.matches(&record)
+                    {
+                        continue;
+                    }
+
+                    if let Some(interval) = query.previous_interval {
+                        if intersects(&record, interval) {
+                            continue;
+                        }
+                    }
+
+                    return Some(Ok(record));
+                }
+                Ok(None) => self.query = None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+// Reads the next candidate record of a region query.
+fn read_record<R>(
+    inner: &mut Inner<BufReader<sniff::Reader<R>>>,
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    source: &mut Source,
+) -> io::Result<Option<Record>>
+where
+    R: Read + Seek,
+{
+    match source {
+        Source::Chunks { chunks, chunk_end } => loop {
+            let end = match *chunk_end {
+                Some(end) => end,
+                None => match chunks.next() {
+                    Some(chunk) => {
+                        seek_chunk(inner, chunk.start())?;
+                        *chunk_end = Some(chunk.end());
+                        chunk.end()
+                    }
+                    None => return Ok(None),
+                },
+            };
+
+            let mut record = Record::default();
+
+            let (n, position) = match inner {
+                Inner::SamBgzf(reader) => {
+                    let n = reader.read_record(header, &mut record)?;
+                    (n, reader.get_ref().virtual_position())
+                }
+                Inner::Bam(reader) => {
+                    let n = reader.read_record(&mut record)?;
+                    (n, reader.virtual_position())
+                }
+                _ => unreachable!(),
+            };
+
+            if n == 0 {
+                *chunk_end = None;
+                continue;
+            }
+
+            if position >= end {
+                *chunk_end = None;
+            }
+
+            return Ok(Some(record));
+        },
+        Source::Containers { offsets, records } => loop {
+            if let Some(record) = records.next() {
+                return record.try_into_alignment_record(header).map(Some);
+            }
+
+            let offset = match offsets.next() {
+                Some(offset) => offset,
+                None => return Ok(None),
+            };
+
+            let reader = match inner {
+                Inner::Cram(reader) => reader,
+                _ => unreachable!(),
+            };
+
+            reader.seek(SeekFrom::Start(offset))?;
+
+            let container = match reader.read_data_container()? {
+                Some(container) => container,
+                None => return Ok(None),
+            };
+
+            let compression_header = container.compression_header();
+            let mut container_records = Vec::new();
+
+            for slice in container.slices() {
+                let mut slice_records = slice.records(compression_header)?;
+
+                slice.resolve_records(
+                    reference_sequence_repository,
+                    header,
+                    compression_header,
+                    &mut slice_records,
+                )?;
+
+                container_records.extend(slice_records);
+            }
+
+            *records = container_records.into_iter();
+        },
+    }
+}
+
+fn seek_chunk<R>(
+    inner: &mut Inner<BufReader<sniff::Reader<R>>>,
+    pos: VirtualPosition,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    match inner {
+        Inner::SamBgzf(reader) => reader.seek(pos).map(|_| ()),
+        Inner::Bam(reader) => reader.seek(pos).map(|_| ()),
+        _ => unreachable!(),
+    }
+}
+
+// Sorts regions by reference sequence, in header order, and start position and merges regions
+// that overlap or are adjacent. An unbounded end (`None`) remains unbounded.
+fn merge_regions(header: &sam::Header, regions: &[Region]) -> Vec<Region> {
+    let reference_sequences = header.reference_sequences();
+
+    let mut intervals: Vec<_> = regions
+        .iter()
+        .map(|region| {
+            let i = reference_sequences
+                .get_index_of(region.name())
+                .unwrap_or(usize::MAX);
+
+            let interval = region.interval();
+            let start = interval.start().unwrap_or(Position::MIN);
+
+            (i, region.name(), start, interval.end())
+        })
+        .collect();
+
+    intervals.sort_unstable_by_key(|(i, name, start, _)| (*i, *name, *start));
+
+    let mut merged_intervals: Vec<(&str, Position, Option<Position>)> = Vec::new();
+
+    for (_, name, start, end) in intervals {
+        if let Some((last_name, _, last_end)) = merged_intervals.last_mut() {
+            if *last_name == name {
+                match *last_end {
+                    None => continue,
+                    Some(e) if usize::from(start) <= usize::from(e).saturating_add(1) => {
+                        *last_end = end.map(|end| cmp::max(e, end));
+                        continue;
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        merged_intervals.push((name, start, end));
+    }
+
+    merged_intervals
+        .into_iter()
+        .map(|(name, start, end)| match end {
+            Some(end) => Region::new(name, start..=end),
+            None => Region::new(name, start..),
+        })
+        .collect()
+}
+
+// Returns whether a record is on the given reference sequence and its alignment span intersects
+// the given interval. This matches the semantics of the format-specific queries.
+fn is_in_region(record: &Record, reference_sequence_id: usize, interval: Interval) -> bool {
+    match (
+        record.reference_sequence_id(),
+        record.alignment_start(),
+        record.alignment_end(),
+    ) {
+        (Some(id), Some(start), Some(end)) => {
+            id == reference_sequence_id && interval.intersects(Interval::from(start..=end))
+        }
+        _ => false,
+    }
+}
+
+fn intersects(record: &Record, interval: Interval) -> bool {
+    match record.alignment_start() {
+        Some(start) => {
+            // An alignment with an empty span, e.g., a placed unmapped record, is treated as a
+            // single position.
+            let end = record
+                .alignment_end()
+                .map(|end| cmp::max(start, end))
+                .unwrap_or(start);
+
+            interval.intersects(Interval::from(start..=end))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_bam::{self as bam, bai};
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+    use noodles_sam::record::{Flags, MappingQuality};
+
+    use super::*;
+    use crate::alignment::reader::Index;
+
+    #[test]
+    fn test_merge_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@SQ\tSN:sq0\tLN:100\n@SQ\tSN:sq1\tLN:100\n".parse()?;
+
+        let regions = [
+            "sq1:5-8".parse()?,
+            "sq0:21-34".parse()?,
+            "sq0:8-13".parse()?,
+            "sq0:14-21".parse()?,
+            "sq1".parse()?,
+            "sq0:55-89".parse()?,
+        ];
+
+        let actual: Vec<_> = merge_regions(&header, &regions)
+            .iter()
+            .map(|region| {
+                let interval = region.interval();
+                (region.name().to_string(), interval.start(), interval.end())
+            })
+            .collect();
+
+        let expected = [
+            (String::from("sq0"), Position::new(8), Position::new(34)),
+            (String::from("sq0"), Position::new(55), Position::new(89)),
+            (String::from("sq1"), Some(Position::MIN), None),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header =
+            "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:100\n@SQ\tSN:sq1\tLN:100\n".parse()?;
+
+        let build_record = |name: &str,
+                            flags: Flags,
+                            reference_sequence_id: usize,
+                            start: usize,
+                            cigar: &str,
+                            mapping_quality: u8|
+         -> Result<Record, Box<dyn std::error::Error>> {
+            Ok(Record::builder()
+                .set_read_name(name.parse()?)
+                .set_flags(flags)
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar(cigar.parse()?)
+                .set_mapping_quality(MappingQuality::new(mapping_quality).unwrap())
+                .build())
+        };
+
+        let records = [
+            build_record("r0", Flags::empty(), 0, 10, "10M", 60)?,
+            build_record("r1", Flags::empty(), 0, 15, "30M", 60)?,
+            build_record("r2", Flags::empty(), 0, 50, "5M", 60)?,
+            build_record("r3", Flags::empty(), 0, 60, "5M", 5)?,
+            build_record("r4", Flags::DUPLICATE, 1, 1, "4M", 60)?,
+            build_record("r5", Flags::empty(), 1, 8, "4M", 60)?,
+        ];
+
+        let mut writer = bam::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        let mut builder = bai::Index::builder();
+
+        for record in &records {
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_record(&header, record)?;
+            writer.get_mut().flush_block()?;
+            let end_position = writer.get_ref().virtual_position();
+            builder.add_record(record, Chunk::new(start_position, end_position))?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+        let index = builder.build(header.reference_sequences().len());
+
+        let mut reader = Reader::builder().build_from_reader(Cursor::new(data))?;
+        reader.index = Some(Index::Bai(index));
+        reader.read_header()?;
+
+        let regions = [
+            "sq0:40-65".parse()?,
+            "sq0:10-12".parse()?,
+            "sq1".parse()?,
+            "sq0:18-20".parse()?,
+        ];
+
+        let filter = Filter::default()
+            .set_excluded_flags(Flags::DUPLICATE)
+            .set_min_mapping_quality(MappingQuality::new(10).unwrap());
+
+        let names = reader
+            .query_regions(&header, &regions, filter)?
+            .map(|result| result.map(|record| record.read_name().map(|name| name.to_string())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let expected = ["r0", "r1", "r2", "r5"]
+            .iter()
+            .map(|name| Some(name.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, expected);
+
+        Ok(())
+    }
+}
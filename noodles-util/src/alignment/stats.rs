@@ -0,0 +1,652 @@
+//! Alignment record statistics.
+
+use std::{collections::BTreeMap, fmt, io};
+
+use noodles_sam::{
+    self as sam,
+    alignment::Record,
+    record::{mapping_quality, sequence::Base, Flags},
+};
+
+const MAX_GC_CONTENT: usize = 100;
+
+/// Mapped and unmapped record counts of a reference sequence.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequenceCounts {
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequenceCounts {
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records, i.e., placed unmapped records.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct QualityScoreSum {
+    sum: u64,
+    count: u64,
+}
+
+/// Alignment record statistics.
+///
+/// Statistics are accumulated one record at a time (see [`Self::add_record`]) and include
+///
+///   * an insert size distribution,
+///   * a mapping quality histogram,
+///   * the mean base quality score per cycle,
+///   * a GC content distribution, and
+///   * mapped and unmapped record counts per reference sequence.
+///
+/// The statistics can be written as a tab-delimited report using [`Self::report`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::alignment::{self, stats::Stats};
+///
+/// let data = b"@HD\tVN:1.6\n*\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\n";
+///
+/// let mut reader = alignment::Reader::builder().build_from_reader(&data[..])?;
+/// let header = reader.read_header()?;
+///
+/// let stats = Stats::from_records(reader.records(&header))?;
+/// assert_eq!(stats.record_count(), 1);
+/// assert_eq!(stats.unplaced_unmapped_record_count(), 1);
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    record_count: u64,
+    insert_sizes: BTreeMap<u32, u64>,
+    mapping_qualities: Vec<u64>,
+    quality_scores_by_cycle: Vec<QualityScoreSum>,
+    gc_contents: Vec<u64>,
+    reference_sequence_counts: Vec<ReferenceSequenceCounts>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl Stats {
+    /// Accumulates statistics from an iterator of records.
+    ///
+    /// This stops at and returns the first error, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::alignment::Record;
+    /// use noodles_util::alignment::stats::Stats;
+    ///
+    /// let records = vec![Ok(Record::default()), Ok(Record::default())];
+    /// let stats = Stats::from_records(records)?;
+    /// assert_eq!(stats.record_count(), 2);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn from_records<I>(records: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = io::Result<Record>>,
+    {
+        let mut stats = Self::default();
+
+        for result in records {
+            let record = result?;
+            stats.add_record(&record);
+        }
+
+        Ok(stats)
+    }
+
+    /// Adds a record to the statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::Record;
+    /// use noodles_util::alignment::stats::Stats;
+    ///
+    /// let mut stats = Stats::default();
+    /// stats.add_record(&Record::default());
+    /// assert_eq!(stats.record_count(), 1);
+    /// ```
+    pub fn add_record(&mut self, record: &Record) {
+        let flags = record.flags();
+
+        self.record_count += 1;
+
+        self.add_reference_sequence_counts(record);
+
+        if flags.is_secondary() || flags.is_supplementary() {
+            return;
+        }
+
+        if !flags.is_unmapped() {
+            let mapping_quality = record
+                .mapping_quality()
+                .map(u8::from)
+                .unwrap_or(mapping_quality::MISSING);
+
+            self.mapping_qualities[usize::from(mapping_quality)] += 1;
+        }
+
+        if is_leftmost_mapped_pair_segment(flags, record.template_length()) {
+            let insert_size = record.template_length().unsigned_abs();
+            *self.insert_sizes.entry(insert_size).or_insert(0) += 1;
+        }
+
+        self.add_quality_scores(record);
+        self.add_gc_content(record);
+    }
+
+    /// Returns the number of records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert_eq!(stats.record_count(), 0);
+    /// ```
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the insert size distribution.
+    ///
+    /// This maps insert sizes to the number of pairs with that size. Only primary records that are
+    /// the leftmost segment of a pair with both segments mapped are counted, i.e., each pair is
+    /// counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert!(stats.insert_sizes().is_empty());
+    /// ```
+    pub fn insert_sizes(&self) -> &BTreeMap<u32, u64> {
+        &self.insert_sizes
+    }
+
+    /// Returns the mapping quality histogram.
+    ///
+    /// The histogram is indexed by the raw mapping quality, where 255 is a missing mapping
+    /// quality. Only primary mapped records are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert_eq!(stats.mapping_qualities().len(), 256);
+    /// ```
+    pub fn mapping_qualities(&self) -> &[u64] {
+        &self.mapping_qualities
+    }
+
+    /// Returns an iterator over the mean base quality score of each cycle.
+    ///
+    /// A cycle is the 0-based position of a base in the read in sequencing order, i.e., the
+    /// quality scores of reverse complemented records are reversed. Only primary records are
+    /// counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert_eq!(stats.mean_quality_scores_by_cycle().count(), 0);
+    /// ```
+    pub fn mean_quality_scores_by_cycle(&self) -> impl Iterator<Item = f64> + '_ {
+        self.quality_scores_by_cycle
+            .iter()
+            .map(|QualityScoreSum { sum, count }| *sum as f64 / *count as f64)
+    }
+
+    /// Returns the GC content distribution.
+    ///
+    /// This is indexed by the GC content of the sequence, as a rounded percentage (0-100) of the
+    /// number of G and C bases in all A, C, G, and T bases. Only primary records with at least
+    /// one A, C, G, or T base are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert_eq!(stats.gc_contents().len(), 101);
+    /// ```
+    pub fn gc_contents(&self) -> &[u64] {
+        &self.gc_contents
+    }
+
+    /// Returns the record counts of each reference sequence.
+    ///
+    /// This is indexed by reference sequence ID. Reference sequences after the last one with a
+    /// record may be missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert!(stats.reference_sequence_counts().is_empty());
+    /// ```
+    pub fn reference_sequence_counts(&self) -> &[ReferenceSequenceCounts] {
+        &self.reference_sequence_counts
+    }
+
+    /// Returns the number of unplaced unmapped records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let stats = Stats::default();
+    /// assert_eq!(stats.unplaced_unmapped_record_count(), 0);
+    /// ```
+    pub fn unplaced_unmapped_record_count(&self) -> u64 {
+        self.unplaced_unmapped_record_count
+    }
+
+    /// Returns a report of the statistics.
+    ///
+    /// The header is used to name the reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::stats::Stats;
+    ///
+    /// let header = sam::Header::default();
+    /// let stats = Stats::default();
+    ///
+    /// let report = stats.report(&header).to_string();
+    /// assert!(report.starts_with("SN\trecords\t0\n"));
+    /// ```
+    pub fn report<'a>(&'a self, header: &'a sam::Header) -> Report<'a> {
+        Report {
+            stats: self,
+            header,
+        }
+    }
+
+    fn add_reference_sequence_counts(&mut self, record: &Record) {
+        let reference_sequence_id = match record.reference_sequence_id() {
+            Some(id) => id,
+            None => {
+                self.unplaced_unmapped_record_count += 1;
+                return;
+            }
+        };
+
+        if reference_sequence_id >= self.reference_sequence_counts.len() {
+            self.reference_sequence_counts.resize(
+                reference_sequence_id + 1,
+                ReferenceSequenceCounts::default(),
+            );
+        }
+
+        let counts = &mut self.reference_sequence_counts[reference_sequence_id];
+
+        if record.flags().is_unmapped() {
+            counts.unmapped_record_count += 1;
+        } else {
+            counts.mapped_record_count += 1;
+        }
+    }
+
+    fn add_quality_scores(&mut self, record: &Record) {
+        let scores = record.quality_scores().as_ref();
+
+        if scores.len() > self.quality_scores_by_cycle.len() {
+            self.quality_scores_by_cycle
+                .resize(scores.len(), QualityScoreSum::default());
+        }
+
+        let is_reverse_complemented = record.flags().is_reverse_complemented();
+
+        for (i, &score) in scores.iter().enumerate() {
+            let cycle = if is_reverse_complemented {
+                scores.len() - 1 - i
+            } else {
+                i
+            };
+
+            let sum = &mut self.quality_scores_by_cycle[cycle];
+            sum.sum += u64::from(u8::from(score));
+            sum.count += 1;
+        }
+    }
+
+    fn add_gc_content(&mut self, record: &Record) {
+        let (mut gc_count, mut acgt_count) = (0, 0);
+
+        for base in record.sequence().as_ref() {
+            match base {
+                Base::C | Base::G => {
+                    gc_count += 1;
+                    acgt_count += 1;
+                }
+                Base::A | Base::T => acgt_count += 1,
+                _ => {}
+            }
+        }
+
+        if let Some(gc_content) =
+            (gc_count * MAX_GC_CONTENT + acgt_count / 2).checked_div(acgt_count)
+        {
+            self.gc_contents[gc_content] += 1;
+        }
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            record_count: 0,
+            insert_sizes: BTreeMap::new(),
+            mapping_qualities: vec![0; usize::from(u8::MAX) + 1],
+            quality_scores_by_cycle: Vec::new(),
+            gc_contents: vec![0; MAX_GC_CONTENT + 1],
+            reference_sequence_counts: Vec::new(),
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+}
+
+fn is_leftmost_mapped_pair_segment(flags: Flags, template_length: i32) -> bool {
+    flags.is_segmented() && !flags.is_unmapped() && !flags.is_mate_unmapped() && template_length > 0
+}
+
+/// An alignment record statistics report.
+///
+/// This is created by calling [`Stats::report`]. It is formatted as tab-delimited lines, where
+/// the first field is the section:
+///
+///   * `SN`: summary numbers (name, value),
+///   * `IS`: insert sizes (insert size, count),
+///   * `MAPQ`: mapping qualities (mapping quality, count),
+///   * `QUAL`: mean quality scores by cycle (1-based cycle, mean quality score),
+///   * `GC`: GC contents (GC content percentage, count), and
+///   * `REF`: reference sequence counts (name, mapped record count, unmapped record count).
+///
+/// Empty bins are omitted.
+///
+/// With the `serde` feature enabled, the report can also be serialized, e.g., as JSON. The
+/// sections are then fields named `records`, `unplaced_unmapped_records`, `insert_sizes`,
+/// `mapping_qualities`, `mean_quality_scores_by_cycle`, `gc_contents`, and
+/// `reference_sequences`.
+pub struct Report<'a> {
+    stats: &'a Stats,
+    header: &'a sam::Header,
+}
+
+impl<'a> fmt::Display for Report<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats = self.stats;
+
+        writeln!(f, "SN\trecords\t{}", stats.record_count)?;
+        writeln!(
+            f,
+            "SN\tunplaced unmapped records\t{}",
+            stats.unplaced_unmapped_record_count
+        )?;
+
+        for (insert_size, count) in &stats.insert_sizes {
+            writeln!(f, "IS\t{}\t{}", insert_size, count)?;
+        }
+
+        for (mapping_quality, count) in stats.mapping_qualities.iter().enumerate() {
+            if *count > 0 {
+                writeln!(f, "MAPQ\t{}\t{}", mapping_quality, count)?;
+            }
+        }
+
+        for (i, mean) in stats.mean_quality_scores_by_cycle().enumerate() {
+            writeln!(f, "QUAL\t{}\t{:.2}", i + 1, mean)?;
+        }
+
+        for (gc_content, count) in stats.gc_contents.iter().enumerate() {
+            if *count > 0 {
+                writeln!(f, "GC\t{}\t{}", gc_content, count)?;
+            }
+        }
+
+        for (i, counts) in stats.reference_sequence_counts.iter().enumerate() {
+            writeln!(
+                f,
+                "REF\t{}\t{}\t{}",
+                self.reference_sequence_name(i),
+                counts.mapped_record_count,
+                counts.unmapped_record_count
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Report<'a> {
+    fn reference_sequence_name(&self, reference_sequence_id: usize) -> &'a str {
+        self.header
+            .reference_sequences()
+            .get_index(reference_sequence_id)
+            .map(|(name, _)| name.as_str())
+            .unwrap_or("*")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Report<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        struct ReferenceSequenceReport<'a> {
+            name: &'a str,
+            mapped_record_count: u64,
+            unmapped_record_count: u64,
+        }
+
+        #[derive(serde::Serialize)]
+        struct RawReport<'a> {
+            records: u64,
+            unplaced_unmapped_records: u64,
+            insert_sizes: &'a BTreeMap<u32, u64>,
+            mapping_qualities: BTreeMap<usize, u64>,
+            mean_quality_scores_by_cycle: Vec<f64>,
+            gc_contents: BTreeMap<usize, u64>,
+            reference_sequences: Vec<ReferenceSequenceReport<'a>>,
+        }
+
+        fn non_empty_bins(bins: &[u64]) -> BTreeMap<usize, u64> {
+            bins.iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(i, count)| (i, *count))
+                .collect()
+        }
+
+        let stats = self.stats;
+
+        let reference_sequences = stats
+            .reference_sequence_counts
+            .iter()
+            .enumerate()
+            .map(|(i, counts)| ReferenceSequenceReport {
+                name: self.reference_sequence_name(i),
+                mapped_record_count: counts.mapped_record_count,
+                unmapped_record_count: counts.unmapped_record_count,
+            })
+            .collect();
+
+        let report = RawReport {
+            records: stats.record_count,
+            unplaced_unmapped_records: stats.unplaced_unmapped_record_count,
+            insert_sizes: &stats.insert_sizes,
+            mapping_qualities: non_empty_bins(&stats.mapping_qualities),
+            mean_quality_scores_by_cycle: stats.mean_quality_scores_by_cycle().collect(),
+            gc_contents: non_empty_bins(&stats.gc_contents),
+            reference_sequences,
+        };
+
+        report.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::record::{MappingQuality, QualityScores, Sequence};
+
+    use super::*;
+
+    fn build_record(
+        flags: Flags,
+        reference_sequence_id: Option<usize>,
+        template_length: i32,
+        sequence: &str,
+        quality_scores: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let mut builder = Record::builder()
+            .set_flags(flags)
+            .set_template_length(template_length)
+            .set_sequence(sequence.parse::<Sequence>()?)
+            .set_quality_scores(quality_scores.parse::<QualityScores>()?);
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder
+                .set_reference_sequence_id(id)
+                .set_alignment_start(Position::MIN)
+                .set_mapping_quality(MappingQuality::new(30).unwrap());
+        }
+
+        Ok(builder.build())
+    }
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut stats = Stats::default();
+
+        let flags = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED;
+        stats.add_record(&build_record(flags, Some(0), 150, "ACGT", "NDLS")?);
+
+        let flags = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::REVERSE_COMPLEMENTED;
+        stats.add_record(&build_record(flags, Some(0), -150, "GGCC", "DDLL")?);
+
+        let flags = Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED;
+        stats.add_record(&build_record(flags, None, 0, "AATT", "NNNN")?);
+
+        let flags = Flags::SECONDARY;
+        stats.add_record(&build_record(flags, Some(1), 0, "ACGT", "NDLS")?);
+
+        assert_eq!(stats.record_count(), 4);
+
+        assert_eq!(
+            stats.insert_sizes().iter().collect::<Vec<_>>(),
+            [(&150, &1)]
+        );
+
+        assert_eq!(stats.mapping_qualities()[30], 2);
+        assert_eq!(stats.mapping_qualities().iter().sum::<u64>(), 2);
+
+        // N = 45, D = 35, L = 43, S = 50
+        let means: Vec<_> = stats.mean_quality_scores_by_cycle().collect();
+        assert_eq!(
+            means,
+            [
+                (45.0 + 43.0 + 45.0) / 3.0,
+                (35.0 + 43.0 + 45.0) / 3.0,
+                (43.0 + 35.0 + 45.0) / 3.0,
+                (50.0 + 35.0 + 45.0) / 3.0,
+            ]
+        );
+
+        assert_eq!(stats.gc_contents()[0], 1);
+        assert_eq!(stats.gc_contents()[50], 1);
+        assert_eq!(stats.gc_contents()[100], 1);
+
+        assert_eq!(
+            stats.reference_sequence_counts(),
+            [
+                ReferenceSequenceCounts {
+                    mapped_record_count: 2,
+                    unmapped_record_count: 0,
+                },
+                ReferenceSequenceCounts {
+                    mapped_record_count: 1,
+                    unmapped_record_count: 0,
+                },
+            ]
+        );
+
+        assert_eq!(stats.unplaced_unmapped_record_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_report() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let mut stats = Stats::default();
+        let flags = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED;
+        stats.add_record(&build_record(flags, Some(0), 8, "ACGT", "NNDD")?);
+
+        let actual = stats.report(&header).to_string();
+        let expected = "\
+SN\trecords\t1
+SN\tunplaced unmapped records\t0
+IS\t8\t1
+MAPQ\t30\t1
+QUAL\t1\t45.00
+QUAL\t2\t45.00
+QUAL\t3\t35.00
+QUAL\t4\t35.00
+GC\t50\t1
+REF\tsq0\t1\t0
+";
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_report() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let mut stats = Stats::default();
+        let flags = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED;
+        stats.add_record(&build_record(flags, Some(0), 8, "ACGT", "NNDD")?);
+
+        let actual = serde_json::to_value(stats.report(&header))?;
+        let expected = serde_json::json!({
+            "records": 1,
+            "unplaced_unmapped_records": 0,
+            "insert_sizes": { "8": 1 },
+            "mapping_qualities": { "30": 1 },
+            "mean_quality_scores_by_cycle": [45.0, 45.0, 35.0, 35.0],
+            "gc_contents": { "50": 1 },
+            "reference_sequences": [
+                { "name": "sq0", "mapped_record_count": 1, "unmapped_record_count": 0 },
+            ],
+        });
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
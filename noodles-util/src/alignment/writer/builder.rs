@@ -1,7 +1,7 @@
 use std::io::Write;
 
 use noodles_bam as bam;
-use noodles_cram as cram;
+use noodles_cram::{self as cram, file_definition::Version};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -13,6 +13,9 @@ pub struct Builder<W> {
     inner: W,
     format: Format,
     reference_sequence_repository: fasta::Repository,
+    cram_version: Version,
+    is_cram_reference_required: bool,
+    embed_cram_reference_sequences: bool,
 }
 
 impl<W> Builder<W>
@@ -24,6 +27,9 @@ where
             inner,
             format: Format::Sam,
             reference_sequence_repository: fasta::Repository::default(),
+            cram_version: Version::default(),
+            is_cram_reference_required: true,
+            embed_cram_reference_sequences: false,
         }
     }
 
@@ -43,6 +49,8 @@ where
 
     /// Sets the reference sequence repository.
     ///
+    /// This is only used when writing CRAM.
+    ///
     /// # Examples
     ///
     /// ```
@@ -63,6 +71,68 @@ where
         self
     }
 
+    /// Sets the CRAM format version.
+    ///
+    /// This is only used when writing CRAM. Only CRAM 3.0 and 3.1 can be written. The default is
+    /// 3.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::file_definition::Version;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let builder = alignment::Writer::builder(io::sink())
+    ///     .set_format(Format::Cram)
+    ///     .set_cram_version(Version::new(3, 1));
+    /// ```
+    pub fn set_cram_version(mut self, version: Version) -> Self {
+        self.cram_version = version;
+        self
+    }
+
+    /// Sets whether a reference sequence is required to decode CRAM records.
+    ///
+    /// If `false`, CRAM is written without using the reference sequence repository (no-ref mode).
+    /// This is only used when writing CRAM. The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let builder = alignment::Writer::builder(io::sink())
+    ///     .set_format(Format::Cram)
+    ///     .set_cram_reference_required(false);
+    /// ```
+    pub fn set_cram_reference_required(mut self, value: bool) -> Self {
+        self.is_cram_reference_required = value;
+        self
+    }
+
+    /// Sets whether to embed reference sequences in CRAM.
+    ///
+    /// If `true`, the regions of the reference sequences that slices span are read from the
+    /// reference sequence repository and stored in the output, so it can be read without the
+    /// reference sequences. This is only used when writing CRAM. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::{self, Format};
+    ///
+    /// let builder = alignment::Writer::builder(io::sink())
+    ///     .set_format(Format::Cram)
+    ///     .set_embed_cram_reference_sequences(true);
+    /// ```
+    pub fn set_embed_cram_reference_sequences(mut self, value: bool) -> Self {
+        self.embed_cram_reference_sequences = value;
+        self
+    }
+
     /// Builds an alignment writer.
     ///
     /// # Examples
@@ -82,6 +152,9 @@ where
             Format::Cram => Box::new(
                 cram::Writer::builder(self.inner)
                     .set_reference_sequence_repository(self.reference_sequence_repository)
+                    .set_version(self.cram_version)
+                    .require_reference_sequences(self.is_cram_reference_required)
+                    .embed_reference_sequences(self.embed_cram_reference_sequences)
                     .build(),
             ),
         };
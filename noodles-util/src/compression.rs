@@ -0,0 +1,73 @@
+use std::{fmt, io};
+
+/// A compression method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Compression {
+    /// BGZF compression.
+    Bgzf,
+    /// gzip compression.
+    ///
+    /// Unlike BGZF, a gzip-compressed stream cannot be queried.
+    Gzip,
+}
+
+// Detects the compression method from the start of a stream, if any.
+//
+// A BGZF block is a gzip member with an extra subfield `BC` (§ 4.1 "The BGZF compression format"
+// (2021-06-03)).
+pub(crate) fn detect_compression(src: &[u8]) -> Option<Compression> {
+    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+    const FEXTRA: u8 = 1 << 2;
+    const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+    if src.get(..2) != Some(&GZIP_MAGIC_NUMBER[..]) {
+        return None;
+    }
+
+    let is_bgzf = src.get(3).map(|flg| flg & FEXTRA != 0).unwrap_or(false)
+        && src.get(12..14) == Some(&BGZF_SUBFIELD_ID[..]);
+
+    if is_bgzf {
+        Some(Compression::Bgzf)
+    } else {
+        Some(Compression::Gzip)
+    }
+}
+
+pub(crate) fn invalid_compression<F>(format: F, compression: Option<Compression>) -> io::Error
+where
+    F: fmt::Debug,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid compression for {:?}: {:?}", format, compression),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_compression() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BAM\x01")?;
+        let data = writer.finish()?;
+        assert_eq!(detect_compression(&data), Some(Compression::Bgzf));
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(b"##fileformat=VCFv4.3\n")?;
+        let data = encoder.finish()?;
+        assert_eq!(detect_compression(&data), Some(Compression::Gzip));
+
+        assert!(detect_compression(b"CRAM").is_none());
+        assert!(detect_compression(b"##fileformat=VCFv4.3\n").is_none());
+        assert!(detect_compression(&[]).is_none());
+
+        Ok(())
+    }
+}
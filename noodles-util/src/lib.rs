@@ -3,3 +3,6 @@
 //! **noodles-util** are utilities for working with noodles.
 
 pub mod alignment;
+mod compression;
+mod sniff;
+pub mod variant;
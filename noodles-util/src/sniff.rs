@@ -0,0 +1,151 @@
+// Reading the start of an input ahead of time to detect its compression method and format.
+//
+// A single read (or `fill_buf`) may return fewer bytes than are needed for detection, e.g., when
+// reading from a pipe. The start of the input is instead read until enough bytes are buffered or
+// EOF is reached and then replayed before the rest of the input.
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+// The number of bytes needed to detect the compression method (a gzip header with a BGZF extra
+// subfield) or the format of an uncompressed input (a magic number).
+pub(crate) const PREFIX_LEN: usize = 16;
+
+// A reader that replays a prefix of its input before reading the rest of it.
+pub(crate) struct Reader<R> {
+    prefix: io::Cursor<Vec<u8>>,
+    inner: R,
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    // Reads the prefix of the input.
+    pub(crate) fn new(mut inner: R) -> io::Result<Self> {
+        let mut prefix = Vec::with_capacity(PREFIX_LEN);
+        (&mut inner)
+            .take(PREFIX_LEN as u64)
+            .read_to_end(&mut prefix)?;
+
+        Ok(Self {
+            prefix: io::Cursor::new(prefix),
+            inner,
+        })
+    }
+
+    // Returns the unread part of the prefix.
+    pub(crate) fn prefix(&self) -> &[u8] {
+        let position = self.prefix.position() as usize;
+        &self.prefix.get_ref()[position..]
+    }
+}
+
+impl<R> Read for Reader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.prefix().is_empty() {
+            self.inner.read(buf)
+        } else {
+            self.prefix.read(buf)
+        }
+    }
+}
+
+impl<R> Seek for Reader<R>
+where
+    R: Read + Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        // The inner reader is positioned after the prefix, so a relative seek is adjusted by the
+        // unread part of the prefix.
+        let remaining = self.prefix().len() as i64;
+        self.prefix = io::Cursor::new(Vec::new());
+
+        match pos {
+            SeekFrom::Current(n) => self.inner.seek(SeekFrom::Current(n - remaining)),
+            _ => self.inner.seek(pos),
+        }
+    }
+}
+
+// An async reader that replays a prefix of its input before reading the rest of it.
+#[cfg(feature = "async")]
+pub(crate) type AsyncReader<R> = tokio::io::Chain<io::Cursor<Vec<u8>>, R>;
+
+// Reads the prefix of an async input.
+//
+// The prefix is replayed in a single read, so the first `fill_buf` of a buffered reader over the
+// returned reader holds the entire prefix.
+#[cfg(feature = "async")]
+pub(crate) async fn read_prefix<R>(mut inner: R) -> tokio::io::Result<AsyncReader<R>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut prefix = Vec::with_capacity(PREFIX_LEN);
+    (&mut inner)
+        .take(PREFIX_LEN as u64)
+        .read_to_end(&mut prefix)
+        .await?;
+
+    Ok(AsyncReadExt::chain(io::Cursor::new(prefix), inner))
+}
+
+// A reader that returns at most one byte per read.
+#[cfg(test)]
+pub(crate) struct ByteReader<R>(pub(crate) R);
+
+#[cfg(test)]
+impl<R> Read for ByteReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() -> io::Result<()> {
+        let data: Vec<u8> = (0..32).collect();
+
+        let mut reader = Reader::new(ByteReader(&data[..]))?;
+        assert_eq!(reader.prefix(), &data[..PREFIX_LEN]);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        let reader = Reader::new(ByteReader(&data[..4]))?;
+        assert_eq!(reader.prefix(), &data[..4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let data: Vec<u8> = (0..32).collect();
+        let mut reader = Reader::new(io::Cursor::new(&data))?;
+
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.stream_position()?, 4);
+
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [4, 5, 6, 7]);
+
+        reader.seek(SeekFrom::Start(2))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [2, 3, 4, 5]);
+
+        Ok(())
+    }
+}
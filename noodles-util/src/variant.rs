@@ -0,0 +1,13 @@
+//! I/O for variant formats.
+
+#[cfg(feature = "async")]
+pub mod r#async;
+mod converter;
+mod format;
+mod reader;
+
+pub use self::{converter::Converter, format::Format, reader::Reader};
+pub use crate::compression::Compression;
+
+#[cfg(feature = "async")]
+pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,201 @@
+//! Async variant reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use futures::{Stream, StreamExt};
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::io::{self, AsyncRead, BufReader};
+
+use crate::{
+    sniff,
+    variant::{Compression, Format},
+};
+
+enum Inner<R>
+where
+    R: AsyncRead,
+{
+    Vcf(vcf::AsyncReader<R>),
+    VcfBgzf(vcf::AsyncReader<bgzf::AsyncReader<R>>),
+    Bcf(bcf::AsyncReader<R>),
+    BcfBgzf(bcf::AsyncReader<bgzf::AsyncReader<R>>),
+}
+
+/// An async variant reader.
+///
+/// This reads VCF and BCF records as VCF records. It is created by calling
+/// [`Builder::build_from_path`] or [`Builder::build_from_reader`].
+pub struct Reader<R>
+where
+    R: AsyncRead,
+{
+    inner: Inner<BufReader<sniff::AsyncReader<R>>>,
+    string_maps: StringMaps,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Returns the format of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant::{self, Format};
+    ///
+    /// let reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_reader(&b"##fileformat=VCFv4.3\n"[..])
+    ///     .await?;
+    ///
+    /// assert_eq!(reader.format(), Format::Vcf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn format(&self) -> Format {
+        match self.inner {
+            Inner::Vcf(_) | Inner::VcfBgzf(_) => Format::Vcf,
+            Inner::Bcf(_) | Inner::BcfBgzf(_) => Format::Bcf,
+        }
+    }
+
+    /// Returns the compression method of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant;
+    ///
+    /// let reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_reader(&b"##fileformat=VCFv4.3\n"[..])
+    ///     .await?;
+    ///
+    /// assert!(reader.compression().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compression(&self) -> Option<Compression> {
+        match self.inner {
+            Inner::Vcf(_) | Inner::Bcf(_) => None,
+            Inner::VcfBgzf(_) | Inner::BcfBgzf(_) => Some(Compression::Bgzf),
+        }
+    }
+
+    /// Reads and parses a VCF header.
+    ///
+    /// For BCF, this also reads the file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// ";
+    ///
+    /// let mut reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_reader(&data[..])
+    ///     .await?;
+    ///
+    /// let header = reader.read_header().await?;
+    /// assert!(header.infos().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_header(&mut self) -> io::Result<vcf::Header> {
+        let raw_header = match &mut self.inner {
+            Inner::Vcf(inner) => inner.read_header().await?,
+            Inner::VcfBgzf(inner) => inner.read_header().await?,
+            Inner::Bcf(inner) => {
+                inner.read_file_format().await?;
+                inner.read_header().await?
+            }
+            Inner::BcfBgzf(inner) => {
+                inner.read_file_format().await?;
+                inner.read_header().await?
+            }
+        };
+
+        if let Inner::Bcf(_) | Inner::BcfBgzf(_) = self.inner {
+            self.string_maps = raw_header
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    ///
+    /// BCF records are converted to VCF records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_util::variant;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_reader(&data[..])
+    ///     .await?;
+    ///
+    /// let header = reader.read_header().await?;
+    /// let mut records = reader.records(&header);
+    ///
+    /// assert!(records.try_next().await?.is_some());
+    /// assert!(records.try_next().await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records<'a>(
+        &'a mut self,
+        header: &'a vcf::Header,
+    ) -> impl Stream<Item = io::Result<vcf::Record>> + 'a {
+        let string_maps = &self.string_maps;
+
+        match &mut self.inner {
+            Inner::Vcf(inner) => inner.records(header).boxed_local(),
+            Inner::VcfBgzf(inner) => inner.records(header).boxed_local(),
+            Inner::Bcf(inner) => inner
+                .records()
+                .map(move |result| {
+                    result.and_then(|record| record.try_into_vcf_record(header, string_maps))
+                })
+                .boxed_local(),
+            Inner::BcfBgzf(inner) => inner
+                .records()
+                .map(move |result| {
+                    result.and_then(|record| record.try_into_vcf_record(header, string_maps))
+                })
+                .boxed_local(),
+        }
+    }
+}
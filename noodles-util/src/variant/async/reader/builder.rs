@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::{
+    fs::File,
+    io::{self, AsyncBufReadExt, AsyncRead, BufReader},
+};
+
+use super::{Inner, Reader};
+use crate::{
+    compression::detect_compression,
+    sniff,
+    variant::{format::detect_format, Compression, Format},
+};
+
+/// An async variant reader builder.
+#[derive(Default)]
+pub struct Builder {
+    format: Option<Format>,
+    compression: Option<Option<Compression>>,
+}
+
+impl Builder {
+    /// Sets the format of the input.
+    ///
+    /// By default, the format is autodetected on build. This can be used to override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Format};
+    /// let builder = variant::r#async::reader::Builder::default().set_format(Format::Vcf);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the compression method of the input.
+    ///
+    /// By default, the compression method is autodetected on build. This can be used to override
+    /// it. `None` is uncompressed.
+    ///
+    /// gzip compression is not supported by the async reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Compression};
+    /// let builder = variant::r#async::reader::Builder::default().set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Builds an async variant reader from a path.
+    ///
+    /// By default, the format and compression method will be autodetected. This can be
+    /// overridden by using [`Self::set_format`] and [`Self::set_compression`], respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant;
+    ///
+    /// let reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_path("sample.vcf.gz")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_from_path<P>(self, path: P) -> io::Result<Reader<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).await?;
+        self.build_from_reader(file).await
+    }
+
+    /// Builds an async variant reader from a reader.
+    ///
+    /// By default, the format and compression method will be autodetected from the content of
+    /// the input. This can be overridden by using [`Self::set_format`] and
+    /// [`Self::set_compression`], respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant;
+    ///
+    /// let reader = variant::r#async::reader::Builder::default()
+    ///     .build_from_reader(tokio::io::empty())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<R>>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let reader = sniff::read_prefix(reader).await?;
+        let mut reader = BufReader::new(reader);
+
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => detect_compression(reader.fill_buf().await?),
+        };
+
+        let format = self.format;
+
+        let inner = match compression {
+            None => {
+                let format = match format {
+                    Some(format) => format,
+                    None => detect_format(reader.fill_buf().await?),
+                };
+
+                match format {
+                    Format::Vcf => Inner::Vcf(vcf::AsyncReader::new(reader)),
+                    Format::Bcf => Inner::Bcf(bcf::AsyncReader::from(reader)),
+                }
+            }
+            Some(Compression::Bgzf) => {
+                let mut reader = bgzf::AsyncReader::new(reader);
+
+                let format = match format {
+                    Some(format) => format,
+                    None => detect_format(reader.fill_buf().await?),
+                };
+
+                match format {
+                    Format::Vcf => Inner::VcfBgzf(vcf::AsyncReader::new(reader)),
+                    Format::Bcf => Inner::BcfBgzf(bcf::AsyncReader::from(reader)),
+                }
+            }
+            Some(Compression::Gzip) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "gzip compression is not supported by the async variant reader",
+                ))
+            }
+        };
+
+        Ok(Reader {
+            inner,
+            string_maps: Default::default(),
+        })
+    }
+}
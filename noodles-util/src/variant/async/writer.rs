@@ -0,0 +1,264 @@
+//! Async variant writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+enum Inner<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    Vcf(vcf::AsyncWriter<W>),
+    VcfBgzf(vcf::AsyncWriter<bgzf::AsyncWriter<W>>),
+    Bcf(BcfWriter<W>),
+    BcfBgzf(BcfWriter<bgzf::AsyncWriter<W>>),
+}
+
+/// An async variant writer.
+///
+/// This writes VCF records as VCF or BCF.
+pub struct Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    inner: Inner<W>,
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async variant writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant;
+    /// let builder = variant::AsyncWriter::builder(tokio::io::sink());
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// For BCF, this also writes the file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant::{self, Format};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = variant::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Bcf)
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Vcf(inner) => inner.write_header(header).await,
+            Inner::VcfBgzf(inner) => inner.write_header(header).await,
+            Inner::Bcf(inner) => inner.write_header(header).await,
+            Inner::BcfBgzf(inner) => inner.write_header(header).await,
+        }
+    }
+
+    /// Writes a VCF record.
+    ///
+    /// For BCF, the record is converted using the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_util::variant::{self, Format};
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let mut writer = variant::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Vcf)
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header).await?;
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_record(&header, &record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &vcf::Record,
+    ) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Vcf(inner) => inner.write_record(record).await,
+            Inner::VcfBgzf(inner) => inner.write_record(record).await,
+            Inner::Bcf(inner) => inner.write_record(header, record).await,
+            Inner::BcfBgzf(inner) => inner.write_record(header, record).await,
+        }
+    }
+
+    /// Shuts down the variant format writer.
+    ///
+    /// For BGZF-compressed output, this writes the final BGZF block and EOF marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_util::variant::{self, Format};
+    ///
+    /// let mut writer = variant::AsyncWriter::builder(Vec::new())
+    ///     .set_format(Format::Bcf)
+    ///     .build()?;
+    ///
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Vcf(inner) => inner.get_mut().shutdown().await,
+            Inner::VcfBgzf(inner) => inner.get_mut().shutdown().await,
+            Inner::Bcf(inner) => inner.inner.shutdown().await,
+            Inner::BcfBgzf(inner) => inner.inner.shutdown().await,
+        }
+    }
+}
+
+// There is no async BCF writer. Headers and records are encoded using the BCF writer into a
+// buffer, which is then written to the async writer.
+struct BcfWriter<W> {
+    inner: W,
+    encoder: bcf::Writer<Vec<u8>>,
+    string_maps: StringMaps,
+}
+
+impl<W> BcfWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            encoder: bcf::Writer::from(Vec::new()),
+            string_maps: StringMaps::default(),
+        }
+    }
+
+    async fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.encoder.write_file_format()?;
+        self.encoder.write_header(header)?;
+        self.string_maps = StringMaps::from(header);
+        self.flush_encoder().await
+    }
+
+    async fn write_record(&mut self, header: &vcf::Header, record: &vcf::Record) -> io::Result<()> {
+        self.encoder
+            .write_vcf_record(header, &self.string_maps, record)?;
+        self.flush_encoder().await
+    }
+
+    async fn flush_encoder(&mut self) -> io::Result<()> {
+        let buf = self.encoder.get_mut();
+        self.inner.write_all(buf).await?;
+        buf.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use noodles_vcf::record::Position;
+
+    use super::*;
+    use crate::variant::{r#async::reader, Compression, Format};
+
+    #[tokio::test]
+    async fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header: vcf::Header = "##fileformat=VCFv4.3
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let record = vcf::Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        for (format, compression) in [
+            (Format::Vcf, None),
+            (Format::Vcf, Some(Compression::Bgzf)),
+            (Format::Bcf, None),
+            (Format::Bcf, Some(Compression::Bgzf)),
+        ] {
+            let mut writer = Writer::builder(Vec::new())
+                .set_format(format)
+                .set_compression(compression)
+                .build()?;
+
+            writer.write_header(&header).await?;
+            writer.write_record(&header, &record).await?;
+            writer.shutdown().await?;
+
+            let data = match writer.inner {
+                Inner::Vcf(inner) => inner.into_inner(),
+                Inner::VcfBgzf(inner) => inner.into_inner().into_inner(),
+                Inner::Bcf(inner) => inner.inner,
+                Inner::BcfBgzf(inner) => inner.inner.into_inner(),
+            };
+
+            let mut reader = reader::Builder::default()
+                .build_from_reader(&data[..])
+                .await?;
+
+            assert_eq!(reader.format(), format);
+            assert_eq!(reader.compression(), compression);
+
+            let actual_header = reader.read_header().await?;
+            let records: Vec<_> = reader.records(&actual_header).try_collect().await?;
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0], record);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_compression() {
+        assert!(matches!(
+            Writer::builder(Vec::new())
+                .set_format(Format::Vcf)
+                .set_compression(Some(Compression::Gzip))
+                .build(),
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::io::{self, AsyncWrite};
+
+use super::{BcfWriter, Inner, Writer};
+use crate::variant::{Compression, Format};
+
+/// An async variant writer builder.
+pub struct Builder<W> {
+    inner: W,
+    format: Format,
+    compression: Option<Option<Compression>>,
+}
+
+impl<W> Builder<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    pub(super) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            format: Format::Vcf,
+            compression: None,
+        }
+    }
+
+    /// Sets the format of the output.
+    ///
+    /// By default, the output format is VCF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Format};
+    /// let builder = variant::AsyncWriter::builder(tokio::io::sink()).set_format(Format::Bcf);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the compression method of the output.
+    ///
+    /// By default, VCF is uncompressed, and BCF is BGZF-compressed. gzip compression is not
+    /// supported by the async writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Compression, Format};
+    ///
+    /// let builder = variant::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Vcf)
+    ///     .set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Builds an async variant writer.
+    ///
+    /// This returns an error if the compression method is gzip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant::{self, Format};
+    ///
+    /// let writer = variant::AsyncWriter::builder(tokio::io::sink())
+    ///     .set_format(Format::Vcf)
+    ///     .build()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build(self) -> io::Result<Writer<W>> {
+        let compression = self.compression.unwrap_or(match self.format {
+            Format::Vcf => None,
+            Format::Bcf => Some(Compression::Bgzf),
+        });
+
+        let inner = match (self.format, compression) {
+            (Format::Vcf, None) => Inner::Vcf(vcf::AsyncWriter::new(self.inner)),
+            (Format::Vcf, Some(Compression::Bgzf)) => {
+                Inner::VcfBgzf(vcf::AsyncWriter::new(bgzf::AsyncWriter::new(self.inner)))
+            }
+            (Format::Bcf, None) => Inner::Bcf(BcfWriter::new(self.inner)),
+            (Format::Bcf, Some(Compression::Bgzf)) => {
+                Inner::BcfBgzf(BcfWriter::new(bgzf::AsyncWriter::new(self.inner)))
+            }
+            (_, Some(Compression::Gzip)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "gzip compression is not supported by the async variant writer",
+                ))
+            }
+        };
+
+        Ok(Writer { inner })
+    }
+}
@@ -0,0 +1,363 @@
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    io::{self, Read, Write},
+    num::NonZeroUsize,
+};
+
+use flate2::write::GzEncoder;
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+
+use super::{Compression, Format};
+use crate::{
+    compression::{detect_compression, invalid_compression},
+    sniff,
+};
+
+type HeaderTransform = Box<dyn Fn(&mut vcf::Header)>;
+
+/// A variant format converter.
+///
+/// A converter reads a variant format (VCF or BCF) and writes it as another variant format. The
+/// input format and compression method are autodetected.
+///
+/// BGZF-compressed streams, i.e., bgzipped VCF and BCF, are decompressed and compressed on a pool
+/// of worker threads (see [`Builder::set_worker_count`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufWriter}, num::NonZeroUsize};
+/// use noodles_util::variant::{self, Format};
+///
+/// let converter = variant::Converter::builder()
+///     .set_format(Format::Bcf)
+///     .set_worker_count(NonZeroUsize::new(8).unwrap())
+///     .build();
+///
+/// let reader = File::open("sample.vcf.gz")?;
+/// let writer = File::create("sample.bcf").map(BufWriter::new)?;
+/// converter.convert(reader, writer)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Converter {
+    format: Format,
+    compression: Option<Compression>,
+    worker_count: NonZeroUsize,
+    header_transform: Option<HeaderTransform>,
+}
+
+impl Converter {
+    /// Creates a variant format converter builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant;
+    /// let builder = variant::Converter::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Converts a variant format to the output format.
+    ///
+    /// The header is read from the input, transformed, if a transform is set, and written to the
+    /// output. Records are then copied from the input to the output. The output stream is finished
+    /// before returning.
+    ///
+    /// This returns the number of records converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant::{self, Format};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let converter = variant::Converter::builder()
+    ///     .set_format(Format::Vcf)
+    ///     .build();
+    ///
+    /// let record_count = converter.convert(&data[..], io::sink())?;
+    /// assert_eq!(record_count, 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn convert<R, W>(&self, reader: R, writer: W) -> io::Result<u64>
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut reader = self.build_reader(reader)?;
+        let src_header = reader.read_header()?;
+
+        let mut dst_header = src_header.clone();
+
+        if let Some(transform) = &self.header_transform {
+            transform(&mut dst_header);
+        }
+
+        let mut writer = self.build_writer(writer)?;
+        writer.write_header(&dst_header)?;
+
+        let mut record_count = 0;
+
+        for result in reader.records(&src_header) {
+            let record = result?;
+            writer.write_record(&dst_header, &record)?;
+            record_count += 1;
+        }
+
+        writer.finish()?;
+
+        Ok(record_count)
+    }
+
+    fn build_reader<R>(&self, reader: R) -> io::Result<super::Reader<Box<dyn Read>>>
+    where
+        R: Read + Send + 'static,
+    {
+        let reader = sniff::Reader::new(reader)?;
+        let compression = detect_compression(reader.prefix());
+
+        // BGZF-compressed input is decompressed here, so the reader only detects the format.
+        let (reader, compression): (Box<dyn Read>, _) = match compression {
+            Some(Compression::Bgzf) => (
+                Box::new(bgzf::MultithreadedReader::with_worker_count(
+                    self.worker_count,
+                    reader,
+                )),
+                None,
+            ),
+            _ => (Box::new(reader), compression),
+        };
+
+        super::Reader::builder()
+            .set_compression(compression)
+            .build_from_reader(reader)
+    }
+
+    fn build_writer<W>(&self, writer: W) -> io::Result<Writer<W>>
+    where
+        W: Write + Send + 'static,
+    {
+        let inner = match self.compression {
+            None => Output::Raw(writer),
+            Some(Compression::Bgzf) => Output::Bgzf(bgzf::MultithreadedWriter::with_worker_count(
+                self.worker_count,
+                writer,
+            )),
+            Some(Compression::Gzip) if self.format == Format::Vcf => {
+                Output::Gzip(GzEncoder::new(writer, Default::default()))
+            }
+            Some(Compression::Gzip) => {
+                return Err(invalid_compression(self.format, self.compression))
+            }
+        };
+
+        match self.format {
+            Format::Vcf => Ok(Writer::Vcf(vcf::Writer::new(inner))),
+            Format::Bcf => Ok(Writer::Bcf(
+                Box::new(bcf::Writer::from(inner)),
+                StringMaps::default(),
+            )),
+        }
+    }
+}
+
+enum Output<W>
+where
+    W: Write + Send + 'static,
+{
+    Raw(W),
+    Gzip(GzEncoder<W>),
+    Bgzf(bgzf::MultithreadedWriter<W>),
+}
+
+impl<W> Output<W>
+where
+    W: Write + Send + 'static,
+{
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Self::Raw(mut inner) => inner.flush(),
+            Self::Gzip(inner) => inner.finish().and_then(|mut inner| inner.flush()),
+            Self::Bgzf(inner) => inner.finish().map(|_| ()),
+        }
+    }
+}
+
+impl<W> Write for Output<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Raw(inner) => inner.write(buf),
+            Self::Gzip(inner) => inner.write(buf),
+            Self::Bgzf(inner) => inner.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Raw(inner) => inner.flush(),
+            Self::Gzip(inner) => inner.flush(),
+            Self::Bgzf(inner) => inner.flush(),
+        }
+    }
+}
+
+enum Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    Vcf(vcf::Writer<Output<W>>),
+    Bcf(Box<bcf::Writer<Output<W>>>, StringMaps),
+}
+
+impl<W> Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        match self {
+            Self::Vcf(inner) => inner.write_header(header),
+            Self::Bcf(inner, string_maps) => {
+                inner.write_file_format()?;
+                inner.write_header(header)?;
+                *string_maps = StringMaps::from(header);
+                Ok(())
+            }
+        }
+    }
+
+    fn write_record(&mut self, header: &vcf::Header, record: &vcf::Record) -> io::Result<()> {
+        match self {
+            Self::Vcf(inner) => inner.write_record(record),
+            Self::Bcf(inner, string_maps) => inner.write_vcf_record(header, string_maps, record),
+        }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Self::Vcf(inner) => inner.into_inner().finish(),
+            Self::Bcf(inner, _) => inner.into_inner().finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuf {
+        fn take(&self) -> Vec<u8> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    const VCF_HEADER: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0,length=8>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+    const VCF_RECORDS: &[u8] = b"sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t5\t.\tC\tG\t.\tPASS\t.
+";
+
+    fn convert(converter: &Converter, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        let buf = SharedBuf::default();
+        assert_eq!(converter.convert(io::Cursor::new(data), buf.clone())?, 2);
+        Ok(buf.take())
+    }
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let worker_count = NonZeroUsize::new(2).unwrap();
+        let vcf_data = [VCF_HEADER, VCF_RECORDS].concat();
+
+        let converter = Converter::builder()
+            .set_format(Format::Bcf)
+            .set_worker_count(worker_count)
+            .build();
+
+        let bcf_data = convert(&converter, vcf_data)?;
+
+        let reader = super::super::Reader::builder().build_from_reader(&bcf_data[..])?;
+        assert_eq!(reader.format(), Format::Bcf);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let converter = Converter::builder()
+            .set_format(Format::Vcf)
+            .set_compression(Some(Compression::Bgzf))
+            .set_worker_count(worker_count)
+            .build();
+
+        let vcf_bgzf_data = convert(&converter, bcf_data)?;
+
+        let reader = super::super::Reader::builder().build_from_reader(&vcf_bgzf_data[..])?;
+        assert_eq!(reader.format(), Format::Vcf);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let converter = Converter::builder()
+            .set_format(Format::Vcf)
+            .set_header_transform(|header| *header.assembly_mut() = Some(String::from("sq.fa")))
+            .build();
+
+        let actual = convert(&converter, vcf_bgzf_data)?;
+
+        let expected = [
+            &VCF_HEADER[..21],
+            b"##assembly=sq.fa\n",
+            &VCF_HEADER[21..],
+            VCF_RECORDS,
+        ]
+        .concat();
+
+        assert_eq!(
+            String::from_utf8_lossy(&actual),
+            String::from_utf8_lossy(&expected)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_with_invalid_compression() {
+        let converter = Converter::builder()
+            .set_format(Format::Bcf)
+            .set_compression(Some(Compression::Gzip))
+            .build();
+
+        let data = [VCF_HEADER, VCF_RECORDS].concat();
+
+        assert!(matches!(
+            converter.convert(io::Cursor::new(data), io::sink()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
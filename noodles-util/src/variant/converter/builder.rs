@@ -0,0 +1,125 @@
+use std::num::NonZeroUsize;
+
+use noodles_vcf as vcf;
+
+use super::Converter;
+use crate::variant::{Compression, Format};
+
+/// A variant format converter builder.
+pub struct Builder {
+    format: Format,
+    compression: Option<Option<Compression>>,
+    worker_count: NonZeroUsize,
+    header_transform: Option<super::HeaderTransform>,
+}
+
+impl Builder {
+    /// Sets the format of the output.
+    ///
+    /// By default, the output format is VCF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Format};
+    /// let builder = variant::Converter::builder().set_format(Format::Bcf);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the compression method of the output.
+    ///
+    /// By default, VCF is uncompressed, and BCF is BGZF-compressed. BCF cannot be gzip-compressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Compression, Format};
+    ///
+    /// let builder = variant::Converter::builder()
+    ///     .set_format(Format::Vcf)
+    ///     .set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Sets the number of worker threads.
+    ///
+    /// Worker threads are used to decompress BGZF-compressed input and compress BGZF-compressed
+    /// output.
+    ///
+    /// By default, the worker count is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::variant;
+    ///
+    /// let builder = variant::Converter::builder()
+    ///     .set_worker_count(NonZeroUsize::new(8).unwrap());
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets a header transform.
+    ///
+    /// The transform is applied to the input header before it is written to the output. Records
+    /// are written using the transformed header, so it must still define the contigs, filters,
+    /// and fields used by the records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant;
+    ///
+    /// let builder = variant::Converter::builder()
+    ///     .set_header_transform(|header| *header.assembly_mut() = Some(String::from("ref.fa")));
+    /// ```
+    pub fn set_header_transform<F>(mut self, header_transform: F) -> Self
+    where
+        F: Fn(&mut vcf::Header) + 'static,
+    {
+        self.header_transform = Some(Box::new(header_transform));
+        self
+    }
+
+    /// Builds a variant format converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant;
+    /// let converter = variant::Converter::builder().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        let compression = self.compression.unwrap_or(match self.format {
+            Format::Vcf => None,
+            Format::Bcf => Some(Compression::Bgzf),
+        });
+
+        Converter {
+            format: self.format,
+            compression,
+            worker_count: self.worker_count,
+            header_transform: self.header_transform,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            format: Format::Vcf,
+            compression: None,
+            worker_count: NonZeroUsize::new(1).unwrap(),
+            header_transform: None,
+        }
+    }
+}
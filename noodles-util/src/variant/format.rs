@@ -0,0 +1,19 @@
+/// A variant format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Variant Call Format (VCF).
+    Vcf,
+    /// Binary Call Format (BCF).
+    Bcf,
+}
+
+// Detects the format from the start of a (decompressed) stream.
+pub(super) fn detect_format(src: &[u8]) -> Format {
+    const BCF_MAGIC_NUMBER: [u8; 4] = [b'B', b'C', b'F', 0x02];
+
+    if src.starts_with(&BCF_MAGIC_NUMBER) {
+        Format::Bcf
+    } else {
+        Format::Vcf
+    }
+}
@@ -0,0 +1,454 @@
+mod builder;
+mod query;
+
+pub use self::builder::Builder;
+
+use std::io::{self, BufReader, Read, Seek};
+
+use flate2::read::MultiGzDecoder;
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_bgzf as bgzf;
+use noodles_core::{aliases::Aliases, Region};
+use noodles_csi::{self as csi, BinningIndex};
+use noodles_tabix as tabix;
+use noodles_vcf as vcf;
+
+use self::query::Query;
+use super::{Compression, Format};
+use crate::sniff;
+
+enum Inner<R> {
+    Vcf(vcf::Reader<R>),
+    VcfGzip(vcf::Reader<BufReader<MultiGzDecoder<R>>>),
+    VcfBgzf(vcf::Reader<bgzf::Reader<R>>),
+    Bcf(bcf::Reader<R>),
+    BcfBgzf(bcf::Reader<bgzf::Reader<R>>),
+}
+
+enum Index {
+    Csi(csi::Index),
+    Tabix(tabix::Index),
+}
+
+/// A variant reader.
+///
+/// This reads VCF and BCF records as VCF records.
+pub struct Reader<R> {
+    inner: Inner<BufReader<sniff::Reader<R>>>,
+    string_maps: StringMaps,
+    index: Option<Index>,
+    aliases: Aliases,
+}
+
+impl Reader<()> {
+    /// Creates a variant reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant;
+    /// let builder = variant::Reader::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    /// Returns the format of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant::{self, Format};
+    /// let reader = variant::Reader::builder().build_from_reader(&b"##fileformat=VCFv4.3\n"[..])?;
+    /// assert_eq!(reader.format(), Format::Vcf);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn format(&self) -> Format {
+        match self.inner {
+            Inner::Vcf(_) | Inner::VcfGzip(_) | Inner::VcfBgzf(_) => Format::Vcf,
+            Inner::Bcf(_) | Inner::BcfBgzf(_) => Format::Bcf,
+        }
+    }
+
+    /// Returns the compression method of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant;
+    /// let reader = variant::Reader::builder().build_from_reader(&b"##fileformat=VCFv4.3\n"[..])?;
+    /// assert!(reader.compression().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn compression(&self) -> Option<Compression> {
+        match self.inner {
+            Inner::Vcf(_) | Inner::Bcf(_) => None,
+            Inner::VcfGzip(_) => Some(Compression::Gzip),
+            Inner::VcfBgzf(_) | Inner::BcfBgzf(_) => Some(Compression::Bgzf),
+        }
+    }
+
+    /// Reads and parses a VCF header.
+    ///
+    /// For BCF, this also reads the file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// ";
+    ///
+    /// let mut reader = variant::Reader::builder().build_from_reader(&data[..])?;
+    /// let header = reader.read_header()?;
+    ///
+    /// assert!(header.infos().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<vcf::Header> {
+        let raw_header = match &mut self.inner {
+            Inner::Vcf(inner) => inner.read_header()?,
+            Inner::VcfGzip(inner) => inner.read_header()?,
+            Inner::VcfBgzf(inner) => inner.read_header()?,
+            Inner::Bcf(inner) => {
+                inner.read_file_format()?;
+                inner.read_header()?
+            }
+            Inner::BcfBgzf(inner) => {
+                inner.read_file_format()?;
+                inner.read_header()?
+            }
+        };
+
+        if let Inner::Bcf(_) | Inner::BcfBgzf(_) = self.inner {
+            self.string_maps = raw_header
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// BCF records are converted to VCF records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = variant::Reader::builder().build_from_reader(&data[..])?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut records = reader.records(&header);
+    ///
+    /// assert!(records.next().transpose()?.is_some());
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records<'a>(
+        &'a mut self,
+        header: &'a vcf::Header,
+    ) -> impl Iterator<Item = io::Result<vcf::Record>> + 'a {
+        let string_maps = &self.string_maps;
+
+        let records: Box<dyn Iterator<Item = _>> = match &mut self.inner {
+            Inner::Vcf(inner) => Box::new(inner.records(header)),
+            Inner::VcfGzip(inner) => Box::new(inner.records(header)),
+            Inner::VcfBgzf(inner) => Box::new(inner.records(header)),
+            Inner::Bcf(inner) => Box::new(inner.records().map(move |result| {
+                result.and_then(|record| record.try_into_vcf_record(header, string_maps))
+            })),
+            Inner::BcfBgzf(inner) => Box::new(inner.records().map(move |result| {
+                result.and_then(|record| record.try_into_vcf_record(header, string_maps))
+            })),
+        };
+
+        records
+    }
+}
+
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// This uses the index that was loaded when the reader was built (see
+    /// [`Builder::build_from_path`]): a tabix index (`.tbi`) or coordinate-sorted index (`.csi`)
+    /// for bgzipped VCF and a coordinate-sorted index for BCF. Uncompressed and gzip-compressed
+    /// inputs cannot be queried.
+    ///
+    /// If the region reference sequence name is not in the index, it is resolved using the
+    /// aliases set by [`Builder::set_aliases`], e.g., `chr1` may be queried as `1`. Records keep
+    /// the reference sequence names of the input.
+    ///
+    /// BCF records are converted to VCF records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::variant;
+    ///
+    /// let mut reader = variant::Reader::builder().build_from_path("sample.vcf.gz")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query(&header, &region)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<'a>(
+        &'a mut self,
+        header: &'a vcf::Header,
+        region: &Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<vcf::Record>> + 'a> {
+        if let Inner::Vcf(_) | Inner::VcfGzip(_) | Inner::Bcf(_) = self.inner {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "uncompressed or gzip-compressed inputs cannot be queried",
+            ));
+        }
+
+        let index = self.index.as_ref().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "cannot query without an index")
+        })?;
+
+        let aliases = &self.aliases;
+        let string_maps = &self.string_maps;
+
+        let iter: Box<dyn Iterator<Item = _>> = match (&mut self.inner, index) {
+            (Inner::VcfBgzf(inner), Index::Tabix(tabix)) => {
+                let names = tabix.header().reference_sequence_names();
+                let region = resolve_region(aliases, region, |name| names.contains(name))?;
+                Box::new(inner.query(header, tabix, &region)?)
+            }
+            (Inner::VcfBgzf(inner), Index::Csi(csi)) => {
+                let index_header = tabix::index::Header::from_csi_aux(csi.aux())?;
+                let names = index_header.reference_sequence_names();
+
+                let region = resolve_region(aliases, region, |name| names.contains(name))?;
+
+                let reference_sequence_id = names.get_index_of(region.name()).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "region reference sequence does not exist in reference sequences: {:?}",
+                            region
+                        ),
+                    )
+                })?;
+
+                let chunks = csi.query(reference_sequence_id, region.interval())?;
+
+                Box::new(Query::new(
+                    inner,
+                    chunks,
+                    region.name().into(),
+                    region.interval(),
+                    header,
+                ))
+            }
+            (Inner::BcfBgzf(inner), Index::Csi(csi)) => {
+                let contigs = string_maps.contigs();
+                let region =
+                    resolve_region(aliases, region, |name| contigs.get_index_of(name).is_some())?;
+
+                Box::new(inner.query(contigs, csi, &region)?.map(move |result| {
+                    result.and_then(|record| record.try_into_vcf_record(header, string_maps))
+                }))
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "index does not match the input format",
+                ))
+            }
+        };
+
+        Ok(iter)
+    }
+}
+
+// Resolves the region reference sequence name to a known name using its aliases, if it is
+// unknown.
+fn resolve_region<F>(aliases: &Aliases, region: &Region, is_known: F) -> io::Result<Region>
+where
+    F: Fn(&str) -> bool,
+{
+    if is_known(region.name()) {
+        return Ok(region.clone());
+    }
+
+    aliases
+        .resolve(region.name(), is_known)
+        .map(|name| Region::new(name, region.interval()))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in index: {:?}",
+                    region.name()
+                ),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_core::Position;
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+
+    use super::*;
+
+    const HEADER: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0,length=8>
+##contig=<ID=sq1,length=13>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+    const RECORDS: [(&str, usize); 4] = [("sq0", 1), ("sq0", 5), ("sq1", 3), ("sq1", 8)];
+
+    fn build_data() -> io::Result<(Vec<u8>, tabix::Index, csi::Index)> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(HEADER)?;
+        writer.flush_block()?;
+
+        let mut tabix_indexer = tabix::Index::indexer();
+        tabix_indexer.set_header(tabix::index::Header::vcf());
+
+        let mut chunks = Vec::new();
+
+        for (name, position) in RECORDS {
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{}\t{}\t.\tA\t.\t.\tPASS\t.", name, position)?;
+            writer.flush_block()?;
+            let end_position = writer.virtual_position();
+
+            let chunk = Chunk::new(start_position, end_position);
+            let position = Position::try_from(position)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            tabix_indexer.add_record(name, position, position, chunk);
+            chunks.push((name, position, chunk));
+        }
+
+        let data = writer.finish()?;
+        let tabix_index = tabix_indexer.build();
+
+        let mut csi_indexer = csi::Index::indexer();
+        csi_indexer.set_aux(tabix_index.header().to_csi_aux()?);
+
+        let names = tabix_index.header().reference_sequence_names();
+
+        for (name, position, chunk) in chunks {
+            let reference_sequence_id = names.get_index_of(name).unwrap();
+            csi_indexer.add_record(reference_sequence_id, position, position, chunk)?;
+        }
+
+        Ok((data, tabix_index, csi_indexer.build()))
+    }
+
+    fn query_positions(
+        reader: &mut Reader<Cursor<Vec<u8>>>,
+        header: &vcf::Header,
+        region: &str,
+    ) -> Result<Vec<(String, usize)>, Box<dyn std::error::Error>> {
+        let region = region.parse()?;
+
+        let records = reader
+            .query(header, &region)?
+            .map(|result| {
+                result.map(|record| {
+                    (
+                        record.chromosome().to_string(),
+                        usize::from(record.position()),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(records)
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, tabix_index, csi_index) = build_data()?;
+
+        let mut aliases = Aliases::default();
+        aliases.insert("sq1", "chrsq1");
+
+        for index in [Index::Tabix(tabix_index), Index::Csi(csi_index)] {
+            let mut reader = Reader::builder()
+                .set_aliases(aliases.clone())
+                .build_from_reader(Cursor::new(data.clone()))?;
+
+            reader.index = Some(index);
+
+            let header = reader.read_header()?;
+
+            assert_eq!(
+                query_positions(&mut reader, &header, "sq0:4-6")?,
+                [(String::from("sq0"), 5)]
+            );
+
+            assert_eq!(
+                query_positions(&mut reader, &header, "chrsq1")?,
+                [(String::from("sq1"), 3), (String::from("sq1"), 8)]
+            );
+
+            assert!(matches!(
+                query_positions(&mut reader, &header, "sq2"),
+                Err(e) if e.downcast_ref::<io::Error>().map(|e| e.kind()) == Some(io::ErrorKind::InvalidInput)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_without_index() -> io::Result<()> {
+        let region = "sq0".parse().unwrap();
+
+        let mut reader = Reader::builder().build_from_reader(Cursor::new(HEADER.to_vec()))?;
+        let header = reader.read_header()?;
+        assert!(matches!(
+            reader.query(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
+        let (data, _, _) = build_data()?;
+        let mut reader = Reader::builder().build_from_reader(Cursor::new(data))?;
+        let header = reader.read_header()?;
+        assert!(matches!(
+            reader.query(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
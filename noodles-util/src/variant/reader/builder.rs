@@ -0,0 +1,267 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::MultiGzDecoder;
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_core::aliases::Aliases;
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+use noodles_vcf as vcf;
+
+use super::{Index, Inner, Reader};
+use crate::{
+    compression::{detect_compression, invalid_compression},
+    sniff,
+    variant::{format::detect_format, Compression, Format},
+};
+
+/// A variant reader builder.
+#[derive(Default)]
+pub struct Builder {
+    format: Option<Format>,
+    compression: Option<Option<Compression>>,
+    aliases: Aliases,
+}
+
+impl Builder {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the format of the input.
+    ///
+    /// By default, the format is autodetected on build. This can be used to override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Format};
+    /// let builder = variant::Reader::builder().set_format(Format::Vcf);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the compression method of the input.
+    ///
+    /// By default, the compression method is autodetected on build. This can be used to override
+    /// it. `None` is uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::{self, Compression};
+    /// let builder = variant::Reader::builder().set_compression(Some(Compression::Bgzf));
+    /// ```
+    pub fn set_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Sets the reference sequence name aliases.
+    ///
+    /// These are used by [`Reader::query`] to resolve region reference sequence names that are
+    /// not in the index. By default, there are no aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::aliases::{Aliases, Assembly};
+    /// use noodles_util::variant;
+    ///
+    /// let builder = variant::Reader::builder().set_aliases(Aliases::from(Assembly::Grch38));
+    /// ```
+    pub fn set_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Builds a variant reader from a path.
+    ///
+    /// By default, the format and compression method will be autodetected. This can be
+    /// overridden by using [`Self::set_format`] and [`Self::set_compression`], respectively.
+    ///
+    /// An associated index will also attempt to be loaded, which is used by [`Reader::query`].
+    /// For bgzipped VCF, this is `<src>.tbi` or `<src>.csi`; and for BCF, `<src>.csi`. The same
+    /// paths with the last extension replaced are also checked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_util::variant;
+    /// let reader = variant::Reader::builder().build_from_path("sample.vcf.gz")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, path: P) -> io::Result<Reader<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let src = path.as_ref();
+        let file = File::open(src)?;
+        let mut reader = self.build_from_reader(file)?;
+
+        if let Some(index_src) = find_index_src(src, reader.format(), reader.compression()) {
+            reader.index = read_index(&index_src)?;
+        }
+
+        Ok(reader)
+    }
+
+    /// Builds a variant reader from a reader.
+    ///
+    /// By default, the format and compression method will be autodetected from the content of
+    /// the input. This can be overridden by using [`Self::set_format`] and
+    /// [`Self::set_compression`], respectively. The input does not need to be seekable, e.g., it
+    /// can be stdin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant;
+    /// let reader = variant::Reader::builder().build_from_reader(io::empty())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> io::Result<Reader<R>>
+    where
+        R: Read,
+    {
+        let reader = sniff::Reader::new(reader)?;
+
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => detect_compression(reader.prefix()),
+        };
+
+        let reader = BufReader::new(reader);
+
+        let inner = match compression {
+            None => match self
+                .format
+                .unwrap_or_else(|| detect_format(reader.get_ref().prefix()))
+            {
+                Format::Vcf => Inner::Vcf(vcf::Reader::new(reader)),
+                Format::Bcf => Inner::Bcf(bcf::Reader::from(reader)),
+            },
+            Some(Compression::Bgzf) => {
+                let mut reader = bgzf::Reader::new(reader);
+
+                match self
+                    .format
+                    .map(Ok)
+                    .unwrap_or_else(|| reader.fill_buf().map(detect_format))?
+                {
+                    Format::Vcf => Inner::VcfBgzf(vcf::Reader::new(reader)),
+                    Format::Bcf => Inner::BcfBgzf(bcf::Reader::from(reader)),
+                }
+            }
+            Some(Compression::Gzip) => match self.format.unwrap_or(Format::Vcf) {
+                Format::Vcf => {
+                    let reader = BufReader::new(MultiGzDecoder::new(reader));
+                    Inner::VcfGzip(vcf::Reader::new(reader))
+                }
+                format => return Err(invalid_compression(format, compression)),
+            },
+        };
+
+        Ok(Reader {
+            inner,
+            string_maps: Default::default(),
+            index: None,
+            aliases: self.aliases,
+        })
+    }
+}
+
+fn read_index(src: &Path) -> io::Result<Option<Index>> {
+    match src.extension().and_then(|ext| ext.to_str()) {
+        Some("csi") => csi::read(src).map(Index::Csi).map(Some),
+        Some("tbi") => tabix::read(src).map(Index::Tabix).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn find_index_src(src: &Path, format: Format, compression: Option<Compression>) -> Option<PathBuf> {
+    let extensions: &[&str] = match (format, compression) {
+        (Format::Vcf, Some(Compression::Bgzf)) => &["tbi", "csi"],
+        (Format::Bcf, Some(Compression::Bgzf)) => &["csi"],
+        _ => &[],
+    };
+
+    extensions
+        .iter()
+        .flat_map(|ext| [push_ext(src.into(), ext), src.with_extension(ext)])
+        .find(|index_src| index_src.exists())
+}
+
+fn push_ext<S>(path: PathBuf, ext: S) -> PathBuf
+where
+    S: AsRef<OsStr>,
+{
+    let mut s = OsString::from(path);
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_build_from_reader() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BCF\x02\x02")?;
+        let data = writer.finish()?;
+        let reader = Builder::default().build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Bcf);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let reader = Builder::default().build_from_reader(&b"BCF\x02\x02"[..])?;
+        assert_eq!(reader.format(), Format::Bcf);
+        assert!(reader.compression().is_none());
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"##fileformat=VCFv4.3\n")?;
+        let data = writer.finish()?;
+        let reader = Builder::default().build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Vcf);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let reader = Builder::default()
+            .set_format(Format::Vcf)
+            .set_compression(None)
+            .build_from_reader(&data[..])?;
+        assert_eq!(reader.format(), Format::Vcf);
+        assert!(reader.compression().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_short_reads() -> io::Result<()> {
+        use crate::sniff::ByteReader;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BCF\x02\x02")?;
+        let data = writer.finish()?;
+        let reader = Builder::default().build_from_reader(ByteReader(&data[..]))?;
+        assert_eq!(reader.format(), Format::Bcf);
+        assert_eq!(reader.compression(), Some(Compression::Bgzf));
+
+        let reader = Builder::default().build_from_reader(ByteReader(&b"BCF\x02\x02"[..]))?;
+        assert_eq!(reader.format(), Format::Bcf);
+        assert!(reader.compression().is_none());
+
+        Ok(())
+    }
+}
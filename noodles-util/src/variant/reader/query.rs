@@ -0,0 +1,132 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::{region::Interval, Position};
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_vcf as vcf;
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+// An iterator over records of a bgzipped VCF reader that intersect a region, using a list of
+// chunks from any binning index.
+//
+// `vcf::Reader::query` only accepts a tabix index, so this is used for coordinate-sorted indices
+// (CSI).
+pub(super) struct Query<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    reader: &'r mut vcf::Reader<bgzf::Reader<R>>,
+    chunks: vec::IntoIter<Chunk>,
+    reference_sequence_name: String,
+    interval: Interval,
+    state: State,
+    header: &'h vcf::Header,
+    line_buf: String,
+}
+
+impl<'r, 'h, R> Query<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut vcf::Reader<bgzf::Reader<R>>,
+        chunks: Vec<Chunk>,
+        reference_sequence_name: String,
+        interval: Interval,
+        header: &'h vcf::Header,
+    ) -> Self {
+        Self {
+            reader,
+            chunks: chunks.into_iter(),
+            reference_sequence_name,
+            interval,
+            state: State::Seek,
+            header,
+            line_buf: String::new(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<vcf::Record>> {
+        self.line_buf.clear();
+
+        match self.reader.read_record(&mut self.line_buf)? {
+            0 => Ok(None),
+            _ => vcf::Record::try_from_str(&self.line_buf, self.header)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for Query<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<vcf::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                State::Seek => {
+                    self.state = match self.chunks.next() {
+                        Some(chunk) => {
+                            if let Err(e) = self.reader.seek(chunk.start()) {
+                                return Some(Err(e));
+                            }
+
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    }
+                }
+                State::Read(chunk_end) => match self.read_record() {
+                    Ok(Some(record)) => {
+                        if self.reader.virtual_position() >= chunk_end {
+                            self.state = State::Seek;
+                        }
+
+                        match intersects(&record, &self.reference_sequence_name, self.interval) {
+                            Ok(true) => return Some(Ok(record)),
+                            Ok(false) => {}
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                    Ok(None) => self.state = State::Seek,
+                    Err(e) => return Some(Err(e)),
+                },
+                State::Done => return None,
+            }
+        }
+    }
+}
+
+fn intersects(
+    record: &vcf::Record,
+    reference_sequence_name: &str,
+    region_interval: Interval,
+) -> io::Result<bool> {
+    if record.chromosome().to_string() != reference_sequence_name {
+        return Ok(false);
+    }
+
+    let start = Position::try_from(usize::from(record.position()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let end = record
+        .end()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|position| {
+            Position::try_from(usize::from(position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
+
+    Ok(Interval::from(start..=end).intersects(region_interval))
+}
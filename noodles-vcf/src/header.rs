@@ -3,6 +3,7 @@
 pub mod alternative_allele;
 mod builder;
 pub mod contig;
+pub mod contigs;
 pub mod file_format;
 pub mod filter;
 mod fmt;
@@ -14,6 +15,8 @@ mod parser;
 pub mod pedigree;
 pub mod record;
 pub mod sample;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 pub use self::{
     alternative_allele::AlternativeAllele, builder::Builder, contig::Contig,
@@ -24,6 +27,7 @@ pub use self::{
 use std::str::FromStr;
 
 use indexmap::{IndexMap, IndexSet};
+use noodles_core::{sequence_dictionary, SequenceDictionary};
 
 /// VCF header info records.
 pub type Infos = IndexMap<info::Key, Info>;
@@ -39,6 +43,9 @@ pub type AlternativeAlleles =
     IndexMap<crate::record::alternate_bases::allele::Symbol, AlternativeAllele>;
 
 /// VCF header contig records.
+///
+/// Contigs are kept in insertion order, i.e., the order of the `contig` records in the input,
+/// through parsing, modification, and formatting.
 pub type Contigs = IndexMap<String, Contig>;
 
 /// VCF header sample records.
@@ -55,16 +62,49 @@ pub type Records = IndexMap<String, Vec<Record>>;
 
 /// A VCF header.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Header {
     file_format: FileFormat,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     infos: Infos,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     filters: Filters,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     formats: Formats,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     alternative_alleles: AlternativeAlleles,
     assembly: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     contigs: Contigs,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     meta: IndexMap<String, Meta>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     samples: Samples,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde_impls::deserialize_keyed_map")
+    )]
     pedigrees: Pedigrees,
     pedigree_db: Option<String>,
     sample_names: SampleNames,
@@ -377,6 +417,74 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Reorders the contig records (`contig`) by the given names.
+    ///
+    /// The names must be a permutation of the names of the existing contigs, i.e., every contig
+    /// must be listed exactly once. On error, the contigs are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Contig};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_contig(Contig::new("sq0".parse()?))
+    ///     .add_contig(Contig::new("sq1".parse()?))
+    ///     .build();
+    ///
+    /// header.reorder_contigs(&["sq1", "sq0"])?;
+    ///
+    /// let names: Vec<_> = header.contigs().keys().collect();
+    /// assert_eq!(names, ["sq1", "sq0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reorder_contigs<N>(&mut self, names: &[N]) -> Result<(), contigs::ReorderError>
+    where
+        N: AsRef<str>,
+    {
+        contigs::reorder(&mut self.contigs, names)
+    }
+
+    /// Validates that records are sorted by the contig order of this header.
+    ///
+    /// Records must be grouped by chromosome in the order of the contig records (`contig`) and
+    /// sorted by position within each chromosome. This is the order required to index a VCF, e.g.,
+    /// with tabix or CSI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Contig, record::Position};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Contig::new("sq0".parse()?))
+    ///     .add_contig(Contig::new("sq1".parse()?))
+    ///     .build();
+    ///
+    /// let records = [
+    ///     vcf::Record::builder()
+    ///         .set_chromosome("sq1".parse()?)
+    ///         .set_position(Position::from(8))
+    ///         .set_reference_bases("A".parse()?)
+    ///         .build()?,
+    ///     vcf::Record::builder()
+    ///         .set_chromosome("sq0".parse()?)
+    ///         .set_position(Position::from(13))
+    ///         .set_reference_bases("A".parse()?)
+    ///         .build()?,
+    /// ];
+    ///
+    /// assert!(header.validate_record_order(&records).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_record_order<I, R>(&self, records: I) -> Result<(), contigs::RecordOrderError>
+    where
+        I: IntoIterator<Item = R>,
+        R: std::borrow::Borrow<crate::Record>,
+    {
+        contigs::validate_record_order(&self.contigs, records)
+    }
+
     /// Returns a map of meta records (`META`).
     ///
     /// # Examples
@@ -803,6 +911,33 @@ impl FromStr for Header {
     }
 }
 
+impl From<&Header> for SequenceDictionary {
+    /// Creates a sequence dictionary from the contig records (`##contig`) of a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// use noodles_vcf::{self as vcf, header::Contig};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Contig::new("sq0".parse()?))
+    ///     .add_contig(Contig::new("sq1".parse()?))
+    ///     .build();
+    ///
+    /// let dictionary = SequenceDictionary::from(&header);
+    /// assert_eq!(dictionary.id("sq1"), Some(1));
+    /// # Ok::<_, vcf::header::contig::name::ParseError>(())
+    /// ```
+    fn from(header: &Header) -> Self {
+        header
+            .contigs()
+            .values()
+            .map(sequence_dictionary::Sequence::from)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -900,4 +1035,39 @@ mod tests {
 
         assert_eq!(header.get("noodles"), Some(&records[..]));
     }
+
+    #[test]
+    fn test_contig_order_is_preserved() -> Result<(), Box<dyn std::error::Error>> {
+        let src = "\
+##fileformat=VCFv4.3
+##contig=<ID=sq2>
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let mut header: Header = src.parse()?;
+        assert!(header.contigs().keys().eq(["sq2", "sq0", "sq1"]));
+
+        let contig = Contig::new("sq3".parse()?);
+        header
+            .contigs_mut()
+            .insert(contig.id().as_ref().into(), contig);
+        header.contigs_mut().shift_remove("sq0");
+
+        let expected = "\
+##fileformat=VCFv4.3
+##contig=<ID=sq2>
+##contig=<ID=sq1>
+##contig=<ID=sq3>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        assert_eq!(header.to_string(), expected);
+
+        let header: Header = header.to_string().parse()?;
+        assert!(header.contigs().keys().eq(["sq2", "sq1", "sq3"]));
+
+        Ok(())
+    }
 }
@@ -12,6 +12,7 @@ const DESCRIPTION: &str = "Description";
 
 /// A VCF header symbolic alternate allele record (`ALT`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct AlternativeAllele {
     id: Symbol,
     description: String,
@@ -9,12 +9,16 @@ pub use self::{name::Name, tag::Tag};
 use std::{error, fmt, num};
 
 use indexmap::IndexMap;
+use noodles_core::sequence_dictionary;
 
 use self::builder::Builder;
 use super::{record, Record};
 
+const MD5: &str = "md5";
+
 /// A VCF header contig record (`contig`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Contig {
     id: Name,
     len: Option<usize>,
@@ -238,10 +242,69 @@ fn parse_struct(
         .map_err(|_| TryFromRecordError::InvalidRecord)
 }
 
+impl From<&Contig> for sequence_dictionary::Sequence {
+    /// Creates a sequence dictionary reference sequence from a VCF header contig record.
+    ///
+    /// The MD5 checksum is read from the `md5` field. It is ignored if it is not a valid
+    /// hex-encoded checksum.
+    fn from(contig: &Contig) -> Self {
+        let mut sequence = Self::new(contig.id().to_string(), contig.len());
+        *sequence.md5_mut() = contig.get(MD5).and_then(parse_md5);
+        sequence
+    }
+}
+
+fn parse_md5(s: &str) -> Option<[u8; 16]> {
+    let s = s.as_bytes();
+
+    if s.len() != 32 {
+        return None;
+    }
+
+    let mut md5 = [0; 16];
+
+    for (b, chunk) in md5.iter_mut().zip(s.chunks_exact(2)) {
+        let hi = char::from(chunk[0]).to_digit(16)?;
+        let lo = char::from(chunk[1]).to_digit(16)?;
+        *b = ((hi << 4) | lo) as u8;
+    }
+
+    Some(md5)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_contig_for_sequence_dictionary_sequence() -> Result<(), TryFromRecordError> {
+        let contig = Contig::try_from(build_record())?;
+        let actual = sequence_dictionary::Sequence::from(&contig);
+
+        let mut expected = sequence_dictionary::Sequence::new("sq0", Some(13));
+        *expected.md5_mut() = Some([
+            0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+            0x15, 0x34,
+        ]);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_md5() {
+        assert_eq!(
+            parse_md5("d7eba311421bbc9d3ada44709dd61534"),
+            Some([
+                0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+                0x15, 0x34,
+            ])
+        );
+        assert!(parse_md5("d7eba311").is_none());
+        assert!(parse_md5("n7eba311421bbc9d3ada44709dd61534").is_none());
+    }
+
     fn build_record() -> Record {
         Record::new(
             record::key::CONTIG,
@@ -46,6 +46,7 @@ impl fmt::Display for Standard {
 
 /// A nonstandard VCF header contig tag.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Other(String);
 
 impl AsRef<str> for Other {
@@ -66,6 +67,19 @@ impl fmt::Display for Other {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Other {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let s = String::deserialize(deserializer)?;
+        Tag::other(&s).ok_or_else(|| D::Error::custom(format!("invalid other tag: {}", s)))
+    }
+}
+
 /// A VCF header contig record tag.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Tag {
@@ -0,0 +1,242 @@
+//! VCF header contig ordering.
+
+use std::{borrow::Borrow, error, fmt};
+
+use super::Contigs;
+use crate::{record::Chromosome, Record};
+
+/// An error returned when VCF header contigs fail to reorder.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReorderError {
+    /// A name is not a contig in the header.
+    MissingContig(String),
+    /// A name is duplicated.
+    DuplicateName(String),
+    /// A contig in the header is not in the given names.
+    UnlistedContig(String),
+}
+
+impl error::Error for ReorderError {}
+
+impl fmt::Display for ReorderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingContig(name) => write!(f, "missing contig: {}", name),
+            Self::DuplicateName(name) => write!(f, "duplicate name: {}", name),
+            Self::UnlistedContig(name) => write!(f, "unlisted contig: {}", name),
+        }
+    }
+}
+
+/// An error returned when VCF records are not sorted by the header contig order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordOrderError {
+    /// A record chromosome is not a contig in the header.
+    MissingContig {
+        /// The index of the record.
+        index: usize,
+        /// The record chromosome.
+        chromosome: String,
+    },
+    /// A record is before the previous record.
+    Unsorted {
+        /// The index of the record.
+        index: usize,
+        /// The record chromosome.
+        chromosome: String,
+        /// The record position.
+        position: usize,
+    },
+}
+
+impl error::Error for RecordOrderError {}
+
+impl fmt::Display for RecordOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingContig { index, chromosome } => {
+                write!(f, "record {}: missing contig: {}", index, chromosome)
+            }
+            Self::Unsorted {
+                index,
+                chromosome,
+                position,
+            } => write!(
+                f,
+                "record {}: unsorted position: {}:{}",
+                index, chromosome, position
+            ),
+        }
+    }
+}
+
+pub(super) fn reorder<N>(contigs: &mut Contigs, names: &[N]) -> Result<(), ReorderError>
+where
+    N: AsRef<str>,
+{
+    let mut reordered_contigs = Contigs::with_capacity(contigs.len());
+
+    for name in names {
+        let name = name.as_ref();
+
+        if reordered_contigs.contains_key(name) {
+            return Err(ReorderError::DuplicateName(name.into()));
+        }
+
+        let contig = contigs
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ReorderError::MissingContig(name.into()))?;
+
+        reordered_contigs.insert(name.into(), contig);
+    }
+
+    if let Some(name) = contigs
+        .keys()
+        .find(|name| !reordered_contigs.contains_key(*name))
+    {
+        return Err(ReorderError::UnlistedContig(name.clone()));
+    }
+
+    *contigs = reordered_contigs;
+
+    Ok(())
+}
+
+pub(super) fn validate_record_order<I, R>(
+    contigs: &Contigs,
+    records: I,
+) -> Result<(), RecordOrderError>
+where
+    I: IntoIterator<Item = R>,
+    R: Borrow<Record>,
+{
+    let mut prev: Option<(usize, usize)> = None;
+
+    for (index, record) in records.into_iter().enumerate() {
+        let record = record.borrow();
+
+        let name = match record.chromosome() {
+            Chromosome::Name(name) => name.as_str(),
+            Chromosome::Symbol(symbol) => symbol.as_str(),
+        };
+
+        let contig_index =
+            contigs
+                .get_index_of(name)
+                .ok_or_else(|| RecordOrderError::MissingContig {
+                    index,
+                    chromosome: record.chromosome().to_string(),
+                })?;
+
+        let key = (contig_index, usize::from(record.position()));
+
+        if let Some(prev_key) = prev {
+            if key < prev_key {
+                return Err(RecordOrderError::Unsorted {
+                    index,
+                    chromosome: record.chromosome().to_string(),
+                    position: key.1,
+                });
+            }
+        }
+
+        prev = Some(key);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{header::Contig, record::Position};
+
+    fn build_contigs(names: &[&str]) -> Result<Contigs, Box<dyn std::error::Error>> {
+        names
+            .iter()
+            .map(|name| Ok((String::from(*name), Contig::new(name.parse()?))))
+            .collect()
+    }
+
+    #[test]
+    fn test_reorder() -> Result<(), Box<dyn std::error::Error>> {
+        let mut contigs = build_contigs(&["sq0", "sq1", "sq2"])?;
+        reorder(&mut contigs, &["sq2", "sq0", "sq1"])?;
+        assert!(contigs.keys().eq(["sq2", "sq0", "sq1"]));
+
+        let mut contigs = build_contigs(&["sq0", "sq1"])?;
+
+        assert_eq!(
+            reorder(&mut contigs, &["sq1", "sq2"]),
+            Err(ReorderError::MissingContig(String::from("sq2")))
+        );
+
+        assert_eq!(
+            reorder(&mut contigs, &["sq1", "sq1"]),
+            Err(ReorderError::DuplicateName(String::from("sq1")))
+        );
+
+        assert_eq!(
+            reorder(&mut contigs, &["sq1"]),
+            Err(ReorderError::UnlistedContig(String::from("sq0")))
+        );
+
+        assert!(contigs.keys().eq(["sq0", "sq1"]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_record_order() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_record(chromosome: &str, position: usize) -> Record {
+            Record::builder()
+                .set_chromosome(chromosome.parse().unwrap())
+                .set_position(Position::from(position))
+                .set_reference_bases("A".parse().unwrap())
+                .build()
+                .unwrap()
+        }
+
+        let contigs = build_contigs(&["sq1", "sq0"])?;
+
+        let records = [
+            build_record("sq1", 8),
+            build_record("sq1", 8),
+            build_record("sq1", 13),
+            build_record("sq0", 5),
+        ];
+        assert_eq!(validate_record_order(&contigs, &records), Ok(()));
+
+        let records = [build_record("sq0", 5), build_record("sq1", 8)];
+        assert_eq!(
+            validate_record_order(&contigs, &records),
+            Err(RecordOrderError::Unsorted {
+                index: 1,
+                chromosome: String::from("sq1"),
+                position: 8,
+            })
+        );
+
+        let records = [build_record("sq1", 13), build_record("sq1", 8)];
+        assert_eq!(
+            validate_record_order(&contigs, &records),
+            Err(RecordOrderError::Unsorted {
+                index: 1,
+                chromosome: String::from("sq1"),
+                position: 8,
+            })
+        );
+
+        let records = [build_record("sq2", 1)];
+        assert_eq!(
+            validate_record_order(&contigs, &records),
+            Err(RecordOrderError::MissingContig {
+                index: 0,
+                chromosome: String::from("sq2"),
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ const IDX: &str = "IDX";
 
 /// A VCF header filter record (`FILTER`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Filter {
     id: String,
     description: String,
@@ -16,6 +16,7 @@ use super::{number, record, FileFormat, Number, Record};
 
 /// A VCF header genotype format record (`FORMAT`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Format {
     id: Key,
     number: Number,
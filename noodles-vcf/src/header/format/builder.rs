@@ -1,6 +1,6 @@
 use indexmap::IndexMap;
 
-use super::{tag, Format, Key, Tag, Type};
+use super::{tag, Format, Key, Type};
 use crate::header::Number;
 
 #[derive(Default)]
@@ -49,12 +49,10 @@ impl Builder {
 
     pub fn build(self) -> Result<Format, BuildError> {
         Ok(Format {
-            id: self.id.ok_or(BuildError::MissingField(tag::ID))?,
-            number: self.number.ok_or(BuildError::MissingField(tag::NUMBER))?,
-            ty: self.ty.ok_or(BuildError::MissingField(tag::TYPE))?,
-            description: self
-                .description
-                .ok_or(BuildError::MissingField(tag::DESCRIPTION))?,
+            id: self.id.ok_or(BuildError::MissingField)?,
+            number: self.number.ok_or(BuildError::MissingField)?,
+            ty: self.ty.ok_or(BuildError::MissingField)?,
+            description: self.description.ok_or(BuildError::MissingField)?,
             idx: self.idx,
             fields: self.other_fields,
         })
@@ -62,5 +60,5 @@ impl Builder {
 }
 
 pub enum BuildError {
-    MissingField(Tag),
+    MissingField,
 }
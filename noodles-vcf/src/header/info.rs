@@ -16,6 +16,7 @@ use super::{number, record, FileFormat, Number, Record};
 
 /// A VCF header information record (`INFO`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Info {
     id: Key,
     number: Number,
@@ -13,6 +13,7 @@ const VALUES: &str = "Values";
 
 /// A VCF header meta record (`META`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Meta {
     id: String,
     values: Vec<String>,
@@ -10,6 +10,7 @@ const ID: &str = "ID";
 
 /// A VCF header pedigree record (`PEDIGREE`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Pedigree {
     id: String,
     fields: IndexMap<String, String>,
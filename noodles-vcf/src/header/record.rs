@@ -12,6 +12,7 @@ pub(crate) const PREFIX: &str = "##";
 
 /// A generic VCF header record.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Record {
     key: Key,
     value: Value,
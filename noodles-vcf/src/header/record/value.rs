@@ -4,6 +4,7 @@ use indexmap::IndexMap;
 
 /// A VCF header record value.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Value {
     /// A string.
     String(String),
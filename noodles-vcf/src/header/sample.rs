@@ -10,6 +10,7 @@ const ID: &str = "ID";
 
 /// A VCF header sample record (`SAMPLE`).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Sample {
     id: String,
     fields: IndexMap<String, String>,
@@ -0,0 +1,200 @@
+//! Serialization and deserialization of VCF headers.
+//!
+//! Header structures are (de)serialized by their fields. Values that have a VCF text
+//! representation, e.g., keys, types, and numbers, are (de)serialized as
+//! strings and validated when deserialized. Records in a map must be keyed by their ID.
+
+use std::{fmt, hash::Hash};
+
+use indexmap::IndexMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    contig, format, info, record, AlternativeAllele, Contig, FileFormat, Filter, Format, Info,
+    Meta, Number, Pedigree, Sample,
+};
+use crate::record::alternate_bases::allele::Symbol;
+
+macro_rules! impl_serde_from_str {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let s = String::deserialize(deserializer)?;
+                    s.parse().map_err(de::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_from_str!(
+    contig::Name,
+    FileFormat,
+    format::Key,
+    format::Type,
+    info::Key,
+    info::Type,
+    Number,
+    Symbol,
+);
+
+impl Serialize for record::Key {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for record::Key {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+// A record that is keyed by its ID in a header map.
+pub(crate) trait Keyed<K> {
+    fn is_keyed_by(&self, key: &K) -> bool;
+}
+
+impl Keyed<info::Key> for Info {
+    fn is_keyed_by(&self, key: &info::Key) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<String> for Filter {
+    fn is_keyed_by(&self, key: &String) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<format::Key> for Format {
+    fn is_keyed_by(&self, key: &format::Key) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<Symbol> for AlternativeAllele {
+    fn is_keyed_by(&self, key: &Symbol) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<String> for Contig {
+    fn is_keyed_by(&self, key: &String) -> bool {
+        self.id().as_ref() == key
+    }
+}
+
+impl Keyed<String> for Meta {
+    fn is_keyed_by(&self, key: &String) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<String> for Sample {
+    fn is_keyed_by(&self, key: &String) -> bool {
+        self.id() == key
+    }
+}
+
+impl Keyed<String> for Pedigree {
+    fn is_keyed_by(&self, key: &String) -> bool {
+        self.id() == key
+    }
+}
+
+/// Deserializes a map of records and validates that each record is keyed by its ID.
+pub(crate) fn deserialize_keyed_map<'de, D, K, V>(
+    deserializer: D,
+) -> Result<IndexMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash + fmt::Display,
+    V: Deserialize<'de> + Keyed<K>,
+{
+    let map = IndexMap::<K, V>::deserialize(deserializer)?;
+
+    for (key, value) in &map {
+        if !value.is_keyed_by(key) {
+            return Err(de::Error::custom(format!(
+                "map key mismatch: {} does not match the record ID",
+                key
+            )));
+        }
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Header;
+
+    #[test]
+    fn test_serde_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = concat!(
+            r#"##fileformat=VCFv4.3
+##fileDate=20220720
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##FILTER=<ID=q10,Description="Quality below 10">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##ALT=<ID=DEL,Description="Deletion">
+##contig=<ID=sq1,length=13,md5=d7eba311421bbc9d3ada44709dd61534>
+##contig=<ID=sq0,length=8>
+##META=<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>
+##SAMPLE=<ID=sample0,Assay=WholeGenome>
+##PEDIGREE=<ID=cid,Father=fid,Mother=mid>
+"#,
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample1\tsample0\n",
+        )
+        .parse()?;
+
+        let json = serde_json::to_string(&header)?;
+        let actual: Header = serde_json::from_str(&json)?;
+        assert_eq!(actual, header);
+
+        let names: Vec<_> = actual.contigs().keys().collect();
+        assert_eq!(names, ["sq1", "sq0"]);
+
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["file_format"], "VCFv4.3");
+        assert_eq!(value["infos"]["NS"]["ty"], "Integer");
+        assert_eq!(value["sample_names"][0], "sample1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_with_mismatched_map_key() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.3\n##contig=<ID=sq0>\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n"
+            .parse()?;
+
+        let mut value = serde_json::to_value(&header)?;
+        assert!(serde_json::from_value::<Header>(value.clone()).is_ok());
+
+        let contig = value["contigs"]["sq0"].take();
+        value["contigs"] = serde_json::json!({ "sq1": contig });
+        assert!(serde_json::from_value::<Header>(value).is_err());
+
+        Ok(())
+    }
+}
@@ -26,7 +26,7 @@ mod r#async;
 pub mod header;
 pub mod reader;
 pub mod record;
-mod writer;
+pub mod writer;
 
 pub use self::{header::Header, reader::Reader, record::Record, writer::Writer};
 
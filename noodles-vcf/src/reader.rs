@@ -1,5 +1,6 @@
 //! VCF reader and iterators.
 
+mod count;
 pub(crate) mod query;
 mod records;
 
@@ -305,6 +306,46 @@ where
             header,
         ))
     }
+
+    /// Counts the records that intersect the given region.
+    ///
+    /// This is faster than counting the records of a [`Self::query`]. When the region covers an
+    /// entire reference sequence, e.g., `sq0`, the count is read from the index's reference
+    /// sequence metadata, if available, and no records are read. Otherwise, only the chromosome,
+    /// position, reference bases, and `END` info field of records in the overlapping chunks are
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(vcf::Reader::new)?;
+    ///
+    /// reader.read_header()?;
+    ///
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let n = reader.count(&index, &region)?;
+    /// println!("{}", n);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count(&mut self, index: &tabix::Index, region: &Region) -> io::Result<u64> {
+        let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+
+        count::count(
+            self,
+            index,
+            reference_sequence_id,
+            &reference_sequence_name,
+            region.interval(),
+        )
+    }
 }
 
 fn read_header<R>(reader: &mut R) -> io::Result<String>
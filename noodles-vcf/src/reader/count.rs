@@ -0,0 +1,193 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::{region::Interval, Position};
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_tabix as tabix;
+
+use super::Reader;
+
+const FIELD_DELIMITER: char = '\t';
+const INFO_FIELD_DELIMITER: char = ';';
+const END_PREFIX: &str = "END=";
+
+// Counts the records that intersect the given interval.
+//
+// If the interval covers the entire reference sequence and the index has reference sequence
+// metadata, the count is taken from the metadata. Otherwise, the records in the chunks that
+// overlap the interval are read, and only the chromosome, position, reference bases, and `END`
+// info field of each record are parsed.
+pub(super) fn count<R>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &tabix::Index,
+    reference_sequence_id: usize,
+    reference_sequence_name: &str,
+    interval: Interval,
+) -> io::Result<u64>
+where
+    R: Read + Seek,
+{
+    if is_unbounded(interval) {
+        if let Some(metadata) = index
+            .reference_sequences()
+            .get(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+        {
+            return Ok(metadata.mapped_record_count() + metadata.unmapped_record_count());
+        }
+    }
+
+    let chunks = index.query(reference_sequence_id, interval)?;
+
+    let mut buf = String::new();
+    let mut n = 0;
+
+    for chunk in chunks {
+        reader.seek(chunk.start())?;
+
+        loop {
+            buf.clear();
+
+            if reader.read_record(&mut buf)? == 0 {
+                break;
+            }
+
+            if let Some((chromosome, record_interval)) = parse_site_interval(&buf)? {
+                if chromosome == reference_sequence_name && record_interval.intersects(interval) {
+                    n += 1;
+                }
+            }
+
+            if reader.virtual_position() >= chunk.end() {
+                break;
+            }
+        }
+    }
+
+    Ok(n)
+}
+
+fn is_unbounded(interval: Interval) -> bool {
+    matches!(interval.start(), None | Some(Position::MIN)) && interval.end().is_none()
+}
+
+// Parses the chromosome and interval of a raw record, skipping all other fields.
+fn parse_site_interval(s: &str) -> io::Result<Option<(&str, Interval)>> {
+    let mut fields = s.split(FIELD_DELIMITER);
+
+    let chromosome = match fields.next() {
+        Some(t) if !t.is_empty() => t,
+        _ => return Ok(None),
+    };
+
+    let start = fields
+        .next()
+        .ok_or_else(|| invalid_data("missing position"))
+        .and_then(|t| t.parse::<Position>().map_err(invalid_data))?;
+
+    let reference_bases = fields
+        .nth(1)
+        .ok_or_else(|| invalid_data("missing reference bases"))?;
+
+    let end = match fields.nth(3).and_then(parse_info_end_position) {
+        Some(t) => t.parse::<Position>().map_err(invalid_data)?,
+        None => usize::from(start)
+            .checked_add(reference_bases.len().max(1) - 1)
+            .and_then(Position::new)
+            .ok_or_else(|| invalid_data("invalid end position"))?,
+    };
+
+    Ok(Some((chromosome, Interval::from(start..=end))))
+}
+
+fn parse_info_end_position(s: &str) -> Option<&str> {
+    s.split(INFO_FIELD_DELIMITER)
+        .find_map(|field| field.strip_prefix(END_PREFIX))
+}
+
+fn invalid_data<E>(e: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_site_interval() -> Result<(), Box<dyn std::error::Error>> {
+        let (chromosome, interval) =
+            parse_site_interval("sq0\t8\t.\tACGT\t.\t.\tPASS\t.")?.expect("missing record");
+        assert_eq!(chromosome, "sq0");
+        assert_eq!(
+            interval,
+            Interval::from(Position::try_from(8)?..=Position::try_from(11)?)
+        );
+
+        let (_, interval) = parse_site_interval("sq0\t8\t.\tA\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=13")?
+            .expect("missing record");
+        assert_eq!(
+            interval,
+            Interval::from(Position::try_from(8)?..=Position::try_from(13)?)
+        );
+
+        assert!(parse_site_interval("")?.is_none());
+        assert!(parse_site_interval("sq0\tndls\t.\tA").is_err());
+        assert!(parse_site_interval("sq0\t8").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let records = [
+            ("sq0", 1, 1, "sq0\t1\t.\tA\t.\t.\tPASS\t."),
+            ("sq0", 8, 12, "sq0\t8\t.\tACGTA\t.\t.\tPASS\t."),
+            ("sq0", 21, 34, "sq0\t21\t.\tC\t<DEL>\t.\tPASS\tEND=34"),
+            ("sq1", 5, 5, "sq1\t5\t.\tG\t.\t.\tPASS\t."),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut indexer = tabix::Index::indexer();
+
+        for (chromosome, start, end, line) in records {
+            let chunk_start = writer.virtual_position();
+            writeln!(writer, "{}", line)?;
+            let chunk_end = writer.virtual_position();
+
+            indexer.add_record(
+                chromosome,
+                Position::try_from(start)?,
+                Position::try_from(end)?,
+                Chunk::new(chunk_start, chunk_end),
+            );
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build();
+
+        let mut reader = Reader::new(bgzf::Reader::new(io::Cursor::new(data)));
+
+        let mut t = |raw_region: &str, expected: u64| -> Result<(), Box<dyn std::error::Error>> {
+            let region = raw_region.parse()?;
+            let actual = reader.count(&index, &region)?;
+            assert_eq!(actual, expected, "{}", raw_region);
+            Ok(())
+        };
+
+        t("sq0", 3)?;
+        t("sq0:1-4", 1)?;
+        t("sq0:13-20", 0)?;
+        t("sq0:30-", 1)?;
+        t("sq1", 1)?;
+        t("sq1:6-", 0)?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod genotype;
 pub mod keys;
+pub mod matrix;
 
 pub use self::{genotype::Genotype, keys::Keys};
 
@@ -0,0 +1,567 @@
+//! Columnar VCF record genotypes.
+
+mod column;
+
+pub use self::column::Column;
+
+use std::{error, fmt};
+
+use indexmap::IndexMap;
+
+use super::genotype::{field::Value, Genotype, GenotypeError};
+use crate::{
+    header::{
+        format::{Key, Type},
+        Format,
+    },
+    Header, Record,
+};
+
+/// The values of a genotype field in a genotype matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    /// 32-bit integers.
+    ///
+    /// Genotypes (`GT`) are decoded as allele positions.
+    Integer(Column<i32>),
+    /// Single-precision floating-points.
+    Float(Column<f32>),
+    /// Characters.
+    Character(Column<char>),
+    /// Strings.
+    String(Column<String>),
+}
+
+/// A columnar genotype matrix.
+///
+/// Each genotype field is decoded into a [`Column`], a flat buffer of values with the shape
+/// variants × samples × width and a mask of missing values. This is suitable to be handed to
+/// array libraries without going through the values of each record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    variant_count: usize,
+    sample_count: usize,
+    columns: IndexMap<Key, Values>,
+}
+
+impl Matrix {
+    /// Returns the number of variants, i.e., records, in the matrix.
+    pub fn variant_count(&self) -> usize {
+        self.variant_count
+    }
+
+    /// Returns the number of samples in the matrix.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the values of the given genotype field key.
+    pub fn get(&self, key: &Key) -> Option<&Values> {
+        self.columns.get(key)
+    }
+
+    /// Returns the values of all genotype fields, in the order they were requested.
+    pub fn columns(&self) -> &IndexMap<Key, Values> {
+        &self.columns
+    }
+}
+
+/// An error returned when a VCF record fails to be added to a genotype matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AddRecordError {
+    /// The number of genotypes does not match the number of samples.
+    SampleCountMismatch {
+        /// The number of samples.
+        expected: usize,
+        /// The number of genotypes in the record.
+        actual: usize,
+    },
+    /// A genotype (`GT`) is invalid.
+    InvalidGenotype(GenotypeError),
+    /// A value type does not match the header type.
+    InvalidValueType(Key),
+}
+
+impl error::Error for AddRecordError {}
+
+impl fmt::Display for AddRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SampleCountMismatch { expected, actual } => write!(
+                f,
+                "sample count mismatch: expected {}, got {}",
+                expected, actual
+            ),
+            Self::InvalidGenotype(e) => write!(f, "invalid genotype: {}", e),
+            Self::InvalidValueType(key) => write!(f, "invalid value type for key: {}", key),
+        }
+    }
+}
+
+/// A genotype matrix builder.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{format::Key, Format},
+///     record::{genotypes::matrix::{self, Values}, Genotypes, Position},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_format(Format::from(Key::Genotype))
+///     .add_format(Format::from(Key::ConditionalGenotypeQuality))
+///     .add_sample_name("sample0")
+///     .add_sample_name("sample1")
+///     .build();
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(1))
+///     .set_reference_bases("A".parse()?)
+///     .set_genotypes(Genotypes::parse("GT:GQ\t0|1:13\t./.:.", &header)?)
+///     .build()?;
+///
+/// let mut builder = matrix::Builder::new(
+///     &header,
+///     &[Key::Genotype, Key::ConditionalGenotypeQuality],
+/// );
+/// builder.add_record(&record)?;
+/// let matrix = builder.build();
+///
+/// match matrix.get(&Key::Genotype) {
+///     Some(Values::Integer(column)) => {
+///         assert_eq!(column.width(), 2);
+///         assert_eq!(column.values(), [0, 1, 0, 0]);
+///         assert_eq!(column.missing(), [false, false, true, true]);
+///     }
+///     _ => unreachable!(),
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct Builder {
+    variant_count: usize,
+    sample_count: usize,
+    columns: IndexMap<Key, Buffer>,
+}
+
+impl Builder {
+    /// Creates a genotype matrix builder for the given genotype field keys.
+    ///
+    /// The value types are taken from the header formats. Keys that are not in the header use the
+    /// type of the reserved key, if any, or `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::format::Key, record::genotypes::matrix};
+    /// let header = vcf::Header::default();
+    /// let builder = matrix::Builder::new(&header, &[Key::Genotype]);
+    /// ```
+    pub fn new(header: &Header, keys: &[Key]) -> Self {
+        let columns = keys
+            .iter()
+            .map(|key| {
+                let ty = if key == &Key::Genotype {
+                    Type::Integer
+                } else {
+                    header
+                        .formats()
+                        .get(key)
+                        .map(|format| format.ty())
+                        .unwrap_or_else(|| Format::from(key.clone()).ty())
+                };
+
+                (key.clone(), Buffer::new(ty))
+            })
+            .collect();
+
+        Self {
+            variant_count: 0,
+            sample_count: header.sample_names().len(),
+            columns,
+        }
+    }
+
+    /// Decodes the genotypes of a record and adds them to the matrix.
+    ///
+    /// A record without genotypes has missing values for all samples. If a record fails to be
+    /// added, the builder should be discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::format::Key, record::{genotypes::matrix, Position}};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut builder = matrix::Builder::new(&header, &[Key::Genotype]);
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// builder.add_record(&record)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(&mut self, record: &Record) -> Result<(), AddRecordError> {
+        let genotypes = record.genotypes();
+
+        if !genotypes.is_empty() && genotypes.len() != self.sample_count {
+            return Err(AddRecordError::SampleCountMismatch {
+                expected: self.sample_count,
+                actual: genotypes.len(),
+            });
+        }
+
+        for (key, buffer) in &mut self.columns {
+            for i in 0..self.sample_count {
+                match genotypes.get(i) {
+                    Some(genotype) => buffer.push_genotype_value(key, genotype)?,
+                    None => buffer.push_missing(),
+                }
+            }
+        }
+
+        self.variant_count += 1;
+
+        Ok(())
+    }
+
+    /// Builds a genotype matrix.
+    ///
+    /// The width of each column is the largest number of values of a genotype field value in all
+    /// records. Shorter values are padded with missing values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::format::Key, record::genotypes::matrix};
+    ///
+    /// let header = vcf::Header::default();
+    /// let matrix = matrix::Builder::new(&header, &[Key::Genotype]).build();
+    ///
+    /// assert_eq!(matrix.variant_count(), 0);
+    /// assert!(matrix.get(&Key::Genotype).is_some());
+    /// ```
+    pub fn build(self) -> Matrix {
+        let (variant_count, sample_count) = (self.variant_count, self.sample_count);
+
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|(key, buffer)| {
+                let values = match buffer {
+                    Buffer::Integer(cells) => {
+                        Values::Integer(cells.build(variant_count, sample_count))
+                    }
+                    Buffer::Float(cells) => Values::Float(cells.build(variant_count, sample_count)),
+                    Buffer::Character(cells) => {
+                        Values::Character(cells.build(variant_count, sample_count))
+                    }
+                    Buffer::String(cells) => {
+                        Values::String(cells.build(variant_count, sample_count))
+                    }
+                };
+
+                (key, values)
+            })
+            .collect();
+
+        Matrix {
+            variant_count,
+            sample_count,
+            columns,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Buffer {
+    Integer(Cells<i32>),
+    Float(Cells<f32>),
+    Character(Cells<char>),
+    String(Cells<String>),
+}
+
+impl Buffer {
+    fn new(ty: Type) -> Self {
+        match ty {
+            Type::Integer => Self::Integer(Cells::default()),
+            Type::Float => Self::Float(Cells::default()),
+            Type::Character => Self::Character(Cells::default()),
+            Type::String => Self::String(Cells::default()),
+        }
+    }
+
+    fn push_missing(&mut self) {
+        match self {
+            Self::Integer(cells) => cells.push(Vec::new()),
+            Self::Float(cells) => cells.push(Vec::new()),
+            Self::Character(cells) => cells.push(Vec::new()),
+            Self::String(cells) => cells.push(Vec::new()),
+        }
+    }
+
+    fn push_genotype_value(
+        &mut self,
+        key: &Key,
+        genotype: &Genotype,
+    ) -> Result<(), AddRecordError> {
+        if key == &Key::Genotype {
+            return self.push_genotype(key, genotype);
+        }
+
+        let value = match genotype.get(key).and_then(|field| field.value()) {
+            Some(value) => value,
+            None => {
+                self.push_missing();
+                return Ok(());
+            }
+        };
+
+        match (self, value) {
+            (Self::Integer(cells), Value::Integer(n)) => cells.push(vec![Some(*n)]),
+            (Self::Integer(cells), Value::IntegerArray(values)) => cells.push(values.clone()),
+            (Self::Float(cells), Value::Float(n)) => cells.push(vec![Some(*n)]),
+            (Self::Float(cells), Value::FloatArray(values)) => cells.push(values.clone()),
+            (Self::Character(cells), Value::Character(c)) => cells.push(vec![Some(*c)]),
+            (Self::Character(cells), Value::CharacterArray(values)) => cells.push(values.clone()),
+            (Self::String(cells), Value::String(s)) => cells.push(vec![Some(s.clone())]),
+            (Self::String(cells), Value::StringArray(values)) => cells.push(values.clone()),
+            _ => return Err(AddRecordError::InvalidValueType(key.clone())),
+        }
+
+        Ok(())
+    }
+
+    fn push_genotype(&mut self, key: &Key, genotype: &Genotype) -> Result<(), AddRecordError> {
+        let cells = match self {
+            Self::Integer(cells) => cells,
+            _ => return Err(AddRecordError::InvalidValueType(key.clone())),
+        };
+
+        let value = match genotype.genotype() {
+            Some(result) => result.map_err(AddRecordError::InvalidGenotype)?,
+            None => {
+                cells.push(Vec::new());
+                return Ok(());
+            }
+        };
+
+        let positions = value
+            .iter()
+            .map(|allele| {
+                allele
+                    .position()
+                    .map(|position| {
+                        i32::try_from(position)
+                            .map_err(|_| AddRecordError::InvalidValueType(key.clone()))
+                    })
+                    .transpose()
+            })
+            .collect::<Result<_, _>>()?;
+
+        cells.push(positions);
+
+        Ok(())
+    }
+}
+
+// Ragged values of each variant-sample cell.
+#[derive(Debug)]
+struct Cells<T> {
+    values: Vec<Option<T>>,
+    lens: Vec<usize>,
+}
+
+impl<T> Default for Cells<T> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            lens: Vec::new(),
+        }
+    }
+}
+
+impl<T> Cells<T>
+where
+    T: Default,
+{
+    fn push(&mut self, values: Vec<Option<T>>) {
+        self.lens.push(values.len());
+        self.values.extend(values);
+    }
+
+    fn build(self, variant_count: usize, sample_count: usize) -> Column<T> {
+        let width = self.lens.iter().copied().max().unwrap_or(0);
+        let len = self.lens.len() * width;
+
+        let mut values = Vec::with_capacity(len);
+        let mut missing = Vec::with_capacity(len);
+
+        let mut src = self.values.into_iter();
+
+        for cell_len in self.lens {
+            for value in src.by_ref().take(cell_len) {
+                missing.push(value.is_none());
+                values.push(value.unwrap_or_default());
+            }
+
+            for _ in cell_len..width {
+                missing.push(true);
+                values.push(T::default());
+            }
+        }
+
+        Column::new(variant_count, sample_count, width, values, missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::Number,
+        record::{Genotypes, Position},
+    };
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_format(Format::from(Key::Genotype))
+            .add_format(Format::from(Key::ConditionalGenotypeQuality))
+            .add_format(Format::from(Key::ReadDepths))
+            .add_format(Format::new(
+                Key::Other("FT0".parse().unwrap()),
+                Number::Count(1),
+                Type::String,
+                String::default(),
+            ))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build()
+    }
+
+    fn build_record(
+        header: &Header,
+        genotypes: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let mut builder = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?);
+
+        if !genotypes.is_empty() {
+            builder = builder.set_genotypes(Genotypes::parse(genotypes, header)?);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let ft0 = Key::Other("FT0".parse()?);
+
+        let mut builder = Builder::new(
+            &header,
+            &[
+                Key::Genotype,
+                Key::ConditionalGenotypeQuality,
+                Key::ReadDepths,
+                ft0.clone(),
+            ],
+        );
+
+        builder.add_record(&build_record(&header, "GT:GQ:AD\t0/1:13:5,8\t1|1:.:0,3")?)?;
+        builder.add_record(&build_record(&header, "GT:AD:FT0\t0:3,.,1:x\t./.:.:.")?)?;
+        builder.add_record(&build_record(&header, "")?)?;
+
+        let matrix = builder.build();
+
+        assert_eq!(matrix.variant_count(), 3);
+        assert_eq!(matrix.sample_count(), 2);
+        assert!(matrix.columns().keys().eq([
+            &Key::Genotype,
+            &Key::ConditionalGenotypeQuality,
+            &Key::ReadDepths,
+            &ft0
+        ]));
+
+        let expected = Column::new(
+            3,
+            2,
+            2,
+            vec![0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            vec![
+                false, false, false, false, false, true, true, true, true, true, true, true,
+            ],
+        );
+        assert_eq!(matrix.get(&Key::Genotype), Some(&Values::Integer(expected)));
+
+        let expected = Column::new(
+            3,
+            2,
+            1,
+            vec![13, 0, 0, 0, 0, 0],
+            vec![false, true, true, true, true, true],
+        );
+        assert_eq!(
+            matrix.get(&Key::ConditionalGenotypeQuality),
+            Some(&Values::Integer(expected))
+        );
+
+        match matrix.get(&Key::ReadDepths) {
+            Some(Values::Integer(column)) => {
+                assert_eq!(column.width(), 3);
+                assert_eq!(
+                    column.get(0, 0),
+                    Some((&[5, 8, 0][..], &[false, false, true][..]))
+                );
+                assert_eq!(
+                    column.get(1, 0),
+                    Some((&[3, 0, 1][..], &[false, true, false][..]))
+                );
+                assert_eq!(
+                    column.get(1, 1),
+                    Some((&[0, 0, 0][..], &[true, true, true][..]))
+                );
+            }
+            _ => panic!("invalid values"),
+        }
+
+        match matrix.get(&ft0) {
+            Some(Values::String(column)) => {
+                assert_eq!(
+                    column.get(1, 0),
+                    Some((&[String::from("x")][..], &[false][..]))
+                );
+                assert_eq!(column.missing(), [true, true, false, true, true, true]);
+            }
+            _ => panic!("invalid values"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_sample_count_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let mut builder = Builder::new(&header, &[Key::Genotype]);
+
+        let record = build_record(&header, "GT\t0/1\t1|1\t0/0")?;
+
+        assert_eq!(
+            builder.add_record(&record),
+            Err(AddRecordError::SampleCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+
+        Ok(())
+    }
+}
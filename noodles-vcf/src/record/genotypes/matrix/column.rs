@@ -0,0 +1,71 @@
+/// A column of a genotype matrix.
+///
+/// The values are stored in row-major order with the shape variants × samples × width, i.e., the
+/// values of variant `i` and sample `j` start at `(i * sample_count + j) * width`. Each value has a
+/// corresponding flag in the missing mask. Missing values are set to the default value of the
+/// type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column<T> {
+    variant_count: usize,
+    sample_count: usize,
+    width: usize,
+    values: Vec<T>,
+    missing: Vec<bool>,
+}
+
+impl<T> Column<T> {
+    pub(super) fn new(
+        variant_count: usize,
+        sample_count: usize,
+        width: usize,
+        values: Vec<T>,
+        missing: Vec<bool>,
+    ) -> Self {
+        Self {
+            variant_count,
+            sample_count,
+            width,
+            values,
+            missing,
+        }
+    }
+
+    /// Returns the shape of the column, i.e., (variants, samples, width).
+    pub fn shape(&self) -> (usize, usize, usize) {
+        (self.variant_count, self.sample_count, self.width)
+    }
+
+    /// Returns the number of values per variant-sample cell.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the values.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the missing mask.
+    ///
+    /// A value is missing if its flag is `true`.
+    pub fn missing(&self) -> &[bool] {
+        &self.missing
+    }
+
+    /// Returns the values and missing mask of the given variant and sample.
+    pub fn get(&self, variant: usize, sample: usize) -> Option<(&[T], &[bool])> {
+        if variant >= self.variant_count || sample >= self.sample_count {
+            return None;
+        }
+
+        let start = (variant * self.sample_count + sample) * self.width;
+        let end = start + self.width;
+
+        Some((&self.values[start..end], &self.missing[start..end]))
+    }
+
+    /// Returns the values and missing mask.
+    pub fn into_parts(self) -> (Vec<T>, Vec<bool>) {
+        (self.values, self.missing)
+    }
+}
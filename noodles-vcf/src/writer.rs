@@ -1,3 +1,9 @@
+//! VCF writer.
+
+pub mod builder;
+
+pub use self::builder::Builder;
+
 use std::io::{self, Write};
 
 use super::{Header, Record};
@@ -20,7 +26,7 @@ use super::{Header, Record};
 ///
 /// let record = vcf::Record::builder()
 ///     .set_chromosome("sq0".parse()?)
-///     .set_position(Position::try_from(1)?)
+///     .set_position(Position::from(1))
 ///     .set_reference_bases("A".parse()?)
 ///     .build()?;
 ///
@@ -122,7 +128,7 @@ where
     ///
     /// let record = vcf::Record::builder()
     ///     .set_chromosome("sq0".parse()?)
-    ///     .set_position(Position::try_from(1)?)
+    ///     .set_position(Position::from(1))
     ///     .set_reference_bases("A".parse()?)
     ///     .build()?;
     ///
@@ -162,7 +168,7 @@ mod tests {
 
         let record = Record::builder()
             .set_chromosome("sq0".parse()?)
-            .set_position(Position::try_from(1)?)
+            .set_position(Position::from(1))
             .set_reference_bases("A".parse()?)
             .build()?;
 
@@ -200,7 +206,7 @@ mod tests {
 
         let record = Record::builder()
             .set_chromosome("sq0".parse()?)
-            .set_position(Position::try_from(1)?)
+            .set_position(Position::from(1))
             .set_reference_bases("A".parse()?)
             .set_genotypes(genotypes)
             .build()?;
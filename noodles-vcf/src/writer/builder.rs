@@ -0,0 +1,361 @@
+//! VCF writer builder.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::Path,
+};
+
+use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+
+use super::Writer;
+
+/// A VCF writer compression method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompressionMethod {
+    /// Uncompressed output.
+    None,
+    /// BGZF-compressed output, e.g., `.vcf.gz`.
+    Bgzf,
+}
+
+/// The output stream of a VCF writer built by a [`Builder`].
+pub enum Output<W>
+where
+    W: Write + Send + 'static,
+{
+    /// An uncompressed stream.
+    Raw(W),
+    /// A BGZF-compressed stream.
+    Bgzf(bgzf::Writer<W>),
+    /// A BGZF-compressed stream that is compressed using multiple worker threads.
+    MultithreadedBgzf(bgzf::MultithreadedWriter<W>),
+}
+
+impl<W> Output<W>
+where
+    W: Write + Send + 'static,
+{
+    /// Finishes the output stream and returns the underlying writer.
+    ///
+    /// For BGZF-compressed streams, this writes any buffered data and the final BGZF EOF block.
+    /// The underlying writer is then flushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::writer::{builder::CompressionMethod, Builder};
+    ///
+    /// let writer = Builder::default()
+    ///     .set_compression_method(CompressionMethod::Bgzf)
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// let buf = writer.into_inner().finish()?;
+    /// assert!(!buf.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<W> {
+        let mut inner = match self {
+            Self::Raw(inner) => inner,
+            Self::Bgzf(inner) => inner.finish()?,
+            Self::MultithreadedBgzf(inner) => inner.finish()?,
+        };
+
+        inner.flush()?;
+
+        Ok(inner)
+    }
+}
+
+impl<W> Write for Output<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Raw(inner) => inner.write(buf),
+            Self::Bgzf(inner) => inner.write(buf),
+            Self::MultithreadedBgzf(inner) => inner.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Raw(inner) => inner.flush(),
+            Self::Bgzf(inner) => inner.flush(),
+            Self::MultithreadedBgzf(inner) => inner.flush(),
+        }
+    }
+}
+
+impl<W> Writer<Output<W>>
+where
+    W: Write + Send + 'static,
+{
+    /// Finishes the output stream and returns the underlying writer.
+    ///
+    /// This should be called when writing is complete. Otherwise, a BGZF-compressed stream is
+    /// only finished when the writer is dropped, and any I/O errors are then ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, writer::Builder};
+    ///
+    /// let mut writer = Builder::default().build_from_writer(Vec::new());
+    /// writer.write_header(&vcf::Header::default())?;
+    ///
+    /// let buf = writer.finish()?;
+    /// assert!(buf.starts_with(b"##fileformat=VCFv4.3\n"));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<W> {
+        self.into_inner().finish()
+    }
+}
+
+/// A VCF writer builder.
+///
+/// The built writer wraps the output stream in a BGZF encoder, optionally using multiple worker
+/// threads, when the compression method is set to [`CompressionMethod::Bgzf`]. Use
+/// [`Writer::finish`] to write the final BGZF EOF block and check for errors when writing is
+/// complete.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_method: Option<CompressionMethod>,
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets the compression method.
+    ///
+    /// By default, the compression method is inferred from the path when using
+    /// [`Self::build_from_path`], i.e., BGZF when the extension is `.gz`, and uncompressed
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::writer::{builder::CompressionMethod, Builder};
+    /// let builder = Builder::default().set_compression_method(CompressionMethod::Bgzf);
+    /// ```
+    pub fn set_compression_method(mut self, compression_method: CompressionMethod) -> Self {
+        self.compression_method = Some(compression_method);
+        self
+    }
+
+    /// Sets the BGZF compression level.
+    ///
+    /// This is only used when the output is BGZF-compressed. By default, the compression level
+    /// is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::writer::CompressionLevel;
+    /// use noodles_vcf::writer::Builder;
+    /// let builder = Builder::default().set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets the number of BGZF compression worker threads.
+    ///
+    /// This is only used when the output is BGZF-compressed. By default, blocks are compressed on
+    /// the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_vcf::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::new(4).unwrap());
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a VCF writer from a path.
+    ///
+    /// The file is created, truncating it if it exists. If the compression method is not set, it
+    /// is inferred from the extension of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, writer::Builder};
+    ///
+    /// let mut writer = Builder::default().build_from_path("sample.vcf.gz")?;
+    /// writer.write_header(&vcf::Header::default())?;
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(mut self, dst: P) -> io::Result<Writer<Output<BufWriter<File>>>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+
+        if self.compression_method.is_none() {
+            self.compression_method = match dst.extension() {
+                Some(ext) if ext == "gz" => Some(CompressionMethod::Bgzf),
+                _ => Some(CompressionMethod::None),
+            };
+        }
+
+        File::create(dst)
+            .map(BufWriter::new)
+            .map(|file| self.build_from_writer(file))
+    }
+
+    /// Builds a VCF writer from a writer.
+    ///
+    /// If the compression method is not set, the output is uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     writer::{builder::CompressionMethod, Builder},
+    /// };
+    ///
+    /// let mut writer = Builder::default()
+    ///     .set_compression_method(CompressionMethod::Bgzf)
+    ///     .build_from_writer(io::sink());
+    ///
+    /// writer.write_header(&vcf::Header::default())?;
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<Output<W>>
+    where
+        W: Write + Send + 'static,
+    {
+        let inner = match self.compression_method {
+            Some(CompressionMethod::Bgzf) => {
+                let builder = bgzf::Writer::builder(writer)
+                    .set_compression_level(self.compression_level.unwrap_or_default());
+
+                match self.worker_count {
+                    Some(worker_count) if worker_count.get() > 1 => {
+                        Output::MultithreadedBgzf(builder.build_with_worker_count(worker_count))
+                    }
+                    _ => Output::Bgzf(builder.build()),
+                }
+            }
+            Some(CompressionMethod::None) | None => Output::Raw(writer),
+        };
+
+        Writer::new(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Read,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::{header::Contig, record::Position, Header, Record};
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn write(builder: Builder) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig(Contig::new("sq0".parse()?))
+            .build();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let buf = SharedBuf::default();
+        let mut writer = builder.build_from_writer(buf.clone());
+        writer.write_header(&header)?;
+        writer.write_record(&record)?;
+        writer.finish()?;
+
+        let data = buf.0.lock().unwrap().clone();
+        Ok(data)
+    }
+
+    #[test]
+    fn test_build_from_writer() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = write(Builder::default().set_compression_method(CompressionMethod::None))?;
+        assert!(expected.starts_with(b"##fileformat=VCFv4.3\n"));
+        assert_eq!(write(Builder::default())?, expected);
+
+        for worker_count in [1, 2] {
+            let builder = Builder::default()
+                .set_compression_method(CompressionMethod::Bgzf)
+                .set_compression_level(CompressionLevel::fast())
+                .set_worker_count(NonZeroUsize::new(worker_count).unwrap());
+
+            let data = write(builder)?;
+            assert!(bgzf::has_eof(&mut io::Cursor::new(&data))?);
+
+            let mut actual = Vec::new();
+            bgzf::Reader::new(&data[..]).read_to_end(&mut actual)?;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_write_error() -> io::Result<()> {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        for worker_count in [1, 2] {
+            let mut writer = Builder::default()
+                .set_compression_method(CompressionMethod::Bgzf)
+                .set_worker_count(NonZeroUsize::new(worker_count).unwrap())
+                .build_from_writer(FailingWriter);
+
+            // The header is buffered by the BGZF encoder and is only written when finishing.
+            writer.write_header(&Header::default())?;
+
+            assert!(matches!(
+                writer.finish(),
+                Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe
+            ));
+        }
+
+        Ok(())
+    }
+}